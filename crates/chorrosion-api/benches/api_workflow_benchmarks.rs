@@ -17,7 +17,7 @@ use chorrosion_infrastructure::{
         SqliteDuplicateRepository, SqliteIndexerDefinitionRepository,
         SqliteMetadataProfileRepository, SqliteQualityProfileRepository,
         SqliteSmartPlaylistRepository, SqliteTagRepository, SqliteTaggedEntityRepository,
-        SqliteTrackRepository,
+        SqliteTrackFileRepository, SqliteTrackRepository,
     },
     ResponseCache,
 };
@@ -59,6 +59,7 @@ fn make_state(pool: SqlitePool) -> AppState {
         Arc::new(SqliteTaggedEntityRepository::new(pool.clone())),
         Arc::new(SqliteSmartPlaylistRepository::new(pool.clone())),
         Arc::new(SqliteDuplicateRepository::new(pool.clone())),
+        Arc::new(SqliteTrackFileRepository::new(pool.clone())),
         ResponseCache::new(1_000, 0),
     )
 }
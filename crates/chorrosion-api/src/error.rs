@@ -0,0 +1,279 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+//! Shared error type for API handlers.
+//!
+//! [`ApiError`] maps each failure class to one status code and a common JSON
+//! body, [`ApiErrorResponse`]:
+//!
+//! * validation failures → `422` with one entry per offending field,
+//! * malformed requests → `400`, with per-field details for rejected import payloads,
+//! * missing credentials → `401`, missing permissions → `403`,
+//! * missing entities → `404`, conflicts → `409`,
+//! * failing upstream indexers or download clients → `502`, unconfigured features → `503`,
+//! * anything unexpected → `500` with a correlation id, the request's
+//!   `X-Request-Id` when served through the router. The underlying error is
+//!   logged under that id and never sent to the client.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use chorrosion_domain::ValidationError;
+use chorrosion_infrastructure::repositories::ConcurrencyConflict;
+use serde::{Deserialize, Serialize};
+use tracing::error;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::middleware::request_id;
+
+#[derive(Debug)]
+pub enum ApiError {
+    /// The request was well-formed but the resulting entity failed validation.
+    Validation(Vec<ValidationError>),
+    BadRequest(String),
+    /// A request payload with several rejected fields, such as an import document.
+    InvalidPayload {
+        message: String,
+        details: Vec<FieldErrorResponse>,
+    },
+    Unauthorized(String),
+    Forbidden(String),
+    NotFound(String),
+    Conflict(String),
+    /// An indexer or download client failed or refused the request.
+    BadGateway(String),
+    /// The feature behind the endpoint is not configured.
+    Unavailable(String),
+    Internal(anyhow::Error),
+}
+
+impl ApiError {
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self::BadRequest(message.into())
+    }
+
+    /// Reject a payload, listing each offending field with its message.
+    pub fn invalid_payload(
+        message: impl Into<String>,
+        details: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        Self::InvalidPayload {
+            message: message.into(),
+            details: details
+                .into_iter()
+                .map(|(field, message)| FieldErrorResponse { field, message })
+                .collect(),
+        }
+    }
+
+    pub fn unauthorized(message: impl Into<String>) -> Self {
+        Self::Unauthorized(message.into())
+    }
+
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self::Forbidden(message.into())
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self::NotFound(message.into())
+    }
+
+    pub fn conflict(message: impl Into<String>) -> Self {
+        Self::Conflict(message.into())
+    }
+
+    pub fn bad_gateway(message: impl Into<String>) -> Self {
+        Self::BadGateway(message.into())
+    }
+
+    pub fn unavailable(message: impl Into<String>) -> Self {
+        Self::Unavailable(message.into())
+    }
+
+    /// An unexpected failure; `error` is logged under `context` and never sent to the client.
+    pub fn internal(error: impl Into<anyhow::Error>, context: &'static str) -> Self {
+        Self::from(error.into().context(context))
+    }
+
+    pub fn status(&self) -> StatusCode {
+        match self {
+            Self::Validation(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::BadRequest(_) | Self::InvalidPayload { .. } => StatusCode::BAD_REQUEST,
+            Self::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            Self::Forbidden(_) => StatusCode::FORBIDDEN,
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Conflict(_) => StatusCode::CONFLICT,
+            Self::BadGateway(_) => StatusCode::BAD_GATEWAY,
+            Self::Unavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
+            Self::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Validation(errors) => {
+                let fields = errors
+                    .iter()
+                    .map(|error| format!("{}: {}", error.field, error.message))
+                    .collect::<Vec<_>>();
+                write!(f, "validation failed: {}", fields.join(", "))
+            }
+            Self::InvalidPayload { message, .. }
+            | Self::BadRequest(message)
+            | Self::Unauthorized(message)
+            | Self::Forbidden(message)
+            | Self::NotFound(message)
+            | Self::Conflict(message)
+            | Self::BadGateway(message)
+            | Self::Unavailable(message) => f.write_str(message),
+            Self::Internal(source) => write!(f, "{source:#}"),
+        }
+    }
+}
+
+impl From<anyhow::Error> for ApiError {
+    fn from(error: anyhow::Error) -> Self {
+        match error.downcast_ref::<ConcurrencyConflict>() {
+            Some(conflict) => Self::Conflict(conflict.to_string()),
+            None => Self::Internal(error),
+        }
+    }
+}
+
+/// Whether `error` is a repository `update` that lost a race with another write,
+/// which handlers report as `409 Conflict`.
+pub(crate) fn is_concurrency_conflict(error: &anyhow::Error) -> bool {
+    error.is::<ConcurrencyConflict>()
+}
+
+impl From<Vec<ValidationError>> for ApiError {
+    fn from(errors: Vec<ValidationError>) -> Self {
+        Self::Validation(errors)
+    }
+}
+
+/// A single field that failed validation.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct FieldErrorResponse {
+    pub field: String,
+    pub message: String,
+}
+
+/// JSON body returned for every [`ApiError`].
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ApiErrorResponse {
+    pub error: String,
+    /// Per-field failures; present only for `422` responses and rejected import payloads.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub details: Vec<FieldErrorResponse>,
+    /// Identifies the server log entry for a `500` response.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<String>,
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let status = self.status();
+        let body = match self {
+            Self::Validation(errors) => ApiErrorResponse {
+                error: "validation failed".to_string(),
+                details: errors
+                    .into_iter()
+                    .map(|error| FieldErrorResponse {
+                        field: error.field.to_string(),
+                        message: error.message,
+                    })
+                    .collect(),
+                correlation_id: None,
+            },
+            Self::InvalidPayload { message, details } => ApiErrorResponse {
+                error: message,
+                details,
+                correlation_id: None,
+            },
+            Self::BadRequest(message)
+            | Self::Unauthorized(message)
+            | Self::Forbidden(message)
+            | Self::NotFound(message)
+            | Self::Conflict(message)
+            | Self::BadGateway(message)
+            | Self::Unavailable(message) => ApiErrorResponse {
+                error: message,
+                details: Vec::new(),
+                correlation_id: None,
+            },
+            Self::Internal(source) => {
+                let correlation_id = request_id::current()
+                    .map(|request_id| request_id.as_str().to_string())
+                    .unwrap_or_else(|| Uuid::new_v4().to_string());
+                error!(target: "api", %correlation_id, error = format!("{source:#}"), "request failed");
+                ApiErrorResponse {
+                    error: "internal server error".to_string(),
+                    details: Vec::new(),
+                    correlation_id: Some(correlation_id),
+                }
+            }
+        };
+        (status, Json(body)).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ApiError, ApiErrorResponse};
+    use axum::{body::to_bytes, http::StatusCode, response::IntoResponse};
+    use chorrosion_domain::ValidationError;
+
+    async fn body_of(error: ApiError) -> (StatusCode, ApiErrorResponse) {
+        let response = error.into_response();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn validation_errors_map_to_422_with_field_details() {
+        let (status, body) = body_of(ApiError::from(vec![ValidationError {
+            field: "name",
+            message: "name cannot be empty".to_string(),
+        }]))
+        .await;
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body.details.len(), 1);
+        assert_eq!(body.details[0].field, "name");
+        assert_eq!(body.details[0].message, "name cannot be empty");
+        assert!(body.correlation_id.is_none());
+    }
+
+    #[tokio::test]
+    async fn internal_errors_hide_the_cause_behind_a_correlation_id() {
+        let (status, body) = body_of(ApiError::from(anyhow::anyhow!("database is locked"))).await;
+
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(body.error, "internal server error");
+        assert!(body.correlation_id.is_some());
+        assert!(body.details.is_empty());
+    }
+
+    #[tokio::test]
+    async fn concurrency_conflicts_map_to_409_through_context() {
+        use anyhow::Context;
+        use chorrosion_infrastructure::repositories::ConcurrencyConflict;
+
+        let error = Err::<(), _>(ConcurrencyConflict::new("artist", "a1"))
+            .context("failed to update artist")
+            .unwrap_err();
+        let (status, body) = body_of(ApiError::from(error)).await;
+
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert!(
+            body.error.contains("artist a1 was modified"),
+            "{}",
+            body.error
+        );
+    }
+}
@@ -1,10 +1,7 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
-use axum::{extract::State, http::StatusCode, Json};
-use chorrosion_application::{
-    AppState, CachedActivityItem, DelugeClient, DownloadClient, DownloadState, NzbgetClient,
-    QBittorrentClient, SabnzbdClient, TransmissionClient,
-};
-use chorrosion_domain::DownloadClientDefinition;
+use crate::error::{ApiError, ApiErrorResponse};
+use axum::{extract::State, Json};
+use chorrosion_application::{download_client_for, AppState, CachedActivityItem, DownloadState};
 use futures_util::future::join_all;
 use serde::Serialize;
 use std::collections::HashSet;
@@ -30,36 +27,6 @@ pub struct ActivityListResponse {
     pub total: i64,
 }
 
-fn build_download_client(definition: &DownloadClientDefinition) -> Option<Box<dyn DownloadClient>> {
-    let client_type = definition.client_type.trim().to_lowercase();
-    match client_type.as_str() {
-        "qbittorrent" => Some(Box::new(QBittorrentClient::new(
-            definition.base_url.clone(),
-            definition.username.clone(),
-            definition.password_encrypted.clone(),
-        ))),
-        "transmission" => Some(Box::new(TransmissionClient::new(
-            definition.base_url.clone(),
-            definition.username.clone(),
-            definition.password_encrypted.clone(),
-        ))),
-        "deluge" => Some(Box::new(DelugeClient::new(
-            definition.base_url.clone(),
-            definition.password_encrypted.clone(),
-        ))),
-        "sabnzbd" => Some(Box::new(SabnzbdClient::new(
-            definition.base_url.clone(),
-            definition.password_encrypted.clone(),
-        ))),
-        "nzbget" => Some(Box::new(NzbgetClient::new(
-            definition.base_url.clone(),
-            definition.username.clone(),
-            definition.password_encrypted.clone(),
-        ))),
-        _ => None,
-    }
-}
-
 fn state_label(state: &DownloadState) -> &'static str {
     match state {
         DownloadState::Queued => "queued",
@@ -101,7 +68,7 @@ async fn poll_cached_snapshot(state: &AppState) -> Result<PolledActivitySnapshot
     let pairs: Vec<_> = enabled
         .into_iter()
         .filter_map(|definition| {
-            let client = build_download_client(&definition);
+            let client = download_client_for(&definition, &state.outbound_http);
             if client.is_none() {
                 warn!(
                     target: "api",
@@ -176,6 +143,13 @@ pub(crate) async fn activity_queue_snapshot(
     Ok(snapshot_to_response(snapshot.items))
 }
 
+/// Downloads currently reported by every enabled download client.
+pub(crate) async fn activity_download_items(
+    state: &AppState,
+) -> Result<Vec<CachedActivityItem>, String> {
+    Ok(poll_cached_snapshot(state).await?.items)
+}
+
 pub(crate) async fn activity_import_snapshot(_state: &AppState) -> ActivityListResponse {
     // Placeholder until import pipeline progress reporting is wired.
     ActivityListResponse {
@@ -234,34 +208,24 @@ pub(crate) async fn activity_stalled_snapshot(
     Ok(snapshot_to_response(filtered))
 }
 
-#[derive(Debug, Serialize, ToSchema)]
-pub struct ActivityErrorResponse {
-    pub error: String,
-}
-
 #[utoipa::path(
     get,
     path = "/api/v1/activity/queue",
     responses(
         (status = 200, description = "Current download queue", body = ActivityListResponse),
-        (status = 500, description = "Internal server error", body = ActivityErrorResponse)
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "activity"
 )]
 pub async fn get_activity_queue(
     State(state): State<AppState>,
-) -> Result<Json<ActivityListResponse>, (StatusCode, Json<ActivityErrorResponse>)> {
+) -> Result<Json<ActivityListResponse>, ApiError> {
     debug!(target: "api", "fetching activity queue");
 
     activity_queue_snapshot(&state)
         .await
         .map(Json)
-        .map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ActivityErrorResponse { error: e }),
-            )
-        })
+        .map_err(|e| ApiError::Internal(anyhow::anyhow!(e)))
 }
 
 #[utoipa::path(
@@ -269,24 +233,19 @@ pub async fn get_activity_queue(
     path = "/api/v1/activity/history",
     responses(
         (status = 200, description = "Activity history", body = ActivityListResponse),
-        (status = 500, description = "Internal server error", body = ActivityErrorResponse)
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "activity"
 )]
 pub async fn get_activity_history(
     State(state): State<AppState>,
-) -> Result<Json<ActivityListResponse>, (StatusCode, Json<ActivityErrorResponse>)> {
+) -> Result<Json<ActivityListResponse>, ApiError> {
     debug!(target: "api", "fetching activity history");
 
     activity_history_snapshot(&state)
         .await
         .map(Json)
-        .map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ActivityErrorResponse { error: e }),
-            )
-        })
+        .map_err(|e| ApiError::Internal(anyhow::anyhow!(e)))
 }
 
 #[utoipa::path(
@@ -294,24 +253,19 @@ pub async fn get_activity_history(
     path = "/api/v1/activity/failed",
     responses(
         (status = 200, description = "Failed downloads", body = ActivityListResponse),
-        (status = 500, description = "Internal server error", body = ActivityErrorResponse)
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "activity"
 )]
 pub async fn get_activity_failed(
     State(state): State<AppState>,
-) -> Result<Json<ActivityListResponse>, (StatusCode, Json<ActivityErrorResponse>)> {
+) -> Result<Json<ActivityListResponse>, ApiError> {
     debug!(target: "api", "fetching failed downloads");
 
     activity_failed_snapshot(&state)
         .await
         .map(Json)
-        .map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ActivityErrorResponse { error: e }),
-            )
-        })
+        .map_err(|e| ApiError::Internal(anyhow::anyhow!(e)))
 }
 
 #[utoipa::path(
@@ -319,24 +273,19 @@ pub async fn get_activity_failed(
     path = "/api/v1/activity/stalled",
     responses(
         (status = 200, description = "Stalled downloads", body = ActivityListResponse),
-        (status = 500, description = "Internal server error", body = ActivityErrorResponse)
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "activity"
 )]
 pub async fn get_activity_stalled(
     State(state): State<AppState>,
-) -> Result<Json<ActivityListResponse>, (StatusCode, Json<ActivityErrorResponse>)> {
+) -> Result<Json<ActivityListResponse>, ApiError> {
     debug!(target: "api", "fetching stalled downloads");
 
     activity_stalled_snapshot(&state)
         .await
         .map(Json)
-        .map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ActivityErrorResponse { error: e }),
-            )
-        })
+        .map_err(|e| ApiError::Internal(anyhow::anyhow!(e)))
 }
 
 #[utoipa::path(
@@ -415,6 +364,11 @@ mod tests {
                     pool.clone(),
                 ),
             ),
+            Arc::new(
+                chorrosion_infrastructure::sqlite_adapters::SqliteTrackFileRepository::new(
+                    pool.clone(),
+                ),
+            ),
             chorrosion_infrastructure::ResponseCache::new(100, 60),
         )
     }
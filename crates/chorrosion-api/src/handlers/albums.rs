@@ -1,15 +1,33 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
+use crate::error::{ApiError, ApiErrorResponse};
+use crate::handlers::activity::activity_download_items;
+use crate::handlers::artists::parse_expected_version;
+use crate::handlers::search::{grab_release, resolve_grab_client, searchable_indexers};
+use crate::middleware::request_id::correlate;
+use anyhow::{anyhow, Context};
 use axum::{
     extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
     Json,
 };
-use chorrosion_application::AppState;
-use chorrosion_domain::{Album, AlbumStatus};
+use chorrosion_application::album_files::{delete_album_files, AlbumFileDeletionSummary};
+use chorrosion_application::{
+    active_download_album_keys, album_search_target, automatic_search_missing_albums,
+    build_album_query, download_client_for, AlbumSearchStatus, AppState, AutomaticSearchDecision,
+    AutomaticSearchReason, DelayProfile, QueryBuildOptions, ReleaseFilterOptions,
+};
+use chorrosion_domain::{
+    ensure_within_any_root, Album, AlbumCreated, AlbumCreatedPayload, AlbumId, AlbumStatus,
+    AlbumUpdated, AlbumUpdatedPayload, DomainEvent, ReleaseDate,
+};
+use chorrosion_infrastructure::{collect_all, pagination::DEFAULT_PAGE_SIZE};
+use futures_util::future::join_all;
 use serde::{Deserialize, Serialize};
-use tracing::debug;
+use std::path::PathBuf;
+use tracing::{debug, warn};
 use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
 
 #[derive(Debug, Deserialize, IntoParams)]
 pub struct ListAlbumsQuery {
@@ -29,10 +47,15 @@ pub struct AlbumResponse {
     pub artist_id: String,
     pub foreign_album_id: Option<String>,
     pub title: String,
+    /// ISO 8601 at the precision known: `2024`, `2024-03` or `2024-03-15`.
     pub release_date: Option<String>,
     pub album_type: Option<String>,
     pub status: String,
     pub monitored: bool,
+    /// RFC 3339 time of the last completed metadata refresh, if any.
+    pub last_metadata_refresh_at: Option<String>,
+    /// Version of the stored album; send it back as `updated_at` when updating.
+    pub updated_at: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -51,6 +74,64 @@ pub struct TriggerAlbumSearchResponse {
     pub message: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AlbumSearchStatusResponse {
+    pub album_id: String,
+    /// Last search decision (`grabbed`, `no_results`, `below_cutoff`, ...), or
+    /// `not_searched` if the album has not been evaluated since startup.
+    pub reason: String,
+    pub decided_at: Option<String>,
+    pub release_title: Option<String>,
+    pub release_score: Option<i32>,
+    pub download_url: Option<String>,
+    pub cutoff_score: Option<i32>,
+    /// When a deferred release becomes old enough to grab (RFC 3339).
+    pub deferred_until: Option<String>,
+    pub error: Option<String>,
+}
+
+impl AlbumSearchStatusResponse {
+    fn new(album_id: String, status: Option<AlbumSearchStatus>) -> Self {
+        let Some(status) = status else {
+            return Self {
+                album_id,
+                reason: "not_searched".to_string(),
+                decided_at: None,
+                release_title: None,
+                release_score: None,
+                download_url: None,
+                cutoff_score: None,
+                deferred_until: None,
+                error: None,
+            };
+        };
+
+        let release = status.reason.release();
+        Self {
+            album_id,
+            reason: status.reason.as_str().to_string(),
+            decided_at: Some(status.decided_at.to_rfc3339()),
+            release_title: release.map(|r| r.search_result.title.clone()),
+            release_score: release.map(|r| r.score),
+            download_url: release.and_then(|r| r.search_result.download_url.clone()),
+            cutoff_score: match &status.reason {
+                AutomaticSearchReason::BelowCutoff { cutoff_score, .. } => Some(*cutoff_score),
+                _ => None,
+            },
+            deferred_until: match &status.reason {
+                AutomaticSearchReason::Deferred { available_at, .. } => {
+                    Some(available_at.to_rfc3339())
+                }
+                _ => None,
+            },
+            error: match status.reason {
+                AutomaticSearchReason::GrabFailed { error, .. } => Some(error),
+                _ => None,
+            },
+        }
+    }
+}
+
 impl From<Album> for AlbumResponse {
     fn from(album: Album) -> Self {
         Self {
@@ -58,12 +139,12 @@ impl From<Album> for AlbumResponse {
             artist_id: album.artist_id.to_string(),
             foreign_album_id: album.foreign_album_id,
             title: album.title,
-            release_date: album
-                .release_date
-                .map(|date| date.format("%Y-%m-%d").to_string()),
+            release_date: album.release_date.map(|date| date.to_iso8601()),
             album_type: album.album_type,
             status: album.status.to_string(),
             monitored: album.monitored,
+            last_metadata_refresh_at: album.last_metadata_refresh_at.map(|at| at.to_rfc3339()),
+            updated_at: album.updated_at.to_rfc3339(),
         }
     }
 }
@@ -88,38 +169,62 @@ pub struct UpdateAlbumRequest {
     pub album_type: Option<String>,
     pub status: Option<String>,
     pub monitored: Option<bool>,
+    /// The `updated_at` the edit was based on. When set, the update is
+    /// rejected with `409` if the album has been changed since.
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SetAlbumMonitoredRequest {
+    pub monitored: bool,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SetAlbumsMonitoredRequest {
+    pub album_ids: Vec<String>,
+    pub monitored: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SetAlbumsMonitoredResponse {
+    /// Number of albums whose flag was written; unknown ids are not counted.
+    pub updated: u64,
+}
+
+#[derive(Debug, Default, Deserialize, IntoParams)]
+pub struct DeleteAlbumQuery {
+    /// Also delete the album's track files from disk, within the artist's path.
+    #[serde(default)]
+    pub delete_files: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct FailedFileDeletionResponse {
+    pub path: String,
+    pub reason: String,
 }
 
-#[derive(Debug, Serialize, ToSchema)]
-#[schema(as = AlbumErrorResponse)]
-pub struct ErrorResponse {
-    pub error: String,
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DeleteAlbumResponse {
+    pub files_deleted: usize,
+    pub failed_files: Vec<FailedFileDeletionResponse>,
 }
 
-fn parse_album_status(status_str: &str) -> Result<AlbumStatus, (StatusCode, Json<ErrorResponse>)> {
+fn parse_album_status(status_str: &str) -> Result<AlbumStatus, ApiError> {
     match status_str.to_ascii_lowercase().as_str() {
         "wanted" => Ok(AlbumStatus::Wanted),
         "released" => Ok(AlbumStatus::Released),
         "announced" => Ok(AlbumStatus::Announced),
-        _ => Err((
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: format!("invalid status value: {status_str}"),
-            }),
-        )),
+        _ => Err(ApiError::bad_request(format!(
+            "invalid status value: {status_str}"
+        ))),
     }
 }
 
-fn parse_release_date(
-    date_str: &str,
-) -> Result<chrono::NaiveDate, (StatusCode, Json<ErrorResponse>)> {
-    chrono::NaiveDate::parse_from_str(date_str, "%Y-%m-%d").map_err(|_| {
-        (
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: "release_date must use YYYY-MM-DD format".to_string(),
-            }),
-        )
+fn parse_release_date(date_str: &str) -> Result<ReleaseDate, ApiError> {
+    ReleaseDate::parse_str(date_str).ok_or_else(|| {
+        ApiError::bad_request("release_date must use YYYY, YYYY-MM or YYYY-MM-DD format")
     })
 }
 
@@ -129,32 +234,24 @@ fn parse_release_date(
     params(ListAlbumsQuery),
     responses(
         (status = 200, description = "List of albums", body = ListAlbumsResponse),
-        (status = 400, description = "Invalid request", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "albums"
 )]
 pub async fn list_albums(
     State(state): State<AppState>,
     Query(query): Query<ListAlbumsQuery>,
-) -> Result<Json<ListAlbumsResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<ListAlbumsResponse>, ApiError> {
     debug!(target: "api", ?query, "listing albums");
 
     if !(1..=500).contains(&query.limit) {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: "limit must be between 1 and 500".to_string(),
-            }),
-        ));
+        return Err(ApiError::bad_request("limit must be between 1 and 500"));
     }
 
     if query.offset < 0 {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: "offset must be greater than or equal to 0".to_string(),
-            }),
+        return Err(ApiError::bad_request(
+            "offset must be greater than or equal to 0",
         ));
     }
 
@@ -163,24 +260,11 @@ pub async fn list_albums(
         .album_repository
         .list(5000, 0)
         .await
-        .map_err(|error| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse {
-                    error: format!("failed to list albums: {error}"),
-                }),
-            )
-        })?;
+        .map_err(|error| ApiError::internal(error, "failed to list albums"))?;
 
     let total = all_albums.len() as i64;
-    let offset = usize::try_from(query.offset).map_err(|_| {
-        (
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: "offset out of range".to_string(),
-            }),
-        )
-    })?;
+    let offset =
+        usize::try_from(query.offset).map_err(|_| ApiError::bad_request("offset out of range"))?;
     let limit = usize::try_from(query.limit).unwrap_or(50);
     let items = all_albums
         .into_iter()
@@ -206,9 +290,9 @@ pub async fn list_albums(
     ),
     responses(
         (status = 200, description = "List of albums for artist", body = ListAlbumsResponse),
-        (status = 400, description = "Invalid request", body = ErrorResponse),
-        (status = 404, description = "Artist not found", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 404, description = "Artist not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "albums"
 )]
@@ -216,24 +300,16 @@ pub async fn list_albums_by_artist(
     State(state): State<AppState>,
     Path(artist_id): Path<String>,
     Query(query): Query<ListAlbumsQuery>,
-) -> Result<Json<ListAlbumsResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<ListAlbumsResponse>, ApiError> {
     debug!(target: "api", %artist_id, ?query, "listing albums by artist");
 
     if !(1..=500).contains(&query.limit) {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: "limit must be between 1 and 500".to_string(),
-            }),
-        ));
+        return Err(ApiError::bad_request("limit must be between 1 and 500"));
     }
 
     if query.offset < 0 {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: "offset must be greater than or equal to 0".to_string(),
-            }),
+        return Err(ApiError::bad_request(
+            "offset must be greater than or equal to 0",
         ));
     }
 
@@ -241,45 +317,18 @@ pub async fn list_albums_by_artist(
         .artist_repository
         .get_by_id(&artist_id)
         .await
-        .map_err(|error| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse {
-                    error: format!("failed to fetch artist: {error}"),
-                }),
-            )
-        })?
-        .ok_or_else(|| {
-            (
-                StatusCode::NOT_FOUND,
-                Json(ErrorResponse {
-                    error: format!("Artist {artist_id} not found"),
-                }),
-            )
-        })?;
+        .map_err(|error| ApiError::internal(error, "failed to fetch artist"))?
+        .ok_or_else(|| ApiError::not_found(format!("Artist {artist_id} not found")))?;
 
     let all_albums = state
         .album_repository
         .get_by_artist(artist.id, 5000, 0)
         .await
-        .map_err(|error| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse {
-                    error: format!("failed to list albums by artist: {error}"),
-                }),
-            )
-        })?;
+        .map_err(|error| ApiError::internal(error, "failed to list albums by artist"))?;
 
     let total = all_albums.len() as i64;
-    let offset = usize::try_from(query.offset).map_err(|_| {
-        (
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: "offset out of range".to_string(),
-            }),
-        )
-    })?;
+    let offset =
+        usize::try_from(query.offset).map_err(|_| ApiError::bad_request("offset out of range"))?;
     let limit = usize::try_from(query.limit).unwrap_or(50);
     let items = all_albums
         .into_iter()
@@ -304,8 +353,8 @@ pub async fn list_albums_by_artist(
     ),
     responses(
         (status = 200, description = "Album found", body = AlbumResponse),
-        (status = 404, description = "Album not found", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 404, description = "Album not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "albums"
 )]
@@ -314,20 +363,8 @@ pub async fn get_album(State(state): State<AppState>, Path(id): Path<String>) ->
 
     match state.album_repository.get_by_id(&id).await {
         Ok(Some(album)) => (StatusCode::OK, Json(AlbumResponse::from(album))).into_response(),
-        Ok(None) => (
-            StatusCode::NOT_FOUND,
-            Json(ErrorResponse {
-                error: format!("Album {} not found", id),
-            }),
-        )
-            .into_response(),
-        Err(error) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: format!("failed to fetch album: {error}"),
-            }),
-        )
-            .into_response(),
+        Ok(None) => ApiError::not_found(format!("Album {} not found", id)).into_response(),
+        Err(error) => ApiError::internal(error, "failed to fetch album").into_response(),
     }
 }
 
@@ -339,8 +376,8 @@ pub async fn get_album(State(state): State<AppState>, Path(id): Path<String>) ->
     ),
     responses(
         (status = 202, description = "Album search triggered", body = TriggerAlbumSearchResponse),
-        (status = 404, description = "Album not found", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 404, description = "Album not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "albums"
 )]
@@ -353,22 +390,10 @@ pub async fn trigger_album_search(
     let album = match state.album_repository.get_by_id(&id).await {
         Ok(Some(album)) => album,
         Ok(None) => {
-            return (
-                StatusCode::NOT_FOUND,
-                Json(ErrorResponse {
-                    error: format!("Album {} not found", id),
-                }),
-            )
-                .into_response();
+            return ApiError::not_found(format!("Album {} not found", id)).into_response();
         }
         Err(error) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse {
-                    error: format!("failed to fetch album: {error}"),
-                }),
-            )
-                .into_response();
+            return ApiError::internal(error, "failed to fetch album").into_response();
         }
     };
 
@@ -380,18 +405,10 @@ pub async fn trigger_album_search(
         Ok(Some(artist)) => artist.name,
         Ok(None) => "Unknown Artist".to_string(),
         Err(error) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse {
-                    error: format!("failed to fetch artist: {error}"),
-                }),
-            )
-                .into_response();
+            return ApiError::internal(error, "failed to fetch artist").into_response();
         }
     };
-    let query = format!("{} {}", artist_name, album.title)
-        .trim()
-        .to_string();
+    let query = build_album_query(&artist_name, &album.title, &QueryBuildOptions::default());
 
     (
         StatusCode::ACCEPTED,
@@ -405,15 +422,239 @@ pub async fn trigger_album_search(
         .into_response()
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/albums/{id}/search-status",
+    params(
+        ("id" = String, Path, description = "Album ID")
+    ),
+    responses(
+        (status = 200, description = "Last automatic search decision for the album", body = AlbumSearchStatusResponse),
+        (status = 404, description = "Album not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
+    ),
+    tag = "albums"
+)]
+pub async fn get_album_search_status(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    debug!(target: "api", %id, "fetching album search status");
+
+    let album = match state.album_repository.get_by_id(&id).await {
+        Ok(Some(album)) => album,
+        Ok(None) => {
+            return ApiError::not_found(format!("Album {} not found", id)).into_response();
+        }
+        Err(error) => {
+            return ApiError::internal(error, "failed to fetch album").into_response();
+        }
+    };
+
+    let album_id = album.id.to_string();
+    let status = state.search_decision_store.get(&album_id);
+    (
+        StatusCode::OK,
+        Json(AlbumSearchStatusResponse::new(album_id, status)),
+    )
+        .into_response()
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/albums/{id}/auto-search",
+    params(
+        ("id" = String, Path, description = "Album ID")
+    ),
+    responses(
+        (status = 200, description = "Automatic search decision, after grabbing the chosen release", body = AlbumSearchStatusResponse),
+        (status = 400, description = "No searchable indexer or usable download client", body = ApiErrorResponse),
+        (status = 404, description = "Album or artist not found", body = ApiErrorResponse),
+        (status = 409, description = "Album or its artist is not monitored", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+        (status = 502, description = "Every indexer search failed", body = ApiErrorResponse)
+    ),
+    tag = "albums"
+)]
+pub async fn auto_search_album(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<axum::response::Response, ApiError> {
+    debug!(target: "api", %id, "running automatic search for album");
+
+    let album = state
+        .album_repository
+        .get_by_id(&id)
+        .await
+        .context("failed to fetch album")?
+        .ok_or_else(|| ApiError::not_found(format!("Album {} not found", id)))?;
+    let artist = state
+        .artist_repository
+        .get_by_id(&album.artist_id.to_string())
+        .await
+        .context("failed to fetch artist")?
+        .ok_or_else(|| ApiError::not_found(format!("Artist {} not found", album.artist_id)))?;
+
+    let album_id = album.id.to_string();
+    if !album.monitored || !artist.monitored {
+        state
+            .search_decision_store
+            .record(&album_id, AutomaticSearchReason::Unmonitored);
+        return Err(ApiError::conflict(if album.monitored {
+            format!("Artist {} of album {album_id} is not monitored", artist.id)
+        } else {
+            format!("Album {album_id} is not monitored")
+        }));
+    }
+
+    let indexers = match searchable_indexers(&state).await {
+        Ok(indexers) => indexers,
+        Err(response) => return Ok(response),
+    };
+    let download_client = match resolve_grab_client(&state, None).await {
+        Ok(definition) => definition,
+        Err(response) => return Ok(response),
+    };
+    let Some(client) = download_client_for(&download_client, &state.outbound_http) else {
+        return Err(ApiError::bad_request(format!(
+            "download client type '{}' is not supported",
+            download_client.client_type
+        )));
+    };
+
+    let metadata_profile = match &artist.metadata_profile_id {
+        Some(id) => {
+            state
+                .metadata_profile_repository
+                .get_by_id(&id.to_string())
+                .await
+        }
+        None => state.metadata_profile_repository.get_default().await,
+    }
+    .context("failed to fetch metadata profile")?;
+    let mut options = match metadata_profile {
+        Some(profile) => ReleaseFilterOptions::from(&state.config.search)
+            .with_release_statuses(&profile.release_statuses),
+        None => ReleaseFilterOptions::from(&state.config.search),
+    };
+    options.quality_profile = match &artist.quality_profile_id {
+        Some(id) => {
+            state
+                .quality_profile_repository
+                .get_by_id(&id.to_string())
+                .await
+        }
+        None => state.quality_profile_repository.get_default().await,
+    }
+    .context("failed to fetch quality profile")?;
+
+    let tracks = collect_all(DEFAULT_PAGE_SIZE, |limit, offset| {
+        state.track_repository.get_by_album(album.id, limit, offset)
+    })
+    .await
+    .context("failed to list album tracks")?;
+    let mut track_files = Vec::new();
+    for track in tracks.iter().filter(|track| track.monitored) {
+        track_files.push(
+            collect_all(DEFAULT_PAGE_SIZE, |limit, offset| {
+                state
+                    .track_file_repository
+                    .get_by_track(track.id, limit, offset)
+            })
+            .await
+            .context("failed to list track files")?,
+        );
+    }
+    let downloads = activity_download_items(&state)
+        .await
+        .map_err(|error| anyhow!(error).context("failed to list downloads"))?;
+    let active_downloads = active_download_album_keys(downloads.iter().map(|item| &item.download));
+
+    let targets = [album_search_target(
+        &artist.name,
+        &album.title,
+        &track_files,
+        &active_downloads,
+        &options,
+    )];
+    let delay_profile = DelayProfile::from(&state.config.search);
+    let searched = indexers.len();
+    let outcomes = join_all(indexers.iter().map(|indexer| {
+        let search =
+            automatic_search_missing_albums(indexer.as_ref(), &targets, &options, &delay_profile);
+        async move { (indexer.config().name.clone(), search.await) }
+    }))
+    .await;
+
+    let mut decisions = Vec::new();
+    for (indexer, outcome) in outcomes {
+        match outcome {
+            Ok(found) => decisions.extend(found),
+            Err(error) => {
+                warn!(target: "api", %indexer, album_id = %album_id, %error, "automatic album search failed on indexer");
+            }
+        }
+    }
+    let Some(decision) = decisions.into_iter().max_by_key(decision_rank) else {
+        return Err(ApiError::bad_gateway(format!(
+            "all {searched} indexer searches failed"
+        )));
+    };
+
+    let reason = match decision.reason {
+        AutomaticSearchReason::Grabbed { release } => {
+            let download_url = release.search_result.download_url.as_deref().unwrap_or("");
+            match grab_release(
+                client.as_ref(),
+                &download_client,
+                download_url,
+                download_client.category.clone(),
+            )
+            .await
+            {
+                Ok(()) => {
+                    debug!(target: "api", album_id = %album_id, release = %release.search_result.title, download_client = %download_client.name, "grabbed release from automatic album search");
+                    AutomaticSearchReason::Grabbed { release }
+                }
+                Err(error) => AutomaticSearchReason::GrabFailed {
+                    release,
+                    error: error.to_string(),
+                },
+            }
+        }
+        other => other,
+    };
+
+    state.search_decision_store.record(&album_id, reason);
+    let status = state.search_decision_store.get(&album_id);
+    Ok((
+        StatusCode::OK,
+        Json(AlbumSearchStatusResponse::new(album_id, status)),
+    )
+        .into_response())
+}
+
+/// Order per-indexer decisions so the most actionable wins: a grab, then a release
+/// below the cutoff, then a deferred one, then nothing; ties go to the higher score.
+fn decision_rank(decision: &AutomaticSearchDecision) -> (u8, Option<i32>) {
+    let rank = match decision.reason {
+        AutomaticSearchReason::Grabbed { .. } => 3,
+        AutomaticSearchReason::BelowCutoff { .. } => 2,
+        AutomaticSearchReason::Deferred { .. } => 1,
+        _ => 0,
+    };
+    (rank, decision.reason.release().map(|release| release.score))
+}
+
 #[utoipa::path(
     post,
     path = "/api/v1/albums",
     request_body = CreateAlbumRequest,
     responses(
         (status = 201, description = "Album created", body = AlbumResponse),
-        (status = 400, description = "Invalid request", body = ErrorResponse),
-        (status = 404, description = "Artist not found", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 404, description = "Artist not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "albums"
 )]
@@ -426,23 +667,10 @@ pub async fn create_album(
     let artist = match state.artist_repository.get_by_id(&request.artist_id).await {
         Ok(Some(artist)) => artist,
         Ok(None) => {
-            return (
-                StatusCode::NOT_FOUND,
-                Json(ErrorResponse {
-                    error: format!("Artist {} not found", request.artist_id),
-                }),
-            )
-                .into_response()
-        }
-        Err(error) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse {
-                    error: format!("failed to fetch artist: {error}"),
-                }),
-            )
+            return ApiError::not_found(format!("Artist {} not found", request.artist_id))
                 .into_response()
         }
+        Err(error) => return ApiError::internal(error, "failed to fetch artist").into_response(),
     };
 
     let mut album = Album::new(artist.id, request.title);
@@ -465,14 +693,20 @@ pub async fn create_album(
     }
 
     match state.album_repository.create(album).await {
-        Ok(created) => (StatusCode::CREATED, Json(AlbumResponse::from(created))).into_response(),
-        Err(error) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: format!("failed to create album: {error}"),
-            }),
-        )
-            .into_response(),
+        Ok(created) => {
+            let event: AlbumCreated = correlate(DomainEvent::new(
+                "album.created",
+                AlbumCreatedPayload {
+                    album_id: created.id,
+                    artist_id: created.artist_id,
+                    title: created.title.clone(),
+                    monitored: created.monitored,
+                },
+            ));
+            state.event_dispatcher.dispatch(&event).await;
+            (StatusCode::CREATED, Json(AlbumResponse::from(created))).into_response()
+        }
+        Err(error) => ApiError::internal(error, "failed to create album").into_response(),
     }
 }
 
@@ -485,9 +719,10 @@ pub async fn create_album(
     request_body = UpdateAlbumRequest,
     responses(
         (status = 200, description = "Album updated", body = AlbumResponse),
-        (status = 404, description = "Album or artist not found", body = ErrorResponse),
-        (status = 400, description = "Invalid request", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 404, description = "Album or artist not found", body = ApiErrorResponse),
+        (status = 409, description = "Album was modified concurrently", body = ApiErrorResponse),
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "albums"
 )]
@@ -495,55 +730,27 @@ pub async fn update_album(
     State(state): State<AppState>,
     Path(id): Path<String>,
     Json(request): Json<UpdateAlbumRequest>,
-) -> impl IntoResponse {
+) -> Result<Json<AlbumResponse>, ApiError> {
     debug!(target: "api", %id, ?request, "updating album");
 
-    let mut album = match state.album_repository.get_by_id(&id).await {
-        Ok(Some(album)) => album,
-        Ok(None) => {
-            return (
-                StatusCode::NOT_FOUND,
-                Json(ErrorResponse {
-                    error: format!("Album {} not found", id),
-                }),
-            )
-                .into_response()
-        }
-        Err(error) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse {
-                    error: format!("failed to fetch album: {error}"),
-                }),
-            )
-                .into_response()
-        }
-    };
+    let mut album = state
+        .album_repository
+        .get_by_id(&id)
+        .await
+        .context("failed to fetch album")?
+        .ok_or_else(|| ApiError::not_found(format!("Album {} not found", id)))?;
 
+    if let Some(version) = request.updated_at.as_deref() {
+        album.updated_at = parse_expected_version(version)?;
+    }
     if let Some(artist_id) = request.artist_id {
-        match state.artist_repository.get_by_id(&artist_id).await {
-            Ok(Some(artist)) => {
-                album.artist_id = artist.id;
-            }
-            Ok(None) => {
-                return (
-                    StatusCode::NOT_FOUND,
-                    Json(ErrorResponse {
-                        error: format!("Artist {} not found", artist_id),
-                    }),
-                )
-                    .into_response()
-            }
-            Err(error) => {
-                return (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(ErrorResponse {
-                        error: format!("failed to fetch artist: {error}"),
-                    }),
-                )
-                    .into_response()
-            }
-        }
+        let artist = state
+            .artist_repository
+            .get_by_id(&artist_id)
+            .await
+            .context("failed to fetch artist")?
+            .ok_or_else(|| ApiError::not_found(format!("Artist {} not found", artist_id)))?;
+        album.artist_id = artist.id;
     }
 
     if let Some(title) = request.title {
@@ -559,27 +766,125 @@ pub async fn update_album(
         album.monitored = monitored;
     }
     if let Some(status_str) = request.status {
-        match parse_album_status(&status_str) {
-            Ok(status) => album.status = status,
-            Err(err_response) => return err_response.into_response(),
-        }
+        album.status = parse_album_status(&status_str)?;
     }
     if let Some(date_str) = request.release_date {
-        match parse_release_date(&date_str) {
-            Ok(date) => album.release_date = Some(date),
-            Err(err_response) => return err_response.into_response(),
+        album.release_date = Some(parse_release_date(&date_str)?);
+    }
+
+    let updated = state
+        .album_repository
+        .update(album)
+        .await
+        .context("failed to update album")?;
+    state
+        .event_dispatcher
+        .dispatch(&album_updated_event(&updated))
+        .await;
+    Ok(Json(AlbumResponse::from(updated)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/albums/{id}/monitored",
+    params(
+        ("id" = String, Path, description = "Album ID")
+    ),
+    request_body = SetAlbumMonitoredRequest,
+    responses(
+        (status = 200, description = "Album monitoring updated", body = AlbumResponse),
+        (status = 404, description = "Album not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
+    ),
+    tag = "albums"
+)]
+pub async fn set_album_monitored(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(request): Json<SetAlbumMonitoredRequest>,
+) -> impl IntoResponse {
+    debug!(target: "api", %id, monitored = request.monitored, "setting album monitored");
+
+    let not_found = || ApiError::not_found(format!("Album {} not found", id)).into_response();
+    let Ok(uuid) = Uuid::parse_str(&id) else {
+        return not_found();
+    };
+
+    match state
+        .album_repository
+        .set_monitored(&[AlbumId::from_uuid(uuid)], request.monitored)
+        .await
+    {
+        Ok(0) => return not_found(),
+        Ok(_) => {}
+        Err(error) => return ApiError::internal(error, "failed to update album").into_response(),
+    }
+
+    match state.album_repository.get_by_id(&id).await {
+        Ok(Some(album)) => {
+            state
+                .event_dispatcher
+                .dispatch(&album_updated_event(&album))
+                .await;
+            (StatusCode::OK, Json(AlbumResponse::from(album))).into_response()
+        }
+        Ok(None) => not_found(),
+        Err(error) => ApiError::internal(error, "failed to fetch album").into_response(),
+    }
+}
+
+fn album_updated_event(album: &Album) -> AlbumUpdated {
+    correlate(DomainEvent::new(
+        "album.updated",
+        AlbumUpdatedPayload {
+            album_id: album.id,
+            artist_id: album.artist_id,
+            title: album.title.clone(),
+            monitored: album.monitored,
+        },
+    ))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/albums/monitored",
+    request_body = SetAlbumsMonitoredRequest,
+    responses(
+        (status = 200, description = "Albums monitoring updated", body = SetAlbumsMonitoredResponse),
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
+    ),
+    tag = "albums"
+)]
+pub async fn set_albums_monitored(
+    State(state): State<AppState>,
+    Json(request): Json<SetAlbumsMonitoredRequest>,
+) -> impl IntoResponse {
+    debug!(target: "api", count = request.album_ids.len(), monitored = request.monitored, "setting albums monitored");
+
+    if request.album_ids.is_empty() {
+        return ApiError::bad_request("album_ids must not be empty").into_response();
+    }
+
+    let mut ids = Vec::with_capacity(request.album_ids.len());
+    for id in &request.album_ids {
+        match Uuid::parse_str(id) {
+            Ok(uuid) => ids.push(AlbumId::from_uuid(uuid)),
+            Err(_) => {
+                return ApiError::bad_request(format!("invalid album id: {id}")).into_response()
+            }
         }
     }
 
-    match state.album_repository.update(album).await {
-        Ok(updated) => (StatusCode::OK, Json(AlbumResponse::from(updated))).into_response(),
-        Err(error) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: format!("failed to update album: {error}"),
-            }),
-        )
-            .into_response(),
+    match state
+        .album_repository
+        .set_monitored(&ids, request.monitored)
+        .await
+    {
+        Ok(updated) => {
+            (StatusCode::OK, Json(SetAlbumsMonitoredResponse { updated })).into_response()
+        }
+        Err(error) => ApiError::internal(error, "failed to update albums").into_response(),
     }
 }
 
@@ -587,75 +892,127 @@ pub async fn update_album(
     delete,
     path = "/api/v1/albums/{id}",
     params(
-        ("id" = String, Path, description = "Album ID")
+        ("id" = String, Path, description = "Album ID"),
+        DeleteAlbumQuery
     ),
     responses(
+        (status = 200, description = "Album and its files deleted", body = DeleteAlbumResponse),
         (status = 204, description = "Album deleted"),
-        (status = 404, description = "Album not found", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 400, description = "Files requested but no library root contains the artist path", body = ApiErrorResponse),
+        (status = 404, description = "Album not found", body = ApiErrorResponse),
+        (status = 409, description = "Some files could not be deleted; the album was kept", body = DeleteAlbumResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "albums"
 )]
 pub async fn delete_album(
     State(state): State<AppState>,
     Path(id): Path<String>,
+    Query(query): Query<DeleteAlbumQuery>,
 ) -> impl IntoResponse {
-    debug!(target: "api", %id, "deleting album");
+    debug!(target: "api", %id, delete_files = query.delete_files, "deleting album");
 
     match state.album_repository.get_by_id(&id).await {
-        Ok(Some(_)) => {
+        Ok(Some(album)) => {
+            let files = if query.delete_files {
+                match delete_files_of(&state, &album).await {
+                    Ok(summary) => Some(summary),
+                    Err(error) => return error.into_response(),
+                }
+            } else {
+                None
+            };
+            if let Some(summary) = &files {
+                if !summary.failed.is_empty() {
+                    // Keep the album so the files that are still on disk stay tracked.
+                    return (
+                        StatusCode::CONFLICT,
+                        Json(delete_album_response(summary.clone())),
+                    )
+                        .into_response();
+                }
+            }
             match state.album_repository.delete(&id).await {
-                Ok(_) => StatusCode::NO_CONTENT.into_response(),
+                Ok(_) => match files {
+                    Some(summary) => {
+                        (StatusCode::OK, Json(delete_album_response(summary))).into_response()
+                    }
+                    None => StatusCode::NO_CONTENT.into_response(),
+                },
                 Err(delete_error) => {
                     // Check if the album was concurrently deleted before we could.
                     match state.album_repository.get_by_id(&id).await {
-                        Ok(None) => (
-                            StatusCode::NOT_FOUND,
-                            Json(ErrorResponse {
-                                error: format!("Album {} not found", id),
-                            }),
-                        )
-                            .into_response(),
-                        Ok(Some(_)) => (
-                            StatusCode::INTERNAL_SERVER_ERROR,
-                            Json(ErrorResponse {
-                                error: format!("failed to delete album: {delete_error}"),
-                            }),
-                        )
+                        Ok(None) => {
+                            ApiError::not_found(format!("Album {} not found", id)).into_response()
+                        }
+                        Ok(Some(_)) => ApiError::internal(delete_error, "failed to delete album")
                             .into_response(),
-                        Err(_) => (
-                            StatusCode::INTERNAL_SERVER_ERROR,
-                            Json(ErrorResponse {
-                                error: format!("failed to delete album: {delete_error}"),
-                            }),
-                        )
+                        Err(_) => ApiError::internal(delete_error, "failed to delete album")
                             .into_response(),
                     }
                 }
             }
         }
-        Ok(None) => (
-            StatusCode::NOT_FOUND,
-            Json(ErrorResponse {
-                error: format!("Album {} not found", id),
-            }),
-        )
-            .into_response(),
-        Err(error) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: format!("failed to fetch album before delete: {error}"),
-            }),
-        )
-            .into_response(),
+        Ok(None) => ApiError::not_found(format!("Album {} not found", id)).into_response(),
+        Err(error) => {
+            ApiError::internal(error, "failed to fetch album before delete").into_response()
+        }
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+fn delete_album_response(summary: AlbumFileDeletionSummary) -> DeleteAlbumResponse {
+    DeleteAlbumResponse {
+        files_deleted: summary.files_deleted,
+        failed_files: summary
+            .failed
+            .into_iter()
+            .map(|failed| FailedFileDeletionResponse {
+                path: failed.path,
+                reason: failed.reason,
+            })
+            .collect(),
+    }
+}
 
-    // ============================================================================
+/// Remove `album`'s files from disk, confined to its artist's path.
+///
+/// The artist path must lie inside a configured library root; without one no
+/// file is touched and the request is rejected.
+async fn delete_files_of(
+    state: &AppState,
+    album: &Album,
+) -> Result<AlbumFileDeletionSummary, ApiError> {
+    let artist = state
+        .artist_repository
+        .get_by_id(&album.artist_id.to_string())
+        .await
+        .map_err(|error| ApiError::internal(error, "failed to fetch album artist"))?;
+    let Some(artist_path) = artist.and_then(|artist| artist.path).map(PathBuf::from) else {
+        return Err(ApiError::bad_request(
+            "cannot delete files: the album's artist has no path",
+        ));
+    };
+    let artist_root = ensure_within_any_root(&state.config.library.root_paths, &artist_path)
+        .map_err(|error| {
+            ApiError::bad_request(format!(
+                "cannot delete files: artist path is not inside a configured library root ({error})"
+            ))
+        })?;
+    delete_album_files(
+        &*state.track_repository,
+        &*state.track_file_repository,
+        album.id,
+        Some(&artist_root),
+    )
+    .await
+    .map_err(|error| ApiError::internal(error, "failed to delete album files"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ============================================================================
     // parse_album_status tests
     // ============================================================================
 
@@ -703,7 +1060,7 @@ mod tests {
     fn parse_status_rejects_unknown_value() {
         let result = parse_album_status("unknown");
         assert!(result.is_err());
-        let (status, _) = result.unwrap_err();
+        let status = result.unwrap_err().status();
         assert_eq!(status, StatusCode::BAD_REQUEST);
     }
 
@@ -711,7 +1068,7 @@ mod tests {
     fn parse_status_rejects_empty_string() {
         let result = parse_album_status("");
         assert!(result.is_err());
-        let (status, _) = result.unwrap_err();
+        let status = result.unwrap_err().status();
         assert_eq!(status, StatusCode::BAD_REQUEST);
     }
 
@@ -765,6 +1122,11 @@ mod tests {
                         pool.clone(),
                     ),
                 ),
+                Arc::new(
+                    chorrosion_infrastructure::sqlite_adapters::SqliteTrackFileRepository::new(
+                        pool.clone(),
+                    ),
+                ),
                 chorrosion_infrastructure::ResponseCache::new(100, 60),
             )
         }
@@ -828,7 +1190,7 @@ mod tests {
             )
             .await;
             assert!(result.is_err());
-            let (status, _) = result.unwrap_err();
+            let status = result.unwrap_err().status();
             assert_eq!(status, StatusCode::NOT_FOUND);
         }
 
@@ -845,7 +1207,7 @@ mod tests {
             )
             .await;
             assert!(result.is_err());
-            let (status, _) = result.unwrap_err();
+            let status = result.unwrap_err().status();
             assert_eq!(status, StatusCode::NOT_FOUND);
         }
 
@@ -879,6 +1241,344 @@ mod tests {
             assert_eq!(response.status(), StatusCode::NOT_FOUND);
         }
 
+        // --- get_album_search_status ---
+
+        async fn search_status_json(
+            state: AppState,
+            id: String,
+        ) -> (StatusCode, serde_json::Value) {
+            let response = get_album_search_status(State(state), Path(id))
+                .await
+                .into_response();
+            let status = response.status();
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .expect("body should be readable");
+            (
+                status,
+                serde_json::from_slice(&body).expect("body should be valid JSON"),
+            )
+        }
+
+        #[tokio::test]
+        async fn get_album_search_status_reports_not_searched_without_decision() {
+            let state = make_test_state().await;
+            let artist = create_test_artist(&state).await;
+            let album = state
+                .album_repository
+                .create(Album::new(artist.id, "Never Searched"))
+                .await
+                .unwrap();
+
+            let (status, body) = search_status_json(state, album.id.to_string()).await;
+            assert_eq!(status, StatusCode::OK);
+            assert_eq!(body["reason"], "not_searched");
+            assert!(body["decided_at"].is_null());
+        }
+
+        #[tokio::test]
+        async fn get_album_search_status_reports_last_decision() {
+            use chorrosion_application::{parse_release_title, IndexerSearchResult, RankedRelease};
+
+            let state = make_test_state().await;
+            let artist = create_test_artist(&state).await;
+            let album = state
+                .album_repository
+                .create(Album::new(artist.id, "Below Cutoff"))
+                .await
+                .unwrap();
+            let title = "Test Artist - Below Cutoff MP3 128";
+            state.search_decision_store.record(
+                album.id.to_string(),
+                AutomaticSearchReason::BelowCutoff {
+                    release: RankedRelease {
+                        parsed: parse_release_title(title),
+                        search_result: IndexerSearchResult {
+                            title: title.to_string(),
+                            guid: None,
+                            download_url: Some("magnet:?xt=urn:btih:low".to_string()),
+                            published_at: None,
+                            size_bytes: None,
+                            seeders: None,
+                            leechers: None,
+                        },
+                        score: 132,
+                    },
+                    cutoff_score: 200,
+                },
+            );
+
+            let (status, body) = search_status_json(state, album.id.to_string()).await;
+            assert_eq!(status, StatusCode::OK);
+            assert_eq!(body["reason"], "below_cutoff");
+            assert_eq!(body["release_title"], title);
+            assert_eq!(body["release_score"], 132);
+            assert_eq!(body["cutoff_score"], 200);
+            assert!(body["decided_at"].is_string());
+        }
+
+        #[tokio::test]
+        async fn get_album_search_status_returns_404_for_unknown_album() {
+            let state = make_test_state().await;
+            let (status, _) =
+                search_status_json(state, "00000000-0000-0000-0000-000000000000".to_string()).await;
+            assert_eq!(status, StatusCode::NOT_FOUND);
+        }
+
+        // --- auto_search_album ---
+
+        async fn auto_search_json(state: AppState, id: String) -> (StatusCode, serde_json::Value) {
+            let response = auto_search_album(State(state), Path(id))
+                .await
+                .into_response();
+            let status = response.status();
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .expect("body should be readable");
+            (
+                status,
+                serde_json::from_slice(&body).expect("body should be valid JSON"),
+            )
+        }
+
+        /// A Newznab indexer returning `items` as `(title, download URL)` pairs and a
+        /// qBittorrent client expected to receive `expected_grabs` additions.
+        async fn mock_search_services(
+            state: &AppState,
+            items: &[(&str, &str)],
+            expected_grabs: u64,
+        ) -> (wiremock::MockServer, wiremock::MockServer) {
+            use chorrosion_domain::{DownloadClientDefinition, IndexerDefinition};
+            use wiremock::matchers::{method, path, query_param};
+
+            let items: String = items
+                .iter()
+                .map(|(title, url)| {
+                    format!(
+                        r#"<item><title>{title}</title><guid>{url}</guid><enclosure url="{url}" length="1000" type="application/x-bittorrent" /></item>"#
+                    )
+                })
+                .collect();
+            let indexer = wiremock::MockServer::start().await;
+            wiremock::Mock::given(method("GET"))
+                .and(path("/api"))
+                .and(query_param("t", "search"))
+                .respond_with(
+                    wiremock::ResponseTemplate::new(200)
+                        .set_body_string(format!("<rss><channel>{items}</channel></rss>")),
+                )
+                .mount(&indexer)
+                .await;
+            state
+                .indexer_definition_repository
+                .create(IndexerDefinition::new("mock", indexer.uri(), "newznab"))
+                .await
+                .expect("create indexer");
+
+            let download_client = wiremock::MockServer::start().await;
+            wiremock::Mock::given(method("POST"))
+                .and(path("/api/v2/torrents/add"))
+                .respond_with(wiremock::ResponseTemplate::new(200).set_body_string("Ok."))
+                .expect(expected_grabs)
+                .mount(&download_client)
+                .await;
+            state
+                .download_client_definition_repository
+                .create(DownloadClientDefinition::new(
+                    "qbit",
+                    "qbittorrent",
+                    download_client.uri(),
+                ))
+                .await
+                .expect("create download client");
+
+            (indexer, download_client)
+        }
+
+        #[tokio::test]
+        async fn auto_search_album_grabs_best_release() {
+            let state = make_test_state().await;
+            let artist = create_test_artist(&state).await;
+            let album = state
+                .album_repository
+                .create(Album::new(artist.id, "Grab Me"))
+                .await
+                .unwrap();
+            let (_indexer, download_client) = mock_search_services(
+                &state,
+                &[
+                    (
+                        "Test Artist - Grab Me 128kbps MP3-LOW",
+                        "magnet:?xt=urn:btih:low",
+                    ),
+                    (
+                        "Test Artist - Grab Me [FLAC]-HQ",
+                        "magnet:?xt=urn:btih:flac",
+                    ),
+                ],
+                1,
+            )
+            .await;
+
+            let (status, body) = auto_search_json(state.clone(), album.id.to_string()).await;
+            assert_eq!(status, StatusCode::OK);
+            assert_eq!(body["reason"], "grabbed");
+            assert_eq!(body["release_title"], "Test Artist - Grab Me [FLAC]-HQ");
+            assert_eq!(body["download_url"], "magnet:?xt=urn:btih:flac");
+            let recorded = state
+                .search_decision_store
+                .get(&album.id.to_string())
+                .expect("decision recorded");
+            assert_eq!(recorded.reason.as_str(), "grabbed");
+            download_client.verify().await;
+        }
+
+        #[tokio::test]
+        async fn auto_search_album_skips_album_whose_tracks_all_have_files() {
+            let state = make_test_state().await;
+            let artist = create_test_artist(&state).await;
+            let album = state
+                .album_repository
+                .create(Album::new(artist.id, "Owned"))
+                .await
+                .unwrap();
+            let track = state
+                .track_repository
+                .create(chorrosion_domain::Track::new(
+                    album.id,
+                    artist.id,
+                    "Only Track",
+                ))
+                .await
+                .unwrap();
+            state
+                .track_file_repository
+                .create(chorrosion_domain::TrackFile::new(
+                    track.id,
+                    "/music/Owned/01.flac",
+                    1_000,
+                ))
+                .await
+                .unwrap();
+            let (_indexer, download_client) = mock_search_services(
+                &state,
+                &[("Test Artist - Owned [FLAC]-HQ", "magnet:?xt=urn:btih:flac")],
+                0,
+            )
+            .await;
+
+            let (status, body) = auto_search_json(state, album.id.to_string()).await;
+            assert_eq!(status, StatusCode::OK);
+            assert_eq!(body["reason"], "already_owned");
+            download_client.verify().await;
+        }
+
+        #[tokio::test]
+        async fn auto_search_album_records_grab_failure_for_release_without_download_url() {
+            let state = make_test_state().await;
+            let artist = create_test_artist(&state).await;
+            let album = state
+                .album_repository
+                .create(Album::new(artist.id, "No Link"))
+                .await
+                .unwrap();
+            let (_indexer, download_client) =
+                mock_search_services(&state, &[("Test Artist - No Link [FLAC]-HQ", "")], 0).await;
+
+            let (status, body) = auto_search_json(state, album.id.to_string()).await;
+            assert_eq!(status, StatusCode::OK);
+            assert_eq!(body["reason"], "grab_failed");
+            download_client.verify().await;
+        }
+
+        #[tokio::test]
+        async fn auto_search_album_rejects_unofficial_releases_for_official_only_profile() {
+            let state = make_test_state().await;
+            let mut profile = chorrosion_domain::MetadataProfile::new("Official Only");
+            profile.release_statuses = vec!["Official".to_string()];
+            let profile = state
+                .metadata_profile_repository
+                .create(profile)
+                .await
+                .unwrap();
+            let mut artist = chorrosion_domain::Artist::new("Test Artist");
+            artist.metadata_profile_id = Some(profile.id);
+            let artist = state.artist_repository.create(artist).await.unwrap();
+            let album = state
+                .album_repository
+                .create(Album::new(artist.id, "Live Forever"))
+                .await
+                .unwrap();
+            let (_indexer, download_client) = mock_search_services(
+                &state,
+                &[(
+                    "Test Artist - Live Forever (Bootleg) [FLAC]-GRP",
+                    "magnet:?xt=urn:btih:bootleg",
+                )],
+                0,
+            )
+            .await;
+
+            let (status, body) = auto_search_json(state, album.id.to_string()).await;
+            assert_eq!(status, StatusCode::OK);
+            assert_eq!(body["reason"], "no_results");
+            download_client.verify().await;
+        }
+
+        #[tokio::test]
+        async fn auto_search_album_reports_no_results_without_grabbing() {
+            let state = make_test_state().await;
+            let artist = create_test_artist(&state).await;
+            let album = state
+                .album_repository
+                .create(Album::new(artist.id, "Nowhere To Be Found"))
+                .await
+                .unwrap();
+            let (_indexer, download_client) = mock_search_services(&state, &[], 0).await;
+
+            let (status, body) = auto_search_json(state, album.id.to_string()).await;
+            assert_eq!(status, StatusCode::OK);
+            assert_eq!(body["reason"], "no_results");
+            assert!(body["release_title"].is_null());
+            download_client.verify().await;
+        }
+
+        #[tokio::test]
+        async fn auto_search_album_refuses_unmonitored_album() {
+            let state = make_test_state().await;
+            let artist = create_test_artist(&state).await;
+            let mut album = Album::new(artist.id, "Ignored");
+            album.monitored = false;
+            let album = state.album_repository.create(album).await.unwrap();
+            let (_indexer, download_client) = mock_search_services(
+                &state,
+                &[(
+                    "Test Artist - Ignored [FLAC]-HQ",
+                    "magnet:?xt=urn:btih:flac",
+                )],
+                0,
+            )
+            .await;
+
+            let (status, body) = auto_search_json(state.clone(), album.id.to_string()).await;
+            assert_eq!(status, StatusCode::CONFLICT);
+            assert!(body["error"].as_str().unwrap().contains("not monitored"));
+            let recorded = state
+                .search_decision_store
+                .get(&album.id.to_string())
+                .expect("decision recorded");
+            assert_eq!(recorded.reason, AutomaticSearchReason::Unmonitored);
+            download_client.verify().await;
+        }
+
+        #[tokio::test]
+        async fn auto_search_album_returns_404_for_unknown_album() {
+            let state = make_test_state().await;
+            let (status, _) =
+                auto_search_json(state, "00000000-0000-0000-0000-000000000000".to_string()).await;
+            assert_eq!(status, StatusCode::NOT_FOUND);
+        }
+
         // --- create_album ---
 
         #[tokio::test]
@@ -900,6 +1600,66 @@ mod tests {
             assert_eq!(response.status(), StatusCode::CREATED);
         }
 
+        #[tokio::test]
+        async fn create_album_publishes_event_correlated_with_the_request() {
+            use crate::middleware::request_id::{request_id_middleware, REQUEST_ID_HEADER};
+            use tower::ServiceExt;
+
+            let hub = Arc::new(chorrosion_realtime::RecordingHub::default());
+            let state = make_test_state()
+                .await
+                .with_event_dispatcher(chorrosion_application::EventDispatcher::new(hub.clone()));
+            let artist = create_test_artist(&state).await;
+            let app = axum::Router::new()
+                .route("/albums", axum::routing::post(create_album))
+                .layer(axum::middleware::from_fn(request_id_middleware))
+                .with_state(state);
+            let request_id = Uuid::new_v4();
+            let body =
+                serde_json::json!({ "artist_id": artist.id.to_string(), "title": "Correlated" });
+            let request = axum::http::Request::post("/albums")
+                .header(&REQUEST_ID_HEADER, request_id.to_string())
+                .header("content-type", "application/json")
+                .body(axum::body::Body::from(body.to_string()))
+                .unwrap();
+
+            let response = app.oneshot(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::CREATED);
+
+            let messages = hub.messages();
+            assert_eq!(messages.len(), 1);
+            let chorrosion_realtime::RealtimeMessage { channel, payload } = &messages[0];
+            assert_eq!(channel, "albums");
+            let value: serde_json::Value = serde_json::from_str(payload).expect("valid JSON");
+            assert_eq!(value["name"], "album.created");
+            assert_eq!(value["payload"]["title"], "Correlated");
+            assert_eq!(value["correlation_id"], request_id.to_string());
+        }
+
+        #[tokio::test]
+        async fn create_album_keeps_partial_release_date() {
+            let state = make_test_state().await;
+            let artist = create_test_artist(&state).await;
+            let request = CreateAlbumRequest {
+                artist_id: artist.id.to_string(),
+                title: "Test Album".to_string(),
+                foreign_album_id: None,
+                release_date: Some("1997-06".to_string()),
+                album_type: None,
+                status: None,
+                monitored: None,
+            };
+            let response = create_album(State(state), Json(request))
+                .await
+                .into_response();
+            assert_eq!(response.status(), StatusCode::CREATED);
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let album: AlbumResponse = serde_json::from_slice(&body).unwrap();
+            assert_eq!(album.release_date.as_deref(), Some("1997-06"));
+        }
+
         #[tokio::test]
         async fn create_album_returns_404_for_unknown_artist() {
             let state = make_test_state().await;
@@ -1002,6 +1762,7 @@ mod tests {
                 album_type: None,
                 status: None,
                 monitored: None,
+                updated_at: None,
             };
             let response = update_album(State(state), Path(album.id.to_string()), Json(request))
                 .await
@@ -1009,6 +1770,54 @@ mod tests {
             assert_eq!(response.status(), StatusCode::OK);
         }
 
+        #[tokio::test]
+        async fn update_album_returns_409_when_the_client_version_is_stale() {
+            let state = make_test_state().await;
+            let artist = create_test_artist(&state).await;
+            let album = state
+                .album_repository
+                .create(Album::new(artist.id, "Shared"))
+                .await
+                .unwrap();
+            let id = album.id.to_string();
+            let read_version = AlbumResponse::from(album).updated_at;
+
+            let retitle = |title: &str| UpdateAlbumRequest {
+                artist_id: None,
+                title: Some(title.to_string()),
+                foreign_album_id: None,
+                release_date: None,
+                album_type: None,
+                status: None,
+                monitored: None,
+                updated_at: Some(read_version.clone()),
+            };
+            let first = update_album(
+                State(state.clone()),
+                Path(id.clone()),
+                Json(retitle("First")),
+            )
+            .await
+            .into_response();
+            assert_eq!(first.status(), StatusCode::OK);
+
+            let second = update_album(
+                State(state.clone()),
+                Path(id.clone()),
+                Json(retitle("Second")),
+            )
+            .await
+            .into_response();
+            assert_eq!(second.status(), StatusCode::CONFLICT);
+            let stored = state
+                .album_repository
+                .get_by_id(&id)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(stored.title, "First");
+        }
+
         #[tokio::test]
         async fn update_album_returns_404_for_unknown_id() {
             let state = make_test_state().await;
@@ -1020,6 +1829,7 @@ mod tests {
                 album_type: None,
                 status: None,
                 monitored: None,
+                updated_at: None,
             };
             let unknown_id = "00000000-0000-0000-0000-000000000000".to_string();
             let response = update_album(State(state), Path(unknown_id), Json(request))
@@ -1045,6 +1855,7 @@ mod tests {
                 album_type: None,
                 status: None,
                 monitored: None,
+                updated_at: None,
             };
             let response = update_album(State(state), Path(album.id.to_string()), Json(request))
                 .await
@@ -1052,6 +1863,134 @@ mod tests {
             assert_eq!(response.status(), StatusCode::NOT_FOUND);
         }
 
+        // --- set_album_monitored / set_albums_monitored ---
+
+        /// The stored album as JSON, minus the columns a monitoring toggle may change.
+        async fn album_without_monitoring(state: &AppState, id: AlbumId) -> serde_json::Value {
+            let album = state
+                .album_repository
+                .get_by_id(&id.to_string())
+                .await
+                .unwrap()
+                .expect("album exists");
+            let mut value = serde_json::to_value(album).unwrap();
+            let object = value.as_object_mut().unwrap();
+            object.remove("monitored");
+            object.remove("updated_at");
+            value
+        }
+
+        #[tokio::test]
+        async fn set_album_monitored_changes_only_monitoring() {
+            let state = make_test_state().await;
+            let artist = create_test_artist(&state).await;
+            let mut album = Album::new(artist.id, "Dummy");
+            album.album_type = Some("Album".to_string());
+            album.foreign_album_id = Some("mb-dummy".to_string());
+            let album = state.album_repository.create(album).await.unwrap();
+            let before = album_without_monitoring(&state, album.id).await;
+
+            let response = set_album_monitored(
+                State(state.clone()),
+                Path(album.id.to_string()),
+                Json(SetAlbumMonitoredRequest { monitored: false }),
+            )
+            .await
+            .into_response();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let stored = state
+                .album_repository
+                .get_by_id(&album.id.to_string())
+                .await
+                .unwrap()
+                .unwrap();
+            assert!(!stored.monitored);
+            assert!(stored.updated_at >= album.updated_at);
+            assert_eq!(album_without_monitoring(&state, album.id).await, before);
+        }
+
+        #[tokio::test]
+        async fn set_album_monitored_returns_404_for_unknown_id() {
+            let state = make_test_state().await;
+            let response = set_album_monitored(
+                State(state),
+                Path("00000000-0000-0000-0000-000000000000".to_string()),
+                Json(SetAlbumMonitoredRequest { monitored: false }),
+            )
+            .await
+            .into_response();
+            assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        }
+
+        #[tokio::test]
+        async fn set_albums_monitored_toggles_only_listed_albums() {
+            let state = make_test_state().await;
+            let artist = create_test_artist(&state).await;
+            let mut albums = Vec::new();
+            for title in ["One", "Two", "Three"] {
+                albums.push(
+                    state
+                        .album_repository
+                        .create(Album::new(artist.id, title))
+                        .await
+                        .unwrap(),
+                );
+            }
+            let before: Vec<_> = futures_util::future::join_all(
+                albums
+                    .iter()
+                    .map(|album| album_without_monitoring(&state, album.id)),
+            )
+            .await;
+
+            let response = set_albums_monitored(
+                State(state.clone()),
+                Json(SetAlbumsMonitoredRequest {
+                    album_ids: vec![albums[0].id.to_string(), albums[1].id.to_string()],
+                    monitored: false,
+                }),
+            )
+            .await
+            .into_response();
+            assert_eq!(response.status(), StatusCode::OK);
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let body: SetAlbumsMonitoredResponse = serde_json::from_slice(&body).unwrap();
+            assert_eq!(body.updated, 2);
+
+            for (album, expected_monitored) in albums.iter().zip([false, false, true]) {
+                let stored = state
+                    .album_repository
+                    .get_by_id(&album.id.to_string())
+                    .await
+                    .unwrap()
+                    .unwrap();
+                assert_eq!(stored.monitored, expected_monitored, "{}", album.title);
+            }
+            for (album, before) in albums.iter().zip(before) {
+                assert_eq!(album_without_monitoring(&state, album.id).await, before);
+            }
+        }
+
+        #[tokio::test]
+        async fn set_albums_monitored_rejects_empty_and_malformed_ids() {
+            let state = make_test_state().await;
+            for album_ids in [vec![], vec!["not-a-uuid".to_string()]] {
+                let response = set_albums_monitored(
+                    State(state.clone()),
+                    Json(SetAlbumsMonitoredRequest {
+                        album_ids,
+                        monitored: true,
+                    }),
+                )
+                .await
+                .into_response();
+                assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+            }
+        }
+
         // --- delete_album ---
 
         #[tokio::test]
@@ -1063,9 +2002,13 @@ mod tests {
                 .create(Album::new(artist.id, "To Delete"))
                 .await
                 .unwrap();
-            let response = delete_album(State(state), Path(album.id.to_string()))
-                .await
-                .into_response();
+            let response = delete_album(
+                State(state),
+                Path(album.id.to_string()),
+                Query(DeleteAlbumQuery::default()),
+            )
+            .await
+            .into_response();
             assert_eq!(response.status(), StatusCode::NO_CONTENT);
         }
 
@@ -1073,12 +2016,173 @@ mod tests {
         async fn delete_album_returns_404_for_unknown_id() {
             let state = make_test_state().await;
             let unknown_id = "00000000-0000-0000-0000-000000000000".to_string();
-            let response = delete_album(State(state), Path(unknown_id))
-                .await
-                .into_response();
+            let response = delete_album(
+                State(state),
+                Path(unknown_id),
+                Query(DeleteAlbumQuery::default()),
+            )
+            .await
+            .into_response();
             assert_eq!(response.status(), StatusCode::NOT_FOUND);
         }
 
+        /// Artist rooted at `library` with one album whose single track has a file on disk.
+        async fn album_with_file_on_disk(
+            state: &AppState,
+            library: &std::path::Path,
+        ) -> (Album, std::path::PathBuf) {
+            use chorrosion_domain::{Track, TrackFile};
+            let mut artist = Artist::new("Rooted Artist");
+            artist.path = Some(library.to_string_lossy().into_owned());
+            let artist = state.artist_repository.create(artist).await.unwrap();
+            let album = state
+                .album_repository
+                .create(Album::new(artist.id, "On Disk"))
+                .await
+                .unwrap();
+            let track = state
+                .track_repository
+                .create(Track::new(album.id, artist.id, "Only Track"))
+                .await
+                .unwrap();
+            let file = library.join("01 Only Track.flac");
+            std::fs::write(&file, b"flac").unwrap();
+            state
+                .track_file_repository
+                .create(TrackFile::new(track.id, file.to_string_lossy(), 4))
+                .await
+                .unwrap();
+            (album, file)
+        }
+
+        #[tokio::test]
+        async fn delete_album_with_delete_files_removes_files_from_disk() {
+            let mut state = make_test_state().await;
+            let library = tempfile::tempdir().unwrap();
+            state.config.library.root_paths = vec![library.path().to_path_buf()];
+            let (album, file) = album_with_file_on_disk(&state, library.path()).await;
+
+            let response = delete_album(
+                State(state.clone()),
+                Path(album.id.to_string()),
+                Query(DeleteAlbumQuery { delete_files: true }),
+            )
+            .await
+            .into_response();
+
+            assert_eq!(response.status(), StatusCode::OK);
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let body: DeleteAlbumResponse = serde_json::from_slice(&body).unwrap();
+            assert_eq!(body.files_deleted, 1);
+            assert!(body.failed_files.is_empty());
+            assert!(!file.exists());
+            assert!(state
+                .album_repository
+                .get_by_id(&album.id.to_string())
+                .await
+                .unwrap()
+                .is_none());
+        }
+
+        #[tokio::test]
+        async fn delete_album_without_delete_files_leaves_files_on_disk() {
+            let state = make_test_state().await;
+            let library = tempfile::tempdir().unwrap();
+            let (album, file) = album_with_file_on_disk(&state, library.path()).await;
+
+            let response = delete_album(
+                State(state),
+                Path(album.id.to_string()),
+                Query(DeleteAlbumQuery::default()),
+            )
+            .await
+            .into_response();
+
+            assert_eq!(response.status(), StatusCode::NO_CONTENT);
+            assert!(file.exists());
+        }
+
+        async fn assert_album_kept(state: &AppState, album: &Album) {
+            assert!(state
+                .album_repository
+                .get_by_id(&album.id.to_string())
+                .await
+                .unwrap()
+                .is_some());
+            assert_eq!(
+                state.track_file_repository.list(10, 0).await.unwrap().len(),
+                1
+            );
+        }
+
+        #[tokio::test]
+        async fn delete_album_files_requires_artist_path_inside_library_roots() {
+            let mut state = make_test_state().await;
+            let library = tempfile::tempdir().unwrap();
+            let elsewhere = tempfile::tempdir().unwrap();
+            state.config.library.root_paths = vec![library.path().to_path_buf()];
+            let (album, file) = album_with_file_on_disk(&state, elsewhere.path()).await;
+
+            let response = delete_album(
+                State(state.clone()),
+                Path(album.id.to_string()),
+                Query(DeleteAlbumQuery { delete_files: true }),
+            )
+            .await
+            .into_response();
+
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+            assert!(file.exists());
+            assert_album_kept(&state, &album).await;
+        }
+
+        #[tokio::test]
+        async fn delete_album_files_requires_a_configured_library_root() {
+            let state = make_test_state().await;
+            let library = tempfile::tempdir().unwrap();
+            let (album, file) = album_with_file_on_disk(&state, library.path()).await;
+
+            let response = delete_album(
+                State(state.clone()),
+                Path(album.id.to_string()),
+                Query(DeleteAlbumQuery { delete_files: true }),
+            )
+            .await
+            .into_response();
+
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+            assert!(file.exists());
+            assert_album_kept(&state, &album).await;
+        }
+
+        #[tokio::test]
+        async fn delete_album_keeps_album_when_a_file_cannot_be_deleted() {
+            let mut state = make_test_state().await;
+            let library = tempfile::tempdir().unwrap();
+            state.config.library.root_paths = vec![library.path().to_path_buf()];
+            let (album, file) = album_with_file_on_disk(&state, library.path()).await;
+            std::fs::remove_file(&file).unwrap();
+
+            let response = delete_album(
+                State(state.clone()),
+                Path(album.id.to_string()),
+                Query(DeleteAlbumQuery { delete_files: true }),
+            )
+            .await
+            .into_response();
+
+            assert_eq!(response.status(), StatusCode::CONFLICT);
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let body: DeleteAlbumResponse = serde_json::from_slice(&body).unwrap();
+            assert_eq!(body.files_deleted, 0);
+            assert_eq!(body.failed_files.len(), 1);
+            assert_album_kept(&state, &album).await;
+        }
+
         // --- list_albums ---
 
         #[tokio::test]
@@ -1115,7 +2219,7 @@ mod tests {
             .await;
 
             assert!(result.is_err());
-            let (status, _) = result.unwrap_err();
+            let status = result.unwrap_err().status();
             assert_eq!(status, StatusCode::BAD_REQUEST);
         }
 
@@ -1133,7 +2237,7 @@ mod tests {
             .await;
 
             assert!(result.is_err());
-            let (status, _) = result.unwrap_err();
+            let status = result.unwrap_err().status();
             assert_eq!(status, StatusCode::BAD_REQUEST);
         }
 
@@ -1152,7 +2256,7 @@ mod tests {
             .await;
 
             assert!(result.is_err());
-            let (status, _) = result.unwrap_err();
+            let status = result.unwrap_err().status();
             assert_eq!(status, StatusCode::BAD_REQUEST);
         }
 
@@ -1171,7 +2275,7 @@ mod tests {
             .await;
 
             assert!(result.is_err());
-            let (status, _) = result.unwrap_err();
+            let status = result.unwrap_err().status();
             assert_eq!(status, StatusCode::BAD_REQUEST);
         }
     }
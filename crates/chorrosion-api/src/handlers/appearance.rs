@@ -1,5 +1,6 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
-use axum::{extract::State, http::StatusCode, Json};
+use crate::error::{ApiError, ApiErrorResponse};
+use axum::{extract::State, Json};
 use chorrosion_application::{
     AppState, AppearanceSettings, FilterOperator, ShortcutProfile, ThemeMode,
     DEFAULT_BULK_SELECTION_LIMIT, DEFAULT_FILTER_HISTORY_LIMIT, DEFAULT_FILTER_OPERATOR,
@@ -167,11 +168,6 @@ impl UpdateAppearanceSettingsRequest {
     }
 }
 
-#[derive(Debug, Serialize, ToSchema)]
-pub struct AppearanceErrorResponse {
-    pub error: String,
-}
-
 #[utoipa::path(
     get,
     path = "/api/v1/settings/appearance",
@@ -208,41 +204,22 @@ pub async fn get_appearance_settings(
     request_body = UpdateAppearanceSettingsRequest,
     responses(
         (status = 200, description = "Updated appearance settings", body = AppearanceSettingsResponse),
-        (status = 400, description = "Invalid theme mode, mobile breakpoint, shortcut profile, or bulk selection limit", body = AppearanceErrorResponse)
+        (status = 400, description = "Invalid theme mode, mobile breakpoint, shortcut profile, or bulk selection limit", body = ApiErrorResponse)
     ),
     tag = "settings"
 )]
 pub async fn update_appearance_settings(
     State(state): State<AppState>,
     Json(request): Json<UpdateAppearanceSettingsRequest>,
-) -> Result<Json<AppearanceSettingsResponse>, (StatusCode, Json<AppearanceErrorResponse>)> {
-    let theme_mode = ThemeMode::from_str(&request.theme_mode).map_err(|err| {
-        (
-            StatusCode::BAD_REQUEST,
-            Json(AppearanceErrorResponse {
-                error: err.to_string(),
-            }),
-        )
-    })?;
+) -> Result<Json<AppearanceSettingsResponse>, ApiError> {
+    let theme_mode = ThemeMode::from_str(&request.theme_mode)
+        .map_err(|err| ApiError::bad_request(err.to_string()))?;
 
-    let shortcut_profile = ShortcutProfile::from_str(&request.shortcut_profile).map_err(|err| {
-        (
-            StatusCode::BAD_REQUEST,
-            Json(AppearanceErrorResponse {
-                error: err.to_string(),
-            }),
-        )
-    })?;
+    let shortcut_profile = ShortcutProfile::from_str(&request.shortcut_profile)
+        .map_err(|err| ApiError::bad_request(err.to_string()))?;
 
     let default_filter_operator = FilterOperator::from_str(&request.default_filter_operator)
-        .map_err(|err| {
-            (
-                StatusCode::BAD_REQUEST,
-                Json(AppearanceErrorResponse {
-                    error: err.to_string(),
-                }),
-            )
-        })?;
+        .map_err(|err| ApiError::bad_request(err.to_string()))?;
 
     let updated = state
         .set_appearance_settings(AppearanceSettings {
@@ -262,14 +239,7 @@ pub async fn update_appearance_settings(
             filter_history_limit: request.filter_history_limit,
         })
         .await
-        .map_err(|err| {
-            (
-                StatusCode::BAD_REQUEST,
-                Json(AppearanceErrorResponse {
-                    error: err.to_string(),
-                }),
-            )
-        })?;
+        .map_err(|err| ApiError::bad_request(err.to_string()))?;
 
     Ok(Json(AppearanceSettingsResponse {
         theme_mode: updated.theme_mode.into(),
@@ -292,6 +262,7 @@ pub async fn update_appearance_settings(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use axum::http::StatusCode;
     use chorrosion_application::{DEFAULT_MAX_FILTER_CLAUSES, DEFAULT_MOBILE_BREAKPOINT_PX};
     use chorrosion_config::AppConfig;
     use chorrosion_infrastructure::sqlite_adapters::{
@@ -336,6 +307,11 @@ mod tests {
                     pool.clone(),
                 ),
             ),
+            Arc::new(
+                chorrosion_infrastructure::sqlite_adapters::SqliteTrackFileRepository::new(
+                    pool.clone(),
+                ),
+            ),
             chorrosion_infrastructure::ResponseCache::new(100, 60),
         )
     }
@@ -455,9 +431,10 @@ mod tests {
         )
         .await;
 
-        let (status, Json(error)) = result.expect_err("invalid breakpoint should fail");
+        let error = result.expect_err("invalid breakpoint should fail");
+        let status = error.status();
         assert_eq!(status, StatusCode::BAD_REQUEST);
-        assert!(error.error.contains("invalid mobile breakpoint"));
+        assert!(error.to_string().contains("invalid mobile breakpoint"));
     }
 
     #[tokio::test]
@@ -485,9 +462,10 @@ mod tests {
         )
         .await;
 
-        let (status, Json(error)) = result.expect_err("invalid profile should fail");
+        let error = result.expect_err("invalid profile should fail");
+        let status = error.status();
         assert_eq!(status, StatusCode::BAD_REQUEST);
-        assert!(error.error.contains("invalid shortcut profile"));
+        assert!(error.to_string().contains("invalid shortcut profile"));
     }
 
     #[tokio::test]
@@ -515,9 +493,10 @@ mod tests {
         )
         .await;
 
-        let (status, Json(error)) = result.expect_err("invalid bulk limit should fail");
+        let error = result.expect_err("invalid bulk limit should fail");
+        let status = error.status();
         assert_eq!(status, StatusCode::BAD_REQUEST);
-        assert!(error.error.contains("invalid bulk selection limit"));
+        assert!(error.to_string().contains("invalid bulk selection limit"));
     }
 
     #[tokio::test]
@@ -545,9 +524,10 @@ mod tests {
         )
         .await;
 
-        let (status, Json(error)) = result.expect_err("invalid mode should fail");
+        let error = result.expect_err("invalid mode should fail");
+        let status = error.status();
         assert_eq!(status, StatusCode::BAD_REQUEST);
-        assert!(error.error.contains("invalid theme mode"));
+        assert!(error.to_string().contains("invalid theme mode"));
     }
 
     #[tokio::test]
@@ -575,9 +555,10 @@ mod tests {
         )
         .await;
 
-        let (status, Json(error)) = result.expect_err("invalid filter operator should fail");
+        let error = result.expect_err("invalid filter operator should fail");
+        let status = error.status();
         assert_eq!(status, StatusCode::BAD_REQUEST);
-        assert!(error.error.contains("invalid filter operator"));
+        assert!(error.to_string().contains("invalid filter operator"));
     }
 
     #[tokio::test]
@@ -605,9 +586,10 @@ mod tests {
         )
         .await;
 
-        let (status, Json(error)) = result.expect_err("invalid max filter clauses should fail");
+        let error = result.expect_err("invalid max filter clauses should fail");
+        let status = error.status();
         assert_eq!(status, StatusCode::BAD_REQUEST);
-        assert!(error.error.contains("invalid max filter clauses"));
+        assert!(error.to_string().contains("invalid max filter clauses"));
     }
 
     #[tokio::test]
@@ -635,8 +617,9 @@ mod tests {
         )
         .await;
 
-        let (status, Json(error)) = result.expect_err("invalid filter history limit should fail");
+        let error = result.expect_err("invalid filter history limit should fail");
+        let status = error.status();
         assert_eq!(status, StatusCode::BAD_REQUEST);
-        assert!(error.error.contains("invalid filter history limit"));
+        assert!(error.to_string().contains("invalid filter history limit"));
     }
 }
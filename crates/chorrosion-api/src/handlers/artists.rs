@@ -1,15 +1,29 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
+use anyhow::Context;
 use axum::{
+    body::{Body, Bytes},
     extract::{Path, Query, State},
-    http::StatusCode,
-    response::IntoResponse,
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
     Json,
 };
 use chorrosion_application::AppState;
-use chorrosion_domain::{Artist, ArtistStatus};
+use chorrosion_domain::{
+    Artist, ArtistCreated, ArtistCreatedPayload, ArtistDeleted, ArtistDeletedPayload, ArtistId,
+    ArtistStatus, ArtistUpdated, ArtistUpdatedPayload, DomainEvent, Validate,
+};
+use chorrosion_infrastructure::repositories::ArtistRepository;
+use chrono::{DateTime, Utc};
+use futures_util::{stream, Stream};
 use serde::{Deserialize, Serialize};
-use tracing::debug;
+use std::convert::Infallible;
+use std::sync::Arc;
+use tracing::{debug, warn};
 use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::error::{ApiError, ApiErrorResponse};
+use crate::middleware::request_id::correlate;
 
 // ============================================================================
 // Request/Response Types
@@ -31,6 +45,14 @@ fn default_limit() -> i64 {
     50
 }
 
+/// Parse the `updated_at` a client sends back with an update, which the
+/// repository then requires to still match the stored row.
+pub(crate) fn parse_expected_version(version: &str) -> Result<DateTime<Utc>, ApiError> {
+    DateTime::parse_from_rfc3339(version)
+        .map(|at| at.with_timezone(&Utc))
+        .map_err(|_| ApiError::bad_request("updated_at must be an RFC 3339 timestamp"))
+}
+
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ArtistResponse {
     pub id: String,
@@ -39,6 +61,10 @@ pub struct ArtistResponse {
     pub status: String,
     pub monitored: bool,
     pub path: Option<String>,
+    /// RFC 3339 time of the last completed metadata refresh, if any.
+    pub last_metadata_refresh_at: Option<String>,
+    /// Version of the stored artist; send it back as `updated_at` when updating.
+    pub updated_at: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -69,6 +95,8 @@ impl From<Artist> for ArtistResponse {
             status: artist.status.to_string(),
             monitored: artist.monitored,
             path: artist.path,
+            last_metadata_refresh_at: artist.last_metadata_refresh_at.map(|at| at.to_rfc3339()),
+            updated_at: artist.updated_at.to_rfc3339(),
         }
     }
 }
@@ -89,32 +117,44 @@ pub struct UpdateArtistRequest {
     pub status: Option<String>,
     pub monitored: Option<bool>,
     pub path: Option<String>,
+    /// The `updated_at` the edit was based on. When set, the update is
+    /// rejected with `409` if the artist has been changed since.
+    #[serde(default)]
+    pub updated_at: Option<String>,
 }
 
-#[derive(Debug, Serialize, ToSchema)]
-pub struct ErrorResponse {
-    pub error: String,
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SetArtistMonitoredRequest {
+    pub monitored: bool,
+    /// Also set `monitored` on every album and track of the artist.
+    #[serde(default)]
+    pub cascade: bool,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct MergeArtistRequest {
+    /// Artist folded into the path artist and then deleted.
+    pub remove_artist_id: String,
 }
 
 // ============================================================================
 // Helpers
 // ============================================================================
 
-fn parse_artist_status(
-    status_str: &str,
-) -> Result<ArtistStatus, (StatusCode, Json<ErrorResponse>)> {
+fn parse_artist_status(status_str: &str) -> Result<ArtistStatus, ApiError> {
     match status_str.to_ascii_lowercase().as_str() {
         "continuing" => Ok(ArtistStatus::Continuing),
         "ended" => Ok(ArtistStatus::Ended),
-        _ => Err((
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: format!("invalid status value: {status_str}"),
-            }),
-        )),
+        _ => Err(ApiError::bad_request(format!(
+            "invalid status value: {status_str}"
+        ))),
     }
 }
 
+fn artist_not_found(id: &str) -> ApiError {
+    ApiError::not_found(format!("Artist {} not found", id))
+}
+
 // ============================================================================
 // Handlers
 // ============================================================================
@@ -126,38 +166,25 @@ fn parse_artist_status(
     params(ListArtistsQuery),
     responses(
         (status = 200, description = "List of artists", body = ListArtistsResponse),
-        (status = 400, description = "Invalid request", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "artists"
 )]
 pub async fn list_artists(
     State(state): State<AppState>,
     Query(query): Query<ListArtistsQuery>,
-) -> Result<Json<ListArtistsResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<ListArtistsResponse>, ApiError> {
     debug!(target: "api", ?query, "listing artists");
 
-    let normalized = normalize_list_query(&query).map_err(|error| {
-        (
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: error.to_string(),
-            }),
-        )
-    })?;
+    let normalized =
+        normalize_list_query(&query).map_err(|error| ApiError::bad_request(error.to_string()))?;
 
     let artists = state
         .artist_repository
         .list(5000, 0)
         .await
-        .map_err(|error| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse {
-                    error: format!("failed to list artists: {error}"),
-                }),
-            )
-        })?;
+        .context("failed to list artists")?;
 
     let (page, total) = apply_list_query(artists, &normalized);
 
@@ -314,6 +341,68 @@ fn apply_list_query(mut artists: Vec<Artist>, query: &NormalizedListQuery) -> (V
     (page, total)
 }
 
+/// Artists fetched per repository page while streaming an export.
+const EXPORT_PAGE_SIZE: i64 = 500;
+
+/// Export every artist as newline-delimited JSON
+///
+/// The library is paged internally and each page is written as soon as it is
+/// fetched, so the response is never buffered in full.
+#[utoipa::path(
+    get,
+    path = "/api/v1/artists/export",
+    responses(
+        (status = 200, description = "One ArtistResponse JSON object per line", content_type = "application/x-ndjson")
+    ),
+    tag = "artists"
+)]
+pub async fn export_artists(State(state): State<AppState>) -> Response {
+    debug!(target: "api", "streaming artist export");
+    let body = Body::from_stream(artist_ndjson_stream(
+        state.artist_repository.clone(),
+        EXPORT_PAGE_SIZE,
+    ));
+    ([(header::CONTENT_TYPE, "application/x-ndjson")], body).into_response()
+}
+
+/// NDJSON chunks, one per repository page of `page_size` artists.
+///
+/// A failed page read ends the stream after the rows already sent; the error
+/// is logged since the status line has already gone out.
+fn artist_ndjson_stream(
+    repository: Arc<dyn ArtistRepository>,
+    page_size: i64,
+) -> impl Stream<Item = Result<Bytes, Infallible>> {
+    stream::unfold(Some(0_i64), move |offset| {
+        let repository = repository.clone();
+        async move {
+            let offset = offset?;
+            let page = match repository.list(page_size, offset).await {
+                Ok(page) => page,
+                Err(error) => {
+                    warn!(target: "api", offset, %error, "artist export aborted");
+                    return None;
+                }
+            };
+            if page.is_empty() {
+                return None;
+            }
+
+            let next = (page.len() as i64 == page_size).then_some(offset + page_size);
+            let mut chunk = Vec::new();
+            for artist in page {
+                if let Err(error) = serde_json::to_writer(&mut chunk, &ArtistResponse::from(artist))
+                {
+                    warn!(target: "api", %error, "artist export aborted");
+                    return None;
+                }
+                chunk.push(b'\n');
+            }
+            Some((Ok(Bytes::from(chunk)), next))
+        }
+    })
+}
+
 /// Get a single artist by ID
 #[utoipa::path(
     get,
@@ -323,34 +412,24 @@ fn apply_list_query(mut artists: Vec<Artist>, query: &NormalizedListQuery) -> (V
     ),
     responses(
         (status = 200, description = "Artist found", body = ArtistResponse),
-        (status = 404, description = "Artist not found", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 404, description = "Artist not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "artists"
 )]
 pub async fn get_artist(
     State(state): State<AppState>,
     Path(id): Path<String>,
-) -> impl IntoResponse {
+) -> Result<Json<ArtistResponse>, ApiError> {
     debug!(target: "api", %id, "fetching artist");
 
-    match state.artist_repository.get_by_id(&id).await {
-        Ok(Some(artist)) => (StatusCode::OK, Json(ArtistResponse::from(artist))).into_response(),
-        Ok(None) => (
-            StatusCode::NOT_FOUND,
-            Json(ErrorResponse {
-                error: format!("Artist {} not found", id),
-            }),
-        )
-            .into_response(),
-        Err(error) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: format!("failed to fetch artist: {error}"),
-            }),
-        )
-            .into_response(),
-    }
+    let artist = state
+        .artist_repository
+        .get_by_id(&id)
+        .await
+        .context("failed to fetch artist")?
+        .ok_or_else(|| artist_not_found(&id))?;
+    Ok(Json(ArtistResponse::from(artist)))
 }
 
 /// Get aggregate statistics for a single artist.
@@ -362,38 +441,23 @@ pub async fn get_artist(
     ),
     responses(
         (status = 200, description = "Artist statistics", body = ArtistStatisticsResponse),
-        (status = 404, description = "Artist not found", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 404, description = "Artist not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "artists"
 )]
 pub async fn get_artist_statistics(
     State(state): State<AppState>,
     Path(id): Path<String>,
-) -> impl IntoResponse {
+) -> Result<Json<ArtistStatisticsResponse>, ApiError> {
     debug!(target: "api", %id, "fetching artist statistics");
 
-    let artist = match state.artist_repository.get_by_id(&id).await {
-        Ok(Some(artist)) => artist,
-        Ok(None) => {
-            return (
-                StatusCode::NOT_FOUND,
-                Json(ErrorResponse {
-                    error: format!("Artist {} not found", id),
-                }),
-            )
-                .into_response();
-        }
-        Err(error) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse {
-                    error: format!("failed to fetch artist: {error}"),
-                }),
-            )
-                .into_response();
-        }
-    };
+    let artist = state
+        .artist_repository
+        .get_by_id(&id)
+        .await
+        .context("failed to fetch artist")?
+        .ok_or_else(|| artist_not_found(&id))?;
 
     const PAGE_SIZE: i64 = 5000;
 
@@ -402,22 +466,11 @@ pub async fn get_artist_statistics(
     let mut album_offset: i64 = 0;
 
     loop {
-        let page = match state
+        let page = state
             .album_repository
             .get_by_artist(artist.id, PAGE_SIZE, album_offset)
             .await
-        {
-            Ok(page) => page,
-            Err(error) => {
-                return (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(ErrorResponse {
-                        error: format!("failed to fetch albums for artist: {error}"),
-                    }),
-                )
-                    .into_response();
-            }
-        };
+            .context("failed to fetch albums for artist")?;
 
         if page.is_empty() {
             break;
@@ -439,22 +492,11 @@ pub async fn get_artist_statistics(
     let mut track_offset: i64 = 0;
 
     loop {
-        let page = match state
+        let page = state
             .track_repository
             .get_by_artist(artist.id, PAGE_SIZE, track_offset)
             .await
-        {
-            Ok(page) => page,
-            Err(error) => {
-                return (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(ErrorResponse {
-                        error: format!("failed to fetch tracks for artist: {error}"),
-                    }),
-                )
-                    .into_response();
-            }
-        };
+            .context("failed to fetch tracks for artist")?;
 
         if page.is_empty() {
             break;
@@ -479,19 +521,15 @@ pub async fn get_artist_statistics(
 
     let tracks_without_files = total_tracks - tracks_with_files;
 
-    (
-        StatusCode::OK,
-        Json(ArtistStatisticsResponse {
-            artist_id: artist.id.to_string(),
-            total_albums,
-            monitored_albums,
-            total_tracks,
-            monitored_tracks,
-            tracks_with_files,
-            tracks_without_files,
-        }),
-    )
-        .into_response()
+    Ok(Json(ArtistStatisticsResponse {
+        artist_id: artist.id.to_string(),
+        total_albums,
+        monitored_albums,
+        total_tracks,
+        monitored_tracks,
+        tracks_with_files,
+        tracks_without_files,
+    }))
 }
 
 /// Create a new artist
@@ -501,15 +539,16 @@ pub async fn get_artist_statistics(
     request_body = CreateArtistRequest,
     responses(
         (status = 201, description = "Artist created", body = ArtistResponse),
-        (status = 400, description = "Invalid request", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 422, description = "Artist failed validation", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "artists"
 )]
 pub async fn create_artist(
     State(state): State<AppState>,
     Json(request): Json<CreateArtistRequest>,
-) -> impl IntoResponse {
+) -> Result<(StatusCode, Json<ArtistResponse>), ApiError> {
     debug!(target: "api", ?request, "creating artist");
 
     let mut artist = Artist::new(request.name);
@@ -518,22 +557,25 @@ pub async fn create_artist(
     artist.path = request.path;
 
     if let Some(status_str) = request.status {
-        match parse_artist_status(&status_str) {
-            Ok(status) => artist.status = status,
-            Err(err_response) => return err_response.into_response(),
-        }
+        artist.status = parse_artist_status(&status_str)?;
     }
+    artist.validate()?;
 
-    match state.artist_repository.create(artist).await {
-        Ok(created) => (StatusCode::CREATED, Json(ArtistResponse::from(created))).into_response(),
-        Err(error) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: format!("failed to create artist: {error}"),
-            }),
-        )
-            .into_response(),
-    }
+    let created = state
+        .artist_repository
+        .create(artist)
+        .await
+        .context("failed to create artist")?;
+    let event: ArtistCreated = correlate(DomainEvent::new(
+        "artist.created",
+        ArtistCreatedPayload {
+            artist_id: created.id,
+            name: created.name.clone(),
+            monitored: created.monitored,
+        },
+    ));
+    state.event_dispatcher.dispatch(&event).await;
+    Ok((StatusCode::CREATED, Json(ArtistResponse::from(created))))
 }
 
 /// Update an existing artist
@@ -546,9 +588,11 @@ pub async fn create_artist(
     request_body = UpdateArtistRequest,
     responses(
         (status = 200, description = "Artist updated", body = ArtistResponse),
-        (status = 404, description = "Artist not found", body = ErrorResponse),
-        (status = 400, description = "Invalid request", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 404, description = "Artist not found", body = ApiErrorResponse),
+        (status = 409, description = "Artist was modified concurrently", body = ApiErrorResponse),
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 422, description = "Artist failed validation", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "artists"
 )]
@@ -556,31 +600,19 @@ pub async fn update_artist(
     State(state): State<AppState>,
     Path(id): Path<String>,
     Json(request): Json<UpdateArtistRequest>,
-) -> impl IntoResponse {
+) -> Result<Json<ArtistResponse>, ApiError> {
     debug!(target: "api", %id, ?request, "updating artist");
 
-    let mut artist = match state.artist_repository.get_by_id(&id).await {
-        Ok(Some(a)) => a,
-        Ok(None) => {
-            return (
-                StatusCode::NOT_FOUND,
-                Json(ErrorResponse {
-                    error: format!("Artist {} not found", id),
-                }),
-            )
-                .into_response()
-        }
-        Err(error) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse {
-                    error: format!("failed to fetch artist: {error}"),
-                }),
-            )
-                .into_response()
-        }
-    };
+    let mut artist = state
+        .artist_repository
+        .get_by_id(&id)
+        .await
+        .context("failed to fetch artist")?
+        .ok_or_else(|| artist_not_found(&id))?;
 
+    if let Some(version) = request.updated_at.as_deref() {
+        artist.updated_at = parse_expected_version(version)?;
+    }
     if let Some(name) = request.name {
         artist.name = name;
     }
@@ -588,10 +620,7 @@ pub async fn update_artist(
         artist.foreign_artist_id = Some(foreign_id);
     }
     if let Some(status_str) = request.status {
-        match parse_artist_status(&status_str) {
-            Ok(status) => artist.status = status,
-            Err(err_response) => return err_response.into_response(),
-        }
+        artist.status = parse_artist_status(&status_str)?;
     }
     if let Some(monitored) = request.monitored {
         artist.monitored = monitored;
@@ -599,17 +628,74 @@ pub async fn update_artist(
     if let Some(path) = request.path {
         artist.path = Some(path);
     }
+    artist.validate()?;
 
-    match state.artist_repository.update(artist).await {
-        Ok(updated) => (StatusCode::OK, Json(ArtistResponse::from(updated))).into_response(),
-        Err(error) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: format!("failed to update artist: {error}"),
-            }),
-        )
-            .into_response(),
-    }
+    let updated = state
+        .artist_repository
+        .update(artist)
+        .await
+        .context("failed to update artist")?;
+    let event: ArtistUpdated = correlate(DomainEvent::new(
+        "artist.updated",
+        ArtistUpdatedPayload {
+            artist_id: updated.id,
+            name: updated.name.clone(),
+            monitored: updated.monitored,
+        },
+    ));
+    state.event_dispatcher.dispatch(&event).await;
+    Ok(Json(ArtistResponse::from(updated)))
+}
+
+/// Set whether an artist is monitored without rewriting the rest of the artist
+#[utoipa::path(
+    put,
+    path = "/api/v1/artists/{id}/monitored",
+    params(
+        ("id" = String, Path, description = "Artist ID")
+    ),
+    request_body = SetArtistMonitoredRequest,
+    responses(
+        (status = 200, description = "Artist monitoring updated", body = ArtistResponse),
+        (status = 404, description = "Artist not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
+    ),
+    tag = "artists"
+)]
+pub async fn set_artist_monitored(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(request): Json<SetArtistMonitoredRequest>,
+) -> Result<Json<ArtistResponse>, ApiError> {
+    debug!(target: "api", %id, monitored = request.monitored, cascade = request.cascade, "setting artist monitored");
+
+    let uuid = Uuid::parse_str(&id).map_err(|_| artist_not_found(&id))?;
+    chorrosion_application::set_artist_monitored(
+        state.artist_repository.as_ref(),
+        ArtistId::from_uuid(uuid),
+        request.monitored,
+        request.cascade,
+    )
+    .await
+    .context("failed to update artist")?
+    .ok_or_else(|| artist_not_found(&id))?;
+
+    let artist = state
+        .artist_repository
+        .get_by_id(&id)
+        .await
+        .context("failed to fetch artist")?
+        .ok_or_else(|| artist_not_found(&id))?;
+    let event: ArtistUpdated = correlate(DomainEvent::new(
+        "artist.updated",
+        ArtistUpdatedPayload {
+            artist_id: artist.id,
+            name: artist.name.clone(),
+            monitored: artist.monitored,
+        },
+    ));
+    state.event_dispatcher.dispatch(&event).await;
+    Ok(Json(ArtistResponse::from(artist)))
 }
 
 /// Delete an artist
@@ -621,64 +707,109 @@ pub async fn update_artist(
     ),
     responses(
         (status = 204, description = "Artist deleted"),
-        (status = 404, description = "Artist not found", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 404, description = "Artist not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "artists"
 )]
 pub async fn delete_artist(
     State(state): State<AppState>,
     Path(id): Path<String>,
-) -> impl IntoResponse {
+) -> Result<StatusCode, ApiError> {
     debug!(target: "api", %id, "deleting artist");
 
-    match state.artist_repository.get_by_id(&id).await {
-        Ok(Some(_)) => {
-            match state.artist_repository.delete(&id).await {
-                Ok(_) => StatusCode::NO_CONTENT.into_response(),
-                Err(delete_error) => {
-                    // Check if the artist was concurrently deleted before we could.
-                    match state.artist_repository.get_by_id(&id).await {
-                        Ok(None) => (
-                            StatusCode::NOT_FOUND,
-                            Json(ErrorResponse {
-                                error: format!("Artist {} not found", id),
-                            }),
-                        )
-                            .into_response(),
-                        Ok(Some(_)) => (
-                            StatusCode::INTERNAL_SERVER_ERROR,
-                            Json(ErrorResponse {
-                                error: format!("failed to delete artist: {delete_error}"),
-                            }),
-                        )
-                            .into_response(),
-                        Err(_) => (
-                            StatusCode::INTERNAL_SERVER_ERROR,
-                            Json(ErrorResponse {
-                                error: format!("failed to delete artist: {delete_error}"),
-                            }),
-                        )
-                            .into_response(),
-                    }
-                }
-            }
-        }
-        Ok(None) => (
-            StatusCode::NOT_FOUND,
-            Json(ErrorResponse {
-                error: format!("Artist {} not found", id),
-            }),
-        )
-            .into_response(),
-        Err(error) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: format!("failed to fetch artist before delete: {error}"),
-            }),
-        )
-            .into_response(),
+    let existing = state
+        .artist_repository
+        .get_by_id(&id)
+        .await
+        .context("failed to fetch artist before delete")?
+        .ok_or_else(|| artist_not_found(&id))?;
+
+    if let Err(delete_error) = state.artist_repository.delete(&id).await {
+        // Check if the artist was concurrently deleted before we could.
+        return match state.artist_repository.get_by_id(&id).await {
+            Ok(None) => Err(artist_not_found(&id)),
+            _ => Err(delete_error.context("failed to delete artist").into()),
+        };
     }
+
+    let event: ArtistDeleted = correlate(DomainEvent::new(
+        "artist.deleted",
+        ArtistDeletedPayload {
+            artist_id: existing.id,
+            name: existing.name,
+        },
+    ));
+    state.event_dispatcher.dispatch(&event).await;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Merge another artist into this one
+///
+/// Albums, tracks, relationships and tags of `remove_artist_id` are moved onto the path
+/// artist, which keeps its own identifiers on conflict, and the removed artist is deleted.
+#[utoipa::path(
+    post,
+    path = "/api/v1/artists/{id}/merge",
+    params(
+        ("id" = String, Path, description = "Artist ID to keep")
+    ),
+    request_body = MergeArtistRequest,
+    responses(
+        (status = 200, description = "Artists merged", body = ArtistResponse),
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 404, description = "Artist not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
+    ),
+    tag = "artists"
+)]
+pub async fn merge_artist(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(request): Json<MergeArtistRequest>,
+) -> Result<Json<ArtistResponse>, ApiError> {
+    debug!(target: "api", %id, remove_artist_id = %request.remove_artist_id, "merging artists");
+
+    if id == request.remove_artist_id {
+        return Err(ApiError::bad_request("cannot merge an artist into itself"));
+    }
+
+    let mut artists = Vec::with_capacity(2);
+    for artist_id in [&id, &request.remove_artist_id] {
+        let artist = state
+            .artist_repository
+            .get_by_id(artist_id)
+            .await
+            .context("failed to fetch artist")?
+            .ok_or_else(|| artist_not_found(artist_id))?;
+        artists.push(artist);
+    }
+    let removed = artists.pop().expect("removed artist fetched");
+    let kept = artists.pop().expect("kept artist fetched");
+
+    let merged = state
+        .artist_repository
+        .merge_artists(kept.id, removed.id)
+        .await
+        .context("failed to merge artists")?;
+    let event: ArtistDeleted = correlate(DomainEvent::new(
+        "artist.deleted",
+        ArtistDeletedPayload {
+            artist_id: removed.id,
+            name: removed.name,
+        },
+    ));
+    state.event_dispatcher.dispatch(&event).await;
+    let event: ArtistUpdated = correlate(DomainEvent::new(
+        "artist.updated",
+        ArtistUpdatedPayload {
+            artist_id: merged.id,
+            name: merged.name.clone(),
+            monitored: merged.monitored,
+        },
+    ));
+    state.event_dispatcher.dispatch(&event).await;
+    Ok(Json(ArtistResponse::from(merged)))
 }
 
 #[cfg(test)]
@@ -887,16 +1018,14 @@ mod tests {
     fn parse_status_rejects_unknown_value() {
         let result = parse_artist_status("unknown");
         assert!(result.is_err());
-        let (status, _) = result.unwrap_err();
-        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(result.unwrap_err().status(), StatusCode::BAD_REQUEST);
     }
 
     #[test]
     fn parse_status_rejects_empty_string() {
         let result = parse_artist_status("");
         assert!(result.is_err());
-        let (status, _) = result.unwrap_err();
-        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(result.unwrap_err().status(), StatusCode::BAD_REQUEST);
     }
 
     // ============================================================================
@@ -950,10 +1079,135 @@ mod tests {
                         pool.clone(),
                     ),
                 ),
+                Arc::new(
+                    chorrosion_infrastructure::sqlite_adapters::SqliteTrackFileRepository::new(
+                        pool.clone(),
+                    ),
+                ),
                 chorrosion_infrastructure::ResponseCache::new(100, 60),
             )
         }
 
+        // --- export_artists ---
+
+        async fn export_lines(response: Response) -> Vec<ArtistResponse> {
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .expect("export body");
+            std::str::from_utf8(&body)
+                .expect("utf-8 body")
+                .lines()
+                .map(|line| serde_json::from_str(line).expect("NDJSON row"))
+                .collect()
+        }
+
+        #[tokio::test]
+        async fn export_artists_streams_every_artist_as_ndjson() {
+            let state = make_test_state().await;
+            for i in 0..7 {
+                state
+                    .artist_repository
+                    .create(Artist::new(format!("Export Artist {i}")))
+                    .await
+                    .unwrap();
+            }
+            let stored = state.artist_repository.list(1000, 0).await.unwrap().len();
+
+            let response = export_artists(State(state)).await;
+            assert_eq!(response.status(), StatusCode::OK);
+            assert_eq!(
+                response.headers()[header::CONTENT_TYPE],
+                "application/x-ndjson"
+            );
+            let rows = export_lines(response).await;
+            assert_eq!(rows.len(), stored);
+            assert_eq!(rows[0].name, "Export Artist 0");
+        }
+
+        #[tokio::test]
+        async fn export_artists_pages_through_the_repository() {
+            let state = make_test_state().await;
+            for i in 0..5 {
+                state
+                    .artist_repository
+                    .create(Artist::new(format!("Paged Artist {i}")))
+                    .await
+                    .unwrap();
+            }
+
+            let body = Body::from_stream(artist_ndjson_stream(state.artist_repository.clone(), 2));
+            let rows = export_lines(body.into_response()).await;
+
+            let names: Vec<_> = rows.iter().map(|row| row.name.as_str()).collect();
+            assert_eq!(
+                names,
+                [
+                    "Paged Artist 0",
+                    "Paged Artist 1",
+                    "Paged Artist 2",
+                    "Paged Artist 3",
+                    "Paged Artist 4"
+                ]
+            );
+        }
+
+        #[tokio::test]
+        async fn export_artists_with_empty_library_returns_empty_body() {
+            let state = make_test_state().await;
+            let rows = export_lines(export_artists(State(state)).await).await;
+            assert!(rows.is_empty());
+        }
+
+        // --- list_artists ---
+
+        #[tokio::test]
+        async fn list_artists_surfaces_last_metadata_refresh() {
+            let state = make_test_state().await;
+            let refreshed = state
+                .artist_repository
+                .create(Artist::new("Refreshed"))
+                .await
+                .unwrap();
+            state
+                .artist_repository
+                .create(Artist::new("Never Refreshed"))
+                .await
+                .unwrap();
+            let at = chrono::DateTime::parse_from_rfc3339("2026-03-01T12:00:00Z")
+                .unwrap()
+                .with_timezone(&chrono::Utc);
+            state
+                .artist_repository
+                .record_metadata_refresh(refreshed.id, at)
+                .await
+                .unwrap();
+
+            let Json(body) = list_artists(
+                State(state),
+                Query(ListArtistsQuery {
+                    limit: 50,
+                    offset: 0,
+                    monitored: None,
+                    status: None,
+                    sort_by: None,
+                    sort_order: None,
+                }),
+            )
+            .await
+            .unwrap();
+
+            let by_name = |name: &str| {
+                body.items
+                    .iter()
+                    .find(|item| item.name == name)
+                    .unwrap()
+                    .last_metadata_refresh_at
+                    .clone()
+            };
+            assert_eq!(by_name("Refreshed"), Some(at.to_rfc3339()));
+            assert_eq!(by_name("Never Refreshed"), None);
+        }
+
         // --- create_artist ---
 
         #[tokio::test]
@@ -972,6 +1226,59 @@ mod tests {
             assert_eq!(response.status(), StatusCode::CREATED);
         }
 
+        #[tokio::test]
+        async fn create_artist_inherits_default_profiles() {
+            let state = make_test_state().await;
+            let quality =
+                chorrosion_domain::QualityProfile::new("Lossless", vec!["FLAC".to_string()]);
+            let metadata = chorrosion_domain::MetadataProfile::new("Standard");
+            state
+                .quality_profile_repository
+                .create(quality.clone())
+                .await
+                .unwrap();
+            state
+                .metadata_profile_repository
+                .create(metadata.clone())
+                .await
+                .unwrap();
+            state
+                .quality_profile_repository
+                .set_default(&quality.id.to_string())
+                .await
+                .unwrap();
+            state
+                .metadata_profile_repository
+                .set_default(&metadata.id.to_string())
+                .await
+                .unwrap();
+
+            let request = CreateArtistRequest {
+                name: "Test Artist".to_string(),
+                foreign_artist_id: None,
+                status: None,
+                monitored: None,
+                path: None,
+            };
+            let response = create_artist(State(state.clone()), Json(request))
+                .await
+                .into_response();
+            assert_eq!(response.status(), StatusCode::CREATED);
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let body: ArtistResponse = serde_json::from_slice(&body).unwrap();
+
+            let artist = state
+                .artist_repository
+                .get_by_id(&body.id)
+                .await
+                .unwrap()
+                .expect("created artist");
+            assert_eq!(artist.quality_profile_id, Some(quality.id));
+            assert_eq!(artist.metadata_profile_id, Some(metadata.id));
+        }
+
         #[tokio::test]
         async fn create_artist_rejects_invalid_status() {
             let state = make_test_state().await;
@@ -1004,6 +1311,34 @@ mod tests {
             assert_eq!(response.status(), StatusCode::CREATED);
         }
 
+        #[tokio::test]
+        async fn create_artist_publishes_artist_created_event() {
+            let hub = Arc::new(chorrosion_realtime::RecordingHub::default());
+            let state = make_test_state()
+                .await
+                .with_event_dispatcher(chorrosion_application::EventDispatcher::new(hub.clone()));
+            let request = CreateArtistRequest {
+                name: "Realtime Artist".to_string(),
+                foreign_artist_id: None,
+                status: None,
+                monitored: Some(false),
+                path: None,
+            };
+            let response = create_artist(State(state), Json(request))
+                .await
+                .into_response();
+            assert_eq!(response.status(), StatusCode::CREATED);
+
+            let messages = hub.messages();
+            assert_eq!(messages.len(), 1);
+            let chorrosion_realtime::RealtimeMessage { channel, payload } = &messages[0];
+            assert_eq!(channel, "artists");
+            let value: serde_json::Value = serde_json::from_str(payload).expect("valid JSON");
+            assert_eq!(value["name"], "artist.created");
+            assert_eq!(value["payload"]["name"], "Realtime Artist");
+            assert_eq!(value["payload"]["monitored"], false);
+        }
+
         // --- update_artist ---
 
         #[tokio::test]
@@ -1022,6 +1357,7 @@ mod tests {
                 status: None,
                 monitored: None,
                 path: None,
+                updated_at: None,
             };
             let response = update_artist(State(state), Path(id), Json(request))
                 .await
@@ -1029,6 +1365,103 @@ mod tests {
             assert_eq!(response.status(), StatusCode::OK);
         }
 
+        #[tokio::test]
+        async fn update_artist_returns_409_when_the_client_version_is_stale() {
+            let state = make_test_state().await;
+            let created = state
+                .artist_repository
+                .create(Artist::new("Shared"))
+                .await
+                .unwrap();
+            let id = created.id.to_string();
+            let read_version = ArtistResponse::from(created).updated_at;
+
+            let rename = |name: &str| UpdateArtistRequest {
+                name: Some(name.to_string()),
+                foreign_artist_id: None,
+                status: None,
+                monitored: None,
+                path: None,
+                updated_at: Some(read_version.clone()),
+            };
+            let first = update_artist(
+                State(state.clone()),
+                Path(id.clone()),
+                Json(rename("First")),
+            )
+            .await
+            .into_response();
+            assert_eq!(first.status(), StatusCode::OK);
+
+            let second = update_artist(
+                State(state.clone()),
+                Path(id.clone()),
+                Json(rename("Second")),
+            )
+            .await
+            .into_response();
+            assert_eq!(second.status(), StatusCode::CONFLICT);
+            let stored = state
+                .artist_repository
+                .get_by_id(&id)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(stored.name, "First");
+        }
+
+        #[tokio::test]
+        async fn set_artist_monitored_cascades_to_albums_when_asked() {
+            let state = make_test_state().await;
+            let artist = state
+                .artist_repository
+                .create(Artist::new("Cascade"))
+                .await
+                .unwrap();
+            let album = state
+                .album_repository
+                .create(Album::new(artist.id, "Kept Monitored?"))
+                .await
+                .unwrap();
+
+            for (cascade, album_monitored) in [(false, true), (true, false)] {
+                let response = set_artist_monitored(
+                    State(state.clone()),
+                    Path(artist.id.to_string()),
+                    Json(SetArtistMonitoredRequest {
+                        monitored: false,
+                        cascade,
+                    }),
+                )
+                .await
+                .into_response();
+                assert_eq!(response.status(), StatusCode::OK);
+                let stored = state
+                    .album_repository
+                    .get_by_id(&album.id.to_string())
+                    .await
+                    .unwrap()
+                    .unwrap();
+                assert_eq!(stored.monitored, album_monitored, "cascade = {cascade}");
+            }
+        }
+
+        #[tokio::test]
+        async fn set_artist_monitored_returns_404_for_unknown_id() {
+            let state = make_test_state().await;
+            let response = set_artist_monitored(
+                State(state),
+                Path("00000000-0000-0000-0000-000000000000".to_string()),
+                Json(SetArtistMonitoredRequest {
+                    monitored: false,
+                    cascade: true,
+                }),
+            )
+            .await
+            .into_response();
+            assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        }
+
         #[tokio::test]
         async fn update_artist_returns_404_for_unknown_id() {
             let state = make_test_state().await;
@@ -1038,6 +1471,7 @@ mod tests {
                 status: None,
                 monitored: None,
                 path: None,
+                updated_at: None,
             };
             let unknown_id = "00000000-0000-0000-0000-000000000000".to_string();
             let response = update_artist(State(state), Path(unknown_id), Json(request))
@@ -1061,6 +1495,7 @@ mod tests {
                 status: Some("bad_status".to_string()),
                 monitored: None,
                 path: None,
+                updated_at: None,
             };
             let response = update_artist(State(state), Path(id), Json(request))
                 .await
@@ -1093,6 +1528,45 @@ mod tests {
             assert_eq!(response.status(), StatusCode::NOT_FOUND);
         }
 
+        #[tokio::test]
+        async fn create_artist_with_blank_name_returns_422_with_field_details() {
+            let state = make_test_state().await;
+            let request = CreateArtistRequest {
+                name: "   ".to_string(),
+                foreign_artist_id: None,
+                status: None,
+                monitored: None,
+                path: None,
+            };
+            let response = create_artist(State(state), Json(request))
+                .await
+                .into_response();
+            assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let body: ApiErrorResponse = serde_json::from_slice(&body).unwrap();
+            assert_eq!(body.details.len(), 1);
+            assert_eq!(body.details[0].field, "name");
+        }
+
+        #[tokio::test]
+        async fn get_artist_returns_404_in_standard_shape() {
+            let state = make_test_state().await;
+            let unknown_id = "00000000-0000-0000-0000-000000000000".to_string();
+            let response = get_artist(State(state), Path(unknown_id.clone()))
+                .await
+                .into_response();
+            assert_eq!(response.status(), StatusCode::NOT_FOUND);
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let body: ApiErrorResponse = serde_json::from_slice(&body).unwrap();
+            assert_eq!(body.error, format!("Artist {unknown_id} not found"));
+            assert!(body.details.is_empty());
+            assert!(body.correlation_id.is_none());
+        }
+
         // --- get_artist_statistics ---
 
         #[tokio::test]
@@ -1150,5 +1624,94 @@ mod tests {
 
             assert_eq!(response.status(), StatusCode::NOT_FOUND);
         }
+
+        // --- merge_artist ---
+
+        #[tokio::test]
+        async fn merge_artist_moves_albums_and_deletes_removed_artist() {
+            let state = make_test_state().await;
+            let keep = state
+                .artist_repository
+                .create(Artist::new("Keep"))
+                .await
+                .unwrap();
+            let remove = state
+                .artist_repository
+                .create(Artist::new("Remove"))
+                .await
+                .unwrap();
+            let album = state
+                .album_repository
+                .create(Album::new(remove.id, "Moved Album"))
+                .await
+                .unwrap();
+
+            let response = merge_artist(
+                State(state.clone()),
+                Path(keep.id.to_string()),
+                Json(MergeArtistRequest {
+                    remove_artist_id: remove.id.to_string(),
+                }),
+            )
+            .await
+            .into_response();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let moved = state
+                .album_repository
+                .get_by_id(&album.id.to_string())
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(moved.artist_id, keep.id);
+            assert!(state
+                .artist_repository
+                .get_by_id(&remove.id.to_string())
+                .await
+                .unwrap()
+                .is_none());
+        }
+
+        #[tokio::test]
+        async fn merge_artist_returns_404_for_unknown_removed_artist() {
+            let state = make_test_state().await;
+            let keep = state
+                .artist_repository
+                .create(Artist::new("Keep"))
+                .await
+                .unwrap();
+
+            let response = merge_artist(
+                State(state),
+                Path(keep.id.to_string()),
+                Json(MergeArtistRequest {
+                    remove_artist_id: "00000000-0000-0000-0000-000000000000".to_string(),
+                }),
+            )
+            .await
+            .into_response();
+            assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        }
+
+        #[tokio::test]
+        async fn merge_artist_rejects_self_merge() {
+            let state = make_test_state().await;
+            let keep = state
+                .artist_repository
+                .create(Artist::new("Keep"))
+                .await
+                .unwrap();
+
+            let response = merge_artist(
+                State(state),
+                Path(keep.id.to_string()),
+                Json(MergeArtistRequest {
+                    remove_artist_id: keep.id.to_string(),
+                }),
+            )
+            .await
+            .into_response();
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        }
     }
 }
@@ -1,4 +1,5 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
+use crate::error::{ApiError, ApiErrorResponse};
 use axum::{
     extract::{Path, State},
     http::StatusCode,
@@ -48,11 +49,6 @@ fn form_session_store() -> &'static RwLock<Vec<FormSessionRecord>> {
     FORM_SESSIONS.get_or_init(|| RwLock::new(Vec::new()))
 }
 
-#[derive(Debug, Serialize, ToSchema)]
-pub struct AuthErrorResponse {
-    pub error: String,
-}
-
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateApiKeyRequest {
     pub name: Option<String>,
@@ -257,22 +253,12 @@ fn forms_auth_configured(state: &AppState) -> bool {
             .is_some_and(|v| !v.trim().is_empty())
 }
 
-pub(crate) fn unauthorized_response() -> (StatusCode, Json<AuthErrorResponse>) {
-    (
-        StatusCode::UNAUTHORIZED,
-        Json(AuthErrorResponse {
-            error: "Unauthorized".to_string(),
-        }),
-    )
+pub(crate) fn unauthorized_response() -> ApiError {
+    ApiError::unauthorized("Unauthorized")
 }
 
-pub(crate) fn permission_denied_response() -> (StatusCode, Json<AuthErrorResponse>) {
-    (
-        StatusCode::FORBIDDEN,
-        Json(AuthErrorResponse {
-            error: "insufficient permissions".to_string(),
-        }),
-    )
+pub(crate) fn permission_denied_response() -> ApiError {
+    ApiError::forbidden("insufficient permissions")
 }
 
 #[utoipa::path(
@@ -281,8 +267,8 @@ pub(crate) fn permission_denied_response() -> (StatusCode, Json<AuthErrorRespons
     request_body(content = FormsLoginRequest, content_type = "application/x-www-form-urlencoded"),
     responses(
         (status = 200, description = "Forms login successful", body = FormsLoginResponse),
-        (status = 401, description = "Invalid credentials", body = AuthErrorResponse),
-        (status = 503, description = "Forms auth not configured", body = AuthErrorResponse)
+        (status = 401, description = "Invalid credentials", body = ApiErrorResponse),
+        (status = 503, description = "Forms auth not configured", body = ApiErrorResponse)
     ),
     security(()),
     tag = "auth"
@@ -295,14 +281,11 @@ pub async fn forms_login(
         AppendHeaders<[(header::HeaderName, String); 1]>,
         Json<FormsLoginResponse>,
     ),
-    (StatusCode, Json<AuthErrorResponse>),
+    ApiError,
 > {
     if !forms_auth_configured(&state) {
-        return Err((
-            StatusCode::SERVICE_UNAVAILABLE,
-            Json(AuthErrorResponse {
-                error: "forms authentication is not configured".to_string(),
-            }),
+        return Err(ApiError::unavailable(
+            "forms authentication is not configured",
         ));
     }
 
@@ -322,12 +305,7 @@ pub async fn forms_login(
     if !constant_time_eq(request.username.as_bytes(), expected_username.as_bytes())
         || !constant_time_eq(request.password.as_bytes(), expected_password.as_bytes())
     {
-        return Err((
-            StatusCode::UNAUTHORIZED,
-            Json(AuthErrorResponse {
-                error: "invalid credentials".to_string(),
-            }),
-        ));
+        return Err(ApiError::unauthorized("invalid credentials"));
     }
 
     let token = format!("cs_{}", Uuid::new_v4());
@@ -457,25 +435,20 @@ pub async fn list_api_keys(State(_state): State<AppState>) -> Json<ListApiKeysRe
     ),
     responses(
         (status = 200, description = "API key deleted", body = DeleteApiKeyResponse),
-        (status = 404, description = "API key not found", body = AuthErrorResponse)
+        (status = 404, description = "API key not found", body = ApiErrorResponse)
     ),
     tag = "auth"
 )]
 pub async fn delete_api_key(
     State(_state): State<AppState>,
     Path(id): Path<String>,
-) -> Result<Json<DeleteApiKeyResponse>, (StatusCode, Json<AuthErrorResponse>)> {
+) -> Result<Json<DeleteApiKeyResponse>, ApiError> {
     let mut store = api_key_store().write().await;
     let before = store.len();
     store.retain(|record| record.id != id);
 
     if store.len() == before {
-        return Err((
-            StatusCode::NOT_FOUND,
-            Json(AuthErrorResponse {
-                error: format!("API key {} not found", id),
-            }),
-        ));
+        return Err(ApiError::not_found(format!("API key {} not found", id)));
     }
 
     Ok(Json(DeleteApiKeyResponse { deleted: true, id }))
@@ -541,6 +514,11 @@ mod tests {
                     pool.clone(),
                 ),
             ),
+            Arc::new(
+                chorrosion_infrastructure::sqlite_adapters::SqliteTrackFileRepository::new(
+                    pool.clone(),
+                ),
+            ),
             chorrosion_infrastructure::ResponseCache::new(100, 60),
         )
     }
@@ -761,7 +739,7 @@ mod tests {
         )
         .await;
 
-        assert!(matches!(login, Err((StatusCode::UNAUTHORIZED, _))));
+        assert!(matches!(login, Err(ApiError::Unauthorized(_))));
     }
 
     #[tokio::test]
@@ -780,7 +758,7 @@ mod tests {
         )
         .await;
 
-        assert!(matches!(login, Err((StatusCode::SERVICE_UNAVAILABLE, _))));
+        assert!(matches!(login, Err(ApiError::Unavailable(_))));
     }
 
     #[tokio::test]
@@ -851,6 +829,11 @@ mod tests {
                     pool.clone(),
                 ),
             ),
+            Arc::new(
+                chorrosion_infrastructure::sqlite_adapters::SqliteTrackFileRepository::new(
+                    pool.clone(),
+                ),
+            ),
             chorrosion_infrastructure::ResponseCache::new(100, 60),
         )
     }
@@ -1,4 +1,5 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
+use crate::error::{ApiError, ApiErrorResponse};
 use axum::{
     extract::{Query, State},
     http::{header, StatusCode},
@@ -47,11 +48,6 @@ pub struct CalendarResponse {
     pub end: String,
 }
 
-#[derive(Debug, Serialize, Deserialize, ToSchema)]
-pub struct CalendarErrorResponse {
-    pub error: String,
-}
-
 // ============================================================================
 // Helpers
 // ============================================================================
@@ -60,19 +56,14 @@ fn parse_date_param(
     s: Option<&str>,
     fallback: NaiveDate,
     label: &str,
-) -> Result<NaiveDate, (StatusCode, Json<CalendarErrorResponse>)> {
+) -> Result<NaiveDate, ApiError> {
     match s {
         None => Ok(fallback),
         Some(v) => NaiveDate::parse_from_str(v, "%Y-%m-%d").map_err(|_| {
-            (
-                StatusCode::BAD_REQUEST,
-                Json(CalendarErrorResponse {
-                    error: format!(
-                        "invalid date for '{}': '{}' (expected YYYY-MM-DD)",
-                        label, v
-                    ),
-                }),
-            )
+            ApiError::bad_request(format!(
+                "invalid date for '{}': '{}' (expected YYYY-MM-DD)",
+                label, v
+            ))
         }),
     }
 }
@@ -127,15 +118,15 @@ fn ical_fold(line: &str) -> String {
     params(CalendarQuery),
     responses(
         (status = 200, description = "Upcoming releases in the requested date window", body = CalendarResponse),
-        (status = 400, description = "Invalid date parameters", body = CalendarErrorResponse),
-        (status = 500, description = "Internal server error", body = CalendarErrorResponse),
+        (status = 400, description = "Invalid date parameters", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
     ),
     tag = "calendar"
 )]
 pub async fn list_upcoming_releases(
     State(state): State<AppState>,
     Query(query): Query<CalendarQuery>,
-) -> Result<Json<CalendarResponse>, (StatusCode, Json<CalendarErrorResponse>)> {
+) -> Result<Json<CalendarResponse>, ApiError> {
     let today = Utc::now().date_naive();
     let default_end = today + chrono::Duration::days(30);
 
@@ -143,11 +134,8 @@ pub async fn list_upcoming_releases(
     let end = parse_date_param(query.end.as_deref(), default_end, "end")?;
 
     if end < start {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(CalendarErrorResponse {
-                error: "end date must not be before start date".to_string(),
-            }),
+        return Err(ApiError::bad_request(
+            "end date must not be before start date",
         ));
     }
 
@@ -157,14 +145,7 @@ pub async fn list_upcoming_releases(
         .album_repository
         .list_upcoming_releases(start, end, 5000, 0)
         .await
-        .map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(CalendarErrorResponse {
-                    error: format!("failed to list upcoming releases: {e}"),
-                }),
-            )
-        })?;
+        .map_err(|e| ApiError::internal(e, "failed to list upcoming releases"))?;
 
     let mut items = Vec::with_capacity(albums.len());
     let mut artist_cache: HashMap<String, String> = HashMap::new();
@@ -177,14 +158,7 @@ pub async fn list_upcoming_releases(
                     .artist_repository
                     .get_by_id(e.key())
                     .await
-                    .map_err(|e| {
-                        (
-                            StatusCode::INTERNAL_SERVER_ERROR,
-                            Json(CalendarErrorResponse {
-                                error: format!("failed to fetch artist: {e}"),
-                            }),
-                        )
-                    })?
+                    .map_err(|e| ApiError::internal(e, "failed to fetch artist"))?
                     .map(|a| a.name)
                     .unwrap_or_else(|| "Unknown Artist".to_string());
                 e.insert(name).clone()
@@ -198,7 +172,7 @@ pub async fn list_upcoming_releases(
             title: album.title,
             release_date: album
                 .release_date
-                .map(|d| d.format("%Y-%m-%d").to_string())
+                .map(|d| d.to_iso8601())
                 .unwrap_or_default(),
             album_type: album.album_type,
             status: album.status.to_string(),
@@ -218,8 +192,8 @@ pub async fn list_upcoming_releases(
     params(CalendarQuery),
     responses(
         (status = 200, description = "iCal feed of upcoming releases (text/calendar)", content_type = "text/calendar"),
-        (status = 400, description = "Invalid date parameters", body = CalendarErrorResponse),
-        (status = 500, description = "Internal server error", body = CalendarErrorResponse),
+        (status = 400, description = "Invalid date parameters", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
     ),
     tag = "calendar"
 )]
@@ -232,21 +206,15 @@ pub async fn get_ical_feed(
 
     let start = match parse_date_param(query.start.as_deref(), today, "start") {
         Ok(d) => d,
-        Err((status, body)) => return (status, body).into_response(),
+        Err(error) => return error.into_response(),
     };
     let end = match parse_date_param(query.end.as_deref(), default_end, "end") {
         Ok(d) => d,
-        Err((status, body)) => return (status, body).into_response(),
+        Err(error) => return error.into_response(),
     };
 
     if end < start {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(CalendarErrorResponse {
-                error: "end date must not be before start date".to_string(),
-            }),
-        )
-            .into_response();
+        return ApiError::bad_request("end date must not be before start date").into_response();
     }
 
     debug!(target: "api", %start, %end, "generating ical feed");
@@ -258,13 +226,7 @@ pub async fn get_ical_feed(
     {
         Ok(a) => a,
         Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(CalendarErrorResponse {
-                    error: format!("failed to list upcoming releases: {e}"),
-                }),
-            )
-                .into_response();
+            return ApiError::internal(e, "failed to list upcoming releases").into_response();
         }
     };
 
@@ -288,13 +250,7 @@ X-WR-CALNAME:Chorrosion Music Releases\r\n",
                         .map(|a| a.name)
                         .unwrap_or_else(|| "Unknown Artist".to_string()),
                     Err(e) => {
-                        return (
-                            StatusCode::INTERNAL_SERVER_ERROR,
-                            Json(CalendarErrorResponse {
-                                error: format!("failed to fetch artist: {e}"),
-                            }),
-                        )
-                            .into_response();
+                        return ApiError::internal(e, "failed to fetch artist").into_response();
                     }
                 };
                 e.insert(name).clone()
@@ -303,6 +259,7 @@ X-WR-CALNAME:Chorrosion Music Releases\r\n",
 
         let release_str = album
             .release_date
+            .and_then(|d| d.to_naive_date_opt())
             .map(|d| d.format("%Y%m%d").to_string())
             .unwrap_or_default();
 
@@ -391,6 +348,11 @@ mod tests {
                     pool.clone(),
                 ),
             ),
+            Arc::new(
+                chorrosion_infrastructure::sqlite_adapters::SqliteTrackFileRepository::new(
+                    pool.clone(),
+                ),
+            ),
             chorrosion_infrastructure::ResponseCache::new(100, 60),
         );
         (pool, state)
@@ -404,7 +366,7 @@ mod tests {
         let mut a = Album::new(artist_id, title);
         a.status = AlbumStatus::Released;
         a.monitored = true;
-        a.release_date = Some(date);
+        a.release_date = Some(date.into());
         a
     }
 
@@ -507,7 +469,7 @@ mod tests {
             end: None,
         };
         let result = list_upcoming_releases(State(state), Query(query)).await;
-        let (status, _) = result.expect_err("expected error");
+        let status = result.expect_err("expected error").status();
         assert_eq!(status, StatusCode::BAD_REQUEST);
     }
 
@@ -520,7 +482,7 @@ mod tests {
             end: Some("2030-06-01".to_string()),
         };
         let result = list_upcoming_releases(State(state), Query(query)).await;
-        let (status, _) = result.expect_err("expected error");
+        let status = result.expect_err("expected error").status();
         assert_eq!(status, StatusCode::BAD_REQUEST);
     }
 
@@ -1,4 +1,5 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
+use crate::error::{is_concurrency_conflict, ApiError, ApiErrorResponse};
 use axum::{
     extract::{Path, Query, State},
     http::StatusCode,
@@ -85,11 +86,6 @@ pub struct UpdateDownloadClientRequest {
     pub enabled: Option<bool>,
 }
 
-#[derive(Debug, Serialize, ToSchema)]
-pub struct DownloadClientErrorResponse {
-    pub error: String,
-}
-
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct DownloadClientBulkRequest {
     pub action: String,
@@ -168,12 +164,6 @@ pub struct DownloadClientImportResponse {
     pub results: Vec<DownloadClientBulkItemResult>,
 }
 
-#[derive(Debug, Serialize, ToSchema)]
-pub struct DownloadClientImportErrorResponse {
-    pub error: String,
-    pub details: Vec<String>,
-}
-
 fn default_import_conflict_policy() -> ImportConflictPolicy {
     ImportConflictPolicy::Merge
 }
@@ -182,51 +172,33 @@ fn default_true() -> bool {
     true
 }
 
-fn validate_name(name: &str) -> Result<(), (StatusCode, Json<DownloadClientErrorResponse>)> {
+fn validate_name(name: &str) -> Result<(), ApiError> {
     if name.trim().is_empty() {
-        Err((
-            StatusCode::BAD_REQUEST,
-            Json(DownloadClientErrorResponse {
-                error: "name cannot be empty".to_string(),
-            }),
-        ))
+        Err(ApiError::bad_request("name cannot be empty"))
     } else {
         Ok(())
     }
 }
 
-fn validate_base_url(
-    base_url: &str,
-) -> Result<(), (StatusCode, Json<DownloadClientErrorResponse>)> {
+fn validate_base_url(base_url: &str) -> Result<(), ApiError> {
     match url::Url::parse(base_url.trim()) {
         Ok(parsed) if matches!(parsed.scheme(), "http" | "https") && parsed.host().is_some() => {
             Ok(())
         }
-        _ => Err((
-            StatusCode::BAD_REQUEST,
-            Json(DownloadClientErrorResponse {
-                error: "base_url must be a valid http or https URL with a host".to_string(),
-            }),
+        _ => Err(ApiError::bad_request(
+            "base_url must be a valid http or https URL with a host",
         )),
     }
 }
 
-fn normalize_client_type(
-    client_type: &str,
-) -> Result<String, (StatusCode, Json<DownloadClientErrorResponse>)> {
+fn normalize_client_type(client_type: &str) -> Result<String, ApiError> {
     let normalized = client_type.trim().to_lowercase();
     match normalized.as_str() {
         "qbittorrent" | "transmission" | "deluge" | "sabnzbd" | "nzbget" => {
             Ok(normalized)
         }
-        _ => Err((
-            StatusCode::BAD_REQUEST,
-            Json(DownloadClientErrorResponse {
-                error:
-                    "unsupported client_type; supported values: qbittorrent, transmission, deluge, sabnzbd, nzbget"
-                        .to_string(),
-            }),
-        )),
+        _ => Err(ApiError::bad_request("unsupported client_type; supported values: qbittorrent, transmission, deluge, sabnzbd, nzbget"
+                        )),
     }
 }
 
@@ -247,30 +219,22 @@ fn normalize_optional(value: Option<String>) -> Option<String> {
     params(ListDownloadClientsQuery),
     responses(
         (status = 200, description = "List download clients", body = ListDownloadClientsResponse),
-        (status = 400, description = "Invalid request", body = DownloadClientErrorResponse),
-        (status = 500, description = "Internal server error", body = DownloadClientErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "settings"
 )]
 pub async fn list_download_clients(
     State(state): State<AppState>,
     Query(query): Query<ListDownloadClientsQuery>,
-) -> Result<Json<ListDownloadClientsResponse>, (StatusCode, Json<DownloadClientErrorResponse>)> {
+) -> Result<Json<ListDownloadClientsResponse>, ApiError> {
     if !(1..=500).contains(&query.limit) {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(DownloadClientErrorResponse {
-                error: "limit must be between 1 and 500".to_string(),
-            }),
-        ));
+        return Err(ApiError::bad_request("limit must be between 1 and 500"));
     }
 
     if query.offset < 0 {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(DownloadClientErrorResponse {
-                error: "offset must be greater than or equal to 0".to_string(),
-            }),
+        return Err(ApiError::bad_request(
+            "offset must be greater than or equal to 0",
         ));
     }
 
@@ -278,32 +242,13 @@ pub async fn list_download_clients(
         .download_client_definition_repository
         .list(5000, 0)
         .await
-        .map_err(|error| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(DownloadClientErrorResponse {
-                    error: format!("failed to list download clients: {error}"),
-                }),
-            )
-        })?;
+        .map_err(|error| ApiError::internal(error, "failed to list download clients"))?;
 
     let total = all.len() as i64;
-    let offset = usize::try_from(query.offset).map_err(|_| {
-        (
-            StatusCode::BAD_REQUEST,
-            Json(DownloadClientErrorResponse {
-                error: "offset out of valid range".to_string(),
-            }),
-        )
-    })?;
-    let limit = usize::try_from(query.limit).map_err(|_| {
-        (
-            StatusCode::BAD_REQUEST,
-            Json(DownloadClientErrorResponse {
-                error: "limit out of valid range".to_string(),
-            }),
-        )
-    })?;
+    let offset = usize::try_from(query.offset)
+        .map_err(|_| ApiError::bad_request("offset out of valid range"))?;
+    let limit = usize::try_from(query.limit)
+        .map_err(|_| ApiError::bad_request("limit out of valid range"))?;
 
     let items = all
         .into_iter()
@@ -326,8 +271,8 @@ pub async fn list_download_clients(
     params(("id" = String, Path, description = "Download client ID")),
     responses(
         (status = 200, description = "Download client found", body = DownloadClientResponse),
-        (status = 404, description = "Download client not found", body = DownloadClientErrorResponse),
-        (status = 500, description = "Internal server error", body = DownloadClientErrorResponse)
+        (status = 404, description = "Download client not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "settings"
 )]
@@ -343,20 +288,10 @@ pub async fn get_download_client(
         Ok(Some(client)) => {
             (StatusCode::OK, Json(DownloadClientResponse::from(client))).into_response()
         }
-        Ok(None) => (
-            StatusCode::NOT_FOUND,
-            Json(DownloadClientErrorResponse {
-                error: format!("Download client {} not found", id),
-            }),
-        )
-            .into_response(),
-        Err(error) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(DownloadClientErrorResponse {
-                error: format!("failed to fetch download client: {error}"),
-            }),
-        )
-            .into_response(),
+        Ok(None) => {
+            ApiError::not_found(format!("Download client {} not found", id)).into_response()
+        }
+        Err(error) => ApiError::internal(error, "failed to fetch download client").into_response(),
     }
 }
 
@@ -366,9 +301,9 @@ pub async fn get_download_client(
     request_body = CreateDownloadClientRequest,
     responses(
         (status = 201, description = "Download client created", body = DownloadClientResponse),
-        (status = 400, description = "Invalid request", body = DownloadClientErrorResponse),
-        (status = 409, description = "Duplicate name", body = DownloadClientErrorResponse),
-        (status = 500, description = "Internal server error", body = DownloadClientErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 409, description = "Duplicate name", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "settings"
 )]
@@ -393,22 +328,15 @@ pub async fn create_download_client(
         .await
     {
         Ok(Some(_)) => {
-            return (
-                StatusCode::CONFLICT,
-                Json(DownloadClientErrorResponse {
-                    error: format!("Download client '{}' already exists", request.name.trim()),
-                }),
-            )
-                .into_response();
+            return ApiError::conflict(format!(
+                "Download client '{}' already exists",
+                request.name.trim()
+            ))
+            .into_response();
         }
         Ok(None) => {}
         Err(error) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(DownloadClientErrorResponse {
-                    error: format!("failed to validate download client name uniqueness: {error}"),
-                }),
-            )
+            return ApiError::internal(error, "failed to validate download client name uniqueness")
                 .into_response();
         }
     }
@@ -433,25 +361,14 @@ pub async fn create_download_client(
         Err(error) => {
             if let Some(sqlx::Error::Database(db_err)) = error.downcast_ref::<sqlx::Error>() {
                 if db_err.is_unique_violation() {
-                    return (
-                        StatusCode::CONFLICT,
-                        Json(DownloadClientErrorResponse {
-                            error: format!(
-                                "Download client '{}' already exists",
-                                request.name.trim()
-                            ),
-                        }),
-                    )
-                        .into_response();
+                    return ApiError::conflict(format!(
+                        "Download client '{}' already exists",
+                        request.name.trim()
+                    ))
+                    .into_response();
                 }
             }
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(DownloadClientErrorResponse {
-                    error: format!("failed to create download client: {error}"),
-                }),
-            )
-                .into_response()
+            ApiError::internal(error, "failed to create download client").into_response()
         }
     }
 }
@@ -463,10 +380,10 @@ pub async fn create_download_client(
     request_body = UpdateDownloadClientRequest,
     responses(
         (status = 200, description = "Download client updated", body = DownloadClientResponse),
-        (status = 400, description = "Invalid request", body = DownloadClientErrorResponse),
-        (status = 404, description = "Download client not found", body = DownloadClientErrorResponse),
-        (status = 409, description = "Duplicate name", body = DownloadClientErrorResponse),
-        (status = 500, description = "Internal server error", body = DownloadClientErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 404, description = "Download client not found", body = ApiErrorResponse),
+        (status = 409, description = "Duplicate name or concurrent modification", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "settings"
 )]
@@ -482,22 +399,11 @@ pub async fn update_download_client(
     {
         Ok(Some(client)) => client,
         Ok(None) => {
-            return (
-                StatusCode::NOT_FOUND,
-                Json(DownloadClientErrorResponse {
-                    error: format!("Download client {} not found", id),
-                }),
-            )
+            return ApiError::not_found(format!("Download client {} not found", id))
                 .into_response();
         }
         Err(error) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(DownloadClientErrorResponse {
-                    error: format!("failed to fetch download client: {error}"),
-                }),
-            )
-                .into_response();
+            return ApiError::internal(error, "failed to fetch download client").into_response();
         }
     };
 
@@ -512,27 +418,21 @@ pub async fn update_download_client(
             .await
         {
             Ok(Some(existing)) if existing.id != client.id => {
-                return (
-                    StatusCode::CONFLICT,
-                    Json(DownloadClientErrorResponse {
-                        error: format!("Download client '{}' already exists", name.trim()),
-                    }),
-                )
-                    .into_response();
+                return ApiError::conflict(format!(
+                    "Download client '{}' already exists",
+                    name.trim()
+                ))
+                .into_response();
             }
             Ok(_) => {
                 client.name = name.trim().to_string();
             }
             Err(error) => {
-                return (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(DownloadClientErrorResponse {
-                        error: format!(
-                            "failed to validate download client name uniqueness: {error}"
-                        ),
-                    }),
+                return ApiError::internal(
+                    error,
+                    "failed to validate download client name uniqueness",
                 )
-                    .into_response();
+                .into_response();
             }
         }
     }
@@ -568,8 +468,6 @@ pub async fn update_download_client(
         client.enabled = enabled;
     }
 
-    client.updated_at = Utc::now();
-
     match state
         .download_client_definition_repository
         .update(client)
@@ -578,13 +476,10 @@ pub async fn update_download_client(
         Ok(updated) => {
             (StatusCode::OK, Json(DownloadClientResponse::from(updated))).into_response()
         }
-        Err(error) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(DownloadClientErrorResponse {
-                error: format!("failed to update download client: {error}"),
-            }),
-        )
-            .into_response(),
+        Err(error) if is_concurrency_conflict(&error) => {
+            ApiError::conflict(error.to_string()).into_response()
+        }
+        Err(error) => ApiError::internal(error, "failed to update download client").into_response(),
     }
 }
 
@@ -594,8 +489,8 @@ pub async fn update_download_client(
     params(("id" = String, Path, description = "Download client ID")),
     responses(
         (status = 204, description = "Download client deleted"),
-        (status = 404, description = "Download client not found", body = DownloadClientErrorResponse),
-        (status = 500, description = "Internal server error", body = DownloadClientErrorResponse)
+        (status = 404, description = "Download client not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "settings"
 )]
@@ -623,43 +518,22 @@ pub async fn delete_download_client(
                         .get_by_id(&id)
                         .await
                     {
-                        Ok(None) => (
-                            StatusCode::NOT_FOUND,
-                            Json(DownloadClientErrorResponse {
-                                error: format!("Download client {} not found", id),
-                            }),
-                        )
-                            .into_response(),
-                        Ok(Some(_)) | Err(_) => (
-                            StatusCode::INTERNAL_SERVER_ERROR,
-                            Json(DownloadClientErrorResponse {
-                                error: format!(
-                                    "failed to delete download client {}: {}",
-                                    id, delete_error
-                                ),
-                            }),
-                        )
-                            .into_response(),
+                        Ok(None) => {
+                            ApiError::not_found(format!("Download client {} not found", id))
+                                .into_response()
+                        }
+                        Ok(Some(_)) | Err(_) => {
+                            ApiError::internal(delete_error, "failed to delete download client")
+                                .into_response()
+                        }
                     }
                 }
             }
         }
-        Ok(None) => (
-            StatusCode::NOT_FOUND,
-            Json(DownloadClientErrorResponse {
-                error: format!("Download client {} not found", id),
-            }),
-        )
-            .into_response(),
-        Err(error) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(DownloadClientErrorResponse {
-                error: format!(
-                    "failed to fetch download client {} before delete: {}",
-                    id, error
-                ),
-            }),
-        )
+        Ok(None) => {
+            ApiError::not_found(format!("Download client {} not found", id)).into_response()
+        }
+        Err(error) => ApiError::internal(error, "failed to fetch download client before delete")
             .into_response(),
     }
 }
@@ -671,7 +545,7 @@ pub async fn delete_download_client(
     responses(
         (status = 200, description = "Bulk action completed", body = DownloadClientBulkResponse),
         (status = 207, description = "Bulk action partially succeeded", body = DownloadClientBulkResponse),
-        (status = 400, description = "Invalid request", body = DownloadClientErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse)
     ),
     tag = "settings"
 )]
@@ -680,22 +554,11 @@ pub async fn bulk_download_clients(
     Json(request): Json<DownloadClientBulkRequest>,
 ) -> impl IntoResponse {
     if request.ids.is_empty() {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(DownloadClientErrorResponse {
-                error: "ids must contain at least one item".to_string(),
-            }),
-        )
-            .into_response();
+        return ApiError::bad_request("ids must contain at least one item").into_response();
     }
 
     if !matches!(request.action.as_str(), "enable" | "disable" | "delete") {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(DownloadClientErrorResponse {
-                error: "action must be one of: enable, disable, delete".to_string(),
-            }),
-        )
+        return ApiError::bad_request("action must be one of: enable, disable, delete")
             .into_response();
     }
 
@@ -728,7 +591,6 @@ pub async fn bulk_download_clients(
                 match fetch_result {
                     Ok(Some(mut client)) => {
                         client.enabled = enabled;
-                        client.updated_at = Utc::now();
                         let update_result = state
                             .download_client_definition_repository
                             .update(client)
@@ -781,7 +643,7 @@ pub async fn bulk_download_clients(
     path = "/api/v1/settings/download-clients/export",
     responses(
         (status = 200, description = "Export download clients", body = DownloadClientExportEnvelope),
-        (status = 500, description = "Internal server error", body = DownloadClientErrorResponse)
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "settings"
 )]
@@ -810,13 +672,9 @@ pub async fn export_download_clients(State(state): State<AppState>) -> impl Into
             };
             (StatusCode::OK, Json(exported)).into_response()
         }
-        Err(error) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(DownloadClientErrorResponse {
-                error: format!("failed to export download clients: {error}"),
-            }),
-        )
-            .into_response(),
+        Err(error) => {
+            ApiError::internal(error, "failed to export download clients").into_response()
+        }
     }
 }
 
@@ -827,8 +685,8 @@ pub async fn export_download_clients(State(state): State<AppState>) -> impl Into
     request_body = DownloadClientImportRequest,
     responses(
         (status = 200, description = "Import processed", body = DownloadClientImportResponse),
-        (status = 400, description = "Invalid request", body = DownloadClientImportErrorResponse),
-        (status = 500, description = "Internal server error", body = DownloadClientErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "settings"
 )]
@@ -838,37 +696,31 @@ pub async fn import_download_clients(
     Json(request): Json<DownloadClientImportRequest>,
 ) -> impl IntoResponse {
     if request.version.trim() != "1" {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(DownloadClientImportErrorResponse {
-                error: "unsupported import version".to_string(),
-                details: vec!["version must be '1'".to_string()],
-            }),
+        return ApiError::invalid_payload(
+            "unsupported import version",
+            [("version".to_string(), "must be '1'".to_string())],
         )
-            .into_response();
+        .into_response();
     }
 
     let mut validation_errors = Vec::new();
     for (idx, item) in request.items.iter().enumerate() {
         if item.name.trim().is_empty() {
-            validation_errors.push(format!("items[{idx}].name cannot be empty"));
+            validation_errors.push((format!("items[{idx}].name"), "cannot be empty".to_string()));
         }
         if validate_base_url(&item.base_url).is_err() {
-            validation_errors.push(format!("items[{idx}].base_url is invalid"));
+            validation_errors.push((format!("items[{idx}].base_url"), "is invalid".to_string()));
         }
         if normalize_client_type(&item.client_type).is_err() {
-            validation_errors.push(format!("items[{idx}].client_type is not supported"));
+            validation_errors.push((
+                format!("items[{idx}].client_type"),
+                "is not supported".to_string(),
+            ));
         }
     }
 
     if !validation_errors.is_empty() {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(DownloadClientImportErrorResponse {
-                error: "invalid import payload".to_string(),
-                details: validation_errors,
-            }),
-        )
+        return ApiError::invalid_payload("invalid import payload", validation_errors)
             .into_response();
     }
 
@@ -879,12 +731,7 @@ pub async fn import_download_clients(
     {
         Ok(existing) => existing,
         Err(error) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(DownloadClientErrorResponse {
-                    error: format!("failed to read existing download clients: {error}"),
-                }),
-            )
+            return ApiError::internal(error, "failed to read existing download clients")
                 .into_response()
         }
     };
@@ -969,7 +816,6 @@ pub async fn import_download_clients(
             existing_item.password_encrypted = normalize_optional(item.password.clone());
             existing_item.category = normalize_optional(item.category.clone());
             existing_item.enabled = item.enabled;
-            existing_item.updated_at = Utc::now();
 
             let update_result = state
                 .download_client_definition_repository
@@ -1090,6 +936,11 @@ mod tests {
                     pool.clone(),
                 ),
             ),
+            Arc::new(
+                chorrosion_infrastructure::sqlite_adapters::SqliteTrackFileRepository::new(
+                    pool.clone(),
+                ),
+            ),
             chorrosion_infrastructure::ResponseCache::new(100, 60),
         )
     }
@@ -1128,7 +979,7 @@ mod tests {
         .await;
 
         assert!(result.is_err());
-        let (status, _) = result.unwrap_err();
+        let status = result.unwrap_err().status();
         assert_eq!(status, StatusCode::BAD_REQUEST);
     }
 
@@ -1146,7 +997,7 @@ mod tests {
         .await;
 
         assert!(result.is_err());
-        let (status, _) = result.unwrap_err();
+        let status = result.unwrap_err().status();
         assert_eq!(status, StatusCode::BAD_REQUEST);
     }
 
@@ -1368,7 +1219,10 @@ mod tests {
         let error: serde_json::Value =
             serde_json::from_slice(&body).expect("deserialize import error");
         assert_eq!(error["error"], "unsupported import version");
-        assert_eq!(error["details"], serde_json::json!(["version must be '1'"]));
+        assert_eq!(
+            error["details"],
+            serde_json::json!([{ "field": "version", "message": "must be '1'" }])
+        );
     }
 
     #[tokio::test]
@@ -1402,13 +1256,12 @@ mod tests {
         let error: serde_json::Value =
             serde_json::from_slice(&body).expect("deserialize import error");
         assert_eq!(error["error"], "invalid import payload");
-        assert!(
-            error["details"]
-                .as_array()
-                .expect("details array")
-                .iter()
-                .any(|detail| detail == "items[0].client_type is not supported")
-        );
+        assert!(error["details"]
+            .as_array()
+            .expect("details array")
+            .iter()
+            .any(|detail| detail["field"] == "items[0].client_type"
+                && detail["message"] == "is not supported"));
     }
 
     #[tokio::test]
@@ -1442,13 +1295,12 @@ mod tests {
         let error: serde_json::Value =
             serde_json::from_slice(&body).expect("deserialize import error");
         assert_eq!(error["error"], "invalid import payload");
-        assert!(
-            error["details"]
-                .as_array()
-                .expect("details array")
-                .iter()
-                .any(|detail| detail == "items[0].name cannot be empty")
-        );
+        assert!(error["details"]
+            .as_array()
+            .expect("details array")
+            .iter()
+            .any(|detail| detail["field"] == "items[0].name"
+                && detail["message"] == "cannot be empty"));
     }
 
     #[tokio::test]
@@ -1482,13 +1334,12 @@ mod tests {
         let error: serde_json::Value =
             serde_json::from_slice(&body).expect("deserialize import error");
         assert_eq!(error["error"], "invalid import payload");
-        assert!(
-            error["details"]
-                .as_array()
-                .expect("details array")
-                .iter()
-                .any(|detail| detail == "items[0].base_url is invalid")
-        );
+        assert!(error["details"]
+            .as_array()
+            .expect("details array")
+            .iter()
+            .any(|detail| detail["field"] == "items[0].base_url"
+                && detail["message"] == "is invalid"));
     }
 
     #[tokio::test]
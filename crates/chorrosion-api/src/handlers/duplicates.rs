@@ -1,9 +1,9 @@
+use crate::error::{ApiError, ApiErrorResponse};
 use axum::{
     extract::{Path, Query, State},
-    http::StatusCode,
     Json,
 };
-use chorrosion_application::AppState;
+use chorrosion_application::{AppState, DuplicateCluster, DuplicateReportService};
 use serde::{Deserialize, Serialize};
 use tracing::{debug, error};
 use utoipa::{IntoParams, ToSchema};
@@ -20,6 +20,9 @@ pub struct ListDuplicatesQuery {
     /// Number of groups to skip
     #[serde(default)]
     pub offset: i64,
+    /// Include the files of each fingerprint group, best quality first
+    #[serde(default)]
+    pub clusters: bool,
 }
 
 fn default_method() -> String {
@@ -52,6 +55,9 @@ pub struct ListDuplicatesResponse {
     pub limit: i64,
     pub offset: i64,
     pub method: String,
+    /// Present when `clusters=true` was requested with the fingerprint method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clusters: Option<Vec<DuplicateClusterResponse>>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -68,6 +74,23 @@ pub struct DuplicateFileResponse {
     pub created_at: String,
 }
 
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DuplicateClusterFileResponse {
+    #[serde(flatten)]
+    pub file: DuplicateFileResponse,
+    /// Quality parsed from the filename
+    pub parsed_quality: String,
+    /// Bitrate parsed from the filename, falling back to the scanned bitrate
+    pub parsed_bitrate_kbps: Option<u32>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DuplicateClusterResponse {
+    pub fingerprint_hash: String,
+    /// Files sharing the fingerprint, the best copy to keep first
+    pub files: Vec<DuplicateClusterFileResponse>,
+}
+
 #[derive(Debug, Serialize, ToSchema)]
 pub struct DuplicateGroupDetailResponse {
     pub key: String,
@@ -88,24 +111,6 @@ pub struct ResolveDuplicateResponse {
     pub message: String,
 }
 
-#[derive(Debug, Serialize, ToSchema)]
-#[schema(as = DuplicateErrorResponse)]
-pub struct ErrorResponse {
-    pub error: String,
-}
-
-fn error_response(
-    status: StatusCode,
-    message: impl Into<String>,
-) -> (StatusCode, Json<ErrorResponse>) {
-    (
-        status,
-        Json(ErrorResponse {
-            error: message.into(),
-        }),
-    )
-}
-
 fn group_to_response(group: chorrosion_application::DuplicateGroup) -> DuplicateGroupResponse {
     let method = match group.method {
         chorrosion_application::DuplicateDetectionMethod::FingerprintHash => "fingerprint",
@@ -120,6 +125,21 @@ fn group_to_response(group: chorrosion_application::DuplicateGroup) -> Duplicate
     }
 }
 
+fn cluster_to_response(cluster: DuplicateCluster) -> DuplicateClusterResponse {
+    DuplicateClusterResponse {
+        fingerprint_hash: cluster.fingerprint_hash,
+        files: cluster
+            .files
+            .into_iter()
+            .map(|file| DuplicateClusterFileResponse {
+                parsed_quality: file.parsed_quality.as_str().to_string(),
+                parsed_bitrate_kbps: file.parsed_bitrate_kbps,
+                file: file_to_response(file.file),
+            })
+            .collect(),
+    }
+}
+
 fn file_to_response(file: chorrosion_application::DuplicateFileDetail) -> DuplicateFileResponse {
     DuplicateFileResponse {
         track_file_id: file.track_file_id.to_string(),
@@ -141,24 +161,20 @@ fn file_to_response(file: chorrosion_application::DuplicateFileDetail) -> Duplic
     params(ListDuplicatesQuery),
     responses(
         (status = 200, description = "List duplicate groups", body = ListDuplicatesResponse),
-        (status = 400, description = "Invalid request", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "duplicates"
 )]
 pub async fn list_duplicate_groups(
     State(state): State<AppState>,
     Query(query): Query<ListDuplicatesQuery>,
-) -> Result<Json<ListDuplicatesResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<ListDuplicatesResponse>, ApiError> {
     if !(1..=500).contains(&query.limit) {
-        return Err(error_response(
-            StatusCode::BAD_REQUEST,
-            "limit must be between 1 and 500",
-        ));
+        return Err(ApiError::bad_request("limit must be between 1 and 500"));
     }
     if query.offset < 0 {
-        return Err(error_response(
-            StatusCode::BAD_REQUEST,
+        return Err(ApiError::bad_request(
             "offset must be greater than or equal to 0",
         ));
     }
@@ -173,7 +189,7 @@ pub async fn list_duplicate_groups(
                 .await
                 .map_err(|err| {
                     error!(target: "api", error = %err, "failed to count fingerprint duplicate groups");
-                    error_response(StatusCode::INTERNAL_SERVER_ERROR, "failed to list duplicates")
+                    ApiError::Internal(anyhow::anyhow!("failed to list duplicates"))
                 })?;
 
             let items = state
@@ -182,18 +198,32 @@ pub async fn list_duplicate_groups(
                 .await
                 .map_err(|err| {
                     error!(target: "api", error = %err, "failed to list fingerprint duplicate groups");
-                    error_response(StatusCode::INTERNAL_SERVER_ERROR, "failed to list duplicates")
+                    ApiError::Internal(anyhow::anyhow!("failed to list duplicates"))
                 })?
                 .into_iter()
                 .map(group_to_response)
                 .collect();
 
+            let clusters = if query.clusters {
+                let clusters = DuplicateReportService::new(state.duplicate_repository.clone())
+                    .fingerprint_clusters(query.limit, query.offset)
+                    .await
+                    .map_err(|err| {
+                        error!(target: "api", error = %err, "failed to build fingerprint duplicate clusters");
+                        ApiError::Internal(anyhow::anyhow!("failed to list duplicates"))
+                    })?;
+                Some(clusters.into_iter().map(cluster_to_response).collect())
+            } else {
+                None
+            };
+
             Ok(Json(ListDuplicatesResponse {
                 items,
                 total,
                 limit: query.limit,
                 offset: query.offset,
                 method: "fingerprint".to_string(),
+                clusters,
             }))
         }
         "hash" => {
@@ -203,10 +233,7 @@ pub async fn list_duplicate_groups(
                 .await
                 .map_err(|err| {
                     error!(target: "api", error = %err, "failed to count hash duplicate groups");
-                    error_response(
-                        StatusCode::INTERNAL_SERVER_ERROR,
-                        "failed to list duplicates",
-                    )
+                    ApiError::Internal(anyhow::anyhow!("failed to list duplicates"))
                 })?;
 
             let items = state
@@ -215,10 +242,7 @@ pub async fn list_duplicate_groups(
                 .await
                 .map_err(|err| {
                     error!(target: "api", error = %err, "failed to list hash duplicate groups");
-                    error_response(
-                        StatusCode::INTERNAL_SERVER_ERROR,
-                        "failed to list duplicates",
-                    )
+                    ApiError::Internal(anyhow::anyhow!("failed to list duplicates"))
                 })?
                 .into_iter()
                 .map(group_to_response)
@@ -230,10 +254,10 @@ pub async fn list_duplicate_groups(
                 limit: query.limit,
                 offset: query.offset,
                 method: "hash".to_string(),
+                clusters: None,
             }))
         }
-        _ => Err(error_response(
-            StatusCode::BAD_REQUEST,
+        _ => Err(ApiError::bad_request(
             "method must be 'fingerprint' or 'hash'",
         )),
     }
@@ -248,9 +272,9 @@ pub async fn list_duplicate_groups(
     ),
     responses(
         (status = 200, description = "Duplicate group detail", body = DuplicateGroupDetailResponse),
-        (status = 400, description = "Invalid request", body = ErrorResponse),
-        (status = 404, description = "Not found", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 404, description = "Not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "duplicates"
 )]
@@ -258,7 +282,7 @@ pub async fn get_duplicate_group(
     State(state): State<AppState>,
     Path(key): Path<String>,
     Query(query): Query<DuplicateGroupQuery>,
-) -> Result<Json<DuplicateGroupDetailResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<DuplicateGroupDetailResponse>, ApiError> {
     debug!(target: "api", key = %key, method = %query.method, "getting duplicate group detail");
 
     match query.method.as_str() {
@@ -269,17 +293,11 @@ pub async fn get_duplicate_group(
                 .await
                 .map_err(|err| {
                     error!(target: "api", error = %err, "failed to get files by fingerprint");
-                    error_response(
-                        StatusCode::INTERNAL_SERVER_ERROR,
-                        "failed to get duplicate group",
-                    )
+                    ApiError::Internal(anyhow::anyhow!("failed to get duplicate group"))
                 })?;
 
             if files.is_empty() {
-                return Err(error_response(
-                    StatusCode::NOT_FOUND,
-                    "duplicate group not found",
-                ));
+                return Err(ApiError::not_found("duplicate group not found"));
             }
 
             Ok(Json(DuplicateGroupDetailResponse {
@@ -295,17 +313,11 @@ pub async fn get_duplicate_group(
                 .await
                 .map_err(|err| {
                     error!(target: "api", error = %err, "failed to get files by hash");
-                    error_response(
-                        StatusCode::INTERNAL_SERVER_ERROR,
-                        "failed to get duplicate group",
-                    )
+                    ApiError::Internal(anyhow::anyhow!("failed to get duplicate group"))
                 })?;
 
             if files.is_empty() {
-                return Err(error_response(
-                    StatusCode::NOT_FOUND,
-                    "duplicate group not found",
-                ));
+                return Err(ApiError::not_found("duplicate group not found"));
             }
 
             Ok(Json(DuplicateGroupDetailResponse {
@@ -314,8 +326,7 @@ pub async fn get_duplicate_group(
                 files: files.into_iter().map(file_to_response).collect(),
             }))
         }
-        _ => Err(error_response(
-            StatusCode::BAD_REQUEST,
+        _ => Err(ApiError::bad_request(
             "method must be 'fingerprint' or 'hash'",
         )),
     }
@@ -331,9 +342,9 @@ pub async fn get_duplicate_group(
     request_body = ResolveDuplicateRequest,
     responses(
         (status = 200, description = "Duplicate resolved", body = ResolveDuplicateResponse),
-        (status = 400, description = "Invalid request", body = ErrorResponse),
-        (status = 404, description = "Track file not found", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 404, description = "Track file not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "duplicates"
 )]
@@ -342,16 +353,13 @@ pub async fn resolve_duplicate_group(
     Path(key): Path<String>,
     Query(query): Query<DuplicateGroupQuery>,
     Json(payload): Json<ResolveDuplicateRequest>,
-) -> Result<Json<ResolveDuplicateResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<ResolveDuplicateResponse>, ApiError> {
     debug!(target: "api", key = %key, method = %query.method, action = %payload.action, "resolving duplicate group");
 
     match payload.action.as_str() {
         "delete_specific" => {
             let track_file_id_raw = payload.track_file_id.ok_or_else(|| {
-                error_response(
-                    StatusCode::BAD_REQUEST,
-                    "track_file_id is required for delete_specific action",
-                )
+                ApiError::bad_request("track_file_id is required for delete_specific action")
             })?;
             let track_file_id = Uuid::parse_str(&track_file_id_raw).map_err(|err| {
                 error!(
@@ -360,10 +368,7 @@ pub async fn resolve_duplicate_group(
                     error = %err,
                     "invalid track_file_id format for duplicate resolution"
                 );
-                error_response(
-                    StatusCode::BAD_REQUEST,
-                    "track_file_id must be a valid UUID",
-                )
+                ApiError::bad_request("track_file_id must be a valid UUID")
             })?;
 
             let group_files = match query.method.as_str() {
@@ -377,10 +382,7 @@ pub async fn resolve_duplicate_group(
                             error = %err,
                             "failed to get duplicate group by fingerprint for resolution"
                         );
-                        error_response(
-                            StatusCode::INTERNAL_SERVER_ERROR,
-                            "failed to resolve duplicate group",
-                        )
+                        ApiError::Internal(anyhow::anyhow!("failed to resolve duplicate group"))
                     })?,
                 "hash" => state
                     .duplicate_repository
@@ -392,24 +394,17 @@ pub async fn resolve_duplicate_group(
                             error = %err,
                             "failed to get duplicate group by hash for resolution"
                         );
-                        error_response(
-                            StatusCode::INTERNAL_SERVER_ERROR,
-                            "failed to resolve duplicate group",
-                        )
+                        ApiError::Internal(anyhow::anyhow!("failed to resolve duplicate group"))
                     })?,
                 _ => {
-                    return Err(error_response(
-                        StatusCode::BAD_REQUEST,
+                    return Err(ApiError::bad_request(
                         "method must be 'fingerprint' or 'hash'",
                     ));
                 }
             };
 
             if group_files.is_empty() {
-                return Err(error_response(
-                    StatusCode::NOT_FOUND,
-                    "duplicate group not found",
-                ));
+                return Err(ApiError::not_found("duplicate group not found"));
             }
 
             let track_file_id_str = track_file_id.to_string();
@@ -417,8 +412,7 @@ pub async fn resolve_duplicate_group(
                 .iter()
                 .any(|file| file.track_file_id.to_string() == track_file_id_str)
             {
-                return Err(error_response(
-                    StatusCode::NOT_FOUND,
+                return Err(ApiError::not_found(
                     "track file not found in duplicate group",
                 ));
             }
@@ -429,27 +423,18 @@ pub async fn resolve_duplicate_group(
                 .await
                 .map_err(|err| {
                     error!(target: "api", error = %err, "failed to delete track file");
-                    error_response(
-                        StatusCode::INTERNAL_SERVER_ERROR,
-                        "failed to delete track file",
-                    )
+                    ApiError::Internal(anyhow::anyhow!("failed to delete track file"))
                 })?;
 
             if !deleted {
-                return Err(error_response(
-                    StatusCode::NOT_FOUND,
-                    "track file not found",
-                ));
+                return Err(ApiError::not_found("track file not found"));
             }
 
             Ok(Json(ResolveDuplicateResponse {
                 message: format!("track file {} deleted", track_file_id_str),
             }))
         }
-        _ => Err(error_response(
-            StatusCode::BAD_REQUEST,
-            "action must be 'delete_specific'",
-        )),
+        _ => Err(ApiError::bad_request("action must be 'delete_specific'")),
     }
 }
 
@@ -457,6 +442,7 @@ pub async fn resolve_duplicate_group(
 mod tests {
     use super::*;
     use axum::extract::{Query, State};
+    use axum::http::StatusCode;
     use chorrosion_config::AppConfig;
     use std::sync::Arc;
 
@@ -465,7 +451,7 @@ mod tests {
         SqliteDuplicateRepository, SqliteIndexerDefinitionRepository,
         SqliteMetadataProfileRepository, SqliteQualityProfileRepository,
         SqliteSmartPlaylistRepository, SqliteTagRepository, SqliteTaggedEntityRepository,
-        SqliteTrackRepository,
+        SqliteTrackFileRepository, SqliteTrackRepository,
     };
 
     async fn make_test_state() -> AppState {
@@ -490,6 +476,7 @@ mod tests {
             Arc::new(SqliteTaggedEntityRepository::new(pool.clone())),
             Arc::new(SqliteSmartPlaylistRepository::new(pool.clone())),
             Arc::new(SqliteDuplicateRepository::new(pool.clone())),
+            Arc::new(SqliteTrackFileRepository::new(pool.clone())),
             chorrosion_infrastructure::ResponseCache::new(100, 60),
         )
     }
@@ -516,6 +503,7 @@ mod tests {
             Arc::new(SqliteTaggedEntityRepository::new(pool.clone())),
             Arc::new(SqliteSmartPlaylistRepository::new(pool.clone())),
             Arc::new(SqliteDuplicateRepository::new(pool.clone())),
+            Arc::new(SqliteTrackFileRepository::new(pool.clone())),
             chorrosion_infrastructure::ResponseCache::new(100, 60),
         );
 
@@ -532,6 +520,7 @@ mod tests {
                 method: "fingerprint".to_string(),
                 limit: 50,
                 offset: 0,
+                clusters: false,
             }),
         )
         .await
@@ -552,6 +541,7 @@ mod tests {
                 method: "hash".to_string(),
                 limit: 50,
                 offset: 0,
+                clusters: false,
             }),
         )
         .await
@@ -572,12 +562,13 @@ mod tests {
                 method: "fingerprint".to_string(),
                 limit: 0,
                 offset: 0,
+                clusters: false,
             }),
         )
         .await;
 
         assert!(result.is_err());
-        let (status, _) = result.unwrap_err();
+        let status = result.unwrap_err().status();
         assert_eq!(status, StatusCode::BAD_REQUEST);
     }
 
@@ -591,12 +582,13 @@ mod tests {
                 method: "fingerprint".to_string(),
                 limit: 50,
                 offset: -1,
+                clusters: false,
             }),
         )
         .await;
 
         assert!(result.is_err());
-        let (status, _) = result.unwrap_err();
+        let status = result.unwrap_err().status();
         assert_eq!(status, StatusCode::BAD_REQUEST);
     }
 
@@ -610,12 +602,13 @@ mod tests {
                 method: "invalid".to_string(),
                 limit: 50,
                 offset: 0,
+                clusters: false,
             }),
         )
         .await;
 
         assert!(result.is_err());
-        let (status, _) = result.unwrap_err();
+        let status = result.unwrap_err().status();
         assert_eq!(status, StatusCode::BAD_REQUEST);
     }
 
@@ -633,7 +626,7 @@ mod tests {
         .await;
 
         assert!(result.is_err());
-        let (status, _) = result.unwrap_err();
+        let status = result.unwrap_err().status();
         assert_eq!(status, StatusCode::NOT_FOUND);
     }
 
@@ -651,7 +644,7 @@ mod tests {
         .await;
 
         assert!(result.is_err());
-        let (status, _) = result.unwrap_err();
+        let status = result.unwrap_err().status();
         assert_eq!(status, StatusCode::BAD_REQUEST);
     }
 
@@ -673,7 +666,7 @@ mod tests {
         .await;
 
         assert!(result.is_err());
-        let (status, _) = result.unwrap_err();
+        let status = result.unwrap_err().status();
         assert_eq!(status, StatusCode::BAD_REQUEST);
     }
 
@@ -695,7 +688,7 @@ mod tests {
         .await;
 
         assert!(result.is_err());
-        let (status, _) = result.unwrap_err();
+        let status = result.unwrap_err().status();
         assert_eq!(status, StatusCode::NOT_FOUND);
     }
 
@@ -717,7 +710,7 @@ mod tests {
         .await;
 
         assert!(result.is_err());
-        let (status, _) = result.unwrap_err();
+        let status = result.unwrap_err().status();
         assert_eq!(status, StatusCode::BAD_REQUEST);
     }
 
@@ -739,7 +732,7 @@ mod tests {
         .await;
 
         assert!(result.is_err());
-        let (status, _) = result.unwrap_err();
+        let status = result.unwrap_err().status();
         assert_eq!(status, StatusCode::BAD_REQUEST);
     }
 
@@ -885,6 +878,7 @@ mod tests {
                 method: "hash".to_string(),
                 limit: 50,
                 offset: 0,
+                clusters: false,
             }),
         )
         .await
@@ -901,6 +895,98 @@ mod tests {
         assert_eq!(result.items[1].file_count, 2);
     }
 
+    #[tokio::test]
+    async fn list_duplicates_includes_fingerprint_clusters_when_requested() {
+        let (state, pool) = make_test_state_with_pool().await;
+
+        sqlx::query(
+            r#"
+            INSERT INTO artists (id, name, monitored, status, created_at, updated_at)
+            VALUES ('11111111-1111-1111-1111-111111111111', 'Test Artist', 1, 'continuing', CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .expect("insert artist");
+        sqlx::query(
+            r#"
+            INSERT INTO albums (id, artist_id, title, monitored, status, created_at, updated_at)
+            VALUES ('22222222-2222-2222-2222-222222222222', '11111111-1111-1111-1111-111111111111', 'Test Album', 1, 'wanted', CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .expect("insert album");
+
+        let files = [
+            (
+                "33333333-3333-3333-3333-333333333333",
+                "/music/Song [MP3 320kbps].mp3",
+                "fp_shared",
+            ),
+            (
+                "44444444-4444-4444-4444-444444444444",
+                "/music/Song [FLAC].flac",
+                "fp_shared",
+            ),
+            (
+                "55555555-5555-5555-5555-555555555555",
+                "/music/Other.flac",
+                "fp_unique",
+            ),
+        ];
+        for (number, (id, path, fingerprint)) in files.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO tracks (id, album_id, artist_id, title, track_number, has_file, monitored, created_at, updated_at)
+                VALUES (?, '22222222-2222-2222-2222-222222222222', '11111111-1111-1111-1111-111111111111', ?, ?, 1, 1, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
+                "#,
+            )
+            .bind(id)
+            .bind(path)
+            .bind(number as i64 + 1)
+            .execute(&pool)
+            .await
+            .expect("insert track");
+            sqlx::query(
+                r#"
+                INSERT INTO track_files (id, track_id, path, size_bytes, fingerprint_hash, created_at)
+                VALUES (?, ?, ?, 100, ?, CURRENT_TIMESTAMP)
+                "#,
+            )
+            .bind(Uuid::new_v4().to_string())
+            .bind(id)
+            .bind(path)
+            .bind(fingerprint)
+            .execute(&pool)
+            .await
+            .expect("insert track file");
+        }
+
+        let result = list_duplicate_groups(
+            State(state),
+            Query(ListDuplicatesQuery {
+                method: "fingerprint".to_string(),
+                limit: 50,
+                offset: 0,
+                clusters: true,
+            }),
+        )
+        .await
+        .expect("list fingerprint duplicate clusters should succeed");
+
+        let clusters = result.clusters.as_ref().expect("clusters requested");
+        assert_eq!(clusters.len(), 1);
+        assert_eq!(clusters[0].fingerprint_hash, "fp_shared");
+        let qualities: Vec<_> = clusters[0]
+            .files
+            .iter()
+            .map(|file| file.parsed_quality.as_str())
+            .collect();
+        assert_eq!(qualities, vec!["flac", "mp3"]);
+        assert_eq!(clusters[0].files[1].parsed_bitrate_kbps, Some(320));
+    }
+
     #[tokio::test]
     async fn resolve_duplicate_rejects_invalid_track_file_id_format() {
         let state = make_test_state().await;
@@ -919,7 +1005,7 @@ mod tests {
         .await;
 
         assert!(result.is_err());
-        let (status, _) = result.unwrap_err();
+        let status = result.unwrap_err().status();
         assert_eq!(status, StatusCode::BAD_REQUEST);
     }
 }
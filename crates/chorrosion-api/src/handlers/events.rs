@@ -1,26 +1,27 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
+use crate::error::{ApiError, ApiErrorResponse};
 use crate::handlers::activity::{
     activity_import_snapshot, activity_queue_snapshot, ActivityListResponse,
 };
 use crate::handlers::system::{system_tasks_snapshot, SystemTasksResponse};
 use axum::{
-    extract::State,
+    extract::{Query, State},
     http::StatusCode,
     response::sse::{Event, KeepAlive, Sse},
     Json,
 };
 use chorrosion_application::AppState;
+use chorrosion_realtime::RealtimeMessage;
 use futures_util::stream;
 use serde::{Deserialize, Serialize};
 use std::{
     convert::Infallible,
     sync::atomic::{AtomicUsize, Ordering},
-    sync::OnceLock,
     time::Duration,
 };
 use tokio::sync::broadcast;
 use tracing::{debug, warn};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 
 const SSE_EVENT_INTERVAL_SECS: u64 = 5;
 
@@ -48,29 +49,24 @@ struct JobStatusEventPayload {
     tasks: SystemTasksResponse,
 }
 
-#[derive(Debug, Clone)]
-struct BroadcastEvent {
-    event: String,
-    payload: String,
-}
-
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct BroadcastEventRequest {
     pub event: String,
     pub payload: serde_json::Value,
 }
 
+#[derive(Debug, Default, Deserialize, IntoParams)]
+pub struct EventStreamQuery {
+    /// Only stream events broadcast on this channel; idle ticks are suppressed.
+    pub channel: Option<String>,
+}
+
 #[derive(Debug, Serialize, ToSchema)]
 pub struct BroadcastEventResponse {
     pub accepted: bool,
     pub delivered_to: usize,
 }
 
-#[derive(Debug, Serialize, ToSchema)]
-pub struct BroadcastErrorResponse {
-    pub error: String,
-}
-
 #[derive(Debug, Serialize, ToSchema)]
 pub struct SseConnectionsResponse {
     pub total: usize,
@@ -92,7 +88,6 @@ static EVENTS_CONNECTIONS: AtomicUsize = AtomicUsize::new(0);
 static DOWNLOAD_PROGRESS_CONNECTIONS: AtomicUsize = AtomicUsize::new(0);
 static IMPORT_PROGRESS_CONNECTIONS: AtomicUsize = AtomicUsize::new(0);
 static JOB_STATUS_CONNECTIONS: AtomicUsize = AtomicUsize::new(0);
-static EVENT_BROADCASTER: OnceLock<broadcast::Sender<BroadcastEvent>> = OnceLock::new();
 
 fn counter_for(kind: StreamKind) -> &'static AtomicUsize {
     match kind {
@@ -135,13 +130,6 @@ impl Drop for ConnectionGuard {
     }
 }
 
-fn event_broadcaster() -> &'static broadcast::Sender<BroadcastEvent> {
-    EVENT_BROADCASTER.get_or_init(|| {
-        let (sender, _receiver) = broadcast::channel(256);
-        sender
-    })
-}
-
 #[utoipa::path(
     get,
     path = "/api/v1/events/connections",
@@ -160,39 +148,25 @@ pub async fn get_sse_connections() -> Json<SseConnectionsResponse> {
     request_body = BroadcastEventRequest,
     responses(
         (status = 202, description = "Broadcast event accepted", body = BroadcastEventResponse),
-        (status = 400, description = "Invalid broadcast event payload", body = BroadcastErrorResponse)
+        (status = 400, description = "Invalid broadcast event payload", body = ApiErrorResponse)
     ),
     tag = "activity"
 )]
 pub async fn post_broadcast_event(
+    State(state): State<AppState>,
     Json(request): Json<BroadcastEventRequest>,
-) -> Result<(StatusCode, Json<BroadcastEventResponse>), (StatusCode, Json<BroadcastErrorResponse>)>
-{
+) -> Result<(StatusCode, Json<BroadcastEventResponse>), ApiError> {
     let event = request.event.trim();
     if event.is_empty() {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(BroadcastErrorResponse {
-                error: "event name must not be empty".to_string(),
-            }),
-        ));
+        return Err(ApiError::bad_request("event name must not be empty"));
     }
     if event.contains(char::is_control) {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(BroadcastErrorResponse {
-                error: "event name must not contain control characters".to_string(),
-            }),
+        return Err(ApiError::bad_request(
+            "event name must not contain control characters",
         ));
     }
 
-    let payload = request.payload.to_string();
-    let delivered_to = event_broadcaster()
-        .send(BroadcastEvent {
-            event: event.to_string(),
-            payload,
-        })
-        .unwrap_or(0);
+    let delivered_to = state.sse_hub.publish(event, &request.payload.to_string());
 
     Ok((
         StatusCode::ACCEPTED,
@@ -211,49 +185,80 @@ fn event_name_for_tick(tick: u64) -> &'static str {
     }
 }
 
+fn broadcast_event(recv_result: Result<RealtimeMessage, broadcast::error::RecvError>) -> Event {
+    match recv_result {
+        Ok(message) => Event::default()
+            .event(message.channel)
+            .data(message.payload),
+        Err(broadcast::error::RecvError::Lagged(_)) => Event::default()
+            .event("broadcast_lagged")
+            .data("{\"status\":\"lagged\"}"),
+        Err(broadcast::error::RecvError::Closed) => Event::default()
+            .event("broadcast_closed")
+            .data("{\"status\":\"closed\"}"),
+    }
+}
+
+/// Waits for the next message on `channel`, skipping other channels.
+async fn next_channel_event(
+    receiver: &mut broadcast::Receiver<RealtimeMessage>,
+    channel: &str,
+) -> Event {
+    loop {
+        let recv_result = receiver.recv().await;
+        if matches!(&recv_result, Ok(message) if message.channel != channel) {
+            continue;
+        }
+        return broadcast_event(recv_result);
+    }
+}
+
 #[utoipa::path(
     get,
     path = "/api/v1/events",
+    params(EventStreamQuery),
     responses(
         (status = 200, description = "Server-sent event stream for real-time updates", content_type = "text/event-stream")
     ),
     tag = "activity"
 )]
 pub async fn stream_events(
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
+    Query(query): Query<EventStreamQuery>,
 ) -> Sse<impl futures_util::Stream<Item = Result<Event, Infallible>>> {
-    debug!(target: "api", "opening realtime event stream");
+    let channel = query
+        .channel
+        .map(|channel| channel.trim().to_string())
+        .filter(|channel| !channel.is_empty());
+    debug!(target: "api", channel = ?channel, "opening realtime event stream");
 
-    let receiver = event_broadcaster().subscribe();
+    let receiver = state.sse_hub.subscribe();
 
     // Emit an initial connection event, then rotate through event types on a fixed interval.
+    // A channel subscription only forwards that channel's broadcasts.
     let events = stream::unfold(
         (
             true,
             0_u64,
             ConnectionGuard::new(StreamKind::Events),
             receiver,
+            channel,
         ),
-        |(connected, tick, guard, mut receiver)| async move {
+        |(connected, tick, guard, mut receiver, channel)| async move {
             if connected {
                 let event = Event::default()
                     .event("connected")
                     .data("{\"status\":\"connected\"}");
-                return Some((Ok(event), (false, tick, guard, receiver)));
+                return Some((Ok(event), (false, tick, guard, receiver, channel)));
+            }
+
+            if let Some(channel_name) = channel.as_deref() {
+                let event = next_channel_event(&mut receiver, channel_name).await;
+                return Some((Ok(event), (false, tick, guard, receiver, channel)));
             }
 
             let event = tokio::select! {
-                recv_result = receiver.recv() => {
-                    match recv_result {
-                        Ok(message) => Event::default().event(message.event).data(message.payload),
-                        Err(broadcast::error::RecvError::Lagged(_)) => {
-                            Event::default().event("broadcast_lagged").data("{\"status\":\"lagged\"}")
-                        }
-                        Err(broadcast::error::RecvError::Closed) => {
-                            Event::default().event("broadcast_closed").data("{\"status\":\"closed\"}")
-                        }
-                    }
-                }
+                recv_result = receiver.recv() => broadcast_event(recv_result),
                 _ = tokio::time::sleep(Duration::from_secs(SSE_EVENT_INTERVAL_SECS)) => {
                     let payload = RealtimeEventPayload {
                         status: "idle",
@@ -265,7 +270,7 @@ pub async fn stream_events(
                 }
             };
 
-            Some((Ok(event), (false, tick + 1, guard, receiver)))
+            Some((Ok(event), (false, tick + 1, guard, receiver, channel)))
         },
     );
 
@@ -502,6 +507,11 @@ mod tests {
                     pool.clone(),
                 ),
             ),
+            Arc::new(
+                chorrosion_infrastructure::sqlite_adapters::SqliteTrackFileRepository::new(
+                    pool.clone(),
+                ),
+            ),
             chorrosion_infrastructure::ResponseCache::new(100, 60),
         )
     }
@@ -567,7 +577,7 @@ mod tests {
         // allowing the 5-second tick intervals to complete instantly.
         tokio::time::pause();
 
-        let sse = stream_events(State(state)).await;
+        let sse = stream_events(State(state.clone()), Query(EventStreamQuery::default())).await;
         let response = sse.into_response();
 
         // 1. Verify SSE content-type header.
@@ -750,75 +760,105 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn state_dispatcher_publishes_to_the_state_sse_hub() {
+        use chorrosion_domain::{ArtistId, DomainEvent};
+
+        let state = make_test_state().await;
+        let mut receiver = state.sse_hub.subscribe();
+        let event = DomainEvent::new(
+            "artist.deleted",
+            serde_json::json!({ "artist_id": ArtistId::new() }),
+        );
+        state.event_dispatcher.dispatch(&event).await;
+
+        let message = receiver.recv().await.expect("event reaches the hub");
+        assert_eq!(message.channel, "artists");
+        // Hubs belong to their state, so another state sees none of these subscribers.
+        assert_eq!(make_test_state().await.sse_hub.subscriber_count(), 0);
+    }
+
     #[tokio::test]
     async fn post_broadcast_event_rejects_empty_event_name() {
-        let result = post_broadcast_event(Json(BroadcastEventRequest {
-            event: "   ".to_string(),
-            payload: serde_json::json!({}),
-        }))
+        let result = post_broadcast_event(
+            State(make_test_state().await),
+            Json(BroadcastEventRequest {
+                event: "   ".to_string(),
+                payload: serde_json::json!({}),
+            }),
+        )
         .await;
 
-        assert!(matches!(result, Err((StatusCode::BAD_REQUEST, _))));
-        let Err((_, Json(err))) = result else {
+        assert!(matches!(result, Err(ApiError::BadRequest(_))));
+        let Err(err) = result else {
             panic!("expected error")
         };
-        assert!(!err.error.is_empty(), "error body should contain a message");
+        assert!(
+            !err.to_string().is_empty(),
+            "error body should contain a message"
+        );
     }
 
     #[tokio::test]
     async fn post_broadcast_event_rejects_event_name_with_newline() {
-        let result = post_broadcast_event(Json(BroadcastEventRequest {
-            event: "bad\nevent".to_string(),
-            payload: serde_json::json!({}),
-        }))
+        let result = post_broadcast_event(
+            State(make_test_state().await),
+            Json(BroadcastEventRequest {
+                event: "bad\nevent".to_string(),
+                payload: serde_json::json!({}),
+            }),
+        )
         .await;
 
-        assert!(matches!(result, Err((StatusCode::BAD_REQUEST, _))));
-        let Err((_, Json(err))) = result else {
+        assert!(matches!(result, Err(ApiError::BadRequest(_))));
+        let Err(err) = result else {
             panic!("expected error")
         };
         assert!(
-            err.error.contains("control characters"),
-            "expected control character error, got: {}",
-            err.error
+            err.to_string().contains("control characters"),
+            "expected control character error, got: {err}"
         );
     }
 
     #[tokio::test]
     async fn post_broadcast_event_rejects_event_name_with_carriage_return() {
-        let result = post_broadcast_event(Json(BroadcastEventRequest {
-            event: "bad\revent".to_string(),
-            payload: serde_json::json!({}),
-        }))
+        let result = post_broadcast_event(
+            State(make_test_state().await),
+            Json(BroadcastEventRequest {
+                event: "bad\revent".to_string(),
+                payload: serde_json::json!({}),
+            }),
+        )
         .await;
 
-        assert!(matches!(result, Err((StatusCode::BAD_REQUEST, _))));
-        let Err((_, Json(err))) = result else {
+        assert!(matches!(result, Err(ApiError::BadRequest(_))));
+        let Err(err) = result else {
             panic!("expected error")
         };
         assert!(
-            err.error.contains("control characters"),
-            "expected control character error, got: {}",
-            err.error
+            err.to_string().contains("control characters"),
+            "expected control character error, got: {err}"
         );
     }
 
     #[tokio::test]
     async fn post_broadcast_event_rejects_event_name_with_other_control_char() {
-        let result = post_broadcast_event(Json(BroadcastEventRequest {
-            event: "bad\x00event".to_string(),
-            payload: serde_json::json!({}),
-        }))
+        let result = post_broadcast_event(
+            State(make_test_state().await),
+            Json(BroadcastEventRequest {
+                event: "bad\x00event".to_string(),
+                payload: serde_json::json!({}),
+            }),
+        )
         .await;
 
-        assert!(matches!(result, Err((StatusCode::BAD_REQUEST, _))));
-        let Err((_, Json(err))) = result else {
+        assert!(matches!(result, Err(ApiError::BadRequest(_))));
+        let Err(err) = result else {
             panic!("expected error")
         };
         assert!(
-            err.error.contains("control characters"),
-            "expected control character error, got: {}",
-            err.error
+            err.to_string().contains("control characters"),
+            "expected control character error, got: {err}"
         );
     }
 
@@ -828,17 +868,20 @@ mod tests {
         let _lock = counter_test_mutex().lock().await;
 
         let state = make_test_state().await;
-        let sse = stream_events(State(state)).await;
+        let sse = stream_events(State(state.clone()), Query(EventStreamQuery::default())).await;
         let response = sse.into_response();
         let mut data_stream = Box::pin(response.into_body().into_data_stream());
 
         let connected = read_next_sse_event(&mut data_stream).await;
         assert!(connected.contains("event: connected"));
 
-        let publish = post_broadcast_event(Json(BroadcastEventRequest {
-            event: "custom_broadcast".to_string(),
-            payload: serde_json::json!({"kind": "test"}),
-        }))
+        let publish = post_broadcast_event(
+            State(state),
+            Json(BroadcastEventRequest {
+                event: "custom_broadcast".to_string(),
+                payload: serde_json::json!({"kind": "test"}),
+            }),
+        )
         .await
         .expect("broadcast should be accepted");
 
@@ -854,4 +897,64 @@ mod tests {
             "expected custom payload, got: {text}"
         );
     }
+
+    #[tokio::test]
+    async fn stream_events_channel_subscription_frames_hub_broadcasts() {
+        use axum::response::IntoResponse;
+        use chorrosion_realtime::RealtimeHub;
+        let _lock = counter_test_mutex().lock().await;
+
+        let state = make_test_state().await;
+        let sse = stream_events(
+            State(state.clone()),
+            Query(EventStreamQuery {
+                channel: Some("sse-test-albums".to_string()),
+            }),
+        )
+        .await;
+        let response = sse.into_response();
+        let mut data_stream = Box::pin(response.into_body().into_data_stream());
+
+        let connected = read_next_sse_event(&mut data_stream).await;
+        assert!(connected.contains("event: connected"));
+
+        state
+            .sse_hub
+            .broadcast("sse-test-other", "{\"skip\":true}")
+            .await;
+        state
+            .sse_hub
+            .broadcast("sse-test-albums", "{\"id\":7}")
+            .await;
+
+        let text = read_next_data_event(&mut data_stream).await;
+        assert_eq!(text, "event: sse-test-albums\ndata: {\"id\":7}\n\n");
+    }
+
+    #[tokio::test]
+    async fn stream_events_channel_subscription_sends_keepalive_comments() {
+        use axum::response::IntoResponse;
+        let _lock = counter_test_mutex().lock().await;
+
+        let state = make_test_state().await;
+        tokio::time::pause();
+
+        let sse = stream_events(
+            State(state.clone()),
+            Query(EventStreamQuery {
+                channel: Some("sse-test-quiet".to_string()),
+            }),
+        )
+        .await;
+        let response = sse.into_response();
+        let mut data_stream = Box::pin(response.into_body().into_data_stream());
+
+        let connected = read_next_sse_event(&mut data_stream).await;
+        assert!(connected.contains("event: connected"));
+
+        // No idle ticks are emitted for a channel subscription, so the next
+        // frame is the keep-alive comment once the interval elapses.
+        let text = read_next_sse_event(&mut data_stream).await;
+        assert_eq!(text, ": keepalive\n\n");
+    }
 }
@@ -1,19 +1,18 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
-use axum::{http::StatusCode, response::IntoResponse, Json};
+use crate::error::{ApiError, ApiErrorResponse};
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
 use chorrosion_application::{
-    evaluate_import_match, parse_track_metadata, CatalogAlbum, CatalogAlbumMatch, ImportDecision,
-    ImportMatchingError, MatchStrategy, MetadataSource, RawTrackMetadata,
+    evaluate_import_match, parse_track_metadata, AppState, CatalogAlbum, CatalogAlbumMatch,
+    ImportDecision, ImportError, ImportMatchingError, ImportOutcome, ImportedFile, MatchStrategy,
+    MetadataSource, RawTrackMetadata,
 };
-use chorrosion_domain::{AlbumId, ArtistId};
+use chorrosion_domain::{ensure_within_any_root, AlbumId, ArtistId};
 use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tracing::{error, info, warn};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Serialize, Deserialize, ToSchema)]
-pub struct ImportErrorResponse {
-    pub error: String,
-}
-
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ImportCandidateRequest {
     pub raw_metadata: ImportRawMetadataRequest,
@@ -100,25 +99,52 @@ pub struct ManualImportDecisionResponse {
     pub decision: ImportDecisionResponse,
 }
 
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ImportScanRequest {
+    /// Absolute path of the directory to import, e.g. a finished-downloads folder
+    pub path: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ImportScanItemResponse {
+    pub path: String,
+    /// One of: imported, skipped, failed
+    pub status: String,
+    pub track_id: Option<String>,
+    pub track_file_id: Option<String>,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ImportScanResponse {
+    pub imported: usize,
+    pub skipped: usize,
+    pub failed: usize,
+    /// One entry per audio file found, ordered by path
+    pub items: Vec<ImportScanItemResponse>,
+}
+
 #[utoipa::path(
     post,
     path = "/api/v1/imports/evaluate",
     request_body = ImportCandidateRequest,
     responses(
         (status = 200, description = "Import candidate evaluated", body = ImportCandidateResponse),
-        (status = 400, description = "Invalid request", body = ImportErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse)
     ),
     tag = "imports"
 )]
 pub async fn evaluate_import_candidate(
     Json(request): Json<ImportCandidateRequest>,
-) -> Result<Json<ImportCandidateResponse>, (StatusCode, Json<ImportErrorResponse>)> {
+) -> Result<Json<ImportCandidateResponse>, ApiError> {
     if !(0.0..=1.0).contains(&request.fuzzy_threshold) {
-        return Err(bad_request("fuzzy_threshold must be between 0.0 and 1.0"));
+        return Err(ApiError::bad_request(
+            "fuzzy_threshold must be between 0.0 and 1.0",
+        ));
     }
 
     if !(0.0..=1.0).contains(&request.auto_import_threshold) {
-        return Err(bad_request(
+        return Err(ApiError::bad_request(
             "auto_import_threshold must be between 0.0 and 1.0",
         ));
     }
@@ -135,7 +161,7 @@ pub async fn evaluate_import_candidate(
                 album_title: item.album_title,
             })
         })
-        .collect::<Result<Vec<_>, (StatusCode, Json<ImportErrorResponse>)>>()?;
+        .collect::<Result<Vec<_>, ApiError>>()?;
 
     let raw = RawTrackMetadata {
         file_path: request.raw_metadata.file_path.into(),
@@ -147,9 +173,9 @@ pub async fn evaluate_import_candidate(
     };
 
     let parsed = parse_track_metadata(&raw).await.map_err(|e| match e {
-        ImportMatchingError::PathNotFound(_) => bad_request("file not found"),
-        ImportMatchingError::Io(_) => bad_request("unable to read file"),
-        ImportMatchingError::MetadataParsing(msg) => bad_request(&msg),
+        ImportMatchingError::PathNotFound(_) => ApiError::bad_request("file not found"),
+        ImportMatchingError::Io(_) => ApiError::bad_request("unable to read file"),
+        ImportMatchingError::MetadataParsing(msg) => ApiError::bad_request(&msg),
     })?;
 
     let evaluation = evaluate_import_match(
@@ -180,7 +206,7 @@ pub async fn evaluate_import_candidate(
     request_body = ManualImportDecisionRequest,
     responses(
         (status = 200, description = "Manual import decision accepted", body = ManualImportDecisionResponse),
-        (status = 400, description = "Invalid request", body = ImportErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse)
     ),
     tag = "imports"
 )]
@@ -197,12 +223,7 @@ pub async fn submit_manual_import_decision(
                     Err(e) => return e.into_response(),
                 },
                 _ => {
-                    return (
-                        StatusCode::BAD_REQUEST,
-                        Json(ImportErrorResponse {
-                            error: "artist_id is required for import action".to_string(),
-                        }),
-                    )
+                    return ApiError::bad_request("artist_id is required for import action")
                         .into_response();
                 }
             };
@@ -212,24 +233,14 @@ pub async fn submit_manual_import_decision(
                     Err(e) => return e.into_response(),
                 },
                 _ => {
-                    return (
-                        StatusCode::BAD_REQUEST,
-                        Json(ImportErrorResponse {
-                            error: "album_id is required for import action".to_string(),
-                        }),
-                    )
+                    return ApiError::bad_request("album_id is required for import action")
                         .into_response();
                 }
             };
 
             if let Some(c) = request.confidence {
                 if !(0.0..=1.0).contains(&c) {
-                    return (
-                        StatusCode::BAD_REQUEST,
-                        Json(ImportErrorResponse {
-                            error: "confidence must be between 0.0 and 1.0".to_string(),
-                        }),
-                    )
+                    return ApiError::bad_request("confidence must be between 0.0 and 1.0")
                         .into_response();
                 }
             }
@@ -259,12 +270,7 @@ pub async fn submit_manual_import_decision(
                 .or_else(|| Some("manual review requested".to_string())),
         },
         _ => {
-            return (
-                StatusCode::BAD_REQUEST,
-                Json(ImportErrorResponse {
-                    error: "action must be one of: import, skip, needs_review".to_string(),
-                }),
-            )
+            return ApiError::bad_request("action must be one of: import, skip, needs_review")
                 .into_response();
         }
     };
@@ -279,6 +285,180 @@ pub async fn submit_manual_import_decision(
         .into_response()
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/v1/import",
+    request_body = ImportScanRequest,
+    responses(
+        (status = 200, description = "Directory scanned and matched files imported", body = ImportScanResponse),
+        (status = 400, description = "Invalid request or directory outside the library and import paths", body = ApiErrorResponse),
+        (status = 404, description = "Directory not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
+    ),
+    tag = "imports"
+)]
+pub async fn scan_import_directory(
+    State(state): State<AppState>,
+    Json(request): Json<ImportScanRequest>,
+) -> Result<Json<ImportScanResponse>, ApiError> {
+    let dir = Path::new(request.path.trim());
+    if !dir.is_absolute() {
+        return Err(ApiError::bad_request(
+            "path must be an absolute directory path",
+        ));
+    }
+    let library = &state.config.library;
+    let allowed: Vec<PathBuf> = library
+        .root_paths
+        .iter()
+        .chain(&library.import_paths)
+        .cloned()
+        .collect();
+    let dir = ensure_within_any_root(&allowed, dir).map_err(|error| {
+        ApiError::bad_request(format!(
+            "path must be inside a configured library root or import path ({error})"
+        ))
+    })?;
+
+    let service = state
+        .file_import_service()
+        .map_err(|err| ApiError::internal(err, "failed to build file import service"))?;
+    let outcomes = service
+        .scan_directory(&dir)
+        .await
+        .map_err(|err| match err {
+            ImportError::FileNotFound(_) => ApiError::not_found("import directory not found"),
+            other => ApiError::internal(other, "failed to scan import directory"),
+        })?;
+
+    let mut response = ImportScanResponse {
+        imported: 0,
+        skipped: 0,
+        failed: 0,
+        items: Vec::with_capacity(outcomes.len()),
+    };
+    for outcome in outcomes {
+        let item = match outcome {
+            ImportOutcome::Imported(imported) => persist_imported_file(&state, *imported).await,
+            ImportOutcome::Skipped { path, reason } => ImportScanItemResponse {
+                path,
+                status: "skipped".to_string(),
+                track_id: None,
+                track_file_id: None,
+                reason: Some(reason),
+            },
+            ImportOutcome::Failed { path, error } => ImportScanItemResponse {
+                path,
+                status: "failed".to_string(),
+                track_id: None,
+                track_file_id: None,
+                reason: Some(error.to_string()),
+            },
+        };
+        match item.status.as_str() {
+            "imported" => response.imported += 1,
+            "skipped" => response.skipped += 1,
+            _ => response.failed += 1,
+        }
+        response.items.push(item);
+    }
+
+    info!(
+        target: "api",
+        imported = response.imported,
+        skipped = response.skipped,
+        failed = response.failed,
+        "import directory scan completed"
+    );
+    Ok(Json(response))
+}
+
+/// Save an imported file and mark its track as having a file.
+///
+/// An upgrade updates the record of the file it replaces and retires that file
+/// from disk; otherwise any record at the same path is replaced.
+async fn persist_imported_file(state: &AppState, imported: ImportedFile) -> ImportScanItemResponse {
+    let mut track_file = imported.track_file;
+    let path = track_file.path.clone();
+    let track_id = track_file.track_id;
+
+    let lookup = match imported.replaced.clone() {
+        Some(replaced) => Ok(Some(replaced)),
+        None => state.track_file_repository.get_by_path(&path).await,
+    };
+    let saved = match lookup {
+        Ok(Some(existing)) => {
+            track_file.id = existing.id;
+            track_file.created_at = existing.created_at;
+            state.track_file_repository.update(track_file).await
+        }
+        Ok(None) => state.track_file_repository.create(track_file).await,
+        Err(err) => Err(err),
+    };
+    let saved = match saved {
+        Ok(saved) => saved,
+        Err(err) => {
+            error!(target: "api", path = %path, error = %err, "failed to save imported track file");
+            return ImportScanItemResponse {
+                path,
+                status: "failed".to_string(),
+                track_id: Some(track_id.to_string()),
+                track_file_id: None,
+                reason: Some("failed to save track file".to_string()),
+            };
+        }
+    };
+
+    if let Some(replaced) = imported.replaced {
+        if replaced.path != saved.path {
+            retire_replaced_file(state, &replaced.path).await;
+        }
+    }
+
+    match state
+        .track_repository
+        .get_by_id(&track_id.to_string())
+        .await
+    {
+        Ok(Some(mut track)) if !track.has_file => {
+            track.has_file = true;
+            if let Err(err) = state.track_repository.update(track).await {
+                warn!(target: "api", track_id = %track_id, error = %err, "failed to mark track as having a file");
+            }
+        }
+        Ok(_) => {}
+        Err(err) => {
+            warn!(target: "api", track_id = %track_id, error = %err, "failed to load imported track");
+        }
+    }
+
+    ImportScanItemResponse {
+        path,
+        status: "imported".to_string(),
+        track_id: Some(track_id.to_string()),
+        track_file_id: Some(saved.id.to_string()),
+        reason: None,
+    }
+}
+
+/// Delete a file superseded by an upgrade, but only inside a configured library root.
+async fn retire_replaced_file(state: &AppState, path: &str) {
+    let path = match ensure_within_any_root(&state.config.library.root_paths, Path::new(path)) {
+        Ok(path) => path,
+        Err(err) => {
+            warn!(target: "api", path = %path, error = %err, "not deleting replaced file outside the library roots");
+            return;
+        }
+    };
+    match tokio::fs::remove_file(&path).await {
+        Ok(()) => info!(target: "api", path = %path.display(), "deleted replaced track file"),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+        Err(err) => {
+            warn!(target: "api", path = %path.display(), error = %err, "failed to delete replaced track file");
+        }
+    }
+}
+
 fn map_match_strategy(strategy: &MatchStrategy) -> &'static str {
     match strategy {
         MatchStrategy::Exact => "exact",
@@ -332,25 +512,17 @@ fn map_decision(decision: ImportDecision) -> ImportDecisionResponse {
     }
 }
 
-fn parse_artist_id(id: &str) -> Result<ArtistId, (StatusCode, Json<ImportErrorResponse>)> {
-    let parsed = Uuid::parse_str(id).map_err(|_| bad_request("invalid artist_id UUID"))?;
+fn parse_artist_id(id: &str) -> Result<ArtistId, ApiError> {
+    let parsed =
+        Uuid::parse_str(id).map_err(|_| ApiError::bad_request("invalid artist_id UUID"))?;
     Ok(ArtistId::from_uuid(parsed))
 }
 
-fn parse_album_id(id: &str) -> Result<AlbumId, (StatusCode, Json<ImportErrorResponse>)> {
-    let parsed = Uuid::parse_str(id).map_err(|_| bad_request("invalid album_id UUID"))?;
+fn parse_album_id(id: &str) -> Result<AlbumId, ApiError> {
+    let parsed = Uuid::parse_str(id).map_err(|_| ApiError::bad_request("invalid album_id UUID"))?;
     Ok(AlbumId::from_uuid(parsed))
 }
 
-fn bad_request(message: &str) -> (StatusCode, Json<ImportErrorResponse>) {
-    (
-        StatusCode::BAD_REQUEST,
-        Json(ImportErrorResponse {
-            error: message.to_string(),
-        }),
-    )
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -692,4 +864,278 @@ mod tests {
             "filename_heuristics"
         );
     }
+
+    // ---- scan_import_directory ----
+
+    /// Minimal FLAC stream: marker, STREAMINFO and an empty last PADDING block.
+    const MINIMAL_FLAC: &[u8] = &[
+        b'f', b'L', b'a', b'C', 0x00, 0x00, 0x00, 0x22, 0x00, 0x10, 0x00, 0x10, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x0A, 0xC4, 0x40, 0xF0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x81, 0x00, 0x00,
+        0x00,
+    ];
+
+    async fn make_test_state() -> AppState {
+        make_test_state_with(chorrosion_config::AppConfig::default()).await
+    }
+
+    /// Test state whose config allows scanning `import_path`.
+    async fn make_import_state(import_path: &Path) -> AppState {
+        let mut config = chorrosion_config::AppConfig::default();
+        config.library.import_paths = vec![import_path.to_path_buf()];
+        make_test_state_with(config).await
+    }
+
+    async fn make_test_state_with(config: chorrosion_config::AppConfig) -> AppState {
+        use chorrosion_infrastructure::sqlite_adapters::{
+            SqliteAlbumRepository, SqliteArtistRepository,
+            SqliteDownloadClientDefinitionRepository, SqliteDuplicateRepository,
+            SqliteIndexerDefinitionRepository, SqliteMetadataProfileRepository,
+            SqliteQualityProfileRepository, SqliteSmartPlaylistRepository, SqliteTagRepository,
+            SqliteTaggedEntityRepository, SqliteTrackFileRepository, SqliteTrackRepository,
+        };
+        use std::sync::Arc;
+
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:")
+            .await
+            .expect("in-memory SQLite");
+        sqlx::migrate!("../../migrations")
+            .run(&pool)
+            .await
+            .expect("migrations");
+
+        AppState::new(
+            config,
+            Arc::new(SqliteArtistRepository::new(pool.clone())),
+            Arc::new(SqliteAlbumRepository::new(pool.clone())),
+            Arc::new(SqliteTrackRepository::new(pool.clone())),
+            Arc::new(SqliteQualityProfileRepository::new(pool.clone())),
+            Arc::new(SqliteMetadataProfileRepository::new(pool.clone())),
+            Arc::new(SqliteIndexerDefinitionRepository::new(pool.clone())),
+            Arc::new(SqliteDownloadClientDefinitionRepository::new(pool.clone())),
+            Arc::new(SqliteTagRepository::new(pool.clone())),
+            Arc::new(SqliteTaggedEntityRepository::new(pool.clone())),
+            Arc::new(SqliteSmartPlaylistRepository::new(pool.clone())),
+            Arc::new(SqliteDuplicateRepository::new(pool.clone())),
+            Arc::new(SqliteTrackFileRepository::new(pool)),
+            chorrosion_infrastructure::ResponseCache::new(100, 60),
+        )
+    }
+
+    #[tokio::test]
+    async fn scan_rejects_relative_path() {
+        let state = make_test_state().await;
+
+        let result = scan_import_directory(
+            State(state),
+            Json(ImportScanRequest {
+                path: "downloads/complete".to_string(),
+            }),
+        )
+        .await;
+
+        let status = result.unwrap_err().status();
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn scan_rejects_directory_outside_configured_paths() {
+        let allowed = tempfile::tempdir().unwrap();
+        let elsewhere = tempfile::tempdir().unwrap();
+        let state = make_import_state(allowed.path()).await;
+
+        for path in [elsewhere.path().display().to_string(), "/".to_string()] {
+            let result = scan_import_directory(
+                State(state.clone()),
+                Json(ImportScanRequest { path: path.clone() }),
+            )
+            .await;
+
+            assert_eq!(
+                result.unwrap_err().status(),
+                StatusCode::BAD_REQUEST,
+                "{path}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn scan_rejects_every_directory_without_configured_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        let state = make_test_state().await;
+
+        let result = scan_import_directory(
+            State(state),
+            Json(ImportScanRequest {
+                path: dir.path().display().to_string(),
+            }),
+        )
+        .await;
+
+        assert_eq!(result.unwrap_err().status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn scan_returns_404_for_missing_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let state = make_import_state(dir.path()).await;
+
+        let result = scan_import_directory(
+            State(state),
+            Json(ImportScanRequest {
+                path: dir.path().join("nonexistent").display().to_string(),
+            }),
+        )
+        .await;
+
+        let body = result.unwrap_err();
+        let status = body.status();
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert!(
+            !body.to_string().contains("nonexistent"),
+            "{}",
+            body.to_string()
+        );
+    }
+
+    #[tokio::test]
+    async fn scan_imports_matched_files_and_reports_the_rest() {
+        use chorrosion_domain::{Album, Artist, Track};
+
+        let dir = tempfile::tempdir().unwrap();
+        let library = tempfile::tempdir().unwrap();
+        let mut config = chorrosion_config::AppConfig::default();
+        config.library.import_paths = vec![dir.path().to_path_buf()];
+        config.library.root_paths = vec![library.path().to_path_buf()];
+        let state = make_test_state_with(config).await;
+        let artist_path = library.path().join("Radiohead");
+        let mut artist = Artist::new("Radiohead");
+        artist.path = Some(artist_path.display().to_string());
+        let artist = state.artist_repository.create(artist).await.unwrap();
+        let album = state
+            .album_repository
+            .create(Album::new(artist.id, "In Rainbows"))
+            .await
+            .unwrap();
+        let track = state
+            .track_repository
+            .create(Track::new(album.id, artist.id, "Nude"))
+            .await
+            .unwrap();
+
+        let album_dir = dir.path().join("Radiohead").join("In Rainbows");
+        std::fs::create_dir_all(&album_dir).unwrap();
+        let nude = album_dir.join("01 - Nude.flac");
+        std::fs::write(&nude, MINIMAL_FLAC).unwrap();
+        std::fs::write(album_dir.join("02 - Bodysnatchers.mp3"), b"not audio").unwrap();
+        std::fs::write(album_dir.join("cover.jpg"), b"image").unwrap();
+        std::fs::write(album_dir.join(".01 - Nude.flac"), MINIMAL_FLAC).unwrap();
+
+        let Json(response) = scan_import_directory(
+            State(state.clone()),
+            Json(ImportScanRequest {
+                path: dir.path().display().to_string(),
+            }),
+        )
+        .await
+        .expect("scan should succeed");
+
+        assert_eq!(
+            (response.imported, response.skipped, response.failed),
+            (1, 1, 0)
+        );
+        let statuses: Vec<_> = response
+            .items
+            .iter()
+            .map(|item| item.status.as_str())
+            .collect();
+        assert_eq!(statuses, vec!["imported", "skipped"]);
+        assert_eq!(response.items[0].track_id, Some(track.id.to_string()));
+
+        let destination = artist_path.join("In Rainbows").join("Nude.flac");
+        assert!(destination.exists());
+        assert_eq!(response.items[0].path, destination.display().to_string());
+        let saved = state
+            .track_file_repository
+            .get_by_path(&destination.display().to_string())
+            .await
+            .unwrap()
+            .expect("imported track file is saved");
+        assert_eq!(response.items[0].track_file_id, Some(saved.id.to_string()));
+        let track = state
+            .track_repository
+            .get_by_id(&track.id.to_string())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(track.has_file);
+    }
+
+    #[tokio::test]
+    async fn scan_upgrade_replaces_the_record_and_deletes_the_old_file() {
+        use chorrosion_domain::{Album, Artist, QualityProfile, Track, TrackFile};
+
+        let dir = tempfile::tempdir().unwrap();
+        let library = tempfile::tempdir().unwrap();
+        let mut config = chorrosion_config::AppConfig::default();
+        config.library.import_paths = vec![dir.path().to_path_buf()];
+        config.library.root_paths = vec![library.path().to_path_buf()];
+        let state = make_test_state_with(config).await;
+        let mut profile = QualityProfile::new("Lossless", vec!["MP3 192".into(), "FLAC".into()]);
+        profile.cutoff_quality = Some("FLAC".to_string());
+        profile.upgrade_allowed = true;
+        let profile = state
+            .quality_profile_repository
+            .create(profile)
+            .await
+            .unwrap();
+        let artist_path = library.path().join("Radiohead");
+        let mut artist = Artist::new("Radiohead");
+        artist.path = Some(artist_path.display().to_string());
+        artist.quality_profile_id = Some(profile.id);
+        let artist = state.artist_repository.create(artist).await.unwrap();
+        let album = state
+            .album_repository
+            .create(Album::new(artist.id, "In Rainbows"))
+            .await
+            .unwrap();
+        let track = state
+            .track_repository
+            .create(Track::new(album.id, artist.id, "Nude"))
+            .await
+            .unwrap();
+        let old_path = artist_path.join("In Rainbows").join("Nude.mp3");
+        std::fs::create_dir_all(old_path.parent().unwrap()).unwrap();
+        std::fs::write(&old_path, b"old audio").unwrap();
+        let mut old = TrackFile::new(track.id, old_path.display().to_string(), 9);
+        old.quality = Some("MP3 192".to_string());
+        let old = state.track_file_repository.create(old).await.unwrap();
+
+        let album_dir = dir.path().join("Radiohead").join("In Rainbows");
+        std::fs::create_dir_all(&album_dir).unwrap();
+        std::fs::write(album_dir.join("01 - Nude.flac"), MINIMAL_FLAC).unwrap();
+
+        let Json(response) = scan_import_directory(
+            State(state.clone()),
+            Json(ImportScanRequest {
+                path: dir.path().display().to_string(),
+            }),
+        )
+        .await
+        .expect("scan should succeed");
+
+        assert_eq!(response.imported, 1, "{:?}", response.items);
+        assert_eq!(response.items[0].track_file_id, Some(old.id.to_string()));
+        let destination = artist_path.join("In Rainbows").join("Nude.flac");
+        assert!(destination.exists());
+        assert!(!old_path.exists(), "the replaced file is deleted");
+        let saved = state
+            .track_file_repository
+            .get_by_id(&old.id.to_string())
+            .await
+            .unwrap()
+            .expect("the upgraded record is kept");
+        assert_eq!(saved.path, destination.display().to_string());
+        assert_eq!(saved.quality.as_deref(), Some("FLAC"));
+    }
 }
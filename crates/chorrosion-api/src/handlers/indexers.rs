@@ -1,15 +1,21 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
+use crate::error::{is_concurrency_conflict, ApiError, ApiErrorResponse};
 use axum::{
     extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
     Json,
 };
-use chorrosion_application::{AppState, IndexerCapabilities, IndexerProtocol};
+use chorrosion_application::{
+    test_all_indexers, AppState, IndexerCapabilities, IndexerConfig, IndexerProtocol,
+    IndexerTestFailure, IndexerTestResult,
+};
 use chorrosion_domain::IndexerDefinition;
+use chorrosion_infrastructure::collect_all;
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::time::Duration;
 use utoipa::{IntoParams, ToSchema};
 
 #[derive(Debug, Deserialize, IntoParams)]
@@ -32,6 +38,8 @@ pub struct IndexerResponse {
     pub protocol: String,
     pub enabled: bool,
     pub has_api_key: bool,
+    pub category_map: BTreeMap<String, String>,
+    pub requests_per_minute: Option<u32>,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -54,6 +62,8 @@ impl From<IndexerDefinition> for IndexerResponse {
                 .api_key
                 .as_ref()
                 .is_some_and(|key| !key.trim().is_empty()),
+            category_map: value.category_map,
+            requests_per_minute: value.requests_per_minute,
         }
     }
 }
@@ -66,6 +76,11 @@ pub struct CreateIndexerRequest {
     pub api_key: Option<String>,
     #[serde(default = "default_true")]
     pub enabled: bool,
+    /// Category ID overrides keyed by category name, e.g. `{"audio/flac": "3040"}`.
+    #[serde(default)]
+    pub category_map: BTreeMap<String, String>,
+    /// Maximum requests per minute sent to the indexer; omit for no limit.
+    pub requests_per_minute: Option<u32>,
 }
 
 #[derive(Debug, Deserialize, ToSchema)]
@@ -75,11 +90,10 @@ pub struct UpdateIndexerRequest {
     pub protocol: Option<String>,
     pub api_key: Option<String>,
     pub enabled: Option<bool>,
-}
-
-#[derive(Debug, Serialize, ToSchema)]
-pub struct IndexerErrorResponse {
-    pub error: String,
+    /// Replaces the whole category map when present.
+    pub category_map: Option<BTreeMap<String, String>>,
+    /// Outer Option tracks field presence; inner Option allows explicit null to remove the limit.
+    pub requests_per_minute: Option<Option<u32>>,
 }
 
 #[derive(Debug, Deserialize, ToSchema)]
@@ -113,6 +127,10 @@ pub struct IndexerImportItem {
     pub protocol: String,
     pub api_key: Option<String>,
     pub enabled: bool,
+    #[serde(default)]
+    pub category_map: BTreeMap<String, String>,
+    #[serde(default)]
+    pub requests_per_minute: Option<u32>,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -158,12 +176,6 @@ pub struct IndexerImportResponse {
     pub results: Vec<IndexerBulkItemResult>,
 }
 
-#[derive(Debug, Serialize, ToSchema)]
-pub struct IndexerImportErrorResponse {
-    pub error: String,
-    pub details: Vec<String>,
-}
-
 fn default_import_conflict_policy() -> ImportConflictPolicy {
     ImportConflictPolicy::Merge
 }
@@ -181,7 +193,11 @@ pub struct TestIndexerResponse {
     pub success: bool,
     pub message: String,
     pub protocol: String,
-    pub capabilities: IndexerCapabilitiesResponse,
+    /// Failure classification: `unreachable`, `timeout`, `authentication`,
+    /// `malformed_response` or `error`. Absent on success.
+    #[schema(value_type = Option<String>)]
+    pub failure: Option<IndexerTestFailure>,
+    pub capabilities: Option<IndexerCapabilitiesResponse>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -209,41 +225,28 @@ fn default_true() -> bool {
     true
 }
 
-fn validate_name(name: &str) -> Result<(), (StatusCode, Json<IndexerErrorResponse>)> {
+fn validate_name(name: &str) -> Result<(), ApiError> {
     if name.trim().is_empty() {
-        Err((
-            StatusCode::BAD_REQUEST,
-            Json(IndexerErrorResponse {
-                error: "name cannot be empty".to_string(),
-            }),
-        ))
+        Err(ApiError::bad_request("name cannot be empty"))
     } else {
         Ok(())
     }
 }
 
-fn validate_base_url(base_url: &str) -> Result<(), (StatusCode, Json<IndexerErrorResponse>)> {
+fn validate_base_url(base_url: &str) -> Result<(), ApiError> {
     if is_valid_base_url(base_url) {
         Ok(())
     } else {
-        Err((
-            StatusCode::BAD_REQUEST,
-            Json(IndexerErrorResponse {
-                error: "base_url must be a valid http or https URL with a host".to_string(),
-            }),
+        Err(ApiError::bad_request(
+            "base_url must be a valid http or https URL with a host",
         ))
     }
 }
 
-fn parse_protocol(
-    protocol: &str,
-) -> Result<IndexerProtocol, (StatusCode, Json<IndexerErrorResponse>)> {
+fn parse_protocol(protocol: &str) -> Result<IndexerProtocol, ApiError> {
     match protocol.parse() {
         Ok(value) => Ok(value),
-        Err(error) => Err((
-            StatusCode::BAD_REQUEST,
-            Json(IndexerErrorResponse { error }),
-        )),
+        Err(error) => Err(ApiError::bad_request(error)),
     }
 }
 
@@ -253,30 +256,22 @@ fn parse_protocol(
     params(ListIndexersQuery),
     responses(
         (status = 200, description = "List indexers", body = ListIndexersResponse),
-        (status = 400, description = "Invalid request", body = IndexerErrorResponse),
-        (status = 500, description = "Internal server error", body = IndexerErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "settings"
 )]
 pub async fn list_indexers(
     State(state): State<AppState>,
     Query(query): Query<ListIndexersQuery>,
-) -> Result<Json<ListIndexersResponse>, (StatusCode, Json<IndexerErrorResponse>)> {
+) -> Result<Json<ListIndexersResponse>, ApiError> {
     if !(1..=500).contains(&query.limit) {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(IndexerErrorResponse {
-                error: "limit must be between 1 and 500".to_string(),
-            }),
-        ));
+        return Err(ApiError::bad_request("limit must be between 1 and 500"));
     }
 
     if query.offset < 0 {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(IndexerErrorResponse {
-                error: "offset must be greater than or equal to 0".to_string(),
-            }),
+        return Err(ApiError::bad_request(
+            "offset must be greater than or equal to 0",
         ));
     }
 
@@ -284,32 +279,13 @@ pub async fn list_indexers(
         .indexer_definition_repository
         .list(5000, 0)
         .await
-        .map_err(|error| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(IndexerErrorResponse {
-                    error: format!("failed to list indexers: {error}"),
-                }),
-            )
-        })?;
+        .map_err(|error| ApiError::internal(error, "failed to list indexers"))?;
 
     let total = all.len() as i64;
-    let offset = usize::try_from(query.offset).map_err(|_| {
-        (
-            StatusCode::BAD_REQUEST,
-            Json(IndexerErrorResponse {
-                error: "offset out of valid range".to_string(),
-            }),
-        )
-    })?;
-    let limit = usize::try_from(query.limit).map_err(|_| {
-        (
-            StatusCode::BAD_REQUEST,
-            Json(IndexerErrorResponse {
-                error: "limit out of valid range".to_string(),
-            }),
-        )
-    })?;
+    let offset = usize::try_from(query.offset)
+        .map_err(|_| ApiError::bad_request("offset out of valid range"))?;
+    let limit = usize::try_from(query.limit)
+        .map_err(|_| ApiError::bad_request("limit out of valid range"))?;
 
     let items = all
         .into_iter()
@@ -332,8 +308,8 @@ pub async fn list_indexers(
     params(("id" = String, Path, description = "Indexer ID")),
     responses(
         (status = 200, description = "Indexer found", body = IndexerResponse),
-        (status = 404, description = "Indexer not found", body = IndexerErrorResponse),
-        (status = 500, description = "Internal server error", body = IndexerErrorResponse)
+        (status = 404, description = "Indexer not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "settings"
 )]
@@ -343,20 +319,8 @@ pub async fn get_indexer(
 ) -> impl IntoResponse {
     match state.indexer_definition_repository.get_by_id(&id).await {
         Ok(Some(indexer)) => (StatusCode::OK, Json(IndexerResponse::from(indexer))).into_response(),
-        Ok(None) => (
-            StatusCode::NOT_FOUND,
-            Json(IndexerErrorResponse {
-                error: format!("Indexer {} not found", id),
-            }),
-        )
-            .into_response(),
-        Err(error) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(IndexerErrorResponse {
-                error: format!("failed to fetch indexer: {error}"),
-            }),
-        )
-            .into_response(),
+        Ok(None) => ApiError::not_found(format!("Indexer {} not found", id)).into_response(),
+        Err(error) => ApiError::internal(error, "failed to fetch indexer").into_response(),
     }
 }
 
@@ -366,9 +330,9 @@ pub async fn get_indexer(
     request_body = CreateIndexerRequest,
     responses(
         (status = 201, description = "Indexer created", body = IndexerResponse),
-        (status = 400, description = "Invalid request", body = IndexerErrorResponse),
-        (status = 409, description = "Duplicate name", body = IndexerErrorResponse),
-        (status = 500, description = "Internal server error", body = IndexerErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 409, description = "Duplicate name", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "settings"
 )]
@@ -394,22 +358,12 @@ pub async fn create_indexer(
         .await
     {
         Ok(Some(_)) => {
-            return (
-                StatusCode::CONFLICT,
-                Json(IndexerErrorResponse {
-                    error: format!("Indexer '{}' already exists", request.name.trim()),
-                }),
-            )
+            return ApiError::conflict(format!("Indexer '{}' already exists", request.name.trim()))
                 .into_response();
         }
         Ok(None) => {}
         Err(error) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(IndexerErrorResponse {
-                    error: format!("failed to validate indexer name uniqueness: {error}"),
-                }),
-            )
+            return ApiError::internal(error, "failed to validate indexer name uniqueness")
                 .into_response();
         }
     }
@@ -429,28 +383,22 @@ pub async fn create_indexer(
     });
     indexer.api_key = normalized_api_key;
     indexer.enabled = request.enabled;
+    indexer.category_map = request.category_map;
+    indexer.requests_per_minute = request.requests_per_minute;
 
     match state.indexer_definition_repository.create(indexer).await {
         Ok(created) => (StatusCode::CREATED, Json(IndexerResponse::from(created))).into_response(),
         Err(error) => {
             if let Some(sqlx::Error::Database(db_err)) = error.downcast_ref::<sqlx::Error>() {
                 if db_err.is_unique_violation() {
-                    return (
-                        StatusCode::CONFLICT,
-                        Json(IndexerErrorResponse {
-                            error: format!("Indexer '{}' already exists", request.name.trim()),
-                        }),
-                    )
-                        .into_response();
+                    return ApiError::conflict(format!(
+                        "Indexer '{}' already exists",
+                        request.name.trim()
+                    ))
+                    .into_response();
                 }
             }
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(IndexerErrorResponse {
-                    error: format!("failed to create indexer: {error}"),
-                }),
-            )
-                .into_response()
+            ApiError::internal(error, "failed to create indexer").into_response()
         }
     }
 }
@@ -462,10 +410,10 @@ pub async fn create_indexer(
     request_body = UpdateIndexerRequest,
     responses(
         (status = 200, description = "Indexer updated", body = IndexerResponse),
-        (status = 400, description = "Invalid request", body = IndexerErrorResponse),
-        (status = 404, description = "Indexer not found", body = IndexerErrorResponse),
-        (status = 409, description = "Duplicate name", body = IndexerErrorResponse),
-        (status = 500, description = "Internal server error", body = IndexerErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 404, description = "Indexer not found", body = ApiErrorResponse),
+        (status = 409, description = "Duplicate name or concurrent modification", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "settings"
 )]
@@ -477,22 +425,10 @@ pub async fn update_indexer(
     let mut indexer = match state.indexer_definition_repository.get_by_id(&id).await {
         Ok(Some(indexer)) => indexer,
         Ok(None) => {
-            return (
-                StatusCode::NOT_FOUND,
-                Json(IndexerErrorResponse {
-                    error: format!("Indexer {} not found", id),
-                }),
-            )
-                .into_response();
+            return ApiError::not_found(format!("Indexer {} not found", id)).into_response();
         }
         Err(error) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(IndexerErrorResponse {
-                    error: format!("failed to fetch indexer: {error}"),
-                }),
-            )
-                .into_response();
+            return ApiError::internal(error, "failed to fetch indexer").into_response();
         }
     };
 
@@ -507,24 +443,14 @@ pub async fn update_indexer(
             .await
         {
             Ok(Some(existing)) if existing.id != indexer.id => {
-                return (
-                    StatusCode::CONFLICT,
-                    Json(IndexerErrorResponse {
-                        error: format!("Indexer '{}' already exists", name.trim()),
-                    }),
-                )
+                return ApiError::conflict(format!("Indexer '{}' already exists", name.trim()))
                     .into_response();
             }
             Ok(_) => {
                 indexer.name = name.trim().to_string();
             }
             Err(error) => {
-                return (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(IndexerErrorResponse {
-                        error: format!("failed to validate indexer name uniqueness: {error}"),
-                    }),
-                )
+                return ApiError::internal(error, "failed to validate indexer name uniqueness")
                     .into_response();
             }
         }
@@ -558,17 +484,20 @@ pub async fn update_indexer(
         indexer.enabled = enabled;
     }
 
-    indexer.updated_at = Utc::now();
+    if let Some(category_map) = request.category_map {
+        indexer.category_map = category_map;
+    }
+
+    if let Some(requests_per_minute) = request.requests_per_minute {
+        indexer.requests_per_minute = requests_per_minute;
+    }
 
     match state.indexer_definition_repository.update(indexer).await {
         Ok(updated) => (StatusCode::OK, Json(IndexerResponse::from(updated))).into_response(),
-        Err(error) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(IndexerErrorResponse {
-                error: format!("failed to update indexer: {error}"),
-            }),
-        )
-            .into_response(),
+        Err(error) if is_concurrency_conflict(&error) => {
+            ApiError::conflict(error.to_string()).into_response()
+        }
+        Err(error) => ApiError::internal(error, "failed to update indexer").into_response(),
     }
 }
 
@@ -578,8 +507,8 @@ pub async fn update_indexer(
     params(("id" = String, Path, description = "Indexer ID")),
     responses(
         (status = 204, description = "Indexer deleted"),
-        (status = 404, description = "Indexer not found", body = IndexerErrorResponse),
-        (status = 500, description = "Internal server error", body = IndexerErrorResponse)
+        (status = 404, description = "Indexer not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "settings"
 )]
@@ -595,38 +524,21 @@ pub async fn delete_indexer(
                     // Recheck existence to distinguish concurrent deletion (404)
                     // from a transient delete failure (500).
                     match state.indexer_definition_repository.get_by_id(&id).await {
-                        Ok(None) => (
-                            StatusCode::NOT_FOUND,
-                            Json(IndexerErrorResponse {
-                                error: format!("Indexer {} not found", id),
-                            }),
-                        )
-                            .into_response(),
-                        Ok(Some(_)) | Err(_) => (
-                            StatusCode::INTERNAL_SERVER_ERROR,
-                            Json(IndexerErrorResponse {
-                                error: format!("failed to delete indexer: {delete_error}"),
-                            }),
-                        )
-                            .into_response(),
+                        Ok(None) => {
+                            ApiError::not_found(format!("Indexer {} not found", id)).into_response()
+                        }
+                        Ok(Some(_)) | Err(_) => {
+                            ApiError::internal(delete_error, "failed to delete indexer")
+                                .into_response()
+                        }
                     }
                 }
             }
         }
-        Ok(None) => (
-            StatusCode::NOT_FOUND,
-            Json(IndexerErrorResponse {
-                error: format!("Indexer {} not found", id),
-            }),
-        )
-            .into_response(),
-        Err(error) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(IndexerErrorResponse {
-                error: format!("failed to fetch indexer before delete: {error}"),
-            }),
-        )
-            .into_response(),
+        Ok(None) => ApiError::not_found(format!("Indexer {} not found", id)).into_response(),
+        Err(error) => {
+            ApiError::internal(error, "failed to fetch indexer before delete").into_response()
+        }
     }
 }
 
@@ -637,7 +549,7 @@ pub async fn delete_indexer(
     responses(
         (status = 200, description = "Bulk action completed", body = IndexerBulkResponse),
         (status = 207, description = "Bulk action partially succeeded", body = IndexerBulkResponse),
-        (status = 400, description = "Invalid request", body = IndexerErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse)
     ),
     tag = "settings"
 )]
@@ -646,22 +558,11 @@ pub async fn bulk_indexers(
     Json(request): Json<IndexerBulkRequest>,
 ) -> impl IntoResponse {
     if request.ids.is_empty() {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(IndexerErrorResponse {
-                error: "ids must contain at least one item".to_string(),
-            }),
-        )
-            .into_response();
+        return ApiError::bad_request("ids must contain at least one item").into_response();
     }
 
     if !matches!(request.action.as_str(), "enable" | "disable" | "delete") {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(IndexerErrorResponse {
-                error: "action must be one of: enable, disable, delete".to_string(),
-            }),
-        )
+        return ApiError::bad_request("action must be one of: enable, disable, delete")
             .into_response();
     }
 
@@ -687,7 +588,6 @@ pub async fn bulk_indexers(
                 match fetch_result {
                     Ok(Some(mut indexer)) => {
                         indexer.enabled = enabled;
-                        indexer.updated_at = Utc::now();
                         let update_result =
                             state.indexer_definition_repository.update(indexer).await;
                         match update_result {
@@ -736,7 +636,7 @@ pub async fn bulk_indexers(
     path = "/api/v1/settings/indexers/export",
     responses(
         (status = 200, description = "Export indexers", body = IndexerExportEnvelope),
-        (status = 500, description = "Internal server error", body = IndexerErrorResponse)
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "settings"
 )]
@@ -755,18 +655,14 @@ pub async fn export_indexers(State(state): State<AppState>) -> impl IntoResponse
                         protocol: item.protocol,
                         api_key: None,
                         enabled: item.enabled,
+                        category_map: item.category_map,
+                        requests_per_minute: item.requests_per_minute,
                     })
                     .collect(),
             }),
         )
             .into_response(),
-        Err(error) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(IndexerErrorResponse {
-                error: format!("failed to export indexers: {error}"),
-            }),
-        )
-            .into_response(),
+        Err(error) => ApiError::internal(error, "failed to export indexers").into_response(),
     }
 }
 
@@ -777,8 +673,8 @@ pub async fn export_indexers(State(state): State<AppState>) -> impl IntoResponse
     request_body = IndexerImportRequest,
     responses(
         (status = 200, description = "Import processed", body = IndexerImportResponse),
-        (status = 400, description = "Invalid request", body = IndexerImportErrorResponse),
-        (status = 500, description = "Internal server error", body = IndexerErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "settings"
 )]
@@ -788,50 +684,35 @@ pub async fn import_indexers(
     Json(request): Json<IndexerImportRequest>,
 ) -> impl IntoResponse {
     if request.version.trim() != "1" {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(IndexerImportErrorResponse {
-                error: "unsupported import version".to_string(),
-                details: vec!["version must be '1'".to_string()],
-            }),
+        return ApiError::invalid_payload(
+            "unsupported import version",
+            [("version".to_string(), "must be '1'".to_string())],
         )
-            .into_response();
+        .into_response();
     }
 
     let mut validation_errors = Vec::new();
     for (idx, item) in request.items.iter().enumerate() {
         if item.name.trim().is_empty() {
-            validation_errors.push(format!("items[{idx}].name cannot be empty"));
+            validation_errors.push((format!("items[{idx}].name"), "cannot be empty".to_string()));
         }
         if validate_base_url(&item.base_url).is_err() {
-            validation_errors.push(format!("items[{idx}].base_url is invalid"));
+            validation_errors.push((format!("items[{idx}].base_url"), "is invalid".to_string()));
         }
         if parse_protocol(&item.protocol).is_err() {
-            validation_errors.push(format!("items[{idx}].protocol is invalid"));
+            validation_errors.push((format!("items[{idx}].protocol"), "is invalid".to_string()));
         }
     }
 
     if !validation_errors.is_empty() {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(IndexerImportErrorResponse {
-                error: "invalid import payload".to_string(),
-                details: validation_errors,
-            }),
-        )
+        return ApiError::invalid_payload("invalid import payload", validation_errors)
             .into_response();
     }
 
     let existing = match state.indexer_definition_repository.list(5000, 0).await {
         Ok(existing) => existing,
         Err(error) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(IndexerErrorResponse {
-                    error: format!("failed to read existing indexers: {error}"),
-                }),
-            )
-                .into_response()
+            return ApiError::internal(error, "failed to read existing indexers").into_response()
         }
     };
 
@@ -913,10 +794,15 @@ pub async fn import_indexers(
             existing_item.protocol = protocol.as_str().to_string();
             existing_item.api_key = item.api_key.as_ref().and_then(|key| {
                 let trimmed = key.trim();
-                if trimmed.is_empty() { None } else { Some(trimmed.to_string()) }
+                if trimmed.is_empty() {
+                    None
+                } else {
+                    Some(trimmed.to_string())
+                }
             });
             existing_item.enabled = item.enabled;
-            existing_item.updated_at = Utc::now();
+            existing_item.category_map = item.category_map.clone();
+            existing_item.requests_per_minute = item.requests_per_minute;
 
             let update_result = state
                 .indexer_definition_repository
@@ -939,9 +825,15 @@ pub async fn import_indexers(
                 IndexerDefinition::new(item.name.trim(), item.base_url.trim(), protocol.as_str());
             new_item.api_key = item.api_key.as_ref().and_then(|key| {
                 let trimmed = key.trim();
-                if trimmed.is_empty() { None } else { Some(trimmed.to_string()) }
+                if trimmed.is_empty() {
+                    None
+                } else {
+                    Some(trimmed.to_string())
+                }
             });
             new_item.enabled = item.enabled;
+            new_item.category_map = item.category_map.clone();
+            new_item.requests_per_minute = item.requests_per_minute;
 
             let create_result = state.indexer_definition_repository.create(new_item).await;
             match create_result {
@@ -989,71 +881,169 @@ pub async fn import_indexers(
         .into_response()
 }
 
-#[derive(Debug, Serialize, ToSchema)]
-pub struct IndexerTestErrorResponse {
-    pub error: String,
-}
-
-/// Test indexer configuration and return detected capabilities.
+/// Connect to an unsaved indexer configuration and return its detected capabilities.
+///
+/// Connection failures are reported with `success: false` and a `failure`
+/// classification rather than an error status.
 #[utoipa::path(
     post,
     path = "/api/v1/indexers/test",
     request_body = TestIndexerRequest,
     responses(
-        (status = 200, description = "Indexer test completed", body = TestIndexerResponse),
-        (status = 400, description = "Invalid request", body = IndexerTestErrorResponse)
+        (status = 200, description = "Indexer connection tested", body = TestIndexerResponse),
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 500, description = "Indexer test did not complete", body = ApiErrorResponse)
     ),
     tag = "indexers"
 )]
-pub async fn test_indexer_endpoint(Json(request): Json<TestIndexerRequest>) -> impl IntoResponse {
+pub async fn test_indexer_endpoint(
+    State(state): State<AppState>,
+    Json(request): Json<TestIndexerRequest>,
+) -> impl IntoResponse {
     if request.name.trim().is_empty() {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(IndexerTestErrorResponse {
-                error: "Indexer name is required".to_string(),
-            }),
-        )
-            .into_response();
+        return ApiError::bad_request("Indexer name is required").into_response();
     }
 
     if !is_valid_base_url(&request.base_url) {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(IndexerTestErrorResponse {
-                error: "Indexer base_url must be a valid http or https URL with a host".to_string(),
-            }),
+        return ApiError::bad_request(
+            "Indexer base_url must be a valid http or https URL with a host",
         )
-            .into_response();
+        .into_response();
     }
 
     let protocol: IndexerProtocol = match request.protocol.parse() {
         Ok(p) => p,
-        Err(e) => {
-            return (
-                StatusCode::BAD_REQUEST,
-                Json(IndexerTestErrorResponse { error: e }),
-            )
-                .into_response()
-        }
+        Err(e) => return ApiError::bad_request(e).into_response(),
+    };
+    let client = state.indexer_clients.client(
+        IndexerConfig {
+            name: request.name.trim().to_string(),
+            base_url: request.base_url.trim().to_string(),
+            protocol: protocol.clone(),
+            api_key: request.api_key,
+            enabled: true,
+            requests_per_minute: None,
+        },
+        BTreeMap::new(),
+    );
+    let timeout = Duration::from_secs(default_test_timeout_seconds());
+    let Some((_, result)) = test_all_indexers(vec![client], timeout).await.pop() else {
+        return ApiError::Internal(anyhow::anyhow!("indexer test did not complete".to_string()))
+            .into_response();
     };
-    let capabilities = capabilities_for_protocol(&protocol);
 
     (
         StatusCode::OK,
         Json(TestIndexerResponse {
-            success: true,
-            message: format!(
-                "Indexer '{}' configuration validated for protocol {}",
-                request.name,
-                protocol.as_str()
-            ),
+            success: result.success,
+            message: result.message,
             protocol: protocol.as_str().to_string(),
-            capabilities: capabilities.into(),
+            failure: result.failure,
+            capabilities: result.capabilities.map(Into::into),
         }),
     )
         .into_response()
 }
 
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct TestAllIndexersQuery {
+    /// Seconds each indexer may take before it is reported as timed out (1-120).
+    #[serde(default = "default_test_timeout_seconds")]
+    pub timeout_seconds: u64,
+}
+
+fn default_test_timeout_seconds() -> u64 {
+    30
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct IndexerTestAllItem {
+    pub name: String,
+    pub success: bool,
+    pub message: String,
+    /// Failure classification: `unreachable`, `timeout`, `authentication`,
+    /// `malformed_response` or `error`. Absent on success.
+    #[schema(value_type = Option<String>)]
+    pub failure: Option<IndexerTestFailure>,
+    pub capabilities: Option<IndexerCapabilitiesResponse>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TestAllIndexersResponse {
+    pub results: Vec<IndexerTestAllItem>,
+}
+
+impl IndexerTestAllItem {
+    fn new(name: String, result: IndexerTestResult) -> Self {
+        Self {
+            name,
+            success: result.success,
+            message: result.message,
+            failure: result.failure,
+            capabilities: result.capabilities.map(Into::into),
+        }
+    }
+}
+
+/// Test the connection to every enabled indexer concurrently.
+///
+/// Each indexer gets at most `timeout_seconds`, so one hanging indexer cannot
+/// delay the others' results.
+#[utoipa::path(
+    post,
+    path = "/api/v1/indexers/test-all",
+    params(TestAllIndexersQuery),
+    responses(
+        (status = 200, description = "Every enabled indexer tested", body = TestAllIndexersResponse),
+        (status = 400, description = "Invalid timeout", body = ApiErrorResponse),
+        (status = 500, description = "Failed to list indexers", body = ApiErrorResponse)
+    ),
+    tag = "indexers"
+)]
+pub async fn test_all_indexers_endpoint(
+    State(state): State<AppState>,
+    Query(query): Query<TestAllIndexersQuery>,
+) -> impl IntoResponse {
+    if !(1..=120).contains(&query.timeout_seconds) {
+        return ApiError::bad_request("timeout_seconds must be between 1 and 120").into_response();
+    }
+
+    let definitions = match collect_all(500, |limit, offset| {
+        state.indexer_definition_repository.list(limit, offset)
+    })
+    .await
+    {
+        Ok(definitions) => definitions,
+        Err(error) => return ApiError::internal(error, "failed to list indexers").into_response(),
+    };
+
+    let mut clients = Vec::new();
+    let mut invalid = Vec::new();
+    for definition in definitions.into_iter().filter(|d| d.enabled) {
+        match state.indexer_clients.for_definition(&definition) {
+            Ok(client) => clients.push(client),
+            Err(error) => invalid.push(IndexerTestAllItem {
+                message: format!("Indexer '{}' is misconfigured: {error}", definition.name),
+                name: definition.name,
+                success: false,
+                failure: Some(IndexerTestFailure::Error),
+                capabilities: None,
+            }),
+        }
+    }
+
+    let mut results: Vec<IndexerTestAllItem> =
+        test_all_indexers(clients, Duration::from_secs(query.timeout_seconds))
+            .await
+            .into_iter()
+            .map(|(name, result)| IndexerTestAllItem::new(name, result))
+            .collect();
+    results.extend(invalid);
+    results.sort_by_key(|item| item.name.to_lowercase());
+
+    (StatusCode::OK, Json(TestAllIndexersResponse { results })).into_response()
+}
+
 fn is_valid_base_url(base_url: &str) -> bool {
     match url::Url::parse(base_url.trim()) {
         Ok(parsed) => matches!(parsed.scheme(), "http" | "https") && parsed.host().is_some(),
@@ -1061,36 +1051,6 @@ fn is_valid_base_url(base_url: &str) -> bool {
     }
 }
 
-fn capabilities_for_protocol(protocol: &IndexerProtocol) -> IndexerCapabilities {
-    match protocol {
-        IndexerProtocol::Newznab | IndexerProtocol::Torznab => IndexerCapabilities {
-            supports_search: true,
-            supports_rss: true,
-            supports_capabilities_detection: true,
-            supports_categories: true,
-            supported_categories: vec![
-                "music".to_string(),
-                "audio/flac".to_string(),
-                "audio/mp3".to_string(),
-            ],
-        },
-        IndexerProtocol::Gazelle => IndexerCapabilities {
-            supports_search: true,
-            supports_rss: false,
-            supports_capabilities_detection: true,
-            supports_categories: true,
-            supported_categories: vec!["music".to_string(), "torrent".to_string()],
-        },
-        IndexerProtocol::Custom => IndexerCapabilities {
-            supports_search: false,
-            supports_rss: false,
-            supports_capabilities_detection: false,
-            supports_categories: false,
-            supported_categories: vec![],
-        },
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1104,6 +1064,7 @@ mod tests {
         SqliteTrackRepository,
     };
     use std::sync::Arc;
+    use wiremock::ResponseTemplate;
 
     async fn make_test_state() -> AppState {
         use sqlx::sqlite::SqlitePoolOptions;
@@ -1137,6 +1098,11 @@ mod tests {
                     pool.clone(),
                 ),
             ),
+            Arc::new(
+                chorrosion_infrastructure::sqlite_adapters::SqliteTrackFileRepository::new(
+                    pool.clone(),
+                ),
+            ),
             chorrosion_infrastructure::ResponseCache::new(100, 60),
         )
     }
@@ -1175,9 +1141,10 @@ mod tests {
         .await;
 
         assert!(result.is_err());
-        let (status, Json(error)) = result.unwrap_err();
+        let error = result.unwrap_err();
+        let status = error.status();
         assert_eq!(status, StatusCode::BAD_REQUEST);
-        assert_eq!(error.error, "limit must be between 1 and 500");
+        assert_eq!(error.to_string(), "limit must be between 1 and 500");
     }
 
     #[tokio::test]
@@ -1194,9 +1161,13 @@ mod tests {
         .await;
 
         assert!(result.is_err());
-        let (status, Json(error)) = result.unwrap_err();
+        let error = result.unwrap_err();
+        let status = error.status();
         assert_eq!(status, StatusCode::BAD_REQUEST);
-        assert_eq!(error.error, "offset must be greater than or equal to 0");
+        assert_eq!(
+            error.to_string(),
+            "offset must be greater than or equal to 0"
+        );
     }
 
     #[tokio::test]
@@ -1222,7 +1193,10 @@ mod tests {
         let error: serde_json::Value =
             serde_json::from_slice(&body).expect("deserialize import error");
         assert_eq!(error["error"], "unsupported import version");
-        assert_eq!(error["details"], serde_json::json!(["version must be '1'"]));
+        assert_eq!(
+            error["details"],
+            serde_json::json!([{ "field": "version", "message": "must be '1'" }])
+        );
     }
 
     #[tokio::test]
@@ -1241,6 +1215,8 @@ mod tests {
                     protocol: "invalid-protocol".to_string(),
                     api_key: None,
                     enabled: true,
+                    category_map: BTreeMap::new(),
+                    requests_per_minute: None,
                 }],
             }),
         )
@@ -1254,13 +1230,12 @@ mod tests {
         let error: serde_json::Value =
             serde_json::from_slice(&body).expect("deserialize import error");
         assert_eq!(error["error"], "invalid import payload");
-        assert!(
-            error["details"]
-                .as_array()
-                .expect("details array")
-                .iter()
-                .any(|detail| detail == "items[0].protocol is invalid")
-        );
+        assert!(error["details"]
+            .as_array()
+            .expect("details array")
+            .iter()
+            .any(|detail| detail["field"] == "items[0].protocol"
+                && detail["message"] == "is invalid"));
     }
 
     #[tokio::test]
@@ -1279,6 +1254,8 @@ mod tests {
                     protocol: "newznab".to_string(),
                     api_key: None,
                     enabled: true,
+                    category_map: BTreeMap::new(),
+                    requests_per_minute: None,
                 }],
             }),
         )
@@ -1292,13 +1269,12 @@ mod tests {
         let error: serde_json::Value =
             serde_json::from_slice(&body).expect("deserialize import error");
         assert_eq!(error["error"], "invalid import payload");
-        assert!(
-            error["details"]
-                .as_array()
-                .expect("details array")
-                .iter()
-                .any(|detail| detail == "items[0].name cannot be empty")
-        );
+        assert!(error["details"]
+            .as_array()
+            .expect("details array")
+            .iter()
+            .any(|detail| detail["field"] == "items[0].name"
+                && detail["message"] == "cannot be empty"));
     }
 
     #[tokio::test]
@@ -1317,6 +1293,8 @@ mod tests {
                     protocol: "newznab".to_string(),
                     api_key: None,
                     enabled: true,
+                    category_map: BTreeMap::new(),
+                    requests_per_minute: None,
                 }],
             }),
         )
@@ -1330,13 +1308,12 @@ mod tests {
         let error: serde_json::Value =
             serde_json::from_slice(&body).expect("deserialize import error");
         assert_eq!(error["error"], "invalid import payload");
-        assert!(
-            error["details"]
-                .as_array()
-                .expect("details array")
-                .iter()
-                .any(|detail| detail == "items[0].base_url is invalid")
-        );
+        assert!(error["details"]
+            .as_array()
+            .expect("details array")
+            .iter()
+            .any(|detail| detail["field"] == "items[0].base_url"
+                && detail["message"] == "is invalid"));
     }
 
     #[tokio::test]
@@ -1350,6 +1327,8 @@ mod tests {
                 protocol: "newznab".to_string(),
                 api_key: Some("secret".to_string()),
                 enabled: true,
+                category_map: BTreeMap::new(),
+                requests_per_minute: None,
             }),
         )
         .await
@@ -1382,6 +1361,8 @@ mod tests {
                 protocol: "badproto".to_string(),
                 api_key: None,
                 enabled: true,
+                category_map: BTreeMap::new(),
+                requests_per_minute: None,
             }),
         )
         .await
@@ -1412,6 +1393,8 @@ mod tests {
                 protocol: Some("torznab".to_string()),
                 api_key: Some("token".to_string()),
                 enabled: Some(false),
+                category_map: None,
+                requests_per_minute: None,
             }),
         )
         .await
@@ -1458,12 +1441,160 @@ mod tests {
 
     #[tokio::test]
     async fn test_indexer_endpoint_rejects_bad_url() {
-        let response = test_indexer_endpoint(Json(TestIndexerRequest {
-            name: "Index".to_string(),
-            base_url: "not-a-url".to_string(),
-            protocol: "newznab".to_string(),
-            api_key: None,
-        }))
+        let state = make_test_state().await;
+        let response = test_indexer_endpoint(
+            State(state),
+            Json(TestIndexerRequest {
+                name: "Index".to_string(),
+                base_url: "not-a-url".to_string(),
+                protocol: "newznab".to_string(),
+                api_key: None,
+            }),
+        )
+        .await
+        .into_response();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    async fn mock_indexer(response: ResponseTemplate) -> wiremock::MockServer {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api"))
+            .and(query_param("t", "caps"))
+            .respond_with(response)
+            .mount(&server)
+            .await;
+        server
+    }
+
+    async fn test_indexer(state: AppState, base_url: String) -> serde_json::Value {
+        let response = test_indexer_endpoint(
+            State(state),
+            Json(TestIndexerRequest {
+                name: "Index".to_string(),
+                base_url,
+                protocol: "newznab".to_string(),
+                api_key: Some("key".to_string()),
+            }),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("read body");
+        serde_json::from_slice(&body).expect("json body")
+    }
+
+    #[tokio::test]
+    async fn test_indexer_endpoint_reports_detected_capabilities() {
+        let state = make_test_state().await;
+        let server = mock_indexer(ResponseTemplate::new(200).set_body_string(
+            r#"<caps><searching><search available="yes"/></searching><categories><category id="3000" name="Audio"/></categories></caps>"#,
+        ))
+        .await;
+
+        let body = test_indexer(state, server.uri()).await;
+        assert_eq!(body["success"], true);
+        assert!(body["failure"].is_null());
+        assert_eq!(body["capabilities"]["supports_search"], true);
+        assert_eq!(
+            server.received_requests().await.expect("requests").len(),
+            1,
+            "the endpoint connects to the indexer"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_indexer_endpoint_reports_authentication_failure() {
+        let state = make_test_state().await;
+        let server = mock_indexer(ResponseTemplate::new(401)).await;
+
+        let body = test_indexer(state, server.uri()).await;
+        assert_eq!(body["success"], false);
+        assert_eq!(body["failure"], "authentication");
+        assert!(body["capabilities"].is_null());
+    }
+
+    async fn create_newznab(state: &AppState, name: &str, base_url: String, enabled: bool) {
+        let mut definition = IndexerDefinition::new(name, base_url, "newznab");
+        definition.api_key = Some("key".to_string());
+        definition.enabled = enabled;
+        state
+            .indexer_definition_repository
+            .create(definition)
+            .await
+            .expect("create indexer");
+    }
+
+    #[tokio::test]
+    async fn test_all_indexers_reports_each_enabled_indexer_within_timeout() {
+        let state = make_test_state().await;
+        let healthy = mock_indexer(ResponseTemplate::new(200).set_body_string(
+            r#"<caps><searching><search available="yes"/></searching><categories><category id="3000" name="Audio"/></categories></caps>"#,
+        ))
+        .await;
+        let rejecting = mock_indexer(ResponseTemplate::new(401)).await;
+        let hanging = mock_indexer(
+            ResponseTemplate::new(200)
+                .set_body_string("<caps/>")
+                .set_delay(std::time::Duration::from_secs(30)),
+        )
+        .await;
+        create_newznab(&state, "Alpha", healthy.uri(), true).await;
+        create_newznab(&state, "Bravo", rejecting.uri(), true).await;
+        create_newznab(&state, "Charlie", hanging.uri(), true).await;
+        create_newznab(&state, "Disabled", healthy.uri(), false).await;
+
+        let started = std::time::Instant::now();
+        let response = test_all_indexers_endpoint(
+            State(state),
+            Query(TestAllIndexersQuery { timeout_seconds: 1 }),
+        )
+        .await
+        .into_response();
+
+        assert!(started.elapsed() < std::time::Duration::from_secs(10));
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("read body");
+        let json: serde_json::Value = serde_json::from_slice(&body).expect("json body");
+        let results = json["results"].as_array().expect("results array");
+        let summary: Vec<_> = results
+            .iter()
+            .map(|item| {
+                (
+                    item["name"].as_str().unwrap(),
+                    item["success"].as_bool().unwrap(),
+                    item["failure"].as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("Alpha", true, None),
+                ("Bravo", false, Some("authentication")),
+                ("Charlie", false, Some("timeout")),
+            ]
+        );
+        assert!(results[0]["capabilities"]["supports_search"]
+            .as_bool()
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_all_indexers_rejects_out_of_range_timeout() {
+        let state = make_test_state().await;
+        let response = test_all_indexers_endpoint(
+            State(state),
+            Query(TestAllIndexersQuery { timeout_seconds: 0 }),
+        )
         .await
         .into_response();
 
@@ -1481,6 +1612,8 @@ mod tests {
                 protocol: "torznab".to_string(),
                 api_key: Some("   ".to_string()),
                 enabled: true,
+                category_map: BTreeMap::new(),
+                requests_per_minute: None,
             }),
         )
         .await
@@ -1519,6 +1652,8 @@ mod tests {
                 protocol: "newznab".to_string(),
                 api_key: None,
                 enabled: true,
+                category_map: BTreeMap::new(),
+                requests_per_minute: None,
             }),
         )
         .await
@@ -1533,6 +1668,8 @@ mod tests {
                 protocol: "torznab".to_string(),
                 api_key: None,
                 enabled: true,
+                category_map: BTreeMap::new(),
+                requests_per_minute: None,
             }),
         )
         .await
@@ -1,4 +1,5 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
+use crate::error::{is_concurrency_conflict, ApiError, ApiErrorResponse};
 use axum::{
     extract::{Path, Query, State},
     http::StatusCode,
@@ -32,6 +33,7 @@ pub struct MetadataProfileResponse {
     pub primary_album_types: Vec<String>,
     pub secondary_album_types: Vec<String>,
     pub release_statuses: Vec<String>,
+    pub is_default: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -50,6 +52,7 @@ impl From<MetadataProfile> for MetadataProfileResponse {
             primary_album_types: profile.primary_album_types,
             secondary_album_types: profile.secondary_album_types,
             release_statuses: profile.release_statuses,
+            is_default: profile.is_default,
         }
     }
 }
@@ -70,12 +73,6 @@ pub struct UpdateMetadataProfileRequest {
     pub release_statuses: Option<Vec<String>>,
 }
 
-#[derive(Debug, Serialize, ToSchema)]
-#[schema(as = MetadataProfileErrorResponse)]
-pub struct ErrorResponse {
-    pub error: String,
-}
-
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct MetadataProfileBulkRequest {
     pub action: String,
@@ -151,24 +148,25 @@ pub struct MetadataProfileImportResponse {
     pub results: Vec<MetadataProfileBulkItemResult>,
 }
 
-#[derive(Debug, Serialize, ToSchema)]
-pub struct MetadataProfileImportErrorResponse {
-    pub error: String,
-    pub details: Vec<String>,
-}
-
 fn default_import_conflict_policy() -> ImportConflictPolicy {
     ImportConflictPolicy::Merge
 }
 
-fn validate_name(name: &str) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
+/// Why `id` cannot be deleted, if artists are still assigned to it. Deleting it
+/// would leave their `metadata_profile_id` pointing at nothing.
+async fn assigned_artists_error(state: &AppState, id: &str) -> anyhow::Result<Option<String>> {
+    let count = state
+        .metadata_profile_repository
+        .count_assigned_artists(id)
+        .await?;
+    Ok((count > 0).then(|| {
+        format!("Metadata profile {id} is assigned to {count} artist(s); reassign them before deleting it")
+    }))
+}
+
+fn validate_name(name: &str) -> Result<(), ApiError> {
     if name.trim().is_empty() {
-        Err((
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: "name cannot be empty".to_string(),
-            }),
-        ))
+        Err(ApiError::bad_request("name cannot be empty"))
     } else {
         Ok(())
     }
@@ -180,31 +178,23 @@ fn validate_name(name: &str) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
     params(ListMetadataProfilesQuery),
     responses(
         (status = 200, description = "List metadata profiles", body = ListMetadataProfilesResponse),
-        (status = 400, description = "Invalid request", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "settings"
 )]
 pub async fn list_metadata_profiles(
     State(state): State<AppState>,
     Query(query): Query<ListMetadataProfilesQuery>,
-) -> Result<Json<ListMetadataProfilesResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<ListMetadataProfilesResponse>, ApiError> {
     debug!(target: "api", ?query, "listing metadata profiles");
 
     if !(1..=500).contains(&query.limit) {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: "limit must be between 1 and 500".to_string(),
-            }),
-        ));
+        return Err(ApiError::bad_request("limit must be between 1 and 500"));
     }
     if query.offset < 0 {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: "offset must be greater than or equal to 0".to_string(),
-            }),
+        return Err(ApiError::bad_request(
+            "offset must be greater than or equal to 0",
         ));
     }
 
@@ -212,36 +202,19 @@ pub async fn list_metadata_profiles(
         .metadata_profile_repository
         .list(5000, 0)
         .await
-        .map_err(|error| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse {
-                    error: format!("failed to list metadata profiles: {error}"),
-                }),
-            )
-        })?;
+        .map_err(|error| ApiError::internal(error, "failed to list metadata profiles"))?;
 
     let total = all_profiles.len() as i64;
     let offset = match usize::try_from(query.offset) {
         Ok(offset) => offset,
         Err(_) => {
-            return Err((
-                StatusCode::BAD_REQUEST,
-                Json(ErrorResponse {
-                    error: "offset out of valid range".to_string(),
-                }),
-            ));
+            return Err(ApiError::bad_request("offset out of valid range"));
         }
     };
     let limit = match usize::try_from(query.limit) {
         Ok(limit) => limit,
         Err(_) => {
-            return Err((
-                StatusCode::BAD_REQUEST,
-                Json(ErrorResponse {
-                    error: "limit out of valid range".to_string(),
-                }),
-            ));
+            return Err(ApiError::bad_request("limit out of valid range"));
         }
     };
 
@@ -266,8 +239,8 @@ pub async fn list_metadata_profiles(
     params(("id" = String, Path, description = "Metadata profile ID")),
     responses(
         (status = 200, description = "Metadata profile found", body = MetadataProfileResponse),
-        (status = 404, description = "Metadata profile not found", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 404, description = "Metadata profile not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "settings"
 )]
@@ -281,20 +254,10 @@ pub async fn get_metadata_profile(
         Ok(Some(profile)) => {
             (StatusCode::OK, Json(MetadataProfileResponse::from(profile))).into_response()
         }
-        Ok(None) => (
-            StatusCode::NOT_FOUND,
-            Json(ErrorResponse {
-                error: format!("Metadata profile {} not found", id),
-            }),
-        )
-            .into_response(),
-        Err(error) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: format!("failed to fetch metadata profile: {error}"),
-            }),
-        )
-            .into_response(),
+        Ok(None) => {
+            ApiError::not_found(format!("Metadata profile {} not found", id)).into_response()
+        }
+        Err(error) => ApiError::internal(error, "failed to fetch metadata profile").into_response(),
     }
 }
 
@@ -304,8 +267,8 @@ pub async fn get_metadata_profile(
     request_body = CreateMetadataProfileRequest,
     responses(
         (status = 201, description = "Metadata profile created", body = MetadataProfileResponse),
-        (status = 400, description = "Invalid request", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "settings"
 )]
@@ -336,13 +299,9 @@ pub async fn create_metadata_profile(
             Json(MetadataProfileResponse::from(created)),
         )
             .into_response(),
-        Err(error) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: format!("failed to create metadata profile: {error}"),
-            }),
-        )
-            .into_response(),
+        Err(error) => {
+            ApiError::internal(error, "failed to create metadata profile").into_response()
+        }
     }
 }
 
@@ -353,9 +312,10 @@ pub async fn create_metadata_profile(
     request_body = UpdateMetadataProfileRequest,
     responses(
         (status = 200, description = "Metadata profile updated", body = MetadataProfileResponse),
-        (status = 400, description = "Invalid request", body = ErrorResponse),
-        (status = 404, description = "Metadata profile not found", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 404, description = "Metadata profile not found", body = ApiErrorResponse),
+        (status = 409, description = "Metadata profile was modified concurrently", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "settings"
 )]
@@ -369,22 +329,11 @@ pub async fn update_metadata_profile(
     let mut profile = match state.metadata_profile_repository.get_by_id(&id).await {
         Ok(Some(profile)) => profile,
         Ok(None) => {
-            return (
-                StatusCode::NOT_FOUND,
-                Json(ErrorResponse {
-                    error: format!("Metadata profile {} not found", id),
-                }),
-            )
+            return ApiError::not_found(format!("Metadata profile {} not found", id))
                 .into_response();
         }
         Err(error) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse {
-                    error: format!("failed to fetch metadata profile: {error}"),
-                }),
-            )
-                .into_response();
+            return ApiError::internal(error, "failed to fetch metadata profile").into_response();
         }
     };
 
@@ -403,19 +352,17 @@ pub async fn update_metadata_profile(
     if let Some(statuses) = request.release_statuses {
         profile.release_statuses = statuses;
     }
-    profile.updated_at = Utc::now();
 
     match state.metadata_profile_repository.update(profile).await {
         Ok(updated) => {
             (StatusCode::OK, Json(MetadataProfileResponse::from(updated))).into_response()
         }
-        Err(error) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: format!("failed to update metadata profile: {error}"),
-            }),
-        )
-            .into_response(),
+        Err(error) if is_concurrency_conflict(&error) => {
+            ApiError::conflict(error.to_string()).into_response()
+        }
+        Err(error) => {
+            ApiError::internal(error, "failed to update metadata profile").into_response()
+        }
     }
 }
 
@@ -425,8 +372,9 @@ pub async fn update_metadata_profile(
     params(("id" = String, Path, description = "Metadata profile ID")),
     responses(
         (status = 204, description = "Metadata profile deleted"),
-        (status = 404, description = "Metadata profile not found", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 404, description = "Metadata profile not found", body = ApiErrorResponse),
+        (status = 409, description = "Metadata profile is assigned to artists", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "settings"
 )]
@@ -438,44 +386,80 @@ pub async fn delete_metadata_profile(
 
     match state.metadata_profile_repository.get_by_id(&id).await {
         Ok(Some(_)) => {
+            match assigned_artists_error(&state, &id).await {
+                Ok(Some(error)) => return ApiError::conflict(error).into_response(),
+                Ok(None) => {}
+                Err(error) => {
+                    return ApiError::internal(error, "failed to check metadata profile usage")
+                        .into_response()
+                }
+            }
             match state.metadata_profile_repository.delete(&id).await {
                 Ok(_) => StatusCode::NO_CONTENT.into_response(),
                 Err(delete_error) => {
                     // Recheck existence to distinguish concurrent deletion (404)
                     // from a transient delete failure (500).
                     match state.metadata_profile_repository.get_by_id(&id).await {
-                        Ok(None) => (
-                            StatusCode::NOT_FOUND,
-                            Json(ErrorResponse {
-                                error: format!("Metadata profile {} not found", id),
-                            }),
-                        )
-                            .into_response(),
-                        Ok(Some(_)) | Err(_) => (
-                            StatusCode::INTERNAL_SERVER_ERROR,
-                            Json(ErrorResponse {
-                                error: format!("failed to delete metadata profile: {delete_error}"),
-                            }),
-                        )
-                            .into_response(),
+                        Ok(None) => {
+                            ApiError::not_found(format!("Metadata profile {} not found", id))
+                                .into_response()
+                        }
+                        Ok(Some(_)) | Err(_) => {
+                            ApiError::internal(delete_error, "failed to delete metadata profile")
+                                .into_response()
+                        }
                     }
                 }
             }
         }
-        Ok(None) => (
-            StatusCode::NOT_FOUND,
-            Json(ErrorResponse {
-                error: format!("Metadata profile {} not found", id),
-            }),
-        )
+        Ok(None) => {
+            ApiError::not_found(format!("Metadata profile {} not found", id)).into_response()
+        }
+        Err(error) => ApiError::internal(error, "failed to fetch metadata profile before delete")
             .into_response(),
-        Err(error) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: format!("failed to fetch metadata profile before delete: {error}"),
-            }),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/settings/metadata-profiles/{id}/default",
+    params(("id" = String, Path, description = "Metadata profile ID")),
+    responses(
+        (status = 200, description = "Metadata profile is now the default", body = MetadataProfileResponse),
+        (status = 404, description = "Metadata profile not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
+    ),
+    tag = "settings"
+)]
+pub async fn set_default_metadata_profile(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    debug!(target: "api", %id, "setting default metadata profile");
+
+    let profile = match state.metadata_profile_repository.get_by_id(&id).await {
+        Ok(Some(profile)) => profile,
+        Ok(None) => {
+            return ApiError::not_found(format!("Metadata profile {} not found", id))
+                .into_response()
+        }
+        Err(error) => {
+            return ApiError::internal(error, "failed to fetch metadata profile").into_response()
+        }
+    };
+
+    match state.metadata_profile_repository.set_default(&id).await {
+        Ok(()) => (
+            StatusCode::OK,
+            Json(MetadataProfileResponse::from(MetadataProfile {
+                is_default: true,
+                ..profile
+            })),
         )
             .into_response(),
+        Err(error) => {
+            ApiError::internal(error, "failed to set default metadata profile").into_response()
+        }
     }
 }
 
@@ -486,7 +470,7 @@ pub async fn delete_metadata_profile(
     responses(
         (status = 200, description = "Bulk action completed", body = MetadataProfileBulkResponse),
         (status = 207, description = "Bulk action partially succeeded", body = MetadataProfileBulkResponse),
-        (status = 400, description = "Invalid request", body = ErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse)
     ),
     tag = "settings"
 )]
@@ -495,39 +479,39 @@ pub async fn bulk_metadata_profiles(
     Json(request): Json<MetadataProfileBulkRequest>,
 ) -> impl IntoResponse {
     if request.ids.is_empty() {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: "ids must contain at least one item".to_string(),
-            }),
-        )
-            .into_response();
+        return ApiError::bad_request("ids must contain at least one item").into_response();
     }
 
     if !matches!(request.action.as_str(), "delete") {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: "action must be one of: delete".to_string(),
-            }),
-        )
-            .into_response();
+        return ApiError::bad_request("action must be one of: delete").into_response();
     }
 
     let mut results = Vec::with_capacity(request.ids.len());
 
     for id in request.ids {
         let result = match request.action.as_str() {
-            "delete" => match state.metadata_profile_repository.delete(&id).await {
-                Ok(_) => MetadataProfileBulkItemResult {
+            "delete" => match assigned_artists_error(&state, &id).await {
+                Ok(Some(error)) => MetadataProfileBulkItemResult {
                     id,
-                    success: true,
-                    error: None,
+                    success: false,
+                    error: Some(error),
                 },
                 Err(error) => MetadataProfileBulkItemResult {
                     id,
                     success: false,
-                    error: Some(format!("failed to delete metadata profile: {error}")),
+                    error: Some(format!("failed to check metadata profile usage: {error}")),
+                },
+                Ok(None) => match state.metadata_profile_repository.delete(&id).await {
+                    Ok(_) => MetadataProfileBulkItemResult {
+                        id,
+                        success: true,
+                        error: None,
+                    },
+                    Err(error) => MetadataProfileBulkItemResult {
+                        id,
+                        success: false,
+                        error: Some(format!("failed to delete metadata profile: {error}")),
+                    },
                 },
             },
             _ => unreachable!(),
@@ -551,7 +535,7 @@ pub async fn bulk_metadata_profiles(
     path = "/api/v1/settings/metadata-profiles/export",
     responses(
         (status = 200, description = "Export metadata profiles", body = MetadataProfileExportEnvelope),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "settings"
 )]
@@ -574,13 +558,9 @@ pub async fn export_metadata_profiles(State(state): State<AppState>) -> impl Int
             }),
         )
             .into_response(),
-        Err(error) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: format!("failed to export metadata profiles: {error}"),
-            }),
-        )
-            .into_response(),
+        Err(error) => {
+            ApiError::internal(error, "failed to export metadata profiles").into_response()
+        }
     }
 }
 
@@ -591,8 +571,8 @@ pub async fn export_metadata_profiles(State(state): State<AppState>) -> impl Int
     request_body = MetadataProfileImportRequest,
     responses(
         (status = 200, description = "Import processed", body = MetadataProfileImportResponse),
-        (status = 400, description = "Invalid request", body = MetadataProfileImportErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "settings"
 )]
@@ -602,42 +582,28 @@ pub async fn import_metadata_profiles(
     Json(request): Json<MetadataProfileImportRequest>,
 ) -> impl IntoResponse {
     if request.version.trim() != "1" {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(MetadataProfileImportErrorResponse {
-                error: "unsupported import version".to_string(),
-                details: vec!["version must be '1'".to_string()],
-            }),
+        return ApiError::invalid_payload(
+            "unsupported import version",
+            [("version".to_string(), "must be '1'".to_string())],
         )
-            .into_response();
+        .into_response();
     }
 
     let mut validation_errors = Vec::new();
     for (idx, item) in request.items.iter().enumerate() {
         if item.name.trim().is_empty() {
-            validation_errors.push(format!("items[{idx}].name cannot be empty"));
+            validation_errors.push((format!("items[{idx}].name"), "cannot be empty".to_string()));
         }
     }
     if !validation_errors.is_empty() {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(MetadataProfileImportErrorResponse {
-                error: "invalid import payload".to_string(),
-                details: validation_errors,
-            }),
-        )
+        return ApiError::invalid_payload("invalid import payload", validation_errors)
             .into_response();
     }
 
     let existing = match state.metadata_profile_repository.list(5000, 0).await {
         Ok(existing) => existing,
         Err(error) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse {
-                    error: format!("failed to read existing metadata profiles: {error}"),
-                }),
-            )
+            return ApiError::internal(error, "failed to read existing metadata profiles")
                 .into_response()
         }
     };
@@ -703,7 +669,6 @@ pub async fn import_metadata_profiles(
             existing_item.primary_album_types = item.primary_album_types.clone();
             existing_item.secondary_album_types = item.secondary_album_types.clone();
             existing_item.release_statuses = item.release_statuses.clone();
-            existing_item.updated_at = Utc::now();
             let update_result = state
                 .metadata_profile_repository
                 .update(existing_item)
@@ -744,10 +709,27 @@ pub async fn import_metadata_profiles(
     if matches!(request.conflict_policy, ImportConflictPolicy::ReplaceAll) {
         for existing_item in existing_by_name.values() {
             if !import_names.contains(&existing_item.name.to_lowercase()) {
-                let delete_result = state
-                    .metadata_profile_repository
-                    .delete(&existing_item.id.to_string())
-                    .await;
+                let id = existing_item.id.to_string();
+                match assigned_artists_error(&state, &id).await {
+                    Ok(None) => {}
+                    Ok(Some(error)) => {
+                        results.push(MetadataProfileBulkItemResult {
+                            id,
+                            success: false,
+                            error: Some(error),
+                        });
+                        continue;
+                    }
+                    Err(error) => {
+                        results.push(MetadataProfileBulkItemResult {
+                            id,
+                            success: false,
+                            error: Some(format!("failed to check metadata profile usage: {error}")),
+                        });
+                        continue;
+                    }
+                }
+                let delete_result = state.metadata_profile_repository.delete(&id).await;
                 if let Err(error) = delete_result {
                     results.push(MetadataProfileBulkItemResult {
                         id: existing_item.id.to_string(),
@@ -838,6 +820,11 @@ mod tests {
                         pool.clone(),
                     ),
                 ),
+                Arc::new(
+                    chorrosion_infrastructure::sqlite_adapters::SqliteTrackFileRepository::new(
+                        pool.clone(),
+                    ),
+                ),
                 chorrosion_infrastructure::ResponseCache::new(100, 60),
             )
         }
@@ -875,7 +862,7 @@ mod tests {
             };
             let result = list_metadata_profiles(State(state), Query(query)).await;
             assert!(result.is_err());
-            let (status, _) = result.unwrap_err();
+            let status = result.unwrap_err().status();
             assert_eq!(status, StatusCode::BAD_REQUEST);
         }
 
@@ -888,7 +875,7 @@ mod tests {
             };
             let result = list_metadata_profiles(State(state), Query(query)).await;
             assert!(result.is_err());
-            let (status, _) = result.unwrap_err();
+            let status = result.unwrap_err().status();
             assert_eq!(status, StatusCode::BAD_REQUEST);
         }
 
@@ -1073,6 +1060,32 @@ mod tests {
             assert_eq!(response.status(), StatusCode::NO_CONTENT);
         }
 
+        #[tokio::test]
+        async fn delete_metadata_profile_returns_409_when_assigned_to_artist() {
+            let state = make_test_state().await;
+            let profile = create_test_profile(&state).await;
+            let mut artist = chorrosion_domain::Artist::new("Assigned Artist");
+            artist.metadata_profile_id = Some(profile.id);
+            state
+                .artist_repository
+                .create(artist)
+                .await
+                .expect("create artist");
+
+            let response =
+                delete_metadata_profile(State(state.clone()), Path(profile.id.to_string()))
+                    .await
+                    .into_response();
+            assert_eq!(response.status(), StatusCode::CONFLICT);
+
+            let fetched = state
+                .metadata_profile_repository
+                .get_by_id(&profile.id.to_string())
+                .await
+                .expect("get_by_id");
+            assert!(fetched.is_some(), "assigned profile should be kept");
+        }
+
         #[tokio::test]
         async fn delete_metadata_profile_returns_404_for_unknown_id() {
             let state = make_test_state().await;
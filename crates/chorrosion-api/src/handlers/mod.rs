@@ -12,6 +12,7 @@ pub mod imports;
 pub mod indexers;
 pub mod metadata_profiles;
 pub mod quality_profiles;
+pub mod realtime;
 pub mod search;
 pub mod smart_playlists;
 pub mod system;
@@ -1,4 +1,5 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
+use crate::error::{is_concurrency_conflict, ApiError, ApiErrorResponse};
 use axum::{
     extract::{Path, Query, State},
     http::StatusCode,
@@ -6,7 +7,7 @@ use axum::{
     Json,
 };
 use chorrosion_application::AppState;
-use chorrosion_domain::QualityProfile;
+use chorrosion_domain::{QualityProfile, Validate};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use tracing::debug;
@@ -31,6 +32,7 @@ pub struct QualityProfileResponse {
     pub allowed_qualities: Vec<String>,
     pub upgrade_allowed: bool,
     pub cutoff_quality: Option<String>,
+    pub is_default: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -49,6 +51,7 @@ impl From<QualityProfile> for QualityProfileResponse {
             allowed_qualities: profile.allowed_qualities,
             upgrade_allowed: profile.upgrade_allowed,
             cutoff_quality: profile.cutoff_quality,
+            is_default: profile.is_default,
         }
     }
 }
@@ -70,12 +73,6 @@ pub struct UpdateQualityProfileRequest {
     pub cutoff_quality: Option<Option<String>>,
 }
 
-#[derive(Debug, Serialize, ToSchema)]
-#[schema(as = QualityProfileErrorResponse)]
-pub struct ErrorResponse {
-    pub error: String,
-}
-
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct QualityProfileBulkRequest {
     pub action: String,
@@ -151,38 +148,46 @@ pub struct QualityProfileImportResponse {
     pub results: Vec<QualityProfileBulkItemResult>,
 }
 
-#[derive(Debug, Serialize, ToSchema)]
-pub struct QualityProfileImportErrorResponse {
-    pub error: String,
-    pub details: Vec<String>,
-}
-
 fn default_import_conflict_policy() -> ImportConflictPolicy {
     ImportConflictPolicy::Merge
 }
 
-fn validate_name(name: &str) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
+/// Why `id` cannot be deleted, if artists are still assigned to it. Deleting it
+/// would leave their `quality_profile_id` pointing at nothing.
+async fn assigned_artists_error(state: &AppState, id: &str) -> anyhow::Result<Option<String>> {
+    let count = state
+        .quality_profile_repository
+        .count_assigned_artists(id)
+        .await?;
+    Ok((count > 0).then(|| {
+        format!("Quality profile {id} is assigned to {count} artist(s); reassign them before deleting it")
+    }))
+}
+
+fn validate_name(name: &str) -> Result<(), ApiError> {
     if name.trim().is_empty() {
-        Err((
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: "name cannot be empty".to_string(),
-            }),
-        ))
+        Err(ApiError::bad_request("name cannot be empty"))
     } else {
         Ok(())
     }
 }
 
-fn validate_allowed_qualities(
-    allowed_qualities: &[String],
-) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
+/// Enforce the domain rules, such as the cutoff being one of the allowed qualities.
+fn validate_profile(profile: &QualityProfile) -> Result<(), ApiError> {
+    profile.validate().map_err(|errors| {
+        ApiError::invalid_payload(
+            "invalid quality profile",
+            errors
+                .into_iter()
+                .map(|error| (error.field.to_string(), error.message.to_string())),
+        )
+    })
+}
+
+fn validate_allowed_qualities(allowed_qualities: &[String]) -> Result<(), ApiError> {
     if allowed_qualities.is_empty() || allowed_qualities.iter().all(|q| q.trim().is_empty()) {
-        Err((
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: "allowed_qualities must contain at least one non-empty value".to_string(),
-            }),
+        Err(ApiError::bad_request(
+            "allowed_qualities must contain at least one non-empty value",
         ))
     } else {
         Ok(())
@@ -195,31 +200,23 @@ fn validate_allowed_qualities(
     params(ListQualityProfilesQuery),
     responses(
         (status = 200, description = "List quality profiles", body = ListQualityProfilesResponse),
-        (status = 400, description = "Invalid request", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "settings"
 )]
 pub async fn list_quality_profiles(
     State(state): State<AppState>,
     Query(query): Query<ListQualityProfilesQuery>,
-) -> Result<Json<ListQualityProfilesResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<ListQualityProfilesResponse>, ApiError> {
     debug!(target: "api", ?query, "listing quality profiles");
 
     if !(1..=500).contains(&query.limit) {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: "limit must be between 1 and 500".to_string(),
-            }),
-        ));
+        return Err(ApiError::bad_request("limit must be between 1 and 500"));
     }
     if query.offset < 0 {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: "offset must be greater than or equal to 0".to_string(),
-            }),
+        return Err(ApiError::bad_request(
+            "offset must be greater than or equal to 0",
         ));
     }
 
@@ -227,37 +224,16 @@ pub async fn list_quality_profiles(
         .quality_profile_repository
         .list(5000, 0)
         .await
-        .map_err(|error| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse {
-                    error: format!("failed to list quality profiles: {error}"),
-                }),
-            )
-        })?;
+        .map_err(|error| ApiError::internal(error, "failed to list quality profiles"))?;
 
     let total = all_profiles.len() as i64;
     let offset = match usize::try_from(query.offset) {
         Ok(offset) => offset,
-        Err(_) => {
-            return Err((
-                StatusCode::BAD_REQUEST,
-                Json(ErrorResponse {
-                    error: "offset out of valid range".to_string(),
-                }),
-            ))
-        }
+        Err(_) => return Err(ApiError::bad_request("offset out of valid range")),
     };
     let limit = match usize::try_from(query.limit) {
         Ok(limit) => limit,
-        Err(_) => {
-            return Err((
-                StatusCode::BAD_REQUEST,
-                Json(ErrorResponse {
-                    error: "limit out of valid range".to_string(),
-                }),
-            ))
-        }
+        Err(_) => return Err(ApiError::bad_request("limit out of valid range")),
     };
     let items = all_profiles
         .into_iter()
@@ -280,8 +256,8 @@ pub async fn list_quality_profiles(
     params(("id" = String, Path, description = "Quality profile ID")),
     responses(
         (status = 200, description = "Quality profile found", body = QualityProfileResponse),
-        (status = 404, description = "Quality profile not found", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 404, description = "Quality profile not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "settings"
 )]
@@ -295,20 +271,10 @@ pub async fn get_quality_profile(
         Ok(Some(profile)) => {
             (StatusCode::OK, Json(QualityProfileResponse::from(profile))).into_response()
         }
-        Ok(None) => (
-            StatusCode::NOT_FOUND,
-            Json(ErrorResponse {
-                error: format!("Quality profile {} not found", id),
-            }),
-        )
-            .into_response(),
-        Err(error) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: format!("failed to fetch quality profile: {error}"),
-            }),
-        )
-            .into_response(),
+        Ok(None) => {
+            ApiError::not_found(format!("Quality profile {} not found", id)).into_response()
+        }
+        Err(error) => ApiError::internal(error, "failed to fetch quality profile").into_response(),
     }
 }
 
@@ -318,8 +284,8 @@ pub async fn get_quality_profile(
     request_body = CreateQualityProfileRequest,
     responses(
         (status = 201, description = "Quality profile created", body = QualityProfileResponse),
-        (status = 400, description = "Invalid request", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "settings"
 )]
@@ -339,6 +305,9 @@ pub async fn create_quality_profile(
     let mut profile = QualityProfile::new(request.name, request.allowed_qualities);
     profile.upgrade_allowed = request.upgrade_allowed.unwrap_or(false);
     profile.cutoff_quality = request.cutoff_quality;
+    if let Err(err_response) = validate_profile(&profile) {
+        return err_response.into_response();
+    }
 
     match state.quality_profile_repository.create(profile).await {
         Ok(created) => (
@@ -346,13 +315,7 @@ pub async fn create_quality_profile(
             Json(QualityProfileResponse::from(created)),
         )
             .into_response(),
-        Err(error) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: format!("failed to create quality profile: {error}"),
-            }),
-        )
-            .into_response(),
+        Err(error) => ApiError::internal(error, "failed to create quality profile").into_response(),
     }
 }
 
@@ -363,9 +326,10 @@ pub async fn create_quality_profile(
     request_body = UpdateQualityProfileRequest,
     responses(
         (status = 200, description = "Quality profile updated", body = QualityProfileResponse),
-        (status = 400, description = "Invalid request", body = ErrorResponse),
-        (status = 404, description = "Quality profile not found", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 404, description = "Quality profile not found", body = ApiErrorResponse),
+        (status = 409, description = "Quality profile was modified concurrently", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "settings"
 )]
@@ -379,22 +343,10 @@ pub async fn update_quality_profile(
     let mut profile = match state.quality_profile_repository.get_by_id(&id).await {
         Ok(Some(profile)) => profile,
         Ok(None) => {
-            return (
-                StatusCode::NOT_FOUND,
-                Json(ErrorResponse {
-                    error: format!("Quality profile {} not found", id),
-                }),
-            )
-                .into_response()
+            return ApiError::not_found(format!("Quality profile {} not found", id)).into_response()
         }
         Err(error) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse {
-                    error: format!("failed to fetch quality profile: {error}"),
-                }),
-            )
-                .into_response()
+            return ApiError::internal(error, "failed to fetch quality profile").into_response()
         }
     };
 
@@ -416,18 +368,18 @@ pub async fn update_quality_profile(
     if let Some(cutoff_quality) = request.cutoff_quality {
         profile.cutoff_quality = cutoff_quality;
     }
+    if let Err(err_response) = validate_profile(&profile) {
+        return err_response.into_response();
+    }
 
     match state.quality_profile_repository.update(profile).await {
         Ok(updated) => {
             (StatusCode::OK, Json(QualityProfileResponse::from(updated))).into_response()
         }
-        Err(error) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: format!("failed to update quality profile: {error}"),
-            }),
-        )
-            .into_response(),
+        Err(error) if is_concurrency_conflict(&error) => {
+            ApiError::conflict(error.to_string()).into_response()
+        }
+        Err(error) => ApiError::internal(error, "failed to update quality profile").into_response(),
     }
 }
 
@@ -437,8 +389,9 @@ pub async fn update_quality_profile(
     params(("id" = String, Path, description = "Quality profile ID")),
     responses(
         (status = 204, description = "Quality profile deleted"),
-        (status = 404, description = "Quality profile not found", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 404, description = "Quality profile not found", body = ApiErrorResponse),
+        (status = 409, description = "Quality profile is assigned to artists", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "settings"
 )]
@@ -450,44 +403,79 @@ pub async fn delete_quality_profile(
 
     match state.quality_profile_repository.get_by_id(&id).await {
         Ok(Some(_)) => {
+            match assigned_artists_error(&state, &id).await {
+                Ok(Some(error)) => return ApiError::conflict(error).into_response(),
+                Ok(None) => {}
+                Err(error) => {
+                    return ApiError::internal(error, "failed to check quality profile usage")
+                        .into_response()
+                }
+            }
             match state.quality_profile_repository.delete(&id).await {
                 Ok(_) => StatusCode::NO_CONTENT.into_response(),
                 Err(delete_error) => {
                     // Recheck existence to distinguish concurrent deletion (404)
                     // from a transient delete failure (500).
                     match state.quality_profile_repository.get_by_id(&id).await {
-                        Ok(None) => (
-                            StatusCode::NOT_FOUND,
-                            Json(ErrorResponse {
-                                error: format!("Quality profile {} not found", id),
-                            }),
-                        )
-                            .into_response(),
-                        Ok(Some(_)) | Err(_) => (
-                            StatusCode::INTERNAL_SERVER_ERROR,
-                            Json(ErrorResponse {
-                                error: format!("failed to delete quality profile: {delete_error}"),
-                            }),
-                        )
-                            .into_response(),
+                        Ok(None) => {
+                            ApiError::not_found(format!("Quality profile {} not found", id))
+                                .into_response()
+                        }
+                        Ok(Some(_)) | Err(_) => {
+                            ApiError::internal(delete_error, "failed to delete quality profile")
+                                .into_response()
+                        }
                     }
                 }
             }
         }
-        Ok(None) => (
-            StatusCode::NOT_FOUND,
-            Json(ErrorResponse {
-                error: format!("Quality profile {} not found", id),
-            }),
-        )
+        Ok(None) => {
+            ApiError::not_found(format!("Quality profile {} not found", id)).into_response()
+        }
+        Err(error) => ApiError::internal(error, "failed to fetch quality profile before delete")
             .into_response(),
-        Err(error) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: format!("failed to fetch quality profile before delete: {error}"),
-            }),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/settings/quality-profiles/{id}/default",
+    params(("id" = String, Path, description = "Quality profile ID")),
+    responses(
+        (status = 200, description = "Quality profile is now the default", body = QualityProfileResponse),
+        (status = 404, description = "Quality profile not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
+    ),
+    tag = "settings"
+)]
+pub async fn set_default_quality_profile(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    debug!(target: "api", %id, "setting default quality profile");
+
+    let profile = match state.quality_profile_repository.get_by_id(&id).await {
+        Ok(Some(profile)) => profile,
+        Ok(None) => {
+            return ApiError::not_found(format!("Quality profile {} not found", id)).into_response()
+        }
+        Err(error) => {
+            return ApiError::internal(error, "failed to fetch quality profile").into_response()
+        }
+    };
+
+    match state.quality_profile_repository.set_default(&id).await {
+        Ok(()) => (
+            StatusCode::OK,
+            Json(QualityProfileResponse::from(QualityProfile {
+                is_default: true,
+                ..profile
+            })),
         )
             .into_response(),
+        Err(error) => {
+            ApiError::internal(error, "failed to set default quality profile").into_response()
+        }
     }
 }
 
@@ -498,7 +486,7 @@ pub async fn delete_quality_profile(
     responses(
         (status = 200, description = "Bulk action completed", body = QualityProfileBulkResponse),
         (status = 207, description = "Bulk action partially succeeded", body = QualityProfileBulkResponse),
-        (status = 400, description = "Invalid request", body = ErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse)
     ),
     tag = "settings"
 )]
@@ -507,39 +495,39 @@ pub async fn bulk_quality_profiles(
     Json(request): Json<QualityProfileBulkRequest>,
 ) -> impl IntoResponse {
     if request.ids.is_empty() {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: "ids must contain at least one item".to_string(),
-            }),
-        )
-            .into_response();
+        return ApiError::bad_request("ids must contain at least one item").into_response();
     }
 
     if !matches!(request.action.as_str(), "delete") {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: "action must be one of: delete".to_string(),
-            }),
-        )
-            .into_response();
+        return ApiError::bad_request("action must be one of: delete").into_response();
     }
 
     let mut results = Vec::with_capacity(request.ids.len());
 
     for id in request.ids {
         let result = match request.action.as_str() {
-            "delete" => match state.quality_profile_repository.delete(&id).await {
-                Ok(_) => QualityProfileBulkItemResult {
+            "delete" => match assigned_artists_error(&state, &id).await {
+                Ok(Some(error)) => QualityProfileBulkItemResult {
                     id,
-                    success: true,
-                    error: None,
+                    success: false,
+                    error: Some(error),
                 },
                 Err(error) => QualityProfileBulkItemResult {
                     id,
                     success: false,
-                    error: Some(format!("failed to delete quality profile: {error}")),
+                    error: Some(format!("failed to check quality profile usage: {error}")),
+                },
+                Ok(None) => match state.quality_profile_repository.delete(&id).await {
+                    Ok(_) => QualityProfileBulkItemResult {
+                        id,
+                        success: true,
+                        error: None,
+                    },
+                    Err(error) => QualityProfileBulkItemResult {
+                        id,
+                        success: false,
+                        error: Some(format!("failed to delete quality profile: {error}")),
+                    },
                 },
             },
             _ => unreachable!(),
@@ -563,7 +551,7 @@ pub async fn bulk_quality_profiles(
     path = "/api/v1/settings/quality-profiles/export",
     responses(
         (status = 200, description = "Export quality profiles", body = QualityProfileExportEnvelope),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "settings"
 )]
@@ -586,13 +574,9 @@ pub async fn export_quality_profiles(State(state): State<AppState>) -> impl Into
             }),
         )
             .into_response(),
-        Err(error) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: format!("failed to export quality profiles: {error}"),
-            }),
-        )
-            .into_response(),
+        Err(error) => {
+            ApiError::internal(error, "failed to export quality profiles").into_response()
+        }
     }
 }
 
@@ -603,8 +587,8 @@ pub async fn export_quality_profiles(State(state): State<AppState>) -> impl Into
     request_body = QualityProfileImportRequest,
     responses(
         (status = 200, description = "Import processed", body = QualityProfileImportResponse),
-        (status = 400, description = "Invalid request", body = QualityProfileImportErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "settings"
 )]
@@ -614,47 +598,45 @@ pub async fn import_quality_profiles(
     Json(request): Json<QualityProfileImportRequest>,
 ) -> impl IntoResponse {
     if request.version.trim() != "1" {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(QualityProfileImportErrorResponse {
-                error: "unsupported import version".to_string(),
-                details: vec!["version must be '1'".to_string()],
-            }),
+        return ApiError::invalid_payload(
+            "unsupported import version",
+            [("version".to_string(), "must be '1'".to_string())],
         )
-            .into_response();
+        .into_response();
     }
 
     let mut validation_errors = Vec::new();
     for (idx, item) in request.items.iter().enumerate() {
         if item.name.trim().is_empty() {
-            validation_errors.push(format!("items[{idx}].name cannot be empty"));
+            validation_errors.push((format!("items[{idx}].name"), "cannot be empty".to_string()));
         }
         if validate_allowed_qualities(&item.allowed_qualities).is_err() {
-            validation_errors.push(format!(
-                "items[{idx}].allowed_qualities must contain at least one value"
+            validation_errors.push((
+                format!("items[{idx}].allowed_qualities"),
+                "must contain at least one value".to_string(),
             ));
+        } else if let Some(cutoff) = &item.cutoff_quality {
+            if !item
+                .allowed_qualities
+                .iter()
+                .any(|quality| quality.eq_ignore_ascii_case(cutoff))
+            {
+                validation_errors.push((
+                    format!("items[{idx}].cutoff_quality"),
+                    "must be one of allowed_qualities".to_string(),
+                ));
+            }
         }
     }
     if !validation_errors.is_empty() {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(QualityProfileImportErrorResponse {
-                error: "invalid import payload".to_string(),
-                details: validation_errors,
-            }),
-        )
+        return ApiError::invalid_payload("invalid import payload", validation_errors)
             .into_response();
     }
 
     let existing = match state.quality_profile_repository.list(5000, 0).await {
         Ok(existing) => existing,
         Err(error) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse {
-                    error: format!("failed to read existing quality profiles: {error}"),
-                }),
-            )
+            return ApiError::internal(error, "failed to read existing quality profiles")
                 .into_response()
         }
     };
@@ -757,10 +739,27 @@ pub async fn import_quality_profiles(
     if matches!(request.conflict_policy, ImportConflictPolicy::ReplaceAll) {
         for existing_item in existing_by_name.values() {
             if !import_names.contains(&existing_item.name.to_lowercase()) {
-                let delete_result = state
-                    .quality_profile_repository
-                    .delete(&existing_item.id.to_string())
-                    .await;
+                let id = existing_item.id.to_string();
+                match assigned_artists_error(&state, &id).await {
+                    Ok(None) => {}
+                    Ok(Some(error)) => {
+                        results.push(QualityProfileBulkItemResult {
+                            id,
+                            success: false,
+                            error: Some(error),
+                        });
+                        continue;
+                    }
+                    Err(error) => {
+                        results.push(QualityProfileBulkItemResult {
+                            id,
+                            success: false,
+                            error: Some(format!("failed to check quality profile usage: {error}")),
+                        });
+                        continue;
+                    }
+                }
+                let delete_result = state.quality_profile_repository.delete(&id).await;
                 if let Err(error) = delete_result {
                     results.push(QualityProfileBulkItemResult {
                         id: existing_item.id.to_string(),
@@ -833,6 +832,11 @@ mod tests {
                         pool.clone(),
                     ),
                 ),
+                Arc::new(
+                    chorrosion_infrastructure::sqlite_adapters::SqliteTrackFileRepository::new(
+                        pool.clone(),
+                    ),
+                ),
                 chorrosion_infrastructure::ResponseCache::new(100, 60),
             )
         }
@@ -873,7 +877,7 @@ mod tests {
             };
             let result = list_quality_profiles(State(state), Query(query)).await;
             assert!(result.is_err());
-            let (status, _) = result.unwrap_err();
+            let status = result.unwrap_err().status();
             assert_eq!(status, StatusCode::BAD_REQUEST);
         }
 
@@ -886,7 +890,7 @@ mod tests {
             };
             let result = list_quality_profiles(State(state), Query(query)).await;
             assert!(result.is_err());
-            let (status, _) = result.unwrap_err();
+            let status = result.unwrap_err().status();
             assert_eq!(status, StatusCode::BAD_REQUEST);
         }
 
@@ -953,6 +957,21 @@ mod tests {
             assert_eq!(response.status(), StatusCode::CREATED);
         }
 
+        #[tokio::test]
+        async fn create_quality_profile_returns_400_for_cutoff_outside_allowed_qualities() {
+            let state = make_test_state().await;
+            let request = CreateQualityProfileRequest {
+                name: "Lossless".to_string(),
+                allowed_qualities: vec!["FLAC".to_string()],
+                upgrade_allowed: None,
+                cutoff_quality: Some("MP3-320".to_string()),
+            };
+            let response = create_quality_profile(State(state), Json(request))
+                .await
+                .into_response();
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        }
+
         #[tokio::test]
         async fn create_quality_profile_returns_400_for_empty_name() {
             let state = make_test_state().await;
@@ -1067,6 +1086,23 @@ mod tests {
             assert_eq!(response.status(), StatusCode::BAD_REQUEST);
         }
 
+        #[tokio::test]
+        async fn update_quality_profile_returns_400_when_cutoff_leaves_allowed_qualities() {
+            let state = make_test_state().await;
+            let profile = create_test_profile(&state).await;
+            let request = UpdateQualityProfileRequest {
+                name: None,
+                allowed_qualities: None,
+                upgrade_allowed: None,
+                cutoff_quality: Some(Some("MP3-320".to_string())),
+            };
+            let response =
+                update_quality_profile(State(state), Path(profile.id.to_string()), Json(request))
+                    .await
+                    .into_response();
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        }
+
         #[tokio::test]
         async fn update_quality_profile_allows_clearing_cutoff_quality_with_null() {
             let state = make_test_state().await;
@@ -1115,6 +1151,89 @@ mod tests {
             assert_eq!(response.status(), StatusCode::NO_CONTENT);
         }
 
+        async fn assign_to_artist(state: &AppState, profile: &chorrosion_domain::QualityProfile) {
+            let mut artist = chorrosion_domain::Artist::new("Assigned Artist");
+            artist.quality_profile_id = Some(profile.id);
+            state
+                .artist_repository
+                .create(artist)
+                .await
+                .expect("create artist");
+        }
+
+        #[tokio::test]
+        async fn set_default_quality_profile_marks_the_profile_as_default() {
+            let state = make_test_state().await;
+            let profile = create_test_profile(&state).await;
+
+            let response =
+                set_default_quality_profile(State(state.clone()), Path(profile.id.to_string()))
+                    .await
+                    .into_response();
+            assert_eq!(response.status(), StatusCode::OK);
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let body: QualityProfileResponse = serde_json::from_slice(&body).unwrap();
+            assert!(body.is_default);
+
+            let default = state
+                .quality_profile_repository
+                .get_default()
+                .await
+                .expect("get_default");
+            assert_eq!(default.map(|p| p.id), Some(profile.id));
+
+            let response = set_default_quality_profile(State(state), Path("missing".to_string()))
+                .await
+                .into_response();
+            assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        }
+
+        #[tokio::test]
+        async fn delete_quality_profile_returns_409_when_assigned_to_artist() {
+            let state = make_test_state().await;
+            let profile = create_test_profile(&state).await;
+            assign_to_artist(&state, &profile).await;
+
+            let response =
+                delete_quality_profile(State(state.clone()), Path(profile.id.to_string()))
+                    .await
+                    .into_response();
+            assert_eq!(response.status(), StatusCode::CONFLICT);
+
+            let fetched = state
+                .quality_profile_repository
+                .get_by_id(&profile.id.to_string())
+                .await
+                .expect("get_by_id");
+            assert!(fetched.is_some(), "assigned profile should be kept");
+        }
+
+        #[tokio::test]
+        async fn bulk_quality_profiles_delete_skips_assigned_profiles() {
+            let state = make_test_state().await;
+            let profile = create_test_profile(&state).await;
+            assign_to_artist(&state, &profile).await;
+
+            let response = bulk_quality_profiles(
+                State(state.clone()),
+                Json(QualityProfileBulkRequest {
+                    action: "delete".to_string(),
+                    ids: vec![profile.id.to_string()],
+                }),
+            )
+            .await
+            .into_response();
+            assert_eq!(response.status(), StatusCode::MULTI_STATUS);
+            assert!(state
+                .quality_profile_repository
+                .get_by_id(&profile.id.to_string())
+                .await
+                .expect("get_by_id")
+                .is_some());
+        }
+
         #[tokio::test]
         async fn delete_quality_profile_returns_404_for_unknown_id() {
             let state = make_test_state().await;
@@ -0,0 +1,238 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        Query, State,
+    },
+    response::Response,
+};
+use chorrosion_application::AppState;
+use chorrosion_realtime::RealtimeMessage;
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+use tracing::{debug, warn};
+use utoipa::IntoParams;
+
+#[derive(Debug, Default, Deserialize, IntoParams)]
+pub struct WebSocketQuery {
+    /// Comma-separated channels to subscribe to; all channels when omitted.
+    pub channels: Option<String>,
+}
+
+impl WebSocketQuery {
+    fn channel_list(&self) -> Vec<String> {
+        self.channels
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|channel| !channel.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+}
+
+/// First frame sent to a client once its subscription is active.
+#[derive(Debug, Serialize)]
+struct SubscribedFrame<'a> {
+    status: &'static str,
+    channels: &'a [String],
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/ws",
+    params(WebSocketQuery),
+    responses(
+        (status = 101, description = "WebSocket upgrade for real-time channel messages"),
+        (status = 400, description = "Request is not a valid WebSocket upgrade", body = String, content_type = "text/plain")
+    ),
+    tag = "activity"
+)]
+pub async fn websocket_handler(
+    State(state): State<AppState>,
+    ws: WebSocketUpgrade,
+    Query(query): Query<WebSocketQuery>,
+) -> Response {
+    let channels = query.channel_list();
+    debug!(target: "api", ?channels, "upgrading realtime websocket");
+    let receiver = state.realtime_hub.subscribe();
+    ws.on_upgrade(move |socket| serve_socket(socket, receiver, channels))
+}
+
+/// Forward hub messages on the requested channels to one client until either
+/// side goes away. Clients that lag behind the hub are disconnected rather
+/// than slowing down the broadcaster.
+async fn serve_socket(
+    mut socket: WebSocket,
+    mut receiver: broadcast::Receiver<RealtimeMessage>,
+    channels: Vec<String>,
+) {
+    let subscribed = serde_json::to_string(&SubscribedFrame {
+        status: "subscribed",
+        channels: &channels,
+    })
+    .expect("SubscribedFrame is always serializable");
+    if socket.send(Message::Text(subscribed)).await.is_err() {
+        return;
+    }
+
+    loop {
+        tokio::select! {
+            received = receiver.recv() => match received {
+                Ok(message) if message.matches(&channels) => {
+                    let frame = serde_json::to_string(&message)
+                        .expect("RealtimeMessage is always serializable");
+                    if socket.send(Message::Text(frame)).await.is_err() {
+                        debug!(target: "api", "realtime websocket client went away");
+                        break;
+                    }
+                }
+                Ok(_) => {}
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    warn!(target: "api", skipped, "disconnecting lagging realtime websocket client");
+                    let _ = socket.send(Message::Close(None)).await;
+                    break;
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            },
+            incoming = socket.recv() => match incoming {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{routing::get, Router};
+    use chorrosion_realtime::RealtimeHub;
+    use futures_util::StreamExt;
+    use std::time::Duration;
+    use tokio_tungstenite::{connect_async, tungstenite, MaybeTlsStream, WebSocketStream};
+
+    type Client = WebSocketStream<MaybeTlsStream<tokio::net::TcpStream>>;
+
+    async fn make_test_state() -> AppState {
+        use chorrosion_config::AppConfig;
+        use chorrosion_infrastructure::sqlite_adapters::{
+            SqliteAlbumRepository, SqliteArtistRepository,
+            SqliteDownloadClientDefinitionRepository, SqliteDuplicateRepository,
+            SqliteIndexerDefinitionRepository, SqliteMetadataProfileRepository,
+            SqliteQualityProfileRepository, SqliteSmartPlaylistRepository, SqliteTagRepository,
+            SqliteTaggedEntityRepository, SqliteTrackFileRepository, SqliteTrackRepository,
+        };
+        use sqlx::sqlite::SqlitePoolOptions;
+        use std::sync::Arc;
+
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("in-memory SQLite");
+        AppState::new(
+            AppConfig::default(),
+            Arc::new(SqliteArtistRepository::new(pool.clone())),
+            Arc::new(SqliteAlbumRepository::new(pool.clone())),
+            Arc::new(SqliteTrackRepository::new(pool.clone())),
+            Arc::new(SqliteQualityProfileRepository::new(pool.clone())),
+            Arc::new(SqliteMetadataProfileRepository::new(pool.clone())),
+            Arc::new(SqliteIndexerDefinitionRepository::new(pool.clone())),
+            Arc::new(SqliteDownloadClientDefinitionRepository::new(pool.clone())),
+            Arc::new(SqliteTagRepository::new(pool.clone())),
+            Arc::new(SqliteTaggedEntityRepository::new(pool.clone())),
+            Arc::new(SqliteSmartPlaylistRepository::new(pool.clone())),
+            Arc::new(SqliteDuplicateRepository::new(pool.clone())),
+            Arc::new(SqliteTrackFileRepository::new(pool)),
+            chorrosion_infrastructure::ResponseCache::new(100, 60),
+        )
+    }
+
+    /// Serve `/ws` over `state`, which tests broadcast through.
+    async fn spawn_server(state: AppState) -> std::net::SocketAddr {
+        let app = Router::new()
+            .route("/ws", get(websocket_handler))
+            .with_state(state);
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind test listener");
+        let addr = listener.local_addr().expect("listener address");
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.expect("serve");
+        });
+        addr
+    }
+
+    async fn next_json(client: &mut Client) -> serde_json::Value {
+        let frame = tokio::time::timeout(Duration::from_secs(5), client.next())
+            .await
+            .expect("frame within timeout")
+            .expect("stream open")
+            .expect("valid frame");
+        match frame {
+            tungstenite::Message::Text(text) => serde_json::from_str(&text).expect("json frame"),
+            other => panic!("unexpected frame: {other:?}"),
+        }
+    }
+
+    /// Connect and wait for the subscription acknowledgement so broadcasts
+    /// sent afterwards are guaranteed to be observed.
+    async fn connect(addr: std::net::SocketAddr, channels: &str) -> Client {
+        let url = format!("ws://{addr}/ws?channels={channels}");
+        let (mut client, _) = connect_async(url).await.expect("websocket connect");
+        let ack = next_json(&mut client).await;
+        assert_eq!(ack["status"], "subscribed");
+        client
+    }
+
+    #[test]
+    fn channel_list_splits_and_trims() {
+        let query = WebSocketQuery {
+            channels: Some(" downloads, ,imports ".to_string()),
+        };
+        assert_eq!(query.channel_list(), vec!["downloads", "imports"]);
+        assert!(WebSocketQuery::default().channel_list().is_empty());
+    }
+
+    #[tokio::test]
+    async fn subscribed_clients_receive_broadcast_on_their_channel() {
+        let state = make_test_state().await;
+        let addr = spawn_server(state.clone()).await;
+        let mut first = connect(addr, "ws-test-downloads").await;
+        let mut second = connect(addr, "ws-test-downloads,ws-test-other").await;
+
+        state
+            .realtime_hub
+            .broadcast("ws-test-downloads", "{\"progress\":42}")
+            .await;
+
+        for client in [&mut first, &mut second] {
+            let message = next_json(client).await;
+            assert_eq!(message["channel"], "ws-test-downloads");
+            assert_eq!(message["payload"], "{\"progress\":42}");
+        }
+    }
+
+    #[tokio::test]
+    async fn client_on_other_channel_does_not_receive_broadcast() {
+        let state = make_test_state().await;
+        let addr = spawn_server(state.clone()).await;
+        let mut imports = connect(addr, "ws-test-imports").await;
+
+        state
+            .realtime_hub
+            .broadcast("ws-test-jobs", "{\"job\":1}")
+            .await;
+        state
+            .realtime_hub
+            .broadcast("ws-test-imports", "{\"import\":1}")
+            .await;
+
+        // The first frame the client sees is the one for its own channel.
+        let message = next_json(&mut imports).await;
+        assert_eq!(message["channel"], "ws-test-imports");
+        assert_eq!(message["payload"], "{\"import\":1}");
+    }
+}
@@ -1,10 +1,17 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
+use crate::error::{ApiError, ApiErrorResponse};
 use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
 use chorrosion_application::{
-    manual_search, AppState, AudioQuality, CustomFormatRule, IndexerConfig, IndexerError,
-    IndexerProtocol, ManualSearchRequest, NewznabClient, ReleaseFilterOptions, TorznabClient,
+    download_client_for, manual_search, AddTorrentRequest, AppState, AudioQuality,
+    CustomFormatRule, DownloadClient, DownloadClientError, IndexerClient, IndexerError,
+    IndexerProtocol, ManualSearchRequest, RankedRelease, ReleaseFilterOptions,
 };
+use chorrosion_config::SearchConfig;
+use chorrosion_domain::{DownloadClientDefinition, IndexerDefinition};
+use futures_util::future::join_all;
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::{debug, warn};
 use utoipa::ToSchema;
 
 const MAX_CUSTOM_FORMAT_SCORE_BONUS: i32 = 10_000;
@@ -23,6 +30,8 @@ pub struct ManualSearchApiRequest {
     #[serde(default)]
     pub preferred_words: Vec<String>,
     #[serde(default)]
+    pub rejected_words: Vec<String>,
+    #[serde(default)]
     pub custom_format_rules: Vec<ManualSearchCustomFormatRule>,
 }
 
@@ -54,23 +63,198 @@ pub struct ManualSearchResultItem {
     pub parsed_quality: String,
     pub parsed_bitrate_kbps: Option<u32>,
     pub parsed_release_group: Option<String>,
+    /// The title carries a bootleg or unofficial-source marker.
+    pub parsed_likely_unofficial: bool,
+    pub score: i32,
+}
+
+impl From<RankedRelease> for ManualSearchResultItem {
+    fn from(result: RankedRelease) -> Self {
+        Self {
+            title: result.search_result.title,
+            guid: result.search_result.guid,
+            download_url: result.search_result.download_url,
+            published_at: result.search_result.published_at,
+            size_bytes: result.search_result.size_bytes,
+            seeders: result.search_result.seeders,
+            leechers: result.search_result.leechers,
+            parsed_artist: result.parsed.artist,
+            parsed_album: result.parsed.album,
+            parsed_quality: result.parsed.quality.as_str().to_string(),
+            parsed_bitrate_kbps: result.parsed.bitrate_kbps,
+            parsed_release_group: result.parsed.release_group,
+            parsed_likely_unofficial: result.parsed.likely_unofficial,
+            score: result.score,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SearchApiRequest {
+    pub artist: Option<String>,
+    pub album: Option<String>,
+    pub query: Option<String>,
+    #[serde(default)]
+    pub preferred_qualities: Vec<String>,
+    pub min_bitrate_kbps: Option<u32>,
+    #[serde(default)]
+    pub preferred_release_groups: Vec<String>,
+    #[serde(default)]
+    pub preferred_words: Vec<String>,
+    #[serde(default)]
+    pub rejected_words: Vec<String>,
+    #[serde(default)]
+    pub custom_format_rules: Vec<ManualSearchCustomFormatRule>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
-pub struct SearchErrorResponse {
+pub struct SearchResultItem {
+    pub indexer: String,
+    #[serde(flatten)]
+    pub release: ManualSearchResultItem,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SearchIndexerFailure {
+    pub indexer: String,
     pub error: String,
 }
 
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SearchApiResponse {
+    pub items: Vec<SearchResultItem>,
+    pub total: usize,
+    pub failed_indexers: Vec<SearchIndexerFailure>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct GrabReleaseRequest {
+    pub download_url: String,
+    /// Download client to use; defaults to the first enabled supported client.
+    pub download_client_id: Option<String>,
+    /// Overrides the download client's configured category.
+    pub category: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GrabReleaseResponse {
+    pub download_client_id: String,
+    pub download_client: String,
+    pub download_url: String,
+}
+
+fn non_empty(value: Option<&str>) -> Option<String> {
+    value
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_owned)
+}
+
+fn build_manual_request(
+    artist: Option<&str>,
+    album: Option<&str>,
+    query: Option<&str>,
+) -> Result<ManualSearchRequest, String> {
+    let request = ManualSearchRequest {
+        artist: non_empty(artist),
+        album: non_empty(album),
+        query: non_empty(query),
+    };
+    if request.artist.is_none() && request.album.is_none() && request.query.is_none() {
+        return Err("at least one of artist, album, or query must be provided".to_string());
+    }
+    Ok(request)
+}
+
+fn build_filter_options(
+    search_config: &SearchConfig,
+    preferred_qualities: &[String],
+    min_bitrate_kbps: Option<u32>,
+    preferred_release_groups: Vec<String>,
+    preferred_words: Vec<String>,
+    rejected_words: Vec<String>,
+    custom_format_rules: Vec<ManualSearchCustomFormatRule>,
+) -> Result<ReleaseFilterOptions, String> {
+    Ok(ReleaseFilterOptions {
+        preferred_qualities: parse_preferred_qualities(preferred_qualities)?,
+        min_bitrate_kbps,
+        preferred_release_groups,
+        preferred_words,
+        rejected_words,
+        custom_format_rules: parse_custom_format_rules(custom_format_rules)?,
+        ..ReleaseFilterOptions::from(search_config)
+    })
+}
+
+/// Whether indexers speaking `protocol` can run an interactive search.
+fn supports_interactive_search(protocol: &IndexerProtocol) -> bool {
+    matches!(
+        protocol,
+        IndexerProtocol::Newznab | IndexerProtocol::Torznab
+    )
+}
+
+/// Search client for a stored indexer definition, or an error message when its
+/// protocol is unknown or has no interactive search support.
+fn interactive_search_client(
+    state: &AppState,
+    definition: &IndexerDefinition,
+) -> Result<Arc<dyn IndexerClient>, String> {
+    let client = state
+        .indexer_clients
+        .for_definition(definition)
+        .map_err(|error| format!("invalid indexer protocol: {error}"))?;
+    if !supports_interactive_search(&client.config().protocol) {
+        return Err(
+            "interactive manual search currently supports newznab/torznab indexers".to_string(),
+        );
+    }
+    Ok(client)
+}
+
+/// Search clients for every enabled indexer with interactive search support.
+pub(crate) async fn searchable_indexers(
+    state: &AppState,
+) -> Result<Vec<Arc<dyn IndexerClient>>, axum::response::Response> {
+    let definitions = state
+        .indexer_definition_repository
+        .list(1000, 0)
+        .await
+        .map_err(|error| ApiError::internal(error, "failed to list indexers").into_response())?;
+
+    let clients: Vec<Arc<dyn IndexerClient>> = definitions
+        .iter()
+        .filter(|definition| definition.enabled)
+        .filter_map(
+            |definition| match interactive_search_client(state, definition) {
+                Ok(client) => Some(client),
+                Err(error) => {
+                    debug!(target: "api", indexer = %definition.name, protocol = %definition.protocol, %error, "skipping indexer without interactive search support");
+                    None
+                }
+            },
+        )
+        .collect();
+
+    if clients.is_empty() {
+        return Err(
+            ApiError::bad_request("no enabled newznab/torznab indexers are configured")
+                .into_response(),
+        );
+    }
+    Ok(clients)
+}
+
 #[utoipa::path(
     post,
     path = "/api/v1/search/manual",
     request_body = ManualSearchApiRequest,
     responses(
         (status = 200, description = "Manual search results", body = ManualSearchApiResponse),
-        (status = 400, description = "Invalid request", body = SearchErrorResponse),
-        (status = 404, description = "Indexer not found", body = SearchErrorResponse),
-        (status = 500, description = "Internal server error", body = SearchErrorResponse),
-        (status = 502, description = "Indexer search failed", body = SearchErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 404, description = "Indexer not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+        (status = 502, description = "Indexer search failed", body = ApiErrorResponse)
     ),
     tag = "search"
 )]
@@ -79,69 +263,29 @@ pub async fn manual_search_endpoint(
     Json(request): Json<ManualSearchApiRequest>,
 ) -> impl IntoResponse {
     if request.indexer_id.trim().is_empty() {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(SearchErrorResponse {
-                error: "indexer_id is required".to_string(),
-            }),
-        )
-            .into_response();
-    }
-
-    let artist = request
-        .artist
-        .as_deref()
-        .map(str::trim)
-        .filter(|s| !s.is_empty())
-        .map(str::to_owned);
-    let album = request
-        .album
-        .as_deref()
-        .map(str::trim)
-        .filter(|s| !s.is_empty())
-        .map(str::to_owned);
-    let query = request
-        .query
-        .as_deref()
-        .map(str::trim)
-        .filter(|s| !s.is_empty())
-        .map(str::to_owned);
-
-    if artist.is_none() && album.is_none() && query.is_none() {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(SearchErrorResponse {
-                error: "at least one of artist, album, or query must be provided".to_string(),
-            }),
-        )
-            .into_response();
+        return ApiError::bad_request("indexer_id is required").into_response();
     }
 
-    let preferred_qualities = match parse_preferred_qualities(&request.preferred_qualities) {
-        Ok(values) => values,
-        Err(error) => {
-            return (StatusCode::BAD_REQUEST, Json(SearchErrorResponse { error })).into_response();
-        }
-    };
-    let custom_format_rules = match parse_custom_format_rules(request.custom_format_rules) {
-        Ok(values) => values,
-        Err(error) => {
-            return (StatusCode::BAD_REQUEST, Json(SearchErrorResponse { error })).into_response();
-        }
-    };
-
-    let options = ReleaseFilterOptions {
-        preferred_qualities,
-        min_bitrate_kbps: request.min_bitrate_kbps,
-        preferred_release_groups: request.preferred_release_groups,
-        preferred_words: request.preferred_words,
-        custom_format_rules,
+    let manual_request = match build_manual_request(
+        request.artist.as_deref(),
+        request.album.as_deref(),
+        request.query.as_deref(),
+    ) {
+        Ok(manual_request) => manual_request,
+        Err(error) => return ApiError::bad_request(error).into_response(),
     };
 
-    let manual_request = ManualSearchRequest {
-        artist,
-        album,
-        query,
+    let options = match build_filter_options(
+        &state.config.search,
+        &request.preferred_qualities,
+        request.min_bitrate_kbps,
+        request.preferred_release_groups,
+        request.preferred_words,
+        request.rejected_words,
+        request.custom_format_rules,
+    ) {
+        Ok(options) => options,
+        Err(error) => return ApiError::bad_request(error).into_response(),
     };
 
     let indexer = match state
@@ -151,87 +295,24 @@ pub async fn manual_search_endpoint(
     {
         Ok(Some(indexer)) => indexer,
         Ok(None) => {
-            return (
-                StatusCode::NOT_FOUND,
-                Json(SearchErrorResponse {
-                    error: format!("Indexer {} not found", request.indexer_id),
-                }),
-            )
+            return ApiError::not_found(format!("Indexer {} not found", request.indexer_id))
                 .into_response();
         }
         Err(error) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(SearchErrorResponse {
-                    error: format!("failed to fetch indexer: {error}"),
-                }),
-            )
-                .into_response();
+            return ApiError::internal(error, "failed to fetch indexer").into_response();
         }
     };
 
-    let protocol = match indexer.protocol.parse::<IndexerProtocol>() {
-        Ok(protocol) => protocol,
-        Err(error) => {
-            return (
-                StatusCode::BAD_REQUEST,
-                Json(SearchErrorResponse {
-                    error: format!("invalid indexer protocol: {error}"),
-                }),
-            )
-                .into_response();
-        }
+    let client = match interactive_search_client(&state, &indexer) {
+        Ok(client) => client,
+        Err(error) => return ApiError::bad_request(error).into_response(),
     };
 
-    let config = IndexerConfig {
-        name: indexer.name,
-        base_url: indexer.base_url,
-        protocol: protocol.clone(),
-        api_key: indexer.api_key,
-        enabled: indexer.enabled,
-    };
-
-    let ranked_results = match protocol {
-        IndexerProtocol::Newznab => {
-            let client = NewznabClient::new(config);
-            let result = manual_search(&client, &manual_request, &options).await;
-            result
-        }
-        IndexerProtocol::Torznab => {
-            let client = TorznabClient::new(config);
-            let result = manual_search(&client, &manual_request, &options).await;
-            result
-        }
-        IndexerProtocol::Gazelle | IndexerProtocol::Custom => {
-            return (
-                StatusCode::BAD_REQUEST,
-                Json(SearchErrorResponse {
-                    error: "interactive manual search currently supports newznab/torznab indexers"
-                        .to_string(),
-                }),
-            )
-                .into_response();
-        }
-    };
-
-    match ranked_results {
+    match manual_search(client.as_ref(), &manual_request, &options).await {
         Ok(results) => {
             let items = results
                 .into_iter()
-                .map(|result| ManualSearchResultItem {
-                    title: result.search_result.title,
-                    guid: result.search_result.guid,
-                    download_url: result.search_result.download_url,
-                    published_at: result.search_result.published_at,
-                    size_bytes: result.search_result.size_bytes,
-                    seeders: result.search_result.seeders,
-                    leechers: result.search_result.leechers,
-                    parsed_artist: result.parsed.artist,
-                    parsed_album: result.parsed.album,
-                    parsed_quality: result.parsed.quality.as_str().to_string(),
-                    parsed_bitrate_kbps: result.parsed.bitrate_kbps,
-                    parsed_release_group: result.parsed.release_group,
-                })
+                .map(ManualSearchResultItem::from)
                 .collect::<Vec<_>>();
 
             (
@@ -243,21 +324,261 @@ pub async fn manual_search_endpoint(
             )
                 .into_response()
         }
-        Err(IndexerError::Request(msg)) => (
-            StatusCode::BAD_REQUEST,
-            Json(SearchErrorResponse {
-                error: format!("invalid search request: {msg}"),
-            }),
-        )
-            .into_response(),
-        Err(error) => (
-            StatusCode::BAD_GATEWAY,
-            Json(SearchErrorResponse {
-                error: format!("indexer search failed: {error}"),
-            }),
-        )
-            .into_response(),
+        Err(IndexerError::Request(msg)) => {
+            ApiError::bad_request(format!("invalid search request: {msg}")).into_response()
+        }
+        Err(error) => {
+            ApiError::bad_gateway(format!("indexer search failed: {error}")).into_response()
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/search",
+    request_body = SearchApiRequest,
+    responses(
+        (status = 200, description = "Ranked results from all enabled indexers", body = SearchApiResponse),
+        (status = 400, description = "Invalid request or no searchable indexers", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+        (status = 502, description = "Every indexer search failed", body = ApiErrorResponse)
+    ),
+    tag = "search"
+)]
+pub async fn search_endpoint(
+    State(state): State<AppState>,
+    Json(request): Json<SearchApiRequest>,
+) -> impl IntoResponse {
+    let manual_request = match build_manual_request(
+        request.artist.as_deref(),
+        request.album.as_deref(),
+        request.query.as_deref(),
+    ) {
+        Ok(manual_request) => manual_request,
+        Err(error) => return ApiError::bad_request(error).into_response(),
+    };
+
+    let options = match build_filter_options(
+        &state.config.search,
+        &request.preferred_qualities,
+        request.min_bitrate_kbps,
+        request.preferred_release_groups,
+        request.preferred_words,
+        request.rejected_words,
+        request.custom_format_rules,
+    ) {
+        Ok(options) => options,
+        Err(error) => return ApiError::bad_request(error).into_response(),
+    };
+
+    let clients = match searchable_indexers(&state).await {
+        Ok(clients) => clients,
+        Err(response) => return response,
+    };
+
+    let searched = clients.len();
+    let outcomes = join_all(clients.iter().map(|client| {
+        let manual_request = &manual_request;
+        let options = &options;
+        async move {
+            let outcome = manual_search(client.as_ref(), manual_request, options).await;
+            (client.config().name.clone(), outcome)
+        }
+    }))
+    .await;
+
+    let mut items = Vec::new();
+    let mut failed_indexers = Vec::new();
+    for (indexer, outcome) in outcomes {
+        match outcome {
+            Ok(results) => {
+                items.extend(results.into_iter().map(|result| SearchResultItem {
+                    indexer: indexer.clone(),
+                    release: ManualSearchResultItem::from(result),
+                }));
+            }
+            Err(error) => {
+                warn!(target: "api", %indexer, %error, "indexer search failed");
+                failed_indexers.push(SearchIndexerFailure {
+                    indexer,
+                    error: error.to_string(),
+                });
+            }
+        }
+    }
+
+    if failed_indexers.len() == searched {
+        return ApiError::bad_gateway(format!("all {searched} indexer searches failed"))
+            .into_response();
+    }
+
+    // Stable sort keeps each indexer's own ordering for equal scores.
+    items.sort_by_key(|item| std::cmp::Reverse(item.release.score));
+
+    (
+        StatusCode::OK,
+        Json(SearchApiResponse {
+            total: items.len(),
+            items,
+            failed_indexers,
+        }),
+    )
+        .into_response()
+}
+
+fn is_grabbable_url(download_url: &str) -> bool {
+    if download_url.starts_with("magnet:") {
+        return true;
     }
+    url::Url::parse(download_url)
+        .map(|url| matches!(url.scheme(), "http" | "https"))
+        .unwrap_or(false)
+}
+
+/// Why a release could not be handed to a download client.
+#[derive(Debug)]
+pub(crate) enum GrabError {
+    /// The release has no http(s) or magnet download URL.
+    InvalidUrl,
+    /// The download client refused the release.
+    Rejected(DownloadClientError),
+}
+
+impl std::fmt::Display for GrabError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidUrl => f.write_str("release has no http(s) or magnet download URL"),
+            Self::Rejected(error) => write!(f, "download client rejected the release: {error}"),
+        }
+    }
+}
+
+/// Send the release at `download_url` to `client`, filed under `category`.
+///
+/// Shared by manual grabs and automatic album searches.
+pub(crate) async fn grab_release(
+    client: &dyn DownloadClient,
+    definition: &DownloadClientDefinition,
+    download_url: &str,
+    category: Option<String>,
+) -> Result<(), GrabError> {
+    if !is_grabbable_url(download_url) {
+        return Err(GrabError::InvalidUrl);
+    }
+    debug!(target: "api", download_client = %definition.name, %download_url, "grabbing release");
+    client
+        .add_torrent(AddTorrentRequest {
+            torrent_or_magnet: download_url.to_string(),
+            category,
+        })
+        .await
+        .map_err(|error| {
+            warn!(target: "api", download_client = %definition.name, %error, "failed to grab release");
+            GrabError::Rejected(error)
+        })
+}
+
+pub(crate) async fn resolve_grab_client(
+    state: &AppState,
+    download_client_id: Option<&str>,
+) -> Result<DownloadClientDefinition, axum::response::Response> {
+    if let Some(id) = download_client_id {
+        return match state
+            .download_client_definition_repository
+            .get_by_id(id)
+            .await
+        {
+            Ok(Some(definition)) if !definition.enabled => Err(ApiError::bad_request(format!(
+                "Download client {} is disabled",
+                definition.name
+            ))
+            .into_response()),
+            Ok(Some(definition)) => Ok(definition),
+            Ok(None) => {
+                Err(ApiError::not_found(format!("Download client {id} not found")).into_response())
+            }
+            Err(error) => {
+                Err(ApiError::internal(error, "failed to fetch download client").into_response())
+            }
+        };
+    }
+
+    let definitions = state
+        .download_client_definition_repository
+        .list(1000, 0)
+        .await
+        .map_err(|error| {
+            ApiError::internal(error, "failed to list download clients").into_response()
+        })?;
+
+    definitions
+        .into_iter()
+        .find(|definition| {
+            definition.enabled && download_client_for(definition, &state.outbound_http).is_some()
+        })
+        .ok_or_else(|| {
+            ApiError::bad_request("no enabled download client is configured").into_response()
+        })
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/search/grab",
+    request_body = GrabReleaseRequest,
+    responses(
+        (status = 200, description = "Release sent to the download client", body = GrabReleaseResponse),
+        (status = 400, description = "Invalid request or no usable download client", body = ApiErrorResponse),
+        (status = 404, description = "Download client not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+        (status = 502, description = "Download client rejected the release", body = ApiErrorResponse)
+    ),
+    tag = "search"
+)]
+pub async fn grab_release_endpoint(
+    State(state): State<AppState>,
+    Json(request): Json<GrabReleaseRequest>,
+) -> impl IntoResponse {
+    let download_url = request.download_url.trim().to_string();
+    if download_url.is_empty() {
+        return ApiError::bad_request("download_url is required").into_response();
+    }
+    if !is_grabbable_url(&download_url) {
+        return ApiError::bad_request("download_url must be an http(s) URL or magnet link")
+            .into_response();
+    }
+
+    let definition = match resolve_grab_client(
+        &state,
+        non_empty(request.download_client_id.as_deref()).as_deref(),
+    )
+    .await
+    {
+        Ok(definition) => definition,
+        Err(response) => return response,
+    };
+
+    let Some(client) = download_client_for(&definition, &state.outbound_http) else {
+        return ApiError::bad_request(format!(
+            "download client type '{}' is not supported",
+            definition.client_type
+        ))
+        .into_response();
+    };
+
+    let category = non_empty(request.category.as_deref()).or_else(|| definition.category.clone());
+    if let Err(error) = grab_release(client.as_ref(), &definition, &download_url, category).await {
+        return ApiError::bad_gateway(error.to_string()).into_response();
+    }
+
+    (
+        StatusCode::OK,
+        Json(GrabReleaseResponse {
+            download_client_id: definition.id.to_string(),
+            download_client: definition.name,
+            download_url,
+        }),
+    )
+        .into_response()
 }
 
 fn parse_preferred_qualities(values: &[String]) -> Result<Vec<AudioQuality>, String> {
@@ -265,12 +586,15 @@ fn parse_preferred_qualities(values: &[String]) -> Result<Vec<AudioQuality>, Str
         .iter()
         .map(|value| match value.trim().to_ascii_lowercase().as_str() {
             "flac" => Ok(AudioQuality::Flac),
+            "flac_hires" => Ok(AudioQuality::FlacHiRes),
+            "dsd" => Ok(AudioQuality::Dsd),
+            "sacd" => Ok(AudioQuality::Sacd),
             "mp3" => Ok(AudioQuality::Mp3),
             "aac" => Ok(AudioQuality::Aac),
             "alac" => Ok(AudioQuality::Alac),
             "unknown" => Ok(AudioQuality::Unknown),
             other => Err(format!(
-                "unsupported quality '{}'; expected one of: flac, mp3, aac, alac, unknown",
+                "unsupported quality '{}'; expected one of: flac, flac_hires, dsd, sacd, mp3, aac, alac, unknown",
                 other
             )),
         })
@@ -365,6 +689,11 @@ mod tests {
                     pool.clone(),
                 ),
             ),
+            Arc::new(
+                chorrosion_infrastructure::sqlite_adapters::SqliteTrackFileRepository::new(
+                    pool.clone(),
+                ),
+            ),
             chorrosion_infrastructure::ResponseCache::new(100, 60),
         )
     }
@@ -375,6 +704,23 @@ mod tests {
         assert!(err.contains("unsupported quality"));
     }
 
+    #[test]
+    fn build_filter_options_applies_configured_torrent_limits() {
+        let search_config = SearchConfig {
+            min_seeders: Some(3),
+            reject_unknown_seeders: true,
+            max_age_days: Some(90),
+            ..SearchConfig::default()
+        };
+        let options =
+            build_filter_options(&search_config, &[], None, vec![], vec![], vec![], vec![])
+                .expect("valid options");
+
+        assert_eq!(options.min_seeders, Some(3));
+        assert!(options.reject_unknown_seeders);
+        assert_eq!(options.max_age_days, Some(90));
+    }
+
     #[test]
     fn parse_preferred_qualities_accepts_trimmed_case_insensitive_values() {
         let parsed = parse_preferred_qualities(&[
@@ -476,6 +822,7 @@ mod tests {
                 min_bitrate_kbps: None,
                 preferred_release_groups: vec![],
                 preferred_words: vec![],
+                rejected_words: vec![],
                 custom_format_rules: vec![],
             }),
         )
@@ -500,6 +847,7 @@ mod tests {
                 min_bitrate_kbps: None,
                 preferred_release_groups: vec![],
                 preferred_words: vec![],
+                rejected_words: vec![],
                 custom_format_rules: vec![],
             }),
         )
@@ -532,6 +880,7 @@ mod tests {
                 min_bitrate_kbps: None,
                 preferred_release_groups: vec![],
                 preferred_words: vec![],
+                rejected_words: vec![],
                 custom_format_rules: vec![],
             }),
         )
@@ -557,6 +906,7 @@ mod tests {
                 min_bitrate_kbps: None,
                 preferred_release_groups: vec![],
                 preferred_words: vec![],
+                rejected_words: vec![],
                 custom_format_rules: vec![],
             }),
         )
@@ -581,6 +931,7 @@ mod tests {
                 min_bitrate_kbps: None,
                 preferred_release_groups: vec![],
                 preferred_words: vec![],
+                rejected_words: vec![],
                 custom_format_rules: vec![],
             }),
         )
@@ -605,6 +956,7 @@ mod tests {
                 min_bitrate_kbps: None,
                 preferred_release_groups: vec![],
                 preferred_words: vec![],
+                rejected_words: vec![],
                 custom_format_rules: vec![ManualSearchCustomFormatRule {
                     name: "   ".to_string(),
                     keywords: vec!["mqa".to_string()],
@@ -617,4 +969,222 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::BAD_REQUEST);
     }
+
+    fn newznab_response(items: &[(&str, &str)]) -> wiremock::ResponseTemplate {
+        let items: String = items
+            .iter()
+            .map(|(title, url)| {
+                format!(
+                    r#"<item><title>{title}</title><guid>{url}</guid><enclosure url="{url}" length="1000" type="application/x-nzb" /></item>"#
+                )
+            })
+            .collect();
+        wiremock::ResponseTemplate::new(200)
+            .set_body_string(format!("<rss><channel>{items}</channel></rss>"))
+    }
+
+    async fn mock_indexer(items: &[(&str, &str)]) -> wiremock::MockServer {
+        use wiremock::matchers::{method, path, query_param};
+
+        let server = wiremock::MockServer::start().await;
+        wiremock::Mock::given(method("GET"))
+            .and(path("/api"))
+            .and(query_param("t", "search"))
+            .respond_with(newznab_response(items))
+            .mount(&server)
+            .await;
+        server
+    }
+
+    async fn create_indexer(state: &AppState, name: &str, base_url: &str, enabled: bool) {
+        let mut indexer = IndexerDefinition::new(name, base_url, "newznab");
+        indexer.enabled = enabled;
+        state
+            .indexer_definition_repository
+            .create(indexer)
+            .await
+            .expect("create indexer");
+    }
+
+    fn search_request() -> SearchApiRequest {
+        SearchApiRequest {
+            artist: Some("Daft Punk".to_string()),
+            album: Some("Discovery".to_string()),
+            query: None,
+            preferred_qualities: vec![],
+            min_bitrate_kbps: None,
+            preferred_release_groups: vec![],
+            preferred_words: vec![],
+            rejected_words: vec![],
+            custom_format_rules: vec![],
+        }
+    }
+
+    async fn json_body(response: axum::response::Response) -> serde_json::Value {
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body should be readable");
+        serde_json::from_slice(&body).expect("body should be valid JSON")
+    }
+
+    #[tokio::test]
+    async fn search_endpoint_merges_ranked_results_from_enabled_indexers() {
+        let state = make_test_state().await;
+        let lossy = mock_indexer(&[(
+            "Daft Punk - Discovery 320kbps MP3-GRP",
+            "https://lossy.test/1.nzb",
+        )])
+        .await;
+        let lossless = mock_indexer(&[(
+            "Daft Punk - Discovery [FLAC]-GRP",
+            "https://lossless.test/1.nzb",
+        )])
+        .await;
+        let disabled = mock_indexer(&[(
+            "Daft Punk - Discovery [FLAC 24bit]-GRP",
+            "https://disabled.test/1.nzb",
+        )])
+        .await;
+        create_indexer(&state, "lossy", &lossy.uri(), true).await;
+        create_indexer(&state, "lossless", &lossless.uri(), true).await;
+        create_indexer(&state, "disabled", &disabled.uri(), false).await;
+
+        let response = search_endpoint(State(state), Json(search_request()))
+            .await
+            .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let payload = json_body(response).await;
+        assert_eq!(payload["total"], 2);
+        assert_eq!(payload["items"][0]["indexer"], "lossless");
+        assert_eq!(payload["items"][0]["parsed_quality"], "flac");
+        assert_eq!(
+            payload["items"][0]["download_url"],
+            "https://lossless.test/1.nzb"
+        );
+        assert_eq!(payload["items"][1]["indexer"], "lossy");
+        assert!(
+            payload["items"][0]["score"].as_i64().unwrap()
+                > payload["items"][1]["score"].as_i64().unwrap()
+        );
+        assert_eq!(payload["failed_indexers"], serde_json::json!([]));
+        assert!(disabled.received_requests().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn search_endpoint_reports_failed_indexers_alongside_results() {
+        let state = make_test_state().await;
+        let healthy = mock_indexer(&[(
+            "Daft Punk - Discovery [FLAC]-GRP",
+            "https://healthy.test/1.nzb",
+        )])
+        .await;
+        let failing = wiremock::MockServer::start().await;
+        wiremock::Mock::given(wiremock::matchers::any())
+            .respond_with(wiremock::ResponseTemplate::new(500))
+            .mount(&failing)
+            .await;
+        create_indexer(&state, "healthy", &healthy.uri(), true).await;
+        create_indexer(&state, "failing", &failing.uri(), true).await;
+
+        let response = search_endpoint(State(state), Json(search_request()))
+            .await
+            .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let payload = json_body(response).await;
+        assert_eq!(payload["total"], 1);
+        assert_eq!(payload["failed_indexers"][0]["indexer"], "failing");
+    }
+
+    #[tokio::test]
+    async fn search_endpoint_returns_400_without_searchable_indexers() {
+        let state = make_test_state().await;
+
+        let response = search_endpoint(State(state), Json(search_request()))
+            .await
+            .into_response();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn grab_release_endpoint_forwards_to_download_client() {
+        use chorrosion_domain::DownloadClientDefinition;
+        use wiremock::matchers::{body_string_contains, method, path};
+
+        let state = make_test_state().await;
+        let server = wiremock::MockServer::start().await;
+        wiremock::Mock::given(method("POST"))
+            .and(path("/api/v2/torrents/add"))
+            .and(body_string_contains(
+                "urls=magnet%3A%3Fxt%3Durn%3Abtih%3Aabc",
+            ))
+            .and(body_string_contains("category=music"))
+            .respond_with(wiremock::ResponseTemplate::new(200).set_body_string("Ok."))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let mut definition = DownloadClientDefinition::new("qbit", "qbittorrent", server.uri());
+        definition.category = Some("music".to_string());
+        let definition = state
+            .download_client_definition_repository
+            .create(definition)
+            .await
+            .expect("create download client");
+
+        let response = grab_release_endpoint(
+            State(state),
+            Json(GrabReleaseRequest {
+                download_url: "magnet:?xt=urn:btih:abc".to_string(),
+                download_client_id: None,
+                category: None,
+            }),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let payload = json_body(response).await;
+        assert_eq!(payload["download_client"], "qbit");
+        assert_eq!(payload["download_client_id"], definition.id.to_string());
+        server.verify().await;
+    }
+
+    #[tokio::test]
+    async fn grab_release_endpoint_rejects_invalid_url() {
+        let state = make_test_state().await;
+
+        let response = grab_release_endpoint(
+            State(state),
+            Json(GrabReleaseRequest {
+                download_url: "ftp://example.test/release.torrent".to_string(),
+                download_client_id: None,
+                category: None,
+            }),
+        )
+        .await
+        .into_response();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn grab_release_endpoint_returns_404_for_unknown_client() {
+        let state = make_test_state().await;
+
+        let response = grab_release_endpoint(
+            State(state),
+            Json(GrabReleaseRequest {
+                download_url: "https://example.test/release.torrent".to_string(),
+                download_client_id: Some("00000000-0000-0000-0000-000000000000".to_string()),
+                category: None,
+            }),
+        )
+        .await
+        .into_response();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
 }
@@ -1,4 +1,5 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
+use crate::error::{is_concurrency_conflict, ApiError, ApiErrorResponse};
 use axum::{
     extract::{Path, Query, State},
     http::StatusCode,
@@ -6,7 +7,7 @@ use axum::{
 };
 use chorrosion_application::{AppState, SmartPlaylist, SmartPlaylistCriteria};
 use chorrosion_domain::Validate;
-use chrono::{Datelike, Duration, Utc};
+use chrono::{Duration, Utc};
 use serde::{Deserialize, Serialize};
 use tracing::{debug, error};
 use utoipa::{IntoParams, ToSchema};
@@ -82,12 +83,6 @@ pub struct SmartPlaylistItemsResponse {
     pub offset: i64,
 }
 
-#[derive(Debug, Serialize, Deserialize, ToSchema)]
-#[schema(as = SmartPlaylistErrorResponse)]
-pub struct ErrorResponse {
-    pub error: String,
-}
-
 impl From<SmartPlaylistCriteriaRequest> for SmartPlaylistCriteria {
     fn from(value: SmartPlaylistCriteriaRequest) -> Self {
         SmartPlaylistCriteria {
@@ -123,18 +118,6 @@ impl From<SmartPlaylist> for SmartPlaylistResponse {
     }
 }
 
-fn error_response(
-    status: StatusCode,
-    message: impl Into<String>,
-) -> (StatusCode, Json<ErrorResponse>) {
-    (
-        status,
-        Json(ErrorResponse {
-            error: message.into(),
-        }),
-    )
-}
-
 fn album_matches_criteria(
     album: &chorrosion_domain::Album,
     criteria: &SmartPlaylistCriteria,
@@ -155,7 +138,7 @@ fn album_matches_criteria(
         let Some(release_date) = album.release_date else {
             return false;
         };
-        if release_date.year() != expected_year {
+        if release_date.year != expected_year {
             return false;
         }
     }
@@ -187,16 +170,16 @@ fn album_matches_criteria(
     request_body = CreateSmartPlaylistRequest,
     responses(
         (status = 201, description = "Smart playlist created", body = SmartPlaylistResponse),
-        (status = 400, description = "Invalid request", body = ErrorResponse),
-        (status = 409, description = "Playlist name already exists", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 409, description = "Playlist name already exists", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "smart_playlists"
 )]
 pub async fn create_smart_playlist(
     State(state): State<AppState>,
     Json(payload): Json<CreateSmartPlaylistRequest>,
-) -> Result<(StatusCode, Json<SmartPlaylistResponse>), (StatusCode, Json<ErrorResponse>)> {
+) -> Result<(StatusCode, Json<SmartPlaylistResponse>), ApiError> {
     debug!(target: "api", name = %payload.name, "creating smart playlist");
 
     let playlist = SmartPlaylist::new(payload.name, payload.description, payload.criteria.into());
@@ -207,7 +190,7 @@ pub async fn create_smart_playlist(
             .map(|item| format!("{} {}", item.field, item.message))
             .collect::<Vec<_>>()
             .join(", ");
-        return Err(error_response(StatusCode::BAD_REQUEST, message));
+        return Err(ApiError::bad_request(message));
     }
 
     match state.smart_playlist_repository.create(playlist).await {
@@ -218,15 +201,13 @@ pub async fn create_smart_playlist(
         Err(err) => {
             error!(target: "api", error = %err, "failed to create smart playlist");
             if err.to_string().contains("UNIQUE") {
-                Err(error_response(
-                    StatusCode::CONFLICT,
+                Err(ApiError::conflict(
                     "smart playlist with this name already exists",
                 ))
             } else {
-                Err(error_response(
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    "failed to create smart playlist",
-                ))
+                Err(ApiError::Internal(anyhow::anyhow!(
+                    "failed to create smart playlist"
+                )))
             }
         }
     }
@@ -238,24 +219,20 @@ pub async fn create_smart_playlist(
     params(ListSmartPlaylistsQuery),
     responses(
         (status = 200, description = "List smart playlists", body = ListSmartPlaylistsResponse),
-        (status = 400, description = "Invalid request", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "smart_playlists"
 )]
 pub async fn list_smart_playlists(
     State(state): State<AppState>,
     Query(query): Query<ListSmartPlaylistsQuery>,
-) -> Result<Json<ListSmartPlaylistsResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<ListSmartPlaylistsResponse>, ApiError> {
     if !(1..=500).contains(&query.limit) {
-        return Err(error_response(
-            StatusCode::BAD_REQUEST,
-            "limit must be between 1 and 500",
-        ));
+        return Err(ApiError::bad_request("limit must be between 1 and 500"));
     }
     if query.offset < 0 {
-        return Err(error_response(
-            StatusCode::BAD_REQUEST,
+        return Err(ApiError::bad_request(
             "offset must be greater than or equal to 0",
         ));
     }
@@ -266,10 +243,7 @@ pub async fn list_smart_playlists(
         .await
         .map_err(|err| {
             error!(target: "api", error = %err, "failed to count smart playlists");
-            error_response(
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "failed to list smart playlists",
-            )
+            ApiError::Internal(anyhow::anyhow!("failed to list smart playlists"))
         })?;
 
     let items = state
@@ -278,10 +252,7 @@ pub async fn list_smart_playlists(
         .await
         .map_err(|err| {
             error!(target: "api", error = %err, "failed to list smart playlists");
-            error_response(
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "failed to list smart playlists",
-            )
+            ApiError::Internal(anyhow::anyhow!("failed to list smart playlists"))
         })?
         .into_iter()
         .map(SmartPlaylistResponse::from)
@@ -303,31 +274,27 @@ pub async fn list_smart_playlists(
     ),
     responses(
         (status = 200, description = "Smart playlist", body = SmartPlaylistResponse),
-        (status = 404, description = "Not found", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 404, description = "Not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "smart_playlists"
 )]
 pub async fn get_smart_playlist(
     State(state): State<AppState>,
     Path(playlist_id): Path<String>,
-) -> Result<Json<SmartPlaylistResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<SmartPlaylistResponse>, ApiError> {
     match state
         .smart_playlist_repository
         .get_by_id(&playlist_id)
         .await
     {
         Ok(Some(playlist)) => Ok(Json(SmartPlaylistResponse::from(playlist))),
-        Ok(None) => Err(error_response(
-            StatusCode::NOT_FOUND,
-            "smart playlist not found",
-        )),
+        Ok(None) => Err(ApiError::not_found("smart playlist not found")),
         Err(err) => {
             error!(target: "api", error = %err, "failed to fetch smart playlist");
-            Err(error_response(
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "failed to fetch smart playlist",
-            ))
+            Err(ApiError::Internal(anyhow::anyhow!(
+                "failed to fetch smart playlist"
+            )))
         }
     }
 }
@@ -341,10 +308,10 @@ pub async fn get_smart_playlist(
     ),
     responses(
         (status = 200, description = "Smart playlist updated", body = SmartPlaylistResponse),
-        (status = 400, description = "Invalid request", body = ErrorResponse),
-        (status = 404, description = "Not found", body = ErrorResponse),
-        (status = 409, description = "Playlist name already exists", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 404, description = "Not found", body = ApiErrorResponse),
+        (status = 409, description = "Playlist name already exists or playlist was modified concurrently", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "smart_playlists"
 )]
@@ -352,19 +319,16 @@ pub async fn update_smart_playlist(
     State(state): State<AppState>,
     Path(playlist_id): Path<String>,
     Json(payload): Json<UpdateSmartPlaylistRequest>,
-) -> Result<Json<SmartPlaylistResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<SmartPlaylistResponse>, ApiError> {
     let mut playlist = state
         .smart_playlist_repository
         .get_by_id(&playlist_id)
         .await
         .map_err(|err| {
             error!(target: "api", error = %err, "failed to fetch smart playlist for update");
-            error_response(
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "failed to update smart playlist",
-            )
+            ApiError::Internal(anyhow::anyhow!("failed to update smart playlist"))
         })?
-        .ok_or_else(|| error_response(StatusCode::NOT_FOUND, "smart playlist not found"))?;
+        .ok_or_else(|| ApiError::not_found("smart playlist not found"))?;
 
     if let Some(name) = payload.name {
         playlist.name = name;
@@ -375,7 +339,6 @@ pub async fn update_smart_playlist(
     if let Some(criteria) = payload.criteria {
         playlist.criteria = criteria.into();
     }
-    playlist.updated_at = Utc::now();
 
     if let Err(validation_errors) = playlist.validate() {
         let message = validation_errors
@@ -383,23 +346,23 @@ pub async fn update_smart_playlist(
             .map(|item| format!("{} {}", item.field, item.message))
             .collect::<Vec<_>>()
             .join(", ");
-        return Err(error_response(StatusCode::BAD_REQUEST, message));
+        return Err(ApiError::bad_request(message));
     }
 
     match state.smart_playlist_repository.update(playlist).await {
         Ok(updated) => Ok(Json(SmartPlaylistResponse::from(updated))),
         Err(err) => {
             error!(target: "api", error = %err, "failed to update smart playlist");
-            if err.to_string().contains("UNIQUE") {
-                Err(error_response(
-                    StatusCode::CONFLICT,
+            if is_concurrency_conflict(&err) {
+                Err(ApiError::conflict(err.to_string()))
+            } else if err.to_string().contains("UNIQUE") {
+                Err(ApiError::conflict(
                     "smart playlist with this name already exists",
                 ))
             } else {
-                Err(error_response(
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    "failed to update smart playlist",
-                ))
+                Err(ApiError::Internal(anyhow::anyhow!(
+                    "failed to update smart playlist"
+                )))
             }
         }
     }
@@ -413,29 +376,25 @@ pub async fn update_smart_playlist(
     ),
     responses(
         (status = 204, description = "Smart playlist deleted"),
-        (status = 404, description = "Not found", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 404, description = "Not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "smart_playlists"
 )]
 pub async fn delete_smart_playlist(
     State(state): State<AppState>,
     Path(playlist_id): Path<String>,
-) -> Result<StatusCode, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<StatusCode, ApiError> {
     match state.smart_playlist_repository.delete(&playlist_id).await {
         Ok(()) => Ok(StatusCode::NO_CONTENT),
         Err(err) => {
             error!(target: "api", error = %err, "failed to delete smart playlist");
             if err.to_string().contains("not found") {
-                Err(error_response(
-                    StatusCode::NOT_FOUND,
-                    "smart playlist not found",
-                ))
+                Err(ApiError::not_found("smart playlist not found"))
             } else {
-                Err(error_response(
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    "failed to delete smart playlist",
-                ))
+                Err(ApiError::Internal(anyhow::anyhow!(
+                    "failed to delete smart playlist"
+                )))
             }
         }
     }
@@ -450,9 +409,9 @@ pub async fn delete_smart_playlist(
     ),
     responses(
         (status = 200, description = "Evaluated smart playlist items", body = SmartPlaylistItemsResponse),
-        (status = 400, description = "Invalid request", body = ErrorResponse),
-        (status = 404, description = "Not found", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 404, description = "Not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "smart_playlists"
 )]
@@ -460,16 +419,12 @@ pub async fn get_smart_playlist_items(
     State(state): State<AppState>,
     Path(playlist_id): Path<String>,
     Query(query): Query<ListSmartPlaylistsQuery>,
-) -> Result<Json<SmartPlaylistItemsResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<SmartPlaylistItemsResponse>, ApiError> {
     if !(1..=500).contains(&query.limit) {
-        return Err(error_response(
-            StatusCode::BAD_REQUEST,
-            "limit must be between 1 and 500",
-        ));
+        return Err(ApiError::bad_request("limit must be between 1 and 500"));
     }
     if query.offset < 0 {
-        return Err(error_response(
-            StatusCode::BAD_REQUEST,
+        return Err(ApiError::bad_request(
             "offset must be greater than or equal to 0",
         ));
     }
@@ -480,12 +435,9 @@ pub async fn get_smart_playlist_items(
         .await
         .map_err(|err| {
             error!(target: "api", error = %err, "failed to fetch smart playlist");
-            error_response(
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "failed to evaluate smart playlist",
-            )
+            ApiError::Internal(anyhow::anyhow!("failed to evaluate smart playlist"))
         })?
-        .ok_or_else(|| error_response(StatusCode::NOT_FOUND, "smart playlist not found"))?;
+        .ok_or_else(|| ApiError::not_found("smart playlist not found"))?;
 
     let now = Utc::now();
     let mut total = 0_i64;
@@ -499,10 +451,7 @@ pub async fn get_smart_playlist_items(
             .await
             .map_err(|err| {
                 error!(target: "api", error = %err, "failed to list albums for smart playlist evaluation");
-                error_response(
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    "failed to evaluate smart playlist",
-                )
+                ApiError::Internal(anyhow::anyhow!("failed to evaluate smart playlist"))
             })?;
 
         let fetched_count = albums.len() as i64;
@@ -537,7 +486,7 @@ mod tests {
     use super::*;
     use axum::extract::{Path, Query, State};
     use chorrosion_config::AppConfig;
-    use chorrosion_domain::{Album, Artist};
+    use chorrosion_domain::{Album, Artist, ReleaseDate};
     use chorrosion_infrastructure::sqlite_adapters::{
         SqliteAlbumRepository, SqliteArtistRepository, SqliteDownloadClientDefinitionRepository,
         SqliteIndexerDefinitionRepository, SqliteMetadataProfileRepository,
@@ -575,6 +524,11 @@ mod tests {
                     pool.clone(),
                 ),
             ),
+            Arc::new(
+                chorrosion_infrastructure::sqlite_adapters::SqliteTrackFileRepository::new(
+                    pool.clone(),
+                ),
+            ),
             chorrosion_infrastructure::ResponseCache::new(100, 60),
         )
     }
@@ -630,7 +584,7 @@ mod tests {
 
         let mut matching_album = Album::new(artist_id, "Matching Album");
         matching_album.genre_tags = Some("metal,rock".to_string());
-        matching_album.release_date = chrono::NaiveDate::from_ymd_opt(2024, 6, 1);
+        matching_album.release_date = Some(ReleaseDate::new(2024, Some(6), Some(1)));
         state
             .album_repository
             .create(matching_album)
@@ -639,7 +593,7 @@ mod tests {
 
         let mut non_matching_album = Album::new(artist_id, "Non Matching Album");
         non_matching_album.genre_tags = Some("jazz".to_string());
-        non_matching_album.release_date = chrono::NaiveDate::from_ymd_opt(2023, 1, 1);
+        non_matching_album.release_date = Some(ReleaseDate::new(2023, None, None));
         state
             .album_repository
             .create(non_matching_album)
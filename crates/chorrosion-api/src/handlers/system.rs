@@ -6,18 +6,200 @@ use axum::{
     Json,
 };
 use chorrosion_application::{
-    AppState, NotificationEvent, NotificationPipeline, NotificationProviderKind,
+    download_client_for, test_all_indexers, AppState, DependencyHealth, DependencyKind,
+    IndexerClients, NotificationEvent, NotificationPipeline, NotificationProviderKind,
+    OutboundHttp,
 };
+use chorrosion_domain::{DownloadClientDefinition, IndexerDefinition};
+use futures_util::future::join_all;
 use serde::{Deserialize, Serialize};
-use tracing::{debug, error};
+use std::time::Duration;
+use tracing::{debug, warn};
 use utoipa::{IntoParams, ToSchema};
 
+use crate::error::{ApiError, ApiErrorResponse};
 use crate::{API_V1_BASE, APP_VERSION};
 
 #[derive(Debug, Serialize, ToSchema)]
 pub struct SystemStatusResponse {
+    /// `ok`, or `degraded` when any external dependency is unreachable.
     pub status: &'static str,
     pub api_base: &'static str,
+    pub dependencies: Vec<DependencyHealthResponse>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DependencyHealthResponse {
+    /// `indexer` or `download_client`.
+    pub kind: &'static str,
+    pub id: String,
+    pub name: String,
+    /// `ok` or `error`.
+    pub status: &'static str,
+    pub message: Option<String>,
+    pub last_checked: String,
+}
+
+impl From<DependencyHealth> for DependencyHealthResponse {
+    fn from(health: DependencyHealth) -> Self {
+        Self {
+            kind: match health.kind {
+                DependencyKind::Indexer => "indexer",
+                DependencyKind::DownloadClient => "download_client",
+            },
+            id: health.id,
+            name: health.name,
+            status: if health.healthy { "ok" } else { "error" },
+            message: health.message,
+            last_checked: health.checked_at.to_rfc3339(),
+        }
+    }
+}
+
+fn dependency_health(
+    kind: DependencyKind,
+    id: String,
+    name: String,
+    outcome: Result<(), String>,
+) -> DependencyHealth {
+    DependencyHealth {
+        kind,
+        id,
+        name,
+        healthy: outcome.is_ok(),
+        message: outcome.err(),
+        checked_at: chrono::Utc::now(),
+    }
+}
+
+/// Upper bound on a single indexer or download client health probe, so one
+/// hanging dependency cannot stall the status endpoint.
+const DEPENDENCY_PROBE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Probe `indexers` concurrently through [`test_all_indexers`], returning their
+/// health in input order.
+async fn probe_indexers(
+    indexer_clients: &IndexerClients,
+    indexers: Vec<IndexerDefinition>,
+) -> Vec<DependencyHealth> {
+    let mut clients = Vec::new();
+    let mut outcomes: Vec<Option<Result<(), String>>> = Vec::with_capacity(indexers.len());
+    for definition in &indexers {
+        match indexer_clients.for_definition(definition) {
+            Ok(client) => {
+                clients.push(client);
+                outcomes.push(None);
+            }
+            Err(error) => outcomes.push(Some(Err(format!("invalid indexer protocol: {error}")))),
+        }
+    }
+
+    let mut tested = test_all_indexers(clients, DEPENDENCY_PROBE_TIMEOUT)
+        .await
+        .into_iter()
+        .map(|(_, result)| {
+            if result.success {
+                Ok(())
+            } else {
+                Err(result.message)
+            }
+        });
+
+    indexers
+        .into_iter()
+        .zip(outcomes)
+        .map(|(definition, outcome)| {
+            let outcome = outcome
+                .or_else(|| tested.next())
+                .unwrap_or_else(|| Err("indexer probe did not complete".to_string()));
+            if let Err(message) = &outcome {
+                warn!(target: "api", indexer = %definition.name, %message, "indexer health probe failed");
+            }
+            dependency_health(
+                DependencyKind::Indexer,
+                definition.id.to_string(),
+                definition.name,
+                outcome,
+            )
+        })
+        .collect()
+}
+
+async fn probe_download_client(
+    definition: DownloadClientDefinition,
+    outbound: &OutboundHttp,
+) -> DependencyHealth {
+    let outcome = match download_client_for(&definition, outbound) {
+        Some(client) => {
+            match tokio::time::timeout(DEPENDENCY_PROBE_TIMEOUT, client.test_connection()).await {
+                Ok(result) => result.map_err(|error| error.to_string()),
+                Err(_) => Err(format!(
+                    "download client did not respond within {}s",
+                    DEPENDENCY_PROBE_TIMEOUT.as_secs()
+                )),
+            }
+        }
+        None => Err(format!(
+            "unsupported download client type: {}",
+            definition.client_type
+        )),
+    };
+    if let Err(message) = &outcome {
+        warn!(target: "api", client_name = %definition.name, %message, "download client health probe failed");
+    }
+    dependency_health(
+        DependencyKind::DownloadClient,
+        definition.id.to_string(),
+        definition.name,
+        outcome,
+    )
+}
+
+/// Probe every enabled indexer and download client concurrently, reusing
+/// results from the last probe while they are still within the cache TTL.
+/// Concurrent requests on a cold cache wait for one shared probe.
+async fn dependency_health_snapshot(state: &AppState) -> Vec<DependencyHealth> {
+    state
+        .dependency_health_cache
+        .get_or_probe(|| probe_dependencies(state))
+        .await
+}
+
+async fn probe_dependencies(state: &AppState) -> Vec<DependencyHealth> {
+    let indexers = state
+        .indexer_definition_repository
+        .list(1000, 0)
+        .await
+        .unwrap_or_else(|error| {
+            warn!(target: "api", ?error, "failed to list indexer definitions for health probe");
+            Vec::new()
+        });
+    let download_clients = state
+        .download_client_definition_repository
+        .list(1000, 0)
+        .await
+        .unwrap_or_else(|error| {
+            warn!(target: "api", ?error, "failed to list download client definitions for health probe");
+            Vec::new()
+        });
+
+    let (mut results, download_client_results) = futures_util::join!(
+        probe_indexers(
+            &state.indexer_clients,
+            indexers
+                .into_iter()
+                .filter(|definition| definition.enabled)
+                .collect(),
+        ),
+        join_all(
+            download_clients
+                .into_iter()
+                .filter(|definition| definition.enabled)
+                .map(|definition| probe_download_client(definition, &state.outbound_http)),
+        ),
+    );
+    results.extend(download_client_results);
+    results
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -82,6 +264,13 @@ pub(crate) async fn system_tasks_snapshot(state: &AppState) -> SystemTasksRespon
             enabled: true,
             status: "scheduled".to_string(),
         },
+        SystemTaskResponse {
+            id: "fingerprint-scan".to_string(),
+            name: "Fingerprint Scan".to_string(),
+            schedule_seconds: 6 * 60 * 60,
+            enabled: true,
+            status: "scheduled".to_string(),
+        },
     ];
 
     let has_lastfm_key = state
@@ -214,15 +403,24 @@ pub struct NotificationTestResponse {
     get,
     path = "/api/v1/system/status",
     responses(
-        (status = 200, description = "System status", body = SystemStatusResponse)
+        (status = 200, description = "System status with per-dependency health", body = SystemStatusResponse)
     ),
     tag = "system"
 )]
-pub async fn get_system_status(State(_state): State<AppState>) -> Json<SystemStatusResponse> {
+pub async fn get_system_status(State(state): State<AppState>) -> Json<SystemStatusResponse> {
     debug!(target: "api", "fetching system status");
+    let dependencies: Vec<DependencyHealthResponse> = dependency_health_snapshot(&state)
+        .await
+        .into_iter()
+        .map(DependencyHealthResponse::from)
+        .collect();
+    let healthy = dependencies
+        .iter()
+        .all(|dependency| dependency.status == "ok");
     Json(SystemStatusResponse {
-        status: "ok",
+        status: if healthy { "ok" } else { "degraded" },
         api_base: API_V1_BASE,
+        dependencies,
     })
 }
 
@@ -318,7 +516,7 @@ pub async fn get_system_notifications(
     path = "/api/v1/system/notifications/test",
     responses(
         (status = 202, description = "Test notification dispatched", body = NotificationTestResponse),
-        (status = 500, description = "Dispatch failed", body = NotificationTestResponse)
+        (status = 500, description = "Dispatch failed", body = ApiErrorResponse)
     ),
     tag = "system"
 )]
@@ -338,18 +536,7 @@ pub async fn post_system_notifications_test(State(state): State<AppState>) -> im
             }),
         )
             .into_response(),
-        Err(err) => {
-            error!(target: "api", %err, "notification test dispatch failed");
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(NotificationTestResponse {
-                    status: "error".to_string(),
-                    dispatched: 0,
-                    message: format!("dispatch failed: {err}"),
-                }),
-            )
-                .into_response()
-        }
+        Err(err) => ApiError::internal(err, "notification test dispatch failed").into_response(),
     }
 }
 
@@ -401,6 +588,11 @@ mod tests {
                     pool.clone(),
                 ),
             ),
+            Arc::new(
+                chorrosion_infrastructure::sqlite_adapters::SqliteTrackFileRepository::new(
+                    pool.clone(),
+                ),
+            ),
             chorrosion_infrastructure::ResponseCache::new(100, 60),
         )
     }
@@ -413,6 +605,115 @@ mod tests {
         assert_eq!(resp.api_base, API_V1_BASE);
     }
 
+    async fn mock_newznab_caps(
+        status: u16,
+        expected_calls: impl Into<wiremock::Times>,
+    ) -> wiremock::MockServer {
+        use wiremock::matchers::{method, path, query_param};
+
+        let server = wiremock::MockServer::start().await;
+        wiremock::Mock::given(method("GET"))
+            .and(path("/api"))
+            .and(query_param("t", "caps"))
+            .respond_with(
+                wiremock::ResponseTemplate::new(status).set_body_string(
+                    "<caps><searching><search available=\"yes\"/></searching></caps>",
+                ),
+            )
+            .expect(expected_calls)
+            .mount(&server)
+            .await;
+        server
+    }
+
+    async fn mock_qbittorrent() -> wiremock::MockServer {
+        use wiremock::matchers::{method, path};
+
+        let server = wiremock::MockServer::start().await;
+        wiremock::Mock::given(method("GET"))
+            .and(path("/api/v2/app/version"))
+            .respond_with(wiremock::ResponseTemplate::new(200).set_body_string("v4.6.0"))
+            .mount(&server)
+            .await;
+        server
+    }
+
+    async fn create_indexer(state: &AppState, name: &str, base_url: &str) {
+        state
+            .indexer_definition_repository
+            .create(IndexerDefinition::new(name, base_url, "newznab"))
+            .await
+            .expect("create indexer");
+    }
+
+    #[tokio::test]
+    async fn get_system_status_reports_all_dependencies_healthy() {
+        let state = make_test_state().await;
+        let indexer = mock_newznab_caps(200, 1).await;
+        let download_client = mock_qbittorrent().await;
+        create_indexer(&state, "Indexer", &indexer.uri()).await;
+        state
+            .download_client_definition_repository
+            .create(DownloadClientDefinition::new(
+                "qBittorrent",
+                "qbittorrent",
+                download_client.uri(),
+            ))
+            .await
+            .expect("create download client");
+
+        let Json(resp) = get_system_status(State(state.clone())).await;
+
+        assert_eq!(resp.status, "ok");
+        assert_eq!(resp.dependencies.len(), 2);
+        assert!(resp.dependencies.iter().all(|d| d.status == "ok"));
+        assert!(resp
+            .dependencies
+            .iter()
+            .any(|d| d.kind == "indexer" && d.name == "Indexer"));
+        assert!(resp
+            .dependencies
+            .iter()
+            .any(|d| d.kind == "download_client" && d.name == "qBittorrent"));
+        assert!(resp
+            .dependencies
+            .iter()
+            .all(|d| chrono::DateTime::parse_from_rfc3339(&d.last_checked).is_ok()));
+
+        // A second request within the TTL is served from cache; the mock
+        // asserts the indexer was only contacted once on drop.
+        let Json(cached) = get_system_status(State(state)).await;
+        assert_eq!(cached.dependencies.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn get_system_status_reports_failing_indexer_without_failing() {
+        let state = make_test_state().await;
+        let healthy = mock_newznab_caps(200, 1).await;
+        let broken = mock_newznab_caps(503, 1..).await;
+        create_indexer(&state, "Healthy", &healthy.uri()).await;
+        create_indexer(&state, "Broken", &broken.uri()).await;
+
+        let Json(resp) = get_system_status(State(state)).await;
+
+        assert_eq!(resp.status, "degraded");
+        assert_eq!(resp.dependencies.len(), 2);
+        let healthy = resp
+            .dependencies
+            .iter()
+            .find(|d| d.name == "Healthy")
+            .expect("healthy indexer should be reported");
+        assert_eq!(healthy.status, "ok");
+        assert!(healthy.message.is_none());
+        let broken = resp
+            .dependencies
+            .iter()
+            .find(|d| d.name == "Broken")
+            .expect("broken indexer should be reported");
+        assert_eq!(broken.status, "error");
+        assert!(broken.message.is_some());
+    }
+
     #[tokio::test]
     async fn get_system_version_returns_name_and_version() {
         let state = make_test_state().await;
@@ -1,4 +1,5 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
+use crate::error::{is_concurrency_conflict, ApiError};
 use axum::{
     extract::{Path, Query, State},
     http::StatusCode,
@@ -53,33 +54,11 @@ pub struct AssignTagRequest {
     pub entity_type: String,
 }
 
-#[derive(Debug, Serialize, Deserialize, ToSchema)]
-pub struct ErrorResponse {
-    pub error: String,
-}
-
-fn error_response(
-    status: StatusCode,
-    message: impl Into<String>,
-) -> (StatusCode, Json<ErrorResponse>) {
-    (
-        status,
-        Json(ErrorResponse {
-            error: message.into(),
-        }),
-    )
-}
-
-fn parse_entity_type(
-    entity_type_str: &str,
-) -> Result<EntityType, (StatusCode, Json<ErrorResponse>)> {
+fn parse_entity_type(entity_type_str: &str) -> Result<EntityType, ApiError> {
     match entity_type_str.to_lowercase().as_str() {
         "artist" => Ok(EntityType::Artist),
         "album" => Ok(EntityType::Album),
-        _ => Err(error_response(
-            StatusCode::BAD_REQUEST,
-            "Invalid entity type",
-        )),
+        _ => Err(ApiError::bad_request("Invalid entity type")),
     }
 }
 
@@ -130,14 +109,11 @@ impl From<Tag> for TagResponse {
 pub async fn create_tag(
     State(state): State<AppState>,
     Json(payload): Json<CreateTagRequest>,
-) -> Result<(StatusCode, Json<TagResponse>), (StatusCode, Json<ErrorResponse>)> {
+) -> Result<(StatusCode, Json<TagResponse>), ApiError> {
     debug!(target: "api", "creating tag: {}", payload.name);
 
     if payload.name.trim().is_empty() {
-        return Err(error_response(
-            StatusCode::BAD_REQUEST,
-            "Tag name cannot be empty",
-        ));
+        return Err(ApiError::bad_request("Tag name cannot be empty"));
     }
 
     let tag = Tag::new(payload.name, payload.description);
@@ -147,15 +123,9 @@ pub async fn create_tag(
         Err(e) => {
             error!(target: "api", "failed to create tag: {}", e);
             if e.to_string().contains("UNIQUE") {
-                Err(error_response(
-                    StatusCode::CONFLICT,
-                    "Tag with this name already exists",
-                ))
+                Err(ApiError::conflict("Tag with this name already exists"))
             } else {
-                Err(error_response(
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    "Failed to create tag",
-                ))
+                Err(ApiError::Internal(anyhow::anyhow!("Failed to create tag")))
             }
         }
     }
@@ -175,7 +145,7 @@ pub async fn create_tag(
 pub async fn list_tags(
     State(state): State<AppState>,
     Query(params): Query<ListTagsQuery>,
-) -> Result<Json<ListTagsResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<ListTagsResponse>, ApiError> {
     debug!(target: "api",
         "listing tags: limit={}, offset={}",
         params.limit, params.offset
@@ -202,10 +172,7 @@ pub async fn list_tags(
         }
         Err(e) => {
             error!(target: "api", "failed to list tags: {}", e);
-            Err(error_response(
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "Failed to list tags",
-            ))
+            Err(ApiError::Internal(anyhow::anyhow!("Failed to list tags")))
         }
     }
 }
@@ -227,18 +194,15 @@ pub async fn list_tags(
 pub async fn get_tag(
     State(state): State<AppState>,
     Path(tag_id): Path<String>,
-) -> Result<Json<TagResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<TagResponse>, ApiError> {
     debug!(target: "api", "getting tag: {}", tag_id);
 
     match state.tag_repository.get_by_id(&tag_id).await {
         Ok(Some(tag)) => Ok(Json(TagResponse::from(tag))),
-        Ok(None) => Err(error_response(StatusCode::NOT_FOUND, "Tag not found")),
+        Ok(None) => Err(ApiError::not_found("Tag not found")),
         Err(e) => {
             error!(target: "api", "failed to get tag: {}", e);
-            Err(error_response(
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "Failed to get tag",
-            ))
+            Err(ApiError::Internal(anyhow::anyhow!("Failed to get tag")))
         }
     }
 }
@@ -255,7 +219,7 @@ pub async fn get_tag(
         (status = 200, description = "Tag updated successfully", body = TagResponse),
         (status = 400, description = "Invalid request"),
         (status = 404, description = "Tag not found"),
-        (status = 409, description = "Tag with this name already exists"),
+        (status = 409, description = "Tag with this name already exists or tag was modified concurrently"),
         (status = 500, description = "Internal server error"),
     ),
     tag = "Tags"
@@ -264,50 +228,39 @@ pub async fn update_tag(
     State(state): State<AppState>,
     Path(tag_id): Path<String>,
     Json(payload): Json<UpdateTagRequest>,
-) -> Result<Json<TagResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<TagResponse>, ApiError> {
     debug!(target: "api", "updating tag: {}", tag_id);
 
     match state.tag_repository.get_by_id(&tag_id).await {
         Ok(Some(mut tag)) => {
             if let Some(name) = payload.name {
                 if name.trim().is_empty() {
-                    return Err(error_response(
-                        StatusCode::BAD_REQUEST,
-                        "Tag name cannot be empty",
-                    ));
+                    return Err(ApiError::bad_request("Tag name cannot be empty"));
                 }
                 tag.name = name;
             }
             if payload.description.is_some() {
                 tag.description = payload.description;
             }
-            tag.updated_at = chrono::Utc::now();
 
             match state.tag_repository.update(tag).await {
                 Ok(updated_tag) => Ok(Json(TagResponse::from(updated_tag))),
                 Err(e) => {
                     error!(target: "api", "failed to update tag: {}", e);
-                    if e.to_string().contains("UNIQUE") {
-                        Err(error_response(
-                            StatusCode::CONFLICT,
-                            "Tag with this name already exists",
-                        ))
+                    if is_concurrency_conflict(&e) {
+                        Err(ApiError::conflict(e.to_string()))
+                    } else if e.to_string().contains("UNIQUE") {
+                        Err(ApiError::conflict("Tag with this name already exists"))
                     } else {
-                        Err(error_response(
-                            StatusCode::INTERNAL_SERVER_ERROR,
-                            "Failed to update tag",
-                        ))
+                        Err(ApiError::Internal(anyhow::anyhow!("Failed to update tag")))
                     }
                 }
             }
         }
-        Ok(None) => Err(error_response(StatusCode::NOT_FOUND, "Tag not found")),
+        Ok(None) => Err(ApiError::not_found("Tag not found")),
         Err(e) => {
             error!(target: "api", "failed to get tag: {}", e);
-            Err(error_response(
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "Failed to update tag",
-            ))
+            Err(ApiError::Internal(anyhow::anyhow!("Failed to update tag")))
         }
     }
 }
@@ -329,7 +282,7 @@ pub async fn update_tag(
 pub async fn delete_tag(
     State(state): State<AppState>,
     Path(tag_id): Path<String>,
-) -> Result<StatusCode, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<StatusCode, ApiError> {
     debug!(target: "api", "deleting tag: {}", tag_id);
 
     match state.tag_repository.delete(&tag_id).await {
@@ -337,12 +290,9 @@ pub async fn delete_tag(
         Err(e) => {
             error!(target: "api", "failed to delete tag: {}", e);
             if e.to_string().contains("not found") {
-                Err(error_response(StatusCode::NOT_FOUND, "Tag not found"))
+                Err(ApiError::not_found("Tag not found"))
             } else {
-                Err(error_response(
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    "Failed to delete tag",
-                ))
+                Err(ApiError::Internal(anyhow::anyhow!("Failed to delete tag")))
             }
         }
     }
@@ -366,7 +316,7 @@ pub async fn delete_tag(
 pub async fn get_entity_tags(
     State(state): State<AppState>,
     Path((entity_type_str, entity_id)): Path<(String, String)>,
-) -> Result<Json<EntityTagsResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<EntityTagsResponse>, ApiError> {
     debug!(target: "api", "getting tags for {}/{}", entity_type_str, entity_id);
 
     let entity_type = parse_entity_type(&entity_type_str)?;
@@ -383,10 +333,9 @@ pub async fn get_entity_tags(
         })),
         Err(e) => {
             error!(target: "api", "failed to get tags for entity: {}", e);
-            Err(error_response(
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "Failed to get entity tags",
-            ))
+            Err(ApiError::Internal(anyhow::anyhow!(
+                "Failed to get entity tags"
+            )))
         }
     }
 }
@@ -411,7 +360,7 @@ pub async fn get_entity_tags(
 pub async fn assign_tag_to_entity(
     State(state): State<AppState>,
     Path((entity_type_str, entity_id, tag_id)): Path<(String, String, String)>,
-) -> Result<StatusCode, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<StatusCode, ApiError> {
     debug!(target: "api",
         "assigning tag {} to {}/{}",
         tag_id, entity_type_str, entity_id
@@ -422,13 +371,10 @@ pub async fn assign_tag_to_entity(
     // Verify tag exists
     match state.tag_repository.get_by_id(&tag_id).await {
         Ok(Some(_)) => {}
-        Ok(None) => return Err(error_response(StatusCode::NOT_FOUND, "Tag not found")),
+        Ok(None) => return Err(ApiError::not_found("Tag not found")),
         Err(e) => {
             error!(target: "api", "failed to get tag: {}", e);
-            return Err(error_response(
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "Failed to verify tag",
-            ));
+            return Err(ApiError::Internal(anyhow::anyhow!("Failed to verify tag")));
         }
     }
 
@@ -446,16 +392,16 @@ pub async fn assign_tag_to_entity(
     }
     .map_err(|e| {
         error!(target: "api", "failed to verify entity: {}", e);
-        error_response(StatusCode::INTERNAL_SERVER_ERROR, "Failed to verify entity")
+        ApiError::Internal(anyhow::anyhow!("Failed to verify entity"))
     })?;
 
     if !entity_exists {
-        return Err(error_response(StatusCode::NOT_FOUND, "Entity not found"));
+        return Err(ApiError::not_found("Entity not found"));
     }
 
     let parsed_tag_id = TagId::from_uuid(
         uuid::Uuid::parse_str(&tag_id)
-            .map_err(|_| error_response(StatusCode::BAD_REQUEST, "Invalid tag ID format"))?,
+            .map_err(|_| ApiError::bad_request("Invalid tag ID format"))?,
     );
 
     match state
@@ -466,10 +412,7 @@ pub async fn assign_tag_to_entity(
         Ok(_) => Ok(StatusCode::NO_CONTENT),
         Err(e) => {
             error!(target: "api", "failed to assign tag: {}", e);
-            Err(error_response(
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "Failed to assign tag",
-            ))
+            Err(ApiError::Internal(anyhow::anyhow!("Failed to assign tag")))
         }
     }
 }
@@ -493,7 +436,7 @@ pub async fn assign_tag_to_entity(
 pub async fn remove_tag_from_entity(
     State(state): State<AppState>,
     Path((entity_type_str, entity_id, tag_id)): Path<(String, String, String)>,
-) -> Result<StatusCode, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<StatusCode, ApiError> {
     debug!(target: "api",
         "removing tag {} from {}/{}",
         tag_id, entity_type_str, entity_id
@@ -503,7 +446,7 @@ pub async fn remove_tag_from_entity(
 
     let parsed_tag_id = TagId::from_uuid(
         uuid::Uuid::parse_str(&tag_id)
-            .map_err(|_| error_response(StatusCode::BAD_REQUEST, "Invalid tag ID format"))?,
+            .map_err(|_| ApiError::bad_request("Invalid tag ID format"))?,
     );
 
     match state
@@ -514,10 +457,7 @@ pub async fn remove_tag_from_entity(
         Ok(_) => Ok(StatusCode::NO_CONTENT),
         Err(e) => {
             error!(target: "api", "failed to remove tag: {}", e);
-            Err(error_response(
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "Failed to remove tag",
-            ))
+            Err(ApiError::Internal(anyhow::anyhow!("Failed to remove tag")))
         }
     }
 }
@@ -568,13 +508,21 @@ mod tests {
                     pool.clone(),
                 ),
             ),
+            Arc::new(
+                chorrosion_infrastructure::sqlite_adapters::SqliteTrackFileRepository::new(
+                    pool.clone(),
+                ),
+            ),
             chorrosion_infrastructure::ResponseCache::new(100, 60),
         )
     }
 
     #[test]
     fn parse_entity_type_accepts_case_insensitive_values() {
-        assert!(matches!(parse_entity_type("artist"), Ok(EntityType::Artist)));
+        assert!(matches!(
+            parse_entity_type("artist"),
+            Ok(EntityType::Artist)
+        ));
         assert!(matches!(parse_entity_type("ALBUM"), Ok(EntityType::Album)));
     }
 
@@ -582,9 +530,10 @@ mod tests {
     fn parse_entity_type_rejects_unknown_value() {
         let result = parse_entity_type("track");
         assert!(result.is_err());
-        let (status, Json(error)) = result.unwrap_err();
+        let error = result.unwrap_err();
+        let status = error.status();
         assert_eq!(status, StatusCode::BAD_REQUEST);
-        assert_eq!(error.error, "Invalid entity type");
+        assert_eq!(error.to_string(), "Invalid entity type");
     }
 
     #[tokio::test]
@@ -601,9 +550,10 @@ mod tests {
         .await;
 
         assert!(result.is_err());
-        let (status, Json(error)) = result.unwrap_err();
+        let error = result.unwrap_err();
+        let status = error.status();
         assert_eq!(status, StatusCode::BAD_REQUEST);
-        assert_eq!(error.error, "Tag name cannot be empty");
+        assert_eq!(error.to_string(), "Tag name cannot be empty");
     }
 
     #[tokio::test]
@@ -626,9 +576,10 @@ mod tests {
         .await;
 
         assert!(result.is_err());
-        let (status, Json(error)) = result.unwrap_err();
+        let error = result.unwrap_err();
+        let status = error.status();
         assert_eq!(status, StatusCode::BAD_REQUEST);
-        assert_eq!(error.error, "Tag name cannot be empty");
+        assert_eq!(error.to_string(), "Tag name cannot be empty");
     }
 
     #[tokio::test]
@@ -660,7 +611,7 @@ mod tests {
     async fn delete_tag_returns_not_found_for_missing_tag() {
         let state = make_test_state().await;
         let result = delete_tag(State(state), Path(uuid::Uuid::new_v4().to_string())).await;
-        assert!(matches!(result, Err((StatusCode::NOT_FOUND, _))));
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
     }
 
     #[tokio::test]
@@ -682,7 +633,7 @@ mod tests {
         )
         .await;
 
-        assert!(matches!(result, Err((StatusCode::NOT_FOUND, _))));
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
     }
 
     #[tokio::test]
@@ -699,7 +650,7 @@ mod tests {
         )
         .await;
 
-        assert!(matches!(result, Err((StatusCode::NOT_FOUND, _))));
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
     }
 
     #[tokio::test]
@@ -712,7 +663,7 @@ mod tests {
         )
         .await;
 
-        assert!(matches!(result, Err((StatusCode::BAD_REQUEST, _))));
+        assert!(matches!(result, Err(ApiError::BadRequest(_))));
     }
 
     #[tokio::test]
@@ -729,6 +680,6 @@ mod tests {
         )
         .await;
 
-        assert!(matches!(result, Err((StatusCode::BAD_REQUEST, _))));
+        assert!(matches!(result, Err(ApiError::BadRequest(_))));
     }
 }
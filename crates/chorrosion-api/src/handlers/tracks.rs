@@ -1,15 +1,21 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
+use crate::error::{is_concurrency_conflict, ApiError, ApiErrorResponse};
+use crate::middleware::request_id::correlate;
 use axum::{
     extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
     Json,
 };
-use chorrosion_application::AppState;
-use chorrosion_domain::{ArtistId, Track};
+use chorrosion_application::{AppState, MatchingStrategy, RematchError, TrackMatch};
+use chorrosion_domain::{
+    ArtistId, DomainEvent, Track, TrackCreated, TrackCreatedPayload, TrackFileId, TrackId,
+    TrackUpdated, TrackUpdatedPayload,
+};
 use serde::{Deserialize, Serialize};
-use tracing::debug;
+use tracing::{debug, error};
 use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
 
 #[derive(Debug, Deserialize, IntoParams)]
 pub struct ListTracksQuery {
@@ -84,10 +90,37 @@ pub struct UpdateTrackRequest {
     pub monitored: Option<bool>,
 }
 
-#[derive(Debug, Serialize, ToSchema)]
-#[schema(as = TrackErrorResponse)]
-pub struct ErrorResponse {
-    pub error: String,
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SetTrackMonitoredRequest {
+    pub monitored: bool,
+}
+
+/// A track association and its recorded match confidence.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TrackMatchResponse {
+    pub track_id: String,
+    pub confidence: Option<f32>,
+}
+
+impl From<TrackMatch> for TrackMatchResponse {
+    fn from(track_match: TrackMatch) -> Self {
+        Self {
+            track_id: track_match.track_id.to_string(),
+            confidence: track_match.confidence,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RematchTrackFileResponse {
+    pub track_file_id: String,
+    /// Whether the file was moved to a different track
+    pub changed: bool,
+    pub previous: TrackMatchResponse,
+    pub current: TrackMatchResponse,
+    /// `fingerprint`, `embedded_tags` or `filename_heuristics`; absent when nothing matched
+    pub strategy: Option<String>,
+    pub musicbrainz_recording_id: Option<String>,
 }
 
 #[utoipa::path(
@@ -96,32 +129,24 @@ pub struct ErrorResponse {
     params(ListTracksQuery),
     responses(
         (status = 200, description = "List of tracks", body = ListTracksResponse),
-        (status = 400, description = "Invalid request", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "tracks"
 )]
 pub async fn list_tracks(
     State(state): State<AppState>,
     Query(query): Query<ListTracksQuery>,
-) -> Result<Json<ListTracksResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<ListTracksResponse>, ApiError> {
     debug!(target: "api", ?query, "listing tracks");
 
     if !(1..=500).contains(&query.limit) {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: "limit must be between 1 and 500".to_string(),
-            }),
-        ));
+        return Err(ApiError::bad_request("limit must be between 1 and 500"));
     }
 
     if query.offset < 0 {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: "offset must be greater than or equal to 0".to_string(),
-            }),
+        return Err(ApiError::bad_request(
+            "offset must be greater than or equal to 0",
         ));
     }
 
@@ -130,14 +155,7 @@ pub async fn list_tracks(
         .track_repository
         .list(5000, 0)
         .await
-        .map_err(|error| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse {
-                    error: format!("failed to list tracks: {error}"),
-                }),
-            )
-        })?;
+        .map_err(|error| ApiError::internal(error, "failed to list tracks"))?;
 
     let total = all_tracks.len() as i64;
     // query.offset is validated >= 0 above, so try_from only fails on 32-bit
@@ -147,12 +165,7 @@ pub async fn list_tracks(
     let offset = match usize::try_from(query.offset) {
         Ok(o) => o,
         Err(_) => {
-            return Err((
-                StatusCode::BAD_REQUEST,
-                Json(ErrorResponse {
-                    error: "offset is out of range".to_string(),
-                }),
-            ));
+            return Err(ApiError::bad_request("offset is out of range"));
         }
     };
     // limit is validated to be in [1, 500], so the cast is always safe.
@@ -181,9 +194,9 @@ pub async fn list_tracks(
     ),
     responses(
         (status = 200, description = "List tracks for album", body = ListTracksResponse),
-        (status = 400, description = "Invalid request", body = ErrorResponse),
-        (status = 404, description = "Album not found", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 404, description = "Album not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "tracks"
 )]
@@ -191,24 +204,16 @@ pub async fn list_tracks_by_album(
     State(state): State<AppState>,
     Path(album_id): Path<String>,
     Query(query): Query<ListTracksQuery>,
-) -> Result<Json<ListTracksResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<ListTracksResponse>, ApiError> {
     debug!(target: "api", %album_id, ?query, "listing tracks by album");
 
     if !(1..=500).contains(&query.limit) {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: "limit must be between 1 and 500".to_string(),
-            }),
-        ));
+        return Err(ApiError::bad_request("limit must be between 1 and 500"));
     }
 
     if query.offset < 0 {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: "offset must be greater than or equal to 0".to_string(),
-            }),
+        return Err(ApiError::bad_request(
+            "offset must be greater than or equal to 0",
         ));
     }
 
@@ -216,46 +221,20 @@ pub async fn list_tracks_by_album(
         .album_repository
         .get_by_id(&album_id)
         .await
-        .map_err(|error| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse {
-                    error: format!("failed to fetch album: {error}"),
-                }),
-            )
-        })?
-        .ok_or_else(|| {
-            (
-                StatusCode::NOT_FOUND,
-                Json(ErrorResponse {
-                    error: format!("Album {album_id} not found"),
-                }),
-            )
-        })?;
+        .map_err(|error| ApiError::internal(error, "failed to fetch album"))?
+        .ok_or_else(|| ApiError::not_found(format!("Album {album_id} not found")))?;
 
     let all_tracks = state
         .track_repository
         .get_by_album(album.id, 5000, 0)
         .await
-        .map_err(|error| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse {
-                    error: format!("failed to list tracks by album: {error}"),
-                }),
-            )
-        })?;
+        .map_err(|error| ApiError::internal(error, "failed to list tracks by album"))?;
 
     let total = all_tracks.len() as i64;
     let offset = match usize::try_from(query.offset) {
         Ok(o) => o,
         Err(_) => {
-            return Err((
-                StatusCode::BAD_REQUEST,
-                Json(ErrorResponse {
-                    error: "offset is out of range".to_string(),
-                }),
-            ));
+            return Err(ApiError::bad_request("offset is out of range"));
         }
     };
     let limit = query.limit as usize;
@@ -283,9 +262,9 @@ pub async fn list_tracks_by_album(
     ),
     responses(
         (status = 200, description = "List tracks for artist", body = ListTracksResponse),
-        (status = 400, description = "Invalid request", body = ErrorResponse),
-        (status = 404, description = "Artist not found", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 404, description = "Artist not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "tracks"
 )]
@@ -293,24 +272,16 @@ pub async fn list_tracks_by_artist(
     State(state): State<AppState>,
     Path(artist_id): Path<String>,
     Query(query): Query<ListTracksQuery>,
-) -> Result<Json<ListTracksResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<ListTracksResponse>, ApiError> {
     debug!(target: "api", %artist_id, ?query, "listing tracks by artist");
 
     if !(1..=500).contains(&query.limit) {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: "limit must be between 1 and 500".to_string(),
-            }),
-        ));
+        return Err(ApiError::bad_request("limit must be between 1 and 500"));
     }
 
     if query.offset < 0 {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: "offset must be greater than or equal to 0".to_string(),
-            }),
+        return Err(ApiError::bad_request(
+            "offset must be greater than or equal to 0",
         ));
     }
 
@@ -318,46 +289,20 @@ pub async fn list_tracks_by_artist(
         .artist_repository
         .get_by_id(&artist_id)
         .await
-        .map_err(|error| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse {
-                    error: format!("failed to fetch artist: {error}"),
-                }),
-            )
-        })?
-        .ok_or_else(|| {
-            (
-                StatusCode::NOT_FOUND,
-                Json(ErrorResponse {
-                    error: format!("Artist {artist_id} not found"),
-                }),
-            )
-        })?;
+        .map_err(|error| ApiError::internal(error, "failed to fetch artist"))?
+        .ok_or_else(|| ApiError::not_found(format!("Artist {artist_id} not found")))?;
 
     let all_tracks = state
         .track_repository
         .get_by_artist(artist.id, 5000, 0)
         .await
-        .map_err(|error| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse {
-                    error: format!("failed to list tracks by artist: {error}"),
-                }),
-            )
-        })?;
+        .map_err(|error| ApiError::internal(error, "failed to list tracks by artist"))?;
 
     let total = all_tracks.len() as i64;
     let offset = match usize::try_from(query.offset) {
         Ok(o) => o,
         Err(_) => {
-            return Err((
-                StatusCode::BAD_REQUEST,
-                Json(ErrorResponse {
-                    error: "offset is out of range".to_string(),
-                }),
-            ));
+            return Err(ApiError::bad_request("offset is out of range"));
         }
     };
     let limit = query.limit as usize;
@@ -384,8 +329,8 @@ pub async fn list_tracks_by_artist(
     ),
     responses(
         (status = 200, description = "Track found", body = TrackResponse),
-        (status = 404, description = "Track not found", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 404, description = "Track not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "tracks"
 )]
@@ -394,20 +339,8 @@ pub async fn get_track(State(state): State<AppState>, Path(id): Path<String>) ->
 
     match state.track_repository.get_by_id(&id).await {
         Ok(Some(track)) => (StatusCode::OK, Json(TrackResponse::from(track))).into_response(),
-        Ok(None) => (
-            StatusCode::NOT_FOUND,
-            Json(ErrorResponse {
-                error: format!("Track {} not found", id),
-            }),
-        )
-            .into_response(),
-        Err(error) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: format!("failed to fetch track: {error}"),
-            }),
-        )
-            .into_response(),
+        Ok(None) => ApiError::not_found(format!("Track {} not found", id)).into_response(),
+        Err(error) => ApiError::internal(error, "failed to fetch track").into_response(),
     }
 }
 
@@ -417,9 +350,9 @@ pub async fn get_track(State(state): State<AppState>, Path(id): Path<String>) ->
     request_body = CreateTrackRequest,
     responses(
         (status = 201, description = "Track created", body = TrackResponse),
-        (status = 400, description = "Invalid request", body = ErrorResponse),
-        (status = 404, description = "Album or artist not found", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 404, description = "Album or artist not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "tracks"
 )]
@@ -432,54 +365,23 @@ pub async fn create_track(
     let album = match state.album_repository.get_by_id(&request.album_id).await {
         Ok(Some(album)) => album,
         Ok(None) => {
-            return (
-                StatusCode::NOT_FOUND,
-                Json(ErrorResponse {
-                    error: format!("Album {} not found", request.album_id),
-                }),
-            )
-                .into_response()
-        }
-        Err(error) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse {
-                    error: format!("failed to fetch album: {error}"),
-                }),
-            )
+            return ApiError::not_found(format!("Album {} not found", request.album_id))
                 .into_response()
         }
+        Err(error) => return ApiError::internal(error, "failed to fetch album").into_response(),
     };
 
     let artist = match state.artist_repository.get_by_id(&request.artist_id).await {
         Ok(Some(artist)) => artist,
         Ok(None) => {
-            return (
-                StatusCode::NOT_FOUND,
-                Json(ErrorResponse {
-                    error: format!("Artist {} not found", request.artist_id),
-                }),
-            )
-                .into_response()
-        }
-        Err(error) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse {
-                    error: format!("failed to fetch artist: {error}"),
-                }),
-            )
+            return ApiError::not_found(format!("Artist {} not found", request.artist_id))
                 .into_response()
         }
+        Err(error) => return ApiError::internal(error, "failed to fetch artist").into_response(),
     };
 
     if album.artist_id != artist.id {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                error: "album_id and artist_id must reference the same artist".to_string(),
-            }),
-        )
+        return ApiError::bad_request("album_id and artist_id must reference the same artist")
             .into_response();
     }
 
@@ -491,14 +393,20 @@ pub async fn create_track(
     track.monitored = request.monitored.unwrap_or(true);
 
     match state.track_repository.create(track).await {
-        Ok(created) => (StatusCode::CREATED, Json(TrackResponse::from(created))).into_response(),
-        Err(error) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: format!("failed to create track: {error}"),
-            }),
-        )
-            .into_response(),
+        Ok(created) => {
+            let event: TrackCreated = correlate(DomainEvent::new(
+                "track.created",
+                TrackCreatedPayload {
+                    track_id: created.id,
+                    album_id: created.album_id,
+                    artist_id: created.artist_id,
+                    title: created.title.clone(),
+                },
+            ));
+            state.event_dispatcher.dispatch(&event).await;
+            (StatusCode::CREATED, Json(TrackResponse::from(created))).into_response()
+        }
+        Err(error) => ApiError::internal(error, "failed to create track").into_response(),
     }
 }
 
@@ -511,9 +419,10 @@ pub async fn create_track(
     request_body = UpdateTrackRequest,
     responses(
         (status = 200, description = "Track updated", body = TrackResponse),
-        (status = 400, description = "Invalid request", body = ErrorResponse),
-        (status = 404, description = "Track, album, or artist not found", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 404, description = "Track, album, or artist not found", body = ApiErrorResponse),
+        (status = 409, description = "Track was modified concurrently", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "tracks"
 )]
@@ -537,24 +446,8 @@ pub async fn update_track(
 
     let mut track = match state.track_repository.get_by_id(&id).await {
         Ok(Some(track)) => track,
-        Ok(None) => {
-            return (
-                StatusCode::NOT_FOUND,
-                Json(ErrorResponse {
-                    error: format!("Track {} not found", id),
-                }),
-            )
-                .into_response()
-        }
-        Err(error) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse {
-                    error: format!("failed to fetch track: {error}"),
-                }),
-            )
-                .into_response()
-        }
+        Ok(None) => return ApiError::not_found(format!("Track {} not found", id)).into_response(),
+        Err(error) => return ApiError::internal(error, "failed to fetch track").into_response(),
     };
 
     // Whether we need to validate album/artist consistency after applying changes.
@@ -575,22 +468,10 @@ pub async fn update_track(
                 }
             }
             Ok(None) => {
-                return (
-                    StatusCode::NOT_FOUND,
-                    Json(ErrorResponse {
-                        error: format!("Album {} not found", album_id),
-                    }),
-                )
-                    .into_response()
+                return ApiError::not_found(format!("Album {} not found", album_id)).into_response()
             }
             Err(error) => {
-                return (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(ErrorResponse {
-                        error: format!("failed to fetch album: {error}"),
-                    }),
-                )
-                    .into_response()
+                return ApiError::internal(error, "failed to fetch album").into_response()
             }
         }
     }
@@ -601,22 +482,11 @@ pub async fn update_track(
                 track.artist_id = artist.id;
             }
             Ok(None) => {
-                return (
-                    StatusCode::NOT_FOUND,
-                    Json(ErrorResponse {
-                        error: format!("Artist {} not found", artist_id),
-                    }),
-                )
+                return ApiError::not_found(format!("Artist {} not found", artist_id))
                     .into_response()
             }
             Err(error) => {
-                return (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(ErrorResponse {
-                        error: format!("failed to fetch artist: {error}"),
-                    }),
-                )
-                    .into_response()
+                return ApiError::internal(error, "failed to fetch artist").into_response()
             }
         }
     }
@@ -636,33 +506,18 @@ pub async fn update_track(
                 {
                     Ok(Some(album)) => album.artist_id,
                     Ok(None) => {
-                        return (
-                            StatusCode::NOT_FOUND,
-                            Json(ErrorResponse {
-                                error: "current album for track was not found".to_string(),
-                            }),
-                        )
+                        return ApiError::not_found("current album for track was not found")
                             .into_response()
                     }
                     Err(error) => {
-                        return (
-                            StatusCode::INTERNAL_SERVER_ERROR,
-                            Json(ErrorResponse {
-                                error: format!("failed to re-validate album: {error}"),
-                            }),
-                        )
+                        return ApiError::internal(error, "failed to re-validate album")
                             .into_response()
                     }
                 }
             }
         };
         if album_artist_id != track.artist_id {
-            return (
-                StatusCode::BAD_REQUEST,
-                Json(ErrorResponse {
-                    error: "album_id and artist_id must reference the same artist".to_string(),
-                }),
-            )
+            return ApiError::bad_request("album_id and artist_id must reference the same artist")
                 .into_response();
         }
     }
@@ -687,17 +542,146 @@ pub async fn update_track(
     }
 
     match state.track_repository.update(track).await {
-        Ok(updated) => (StatusCode::OK, Json(TrackResponse::from(updated))).into_response(),
-        Err(error) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: format!("failed to update track: {error}"),
-            }),
-        )
-            .into_response(),
+        Ok(updated) => {
+            state
+                .event_dispatcher
+                .dispatch(&track_updated_event(&updated))
+                .await;
+            (StatusCode::OK, Json(TrackResponse::from(updated))).into_response()
+        }
+        Err(error) if is_concurrency_conflict(&error) => {
+            ApiError::conflict(error.to_string()).into_response()
+        }
+        Err(error) => ApiError::internal(error, "failed to update track").into_response(),
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/tracks/{id}/monitored",
+    params(
+        ("id" = String, Path, description = "Track ID")
+    ),
+    request_body = SetTrackMonitoredRequest,
+    responses(
+        (status = 200, description = "Track monitoring updated", body = TrackResponse),
+        (status = 404, description = "Track not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
+    ),
+    tag = "tracks"
+)]
+pub async fn set_track_monitored(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(request): Json<SetTrackMonitoredRequest>,
+) -> impl IntoResponse {
+    debug!(target: "api", %id, monitored = request.monitored, "setting track monitored");
+
+    let not_found = || ApiError::not_found(format!("Track {} not found", id)).into_response();
+    let Ok(uuid) = Uuid::parse_str(&id) else {
+        return not_found();
+    };
+
+    match state
+        .track_repository
+        .set_monitored(&[TrackId::from_uuid(uuid)], request.monitored)
+        .await
+    {
+        Ok(0) => return not_found(),
+        Ok(_) => {}
+        Err(error) => return ApiError::internal(error, "failed to update track").into_response(),
+    }
+
+    match state.track_repository.get_by_id(&id).await {
+        Ok(Some(track)) => {
+            state
+                .event_dispatcher
+                .dispatch(&track_updated_event(&track))
+                .await;
+            (StatusCode::OK, Json(TrackResponse::from(track))).into_response()
+        }
+        Ok(None) => not_found(),
+        Err(error) => ApiError::internal(error, "failed to fetch track").into_response(),
+    }
+}
+
+fn track_updated_event(track: &Track) -> TrackUpdated {
+    correlate(DomainEvent::new(
+        "track.updated",
+        TrackUpdatedPayload {
+            track_id: track.id,
+            album_id: track.album_id,
+            artist_id: track.artist_id,
+            title: track.title.clone(),
+        },
+    ))
+}
+
+fn strategy_name(strategy: MatchingStrategy) -> &'static str {
+    match strategy {
+        MatchingStrategy::Fingerprint => "fingerprint",
+        MatchingStrategy::EmbeddedTags => "embedded_tags",
+        MatchingStrategy::FilenameHeuristics => "filename_heuristics",
     }
 }
 
+/// Re-run matching for an imported file and move it to a better-matching track
+#[utoipa::path(
+    post,
+    path = "/api/v1/track-files/{id}/rematch",
+    params(
+        ("id" = String, Path, description = "Track file ID")
+    ),
+    responses(
+        (status = 200, description = "Rematch result with the old and new match", body = RematchTrackFileResponse),
+        (status = 404, description = "Track file not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
+    ),
+    tag = "tracks"
+)]
+pub async fn rematch_track_file(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    debug!(target: "api", %id, "rematching track file");
+
+    let not_found = || ApiError::not_found(format!("Track file {} not found", id)).into_response();
+    let internal_error = |error: &dyn std::fmt::Display| {
+        error!(target: "api", %id, %error, "failed to rematch track file");
+        ApiError::Internal(anyhow::anyhow!("failed to rematch track file")).into_response()
+    };
+    let Ok(uuid) = Uuid::parse_str(&id) else {
+        return not_found();
+    };
+
+    let service = match state.track_file_rematch_service() {
+        Ok(service) => service,
+        Err(error) => return internal_error(&error),
+    };
+    let outcome = match service
+        .rematch_track_file(TrackFileId::from_uuid(uuid))
+        .await
+    {
+        Ok(outcome) => outcome,
+        Err(RematchError::TrackFileNotFound(_)) => return not_found(),
+        Err(error) => return internal_error(&error),
+    };
+
+    if let Some(event) = outcome.rematched_event() {
+        state.event_dispatcher.dispatch(&correlate(event)).await;
+    }
+
+    let response = RematchTrackFileResponse {
+        track_file_id: outcome.track_file.id.to_string(),
+        changed: outcome.changed,
+        previous: outcome.previous.into(),
+        current: outcome.current.into(),
+        strategy: outcome.strategy.map(|s| strategy_name(s).to_string()),
+        musicbrainz_recording_id: outcome.musicbrainz_recording_id,
+    };
+    (StatusCode::OK, Json(response)).into_response()
+}
+
 #[utoipa::path(
     delete,
     path = "/api/v1/tracks/{id}",
@@ -706,8 +690,8 @@ pub async fn update_track(
     ),
     responses(
         (status = 204, description = "Track deleted"),
-        (status = 404, description = "Track not found", body = ErrorResponse),
-        (status = 500, description = "Internal server error", body = ErrorResponse)
+        (status = 404, description = "Track not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse)
     ),
     tag = "tracks"
 )]
@@ -724,38 +708,21 @@ pub async fn delete_track(
                 Err(delete_error) => {
                     // Check if the track was concurrently deleted before we could.
                     match state.track_repository.get_by_id(&id).await {
-                        Ok(None) => (
-                            StatusCode::NOT_FOUND,
-                            Json(ErrorResponse {
-                                error: format!("Track {} not found", id),
-                            }),
-                        )
-                            .into_response(),
-                        Ok(Some(_)) | Err(_) => (
-                            StatusCode::INTERNAL_SERVER_ERROR,
-                            Json(ErrorResponse {
-                                error: format!("failed to delete track: {delete_error}"),
-                            }),
-                        )
-                            .into_response(),
+                        Ok(None) => {
+                            ApiError::not_found(format!("Track {} not found", id)).into_response()
+                        }
+                        Ok(Some(_)) | Err(_) => {
+                            ApiError::internal(delete_error, "failed to delete track")
+                                .into_response()
+                        }
                     }
                 }
             }
         }
-        Ok(None) => (
-            StatusCode::NOT_FOUND,
-            Json(ErrorResponse {
-                error: format!("Track {} not found", id),
-            }),
-        )
-            .into_response(),
-        Err(error) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: format!("failed to fetch track before delete: {error}"),
-            }),
-        )
-            .into_response(),
+        Ok(None) => ApiError::not_found(format!("Track {} not found", id)).into_response(),
+        Err(error) => {
+            ApiError::internal(error, "failed to fetch track before delete").into_response()
+        }
     }
 }
 
@@ -813,6 +780,11 @@ mod tests {
                         pool.clone(),
                     ),
                 ),
+                Arc::new(
+                    chorrosion_infrastructure::sqlite_adapters::SqliteTrackFileRepository::new(
+                        pool.clone(),
+                    ),
+                ),
                 chorrosion_infrastructure::ResponseCache::new(100, 60),
             )
         }
@@ -856,7 +828,7 @@ mod tests {
             };
             let result = list_tracks(State(state), Query(query)).await;
             assert!(result.is_err());
-            let (status, _) = result.unwrap_err();
+            let status = result.unwrap_err().status();
             assert_eq!(status, StatusCode::BAD_REQUEST);
         }
 
@@ -869,7 +841,7 @@ mod tests {
             };
             let result = list_tracks(State(state), Query(query)).await;
             assert!(result.is_err());
-            let (status, _) = result.unwrap_err();
+            let status = result.unwrap_err().status();
             assert_eq!(status, StatusCode::BAD_REQUEST);
         }
 
@@ -945,9 +917,10 @@ mod tests {
             )
             .await;
             assert!(result.is_err());
-            let (status, Json(body)) = result.unwrap_err();
+            let body = result.unwrap_err();
+            let status = body.status();
             assert_eq!(status, StatusCode::NOT_FOUND);
-            assert_eq!(body.error, format!("Album {unknown_id} not found"));
+            assert_eq!(body.to_string(), format!("Album {unknown_id} not found"));
         }
 
         #[tokio::test]
@@ -1006,9 +979,10 @@ mod tests {
             )
             .await;
             assert!(result.is_err());
-            let (status, Json(body)) = result.unwrap_err();
+            let body = result.unwrap_err();
+            let status = body.status();
             assert_eq!(status, StatusCode::NOT_FOUND);
-            assert_eq!(body.error, format!("Artist {unknown_id} not found"));
+            assert_eq!(body.to_string(), format!("Artist {unknown_id} not found"));
         }
 
         // --- get_track ---
@@ -1310,5 +1284,51 @@ mod tests {
                 .into_response();
             assert_eq!(response.status(), StatusCode::NOT_FOUND);
         }
+
+        // --- rematch_track_file ---
+
+        #[tokio::test]
+        async fn rematch_track_file_returns_404_for_unknown_id() {
+            let state = make_test_state().await;
+            let unknown_id = "00000000-0000-0000-0000-000000000000".to_string();
+            let response = rematch_track_file(State(state), Path(unknown_id))
+                .await
+                .into_response();
+            assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        }
+
+        #[tokio::test]
+        async fn rematch_track_file_without_match_reports_unchanged() {
+            let state = make_test_state().await;
+            let artist = create_test_artist(&state).await;
+            let album = create_test_album(&state, &artist).await;
+            let track = state
+                .track_repository
+                .create(Track::new(album.id, artist.id, "Kept"))
+                .await
+                .unwrap();
+            let track_file = state
+                .track_file_repository
+                .create(chorrosion_domain::TrackFile::new(
+                    track.id,
+                    "/music/missing/01 - Kept.flac",
+                    1024,
+                ))
+                .await
+                .unwrap();
+
+            let response = rematch_track_file(State(state), Path(track_file.id.to_string()))
+                .await
+                .into_response();
+            assert_eq!(response.status(), StatusCode::OK);
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let body: RematchTrackFileResponse = serde_json::from_slice(&body).unwrap();
+            assert!(!body.changed);
+            assert_eq!(body.previous.track_id, track.id.to_string());
+            assert_eq!(body.current.track_id, track.id.to_string());
+            assert!(body.strategy.is_none());
+        }
     }
 }
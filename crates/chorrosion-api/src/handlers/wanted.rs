@@ -1,11 +1,12 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
+use crate::error::{ApiError, ApiErrorResponse};
 use axum::{
     extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
     Json,
 };
-use chorrosion_application::AppState;
+use chorrosion_application::{build_album_query, AppState, QueryBuildOptions};
 use chorrosion_domain::{Album, AlbumStatus};
 use serde::{Deserialize, Serialize};
 use tracing::{debug, warn};
@@ -43,11 +44,6 @@ pub struct WantedAlbumsResponse {
     pub offset: i64,
 }
 
-#[derive(Debug, Serialize, Deserialize, ToSchema)]
-pub struct WantedErrorResponse {
-    pub error: String,
-}
-
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct WantedManualSearchResponse {
     pub album_id: String,
@@ -63,7 +59,7 @@ impl From<Album> for WantedAlbumResponse {
             artist_id: album.artist_id.to_string(),
             foreign_album_id: album.foreign_album_id,
             title: album.title,
-            release_date: album.release_date.map(|d| d.format("%Y-%m-%d").to_string()),
+            release_date: album.release_date.map(|d| d.to_iso8601()),
             album_type: album.album_type,
             status: album.status.to_string(),
             monitored: album.monitored,
@@ -71,21 +67,13 @@ impl From<Album> for WantedAlbumResponse {
     }
 }
 
-fn validate_query(query: &WantedQuery) -> Result<(), (StatusCode, Json<WantedErrorResponse>)> {
+fn validate_query(query: &WantedQuery) -> Result<(), ApiError> {
     if !(1..=500).contains(&query.limit) {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(WantedErrorResponse {
-                error: "limit must be between 1 and 500".to_string(),
-            }),
-        ));
+        return Err(ApiError::bad_request("limit must be between 1 and 500"));
     }
     if query.offset < 0 {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(WantedErrorResponse {
-                error: "offset must be greater than or equal to 0".to_string(),
-            }),
+        return Err(ApiError::bad_request(
+            "offset must be greater than or equal to 0",
         ));
     }
     Ok(())
@@ -97,15 +85,15 @@ fn validate_query(query: &WantedQuery) -> Result<(), (StatusCode, Json<WantedErr
     params(WantedQuery),
     responses(
         (status = 200, description = "Paginated list of wanted albums", body = WantedAlbumsResponse),
-        (status = 400, description = "Invalid query parameters", body = WantedErrorResponse),
-        (status = 500, description = "Internal server error", body = WantedErrorResponse),
+        (status = 400, description = "Invalid query parameters", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
     ),
     tag = "wanted"
 )]
 pub async fn list_wanted_albums(
     State(state): State<AppState>,
     Query(query): Query<WantedQuery>,
-) -> Result<Json<WantedAlbumsResponse>, (StatusCode, Json<WantedErrorResponse>)> {
+) -> Result<Json<WantedAlbumsResponse>, ApiError> {
     debug!(target: "api", ?query, "listing wanted albums");
     validate_query(&query)?;
 
@@ -115,24 +103,11 @@ pub async fn list_wanted_albums(
         .album_repository
         .get_by_status(AlbumStatus::Wanted, 5000, 0)
         .await
-        .map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(WantedErrorResponse {
-                    error: format!("failed to list wanted albums: {e}"),
-                }),
-            )
-        })?;
+        .map_err(|e| ApiError::internal(e, "failed to list wanted albums"))?;
 
     let total = all.len() as i64;
-    let offset = usize::try_from(query.offset).map_err(|_| {
-        (
-            StatusCode::BAD_REQUEST,
-            Json(WantedErrorResponse {
-                error: "offset is out of range".to_string(),
-            }),
-        )
-    })?;
+    let offset = usize::try_from(query.offset)
+        .map_err(|_| ApiError::bad_request("offset is out of range"))?;
     let limit = query.limit as usize;
     let items = all
         .into_iter()
@@ -155,15 +130,15 @@ pub async fn list_wanted_albums(
     params(WantedQuery),
     responses(
         (status = 200, description = "Paginated list of wanted albums with no tracks", body = WantedAlbumsResponse),
-        (status = 400, description = "Invalid query parameters", body = WantedErrorResponse),
-        (status = 500, description = "Internal server error", body = WantedErrorResponse),
+        (status = 400, description = "Invalid query parameters", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
     ),
     tag = "wanted"
 )]
 pub async fn list_missing_albums(
     State(state): State<AppState>,
     Query(query): Query<WantedQuery>,
-) -> Result<Json<WantedAlbumsResponse>, (StatusCode, Json<WantedErrorResponse>)> {
+) -> Result<Json<WantedAlbumsResponse>, ApiError> {
     debug!(target: "api", ?query, "listing missing (wanted, no tracks) albums");
     validate_query(&query)?;
 
@@ -171,24 +146,11 @@ pub async fn list_missing_albums(
         .album_repository
         .list_wanted_without_tracks(5000, 0)
         .await
-        .map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(WantedErrorResponse {
-                    error: format!("failed to list missing albums: {e}"),
-                }),
-            )
-        })?;
+        .map_err(|e| ApiError::internal(e, "failed to list missing albums"))?;
 
     let total = all.len() as i64;
-    let offset = usize::try_from(query.offset).map_err(|_| {
-        (
-            StatusCode::BAD_REQUEST,
-            Json(WantedErrorResponse {
-                error: "offset is out of range".to_string(),
-            }),
-        )
-    })?;
+    let offset = usize::try_from(query.offset)
+        .map_err(|_| ApiError::bad_request("offset is out of range"))?;
     let limit = query.limit as usize;
     let items = all
         .into_iter()
@@ -211,15 +173,15 @@ pub async fn list_missing_albums(
     params(WantedQuery),
     responses(
         (status = 200, description = "Paginated list of albums with files below quality cutoff", body = WantedAlbumsResponse),
-        (status = 400, description = "Invalid query parameters", body = WantedErrorResponse),
-        (status = 500, description = "Internal server error", body = WantedErrorResponse),
+        (status = 400, description = "Invalid query parameters", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
     ),
     tag = "wanted"
 )]
 pub async fn list_cutoff_unmet_albums(
     State(state): State<AppState>,
     Query(query): Query<WantedQuery>,
-) -> Result<Json<WantedAlbumsResponse>, (StatusCode, Json<WantedErrorResponse>)> {
+) -> Result<Json<WantedAlbumsResponse>, ApiError> {
     debug!(target: "api", ?query, "listing cutoff-unmet albums");
     validate_query(&query)?;
 
@@ -227,24 +189,11 @@ pub async fn list_cutoff_unmet_albums(
         .album_repository
         .list_cutoff_unmet_albums(5000, 0)
         .await
-        .map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(WantedErrorResponse {
-                    error: format!("failed to list cutoff-unmet albums: {e}"),
-                }),
-            )
-        })?;
+        .map_err(|e| ApiError::internal(e, "failed to list cutoff-unmet albums"))?;
 
     let total = all.len() as i64;
-    let offset = usize::try_from(query.offset).map_err(|_| {
-        (
-            StatusCode::BAD_REQUEST,
-            Json(WantedErrorResponse {
-                error: "offset is out of range".to_string(),
-            }),
-        )
-    })?;
+    let offset = usize::try_from(query.offset)
+        .map_err(|_| ApiError::bad_request("offset is out of range"))?;
     let limit = query.limit as usize;
     let items = all
         .into_iter()
@@ -269,9 +218,9 @@ pub async fn list_cutoff_unmet_albums(
     ),
     responses(
         (status = 202, description = "Wanted album search triggered", body = WantedManualSearchResponse),
-        (status = 404, description = "Album not found", body = WantedErrorResponse),
-        (status = 409, description = "Album is not wanted", body = WantedErrorResponse),
-        (status = 500, description = "Internal server error", body = WantedErrorResponse),
+        (status = 404, description = "Album not found", body = ApiErrorResponse),
+        (status = 409, description = "Album is not wanted", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
     ),
     tag = "wanted"
 )]
@@ -284,36 +233,19 @@ pub async fn trigger_wanted_album_search(
     let album = match state.album_repository.get_by_id(&id).await {
         Ok(Some(album)) => album,
         Ok(None) => {
-            return (
-                StatusCode::NOT_FOUND,
-                Json(WantedErrorResponse {
-                    error: format!("Album {} not found", id),
-                }),
-            )
-                .into_response();
+            return ApiError::not_found(format!("Album {} not found", id)).into_response();
         }
         Err(error) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(WantedErrorResponse {
-                    error: format!("failed to fetch album: {error}"),
-                }),
-            )
-                .into_response();
+            return ApiError::internal(error, "failed to fetch album").into_response();
         }
     };
 
     if album.status != AlbumStatus::Wanted {
-        return (
-            StatusCode::CONFLICT,
-            Json(WantedErrorResponse {
-                error: format!(
-                    "Album {} has status '{}' and is not eligible for wanted search",
-                    album.id, album.status
-                ),
-            }),
-        )
-            .into_response();
+        return ApiError::conflict(format!(
+            "Album {} has status '{}' and is not eligible for wanted search",
+            album.id, album.status
+        ))
+        .into_response();
     }
 
     let artist_name = match state
@@ -332,19 +264,11 @@ pub async fn trigger_wanted_album_search(
             String::new()
         }
         Err(error) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(WantedErrorResponse {
-                    error: format!("failed to fetch artist: {error}"),
-                }),
-            )
-                .into_response();
+            return ApiError::internal(error, "failed to fetch artist").into_response();
         }
     };
 
-    let query = format!("{} {}", artist_name, album.title)
-        .trim()
-        .to_string();
+    let query = build_album_query(&artist_name, &album.title, &QueryBuildOptions::default());
 
     (
         StatusCode::ACCEPTED,
@@ -405,6 +329,11 @@ mod tests {
                     pool.clone(),
                 ),
             ),
+            Arc::new(
+                chorrosion_infrastructure::sqlite_adapters::SqliteTrackFileRepository::new(
+                    pool.clone(),
+                ),
+            ),
             chorrosion_infrastructure::ResponseCache::new(100, 60),
         );
         (pool, state)
@@ -561,7 +490,7 @@ mod tests {
         )
         .await
         .unwrap_err();
-        assert_eq!(err.0, StatusCode::BAD_REQUEST);
+        assert_eq!(err.status(), StatusCode::BAD_REQUEST);
     }
 
     #[tokio::test]
@@ -576,7 +505,7 @@ mod tests {
         )
         .await
         .unwrap_err();
-        assert_eq!(err.0, StatusCode::BAD_REQUEST);
+        assert_eq!(err.status(), StatusCode::BAD_REQUEST);
     }
 
     #[tokio::test]
@@ -591,7 +520,7 @@ mod tests {
         )
         .await
         .unwrap_err();
-        assert_eq!(err.0, StatusCode::BAD_REQUEST);
+        assert_eq!(err.status(), StatusCode::BAD_REQUEST);
     }
 
     #[tokio::test]
@@ -606,7 +535,7 @@ mod tests {
         )
         .await
         .unwrap_err();
-        assert_eq!(err.0, StatusCode::BAD_REQUEST);
+        assert_eq!(err.status(), StatusCode::BAD_REQUEST);
     }
 
     #[tokio::test]
@@ -637,7 +566,7 @@ mod tests {
         )
         .await
         .unwrap_err();
-        assert_eq!(err.0, StatusCode::BAD_REQUEST);
+        assert_eq!(err.status(), StatusCode::BAD_REQUEST);
     }
 
     #[tokio::test]
@@ -652,7 +581,7 @@ mod tests {
         )
         .await
         .unwrap_err();
-        assert_eq!(err.0, StatusCode::BAD_REQUEST);
+        assert_eq!(err.status(), StatusCode::BAD_REQUEST);
     }
 
     #[tokio::test]
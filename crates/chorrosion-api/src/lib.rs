@@ -1,4 +1,5 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
+pub mod error;
 pub mod handlers;
 pub mod middleware;
 
@@ -12,156 +13,177 @@ use axum::{
     http::StatusCode,
     http::{header, HeaderValue, Method},
     middleware as axum_middleware,
-    routing::{get, post},
+    routing::{get, post, put},
     Json, Router,
 };
 use chorrosion_application::AppState;
 use chorrosion_config::PermissionLevel;
+use error::{ApiErrorResponse, FieldErrorResponse};
 use handlers::activity::{
-    get_activity_failed, get_activity_history, get_activity_processing, get_activity_queue,
-    get_activity_stalled, ActivityErrorResponse, ActivityItemResponse, ActivityListResponse,
     __path_get_activity_failed, __path_get_activity_history, __path_get_activity_processing,
-    __path_get_activity_queue, __path_get_activity_stalled,
+    __path_get_activity_queue, __path_get_activity_stalled, get_activity_failed,
+    get_activity_history, get_activity_processing, get_activity_queue, get_activity_stalled,
+    ActivityItemResponse, ActivityListResponse,
 };
 use handlers::albums::{
-    create_album, delete_album, get_album, list_albums, list_albums_by_artist,
-    trigger_album_search, update_album, AlbumResponse, CreateAlbumRequest,
-    ErrorResponse as AlbumErrorResponse, ListAlbumsResponse, TriggerAlbumSearchResponse,
-    UpdateAlbumRequest, __path_create_album, __path_delete_album, __path_get_album,
-    __path_list_albums, __path_list_albums_by_artist, __path_trigger_album_search,
-    __path_update_album,
+    __path_auto_search_album, __path_create_album, __path_delete_album, __path_get_album,
+    __path_get_album_search_status, __path_list_albums, __path_list_albums_by_artist,
+    __path_set_album_monitored, __path_set_albums_monitored, __path_trigger_album_search,
+    __path_update_album, auto_search_album, create_album, delete_album, get_album,
+    get_album_search_status, list_albums, list_albums_by_artist, set_album_monitored,
+    set_albums_monitored, trigger_album_search, update_album, AlbumResponse,
+    AlbumSearchStatusResponse, CreateAlbumRequest, DeleteAlbumResponse, FailedFileDeletionResponse,
+    ListAlbumsResponse, SetAlbumMonitoredRequest, SetAlbumsMonitoredRequest,
+    SetAlbumsMonitoredResponse, TriggerAlbumSearchResponse, UpdateAlbumRequest,
 };
 use handlers::appearance::{
-    get_appearance_settings, update_appearance_settings, AppearanceErrorResponse,
-    AppearanceSettingsResponse, FilterOperatorApi, ShortcutProfileApi, ThemeModeApi,
-    UpdateAppearanceSettingsRequest, __path_get_appearance_settings,
-    __path_update_appearance_settings,
+    __path_get_appearance_settings, __path_update_appearance_settings, get_appearance_settings,
+    update_appearance_settings, AppearanceSettingsResponse, FilterOperatorApi, ShortcutProfileApi,
+    ThemeModeApi, UpdateAppearanceSettingsRequest,
 };
 use handlers::artists::{
-    create_artist, delete_artist, get_artist, get_artist_statistics, list_artists, update_artist,
-    ArtistResponse, ArtistStatisticsResponse, CreateArtistRequest, ErrorResponse,
-    ListArtistsResponse, UpdateArtistRequest, __path_create_artist, __path_delete_artist,
-    __path_get_artist, __path_get_artist_statistics, __path_list_artists, __path_update_artist,
+    __path_create_artist, __path_delete_artist, __path_export_artists, __path_get_artist,
+    __path_get_artist_statistics, __path_list_artists, __path_merge_artist,
+    __path_set_artist_monitored, __path_update_artist, create_artist, delete_artist,
+    export_artists, get_artist, get_artist_statistics, list_artists, merge_artist,
+    set_artist_monitored, update_artist, ArtistResponse, ArtistStatisticsResponse,
+    CreateArtistRequest, ListArtistsResponse, MergeArtistRequest, SetArtistMonitoredRequest,
+    UpdateArtistRequest,
 };
 use handlers::auth::{
-    create_api_key, delete_api_key, forms_login, forms_logout, list_api_keys,
-    ApiKeyMetadataResponse, ApiKeyResponse, AuthErrorResponse, CreateApiKeyRequest,
-    DeleteApiKeyResponse, FormsLoginRequest, FormsLoginResponse, FormsLogoutResponse,
-    ListApiKeysResponse, __path_create_api_key, __path_delete_api_key, __path_forms_login,
-    __path_forms_logout, __path_list_api_keys,
+    __path_create_api_key, __path_delete_api_key, __path_forms_login, __path_forms_logout,
+    __path_list_api_keys, create_api_key, delete_api_key, forms_login, forms_logout, list_api_keys,
+    ApiKeyMetadataResponse, ApiKeyResponse, CreateApiKeyRequest, DeleteApiKeyResponse,
+    FormsLoginRequest, FormsLoginResponse, FormsLogoutResponse, ListApiKeysResponse,
 };
 use handlers::calendar::{
-    get_ical_feed, list_upcoming_releases, CalendarAlbumResponse, CalendarErrorResponse,
-    CalendarResponse, __path_get_ical_feed, __path_list_upcoming_releases,
+    __path_get_ical_feed, __path_list_upcoming_releases, get_ical_feed, list_upcoming_releases,
+    CalendarAlbumResponse, CalendarResponse,
 };
 use handlers::download_clients::{
-    bulk_download_clients, create_download_client, delete_download_client, export_download_clients,
-    get_download_client, import_download_clients, list_download_clients, update_download_client,
-    CreateDownloadClientRequest, DownloadClientBulkRequest, DownloadClientBulkResponse,
-    DownloadClientErrorResponse, DownloadClientExportEnvelope, DownloadClientImportErrorResponse,
+    __path_bulk_download_clients, __path_create_download_client, __path_delete_download_client,
+    __path_export_download_clients, __path_get_download_client, __path_import_download_clients,
+    __path_list_download_clients, __path_update_download_client, bulk_download_clients,
+    create_download_client, delete_download_client, export_download_clients, get_download_client,
+    import_download_clients, list_download_clients, update_download_client,
+    CreateDownloadClientRequest, DownloadClientBulkItemResult, DownloadClientBulkRequest,
+    DownloadClientBulkResponse, DownloadClientExportEnvelope, DownloadClientImportItem,
     DownloadClientImportRequest, DownloadClientImportResponse, DownloadClientResponse,
-    ListDownloadClientsResponse, UpdateDownloadClientRequest, __path_bulk_download_clients,
-    __path_create_download_client, __path_delete_download_client, __path_export_download_clients,
-    __path_get_download_client, __path_import_download_clients, __path_list_download_clients,
-    __path_update_download_client,
+    ListDownloadClientsResponse, UpdateDownloadClientRequest,
 };
 use handlers::duplicates::{
-    get_duplicate_group, list_duplicate_groups, resolve_duplicate_group, DuplicateFileResponse,
-    DuplicateGroupDetailResponse, DuplicateGroupQuery, DuplicateGroupResponse,
-    ErrorResponse as DuplicateErrorResponse, ListDuplicatesQuery, ListDuplicatesResponse,
-    ResolveDuplicateRequest, ResolveDuplicateResponse, __path_get_duplicate_group,
-    __path_list_duplicate_groups, __path_resolve_duplicate_group,
+    __path_get_duplicate_group, __path_list_duplicate_groups, __path_resolve_duplicate_group,
+    get_duplicate_group, list_duplicate_groups, resolve_duplicate_group,
+    DuplicateClusterFileResponse, DuplicateClusterResponse, DuplicateFileResponse,
+    DuplicateGroupDetailResponse, DuplicateGroupQuery, DuplicateGroupResponse, ListDuplicatesQuery,
+    ListDuplicatesResponse, ResolveDuplicateRequest, ResolveDuplicateResponse,
 };
 use handlers::events::{
     __path_get_sse_connections, __path_post_broadcast_event,
     __path_stream_download_progress_events, __path_stream_events,
     __path_stream_import_progress_events, __path_stream_job_status_events, get_sse_connections,
     post_broadcast_event, stream_download_progress_events, stream_events,
-    stream_import_progress_events, stream_job_status_events, BroadcastErrorResponse,
-    BroadcastEventRequest, BroadcastEventResponse, SseConnectionsResponse,
+    stream_import_progress_events, stream_job_status_events, BroadcastEventRequest,
+    BroadcastEventResponse, SseConnectionsResponse,
 };
 use handlers::imports::{
-    evaluate_import_candidate, submit_manual_import_decision, CatalogAlbumMatchResponse,
-    ImportCandidateRequest, ImportCandidateResponse, ImportDecisionResponse, ImportErrorResponse,
-    ImportRawMetadataRequest, ManualImportDecisionRequest, ManualImportDecisionResponse,
-    ParsedMetadataResponse, __path_evaluate_import_candidate, __path_submit_manual_import_decision,
+    __path_evaluate_import_candidate, __path_scan_import_directory,
+    __path_submit_manual_import_decision, evaluate_import_candidate, scan_import_directory,
+    submit_manual_import_decision, CatalogAlbumMatchResponse, ImportCandidateRequest,
+    ImportCandidateResponse, ImportCatalogAlbumRequest, ImportDecisionResponse,
+    ImportRawMetadataRequest, ImportScanItemResponse, ImportScanRequest, ImportScanResponse,
+    ManualImportDecisionRequest, ManualImportDecisionResponse, ParsedMetadataResponse,
 };
 use handlers::indexers::{
+    __path_bulk_indexers, __path_create_indexer, __path_delete_indexer, __path_export_indexers,
+    __path_get_indexer, __path_import_indexers, __path_list_indexers,
+    __path_test_all_indexers_endpoint, __path_test_indexer_endpoint, __path_update_indexer,
     bulk_indexers, create_indexer, delete_indexer, export_indexers, get_indexer, import_indexers,
-    list_indexers, test_indexer_endpoint, update_indexer, CreateIndexerRequest, IndexerBulkRequest,
-    IndexerBulkResponse, IndexerCapabilitiesResponse, IndexerErrorResponse, IndexerExportEnvelope,
-    IndexerImportErrorResponse, IndexerImportRequest, IndexerImportResponse, IndexerResponse,
-    IndexerTestErrorResponse, ListIndexersResponse, TestIndexerRequest, TestIndexerResponse,
-    UpdateIndexerRequest, __path_bulk_indexers, __path_create_indexer, __path_delete_indexer,
-    __path_export_indexers, __path_get_indexer, __path_import_indexers, __path_list_indexers,
-    __path_test_indexer_endpoint, __path_update_indexer,
+    list_indexers, test_all_indexers_endpoint, test_indexer_endpoint, update_indexer,
+    CreateIndexerRequest, IndexerBulkItemResult, IndexerBulkRequest, IndexerBulkResponse,
+    IndexerCapabilitiesResponse, IndexerExportEnvelope, IndexerImportItem, IndexerImportRequest,
+    IndexerImportResponse, IndexerResponse, IndexerTestAllItem, ListIndexersResponse,
+    TestAllIndexersResponse, TestIndexerRequest, TestIndexerResponse, UpdateIndexerRequest,
 };
 use handlers::metadata_profiles::{
-    bulk_metadata_profiles, create_metadata_profile, delete_metadata_profile,
-    export_metadata_profiles, get_metadata_profile, import_metadata_profiles,
-    list_metadata_profiles, update_metadata_profile, CreateMetadataProfileRequest,
-    ErrorResponse as MetadataProfileErrorResponse, ListMetadataProfilesResponse,
-    MetadataProfileBulkRequest, MetadataProfileBulkResponse, MetadataProfileExportEnvelope,
-    MetadataProfileImportErrorResponse, MetadataProfileImportRequest,
-    MetadataProfileImportResponse, MetadataProfileResponse, UpdateMetadataProfileRequest,
     __path_bulk_metadata_profiles, __path_create_metadata_profile, __path_delete_metadata_profile,
     __path_export_metadata_profiles, __path_get_metadata_profile, __path_import_metadata_profiles,
-    __path_list_metadata_profiles, __path_update_metadata_profile,
+    __path_list_metadata_profiles, __path_set_default_metadata_profile,
+    __path_update_metadata_profile, bulk_metadata_profiles, create_metadata_profile,
+    delete_metadata_profile, export_metadata_profiles, get_metadata_profile,
+    import_metadata_profiles, list_metadata_profiles, set_default_metadata_profile,
+    update_metadata_profile, CreateMetadataProfileRequest, ListMetadataProfilesResponse,
+    MetadataProfileBulkItemResult, MetadataProfileBulkRequest, MetadataProfileBulkResponse,
+    MetadataProfileExportEnvelope, MetadataProfileImportItem, MetadataProfileImportRequest,
+    MetadataProfileImportResponse, MetadataProfileResponse, UpdateMetadataProfileRequest,
 };
 use handlers::quality_profiles::{
-    bulk_quality_profiles, create_quality_profile, delete_quality_profile, export_quality_profiles,
-    get_quality_profile, import_quality_profiles, list_quality_profiles, update_quality_profile,
-    CreateQualityProfileRequest, ErrorResponse as QualityProfileErrorResponse,
-    ListQualityProfilesResponse, QualityProfileBulkRequest, QualityProfileBulkResponse,
-    QualityProfileExportEnvelope, QualityProfileImportErrorResponse, QualityProfileImportRequest,
-    QualityProfileImportResponse, QualityProfileResponse, UpdateQualityProfileRequest,
     __path_bulk_quality_profiles, __path_create_quality_profile, __path_delete_quality_profile,
     __path_export_quality_profiles, __path_get_quality_profile, __path_import_quality_profiles,
-    __path_list_quality_profiles, __path_update_quality_profile,
+    __path_list_quality_profiles, __path_set_default_quality_profile,
+    __path_update_quality_profile, bulk_quality_profiles, create_quality_profile,
+    delete_quality_profile, export_quality_profiles, get_quality_profile, import_quality_profiles,
+    list_quality_profiles, set_default_quality_profile, update_quality_profile,
+    CreateQualityProfileRequest, ListQualityProfilesResponse, QualityProfileBulkItemResult,
+    QualityProfileBulkRequest, QualityProfileBulkResponse, QualityProfileExportEnvelope,
+    QualityProfileImportItem, QualityProfileImportRequest, QualityProfileImportResponse,
+    QualityProfileResponse, UpdateQualityProfileRequest,
 };
+use handlers::realtime::{__path_websocket_handler, websocket_handler};
 use handlers::search::{
-    manual_search_endpoint, ManualSearchApiRequest, ManualSearchApiResponse,
-    ManualSearchResultItem, SearchErrorResponse, __path_manual_search_endpoint,
+    __path_grab_release_endpoint, __path_manual_search_endpoint, __path_search_endpoint,
+    grab_release_endpoint, manual_search_endpoint, search_endpoint, GrabReleaseRequest,
+    GrabReleaseResponse, ManualSearchApiRequest, ManualSearchApiResponse,
+    ManualSearchCustomFormatRule, ManualSearchResultItem, SearchApiRequest, SearchApiResponse,
+    SearchIndexerFailure, SearchResultItem,
 };
 use handlers::smart_playlists::{
-    create_smart_playlist, delete_smart_playlist, get_smart_playlist, get_smart_playlist_items,
-    list_smart_playlists, update_smart_playlist, CreateSmartPlaylistRequest,
-    ErrorResponse as SmartPlaylistErrorResponse, ListSmartPlaylistsResponse,
-    SmartPlaylistCriteriaRequest, SmartPlaylistItemsResponse, SmartPlaylistResponse,
     __path_create_smart_playlist, __path_delete_smart_playlist, __path_get_smart_playlist,
     __path_get_smart_playlist_items, __path_list_smart_playlists, __path_update_smart_playlist,
+    create_smart_playlist, delete_smart_playlist, get_smart_playlist, get_smart_playlist_items,
+    list_smart_playlists, update_smart_playlist, CreateSmartPlaylistRequest,
+    ListSmartPlaylistsResponse, SmartPlaylistCriteriaRequest, SmartPlaylistItemsResponse,
+    SmartPlaylistResponse, UpdateSmartPlaylistRequest,
 };
 use handlers::system::{
+    __path_get_system_logs, __path_get_system_notifications, __path_get_system_status,
+    __path_get_system_tasks, __path_get_system_version, __path_post_system_notifications_test,
     get_system_logs, get_system_notifications, get_system_status, get_system_tasks,
-    get_system_version, post_system_notifications_test, NotificationProviderStatusResponse,
-    NotificationStatusResponse, NotificationTestResponse, SystemLogEntryResponse,
-    SystemLogsResponse, SystemStatusResponse, SystemTaskResponse, SystemTasksResponse,
-    SystemVersionResponse, __path_get_system_logs, __path_get_system_notifications,
-    __path_get_system_status, __path_get_system_tasks, __path_get_system_version,
-    __path_post_system_notifications_test,
+    get_system_version, post_system_notifications_test, DependencyHealthResponse,
+    NotificationProviderKindApi, NotificationProviderStatusResponse, NotificationStatusResponse,
+    NotificationTestResponse, SystemLogEntryResponse, SystemLogsResponse, SystemStatusResponse,
+    SystemTaskResponse, SystemTasksResponse, SystemVersionResponse,
 };
 use handlers::tags::{
-    assign_tag_to_entity, create_tag, delete_tag, get_entity_tags, get_tag, list_tags,
-    remove_tag_from_entity, update_tag, CreateTagRequest, EntityTagsResponse,
-    ErrorResponse as TagErrorResponse, ListTagsResponse, TagResponse, UpdateTagRequest,
     __path_assign_tag_to_entity, __path_create_tag, __path_delete_tag, __path_get_entity_tags,
     __path_get_tag, __path_list_tags, __path_remove_tag_from_entity, __path_update_tag,
+    assign_tag_to_entity, create_tag, delete_tag, get_entity_tags, get_tag, list_tags,
+    remove_tag_from_entity, update_tag, CreateTagRequest, EntityTagsResponse, ListTagsResponse,
+    TagResponse, UpdateTagRequest,
 };
 use handlers::tracks::{
-    create_track, delete_track, get_track, list_tracks, list_tracks_by_album,
-    list_tracks_by_artist, update_track, CreateTrackRequest, ErrorResponse as TrackErrorResponse,
-    ListTracksResponse, TrackResponse, UpdateTrackRequest, __path_create_track,
-    __path_delete_track, __path_get_track, __path_list_tracks, __path_list_tracks_by_album,
-    __path_list_tracks_by_artist, __path_update_track,
+    __path_create_track, __path_delete_track, __path_get_track, __path_list_tracks,
+    __path_list_tracks_by_album, __path_list_tracks_by_artist, __path_rematch_track_file,
+    __path_set_track_monitored, __path_update_track, create_track, delete_track, get_track,
+    list_tracks, list_tracks_by_album, list_tracks_by_artist, rematch_track_file,
+    set_track_monitored, update_track, CreateTrackRequest, ListTracksResponse,
+    RematchTrackFileResponse, SetTrackMonitoredRequest, TrackMatchResponse, TrackResponse,
+    UpdateTrackRequest,
 };
 use handlers::wanted::{
-    list_cutoff_unmet_albums, list_missing_albums, list_wanted_albums, trigger_wanted_album_search,
-    WantedAlbumResponse, WantedAlbumsResponse, WantedErrorResponse, WantedManualSearchResponse,
     __path_list_cutoff_unmet_albums, __path_list_missing_albums, __path_list_wanted_albums,
-    __path_trigger_wanted_album_search,
+    __path_trigger_wanted_album_search, list_cutoff_unmet_albums, list_missing_albums,
+    list_wanted_albums, trigger_wanted_album_search, WantedAlbumResponse, WantedAlbumsResponse,
+    WantedManualSearchResponse,
 };
 use middleware::auth::auth_middleware;
+use middleware::etag::etag_middleware;
 use middleware::metrics::{metrics_handler, metrics_middleware};
+use middleware::rate_limit::{
+    rate_limit_middleware, RateLimiter, RATE_LIMIT_LIMIT_HEADER, RATE_LIMIT_REMAINING_HEADER,
+    RATE_LIMIT_RESET_HEADER,
+};
+use middleware::request_id::request_id_middleware;
 use middleware::response_cache::response_cache_middleware;
 use middleware::tracing::request_tracing_middleware;
 use serde::Serialize;
@@ -169,7 +191,9 @@ use std::path::PathBuf;
 use tower_http::cors::CorsLayer;
 use tower_http::services::{ServeDir, ServeFile};
 use tracing::{info, warn};
-use utoipa::openapi::security::{ApiKey, ApiKeyValue, Http, HttpAuthScheme, SecurityScheme};
+use utoipa::openapi::security::{
+    ApiKey, ApiKeyValue, Http, HttpAuthScheme, SecurityRequirement, SecurityScheme,
+};
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
@@ -192,18 +216,93 @@ impl utoipa::Modify for SecurityAddon {
         );
         // Apply a default global security requirement (api key OR bearer).
         openapi.security = Some(vec![
-            utoipa::openapi::security::SecurityRequirement::new::<&str, [&str; 0], &str>(
-                "ApiKeyAuth",
-                [],
-            ),
-            utoipa::openapi::security::SecurityRequirement::new::<&str, [&str; 0], &str>(
-                "BearerAuth",
-                [],
-            ),
+            SecurityRequirement::new::<&str, [&str; 0], &str>("ApiKeyAuth", []),
+            SecurityRequirement::new::<&str, [&str; 0], &str>("BearerAuth", []),
         ]);
     }
 }
 
+/// Documents the standardized [`ApiErrorResponse`] body on every operation.
+///
+/// Secured operations gain shared `401`/`403` responses for what the auth
+/// middleware rejects, and operations that declare no error status of their
+/// own fall back to a `default` error response.
+struct ErrorResponsesAddon;
+
+impl ErrorResponsesAddon {
+    fn error_response(description: &str) -> utoipa::openapi::Response {
+        utoipa::openapi::ResponseBuilder::new()
+            .description(description)
+            .content(
+                "application/json",
+                utoipa::openapi::ContentBuilder::new()
+                    .schema(Some(utoipa::openapi::Ref::from_schema_name(
+                        "ApiErrorResponse",
+                    )))
+                    .build(),
+            )
+            .build()
+    }
+
+    fn is_public(operation: &utoipa::openapi::path::Operation) -> bool {
+        operation.security.as_ref().is_some_and(|requirements| {
+            requirements
+                .iter()
+                .all(|requirement| *requirement == SecurityRequirement::default())
+        })
+    }
+}
+
+impl utoipa::Modify for ErrorResponsesAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi
+            .components
+            .get_or_insert_with(utoipa::openapi::Components::new);
+        for (name, description) in [
+            ("Unauthorized", "Missing or invalid credentials"),
+            (
+                "Forbidden",
+                "Credentials lack the scope required by this route",
+            ),
+            ("Error", "Unexpected error"),
+        ] {
+            components
+                .responses
+                .insert(name.to_string(), Self::error_response(description).into());
+        }
+
+        for item in openapi.paths.paths.values_mut() {
+            let operations = [
+                &mut item.get,
+                &mut item.put,
+                &mut item.post,
+                &mut item.delete,
+                &mut item.patch,
+            ];
+            for operation in operations.into_iter().flatten() {
+                let public = Self::is_public(operation);
+                let responses = &mut operation.responses.responses;
+                let declares_error = responses
+                    .keys()
+                    .any(|status| status.starts_with('4') || status.starts_with('5'));
+                if !public {
+                    for (status, name) in [("401", "Unauthorized"), ("403", "Forbidden")] {
+                        responses.entry(status.to_string()).or_insert_with(|| {
+                            utoipa::openapi::Ref::from_response_name(name).into()
+                        });
+                    }
+                }
+                if !declares_error {
+                    responses.insert(
+                        "default".to_string(),
+                        utoipa::openapi::Ref::from_response_name("Error").into(),
+                    );
+                }
+            }
+        }
+    }
+}
+
 #[derive(Serialize, utoipa::ToSchema)]
 struct HealthCheckDependency {
     status: &'static str,
@@ -267,14 +366,17 @@ async fn health(
     get,
     path = "/metrics",
     responses(
-        (status = 200, description = "Prometheus metrics in text exposition format", body = String)
+        (status = 200, description = "Prometheus metrics in text exposition format", body = String),
+        (status = 500, description = "Metrics could not be rendered", body = String, content_type = "text/plain")
     ),
     security(()),
     tag = "system"
 )]
 #[allow(dead_code)]
-async fn metrics() -> axum::response::Response {
-    metrics_handler().await
+async fn metrics(
+    axum::extract::State(state): axum::extract::State<AppState>,
+) -> axum::response::Response {
+    metrics_handler(axum::extract::State(state)).await
 }
 
 #[derive(OpenApi)]
@@ -288,25 +390,34 @@ async fn metrics() -> axum::response::Response {
         forms_login,
         forms_logout,
         list_artists,
+        export_artists,
         get_artist,
         get_artist_statistics,
         create_artist,
         update_artist,
+        set_artist_monitored,
         delete_artist,
+        merge_artist,
         list_albums,
         list_albums_by_artist,
         get_album,
         create_album,
         update_album,
+        set_album_monitored,
+        set_albums_monitored,
         delete_album,
         trigger_album_search,
+        get_album_search_status,
+        auto_search_album,
         list_tracks,
         list_tracks_by_album,
         list_tracks_by_artist,
         get_track,
         create_track,
         update_track,
+        set_track_monitored,
         delete_track,
+        rematch_track_file,
         get_system_status,
         get_system_version,
         get_system_tasks,
@@ -326,11 +437,13 @@ async fn metrics() -> axum::response::Response {
         stream_download_progress_events,
         stream_import_progress_events,
         stream_job_status_events,
+        websocket_handler,
         list_quality_profiles,
         get_quality_profile,
         create_quality_profile,
         update_quality_profile,
         delete_quality_profile,
+        set_default_quality_profile,
         bulk_quality_profiles,
         export_quality_profiles,
         import_quality_profiles,
@@ -339,6 +452,7 @@ async fn metrics() -> axum::response::Response {
         create_metadata_profile,
         update_metadata_profile,
         delete_metadata_profile,
+        set_default_metadata_profile,
         bulk_metadata_profiles,
         export_metadata_profiles,
         import_metadata_profiles,
@@ -359,9 +473,13 @@ async fn metrics() -> axum::response::Response {
         export_indexers,
         import_indexers,
         test_indexer_endpoint,
+        test_all_indexers_endpoint,
         manual_search_endpoint,
+        search_endpoint,
+        grab_release_endpoint,
         evaluate_import_candidate,
         submit_manual_import_decision,
+        scan_import_directory,
         list_wanted_albums,
         list_missing_albums,
         list_cutoff_unmet_albums,
@@ -389,12 +507,12 @@ async fn metrics() -> axum::response::Response {
     components(
         schemas(
             HealthResponse,
+            HealthCheckDependency,
             ListApiKeysResponse,
             ApiKeyResponse,
             ApiKeyMetadataResponse,
             CreateApiKeyRequest,
             DeleteApiKeyResponse,
-            AuthErrorResponse,
             PermissionLevel,
             FormsLoginRequest,
             FormsLoginResponse,
@@ -406,19 +524,30 @@ async fn metrics() -> axum::response::Response {
             ArtistStatisticsResponse,
             CreateArtistRequest,
             UpdateArtistRequest,
-            ErrorResponse,
+            SetArtistMonitoredRequest,
+            MergeArtistRequest,
+            ApiErrorResponse,
+            FieldErrorResponse,
             ListAlbumsResponse,
             AlbumResponse,
             CreateAlbumRequest,
             UpdateAlbumRequest,
+            SetAlbumMonitoredRequest,
+            SetAlbumsMonitoredRequest,
+            SetAlbumsMonitoredResponse,
+            DeleteAlbumResponse,
+            FailedFileDeletionResponse,
             TriggerAlbumSearchResponse,
-            AlbumErrorResponse,
+            AlbumSearchStatusResponse,
             ListTracksResponse,
             TrackResponse,
             CreateTrackRequest,
             UpdateTrackRequest,
-            TrackErrorResponse,
+            SetTrackMonitoredRequest,
+            TrackMatchResponse,
+            RematchTrackFileResponse,
             SystemStatusResponse,
+            DependencyHealthResponse,
             SystemVersionResponse,
             SystemTasksResponse,
             SystemTaskResponse,
@@ -426,105 +555,111 @@ async fn metrics() -> axum::response::Response {
             SystemLogEntryResponse,
             NotificationStatusResponse,
             NotificationProviderStatusResponse,
+            NotificationProviderKindApi,
             NotificationTestResponse,
             AppearanceSettingsResponse,
             UpdateAppearanceSettingsRequest,
-            AppearanceErrorResponse,
             ThemeModeApi,
             ShortcutProfileApi,
             FilterOperatorApi,
             ActivityItemResponse,
             ActivityListResponse,
-            ActivityErrorResponse,
-            BroadcastErrorResponse,
             SseConnectionsResponse,
             ListQualityProfilesResponse,
             QualityProfileResponse,
             CreateQualityProfileRequest,
             UpdateQualityProfileRequest,
-            QualityProfileErrorResponse,
             QualityProfileBulkRequest,
             QualityProfileBulkResponse,
+            QualityProfileBulkItemResult,
             QualityProfileExportEnvelope,
             QualityProfileImportRequest,
+            QualityProfileImportItem,
             QualityProfileImportResponse,
-            QualityProfileImportErrorResponse,
             ListMetadataProfilesResponse,
             MetadataProfileResponse,
             CreateMetadataProfileRequest,
             UpdateMetadataProfileRequest,
-            MetadataProfileErrorResponse,
             MetadataProfileBulkRequest,
             MetadataProfileBulkResponse,
+            MetadataProfileBulkItemResult,
             MetadataProfileExportEnvelope,
             MetadataProfileImportRequest,
+            MetadataProfileImportItem,
             MetadataProfileImportResponse,
-            MetadataProfileImportErrorResponse,
             ListDownloadClientsResponse,
             DownloadClientResponse,
             CreateDownloadClientRequest,
             UpdateDownloadClientRequest,
-            DownloadClientErrorResponse,
             DownloadClientBulkRequest,
             DownloadClientBulkResponse,
+            DownloadClientBulkItemResult,
             DownloadClientExportEnvelope,
             DownloadClientImportRequest,
+            DownloadClientImportItem,
             DownloadClientImportResponse,
-            DownloadClientImportErrorResponse,
             ListIndexersResponse,
             IndexerResponse,
             CreateIndexerRequest,
             UpdateIndexerRequest,
-            IndexerErrorResponse,
             IndexerBulkRequest,
             IndexerBulkResponse,
+            IndexerBulkItemResult,
             IndexerExportEnvelope,
             IndexerImportRequest,
+            IndexerImportItem,
             IndexerImportResponse,
-            IndexerImportErrorResponse,
             TestIndexerRequest,
             TestIndexerResponse,
             IndexerCapabilitiesResponse,
-            IndexerTestErrorResponse,
+            TestAllIndexersResponse,
+            IndexerTestAllItem,
             ManualSearchApiRequest,
+            ManualSearchCustomFormatRule,
             ManualSearchResultItem,
             ManualSearchApiResponse,
-            SearchErrorResponse,
-            ImportErrorResponse,
+            SearchApiRequest,
+            SearchResultItem,
+            SearchIndexerFailure,
+            SearchApiResponse,
+            GrabReleaseRequest,
+            GrabReleaseResponse,
             ImportRawMetadataRequest,
             ImportCandidateRequest,
+            ImportCatalogAlbumRequest,
             ParsedMetadataResponse,
             CatalogAlbumMatchResponse,
             ImportDecisionResponse,
             ImportCandidateResponse,
             ManualImportDecisionRequest,
             ManualImportDecisionResponse,
+            ImportScanRequest,
+            ImportScanItemResponse,
+            ImportScanResponse,
             WantedAlbumsResponse,
             WantedAlbumResponse,
-            WantedErrorResponse,
             WantedManualSearchResponse,
             CalendarResponse,
             CalendarAlbumResponse,
-            CalendarErrorResponse,
             ListTagsResponse,
             TagResponse,
             EntityTagsResponse,
-            TagErrorResponse,
             CreateTagRequest,
             UpdateTagRequest,
             ListSmartPlaylistsResponse,
             SmartPlaylistResponse,
             SmartPlaylistCriteriaRequest,
             CreateSmartPlaylistRequest,
+            UpdateSmartPlaylistRequest,
             SmartPlaylistItemsResponse,
-            SmartPlaylistErrorResponse,
             ListDuplicatesResponse,
             DuplicateGroupResponse,
             DuplicateGroupDetailResponse,
             DuplicateFileResponse,
+            DuplicateClusterResponse,
+            DuplicateClusterFileResponse,
             ResolveDuplicateRequest,
             ResolveDuplicateResponse,
-            DuplicateErrorResponse,
             ListDuplicatesQuery,
             DuplicateGroupQuery,
         )
@@ -539,14 +674,14 @@ async fn metrics() -> axum::response::Response {
         (name = "settings", description = "Configuration and profile endpoints"),
         (name = "indexers", description = "Indexer configuration and validation endpoints"),
         (name = "search", description = "Manual and interactive search endpoints"),
-        (name = "imports", description = "Import evaluation and manual decision endpoints"),
+        (name = "imports", description = "Import evaluation, manual decision and directory scan endpoints"),
         (name = "wanted", description = "Wanted and missing album tracking"),
         (name = "calendar", description = "Upcoming releases calendar"),
         (name = "tags", description = "Tag organization endpoints"),
         (name = "smart_playlists", description = "Dynamic smart playlist endpoints"),
         (name = "duplicates", description = "Duplicate file detection and management endpoints")
     ),
-    modifiers(&SecurityAddon),
+    modifiers(&SecurityAddon, &ErrorResponsesAddon),
     info(
         title = "Chorrosion API",
         version = "0.1.0",
@@ -588,6 +723,12 @@ fn build_cors_layer(origins: &[String]) -> Option<CorsLayer> {
                 header::CONTENT_TYPE,
                 header::AUTHORIZATION,
                 header::HeaderName::from_static("x-api-key"),
+            ])
+            .expose_headers([
+                RATE_LIMIT_LIMIT_HEADER.clone(),
+                RATE_LIMIT_REMAINING_HEADER.clone(),
+                RATE_LIMIT_RESET_HEADER.clone(),
+                header::RETRY_AFTER,
             ]),
     )
 }
@@ -596,29 +737,38 @@ pub fn router(state: AppState) -> Router {
     info!(target: "api", "building router");
     let web_config = state.config.web.clone();
 
-    let api_v1 = Router::new()
+    let mut api_v1 = Router::new()
         .route("/auth/api-keys", get(list_api_keys).post(create_api_key))
         .route("/auth/api-keys/:id", axum::routing::delete(delete_api_key))
         .route("/auth/forms/login", post(forms_login))
         .route("/auth/forms/logout", post(forms_logout))
         .route("/artists", get(list_artists).post(create_artist))
+        .route("/artists/export", get(export_artists))
         .route(
             "/artists/:id",
             get(get_artist).put(update_artist).delete(delete_artist),
         )
         .route("/artists/:id/statistics", get(get_artist_statistics))
+        .route("/artists/:id/monitored", put(set_artist_monitored))
+        .route("/artists/:id/merge", post(merge_artist))
         .route("/albums", get(list_albums).post(create_album))
         .route(
             "/albums/:id",
             get(get_album).put(update_album).delete(delete_album),
         )
+        .route("/albums/monitored", put(set_albums_monitored))
+        .route("/albums/:id/monitored", put(set_album_monitored))
         .route("/albums/:id/search", post(trigger_album_search))
+        .route("/albums/:id/search-status", get(get_album_search_status))
+        .route("/albums/:id/auto-search", post(auto_search_album))
         .route("/artists/:artist_id/albums", get(list_albums_by_artist))
         .route("/tracks", get(list_tracks).post(create_track))
         .route(
             "/tracks/:id",
             get(get_track).put(update_track).delete(delete_track),
         )
+        .route("/tracks/:id/monitored", put(set_track_monitored))
+        .route("/track-files/:id/rematch", post(rematch_track_file))
         .route("/albums/:album_id/tracks", get(list_tracks_by_album))
         .route("/artists/:artist_id/tracks", get(list_tracks_by_artist))
         .route("/system/status", get(get_system_status))
@@ -647,6 +797,7 @@ pub fn router(state: AppState) -> Router {
             get(stream_import_progress_events),
         )
         .route("/events/job-status", get(stream_job_status_events))
+        .route("/ws", get(websocket_handler))
         .route(
             "/settings/appearance",
             get(get_appearance_settings).put(update_appearance_settings),
@@ -673,6 +824,10 @@ pub fn router(state: AppState) -> Router {
                 .put(update_quality_profile)
                 .delete(delete_quality_profile),
         )
+        .route(
+            "/settings/quality-profiles/:id/default",
+            post(set_default_quality_profile),
+        )
         .route(
             "/settings/metadata-profiles",
             get(list_metadata_profiles).post(create_metadata_profile),
@@ -695,6 +850,10 @@ pub fn router(state: AppState) -> Router {
                 .put(update_metadata_profile)
                 .delete(delete_metadata_profile),
         )
+        .route(
+            "/settings/metadata-profiles/:id/default",
+            post(set_default_metadata_profile),
+        )
         .route(
             "/settings/download-clients",
             get(list_download_clients).post(create_download_client),
@@ -729,7 +888,10 @@ pub fn router(state: AppState) -> Router {
             get(get_indexer).put(update_indexer).delete(delete_indexer),
         )
         .route("/indexers/test", post(test_indexer_endpoint))
+        .route("/indexers/test-all", post(test_all_indexers_endpoint))
+        .route("/search", post(search_endpoint))
         .route("/search/manual", post(manual_search_endpoint))
+        .route("/search/grab", post(grab_release_endpoint))
         .route(
             "/smart-playlists",
             get(list_smart_playlists).post(create_smart_playlist),
@@ -759,6 +921,7 @@ pub fn router(state: AppState) -> Router {
         )
         .route("/imports/evaluate", post(evaluate_import_candidate))
         .route("/imports/decision", post(submit_manual_import_decision))
+        .route("/import", post(scan_import_directory))
         .route("/wanted", get(list_wanted_albums))
         .route("/wanted/missing", get(list_missing_albums))
         .route("/wanted/cutoff", get(list_cutoff_unmet_albums))
@@ -769,24 +932,43 @@ pub fn router(state: AppState) -> Router {
             state.clone(),
             response_cache_middleware,
         ))
+        .layer(axum_middleware::from_fn(etag_middleware))
         .layer(axum_middleware::from_fn_with_state(
             state.clone(),
             auth_middleware,
         ));
+    if let Some(limiter) = RateLimiter::from_config(&state.config.http) {
+        api_v1 = api_v1.layer(axum_middleware::from_fn_with_state(
+            limiter,
+            rate_limit_middleware,
+        ));
+    }
 
     let mut openapi = ApiDoc::openapi();
     openapi.info.version = APP_VERSION.to_string();
 
+    let mut metrics_route = get(metrics_handler);
+    if state.config.telemetry.metrics_require_auth {
+        metrics_route = metrics_route.route_layer(axum_middleware::from_fn_with_state(
+            state.clone(),
+            auth_middleware,
+        ));
+    }
+
     let mut app = Router::new()
         .route("/health", get(health_handler))
-        .route("/metrics", get(metrics_handler))
+        .route("/metrics", metrics_route)
         .nest(API_V1_BASE, api_v1)
         .merge(SwaggerUi::new("/docs").url("/api-doc/openapi.json", openapi))
         .route_layer(axum_middleware::from_fn_with_state(
             state.clone(),
             request_tracing_middleware,
         ))
-        .route_layer(axum_middleware::from_fn(metrics_middleware))
+        .route_layer(axum_middleware::from_fn_with_state(
+            state.metrics.clone(),
+            metrics_middleware,
+        ))
+        .layer(axum_middleware::from_fn(request_id_middleware))
         .with_state(state);
 
     if let Some(cors_layer) = build_cors_layer(&web_config.allowed_origins) {
@@ -842,6 +1024,11 @@ mod health_tests {
                     pool.clone(),
                 ),
             ),
+            Arc::new(
+                chorrosion_infrastructure::sqlite_adapters::SqliteTrackFileRepository::new(
+                    pool.clone(),
+                ),
+            ),
             chorrosion_infrastructure::ResponseCache::new(100, 60),
         )
     }
@@ -887,4 +1074,164 @@ mod health_tests {
             Some("database probe failed")
         );
     }
+
+    async fn get_metrics_status(state: AppState) -> StatusCode {
+        use tower::util::ServiceExt;
+
+        router(state)
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri("/metrics")
+                    .body(axum::body::Body::empty())
+                    .expect("request should build"),
+            )
+            .await
+            .expect("request should succeed")
+            .status()
+    }
+
+    #[tokio::test]
+    async fn metrics_endpoint_is_public_by_default() {
+        let pool = SqlitePool::connect("sqlite::memory:")
+            .await
+            .expect("in-memory sqlite");
+        let state = make_state_with_pool(pool);
+
+        assert_eq!(get_metrics_status(state).await, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn metrics_endpoint_requires_auth_when_configured() {
+        let pool = SqlitePool::connect("sqlite::memory:")
+            .await
+            .expect("in-memory sqlite");
+        let mut state = make_state_with_pool(pool);
+        state.config.telemetry.metrics_require_auth = true;
+
+        assert_eq!(get_metrics_status(state).await, StatusCode::UNAUTHORIZED);
+    }
+}
+
+#[cfg(test)]
+mod openapi_tests {
+    use super::*;
+    use serde_json::Value;
+
+    fn spec() -> Value {
+        serde_json::from_str(&ApiDoc::openapi().to_json().expect("spec should serialize"))
+            .expect("spec should be valid JSON")
+    }
+
+    fn collect_refs<'a>(value: &'a Value, refs: &mut Vec<&'a str>) {
+        match value {
+            Value::Object(map) => {
+                if let Some(Value::String(reference)) = map.get("$ref") {
+                    refs.push(reference);
+                }
+                map.values().for_each(|child| collect_refs(child, refs));
+            }
+            Value::Array(items) => items.iter().for_each(|child| collect_refs(child, refs)),
+            _ => {}
+        }
+    }
+
+    #[test]
+    fn spec_declares_openapi_3_document() {
+        let spec = spec();
+        assert!(spec["openapi"]
+            .as_str()
+            .is_some_and(|version| version.starts_with("3.")));
+        assert!(spec["info"]["title"].is_string());
+        assert!(spec["info"]["version"].is_string());
+        assert!(spec["paths"]
+            .as_object()
+            .is_some_and(|paths| !paths.is_empty()));
+    }
+
+    #[test]
+    fn spec_registers_expected_paths() {
+        let spec = spec();
+        let paths = spec["paths"].as_object().expect("paths object");
+        for path in [
+            "/health",
+            "/api/v1/artists",
+            "/api/v1/albums",
+            "/api/v1/albums/{id}",
+            "/api/v1/tracks",
+            "/api/v1/tracks/{id}",
+            "/api/v1/settings/quality-profiles",
+            "/api/v1/settings/metadata-profiles",
+            "/api/v1/search",
+            "/api/v1/search/manual",
+            "/api/v1/system/status",
+            "/api/v1/system/tasks",
+        ] {
+            assert!(paths.contains_key(path), "missing path {path}");
+        }
+    }
+
+    #[test]
+    fn spec_includes_error_component_with_field_details() {
+        let spec = spec();
+        let schema = &spec["components"]["schemas"]["ApiErrorResponse"];
+        assert_eq!(
+            schema["properties"]["details"]["items"]["$ref"],
+            "#/components/schemas/FieldErrorResponse"
+        );
+        assert!(spec["components"]["schemas"]["FieldErrorResponse"].is_object());
+        assert!(spec["components"]["responses"]["Unauthorized"].is_object());
+    }
+
+    #[test]
+    fn spec_validates_against_the_openapi_3_1_schema() {
+        let schema: Value =
+            serde_json::from_str(include_str!("../tests/fixtures/openapi-3.1-schema.json"))
+                .expect("schema fixture should be valid JSON");
+        let validator = jsonschema::validator_for(&schema).expect("schema should compile");
+        let spec = spec();
+        let errors: Vec<String> = validator
+            .iter_errors(&spec)
+            .map(|error| format!("{}: {error}", error.instance_path))
+            .collect();
+        assert!(errors.is_empty(), "invalid OpenAPI document: {errors:#?}");
+    }
+
+    #[test]
+    fn every_reference_resolves() {
+        let spec = spec();
+        let mut refs = Vec::new();
+        collect_refs(&spec, &mut refs);
+        assert!(!refs.is_empty());
+        for reference in refs {
+            let pointer = reference
+                .strip_prefix('#')
+                .unwrap_or_else(|| panic!("non-local reference {reference}"));
+            assert!(
+                spec.pointer(pointer).is_some(),
+                "dangling reference {reference}"
+            );
+        }
+    }
+
+    #[test]
+    fn every_operation_documents_an_error_response() {
+        let spec = spec();
+        for (path, item) in spec["paths"].as_object().expect("paths object") {
+            for (method, operation) in item.as_object().expect("path item") {
+                let responses = operation["responses"].as_object().expect("responses");
+                assert!(
+                    responses
+                        .keys()
+                        .any(|status| status == "default" || !status.starts_with('2')),
+                    "{method} {path} documents no error response"
+                );
+                if operation.get("security").is_none() {
+                    assert!(
+                        responses.contains_key("401"),
+                        "{method} {path} is secured but lacks a 401 response"
+                    );
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,193 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+//! Verification of bearer JWTs issued by an external identity provider.
+//!
+//! HMAC-signed tokens are checked against the configured shared secret;
+//! asymmetrically signed tokens against the keys published at the JWKS URL,
+//! which are cached and refetched when stale or when an unknown `kid` appears.
+//! The accepted algorithms come from the key, never from the token header.
+use chorrosion_application::JwksCache;
+use chorrosion_config::BearerAuthConfig;
+use jsonwebtoken::jwk::{AlgorithmParameters, EllipticCurve, Jwk, JwkSet};
+use jsonwebtoken::{decode, decode_header, Algorithm, DecodingKey, Header, Validation};
+use std::str::FromStr;
+use std::time::Duration;
+use tracing::{debug, warn};
+
+/// How long fetched JWKS documents are trusted before being refetched.
+const JWKS_TTL: Duration = Duration::from_secs(600);
+
+/// Minimum age before an unknown `kid` may trigger a refetch, so forged
+/// key IDs cannot hammer the identity provider.
+const JWKS_MIN_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+const HMAC_ALGORITHMS: [Algorithm; 3] = [Algorithm::HS256, Algorithm::HS384, Algorithm::HS512];
+
+/// Whether `token` has the three dot-separated segments of a compact JWS.
+pub(crate) fn looks_like_jwt(token: &str) -> bool {
+    token.split('.').count() == 3
+}
+
+/// Verify `token`'s signature, expiry and any configured issuer/audience.
+pub(crate) async fn validate_bearer_token(
+    config: &BearerAuthConfig,
+    http_client: &reqwest::Client,
+    jwks_cache: &JwksCache,
+    token: &str,
+) -> bool {
+    let header = match decode_header(token) {
+        Ok(header) => header,
+        Err(error) => {
+            debug!(target: "auth", %error, "malformed bearer token header");
+            return false;
+        }
+    };
+
+    let Some((key, algorithms)) = decoding_key(config, http_client, jwks_cache, &header).await
+    else {
+        return false;
+    };
+    if !algorithms.contains(&header.alg) {
+        debug!(target: "auth", alg = ?header.alg, ?algorithms, "bearer token algorithm not allowed for its key");
+        return false;
+    }
+
+    let mut validation = Validation::new(header.alg);
+    validation.algorithms = algorithms;
+    if let Some(issuer) = &config.issuer {
+        validation.set_issuer(&[issuer]);
+    }
+    match &config.audience {
+        Some(audience) => validation.set_audience(&[audience]),
+        None => validation.validate_aud = false,
+    }
+
+    match decode::<serde_json::Value>(token, &key, &validation) {
+        Ok(_) => true,
+        Err(error) => {
+            debug!(target: "auth", %error, "bearer token rejected");
+            false
+        }
+    }
+}
+
+/// The key that verifies a token with `header`, and the algorithms that key may
+/// be used with.
+async fn decoding_key(
+    config: &BearerAuthConfig,
+    http_client: &reqwest::Client,
+    jwks_cache: &JwksCache,
+    header: &Header,
+) -> Option<(DecodingKey, Vec<Algorithm>)> {
+    if HMAC_ALGORITHMS.contains(&header.alg) {
+        let secret = config.shared_secret.as_deref().filter(|s| !s.is_empty());
+        if secret.is_none() {
+            debug!(target: "auth", alg = ?header.alg, "HMAC bearer token but no shared secret configured");
+        }
+        return secret.map(|secret| {
+            (
+                DecodingKey::from_secret(secret.as_bytes()),
+                HMAC_ALGORITHMS.to_vec(),
+            )
+        });
+    }
+
+    let Some(jwks_url) = config.jwks_url.as_deref() else {
+        debug!(target: "auth", alg = ?header.alg, "asymmetric bearer token but no JWKS URL configured");
+        return None;
+    };
+    let jwk = find_jwk(http_client, jwks_cache, jwks_url, header.kid.as_deref()).await?;
+    let algorithms = jwk_algorithms(&jwk);
+    if algorithms.is_empty() {
+        warn!(target: "auth", kid = ?jwk.common.key_id, "JWKS key has no usable signing algorithm");
+        return None;
+    }
+    match DecodingKey::from_jwk(&jwk) {
+        Ok(key) => Some((key, algorithms)),
+        Err(error) => {
+            warn!(target: "auth", %error, "unusable key in JWKS document");
+            None
+        }
+    }
+}
+
+/// The signing algorithms `jwk` may verify: its published `alg` when present,
+/// otherwise those matching its key type. Symmetric JWKS keys are never accepted.
+fn jwk_algorithms(jwk: &Jwk) -> Vec<Algorithm> {
+    if let Some(key_algorithm) = jwk.common.key_algorithm {
+        return Algorithm::from_str(&key_algorithm.to_string())
+            .ok()
+            .filter(|algorithm| !HMAC_ALGORITHMS.contains(algorithm))
+            .into_iter()
+            .collect();
+    }
+    match &jwk.algorithm {
+        AlgorithmParameters::RSA(_) => vec![
+            Algorithm::RS256,
+            Algorithm::RS384,
+            Algorithm::RS512,
+            Algorithm::PS256,
+            Algorithm::PS384,
+            Algorithm::PS512,
+        ],
+        AlgorithmParameters::EllipticCurve(params) => match params.curve {
+            EllipticCurve::P256 => vec![Algorithm::ES256],
+            EllipticCurve::P384 => vec![Algorithm::ES384],
+            _ => Vec::new(),
+        },
+        AlgorithmParameters::OctetKeyPair(_) => vec![Algorithm::EdDSA],
+        AlgorithmParameters::OctetKey(_) => Vec::new(),
+    }
+}
+
+async fn find_jwk(
+    http_client: &reqwest::Client,
+    jwks_cache: &JwksCache,
+    jwks_url: &str,
+    kid: Option<&str>,
+) -> Option<Jwk> {
+    let lookup = |keys: &JwkSet| match kid {
+        Some(kid) => keys.find(kid).cloned(),
+        None if keys.keys.len() == 1 => keys.keys.first().cloned(),
+        None => None,
+    };
+
+    let refresh_after = match jwks_cache.get(jwks_url).await {
+        Some(cached) if cached.fetched_at.elapsed() < JWKS_TTL => {
+            if let Some(jwk) = lookup(&cached.keys) {
+                return Some(jwk);
+            }
+            Some(cached.fetched_at)
+        }
+        _ => None,
+    };
+    if refresh_after.is_some_and(|fetched_at| fetched_at.elapsed() < JWKS_MIN_REFRESH_INTERVAL) {
+        debug!(target: "auth", ?kid, "bearer token key not in recently fetched JWKS");
+        return None;
+    }
+
+    let keys = fetch_jwks(http_client, jwks_url).await?;
+    let jwk = lookup(&keys);
+    jwks_cache.insert(jwks_url, keys).await;
+    jwk
+}
+
+async fn fetch_jwks(http_client: &reqwest::Client, jwks_url: &str) -> Option<JwkSet> {
+    let response = http_client
+        .get(jwks_url)
+        .send()
+        .await
+        .and_then(reqwest::Response::error_for_status);
+    match response {
+        Ok(response) => match response.json::<JwkSet>().await {
+            Ok(keys) => Some(keys),
+            Err(error) => {
+                warn!(target: "auth", %jwks_url, %error, "invalid JWKS document");
+                None
+            }
+        },
+        Err(error) => {
+            warn!(target: "auth", %jwks_url, %error, "failed to fetch JWKS");
+            None
+        }
+    }
+}
@@ -0,0 +1,291 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Axum middleware that adds weak ETags to JSON GET responses.
+//!
+//! The tag is a hash of the serialized body, so polling clients that send it
+//! back in `If-None-Match` get an empty `304 Not Modified` until the data
+//! changes. Wire this outside the response cache so cache hits are tagged too:
+//!
+//! ```text
+//! Request → auth_middleware → etag_middleware → response_cache_middleware → handler
+//! ```
+
+use axum::{
+    body::{Body, HttpBody},
+    extract::Request,
+    http::{header, HeaderMap, HeaderValue, Method, StatusCode},
+    middleware::Next,
+    response::Response,
+};
+use tracing::{debug, error};
+
+/// Bodies larger than this are passed through untagged rather than buffered.
+const MAX_ETAG_BODY_BYTES: usize = 8 * 1024 * 1024;
+
+/// Headers RFC 9110 §15.4.5 requires a 304 to repeat from the 200 it replaces.
+const NOT_MODIFIED_HEADERS: [header::HeaderName; 5] = [
+    header::CACHE_CONTROL,
+    header::CONTENT_LOCATION,
+    header::DATE,
+    header::EXPIRES,
+    header::VARY,
+];
+
+/// Weak ETag for `body`, derived from a 64-bit FNV-1a hash.
+///
+/// FNV is used instead of `DefaultHasher` because its output is stable across
+/// builds, so tags issued before a restart still match afterwards.
+fn weak_etag(body: &[u8]) -> String {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    let hash = body.iter().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(PRIME)
+    });
+    format!("W/\"{hash:016x}-{:x}\"", body.len())
+}
+
+/// Whether `If-None-Match` lists `etag`, using the weak comparison RFC 9110 requires.
+fn if_none_match_matches(headers: &HeaderMap, etag: &str) -> bool {
+    let opaque = |tag: &str| tag.trim().trim_start_matches("W/").to_string();
+    let etag = opaque(etag);
+    headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|candidate| candidate.trim() == "*" || opaque(candidate) == etag)
+}
+
+/// Middleware function — register with `axum_middleware::from_fn(etag_middleware)`.
+pub async fn etag_middleware(req: Request, next: Next) -> Response {
+    if req.method() != Method::GET {
+        return next.run(req).await;
+    }
+
+    let request_headers = req.headers().clone();
+    let response = next.run(req).await;
+
+    let is_json = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/json"));
+    let fits = response
+        .body()
+        .size_hint()
+        .upper()
+        .is_some_and(|upper| upper <= MAX_ETAG_BODY_BYTES as u64);
+    if response.status() != StatusCode::OK || !is_json || !fits {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let bytes = match axum::body::to_bytes(body, MAX_ETAG_BODY_BYTES).await {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            error!(target: "api", error = %e, "failed to collect response body for ETag");
+            return Response::builder()
+                .status(StatusCode::INTERNAL_SERVER_ERROR)
+                .body(Body::empty())
+                .unwrap();
+        }
+    };
+
+    let etag = weak_etag(&bytes);
+    let Ok(etag_value) = HeaderValue::from_str(&etag) else {
+        return Response::from_parts(parts, Body::from(bytes));
+    };
+
+    if if_none_match_matches(&request_headers, &etag) {
+        debug!(target: "api", %etag, "ETag matched; returning 304");
+        let mut not_modified = Response::builder()
+            .status(StatusCode::NOT_MODIFIED)
+            .body(Body::empty())
+            .unwrap();
+        let headers = not_modified.headers_mut();
+        for name in NOT_MODIFIED_HEADERS {
+            for value in parts.headers.get_all(&name) {
+                headers.append(name.clone(), value.clone());
+            }
+        }
+        headers.insert(header::ETAG, etag_value);
+        return not_modified;
+    }
+
+    parts.headers.insert(header::ETAG, etag_value);
+    Response::from_parts(parts, Body::from(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{if_none_match_matches, weak_etag};
+    use axum::body::Body;
+    use axum::http::{header, HeaderMap, HeaderValue, Request, StatusCode};
+    use chorrosion_application::AppState;
+    use chorrosion_config::AppConfig;
+    use chorrosion_infrastructure::sqlite_adapters::{
+        SqliteAlbumRepository, SqliteArtistRepository, SqliteDownloadClientDefinitionRepository,
+        SqliteDuplicateRepository, SqliteIndexerDefinitionRepository,
+        SqliteMetadataProfileRepository, SqliteQualityProfileRepository,
+        SqliteSmartPlaylistRepository, SqliteTagRepository, SqliteTaggedEntityRepository,
+        SqliteTrackFileRepository, SqliteTrackRepository,
+    };
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    /// `user:pass`, matching the credentials configured by [`make_test_state`].
+    const BASIC_AUTH: &str = "Basic dXNlcjpwYXNz";
+
+    async fn make_test_state() -> AppState {
+        use sqlx::sqlite::SqlitePoolOptions;
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("in-memory SQLite");
+        sqlx::migrate!("../../migrations")
+            .run(&pool)
+            .await
+            .expect("migrations");
+        let mut config = AppConfig::default();
+        config.auth.basic_username = Some("user".to_string());
+        config.auth.basic_password = Some("pass".to_string());
+        AppState::new(
+            config,
+            Arc::new(SqliteArtistRepository::new(pool.clone())),
+            Arc::new(SqliteAlbumRepository::new(pool.clone())),
+            Arc::new(SqliteTrackRepository::new(pool.clone())),
+            Arc::new(SqliteQualityProfileRepository::new(pool.clone())),
+            Arc::new(SqliteMetadataProfileRepository::new(pool.clone())),
+            Arc::new(SqliteIndexerDefinitionRepository::new(pool.clone())),
+            Arc::new(SqliteDownloadClientDefinitionRepository::new(pool.clone())),
+            Arc::new(SqliteTagRepository::new(pool.clone())),
+            Arc::new(SqliteTaggedEntityRepository::new(pool.clone())),
+            Arc::new(SqliteSmartPlaylistRepository::new(pool.clone())),
+            Arc::new(SqliteDuplicateRepository::new(pool.clone())),
+            Arc::new(SqliteTrackFileRepository::new(pool.clone())),
+            chorrosion_infrastructure::ResponseCache::new(100, 60),
+        )
+    }
+
+    fn list_artists(if_none_match: Option<&str>) -> Request<Body> {
+        let mut request = Request::builder()
+            .uri("/api/v1/artists")
+            .method("GET")
+            .header(header::AUTHORIZATION, BASIC_AUTH);
+        if let Some(etag) = if_none_match {
+            request = request.header(header::IF_NONE_MATCH, etag);
+        }
+        request.body(Body::empty()).expect("request")
+    }
+
+    #[test]
+    fn etag_is_weak_and_depends_on_body() {
+        let etag = weak_etag(b"[]");
+        assert!(etag.starts_with("W/\""));
+        assert_eq!(etag, weak_etag(b"[]"));
+        assert_ne!(etag, weak_etag(b"[1]"));
+    }
+
+    #[test]
+    fn if_none_match_uses_weak_comparison_over_lists() {
+        let etag = weak_etag(b"{}");
+        let strong = etag.trim_start_matches("W/").to_string();
+        for value in [
+            etag.clone(),
+            strong,
+            format!("\"other\", {etag}"),
+            "*".to_string(),
+        ] {
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                header::IF_NONE_MATCH,
+                HeaderValue::from_str(&value).unwrap(),
+            );
+            assert!(if_none_match_matches(&headers, &etag), "{value}");
+        }
+
+        let mut headers = HeaderMap::new();
+        headers.insert(header::IF_NONE_MATCH, HeaderValue::from_static("\"other\""));
+        assert!(!if_none_match_matches(&headers, &etag));
+    }
+
+    #[tokio::test]
+    async fn not_modified_keeps_etag_and_cache_control() {
+        use axum::{middleware, routing::get, Json, Router};
+
+        let app = Router::new()
+            .route(
+                "/",
+                get(|| async {
+                    (
+                        [(header::CACHE_CONTROL, "private, max-age=30")],
+                        Json(serde_json::json!({ "ok": true })),
+                    )
+                }),
+            )
+            .layer(middleware::from_fn(super::etag_middleware));
+        let get_root = |if_none_match: Option<&str>| {
+            let mut request = Request::builder().uri("/");
+            if let Some(etag) = if_none_match {
+                request = request.header(header::IF_NONE_MATCH, etag);
+            }
+            request.body(Body::empty()).unwrap()
+        };
+
+        let first = app.clone().oneshot(get_root(None)).await.unwrap();
+        let etag = first.headers()[header::ETAG].to_str().unwrap().to_string();
+
+        let revalidated = app.oneshot(get_root(Some(&etag))).await.unwrap();
+        assert_eq!(revalidated.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(revalidated.headers()[header::ETAG], etag.as_str());
+        assert_eq!(
+            revalidated.headers()[header::CACHE_CONTROL],
+            "private, max-age=30"
+        );
+    }
+
+    #[tokio::test]
+    async fn unchanged_list_returns_304_and_changed_list_a_new_etag() {
+        let app = crate::router(make_test_state().await);
+
+        let first = app.clone().oneshot(list_artists(None)).await.unwrap();
+        assert_eq!(first.status(), StatusCode::OK);
+        let etag = first
+            .headers()
+            .get(header::ETAG)
+            .expect("GET returns an ETag")
+            .to_str()
+            .unwrap()
+            .to_string();
+
+        let revalidated = app
+            .clone()
+            .oneshot(list_artists(Some(&etag)))
+            .await
+            .unwrap();
+        assert_eq!(revalidated.status(), StatusCode::NOT_MODIFIED);
+        let body = axum::body::to_bytes(revalidated.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert!(body.is_empty());
+
+        let create = Request::builder()
+            .uri("/api/v1/artists")
+            .method("POST")
+            .header(header::AUTHORIZATION, BASIC_AUTH)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(r#"{"name":"Massive Attack"}"#))
+            .unwrap();
+        let created = app.clone().oneshot(create).await.unwrap();
+        assert_eq!(created.status(), StatusCode::CREATED);
+
+        let changed = app.oneshot(list_artists(Some(&etag))).await.unwrap();
+        assert_eq!(changed.status(), StatusCode::OK);
+        let new_etag = changed
+            .headers()
+            .get(header::ETAG)
+            .expect("changed list returns an ETag");
+        assert_ne!(new_etag.to_str().unwrap(), etag);
+    }
+}
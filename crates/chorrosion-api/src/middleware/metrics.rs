@@ -2,80 +2,30 @@
 
 use axum::{
     body::Body,
-    extract::{MatchedPath, Request},
-    http::{header, HeaderValue, Method, StatusCode},
+    extract::{MatchedPath, Request, State},
+    http::{header, HeaderValue, StatusCode},
     middleware::Next,
     response::Response,
 };
-use prometheus::{Encoder, HistogramOpts, HistogramVec, IntCounterVec, Registry, TextEncoder};
-use std::sync::{Arc, OnceLock};
+use chorrosion_application::{AppMetrics, AppState};
+use prometheus::{proto::MetricFamily, Encoder, TextEncoder};
 use std::time::Instant;
 
-struct HttpMetrics {
-    registry: Registry,
-    request_count: IntCounterVec,
-    request_duration_seconds: HistogramVec,
+fn render(metric_families: &[MetricFamily]) -> Result<String, StatusCode> {
+    let mut buffer = Vec::new();
+    TextEncoder::new()
+        .encode(metric_families, &mut buffer)
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    String::from_utf8(buffer).map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
 }
 
-impl HttpMetrics {
-    fn new() -> Self {
-        let registry = Registry::new();
-        let request_count = IntCounterVec::new(
-            prometheus::Opts::new(
-                "chorrosion_http_requests_total",
-                "Total number of HTTP requests handled by Chorrosion",
-            ),
-            &["method", "path", "status"],
-        )
-        .expect("request counter should be created");
-        let request_duration_seconds = HistogramVec::new(
-            HistogramOpts::new(
-                "chorrosion_http_request_duration_seconds",
-                "HTTP request duration in seconds for Chorrosion endpoints",
-            ),
-            &["method", "path", "status"],
-        )
-        .expect("request duration histogram should be created");
-
-        registry
-            .register(Box::new(request_count.clone()))
-            .expect("request counter should be registered");
-        registry
-            .register(Box::new(request_duration_seconds.clone()))
-            .expect("request duration histogram should be registered");
-
-        Self {
-            registry,
-            request_count,
-            request_duration_seconds,
-        }
-    }
-
-    fn observe(&self, method: &Method, path: &str, status: StatusCode, duration_seconds: f64) {
-        let labels = [method.as_str(), path, status.as_str()];
-        self.request_count.with_label_values(&labels).inc();
-        self.request_duration_seconds
-            .with_label_values(&labels)
-            .observe(duration_seconds);
-    }
-
-    fn render(&self) -> Result<String, StatusCode> {
-        let metric_families = self.registry.gather();
-        let mut buffer = Vec::new();
-        TextEncoder::new()
-            .encode(&metric_families, &mut buffer)
-            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-        String::from_utf8(buffer).map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
-    }
+pub async fn metrics_handler(State(state): State<AppState>) -> Response {
+    metrics_response(&state.metrics)
 }
 
-fn metrics() -> &'static Arc<HttpMetrics> {
-    static METRICS: OnceLock<Arc<HttpMetrics>> = OnceLock::new();
-    METRICS.get_or_init(|| Arc::new(HttpMetrics::new()))
-}
-
-pub async fn metrics_handler() -> Response {
-    match metrics().render() {
+/// Render the application's HTTP, job and pool metrics.
+pub fn metrics_response(app_metrics: &AppMetrics) -> Response {
+    match render(&app_metrics.gather()) {
         Ok(body) => Response::builder()
             .status(StatusCode::OK)
             .header(
@@ -91,7 +41,13 @@ pub async fn metrics_handler() -> Response {
     }
 }
 
-pub async fn metrics_middleware(req: Request, next: Next) -> Response {
+/// Record each request in `metrics`; register with
+/// `axum_middleware::from_fn_with_state(state.metrics.clone(), metrics_middleware)`.
+pub async fn metrics_middleware(
+    State(metrics): State<AppMetrics>,
+    req: Request,
+    next: Next,
+) -> Response {
     let method = req.method().clone();
     let path = req
         .extensions()
@@ -105,15 +61,16 @@ pub async fn metrics_middleware(req: Request, next: Next) -> Response {
     let status = response.status();
     let duration_seconds = started_at.elapsed().as_secs_f64();
 
-    metrics().observe(&method, &path, status, duration_seconds);
+    metrics.record_http_request(method.as_str(), &path, status.as_u16(), duration_seconds);
 
     response
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{metrics_handler, metrics_middleware};
+    use super::{metrics_middleware, metrics_response};
     use axum::{body::to_bytes, http::Request, routing::get, Router};
+    use chorrosion_application::AppMetrics;
     use tower::util::ServiceExt;
 
     async fn ok_handler() -> &'static str {
@@ -123,12 +80,11 @@ mod tests {
     #[tokio::test]
     async fn metrics_endpoint_returns_prometheus_text() {
         // Ensure at least one observation exists before scraping; the Prometheus
-        // text encoder only emits metric families that have at least one sample,
-        // so the test must not rely on other (potentially parallel) tests having
-        // populated the global registry first.
-        let setup_app = Router::new()
-            .route("/probe", get(ok_handler))
-            .route_layer(axum::middleware::from_fn(metrics_middleware));
+        // text encoder only emits metric families that have at least one sample.
+        let app_metrics = AppMetrics::default();
+        let setup_app = Router::new().route("/probe", get(ok_handler)).route_layer(
+            axum::middleware::from_fn_with_state(app_metrics.clone(), metrics_middleware),
+        );
         setup_app
             .oneshot(
                 Request::builder()
@@ -140,7 +96,7 @@ mod tests {
             .await
             .expect("request should succeed");
 
-        let response = metrics_handler().await;
+        let response = metrics_response(&app_metrics);
         assert_eq!(response.status(), axum::http::StatusCode::OK);
         let content_type = response
             .headers()
@@ -159,9 +115,13 @@ mod tests {
 
     #[tokio::test]
     async fn middleware_records_metrics_for_matched_route() {
+        let app_metrics = AppMetrics::default();
         let app = Router::new()
             .route("/metrics-test", get(ok_handler))
-            .route_layer(axum::middleware::from_fn(metrics_middleware));
+            .route_layer(axum::middleware::from_fn_with_state(
+                app_metrics.clone(),
+                metrics_middleware,
+            ));
 
         let response = app
             .oneshot(
@@ -176,7 +136,7 @@ mod tests {
 
         assert_eq!(response.status(), axum::http::StatusCode::OK);
 
-        let metrics_response = metrics_handler().await;
+        let metrics_response = metrics_response(&app_metrics);
         let body = to_bytes(metrics_response.into_body(), usize::MAX)
             .await
             .expect("metrics body should be readable");
@@ -196,9 +156,13 @@ mod tests {
             "item"
         }
 
+        let app_metrics = AppMetrics::default();
         let app = Router::new()
             .route("/items/:id", get(item_handler))
-            .route_layer(axum::middleware::from_fn(metrics_middleware));
+            .route_layer(axum::middleware::from_fn_with_state(
+                app_metrics.clone(),
+                metrics_middleware,
+            ));
 
         // Hit a concrete URL; the label must use the template, not the concrete value.
         let response = app
@@ -214,7 +178,7 @@ mod tests {
 
         assert_eq!(response.status(), axum::http::StatusCode::OK);
 
-        let metrics_response = metrics_handler().await;
+        let metrics_response = metrics_response(&app_metrics);
         let body = to_bytes(metrics_response.into_body(), usize::MAX)
             .await
             .expect("metrics body should be readable");
@@ -230,4 +194,35 @@ mod tests {
             "concrete path must not appear as a label:\n{text}"
         );
     }
+
+    #[tokio::test]
+    async fn metrics_include_parseable_job_and_pool_metrics() {
+        let app_metrics = AppMetrics::new()
+            .with_pool_sampler(|| chorrosion_application::PoolUsage { size: 4, idle: 1 });
+        app_metrics.record_job_execution("housekeeping", "success");
+
+        let response = metrics_response(&app_metrics);
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("metrics body should be readable");
+        let text = String::from_utf8(body.to_vec()).expect("metrics body should be utf-8");
+
+        assert!(text.contains(
+            "chorrosion_job_executions_total{job_type=\"housekeeping\",result=\"success\"} 1"
+        ));
+        assert!(text.contains("chorrosion_scheduler_active_jobs 0"));
+        assert!(text.contains("chorrosion_db_pool_connections{state=\"active\"} 3"));
+
+        // Every sample line is `name{labels} value` with a numeric value.
+        for line in text.lines().filter(|line| !line.starts_with('#')) {
+            let value = line
+                .rsplit_once(' ')
+                .map(|(_, value)| value)
+                .unwrap_or_else(|| panic!("malformed sample line: {line}"));
+            assert!(
+                value.parse::<f64>().is_ok(),
+                "non-numeric sample value in line: {line}"
+            );
+        }
+    }
 }
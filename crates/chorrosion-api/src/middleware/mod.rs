@@ -1,5 +1,9 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 pub mod auth;
+pub(crate) mod bearer;
+pub mod etag;
 pub mod metrics;
+pub mod rate_limit;
+pub mod request_id;
 pub mod response_cache;
 pub mod tracing;
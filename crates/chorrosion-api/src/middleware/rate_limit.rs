@@ -0,0 +1,282 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+//! Fixed-window rate limiting for the `/api/v1` routes.
+//!
+//! Each caller, identified by its peer address, may make
+//! `http.rate_limit_requests` requests per `http.rate_limit_window_seconds`.
+//! Every response carries the state of the caller's bucket so clients can pace
+//! themselves before they are throttled:
+//!
+//! * `X-RateLimit-Limit` — requests allowed per window,
+//! * `X-RateLimit-Remaining` — requests left in the current window,
+//! * `X-RateLimit-Reset` — seconds until the window starts over.
+//!
+//! Requests over the limit get `429 Too Many Requests` with a `Retry-After`
+//! equal to `X-RateLimit-Reset`.
+
+use crate::error::ApiErrorResponse;
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    http::{header, HeaderMap, HeaderName, HeaderValue, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use chorrosion_config::HttpConfig;
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::debug;
+
+pub static RATE_LIMIT_LIMIT_HEADER: HeaderName = HeaderName::from_static("x-ratelimit-limit");
+pub static RATE_LIMIT_REMAINING_HEADER: HeaderName =
+    HeaderName::from_static("x-ratelimit-remaining");
+pub static RATE_LIMIT_RESET_HEADER: HeaderName = HeaderName::from_static("x-ratelimit-reset");
+
+/// Expired buckets are dropped once this many callers are being tracked.
+const PRUNE_THRESHOLD: usize = 10_000;
+
+struct Bucket {
+    window_start: Instant,
+    count: u32,
+}
+
+/// The caller's bucket after counting one request against it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitStatus {
+    pub limit: u32,
+    pub remaining: u32,
+    /// Time until the current window ends.
+    pub reset: Duration,
+    /// Whether the request fit in the window.
+    pub allowed: bool,
+}
+
+impl RateLimitStatus {
+    /// `reset` rounded up to whole seconds, as sent in headers.
+    pub fn reset_seconds(&self) -> u64 {
+        self.reset.as_secs() + u64::from(self.reset.subsec_nanos() > 0)
+    }
+}
+
+/// Per-caller request counters shared by every clone.
+#[derive(Clone)]
+pub struct RateLimiter {
+    limit: u32,
+    window: Duration,
+    buckets: Arc<Mutex<HashMap<String, Bucket>>>,
+}
+
+impl RateLimiter {
+    pub fn new(limit: u32, window: Duration) -> Self {
+        Self {
+            limit,
+            window,
+            buckets: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// The limiter configured in `[http]`, or `None` when rate limiting is off.
+    pub fn from_config(config: &HttpConfig) -> Option<Self> {
+        (config.rate_limit_requests > 0).then(|| {
+            Self::new(
+                config.rate_limit_requests,
+                Duration::from_secs(config.rate_limit_window_seconds.max(1)),
+            )
+        })
+    }
+
+    /// Count a request from `caller` arriving at `now`.
+    pub fn check(&self, caller: &str, now: Instant) -> RateLimitStatus {
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        if buckets.len() >= PRUNE_THRESHOLD {
+            buckets.retain(|_, bucket| now.duration_since(bucket.window_start) < self.window);
+        }
+
+        let bucket = buckets.entry(caller.to_string()).or_insert(Bucket {
+            window_start: now,
+            count: 0,
+        });
+        if now.duration_since(bucket.window_start) >= self.window {
+            bucket.window_start = now;
+            bucket.count = 0;
+        }
+
+        let allowed = bucket.count < self.limit;
+        if allowed {
+            bucket.count += 1;
+        }
+        RateLimitStatus {
+            limit: self.limit,
+            remaining: self.limit - bucket.count,
+            reset: self.window - now.duration_since(bucket.window_start),
+            allowed,
+        }
+    }
+}
+
+fn caller_key(req: &Request) -> String {
+    match req.extensions().get::<ConnectInfo<SocketAddr>>() {
+        Some(ConnectInfo(addr)) => addr.ip().to_string(),
+        None => "unknown".to_string(),
+    }
+}
+
+fn insert_headers(headers: &mut HeaderMap, status: &RateLimitStatus) {
+    headers.insert(
+        RATE_LIMIT_LIMIT_HEADER.clone(),
+        HeaderValue::from(status.limit),
+    );
+    headers.insert(
+        RATE_LIMIT_REMAINING_HEADER.clone(),
+        HeaderValue::from(status.remaining),
+    );
+    headers.insert(
+        RATE_LIMIT_RESET_HEADER.clone(),
+        HeaderValue::from(status.reset_seconds()),
+    );
+}
+
+/// Middleware function — register with
+/// `axum_middleware::from_fn_with_state(limiter, rate_limit_middleware)`.
+pub async fn rate_limit_middleware(
+    State(limiter): State<RateLimiter>,
+    req: Request,
+    next: Next,
+) -> Response {
+    let caller = caller_key(&req);
+    let status = limiter.check(&caller, Instant::now());
+
+    let mut response = if status.allowed {
+        next.run(req).await
+    } else {
+        debug!(target: "api", %caller, limit = status.limit, reset = status.reset_seconds(), "rate limit exceeded");
+        let mut response = (
+            StatusCode::TOO_MANY_REQUESTS,
+            Json(ApiErrorResponse {
+                error: "Too many requests".to_string(),
+                details: Vec::new(),
+                correlation_id: None,
+            }),
+        )
+            .into_response();
+        response.headers_mut().insert(
+            header::RETRY_AFTER,
+            HeaderValue::from(status.reset_seconds()),
+        );
+        response
+    };
+
+    insert_headers(response.headers_mut(), &status);
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        rate_limit_middleware, RateLimiter, RATE_LIMIT_LIMIT_HEADER, RATE_LIMIT_REMAINING_HEADER,
+        RATE_LIMIT_RESET_HEADER,
+    };
+    use axum::{
+        body::Body,
+        extract::ConnectInfo,
+        http::{header, Request, StatusCode},
+        middleware as axum_middleware,
+        response::Response,
+        routing::get,
+        Router,
+    };
+    use std::net::SocketAddr;
+    use std::time::{Duration, Instant};
+    use tower::ServiceExt;
+
+    fn app(limiter: RateLimiter) -> Router {
+        Router::new()
+            .route("/ping", get(|| async { "pong" }))
+            .layer(axum_middleware::from_fn_with_state(
+                limiter,
+                rate_limit_middleware,
+            ))
+    }
+
+    async fn get_ping(app: &Router, peer: &str) -> Response {
+        let mut request = Request::builder().uri("/ping").body(Body::empty()).unwrap();
+        request
+            .extensions_mut()
+            .insert(ConnectInfo(peer.parse::<SocketAddr>().unwrap()));
+        app.clone().oneshot(request).await.unwrap()
+    }
+
+    fn header(response: &Response, name: &header::HeaderName) -> Option<String> {
+        response
+            .headers()
+            .get(name)
+            .map(|value| value.to_str().unwrap().to_string())
+    }
+
+    #[tokio::test]
+    async fn remaining_decrements_until_requests_are_rejected() {
+        let app = app(RateLimiter::new(2, Duration::from_secs(60)));
+
+        let first = get_ping(&app, "10.0.0.1:5000").await;
+        assert_eq!(first.status(), StatusCode::OK);
+        assert_eq!(
+            header(&first, &RATE_LIMIT_LIMIT_HEADER).as_deref(),
+            Some("2")
+        );
+        assert_eq!(
+            header(&first, &RATE_LIMIT_REMAINING_HEADER).as_deref(),
+            Some("1")
+        );
+        assert_eq!(
+            header(&first, &RATE_LIMIT_RESET_HEADER).as_deref(),
+            Some("60")
+        );
+
+        let second = get_ping(&app, "10.0.0.1:5001").await;
+        assert_eq!(second.status(), StatusCode::OK);
+        assert_eq!(
+            header(&second, &RATE_LIMIT_REMAINING_HEADER).as_deref(),
+            Some("0")
+        );
+
+        let third = get_ping(&app, "10.0.0.1:5002").await;
+        assert_eq!(third.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(
+            header(&third, &RATE_LIMIT_REMAINING_HEADER).as_deref(),
+            Some("0")
+        );
+        assert_eq!(
+            header(&third, &header::RETRY_AFTER),
+            header(&third, &RATE_LIMIT_RESET_HEADER)
+        );
+
+        let other_caller = get_ping(&app, "10.0.0.2:5000").await;
+        assert_eq!(other_caller.status(), StatusCode::OK);
+        assert_eq!(
+            header(&other_caller, &RATE_LIMIT_REMAINING_HEADER).as_deref(),
+            Some("1")
+        );
+    }
+
+    #[test]
+    fn bucket_resets_after_the_window() {
+        let limiter = RateLimiter::new(2, Duration::from_secs(10));
+        let start = Instant::now();
+
+        limiter.check("10.0.0.1", start);
+        let exhausted = limiter.check("10.0.0.1", start + Duration::from_millis(2500));
+        assert_eq!(exhausted.remaining, 0);
+        assert_eq!(exhausted.reset_seconds(), 8);
+        assert!(
+            !limiter
+                .check("10.0.0.1", start + Duration::from_secs(9))
+                .allowed
+        );
+
+        let fresh = limiter.check("10.0.0.1", start + Duration::from_secs(10));
+        assert!(fresh.allowed);
+        assert_eq!(fresh.remaining, 1);
+        assert_eq!(fresh.reset_seconds(), 10);
+    }
+}
@@ -0,0 +1,183 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+//! Per-request identifier propagated through logs, error bodies and events.
+//!
+//! [`request_id_middleware`] takes the caller's `X-Request-Id` when it is a
+//! reasonable header value and generates a UUID otherwise. The id is:
+//!
+//! * recorded on a `request` [`tracing`] span wrapping the rest of the stack,
+//! * stored as a [`RequestId`] request extension for handlers that extract it,
+//! * readable through [`current`] anywhere the request future runs, which is
+//!   how [`ApiError`](crate::error::ApiError) and [`correlate`] reach it,
+//! * echoed back in the response's `X-Request-Id` header.
+
+use axum::{
+    extract::Request,
+    http::{HeaderName, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+use chorrosion_domain::DomainEvent;
+use tracing::{info_span, Instrument};
+use uuid::Uuid;
+
+pub static REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+/// Caller-supplied ids longer than this are replaced rather than echoed.
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestId {
+    id: String,
+    correlation_id: Uuid,
+}
+
+impl RequestId {
+    fn generate() -> Self {
+        let correlation_id = Uuid::new_v4();
+        Self {
+            id: correlation_id.to_string(),
+            correlation_id,
+        }
+    }
+
+    /// Keep a caller's id verbatim. Ids that are not UUIDs still tag logs and
+    /// headers, but domain events get a fresh UUID since their field is typed.
+    fn from_header(value: &HeaderValue) -> Option<Self> {
+        let id = value.to_str().ok()?.trim();
+        if id.is_empty()
+            || id.len() > MAX_REQUEST_ID_LEN
+            || !id.chars().all(|c| c.is_ascii_graphic())
+        {
+            return None;
+        }
+        Some(Self {
+            id: id.to_string(),
+            correlation_id: Uuid::parse_str(id).unwrap_or_else(|_| Uuid::new_v4()),
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.id
+    }
+
+    pub fn correlation_id(&self) -> Uuid {
+        self.correlation_id
+    }
+}
+
+tokio::task_local! {
+    static CURRENT: RequestId;
+}
+
+/// The id of the request being served, if called from within one.
+pub fn current() -> Option<RequestId> {
+    CURRENT.try_with(Clone::clone).ok()
+}
+
+/// Tag `event` with the current request's id unless it already has one.
+pub fn correlate<T>(event: DomainEvent<T>) -> DomainEvent<T> {
+    match current() {
+        Some(request_id) if event.correlation_id.is_none() => {
+            event.with_correlation(request_id.correlation_id())
+        }
+        _ => event,
+    }
+}
+
+/// Middleware function — register with `axum_middleware::from_fn(request_id_middleware)`.
+pub async fn request_id_middleware(mut req: Request, next: Next) -> Response {
+    let request_id = req
+        .headers()
+        .get(&REQUEST_ID_HEADER)
+        .and_then(RequestId::from_header)
+        .unwrap_or_else(RequestId::generate);
+    req.extensions_mut().insert(request_id.clone());
+
+    let span = info_span!(target: "api", "request", request_id = %request_id.as_str());
+    let mut response = CURRENT
+        .scope(request_id.clone(), next.run(req))
+        .instrument(span)
+        .await;
+
+    if let Ok(value) = HeaderValue::from_str(request_id.as_str()) {
+        response
+            .headers_mut()
+            .insert(REQUEST_ID_HEADER.clone(), value);
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{current, request_id_middleware, RequestId, REQUEST_ID_HEADER};
+    use axum::{
+        body::Body,
+        http::{HeaderValue, Request, StatusCode},
+        middleware as axum_middleware,
+        routing::get,
+        Extension, Router,
+    };
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    fn app() -> Router {
+        Router::new()
+            .route(
+                "/echo",
+                get(|Extension(request_id): Extension<RequestId>| async move {
+                    assert_eq!(current().as_ref(), Some(&request_id));
+                    request_id.as_str().to_string()
+                }),
+            )
+            .layer(axum_middleware::from_fn(request_id_middleware))
+    }
+
+    async fn get_echo(request_id: Option<&str>) -> (StatusCode, Option<String>, String) {
+        let mut request = Request::builder().uri("/echo");
+        if let Some(id) = request_id {
+            request = request.header(&REQUEST_ID_HEADER, id);
+        }
+        let response = app()
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let header = response
+            .headers()
+            .get(&REQUEST_ID_HEADER)
+            .map(|value| value.to_str().unwrap().to_string());
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, header, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn generates_an_id_when_none_is_provided() {
+        let (status, header, body) = get_echo(None).await;
+
+        assert_eq!(status, StatusCode::OK);
+        let header = header.expect("response carries X-Request-Id");
+        assert!(Uuid::parse_str(&header).is_ok());
+        assert_eq!(body, header);
+    }
+
+    #[tokio::test]
+    async fn preserves_a_provided_id() {
+        let (_, header, body) = get_echo(Some("import-7f3a")).await;
+
+        assert_eq!(header.as_deref(), Some("import-7f3a"));
+        assert_eq!(body, "import-7f3a");
+    }
+
+    #[test]
+    fn uuid_ids_become_the_event_correlation_id() {
+        let id = Uuid::new_v4();
+        let request_id =
+            RequestId::from_header(&HeaderValue::from_str(&id.to_string()).unwrap()).unwrap();
+        assert_eq!(request_id.correlation_id(), id);
+
+        assert!(RequestId::from_header(&HeaderValue::from_static("has space")).is_none());
+        assert!(RequestId::from_header(&HeaderValue::from_static("")).is_none());
+    }
+}
@@ -0,0 +1,235 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+//! On-disk cleanup of an album's audio files.
+//!
+//! Deleting an album only removes database rows. [`delete_album_files`] is run
+//! first when the caller also wants the files gone; it never touches a path
+//! that does not resolve to somewhere inside the library root, and callers
+//! should keep the album when any file could not be removed.
+
+use std::path::Path;
+
+use anyhow::Result;
+use chorrosion_domain::{ensure_within_root, AlbumId};
+use chorrosion_infrastructure::collect_all;
+use chorrosion_infrastructure::repositories::{TrackFileRepository, TrackRepository};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+
+const PAGE_SIZE: i64 = 500;
+
+/// A file that could not be removed, with the reason.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FailedFileDeletion {
+    pub path: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlbumFileDeletionSummary {
+    pub files_deleted: usize,
+    pub failed: Vec<FailedFileDeletion>,
+}
+
+/// Delete every track file of `album_id` from disk.
+///
+/// Paths are canonicalized and must stay under `library_root`, so symlinks and
+/// `..` components cannot reach outside it. When `library_root` is `None` no
+/// file is deleted and each one is reported as failed. Missing files are
+/// reported as failed too. The track-file row of each removed file is deleted
+/// so a retry only sees the files that are still on disk; rows of failed files
+/// are kept.
+pub async fn delete_album_files<TR, TFR>(
+    track_repo: &TR,
+    track_file_repo: &TFR,
+    album_id: AlbumId,
+    library_root: Option<&Path>,
+) -> Result<AlbumFileDeletionSummary>
+where
+    TR: TrackRepository + ?Sized,
+    TFR: TrackFileRepository + ?Sized,
+{
+    let tracks = collect_all(PAGE_SIZE, |limit, offset| {
+        track_repo.get_by_album(album_id, limit, offset)
+    })
+    .await?;
+    let mut files = Vec::new();
+    for track in &tracks {
+        files.extend(
+            collect_all(PAGE_SIZE, |limit, offset| {
+                track_file_repo.get_by_track(track.id, limit, offset)
+            })
+            .await?,
+        );
+    }
+
+    let mut summary = AlbumFileDeletionSummary::default();
+    let root = match library_root.map(Path::canonicalize) {
+        Some(Ok(root)) => Some(root),
+        Some(Err(error)) => {
+            warn!(target: "application", %album_id, %error, "library root is not accessible");
+            None
+        }
+        None => None,
+    };
+
+    for file in files {
+        let path = file.path;
+        let outcome = match &root {
+            None => Err("artist has no accessible library path".to_string()),
+            Some(root) => remove_within_root(Path::new(&path), root),
+        };
+        match outcome {
+            Ok(()) => {
+                debug!(target: "application", %album_id, %path, "deleted track file");
+                track_file_repo.delete(&file.id.to_string()).await?;
+                summary.files_deleted += 1;
+            }
+            Err(reason) => {
+                warn!(target: "application", %album_id, %path, %reason, "could not delete track file");
+                summary.failed.push(FailedFileDeletion { path, reason });
+            }
+        }
+    }
+
+    Ok(summary)
+}
+
+fn remove_within_root(path: &Path, root: &Path) -> std::result::Result<(), String> {
+    let resolved = ensure_within_root(root, path).map_err(|error| error.to_string())?;
+    if !resolved.exists() {
+        return Err("file does not exist".to_string());
+    }
+    if !resolved.is_file() {
+        return Err("path is not a file".to_string());
+    }
+    std::fs::remove_file(&resolved).map_err(|error| error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::delete_album_files;
+    use chorrosion_config::AppConfig;
+    use chorrosion_domain::{Album, Artist, Track, TrackFile};
+    use chorrosion_infrastructure::init_database;
+    use chorrosion_infrastructure::repositories::Repository;
+    use chorrosion_infrastructure::sqlite_adapters::{
+        SqliteAlbumRepository, SqliteArtistRepository, SqliteTrackFileRepository,
+        SqliteTrackRepository,
+    };
+    use std::path::Path;
+
+    struct Fixture {
+        tracks: SqliteTrackRepository,
+        files: SqliteTrackFileRepository,
+        album: Album,
+    }
+
+    async fn fixture(paths: &[&Path]) -> Fixture {
+        let mut config = AppConfig::default();
+        config.database.url = "sqlite::memory:".to_string();
+        config.database.pool_max_size = 1;
+        let pool = init_database(&config).await.expect("in-memory database");
+        let artist = SqliteArtistRepository::new(pool.clone())
+            .create(Artist::new("Portishead"))
+            .await
+            .unwrap();
+        let album = SqliteAlbumRepository::new(pool.clone())
+            .create(Album::new(artist.id, "Dummy"))
+            .await
+            .unwrap();
+        let tracks = SqliteTrackRepository::new(pool.clone());
+        let files = SqliteTrackFileRepository::new(pool);
+        for (index, path) in paths.iter().enumerate() {
+            let track = tracks
+                .create(Track::new(album.id, artist.id, format!("Track {index}")))
+                .await
+                .unwrap();
+            files
+                .create(TrackFile::new(track.id, path.to_string_lossy(), 4))
+                .await
+                .unwrap();
+        }
+        Fixture {
+            tracks,
+            files,
+            album,
+        }
+    }
+
+    #[tokio::test]
+    async fn deletes_files_inside_the_library_root() {
+        let library = tempfile::tempdir().unwrap();
+        let first = library.path().join("01.flac");
+        let second = library.path().join("02.flac");
+        std::fs::write(&first, b"flac").unwrap();
+        std::fs::write(&second, b"flac").unwrap();
+        let fixture = fixture(&[&first, &second]).await;
+
+        let summary = delete_album_files(
+            &fixture.tracks,
+            &fixture.files,
+            fixture.album.id,
+            Some(library.path()),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(summary.files_deleted, 2);
+        assert!(summary.failed.is_empty());
+        assert!(!first.exists());
+        assert!(!second.exists());
+        assert!(fixture.files.list(10, 0).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn refuses_files_outside_the_library_root() {
+        let library = tempfile::tempdir().unwrap();
+        let elsewhere = tempfile::tempdir().unwrap();
+        let outside = elsewhere.path().join("keep.flac");
+        std::fs::write(&outside, b"flac").unwrap();
+        // Both temp dirs share a parent, so this climbs out of the library.
+        let traversal = library
+            .path()
+            .join("..")
+            .join(elsewhere.path().file_name().unwrap())
+            .join("keep.flac");
+        let fixture = fixture(&[&traversal]).await;
+
+        let summary = delete_album_files(
+            &fixture.tracks,
+            &fixture.files,
+            fixture.album.id,
+            Some(library.path()),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(summary.files_deleted, 0);
+        assert_eq!(summary.failed.len(), 1);
+        assert!(
+            summary.failed[0]
+                .reason
+                .contains("outside the library root"),
+            "{}",
+            summary.failed[0].reason
+        );
+        assert!(outside.exists());
+    }
+
+    #[tokio::test]
+    async fn without_a_library_root_nothing_is_deleted() {
+        let library = tempfile::tempdir().unwrap();
+        let file = library.path().join("01.flac");
+        std::fs::write(&file, b"flac").unwrap();
+        let fixture = fixture(&[&file]).await;
+
+        let summary = delete_album_files(&fixture.tracks, &fixture.files, fixture.album.id, None)
+            .await
+            .unwrap();
+
+        assert_eq!(summary.files_deleted, 0);
+        assert_eq!(summary.failed.len(), 1);
+        assert!(file.exists());
+        assert_eq!(fixture.files.list(10, 0).await.unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,193 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Technical stream properties read from audio file headers.
+//!
+//! [`probe_audio`] reports the codec, bitrate, sample rate, channel count and
+//! duration of a file so imports can populate the technical fields of a
+//! `TrackFile`. Codec names are lowercase and match the strings understood by
+//! `resolve_quality_from_codec_bitrate` (e.g. `flac`, `mp3`, `aac`, `alac`).
+
+use lofty::config::ParseOptions;
+use lofty::error::{ErrorKind, LoftyError};
+use lofty::file::{AudioFile, FileType, TaggedFileExt};
+use lofty::mp4::{Mp4Codec, Mp4File};
+use lofty::probe::Probe;
+use std::path::Path;
+use thiserror::Error;
+
+/// Errors that can occur while probing an audio file.
+#[derive(Debug, Error)]
+pub enum AudioProbeError {
+    #[error("File not found: {0}")]
+    FileNotFound(String),
+
+    #[error("Unsupported audio container: {0}")]
+    UnsupportedFormat(String),
+
+    #[error("Failed to read audio properties: {0}")]
+    ProbeFailed(String),
+}
+
+/// Result type used by the audio probe.
+pub type AudioProbeResult<T> = Result<T, AudioProbeError>;
+
+/// Technical properties of an audio stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AudioProperties {
+    /// Lowercase codec name (`flac`, `mp3`, `aac`, `alac`, `opus`, ...)
+    pub codec: String,
+    /// Audio bitrate in kbps; an average for variable-bitrate streams
+    pub bitrate_kbps: Option<u32>,
+    /// Sample rate in Hz
+    pub sample_rate_hz: Option<u32>,
+    /// Number of channels
+    pub channels: Option<u8>,
+    /// Stream duration in milliseconds
+    pub duration_ms: Option<u32>,
+}
+
+/// Read codec, bitrate, sample rate, channel count and duration from the
+/// headers of the file at `path`.
+///
+/// Tags are not parsed. When the container does not report a bitrate, an
+/// average is derived from the file size and duration.
+pub async fn probe_audio(path: impl AsRef<Path>) -> AudioProbeResult<AudioProperties> {
+    let path = path.as_ref().to_path_buf();
+    tokio::task::spawn_blocking(move || probe_audio_blocking(&path))
+        .await
+        .map_err(|e| AudioProbeError::ProbeFailed(format!("probe task failed: {}", e)))?
+}
+
+fn probe_audio_blocking(path: &Path) -> AudioProbeResult<AudioProperties> {
+    if !path.exists() {
+        return Err(AudioProbeError::FileNotFound(path.display().to_string()));
+    }
+
+    let options = ParseOptions::new().read_tags(false);
+    let tagged_file = Probe::open(path)
+        .and_then(|probe| probe.options(options).guess_file_type().map_err(Into::into))
+        .and_then(|probe| probe.read())
+        .map_err(|e| probe_error(path, e))?;
+
+    let file_type = tagged_file.file_type();
+    let codec = match file_type {
+        FileType::Mp4 => mp4_codec(path, options)?,
+        other => codec_name(other).to_string(),
+    };
+
+    let properties = tagged_file.properties();
+    let duration_ms = u32::try_from(properties.duration().as_millis()).ok();
+    let bitrate_kbps = properties
+        .audio_bitrate()
+        .filter(|&kbps| kbps > 0)
+        .or_else(|| average_bitrate_kbps(path, duration_ms));
+
+    Ok(AudioProperties {
+        codec,
+        bitrate_kbps,
+        sample_rate_hz: properties.sample_rate().filter(|&hz| hz > 0),
+        channels: properties.channels().filter(|&ch| ch > 0),
+        duration_ms: duration_ms.filter(|&ms| ms > 0),
+    })
+}
+
+/// MP4 files may carry AAC, ALAC, MP3 or FLAC, so the codec comes from the
+/// sample description rather than the container.
+fn mp4_codec(path: &Path, options: ParseOptions) -> AudioProbeResult<String> {
+    let mut file = std::fs::File::open(path)
+        .map_err(|e| AudioProbeError::ProbeFailed(format!("{}: {}", path.display(), e)))?;
+    let mp4 = Mp4File::read_from(&mut file, options).map_err(|e| probe_error(path, e))?;
+    let codec = match mp4.properties().codec() {
+        Mp4Codec::AAC => "aac",
+        Mp4Codec::ALAC => "alac",
+        Mp4Codec::MP3 => "mp3",
+        Mp4Codec::FLAC => "flac",
+        _ => "mp4",
+    };
+    Ok(codec.to_string())
+}
+
+fn codec_name(file_type: FileType) -> &'static str {
+    match file_type {
+        FileType::Aac => "aac",
+        FileType::Aiff => "aiff",
+        FileType::Ape => "ape",
+        FileType::Flac => "flac",
+        FileType::Mpeg => "mp3",
+        FileType::Mp4 => "mp4",
+        FileType::Mpc => "mpc",
+        FileType::Opus => "opus",
+        FileType::Vorbis => "vorbis",
+        FileType::Speex => "speex",
+        FileType::Wav => "wav",
+        FileType::WavPack => "wavpack",
+        FileType::Custom(name) => name,
+        _ => "unknown",
+    }
+}
+
+/// Average bitrate from the file size, used when the headers carry none.
+fn average_bitrate_kbps(path: &Path, duration_ms: Option<u32>) -> Option<u32> {
+    let duration_ms = u64::from(duration_ms.filter(|&ms| ms > 0)?);
+    let size_bytes = std::fs::metadata(path).ok()?.len();
+    u32::try_from(size_bytes * 8 / duration_ms).ok()
+}
+
+fn probe_error(path: &Path, error: LoftyError) -> AudioProbeError {
+    match error.kind() {
+        ErrorKind::UnknownFormat => AudioProbeError::UnsupportedFormat(path.display().to_string()),
+        _ => AudioProbeError::ProbeFailed(format!("{}: {}", path.display(), error)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{MINIMAL_FLAC, MINIMAL_MP3};
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn probe_audio_reads_flac_stream_info() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("track.flac");
+        std::fs::write(&path, MINIMAL_FLAC).unwrap();
+
+        let props = probe_audio(&path).await.unwrap();
+
+        assert_eq!(props.codec, "flac");
+        assert_eq!(props.channels, Some(1));
+        assert_eq!(props.sample_rate_hz, Some(44_100));
+        assert_eq!(props.duration_ms, None);
+    }
+
+    #[tokio::test]
+    async fn probe_audio_reads_mp3_frame_header() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("track.mp3");
+        std::fs::write(&path, MINIMAL_MP3).unwrap();
+
+        let props = probe_audio(&path).await.unwrap();
+
+        assert_eq!(props.codec, "mp3");
+        assert_eq!(props.channels, Some(2));
+        assert_eq!(props.sample_rate_hz, Some(44_100));
+        assert_eq!(props.duration_ms, Some(52));
+        assert_eq!(props.bitrate_kbps, Some(32));
+    }
+
+    #[tokio::test]
+    async fn probe_audio_missing_file_returns_not_found() {
+        let err = probe_audio("/nonexistent/track.flac").await.unwrap_err();
+        assert!(matches!(err, AudioProbeError::FileNotFound(_)));
+    }
+
+    #[tokio::test]
+    async fn probe_audio_non_audio_file_returns_unsupported_format() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("notes.txt");
+        std::fs::write(&path, b"not audio at all").unwrap();
+
+        let err = probe_audio(&path).await.unwrap_err();
+        assert!(matches!(err, AudioProbeError::UnsupportedFormat(_)));
+    }
+}
@@ -0,0 +1,134 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+//! Delay profiles gating how soon a freshly published release may be grabbed.
+//!
+//! Fresh releases are often mislabeled or incomplete, so automatic search waits
+//! until a release has been public for a protocol-specific minimum age. Usenet
+//! and torrent delays are configured separately, which lets Usenet releases be
+//! preferred while torrents are still held back.
+//!
+//! Ages are measured from [`IndexerSearchResult::published_at`], which indexers
+//! normalize to RFC 3339. Releases without a parseable publish date are never
+//! deferred.
+//!
+//! [`IndexerSearchResult::published_at`]: crate::indexers::IndexerSearchResult::published_at
+
+use chorrosion_config::SearchConfig;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::indexers::IndexerProtocol;
+
+/// Minimum release age, per protocol, before a release may be grabbed automatically.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DelayProfile {
+    /// Minutes to wait after publication before grabbing a Usenet (Newznab) release.
+    #[serde(default)]
+    pub usenet_delay_minutes: u32,
+    /// Minutes to wait after publication before grabbing a torrent (Torznab, Gazelle) release.
+    #[serde(default)]
+    pub torrent_delay_minutes: u32,
+}
+
+impl From<&SearchConfig> for DelayProfile {
+    fn from(config: &SearchConfig) -> Self {
+        Self {
+            usenet_delay_minutes: config.usenet_delay_minutes,
+            torrent_delay_minutes: config.torrent_delay_minutes,
+        }
+    }
+}
+
+impl DelayProfile {
+    /// The minimum age for releases from an indexer speaking `protocol`.
+    ///
+    /// Custom indexers may serve either protocol, so they get the longer of the two delays.
+    pub fn delay_for(&self, protocol: &IndexerProtocol) -> Duration {
+        let minutes = match protocol {
+            IndexerProtocol::Newznab => self.usenet_delay_minutes,
+            IndexerProtocol::Torznab | IndexerProtocol::Gazelle => self.torrent_delay_minutes,
+            IndexerProtocol::Custom => self.usenet_delay_minutes.max(self.torrent_delay_minutes),
+        };
+        Duration::minutes(i64::from(minutes))
+    }
+
+    /// When a release published at `published_at` becomes eligible, if that is after `now`.
+    ///
+    /// Returns `None` when the release may be grabbed immediately, including when
+    /// `published_at` is missing or not valid RFC 3339.
+    pub fn deferred_until(
+        &self,
+        protocol: &IndexerProtocol,
+        published_at: Option<&str>,
+        now: DateTime<Utc>,
+    ) -> Option<DateTime<Utc>> {
+        let published_at = DateTime::parse_from_rfc3339(published_at?).ok()?;
+        let available_at = published_at.with_timezone(&Utc) + self.delay_for(protocol);
+        (available_at > now).then_some(available_at)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DelayProfile;
+    use crate::indexers::IndexerProtocol;
+    use chrono::{Duration, Utc};
+
+    fn profile() -> DelayProfile {
+        DelayProfile {
+            usenet_delay_minutes: 0,
+            torrent_delay_minutes: 60,
+        }
+    }
+
+    #[test]
+    fn delay_depends_on_protocol() {
+        let profile = profile();
+        assert_eq!(
+            profile.delay_for(&IndexerProtocol::Newznab),
+            Duration::zero()
+        );
+        assert_eq!(
+            profile.delay_for(&IndexerProtocol::Torznab),
+            Duration::minutes(60)
+        );
+        assert_eq!(
+            profile.delay_for(&IndexerProtocol::Gazelle),
+            Duration::minutes(60)
+        );
+        assert_eq!(
+            profile.delay_for(&IndexerProtocol::Custom),
+            Duration::minutes(60)
+        );
+    }
+
+    #[test]
+    fn defers_only_releases_younger_than_the_delay() {
+        let profile = profile();
+        let now = Utc::now();
+        let fresh = (now - Duration::minutes(10)).to_rfc3339();
+        let old = (now - Duration::minutes(90)).to_rfc3339();
+
+        let until = profile
+            .deferred_until(&IndexerProtocol::Torznab, Some(&fresh), now)
+            .expect("fresh torrent should be deferred");
+        assert_eq!(until.timestamp(), (now + Duration::minutes(50)).timestamp());
+        assert!(profile
+            .deferred_until(&IndexerProtocol::Torznab, Some(&old), now)
+            .is_none());
+        assert!(profile
+            .deferred_until(&IndexerProtocol::Newznab, Some(&fresh), now)
+            .is_none());
+    }
+
+    #[test]
+    fn missing_or_unparseable_dates_are_not_deferred() {
+        let profile = profile();
+        let now = Utc::now();
+        assert!(profile
+            .deferred_until(&IndexerProtocol::Torznab, None, now)
+            .is_none());
+        assert!(profile
+            .deferred_until(&IndexerProtocol::Torznab, Some("yesterday-ish"), now)
+            .is_none());
+    }
+}
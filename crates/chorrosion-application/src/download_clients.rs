@@ -1,5 +1,7 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
+use crate::http_client::OutboundHttp;
 use async_trait::async_trait;
+use chorrosion_domain::DownloadClientDefinition;
 use reqwest::{Client, Url};
 use serde::{de::DeserializeOwned, Deserialize};
 use serde_json::{json, Value};
@@ -57,13 +59,9 @@ pub trait DownloadClient: Send + Sync {
     async fn prioritize_download(&self, hash: &str) -> Result<(), DownloadClientError>;
 }
 
-fn build_download_client_http_client() -> Client {
-    Client::builder()
-        .user_agent(concat!(
-            "chorrosion/",
-            env!("CARGO_PKG_VERSION"),
-            " (+https://github.com/SvetaKrava/chorrosion)"
-        ))
+fn build_download_client_http_client(outbound: &OutboundHttp) -> Client {
+    outbound
+        .client_builder()
         .timeout(Duration::from_secs(30))
         .cookie_store(true)
         .build()
@@ -76,6 +74,42 @@ fn build_download_client_http_client() -> Client {
         })
 }
 
+/// Build a client for a download client definition, sending its requests through
+/// `outbound`'s proxy. Returns `None` for unsupported client types.
+pub fn download_client_for(
+    definition: &DownloadClientDefinition,
+    outbound: &OutboundHttp,
+) -> Option<Box<dyn DownloadClient>> {
+    let base_url = definition.base_url.clone();
+    let username = definition.username.clone();
+    let password = definition.password_encrypted.clone();
+    let client: Box<dyn DownloadClient> =
+        match definition.client_type.trim().to_lowercase().as_str() {
+            "qbittorrent" => Box::new(
+                QBittorrentClient::new(base_url, username, password)
+                    .with_http_client(build_download_client_http_client(outbound)),
+            ),
+            "transmission" => Box::new(
+                TransmissionClient::new(base_url, username, password)
+                    .with_http_client(build_download_client_http_client(outbound)),
+            ),
+            "deluge" => Box::new(
+                DelugeClient::new(base_url, password)
+                    .with_http_client(build_download_client_http_client(outbound)),
+            ),
+            "sabnzbd" => Box::new(
+                SabnzbdClient::new(base_url, password)
+                    .with_http_client(build_download_client_http_client(outbound)),
+            ),
+            "nzbget" => Box::new(
+                NzbgetClient::new(base_url, username, password)
+                    .with_http_client(build_download_client_http_client(outbound)),
+            ),
+            _ => return None,
+        };
+    Some(client)
+}
+
 pub struct QBittorrentClient {
     client: Client,
     base_url: String,
@@ -112,7 +146,7 @@ pub struct NzbgetClient {
 
 impl DelugeClient {
     pub fn new(base_url: String, password: Option<String>) -> Self {
-        let client = build_download_client_http_client();
+        let client = build_download_client_http_client(&OutboundHttp::default());
         let base_url = base_url.trim_end_matches('/').to_string();
         debug!(target: "download_clients", %base_url, "Initialized DelugeClient");
         Self {
@@ -122,6 +156,12 @@ impl DelugeClient {
         }
     }
 
+    /// Sends requests through `client` instead of one built without the configured proxy.
+    pub fn with_http_client(mut self, client: Client) -> Self {
+        self.client = client;
+        self
+    }
+
     fn endpoint(&self) -> Result<Url, DownloadClientError> {
         let mut base = Url::parse(&self.base_url)
             .map_err(|err| DownloadClientError::InvalidBaseUrl(err.to_string()))?;
@@ -193,7 +233,7 @@ impl DelugeClient {
 
 impl SabnzbdClient {
     pub fn new(base_url: String, api_key: Option<String>) -> Self {
-        let client = build_download_client_http_client();
+        let client = build_download_client_http_client(&OutboundHttp::default());
         let base_url = base_url.trim_end_matches('/').to_string();
         debug!(target: "download_clients", %base_url, "Initialized SabnzbdClient");
         Self {
@@ -203,6 +243,12 @@ impl SabnzbdClient {
         }
     }
 
+    /// Sends requests through `client` instead of one built without the configured proxy.
+    pub fn with_http_client(mut self, client: Client) -> Self {
+        self.client = client;
+        self
+    }
+
     fn endpoint(&self) -> Result<Url, DownloadClientError> {
         let mut base = Url::parse(&self.base_url)
             .map_err(|err| DownloadClientError::InvalidBaseUrl(err.to_string()))?;
@@ -248,7 +294,7 @@ impl SabnzbdClient {
 
 impl NzbgetClient {
     pub fn new(base_url: String, username: Option<String>, password: Option<String>) -> Self {
-        let client = build_download_client_http_client();
+        let client = build_download_client_http_client(&OutboundHttp::default());
         let base_url = base_url.trim_end_matches('/').to_string();
         debug!(target: "download_clients", %base_url, "Initialized NzbgetClient");
         Self {
@@ -259,6 +305,12 @@ impl NzbgetClient {
         }
     }
 
+    /// Sends requests through `client` instead of one built without the configured proxy.
+    pub fn with_http_client(mut self, client: Client) -> Self {
+        self.client = client;
+        self
+    }
+
     fn endpoint(&self) -> Result<Url, DownloadClientError> {
         let mut base = Url::parse(&self.base_url)
             .map_err(|err| DownloadClientError::InvalidBaseUrl(err.to_string()))?;
@@ -321,7 +373,7 @@ impl NzbgetClient {
 
 impl TransmissionClient {
     pub fn new(base_url: String, username: Option<String>, password: Option<String>) -> Self {
-        let client = build_download_client_http_client();
+        let client = build_download_client_http_client(&OutboundHttp::default());
         let base_url = base_url.trim_end_matches('/').to_string();
         debug!(target: "download_clients", %base_url, "Initialized TransmissionClient");
         Self {
@@ -333,6 +385,12 @@ impl TransmissionClient {
         }
     }
 
+    /// Sends requests through `client` instead of one built without the configured proxy.
+    pub fn with_http_client(mut self, client: Client) -> Self {
+        self.client = client;
+        self
+    }
+
     fn endpoint(&self) -> Result<Url, DownloadClientError> {
         let base = Url::parse(&self.base_url)
             .map_err(|err| DownloadClientError::InvalidBaseUrl(err.to_string()))?;
@@ -415,7 +473,7 @@ impl TransmissionClient {
 
 impl QBittorrentClient {
     pub fn new(base_url: String, username: Option<String>, password: Option<String>) -> Self {
-        let client = build_download_client_http_client();
+        let client = build_download_client_http_client(&OutboundHttp::default());
         let base_url = base_url.trim_end_matches('/').to_string();
         debug!(target: "download_clients", %base_url, "Initialized QBittorrentClient");
         Self {
@@ -426,6 +484,12 @@ impl QBittorrentClient {
         }
     }
 
+    /// Sends requests through `client` instead of one built without the configured proxy.
+    pub fn with_http_client(mut self, client: Client) -> Self {
+        self.client = client;
+        self
+    }
+
     fn endpoint(&self, path: &str) -> Result<Url, DownloadClientError> {
         let base = Url::parse(&self.base_url)
             .map_err(|err| DownloadClientError::InvalidBaseUrl(err.to_string()))?;
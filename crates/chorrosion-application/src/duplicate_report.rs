@@ -0,0 +1,193 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+//! Likely-duplicate files across the library.
+//!
+//! [`DuplicateReportService`] groups track files that share a Chromaprint
+//! fingerprint into clusters and annotates every file with the quality parsed
+//! from its filename, best first, so the user can decide which copy to keep.
+
+use crate::release_parsing::{parse_release_title, AudioQuality};
+use anyhow::Result;
+use chorrosion_domain::DuplicateFileDetail;
+use chorrosion_infrastructure::repositories::DuplicateRepository;
+use serde::Serialize;
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::Arc;
+use tracing::debug;
+
+/// One file of a [`DuplicateCluster`] with the quality its filename suggests.
+#[derive(Debug, Clone, Serialize)]
+pub struct DuplicateClusterFile {
+    pub file: DuplicateFileDetail,
+    pub parsed_quality: AudioQuality,
+    /// Bitrate from the filename, falling back to the scanned bitrate.
+    pub parsed_bitrate_kbps: Option<u32>,
+}
+
+impl DuplicateClusterFile {
+    /// Annotate `file` with the quality parsed from its filename.
+    pub fn from_detail(file: DuplicateFileDetail) -> Self {
+        let file_name = Path::new(&file.path)
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| file.path.clone());
+        let parsed = parse_release_title(&file_name);
+        Self {
+            parsed_quality: parsed.quality,
+            parsed_bitrate_kbps: parsed.bitrate_kbps.or(file.bitrate_kbps),
+            file,
+        }
+    }
+
+    /// Sort key where greater means the better copy to keep.
+    fn keep_rank(&self) -> (u8, u32, u64) {
+        let quality = match self.parsed_quality {
+            AudioQuality::FlacHiRes | AudioQuality::Dsd | AudioQuality::Sacd => 3,
+            AudioQuality::Flac | AudioQuality::Alac => 2,
+            AudioQuality::Mp3 | AudioQuality::Aac => 1,
+            AudioQuality::Unknown => 0,
+        };
+        (
+            quality,
+            self.parsed_bitrate_kbps.unwrap_or(0),
+            self.file.size_bytes,
+        )
+    }
+}
+
+/// Two or more files at different paths sharing one fingerprint, best first.
+#[derive(Debug, Clone, Serialize)]
+pub struct DuplicateCluster {
+    pub fingerprint_hash: String,
+    pub files: Vec<DuplicateClusterFile>,
+}
+
+/// Builds fingerprint duplicate clusters from the duplicate repository.
+pub struct DuplicateReportService {
+    repository: Arc<dyn DuplicateRepository>,
+}
+
+impl DuplicateReportService {
+    pub fn new(repository: Arc<dyn DuplicateRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Clusters for a page of fingerprint duplicate groups, in the repository's
+    /// group order. Groups that no longer span two distinct paths are dropped.
+    pub async fn fingerprint_clusters(
+        &self,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<DuplicateCluster>> {
+        let groups = self
+            .repository
+            .find_fingerprint_duplicate_groups(limit, offset)
+            .await?;
+
+        let keys: Vec<String> = groups.iter().map(|group| group.key.clone()).collect();
+        let mut files_by_fingerprint = self.repository.get_files_by_fingerprints(&keys).await?;
+
+        let mut clusters = Vec::with_capacity(groups.len());
+        for group in groups {
+            let files = files_by_fingerprint.remove(&group.key).unwrap_or_default();
+            let paths: HashSet<&str> = files.iter().map(|file| file.path.as_str()).collect();
+            if paths.len() < 2 {
+                debug!(target: "application", fingerprint = %group.key, "skipping group without distinct paths");
+                continue;
+            }
+
+            let mut files: Vec<_> = files
+                .into_iter()
+                .map(DuplicateClusterFile::from_detail)
+                .collect();
+            files.sort_by_key(|file| Reverse(file.keep_rank()));
+            clusters.push(DuplicateCluster {
+                fingerprint_hash: group.key,
+                files,
+            });
+        }
+        Ok(clusters)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DuplicateReportService;
+    use crate::release_parsing::AudioQuality;
+    use chorrosion_config::AppConfig;
+    use chorrosion_domain::{Album, Artist, Track, TrackFile};
+    use chorrosion_infrastructure::init_database;
+    use chorrosion_infrastructure::repositories::Repository;
+    use chorrosion_infrastructure::sqlite_adapters::{
+        SqliteAlbumRepository, SqliteArtistRepository, SqliteDuplicateRepository,
+        SqliteTrackFileRepository, SqliteTrackRepository,
+    };
+    use std::sync::Arc;
+
+    /// One track file per `(path, fingerprint)` pair.
+    async fn service(files: &[(&str, &str)]) -> DuplicateReportService {
+        let mut config = AppConfig::default();
+        config.database.url = "sqlite::memory:".to_string();
+        config.database.pool_max_size = 1;
+        let pool = init_database(&config).await.expect("in-memory database");
+        let artist = SqliteArtistRepository::new(pool.clone())
+            .create(Artist::new("Radiohead"))
+            .await
+            .unwrap();
+        let album = SqliteAlbumRepository::new(pool.clone())
+            .create(Album::new(artist.id, "In Rainbows"))
+            .await
+            .unwrap();
+        let tracks = SqliteTrackRepository::new(pool.clone());
+        let track_files = SqliteTrackFileRepository::new(pool.clone());
+        for (path, fingerprint) in files {
+            let track = tracks
+                .create(Track::new(album.id, artist.id, *path))
+                .await
+                .unwrap();
+            let mut file = TrackFile::new(track.id, *path, 1024);
+            file.fingerprint_hash = Some(fingerprint.to_string());
+            track_files.create(file).await.unwrap();
+        }
+        DuplicateReportService::new(Arc::new(SqliteDuplicateRepository::new(pool)))
+    }
+
+    #[tokio::test]
+    async fn reports_only_fingerprints_shared_by_several_files() {
+        let service = service(&[
+            ("/music/Radiohead/15 Step [MP3 320kbps].mp3", "shared"),
+            ("/music/Radiohead/15 Step [FLAC].flac", "shared"),
+            ("/music/Radiohead/Nude.flac", "nude"),
+            ("/music/Radiohead/Reckoner.flac", "reckoner"),
+        ])
+        .await;
+
+        let clusters = service.fingerprint_clusters(50, 0).await.unwrap();
+
+        assert_eq!(clusters.len(), 1);
+        let cluster = &clusters[0];
+        assert_eq!(cluster.fingerprint_hash, "shared");
+        let summary: Vec<_> = cluster
+            .files
+            .iter()
+            .map(|file| (file.parsed_quality.clone(), file.parsed_bitrate_kbps))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![(AudioQuality::Flac, None), (AudioQuality::Mp3, Some(320))],
+            "the lossless copy is listed first"
+        );
+    }
+
+    #[tokio::test]
+    async fn reports_nothing_when_every_fingerprint_is_unique() {
+        let service = service(&[("/music/a.flac", "a"), ("/music/b.flac", "b")]).await;
+
+        assert!(service
+            .fingerprint_clusters(50, 0)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -5,12 +5,19 @@
 //! This module provides basic matching using embedded audio metadata
 //! (artist/album/track name from ID3/FLAC/Vorbis tags).
 //!
-//! Supports extraction from ID3v2 (MP3), Vorbis Comments (FLAC/OGG),
-//! MP4 atoms (M4A), and APEv2 tags via the `lofty` audio library.
+//! Supports extraction from ID3v2 (MP3), Vorbis Comments (FLAC/OGG/Opus),
+//! MP4 atoms (M4A with AAC or ALAC, including iTunes `----` freeform atoms),
+//! and APEv2 tags via the `lofty` audio library.
+//!
+//! Embedded artwork (`APIC` frames, `METADATA_BLOCK_PICTURE` blocks) can also
+//! be extracted and written next to the file as folder art.
 
 use crate::matching::MatchResult;
+use crate::tag_embedding::ArtworkData;
 use lofty::file::TaggedFileExt;
+use lofty::picture::{MimeType, Picture, PictureType};
 use lofty::prelude::Accessor;
+use lofty::tag::{ItemKey, Tag};
 use std::path::{Path, PathBuf};
 use thiserror::Error;
 use tracing::debug;
@@ -24,24 +31,147 @@ pub enum EmbeddedTagError {
     #[error("Failed to extract tags: {0}")]
     ExtractionFailed(String),
 
+    #[error("Unsupported audio container: {0}")]
+    UnsupportedFormat(String),
+
     #[error("Insufficient metadata to match")]
     InsufficientMetadata,
+
+    #[error("Failed to write artwork: {0}")]
+    ArtworkWriteFailed(String),
 }
 
+/// File stems recognised as existing folder art, matched case-insensitively
+/// together with [`FOLDER_ART_EXTENSIONS`].
+const FOLDER_ART_STEMS: &[&str] = &["cover", "folder", "front", "album"];
+const FOLDER_ART_EXTENSIONS: &[&str] = &["jpg", "jpeg", "png"];
+
 /// Result type used throughout the embedded tag matching module.
 pub type EmbeddedTagResult<T> = Result<T, EmbeddedTagError>;
 
+/// Separator used when a tag field carries several values (e.g. multiple
+/// `ARTIST` Vorbis comments).  Values keep their order in the file.
+pub const MULTI_VALUE_SEPARATOR: &str = "; ";
+
 /// Extracted metadata from audio file tags
 #[derive(Debug, Clone, Default)]
 pub struct ExtractedTags {
-    /// Artist name from tags
+    /// Artist name from tags (multiple artists joined with [`MULTI_VALUE_SEPARATOR`])
     pub artist: Option<String>,
+    /// Album artist from tags
+    pub album_artist: Option<String>,
     /// Album title from tags
     pub album: Option<String>,
     /// Track title from tags
     pub title: Option<String>,
     /// Track number from tags
     pub track_number: Option<u32>,
+    /// Total number of tracks on the disc
+    pub total_tracks: Option<u32>,
+    /// Disc number from tags
+    pub disc_number: Option<u32>,
+    /// Release date as written in the tags (e.g. `1997` or `1997-05-21`)
+    pub date: Option<String>,
+    /// MusicBrainz recording ID (`MUSICBRAINZ_TRACKID` in Vorbis comments)
+    pub musicbrainz_recording_id: Option<String>,
+    /// International Standard Recording Code
+    pub isrc: Option<String>,
+    /// ReplayGain track gain in dB
+    pub replaygain_track_gain: Option<f32>,
+    /// ReplayGain track peak amplitude (1.0 = full scale)
+    pub replaygain_track_peak: Option<f32>,
+    /// ReplayGain album gain in dB
+    pub replaygain_album_gain: Option<f32>,
+    /// ReplayGain album peak amplitude (1.0 = full scale)
+    pub replaygain_album_peak: Option<f32>,
+}
+
+impl ExtractedTags {
+    fn from_tag(tag: &Tag) -> Self {
+        let (track_number, slash_total) = number_pair(tag, ItemKey::TrackNumber);
+        let (disc_number, _) = number_pair(tag, ItemKey::DiscNumber);
+
+        Self {
+            artist: joined_text(tag, ItemKey::TrackArtist),
+            album_artist: joined_text(tag, ItemKey::AlbumArtist),
+            album: joined_text(tag, ItemKey::AlbumTitle),
+            title: joined_text(tag, ItemKey::TrackTitle),
+            track_number,
+            total_tracks: tag.track_total().or(slash_total),
+            disc_number,
+            date: first_text(tag, ItemKey::RecordingDate)
+                .or_else(|| first_text(tag, ItemKey::Year)),
+            musicbrainz_recording_id: first_text(tag, ItemKey::MusicBrainzRecordingId),
+            isrc: first_text(tag, ItemKey::Isrc),
+            replaygain_track_gain: replaygain_value(tag, ItemKey::ReplayGainTrackGain),
+            replaygain_track_peak: replaygain_value(tag, ItemKey::ReplayGainTrackPeak),
+            replaygain_album_gain: replaygain_value(tag, ItemKey::ReplayGainAlbumGain),
+            replaygain_album_peak: replaygain_value(tag, ItemKey::ReplayGainAlbumPeak),
+        }
+    }
+}
+
+/// Parse a ReplayGain value such as `-6.54 dB` or `0.988547`.
+///
+/// Malformed values are logged and treated as absent so one bad tag does not
+/// fail the whole extraction.
+fn replaygain_value(tag: &Tag, key: ItemKey) -> Option<f32> {
+    let raw = first_text(tag, key.clone())?;
+    let number = raw
+        .strip_suffix("dB")
+        .or_else(|| raw.strip_suffix("db"))
+        .or_else(|| raw.strip_suffix("DB"))
+        .unwrap_or(&raw)
+        .trim();
+
+    match number.parse::<f32>() {
+        Ok(value) if value.is_finite() => Some(value),
+        _ => {
+            debug!(target: "matching", ?key, value = %raw, "ignoring malformed ReplayGain value");
+            None
+        }
+    }
+}
+
+/// All non-empty values of `key`, de-duplicated and joined in file order.
+fn joined_text(tag: &Tag, key: ItemKey) -> Option<String> {
+    let mut values: Vec<&str> = Vec::new();
+    for value in tag.get_strings(&key).map(str::trim) {
+        if !value.is_empty() && !values.contains(&value) {
+            values.push(value);
+        }
+    }
+
+    (!values.is_empty()).then(|| values.join(MULTI_VALUE_SEPARATOR))
+}
+
+fn first_text(tag: &Tag, key: ItemKey) -> Option<String> {
+    tag.get_strings(&key)
+        .map(str::trim)
+        .find(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+/// Parse a `N` or `N/TOTAL` number field such as `TRACKNUMBER=3/12`.
+fn number_pair(tag: &Tag, key: ItemKey) -> (Option<u32>, Option<u32>) {
+    let Some(value) = first_text(tag, key) else {
+        return (None, None);
+    };
+
+    let mut parts = value.splitn(2, '/');
+    let number = parts.next().and_then(|n| n.trim().parse().ok());
+    let total = parts.next().and_then(|t| t.trim().parse().ok());
+    (number, total)
+}
+
+/// Map a lofty read failure, singling out containers lofty cannot identify.
+fn read_error(error: lofty::error::LoftyError) -> EmbeddedTagError {
+    match error.kind() {
+        lofty::error::ErrorKind::UnknownFormat => {
+            EmbeddedTagError::UnsupportedFormat(error.to_string())
+        }
+        _ => EmbeddedTagError::ExtractionFailed(format!("Failed to read metadata: {}", error)),
+    }
 }
 
 /// Fallback matching using embedded tags in audio files.
@@ -53,8 +183,9 @@ impl EmbeddedTagMatchingService {
     ///
     /// Supports the following formats via the `lofty` audio library:
     /// - ID3v2 tags (MP3)
-    /// - Vorbis comments (OGG, FLAC)
-    /// - MP4 atoms (M4A)
+    /// - Vorbis comments (OGG, FLAC) and Ogg-Opus comment headers
+    /// - MP4 atoms (M4A): `©ART`, `©alb`, `©nam`, `trkn`, `disk` and the
+    ///   MusicBrainz `----:com.apple.iTunes:*` freeform atoms
     /// - APEv2 tags
     ///
     /// The blocking file I/O is offloaded to a thread pool via
@@ -66,6 +197,7 @@ impl EmbeddedTagMatchingService {
     /// # Returns
     /// * `Ok(ExtractedTags)` - Successfully extracted tags
     /// * `Err(EmbeddedTagError::FileNotFound)` - File does not exist
+    /// * `Err(EmbeddedTagError::UnsupportedFormat)` - The container is not a supported audio format
     /// * `Err(EmbeddedTagError::ExtractionFailed)` - lofty could not parse the file
     /// * `Err(EmbeddedTagError::InsufficientMetadata)` - File parsed but has no tag block
     pub async fn extract_tags(&self, path: impl AsRef<Path>) -> EmbeddedTagResult<ExtractedTags> {
@@ -86,9 +218,7 @@ impl EmbeddedTagMatchingService {
         // async runtime worker thread is not blocked during imports/matching.
         let owned_path: PathBuf = path.to_path_buf();
         let tags_result = tokio::task::spawn_blocking(move || {
-            lofty::read_from_path(&owned_path).map_err(|e| {
-                EmbeddedTagError::ExtractionFailed(format!("Failed to read metadata: {}", e))
-            })
+            lofty::read_from_path(&owned_path).map_err(read_error)
         })
         .await
         .map_err(|e| EmbeddedTagError::ExtractionFailed(format!("Task join error: {}", e)))?;
@@ -113,23 +243,112 @@ impl EmbeddedTagMatchingService {
             .or_else(|| metadata.first_tag())
             .ok_or(EmbeddedTagError::InsufficientMetadata)?;
 
+        let extracted = ExtractedTags::from_tag(tag);
+
         debug!(
             target: "matching",
             path = %path.display(),
             format = %ext,
-            artist = ?tag.artist(),
-            album = ?tag.album(),
-            title = ?tag.title(),
-            track_number = ?tag.track(),
+            artist = ?extracted.artist,
+            album = ?extracted.album,
+            title = ?extracted.title,
+            track_number = ?extracted.track_number,
             "extracted tags from audio file"
         );
 
-        Ok(ExtractedTags {
-            artist: tag.artist().map(|s| s.to_string()),
-            album: tag.album().map(|s| s.to_string()),
-            title: tag.title().map(|s| s.to_string()),
-            track_number: tag.track(),
+        Ok(extracted)
+    }
+
+    /// Extract the embedded artwork from an audio file.
+    ///
+    /// When several images are embedded the front cover is preferred, falling
+    /// back to the first image. Returns `Ok(None)` when the file carries no
+    /// artwork.
+    pub async fn extract_artwork(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> EmbeddedTagResult<Option<ArtworkData>> {
+        let path = path.as_ref();
+
+        if !path.exists() {
+            return Err(EmbeddedTagError::FileNotFound(path.display().to_string()));
+        }
+
+        let owned_path: PathBuf = path.to_path_buf();
+        let tagged = tokio::task::spawn_blocking(move || {
+            lofty::read_from_path(&owned_path).map_err(read_error)
         })
+        .await
+        .map_err(|e| EmbeddedTagError::ExtractionFailed(format!("Task join error: {}", e)))??;
+
+        let pictures: Vec<&Picture> = tagged
+            .tags()
+            .iter()
+            .flat_map(|tag| tag.pictures())
+            .collect();
+
+        let Some(picture) = pictures
+            .iter()
+            .find(|picture| picture.pic_type() == PictureType::CoverFront)
+            .or_else(|| pictures.first())
+        else {
+            debug!(target: "matching", path = %path.display(), "no embedded artwork found");
+            return Ok(None);
+        };
+
+        Ok(Some(ArtworkData {
+            mime_type: picture_mime_type(picture).to_string(),
+            bytes: picture.data().to_vec(),
+        }))
+    }
+
+    /// Write a file's embedded artwork into its folder as `cover.jpg` or
+    /// `cover.png`, unless the folder already has artwork.
+    ///
+    /// Returns the path of the written cover, or `Ok(None)` when folder art
+    /// already exists or the file has no embedded artwork.
+    pub async fn write_folder_artwork(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> EmbeddedTagResult<Option<PathBuf>> {
+        let path = path.as_ref();
+        let folder = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+            .unwrap_or_else(|| Path::new("."));
+
+        if let Some(existing) = find_folder_artwork(folder)? {
+            debug!(
+                target: "matching",
+                existing = %existing.display(),
+                "folder artwork already present, skipping embedded artwork"
+            );
+            return Ok(None);
+        }
+
+        let Some(artwork) = self.extract_artwork(path).await? else {
+            return Ok(None);
+        };
+
+        let extension = if artwork.mime_type == "image/png" {
+            "png"
+        } else {
+            "jpg"
+        };
+        let cover_path = folder.join(format!("cover.{}", extension));
+
+        tokio::fs::write(&cover_path, &artwork.bytes)
+            .await
+            .map_err(|e| EmbeddedTagError::ArtworkWriteFailed(e.to_string()))?;
+
+        debug!(
+            target: "matching",
+            cover = %cover_path.display(),
+            bytes = artwork.bytes.len(),
+            "wrote embedded artwork as folder art"
+        );
+
+        Ok(Some(cover_path))
     }
 
     /// Attempt to match using embedded tags from the given file path.
@@ -181,10 +400,52 @@ impl EmbeddedTagMatchingService {
     }
 }
 
+/// MIME type of an embedded picture, sniffing the bytes when the tag does
+/// not declare one.
+fn picture_mime_type(picture: &Picture) -> &str {
+    match picture.mime_type() {
+        Some(MimeType::Png) => "image/png",
+        Some(MimeType::Jpeg) => "image/jpeg",
+        Some(other) => other.as_str(),
+        None if picture.data().starts_with(b"\x89PNG") => "image/png",
+        None => "image/jpeg",
+    }
+}
+
+/// Find existing folder art such as `cover.jpg` or `Folder.png`.
+fn find_folder_artwork(folder: &Path) -> EmbeddedTagResult<Option<PathBuf>> {
+    let entries = match std::fs::read_dir(folder) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            return Err(EmbeddedTagError::FileNotFound(folder.display().to_string()))
+        }
+        Err(e) => return Err(EmbeddedTagError::ArtworkWriteFailed(e.to_string())),
+    };
+
+    Ok(entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .find(|candidate| {
+            let stem = candidate.file_stem().and_then(|s| s.to_str());
+            let extension = candidate.extension().and_then(|e| e.to_str());
+            match (stem, extension) {
+                (Some(stem), Some(extension)) => {
+                    FOLDER_ART_STEMS
+                        .iter()
+                        .any(|s| stem.eq_ignore_ascii_case(s))
+                        && FOLDER_ART_EXTENSIONS
+                            .iter()
+                            .any(|e| extension.eq_ignore_ascii_case(e))
+                }
+                _ => false,
+            }
+        }))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_fixtures::{MINIMAL_FLAC, MINIMAL_MP3};
+    use crate::test_fixtures::{minimal_m4a, minimal_opus, MINIMAL_FLAC, MINIMAL_MP3};
     use std::fs;
 
     fn write_fixture(dir: &tempfile::TempDir, name: &str, bytes: &[u8]) -> PathBuf {
@@ -251,7 +512,7 @@ mod tests {
         let result = svc.extract_tags(&test_file).await;
         assert!(matches!(
             result,
-            Err(EmbeddedTagError::ExtractionFailed(_) | EmbeddedTagError::InsufficientMetadata)
+            Err(EmbeddedTagError::UnsupportedFormat(_))
         ));
     }
 
@@ -290,4 +551,400 @@ mod tests {
         assert_eq!(tags.title.as_deref(), Some("Test Title"));
         assert_eq!(tags.track_number, Some(3));
     }
+
+    /// Embed the given pictures into a fixture file using lofty.
+    fn embed_pictures(path: &PathBuf, pictures: Vec<Picture>) {
+        use lofty::config::WriteOptions;
+        use lofty::file::AudioFile;
+        use lofty::probe::Probe;
+
+        let mut tagged = Probe::open(path)
+            .expect("probe open")
+            .guess_file_type()
+            .expect("guess type")
+            .read()
+            .expect("read tagged file");
+
+        if tagged.primary_tag_mut().is_none() {
+            let tag_type = tagged.primary_tag_type();
+            tagged.insert_tag(lofty::tag::Tag::new(tag_type));
+        }
+        let tag = tagged.primary_tag_mut().expect("tag inserted");
+        for picture in pictures {
+            tag.push_picture(picture);
+        }
+
+        tagged
+            .save_to_path(path, WriteOptions::default())
+            .expect("save tags");
+    }
+
+    fn picture(pic_type: PictureType, mime_type: MimeType, bytes: &[u8]) -> Picture {
+        Picture::new_unchecked(pic_type, Some(mime_type), None, bytes.to_vec())
+    }
+
+    #[tokio::test]
+    async fn write_folder_artwork_writes_embedded_front_cover() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = write_fixture(&dir, "track.mp3", MINIMAL_MP3);
+        embed_pictures(
+            &path,
+            vec![
+                picture(PictureType::Artist, MimeType::Png, b"\x89PNG artist"),
+                picture(PictureType::CoverFront, MimeType::Jpeg, b"\xFF\xD8 front"),
+            ],
+        );
+
+        let svc = EmbeddedTagMatchingService;
+        let written = svc
+            .write_folder_artwork(&path)
+            .await
+            .expect("write should succeed");
+
+        let cover = dir.path().join("cover.jpg");
+        assert_eq!(written.as_deref(), Some(cover.as_path()));
+        assert_eq!(fs::read(&cover).expect("read cover"), b"\xFF\xD8 front");
+    }
+
+    #[tokio::test]
+    async fn write_folder_artwork_uses_png_extension_for_png_art() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = write_fixture(&dir, "track.flac", MINIMAL_FLAC);
+        embed_pictures(
+            &path,
+            vec![picture(
+                PictureType::CoverFront,
+                MimeType::Png,
+                b"\x89PNG front",
+            )],
+        );
+
+        let svc = EmbeddedTagMatchingService;
+        let written = svc
+            .write_folder_artwork(&path)
+            .await
+            .expect("write should succeed");
+
+        assert_eq!(written, Some(dir.path().join("cover.png")));
+    }
+
+    #[tokio::test]
+    async fn write_folder_artwork_skips_file_without_art() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = write_fixture(&dir, "track.mp3", MINIMAL_MP3);
+        embed_known_tags(&path);
+
+        let svc = EmbeddedTagMatchingService;
+        let written = svc
+            .write_folder_artwork(&path)
+            .await
+            .expect("write should succeed");
+
+        assert_eq!(written, None);
+        assert_eq!(fs::read_dir(dir.path()).expect("read dir").count(), 1);
+    }
+
+    #[tokio::test]
+    async fn write_folder_artwork_keeps_existing_folder_art() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = write_fixture(&dir, "track.mp3", MINIMAL_MP3);
+        embed_pictures(
+            &path,
+            vec![picture(
+                PictureType::CoverFront,
+                MimeType::Jpeg,
+                b"embedded",
+            )],
+        );
+        let existing = write_fixture(&dir, "Folder.JPG", b"existing");
+
+        let svc = EmbeddedTagMatchingService;
+        let written = svc
+            .write_folder_artwork(&path)
+            .await
+            .expect("write should succeed");
+
+        assert_eq!(written, None);
+        assert_eq!(fs::read(&existing).expect("read existing"), b"existing");
+        assert!(!dir.path().join("cover.jpg").exists());
+    }
+
+    const VORBIS_COMMENTS: &[(&str, &str)] = &[
+        ("ARTIST", "Thom Yorke"),
+        ("ARTIST", "Jonny Greenwood"),
+        ("ALBUMARTIST", "Radiohead"),
+        ("ALBUM", "OK Computer"),
+        ("TITLE", "Paranoid Android"),
+        ("TRACKNUMBER", "2/12"),
+        ("DISCNUMBER", "1"),
+        ("DATE", "1997-05-21"),
+        (
+            "MUSICBRAINZ_TRACKID",
+            "e5a3f0c4-1fae-4f2e-8f76-0c3b4f1e4fa6",
+        ),
+        ("ISRC", "GBAYE9700245"),
+    ];
+
+    fn assert_vorbis_fields(tags: &ExtractedTags) {
+        assert_eq!(tags.artist.as_deref(), Some("Thom Yorke; Jonny Greenwood"));
+        assert_eq!(tags.album_artist.as_deref(), Some("Radiohead"));
+        assert_eq!(tags.album.as_deref(), Some("OK Computer"));
+        assert_eq!(tags.title.as_deref(), Some("Paranoid Android"));
+        assert_eq!(tags.track_number, Some(2));
+        assert_eq!(tags.total_tracks, Some(12));
+        assert_eq!(tags.disc_number, Some(1));
+        assert_eq!(tags.date.as_deref(), Some("1997-05-21"));
+        assert_eq!(
+            tags.musicbrainz_recording_id.as_deref(),
+            Some("e5a3f0c4-1fae-4f2e-8f76-0c3b4f1e4fa6")
+        );
+        assert_eq!(tags.isrc.as_deref(), Some("GBAYE9700245"));
+    }
+
+    #[tokio::test]
+    async fn extract_tags_flac_reads_vorbis_comments() {
+        use lofty::config::{ParseOptions, WriteOptions};
+        use lofty::file::AudioFile;
+        use lofty::flac::FlacFile;
+        use lofty::ogg::VorbisComments;
+
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = write_fixture(&dir, "track.flac", MINIMAL_FLAC);
+
+        let mut comments = VorbisComments::new();
+        for (key, value) in VORBIS_COMMENTS {
+            comments.push(key.to_string(), value.to_string());
+        }
+        let mut flac = FlacFile::read_from(
+            &mut fs::File::open(&path).expect("open fixture"),
+            ParseOptions::new(),
+        )
+        .expect("read flac");
+        flac.set_vorbis_comments(comments);
+        flac.save_to_path(&path, WriteOptions::default())
+            .expect("save flac");
+
+        let tags = EmbeddedTagMatchingService
+            .extract_tags(&path)
+            .await
+            .expect("extract should succeed");
+
+        assert_vorbis_fields(&tags);
+    }
+
+    #[tokio::test]
+    async fn extract_tags_ogg_reads_vorbis_comments() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = write_fixture(&dir, "track.opus", &minimal_opus(VORBIS_COMMENTS));
+
+        let tags = EmbeddedTagMatchingService
+            .extract_tags(&path)
+            .await
+            .expect("extract should succeed");
+
+        assert_vorbis_fields(&tags);
+    }
+
+    #[tokio::test]
+    async fn extract_tags_m4a_reads_mp4_atoms() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = write_fixture(
+            &dir,
+            "track.m4a",
+            &minimal_m4a(&[
+                ("©ART", "Radiohead"),
+                ("aART", "Radiohead"),
+                ("©alb", "OK Computer"),
+                ("©nam", "Paranoid Android"),
+                ("trkn", "2/12"),
+                ("disk", "1/1"),
+                ("©day", "1997-05-21"),
+                (
+                    "----:com.apple.iTunes:MusicBrainz Track Id",
+                    "e5a3f0c4-1fae-4f2e-8f76-0c3b4f1e4fa6",
+                ),
+                ("----:com.apple.iTunes:ISRC", "GBAYE9700245"),
+            ]),
+        );
+
+        let tags = EmbeddedTagMatchingService
+            .extract_tags(&path)
+            .await
+            .expect("extract should succeed");
+
+        assert_eq!(tags.artist.as_deref(), Some("Radiohead"));
+        assert_eq!(tags.album_artist.as_deref(), Some("Radiohead"));
+        assert_eq!(tags.album.as_deref(), Some("OK Computer"));
+        assert_eq!(tags.title.as_deref(), Some("Paranoid Android"));
+        assert_eq!(tags.track_number, Some(2));
+        assert_eq!(tags.total_tracks, Some(12));
+        assert_eq!(tags.disc_number, Some(1));
+        assert_eq!(tags.date.as_deref(), Some("1997-05-21"));
+        assert_eq!(
+            tags.musicbrainz_recording_id.as_deref(),
+            Some("e5a3f0c4-1fae-4f2e-8f76-0c3b4f1e4fa6")
+        );
+        assert_eq!(tags.isrc.as_deref(), Some("GBAYE9700245"));
+    }
+
+    #[tokio::test]
+    async fn extract_tags_opus_reads_core_fields() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = write_fixture(
+            &dir,
+            "track.opus",
+            &minimal_opus(&[
+                ("ARTIST", "Björk"),
+                ("ALBUM", "Homogenic"),
+                ("TITLE", "Jóga"),
+                ("TRACKNUMBER", "3"),
+            ]),
+        );
+
+        let tags = EmbeddedTagMatchingService
+            .extract_tags(&path)
+            .await
+            .expect("extract should succeed");
+
+        assert_eq!(tags.artist.as_deref(), Some("Björk"));
+        assert_eq!(tags.album.as_deref(), Some("Homogenic"));
+        assert_eq!(tags.title.as_deref(), Some("Jóga"));
+        assert_eq!(tags.track_number, Some(3));
+    }
+
+    #[tokio::test]
+    async fn extract_tags_unknown_container_returns_unsupported_format() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = write_fixture(&dir, "track.m4a", b"RIFF\0\0\0\0AVI LIST not music");
+
+        let result = EmbeddedTagMatchingService.extract_tags(&path).await;
+
+        assert!(
+            matches!(result, Err(EmbeddedTagError::UnsupportedFormat(_))),
+            "{result:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn extract_tags_uses_separate_total_tracks_comment() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = write_fixture(
+            &dir,
+            "track.opus",
+            &minimal_opus(&[("TRACKNUMBER", "7"), ("TOTALTRACKS", "10")]),
+        );
+
+        let tags = EmbeddedTagMatchingService
+            .extract_tags(&path)
+            .await
+            .expect("extract should succeed");
+
+        assert_eq!(tags.track_number, Some(7));
+        assert_eq!(tags.total_tracks, Some(10));
+        assert_eq!(tags.artist, None);
+    }
+
+    #[tokio::test]
+    async fn extract_tags_corrupt_flac_returns_extraction_error() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let mut corrupt = MINIMAL_FLAC[..12].to_vec();
+        corrupt.extend_from_slice(b"not a flac stream");
+        let path = write_fixture(&dir, "corrupt.flac", &corrupt);
+
+        let result = EmbeddedTagMatchingService.extract_tags(&path).await;
+
+        assert!(matches!(result, Err(EmbeddedTagError::ExtractionFailed(_))));
+    }
+
+    /// Write the given ReplayGain items into a fixture file using lofty.
+    fn embed_replaygain(path: &PathBuf, items: &[(ItemKey, &str)]) {
+        use lofty::config::WriteOptions;
+        use lofty::file::AudioFile;
+        use lofty::probe::Probe;
+
+        let mut tagged = Probe::open(path)
+            .expect("probe open")
+            .guess_file_type()
+            .expect("guess type")
+            .read()
+            .expect("read tagged file");
+
+        if tagged.primary_tag_mut().is_none() {
+            let tag_type = tagged.primary_tag_type();
+            tagged.insert_tag(Tag::new(tag_type));
+        }
+        let tag = tagged.primary_tag_mut().expect("tag inserted");
+        for (key, value) in items {
+            assert!(tag.insert_text(key.clone(), value.to_string()));
+        }
+
+        tagged
+            .save_to_path(path, WriteOptions::default())
+            .expect("save tags");
+    }
+
+    #[tokio::test]
+    async fn extract_tags_reads_full_replaygain_from_vorbis_comments() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = write_fixture(
+            &dir,
+            "track.opus",
+            &minimal_opus(&[
+                ("REPLAYGAIN_TRACK_GAIN", "-6.54 dB"),
+                ("REPLAYGAIN_TRACK_PEAK", "0.988547"),
+                ("REPLAYGAIN_ALBUM_GAIN", "+1.20 dB"),
+                ("REPLAYGAIN_ALBUM_PEAK", "1.000000"),
+            ]),
+        );
+
+        let tags = EmbeddedTagMatchingService
+            .extract_tags(&path)
+            .await
+            .expect("extract should succeed");
+
+        assert_eq!(tags.replaygain_track_gain, Some(-6.54));
+        assert_eq!(tags.replaygain_track_peak, Some(0.988547));
+        assert_eq!(tags.replaygain_album_gain, Some(1.2));
+        assert_eq!(tags.replaygain_album_peak, Some(1.0));
+    }
+
+    #[tokio::test]
+    async fn extract_tags_reads_replaygain_track_gain_from_id3_txxx() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = write_fixture(&dir, "track.mp3", MINIMAL_MP3);
+        embed_replaygain(&path, &[(ItemKey::ReplayGainTrackGain, "-3.10 dB")]);
+
+        let tags = EmbeddedTagMatchingService
+            .extract_tags(&path)
+            .await
+            .expect("extract should succeed");
+
+        assert_eq!(tags.replaygain_track_gain, Some(-3.1));
+        assert_eq!(tags.replaygain_track_peak, None);
+        assert_eq!(tags.replaygain_album_gain, None);
+        assert_eq!(tags.replaygain_album_peak, None);
+    }
+
+    #[tokio::test]
+    async fn extract_tags_ignores_malformed_replaygain_value() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = write_fixture(
+            &dir,
+            "track.opus",
+            &minimal_opus(&[
+                ("TITLE", "Lucky"),
+                ("REPLAYGAIN_TRACK_GAIN", "loud"),
+                ("REPLAYGAIN_TRACK_PEAK", "0.5"),
+            ]),
+        );
+
+        let tags = EmbeddedTagMatchingService
+            .extract_tags(&path)
+            .await
+            .expect("extract should succeed");
+
+        assert_eq!(tags.title.as_deref(), Some("Lucky"));
+        assert_eq!(tags.replaygain_track_gain, None);
+        assert_eq!(tags.replaygain_track_peak, Some(0.5));
+    }
 }
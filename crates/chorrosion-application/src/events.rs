@@ -2,8 +2,10 @@
 use std::sync::{Arc, Mutex};
 
 use chorrosion_domain::DomainEvent;
+use chorrosion_realtime::{NoopRealtimeHub, RealtimeHub};
 use serde::Serialize;
 use serde_json::json;
+use tracing::{debug, warn};
 
 /// Event publisher abstraction
 pub trait EventPublisher: Send + Sync {
@@ -45,22 +47,89 @@ impl EventPublisher for InMemoryEventBus {
     where
         T: Serialize + Send + Sync + 'static,
     {
-        let value = json!({
-            "name": event.name,
-            "occurred_at": event.occurred_at,
-            "payload": event.payload,
-        });
         self.inner
             .lock()
             .expect("Failed to acquire lock")
-            .push(value);
+            .push(event_json(event));
+    }
+}
+
+/// Serialized envelope shared by every publisher.
+fn event_json<T: Serialize>(event: &DomainEvent<T>) -> serde_json::Value {
+    json!({
+        "id": event.id,
+        "name": event.name,
+        "schema_version": event.schema_version,
+        "correlation_id": event.correlation_id,
+        "occurred_at": event.occurred_at,
+        "payload": event.payload,
+    })
+}
+
+/// Realtime channel an event is broadcast on: the entity prefix of the event
+/// name, pluralized (`artist.created` -> `artists`, `track.file.imported` -> `tracks`).
+pub fn channel_for_event(name: &str) -> String {
+    let entity = name.split('.').next().unwrap_or(name);
+    format!("{entity}s")
+}
+
+/// Forwards domain events to one or more [`RealtimeHub`]s as JSON.
+#[derive(Clone)]
+pub struct EventDispatcher {
+    hubs: Vec<Arc<dyn RealtimeHub>>,
+}
+
+impl EventDispatcher {
+    pub fn new(hub: Arc<dyn RealtimeHub>) -> Self {
+        Self { hubs: vec![hub] }
+    }
+
+    /// Also broadcast to `hub`, e.g. to serve both WebSocket and SSE clients.
+    pub fn with_hub(mut self, hub: Arc<dyn RealtimeHub>) -> Self {
+        self.hubs.push(hub);
+        self
+    }
+
+    /// Serialize `event` and broadcast it on its entity channel.
+    pub async fn dispatch<T: Serialize>(&self, event: &DomainEvent<T>) {
+        let channel = channel_for_event(event.name);
+        let payload = match serde_json::to_string(&event_json(event)) {
+            Ok(payload) => payload,
+            Err(error) => {
+                warn!(target: "application", event = event.name, %error, "failed to serialize domain event");
+                return;
+            }
+        };
+
+        debug!(target: "application", event = event.name, %channel, "dispatching domain event");
+        for hub in &self.hubs {
+            hub.broadcast(&channel, &payload).await;
+        }
+    }
+}
+
+impl Default for EventDispatcher {
+    fn default() -> Self {
+        Self::new(Arc::new(NoopRealtimeHub))
+    }
+}
+
+impl std::fmt::Debug for EventDispatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventDispatcher")
+            .field("hubs", &self.hubs.len())
+            .finish()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chorrosion_domain::{TrackFileId, TrackFileImported, TrackFileImportedPayload, TrackId};
+    use chorrosion_domain::{
+        ArtistCreated, ArtistCreatedPayload, ArtistId, TrackFileId, TrackFileImported,
+        TrackFileImportedPayload, TrackId,
+    };
+    use chorrosion_realtime::{RealtimeMessage, RecordingHub};
 
     #[test]
     fn publish_and_drain_events() {
@@ -87,4 +156,57 @@ mod tests {
             .ends_with("Song.flac"));
         assert!(bus.is_empty());
     }
+
+    #[test]
+    fn channel_maps_from_event_entity_prefix() {
+        assert_eq!(channel_for_event("artist.created"), "artists");
+        assert_eq!(channel_for_event("album.updated"), "albums");
+        assert_eq!(channel_for_event("track.file.imported"), "tracks");
+    }
+
+    #[tokio::test]
+    async fn dispatch_broadcasts_artist_created_on_artists_channel() {
+        let hub = Arc::new(RecordingHub::default());
+        let dispatcher = EventDispatcher::new(hub.clone());
+
+        let event: ArtistCreated = DomainEvent::new(
+            "artist.created",
+            ArtistCreatedPayload {
+                artist_id: ArtistId::new(),
+                name: "Boards of Canada".to_string(),
+                monitored: true,
+            },
+        );
+        dispatcher.dispatch(&event).await;
+
+        let messages = hub.messages();
+        assert_eq!(messages.len(), 1);
+        let RealtimeMessage { channel, payload } = &messages[0];
+        assert_eq!(channel, "artists");
+        let value: serde_json::Value = serde_json::from_str(payload).expect("valid JSON");
+        assert_eq!(value["name"], "artist.created");
+        assert_eq!(value["payload"]["name"], "Boards of Canada");
+    }
+
+    #[tokio::test]
+    async fn dispatch_fans_out_to_every_hub() {
+        let first = Arc::new(RecordingHub::default());
+        let second = Arc::new(RecordingHub::default());
+        let dispatcher = EventDispatcher::new(first.clone()).with_hub(second.clone());
+
+        let payload = TrackFileImportedPayload {
+            track_id: TrackId::new(),
+            track_file_id: TrackFileId::new(),
+            path: "song.flac".to_string(),
+        };
+        dispatcher
+            .dispatch(&DomainEvent::new("track.file.imported", payload))
+            .await;
+
+        for hub in [first, second] {
+            let messages = hub.messages();
+            assert_eq!(messages.len(), 1);
+            assert_eq!(messages[0].channel, "tracks");
+        }
+    }
 }
@@ -4,7 +4,7 @@ use crate::permission::{PermissionChecker, PermissionConfig, PermissionManager};
 use lazy_static::lazy_static;
 use regex::Regex;
 use std::fs;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 use thiserror::Error;
 use tracing::trace;
 
@@ -42,6 +42,8 @@ pub enum FileOrganizationError {
     FileOperation(String),
     #[error("permission denied: {0}")]
     Permission(String),
+    #[error("path escapes the library folder: {0}")]
+    OutsideLibrary(String),
 }
 
 pub fn render_naming_pattern(
@@ -101,6 +103,21 @@ pub fn build_organized_file_path(
     }
 
     path.push(file_name);
+
+    // Sanitizing neutralizes `..`, so this only trips if that guarantee is broken.
+    let escapes = path
+        .strip_prefix(base)
+        .map(|relative| {
+            relative
+                .components()
+                .any(|component| !matches!(component, Component::Normal(_)))
+        })
+        .unwrap_or(true);
+    if escapes {
+        return Err(FileOrganizationError::OutsideLibrary(
+            path.display().to_string(),
+        ));
+    }
     Ok(path)
 }
 
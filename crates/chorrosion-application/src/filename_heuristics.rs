@@ -7,6 +7,10 @@
 //! - Artist - Album - Track - Title
 //! - Artist - Album (Track #Title)
 //! - [Artist] Album - Track Title
+//! - Disc-Track Title (e.g. `1-05 Title`), or a `CD2`/`Disc 2` parent folder
+//!
+//! Collaboration credits such as `A & B` or `A feat. B` are split into
+//! individual artists.
 //!
 //! Confidence is typically lower than fingerprint or tag-based matching.
 
@@ -43,9 +47,39 @@ pub struct ParsedFilename {
     pub title: Option<String>,
     /// Track number if present
     pub track_number: Option<u32>,
+    /// Disc number from a `1-05` filename prefix or a `CD2`/`Disc 2` parent folder
+    pub disc_number: Option<u32>,
+    /// Individual artists split out of a collaboration credit (`A & B`, `A feat. B`)
+    pub artists: Vec<String>,
+    /// How much to trust this parse, from 0.0 (bare track number) to 1.0.
+    ///
+    /// Rises with a clear ` - ` delimiter, a track number, a title, and
+    /// artist/album context from the filename or folders.
+    pub confidence: f32,
 }
 
 lazy_static! {
+    // Pattern: TrackNum - Title with whitespace before the dash (e.g. "10 - Title"),
+    // which can be neither a disc prefix nor an artist name
+    static ref PATTERN_TRACK_SPACED_DASH_TITLE: Regex = Regex::new(
+        r"^(?P<track>\d+)\s+-\s*(?P<title>.+?)(?:\.|$)"
+    ).unwrap();
+
+    // Pattern: Disc-TrackNum Title (e.g. "1-05 Title" or "1-05 - Title")
+    static ref PATTERN_DISC_TRACK_TITLE: Regex = Regex::new(
+        r"^(?P<disc>\d{1,2})-(?P<track>\d{1,3})(?:\s*-\s*|\s+)(?P<title>.+?)(?:\.|$)"
+    ).unwrap();
+
+    // Parent folder naming a disc: "CD2", "Disc 2", "disk02"
+    static ref PATTERN_DISC_FOLDER: Regex = Regex::new(
+        r"(?i)^(?:cd|dis[ck])\s*(?P<disc>\d+)$"
+    ).unwrap();
+
+    // Separators between collaborating artists: "A & B", "A feat. B", "A (ft. B)"
+    static ref ARTIST_SEPARATOR: Regex = Regex::new(
+        r"(?i)\s+&\s+|\s*[(\[]?\b(?:feat\.?|ft\.?|featuring)\s+"
+    ).unwrap();
+
     // Pattern: Artist - Album - TrackNum - Title
     static ref PATTERN_DETAILED: Regex = Regex::new(
         r"^(?P<artist>[^-]+)\s*-\s*(?P<album>[^-]+)\s*-\s*(?P<track>\d+)\s*-\s*(?P<title>.+?)(?:\.|$)"
@@ -65,8 +99,21 @@ lazy_static! {
     static ref PATTERN_TRACK_TITLE_SPACE: Regex = Regex::new(
         r"^(?P<track>\d+)\s+(?P<title>.+?)(?:\.|$)"
     ).unwrap();
+
+    // Pattern: TrackNum only (everything else must come from folders)
+    static ref PATTERN_TRACK_ONLY: Regex = Regex::new(r"^(?P<track>\d+)$").unwrap();
+
+    // Album folder tagged with a release year, e.g. "Album (2020)"
+    static ref PATTERN_ALBUM_YEAR: Regex = Regex::new(r"[(\[]\d{4}[)\]]").unwrap();
 }
 
+/// Pattern clarity for `Artist - Album - 01 - Title`, where every field is delimited.
+const CLARITY_FULLY_DELIMITED: f32 = 0.2;
+/// Pattern clarity for patterns with a ` - ` delimiter but some fields from folders.
+const CLARITY_DELIMITED: f32 = 0.15;
+/// Pattern clarity for `01 Title`, where only whitespace separates track and title.
+const CLARITY_UNDELIMITED: f32 = 0.05;
+
 /// Filename-based heuristics matching service (final fallback).
 #[derive(Default, Clone)]
 pub struct FilenameHeuristicsService;
@@ -82,6 +129,11 @@ impl FilenameHeuristicsService {
     /// 2. `Artist - 01 - Title` (album from folder)
     /// 3. `01 - Title` (artist/album from folder structure)
     /// 4. `01 Title` (space-separated variant)
+    /// 5. `1-05 Title` (disc-track prefix, tried first)
+    /// 6. `01` (bare track number, lowest confidence)
+    ///
+    /// A `CD2`/`Disc 2` parent folder supplies the disc number when the
+    /// filename has none, and the artist credit is split into `artists`.
     ///
     /// # Arguments
     /// * `path` - Path to the audio file
@@ -118,61 +170,29 @@ impl FilenameHeuristicsService {
             "attempting to parse filename"
         );
 
-        // Try patterns in order of specificity
-        if let Some(caps) = PATTERN_DETAILED.captures(filename) {
-            return Ok(ParsedFilename {
-                artist: caps.name("artist").map(|m| m.as_str().trim().to_string()),
-                album: caps.name("album").map(|m| m.as_str().trim().to_string()),
-                title: caps.name("title").map(|m| m.as_str().trim().to_string()),
-                track_number: caps
-                    .name("track")
-                    .and_then(|m| m.as_str().parse::<u32>().ok()),
-            });
-        }
-
-        if let Some(caps) = PATTERN_ARTIST_TRACK_TITLE.captures(filename) {
-            return Ok(ParsedFilename {
-                artist: caps.name("artist").map(|m| m.as_str().trim().to_string()),
-                album: folder_album.map(|s| s.to_string()),
-                title: caps.name("title").map(|m| m.as_str().trim().to_string()),
-                track_number: caps
-                    .name("track")
-                    .and_then(|m| m.as_str().parse::<u32>().ok()),
-            });
-        }
-
-        if let Some(caps) = PATTERN_TRACK_TITLE.captures(filename) {
-            return Ok(ParsedFilename {
-                artist: folder_artist.map(|s| s.to_string()),
-                album: folder_album.map(|s| s.to_string()),
-                title: caps.name("title").map(|m| m.as_str().trim().to_string()),
-                track_number: caps
-                    .name("track")
-                    .and_then(|m| m.as_str().parse::<u32>().ok()),
-            });
-        }
-
-        if let Some(caps) = PATTERN_TRACK_TITLE_SPACE.captures(filename) {
-            return Ok(ParsedFilename {
-                artist: folder_artist.map(|s| s.to_string()),
-                album: folder_album.map(|s| s.to_string()),
-                title: caps.name("title").map(|m| m.as_str().trim().to_string()),
-                track_number: caps
-                    .name("track")
-                    .and_then(|m| m.as_str().parse::<u32>().ok()),
-            });
+        let Some(mut parsed) = parse_stem(filename, folder_artist, folder_album) else {
+            warn!(
+                target: "matching",
+                filename = %filename,
+                "no filename patterns matched"
+            );
+            return Err(FilenameHeuristicsError::ParsingFailed(format!(
+                "No matching filename patterns for '{}'",
+                filename
+            )));
+        };
+
+        if parsed.disc_number.is_none() {
+            parsed.disc_number = disc_number_from_folder(path);
         }
-
-        // No pattern matched
-        warn!(
-            target: "matching",
-            filename = %filename,
-            "no filename patterns matched"
-        );
-        Err(FilenameHeuristicsError::ParsingFailed(format!(
-            "No matching filename patterns for '{}'",
-            filename
-        )))
+        parsed.artists = parsed
+            .artist
+            .as_deref()
+            .map(split_artist_credit)
+            .unwrap_or_default();
+        parsed.confidence = score_confidence(&parsed);
+
+        Ok(parsed)
     }
 
     /// Attempt to match a track using filename heuristics.
@@ -213,6 +233,8 @@ impl FilenameHeuristicsService {
                     album = ?parsed.album,
                     title = ?parsed.title,
                     track_number = ?parsed.track_number,
+                    disc_number = ?parsed.disc_number,
+                    confidence = parsed.confidence,
                     "successfully parsed filename for matching"
                 );
 
@@ -232,6 +254,131 @@ impl FilenameHeuristicsService {
     }
 }
 
+/// Match `filename` (without extension) against the supported patterns.
+fn parse_stem(
+    filename: &str,
+    folder_artist: Option<&str>,
+    folder_album: Option<&str>,
+) -> Option<ParsedFilename> {
+    let capture = |caps: &regex::Captures<'_>, name: &str| {
+        caps.name(name).map(|m| m.as_str().trim().to_string())
+    };
+    let number = |caps: &regex::Captures<'_>, name: &str| {
+        caps.name(name).and_then(|m| m.as_str().parse::<u32>().ok())
+    };
+
+    // A plain "10 - Title" is unambiguous, so it goes before the disc and
+    // artist patterns that would otherwise split its title on inner dashes.
+    if let Some(caps) = PATTERN_TRACK_SPACED_DASH_TITLE.captures(filename) {
+        return Some(ParsedFilename {
+            artist: folder_artist.map(|s| s.to_string()),
+            album: folder_album.map(|s| s.to_string()),
+            title: capture(&caps, "title"),
+            track_number: number(&caps, "track"),
+            confidence: CLARITY_DELIMITED,
+            ..ParsedFilename::default()
+        });
+    }
+
+    // Try the remaining patterns in order of specificity
+    if let Some(caps) = PATTERN_DISC_TRACK_TITLE.captures(filename) {
+        return Some(ParsedFilename {
+            artist: folder_artist.map(|s| s.to_string()),
+            album: folder_album.map(|s| s.to_string()),
+            title: capture(&caps, "title"),
+            track_number: number(&caps, "track"),
+            disc_number: number(&caps, "disc"),
+            confidence: CLARITY_DELIMITED,
+            ..ParsedFilename::default()
+        });
+    }
+
+    if let Some(caps) = PATTERN_DETAILED.captures(filename) {
+        return Some(ParsedFilename {
+            artist: capture(&caps, "artist"),
+            album: capture(&caps, "album"),
+            title: capture(&caps, "title"),
+            track_number: number(&caps, "track"),
+            confidence: CLARITY_FULLY_DELIMITED,
+            ..ParsedFilename::default()
+        });
+    }
+
+    if let Some(caps) = PATTERN_ARTIST_TRACK_TITLE.captures(filename) {
+        return Some(ParsedFilename {
+            artist: capture(&caps, "artist"),
+            album: folder_album.map(|s| s.to_string()),
+            title: capture(&caps, "title"),
+            track_number: number(&caps, "track"),
+            confidence: CLARITY_DELIMITED,
+            ..ParsedFilename::default()
+        });
+    }
+
+    let (caps, clarity) = if let Some(caps) = PATTERN_TRACK_TITLE.captures(filename) {
+        (caps, CLARITY_DELIMITED)
+    } else if let Some(caps) = PATTERN_TRACK_TITLE_SPACE.captures(filename) {
+        (caps, CLARITY_UNDELIMITED)
+    } else {
+        (PATTERN_TRACK_ONLY.captures(filename)?, 0.0)
+    };
+    Some(ParsedFilename {
+        artist: folder_artist.map(|s| s.to_string()),
+        album: folder_album.map(|s| s.to_string()),
+        title: capture(&caps, "title"),
+        track_number: number(&caps, "track"),
+        confidence: clarity,
+        ..ParsedFilename::default()
+    })
+}
+
+/// Add context bonuses to the pattern clarity already stored in `parsed.confidence`.
+fn score_confidence(parsed: &ParsedFilename) -> f32 {
+    let mut confidence = parsed.confidence;
+    if parsed.track_number.is_some() {
+        confidence += 0.2;
+    }
+    if parsed.title.is_some() {
+        confidence += 0.2;
+    }
+    if parsed.artist.is_some() {
+        confidence += 0.15;
+    }
+    if let Some(album) = parsed.album.as_deref() {
+        confidence += 0.15;
+        if PATTERN_ALBUM_YEAR.is_match(album) {
+            confidence += 0.05;
+        }
+    }
+    if parsed.disc_number.is_some() {
+        confidence += 0.05;
+    }
+    confidence.clamp(0.0, 1.0)
+}
+
+/// Disc number from a parent folder named like `CD2` or `Disc 2`.
+fn disc_number_from_folder(path: &Path) -> Option<u32> {
+    let folder = path.parent()?.file_name()?.to_str()?;
+    PATTERN_DISC_FOLDER
+        .captures(folder.trim())?
+        .name("disc")?
+        .as_str()
+        .parse()
+        .ok()
+}
+
+/// Split a credit such as `A & B` or `A (feat. B)` into individual artist names.
+fn split_artist_credit(credit: &str) -> Vec<String> {
+    ARTIST_SEPARATOR
+        .split(credit)
+        .map(|name| {
+            name.trim_matches(|c: char| c.is_whitespace() || matches!(c, '(' | ')' | '[' | ']'))
+        })
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -368,4 +515,164 @@ mod tests {
             Some("Run Like Hell")
         );
     }
+
+    fn touch(dir: &Path, relative: &str) -> std::path::PathBuf {
+        let path = dir.join(relative);
+        std::fs::create_dir_all(path.parent().expect("parent dir")).expect("create dirs");
+        std::fs::write(&path, b"").expect("create file");
+        path
+    }
+
+    #[test]
+    fn parses_disc_track_prefix() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = touch(dir.path(), "1-05 Comfortably Numb.flac");
+
+        let parsed = FilenameHeuristicsService
+            .parse_filename(&path, Some("Pink Floyd"), Some("The Wall"))
+            .expect("disc-prefixed filename should parse");
+
+        assert_eq!(parsed.disc_number, Some(1));
+        assert_eq!(parsed.track_number, Some(5));
+        assert_eq!(parsed.title.as_deref(), Some("Comfortably Numb"));
+        assert_eq!(parsed.artists, vec!["Pink Floyd"]);
+    }
+
+    #[test]
+    fn plain_track_prefix_is_tried_before_disc_and_artist_patterns() {
+        let dir = tempfile::tempdir().expect("temp dir");
+
+        let path = touch(dir.path(), "10 - Track.flac");
+        let parsed = FilenameHeuristicsService
+            .parse_filename(&path, Some("Artist"), Some("Album"))
+            .expect("filename should parse");
+        assert_eq!(parsed.disc_number, None);
+        assert_eq!(parsed.track_number, Some(10));
+        assert_eq!(parsed.title.as_deref(), Some("Track"));
+
+        let path = touch(dir.path(), "10 - 2-4-6-8 Motorway.flac");
+        let parsed = FilenameHeuristicsService
+            .parse_filename(
+                &path,
+                Some("Tom Robinson Band"),
+                Some("Power in the Darkness"),
+            )
+            .expect("filename should parse");
+        assert_eq!(parsed.disc_number, None);
+        assert_eq!(parsed.track_number, Some(10));
+        assert_eq!(parsed.title.as_deref(), Some("2-4-6-8 Motorway"));
+        assert_eq!(parsed.artist.as_deref(), Some("Tom Robinson Band"));
+    }
+
+    #[test]
+    fn disc_number_comes_from_cd_folder() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = touch(dir.path(), "The Wall/CD2/03 - Hey You.flac");
+
+        let parsed = FilenameHeuristicsService
+            .parse_filename(&path, Some("Pink Floyd"), Some("The Wall"))
+            .expect("filename should parse");
+
+        assert_eq!(parsed.disc_number, Some(2));
+        assert_eq!(parsed.track_number, Some(3));
+        assert_eq!(parsed.title.as_deref(), Some("Hey You"));
+    }
+
+    #[test]
+    fn splits_featured_artist_credit() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = touch(
+            dir.path(),
+            "Daft Punk feat. Pharrell Williams - 08 - Get Lucky.flac",
+        );
+
+        let parsed = FilenameHeuristicsService
+            .parse_filename(&path, None, Some("Random Access Memories"))
+            .expect("filename should parse");
+
+        assert_eq!(
+            parsed.artist.as_deref(),
+            Some("Daft Punk feat. Pharrell Williams")
+        );
+        assert_eq!(parsed.artists, vec!["Daft Punk", "Pharrell Williams"]);
+        assert_eq!(parsed.title.as_deref(), Some("Get Lucky"));
+        assert_eq!(parsed.disc_number, None);
+    }
+
+    #[test]
+    fn splits_collaboration_credits() {
+        assert_eq!(
+            split_artist_credit("Simon & Garfunkel"),
+            vec!["Simon", "Garfunkel"]
+        );
+        assert_eq!(split_artist_credit("A (ft. B) & C"), vec!["A", "B", "C"]);
+        assert_eq!(split_artist_credit("Soft Cell"), vec!["Soft Cell"]);
+        assert_eq!(split_artist_credit("Pink Floyd"), vec!["Pink Floyd"]);
+    }
+
+    #[test]
+    fn clean_path_scores_higher_than_bare_track_number() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let clean = touch(dir.path(), "Artist/Album (2020)/01 - Title.flac");
+        let bare = touch(dir.path(), "01.mp3");
+
+        let clean = FilenameHeuristicsService
+            .parse_filename(&clean, Some("Artist"), Some("Album (2020)"))
+            .expect("clean path should parse");
+        let bare = FilenameHeuristicsService
+            .parse_filename(&bare, None, None)
+            .expect("bare track number should parse");
+
+        assert_eq!(bare.track_number, Some(1));
+        assert!(bare.title.is_none());
+        assert!(bare.confidence < 0.3, "bare: {}", bare.confidence);
+        assert!(clean.confidence > 0.8, "clean: {}", clean.confidence);
+    }
+
+    #[test]
+    fn delimited_filename_scores_higher_than_space_separated() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let delimited = touch(dir.path(), "Pink Floyd - 07 - Run Like Hell.flac");
+        let messy = touch(dir.path(), "07 Run Like Hell.flac");
+
+        let delimited = FilenameHeuristicsService
+            .parse_filename(&delimited, None, None)
+            .expect("delimited filename should parse");
+        let messy = FilenameHeuristicsService
+            .parse_filename(&messy, None, None)
+            .expect("space separated filename should parse");
+
+        assert!(delimited.confidence > messy.confidence);
+    }
+
+    #[test]
+    fn confidence_stays_within_unit_range() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let inputs = [
+            (
+                "CD2/1-05 - Title.flac",
+                Some("Artist"),
+                Some("Album (1999)"),
+            ),
+            (
+                "Artist - Album [2001] - 01 - Title.flac",
+                Some("A"),
+                Some("B"),
+            ),
+            ("07 Title.flac", None, None),
+            ("3.flac", None, None),
+        ];
+
+        for (relative, artist, album) in inputs {
+            let path = touch(dir.path(), relative);
+            let parsed = FilenameHeuristicsService
+                .parse_filename(&path, artist, album)
+                .expect("filename should parse");
+            assert!(
+                (0.0..=1.0).contains(&parsed.confidence),
+                "{relative}: {}",
+                parsed.confidence
+            );
+        }
+    }
 }
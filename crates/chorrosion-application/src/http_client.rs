@@ -1,36 +1,99 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
-use reqwest::Client;
+use chorrosion_config::HttpConfig;
+use reqwest::{Client, ClientBuilder, Proxy};
 use std::time::Duration;
 
 const DEFAULT_HTTP_TIMEOUT_SECONDS: u64 = 30;
 
-fn default_user_agent() -> &'static str {
+pub(crate) fn default_user_agent() -> &'static str {
     concat!(
-        "chorrosion/",
+        "Chorrosion/",
         env!("CARGO_PKG_VERSION"),
         " (+https://github.com/SvetaKrava/chorrosion)"
     )
 }
 
-fn build_http_client_with_timeout(timeout: Duration) -> Client {
-    Client::builder()
-        .user_agent(default_user_agent())
-        .timeout(timeout)
-        .build()
-        .unwrap_or_else(|error| {
-            tracing::debug!(
-                target: "application",
-                ?error,
-                "Failed to build HTTP client with custom settings, falling back to default"
-            );
-            Client::new()
-        })
+/// Settings every outbound HTTP client is built with, taken from `http.proxy_url`
+/// and `http.user_agent`.
+///
+/// Hand it, or a client built from it, to anything that talks to another service
+/// so indexers, download clients, notifications and metadata providers all
+/// leave through the same proxy with the same User-Agent.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OutboundHttp {
+    proxy_url: Option<String>,
+    user_agent: Option<String>,
 }
 
-/// Builds a `reqwest::Client` configured with the chorrosion user-agent and a 30-second timeout.
-/// Falls back to a default `Client` if the builder fails.
-pub(crate) fn build_http_client() -> Client {
-    build_http_client_with_timeout(Duration::from_secs(DEFAULT_HTTP_TIMEOUT_SECONDS))
+impl OutboundHttp {
+    pub fn from_config(config: &HttpConfig) -> Self {
+        Self {
+            proxy_url: config.proxy_url.clone(),
+            user_agent: config.user_agent.clone(),
+        }
+    }
+
+    /// The proxy outbound requests go through, if one is configured.
+    pub fn proxy_url(&self) -> Option<&str> {
+        self.proxy_url.as_deref()
+    }
+
+    /// The User-Agent outbound requests send: the configured one or the chorrosion default.
+    pub fn user_agent(&self) -> &str {
+        self.user_agent.as_deref().unwrap_or(default_user_agent())
+    }
+
+    /// A client builder with the proxy and User-Agent applied, for callers that
+    /// need further settings such as a cookie store.
+    pub fn client_builder(&self) -> ClientBuilder {
+        let builder = Client::builder().user_agent(self.user_agent());
+        match self.proxy() {
+            Some(proxy) => builder.proxy(proxy),
+            None => builder,
+        }
+    }
+
+    /// A client with the proxy and User-Agent applied that gives up on requests
+    /// after `timeout`. Falls back to a default `Client` if the builder fails.
+    pub fn build_client(&self, timeout: Duration) -> Client {
+        build_http_client_with(timeout, self.user_agent(), self.proxy())
+    }
+
+    /// [`Self::build_client`] with the default 30-second timeout.
+    pub fn default_client(&self) -> Client {
+        self.build_client(Duration::from_secs(DEFAULT_HTTP_TIMEOUT_SECONDS))
+    }
+
+    fn proxy(&self) -> Option<Proxy> {
+        let proxy_url = self.proxy_url.as_deref()?;
+        // `AppConfig::validate` only admits http(s) URLs, so this drops a proxy
+        // only if reqwest still refuses one of those.
+        Proxy::all(proxy_url)
+            .map_err(|error| {
+                tracing::warn!(
+                    target: "application",
+                    %error,
+                    "ignoring invalid outbound proxy URL"
+                );
+            })
+            .ok()
+    }
+}
+
+fn build_http_client_with(timeout: Duration, user_agent: &str, proxy: Option<Proxy>) -> Client {
+    let builder = Client::builder().user_agent(user_agent).timeout(timeout);
+    let builder = match proxy {
+        Some(proxy) => builder.proxy(proxy),
+        None => builder,
+    };
+    builder.build().unwrap_or_else(|error| {
+        tracing::debug!(
+            target: "application",
+            ?error,
+            "Failed to build HTTP client with custom settings, falling back to default"
+        );
+        Client::new()
+    })
 }
 
 #[cfg(test)]
@@ -73,7 +136,7 @@ mod tests {
                 .expect("socket write should succeed");
         });
 
-        let client = build_http_client();
+        let client = OutboundHttp::default().default_client();
         let response = client
             .get(format!("http://{addr}/ua"))
             .send()
@@ -107,7 +170,7 @@ mod tests {
             tokio::time::sleep(Duration::from_secs(5)).await;
         });
 
-        let client = build_http_client_with_timeout(Duration::from_millis(50));
+        let client = OutboundHttp::default().build_client(Duration::from_millis(50));
         let error = client
             .get(format!("http://{addr}/timeout"))
             .send()
@@ -116,4 +179,109 @@ mod tests {
 
         assert!(error.is_timeout(), "expected timeout error, got: {error}");
     }
+
+    /// Accepts one connection, answers `200 ok`, and returns the request head it received.
+    async fn capture_one_request(listener: tokio::net::TcpListener) -> String {
+        let (mut socket, _) = listener.accept().await.expect("accept should succeed");
+        let mut buffer = Vec::new();
+        let mut chunk = [0_u8; 4096];
+        loop {
+            let read = socket
+                .read(&mut chunk)
+                .await
+                .expect("socket read should succeed");
+            buffer.extend_from_slice(&chunk[..read]);
+            if read == 0 || buffer.windows(4).any(|w| w == b"\r\n\r\n") {
+                break;
+            }
+        }
+        socket
+            .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok")
+            .await
+            .expect("socket write should succeed");
+        String::from_utf8_lossy(&buffer).to_string()
+    }
+
+    #[tokio::test]
+    async fn build_http_client_routes_through_proxy() {
+        let proxy = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("listener should bind");
+        let proxy_addr = proxy
+            .local_addr()
+            .expect("listener should expose local address");
+        let captured = tokio::spawn(capture_one_request(proxy));
+
+        let outbound = OutboundHttp::from_config(&HttpConfig {
+            proxy_url: Some(format!("http://{proxy_addr}")),
+            ..HttpConfig::default()
+        });
+        let client = outbound.build_client(Duration::from_secs(5));
+        let response = client
+            .get("http://musicbrainz.invalid/ws/2/artist")
+            .send()
+            .await
+            .expect("request should reach the proxy");
+        assert!(response.status().is_success());
+
+        let request = captured.await.expect("proxy task should finish");
+        assert!(
+            request.starts_with("GET http://musicbrainz.invalid/ws/2/artist HTTP/1.1"),
+            "expected an absolute-form proxy request, got: {request}"
+        );
+    }
+
+    #[tokio::test]
+    async fn build_http_client_without_proxy_connects_directly() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("listener should bind");
+        let addr = listener
+            .local_addr()
+            .expect("listener should expose local address");
+        let captured = tokio::spawn(capture_one_request(listener));
+
+        let client = OutboundHttp::default().build_client(Duration::from_secs(5));
+        client
+            .get(format!("http://{addr}/direct"))
+            .send()
+            .await
+            .expect("request should complete");
+
+        let request = captured.await.expect("server task should finish");
+        assert!(
+            request.starts_with("GET /direct HTTP/1.1"),
+            "expected an origin-form request, got: {request}"
+        );
+    }
+
+    #[tokio::test]
+    async fn build_http_client_sends_configured_user_agent() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("listener should bind");
+        let addr = listener
+            .local_addr()
+            .expect("listener should expose local address");
+        let captured = tokio::spawn(capture_one_request(listener));
+
+        let outbound = OutboundHttp::from_config(&HttpConfig {
+            user_agent: Some("MyTracker-Friendly/2.0 (ops@example.com)".to_string()),
+            ..HttpConfig::default()
+        });
+        let client = outbound.build_client(Duration::from_secs(5));
+        client
+            .get(format!("http://{addr}/ua"))
+            .send()
+            .await
+            .expect("request should complete");
+
+        let request = captured.await.expect("server task should finish");
+        assert!(
+            request
+                .to_ascii_lowercase()
+                .contains("user-agent: mytracker-friendly/2.0 (ops@example.com)"),
+            "expected the configured user-agent, got: {request}"
+        );
+    }
 }
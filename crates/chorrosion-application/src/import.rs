@@ -4,14 +4,37 @@
 //! - Reading file metadata (duration, size, codec, etc.)
 //! - Generating Chromaprint fingerprints for matching
 //! - Creating TrackFile entities with fingerprint data
+//! - Transferring files into the library by copy, move, or hardlink
+//! - Rejecting or replacing files according to the artist's quality profile
+//! - Scanning a directory and matching each audio file to a catalog track
 //!
 //! Note: This service creates TrackFile entities but does not persist them.
 //! The caller is responsible for saving entities via the TrackFileRepository.
 
-use chorrosion_domain::{TrackFile, TrackId};
+use crate::audio_probe::probe_audio;
+use crate::embedded_tags::EmbeddedTagMatchingService;
+use crate::file_organization::{build_organized_file_path, TrackPathContext};
+use crate::import_matching::{
+    parse_track_metadata, resolve_track_file_quality, scan_audio_files, AudioExtensions,
+    ImportMatchingError, ParsedTrackMetadata, RawTrackMetadata,
+};
+use crate::quality_upgrade::{
+    resolve_parsed_quality, QualityComparer, QualityUpgradeService, UpgradeDecision,
+};
+use crate::release_parsing::{parse_release_title, AudioQuality};
+pub use chorrosion_config::ImportMode;
+use chorrosion_domain::{
+    ensure_within_any_root, Album, Artist, QualityProfile, Track, TrackFile, TrackId,
+};
 use chorrosion_fingerprint::{AcoustidClient, FingerprintGenerator};
+use chorrosion_infrastructure::repositories::{
+    AlbumRepository, ArtistRepository, QualityProfileRepository, TrackFileRepository,
+    TrackRepository,
+};
 use chrono::Utc;
-use std::path::Path;
+use std::future::Future;
+use std::io;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use thiserror::Error;
 use tracing::Instrument as _;
@@ -35,9 +58,47 @@ pub enum ImportError {
     #[error("File does not exist: {0}")]
     FileNotFound(String),
 
+    /// Failed to copy, move, or link the file into the library
+    #[error("Failed to transfer {source_path} to {destination}: {message}")]
+    TransferError {
+        source_path: String,
+        destination: String,
+        message: String,
+    },
+
+    /// The incoming file's quality is not in the quality profile
+    #[error("Quality {quality} is not allowed by quality profile '{profile}'")]
+    QualityNotAllowed { quality: String, profile: String },
+
+    /// The incoming file does not improve on the existing file
+    #[error("{candidate_quality} is not an upgrade over existing {existing_quality}")]
+    NotAnUpgrade {
+        existing_quality: String,
+        candidate_quality: String,
+    },
+
+    /// The existing file already meets the profile cutoff and upgrades are disabled
+    #[error("Existing {existing_quality} already meets cutoff {cutoff} and upgrades are disabled")]
+    CutoffMet {
+        existing_quality: String,
+        cutoff: String,
+    },
+
+    /// Destination resolves outside every configured library root
+    #[error("Destination is outside the library: {0}")]
+    OutsideLibrary(String),
+
+    /// File exists but could not be parsed as audio
+    #[error("File is not decodable audio: {0}")]
+    Undecodable(String),
+
     /// Spawned import task panicked or was cancelled by the runtime
     #[error("Import task failed unexpectedly: {0}")]
     TaskFailed(String),
+
+    /// Directory scanning needs a catalog to match files against
+    #[error("No track catalog configured for directory scans")]
+    NoCatalog,
 }
 
 /// Result type for import operations.
@@ -54,6 +115,78 @@ pub struct ImportedFile {
 
     /// Whether a fingerprint was successfully generated
     pub has_fingerprint: bool,
+
+    /// The existing file this import replaces as a quality upgrade, if any.
+    /// The caller is responsible for retiring it from disk.
+    pub replaced: Option<TrackFile>,
+}
+
+/// What happened to one file of a batch import.
+#[derive(Debug)]
+pub enum ImportOutcome {
+    Imported(Box<ImportedFile>),
+    /// Not imported, deliberately; e.g. a duplicate of another file in the batch
+    /// or a file the library already tracks.
+    Skipped {
+        path: String,
+        reason: String,
+    },
+    Failed {
+        path: String,
+        error: ImportError,
+    },
+}
+
+/// Per-file outcomes of a batch import, in input order, with aggregate counts.
+#[derive(Debug, Default)]
+pub struct ImportBatchSummary {
+    pub outcomes: Vec<ImportOutcome>,
+    pub imported: usize,
+    pub skipped: usize,
+    pub failed: usize,
+}
+
+impl ImportBatchSummary {
+    fn push(&mut self, outcome: ImportOutcome) {
+        match outcome {
+            ImportOutcome::Imported(_) => self.imported += 1,
+            ImportOutcome::Skipped { .. } => self.skipped += 1,
+            ImportOutcome::Failed { .. } => self.failed += 1,
+        }
+        self.outcomes.push(outcome);
+    }
+}
+
+/// Upper bound on the tracks of one album considered when matching a file.
+const MAX_ALBUM_TRACKS: i64 = 5000;
+
+/// Folder, below the artist path, that scanned files are imported into.
+const ALBUM_FOLDER_PATTERN: &str = "{album}";
+/// File name of a scanned file imported for a numbered track.
+const NUMBERED_TRACK_PATTERN: &str = "{track:02} - {title}";
+/// File name of a scanned file imported for a track without a number.
+const UNNUMBERED_TRACK_PATTERN: &str = "{title}";
+
+/// One file of a batch import and, for directory scans, where it goes in the library.
+struct BatchEntry {
+    path: String,
+    track_id: TrackId,
+    destination: Option<PathBuf>,
+}
+
+/// A catalog track matched by a directory scan, with its album and artist.
+struct CatalogTrack {
+    artist: Artist,
+    album: Album,
+    track: Track,
+}
+
+/// Repositories a directory scan matches files against.
+#[derive(Clone)]
+struct TrackCatalog {
+    artists: Arc<dyn ArtistRepository>,
+    albums: Arc<dyn AlbumRepository>,
+    tracks: Arc<dyn TrackRepository>,
 }
 
 /// Service for importing audio files with fingerprint generation.
@@ -65,6 +198,18 @@ pub struct FileImportService {
     /// Maximum number of files processed concurrently in a batch import.
     /// Validated to be >= 1 at construction time.
     max_concurrent_imports: usize,
+    /// How files are placed at their library destination by `import_file_to`.
+    import_mode: ImportMode,
+    /// Destinations of `import_file_to` must resolve inside one of these; empty disables the check.
+    library_roots: Vec<PathBuf>,
+    /// Extensions `scan_directory` treats as audio.
+    audio_extensions: AudioExtensions,
+    /// Catalog `scan_directory` matches files against; scans fail without one.
+    catalog: Option<TrackCatalog>,
+    /// Track files `import_batch` checks paths against to skip re-imports.
+    track_files: Option<Arc<dyn TrackFileRepository>>,
+    /// Profiles `import_batch` checks each file against, via the track's artist.
+    quality_profiles: Option<Arc<dyn QualityProfileRepository>>,
 }
 
 impl FileImportService {
@@ -80,7 +225,133 @@ impl FileImportService {
         Self {
             acoustid_client,
             max_concurrent_imports,
+            import_mode: ImportMode::default(),
+            library_roots: Vec::new(),
+            audio_extensions: AudioExtensions::default(),
+            catalog: None,
+            track_files: None,
+            quality_profiles: None,
+        }
+    }
+
+    /// Set how files are placed into the library by `import_file_to`.
+    pub fn with_import_mode(mut self, import_mode: ImportMode) -> Self {
+        self.import_mode = import_mode;
+        self
+    }
+
+    /// Confine `import_file_to` destinations to `library_roots`, e.g. `library.root_paths`.
+    pub fn with_library_roots(mut self, library_roots: Vec<PathBuf>) -> Self {
+        self.library_roots = library_roots;
+        self
+    }
+
+    /// Limit `scan_directory` to files with these extensions, e.g. `library.audio_extensions`.
+    pub fn with_audio_extensions(mut self, audio_extensions: AudioExtensions) -> Self {
+        self.audio_extensions = audio_extensions;
+        self
+    }
+
+    /// Match files found by `scan_directory` against these repositories.
+    pub fn with_catalog(
+        mut self,
+        artists: Arc<dyn ArtistRepository>,
+        albums: Arc<dyn AlbumRepository>,
+        tracks: Arc<dyn TrackRepository>,
+    ) -> Self {
+        self.catalog = Some(TrackCatalog {
+            artists,
+            albums,
+            tracks,
+        });
+        self
+    }
+
+    /// Skip batch files whose path `track_files` already records.
+    pub fn with_track_files(mut self, track_files: Arc<dyn TrackFileRepository>) -> Self {
+        self.track_files = Some(track_files);
+        self
+    }
+
+    /// Import batch files subject to their artist's quality profile from `quality_profiles`.
+    ///
+    /// Takes effect together with [`with_catalog`](Self::with_catalog), which resolves
+    /// each track's artist, and [`with_track_files`](Self::with_track_files), which
+    /// supplies the track's existing file.
+    pub fn with_quality_profiles(
+        mut self,
+        quality_profiles: Arc<dyn QualityProfileRepository>,
+    ) -> Self {
+        self.quality_profiles = Some(quality_profiles);
+        self
+    }
+
+    /// The configured import mode.
+    pub fn import_mode(&self) -> ImportMode {
+        self.import_mode
+    }
+
+    /// Transfer a file to its library destination using the configured
+    /// [`ImportMode`], then import it from there.
+    ///
+    /// The resulting `TrackFile` always records `destination` as its path,
+    /// whichever mode was used. Missing parent directories are created, and
+    /// embedded artwork is written as `cover.jpg`/`cover.png` when the
+    /// destination folder has no artwork yet.
+    #[tracing::instrument(
+        skip(self),
+        fields(
+            source = %source.as_ref().display(),
+            destination = %destination.as_ref().display(),
+            mode = ?self.import_mode,
+        )
+    )]
+    pub async fn import_file_to(
+        &self,
+        source: impl AsRef<Path>,
+        destination: impl AsRef<Path>,
+        track_id: TrackId,
+    ) -> ImportResult<ImportedFile> {
+        let source = source.as_ref();
+        let destination = destination.as_ref();
+
+        if !tokio::fs::try_exists(source).await.unwrap_or(false) {
+            return Err(ImportError::FileNotFound(source.display().to_string()));
+        }
+
+        if !self.library_roots.is_empty() {
+            ensure_within_any_root(&self.library_roots, destination)
+                .map_err(|e| ImportError::OutsideLibrary(e.to_string()))?;
+        }
+
+        if let Some(parent) = destination.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| transfer_error(source, destination, e))?;
+        }
+
+        match self.import_mode {
+            ImportMode::Copy => copy_file(source, destination).await?,
+            ImportMode::Move => move_file(source, destination).await?,
+            ImportMode::Hardlink => {
+                hard_link_or_copy(source, destination, tokio::fs::hard_link).await?
+            }
         }
+
+        // Embedded artwork is often the best cover source; keep it as folder
+        // art when the album folder has none. Failures never block the import.
+        match EmbeddedTagMatchingService
+            .write_folder_artwork(destination)
+            .await
+        {
+            Ok(Some(cover)) => {
+                tracing::info!(cover = %cover.display(), "Wrote embedded artwork as folder art")
+            }
+            Ok(None) => {}
+            Err(e) => tracing::debug!(error = %e, "Could not extract embedded artwork"),
+        }
+
+        self.import_file(destination, track_id).await
     }
 
     /// Import a single audio file, generating its fingerprint.
@@ -121,6 +392,20 @@ impl FileImportService {
         // Create initial TrackFile entity
         let mut track_file = TrackFile::new(track_id, path.display().to_string(), size_bytes);
 
+        // Read codec, bitrate, channels and duration from the stream headers
+        match probe_audio(path).await {
+            Ok(properties) => {
+                track_file.codec = Some(properties.codec);
+                track_file.bitrate_kbps = properties.bitrate_kbps;
+                track_file.channels = properties.channels;
+                track_file.duration_ms = properties.duration_ms;
+            }
+            Err(e) => {
+                // Technical properties are optional, like the fingerprint
+                tracing::warn!(error = %e, "Failed to probe audio properties, continuing without them");
+            }
+        }
+
         let mut has_fingerprint = false;
 
         // Generate fingerprint
@@ -149,9 +434,43 @@ impl FileImportService {
             track_file,
             was_created: true,
             has_fingerprint,
+            replaced: None,
         })
     }
 
+    /// Import a file subject to the artist's quality profile.
+    ///
+    /// The incoming quality is parsed from the file path (folder and file
+    /// names) and resolved against `profile.allowed_qualities`. When the track
+    /// already has a file, the import only proceeds if the candidate is an
+    /// upgrade according to the profile's ranking, `cutoff_quality` and
+    /// `upgrade_allowed`; the returned `TrackFile` then keeps the existing
+    /// file's ID so the caller updates it in place. An existing file whose
+    /// quality is not in the profile is always replaceable.
+    ///
+    /// # Arguments
+    /// * `path` - Path to the audio file to import
+    /// * `track_id` - The track this file belongs to
+    /// * `profile` - The quality profile referenced by the artist's `quality_profile_id`
+    /// * `existing` - The track's current file, if any
+    #[tracing::instrument(
+        skip(self, profile, existing),
+        fields(path = %path.as_ref().display(), profile = %profile.name)
+    )]
+    pub async fn import_file_with_profile(
+        &self,
+        path: impl AsRef<Path>,
+        track_id: TrackId,
+        profile: &QualityProfile,
+        existing: Option<&TrackFile>,
+    ) -> ImportResult<ImportedFile> {
+        let path = path.as_ref();
+        let candidate_quality = check_import_quality(path, profile, existing)?;
+
+        let imported = self.import_file(path, track_id).await?;
+        Ok(replace_existing(imported, candidate_quality, existing))
+    }
+
     /// Import multiple files in batch, processing up to `max_concurrent_imports` concurrently.
     ///
     /// Permits are acquired *before* spawning each task so the number of live Tokio tasks is
@@ -161,30 +480,78 @@ impl FileImportService {
     /// Spawned tasks are instrumented with the caller's tracing span so per-file log lines
     /// remain correlated with the batch.
     ///
+    /// Unlike [`import_file`](Self::import_file), each file must decode as audio. A path
+    /// listed earlier in `files`, or already recorded by the repository set with
+    /// [`with_track_files`](Self::with_track_files), is skipped before any decoding or
+    /// fingerprinting. A file whose Chromaprint fingerprint matches one earlier in `files`
+    /// is skipped as a duplicate too. When the track's artist has a quality profile (see
+    /// [`with_quality_profiles`](Self::with_quality_profiles)) the file goes through
+    /// [`import_file_with_profile`](Self::import_file_with_profile), and files the profile
+    /// rejects are skipped. One file failing never aborts the others.
+    ///
     /// # Arguments
     /// * `files` - Collection of (path, track_id) tuples to import
     ///
     /// # Returns
-    /// A tuple of (successes, failures) where successes are ImportedFile entries
-    /// and failures are (path, error) tuples.  The sum `successes + failures` always
-    /// equals `files.len()`.
+    /// An [`ImportBatchSummary`] with exactly one outcome per input file, in input order.
     #[tracing::instrument(skip(self, files), fields(count = files.len()))]
-    pub async fn import_batch(
-        &self,
-        files: Vec<(String, TrackId)>,
-    ) -> (Vec<ImportedFile>, Vec<(String, ImportError)>) {
+    pub async fn import_batch(&self, files: Vec<(String, TrackId)>) -> ImportBatchSummary {
+        let entries = files
+            .into_iter()
+            .map(|(path, track_id)| BatchEntry {
+                path,
+                track_id,
+                destination: None,
+            })
+            .collect();
+        self.import_entries(entries).await
+    }
+
+    /// [`import_batch`](Self::import_batch) for entries that may carry a library
+    /// destination; two entries bound for the same destination are duplicates.
+    async fn import_entries(&self, files: Vec<BatchEntry>) -> ImportBatchSummary {
+        use std::collections::HashMap;
         use tokio::sync::Semaphore;
         use tokio::task::JoinSet;
 
         let total = files.len();
         let semaphore = Arc::new(Semaphore::new(self.max_concurrent_imports));
-        // Task return type always includes the path so join errors can be attributed.
-        let mut set: JoinSet<(String, Result<ImportedFile, ImportError>)> = JoinSet::new();
+        let mut set: JoinSet<(usize, ImportResult<ImportedFile>)> = JoinSet::new();
+        // Panicked tasks lose their return value, so remember which file each task was for.
+        let mut task_files = HashMap::with_capacity(total);
+        let mut results: Vec<Option<ImportResult<ImportedFile>>> =
+            (0..total).map(|_| None).collect();
+        let mut skipped: Vec<Option<String>> = (0..total).map(|_| None).collect();
+        let mut seen_paths: HashMap<String, usize> = HashMap::new();
+        let paths: Vec<String> = files.iter().map(|entry| entry.path.clone()).collect();
 
-        let mut successes = Vec::new();
-        let mut failures = Vec::new();
+        for (index, entry) in files.into_iter().enumerate() {
+            // Files headed for the same library path collide just like repeated sources.
+            let target = entry
+                .destination
+                .as_ref()
+                .map_or_else(|| entry.path.clone(), |d| d.display().to_string());
+            if let Some(&first) = seen_paths.get(&target) {
+                skipped[index] = Some(format!("duplicate of {}", paths[first]));
+                continue;
+            }
+            seen_paths.insert(target, index);
+            // A file already recorded at the destination is not skipped here: an
+            // upgrade lands on the same library path, and the quality profile
+            // rejects re-imports of the same release.
+            match self.existing_track_file(&entry.path).await {
+                Ok(Some(existing)) => {
+                    skipped[index] =
+                        Some(format!("already imported as track file {}", existing.id));
+                    continue;
+                }
+                Ok(None) => {}
+                Err(error) => {
+                    results[index] = Some(Err(error));
+                    continue;
+                }
+            }
 
-        for (path, track_id) in files {
             // Acquire the permit *before* spawning so we only create tasks when capacity
             // is available, keeping the number of in-flight Tokio tasks bounded.
             // The semaphore is created locally and never explicitly closed, so acquire_owned()
@@ -198,46 +565,241 @@ impl FileImportService {
             // Propagate the current span into the spawned task so per-file logs are
             // correlated with the batch span.
             let span = tracing::Span::current();
-            set.spawn(
+            let handle = set.spawn(
                 async move {
                     let _permit = permit;
-                    let result = service.import_file(&path, track_id).await;
-                    (path, result)
+                    let result = service.import_batch_entry(entry).await;
+                    (index, result)
                 }
                 .instrument(span),
             );
+            task_files.insert(handle.id(), index);
         }
 
-        loop {
-            let joined = set.join_next().await;
-            let Some(result) = joined else {
-                break;
-            };
-            match result {
-                Ok((_path, Ok(imported))) => successes.push(imported),
-                Ok((path, Err(error))) => failures.push((path, error)),
+        while let Some(joined) = set.join_next().await {
+            match joined {
+                Ok((index, result)) => results[index] = Some(result),
                 Err(join_err) => {
-                    // Task panicked or was cancelled by the runtime; when a task panics,
-                    // JoinSet::join_next() returns Err(JoinError) and the task's return
-                    // value (which held the path) is lost.  We still record a failure entry
-                    // to preserve the `successes + failures == total` invariant.
                     tracing::warn!(error = %join_err, "import task panicked unexpectedly");
-                    failures.push((
-                        "<unknown>".to_string(),
-                        ImportError::TaskFailed(join_err.to_string()),
-                    ));
+                    if let Some(&index) = task_files.get(&join_err.id()) {
+                        results[index] = Some(Err(ImportError::TaskFailed(join_err.to_string())));
+                    }
                 }
             }
         }
 
+        let mut summary = ImportBatchSummary::default();
+        let mut seen_fingerprints: HashMap<String, usize> = HashMap::new();
+        for (index, ((path, result), skip_reason)) in
+            paths.into_iter().zip(results).zip(skipped).enumerate()
+        {
+            if let Some(reason) = skip_reason {
+                summary.push(ImportOutcome::Skipped { path, reason });
+                continue;
+            }
+            let result = result.unwrap_or_else(|| {
+                Err(ImportError::TaskFailed("import task did not report".into()))
+            });
+            let outcome = match result {
+                Ok(imported) => {
+                    let first = imported
+                        .track_file
+                        .fingerprint_hash
+                        .as_ref()
+                        .and_then(|hash| match seen_fingerprints.get(hash) {
+                            Some(&first) => Some(first),
+                            None => {
+                                seen_fingerprints.insert(hash.clone(), index);
+                                None
+                            }
+                        });
+                    match first {
+                        Some(first) => ImportOutcome::Skipped {
+                            reason: format!("duplicate of {}", summary_path(&summary, first)),
+                            path,
+                        },
+                        None => ImportOutcome::Imported(Box::new(imported)),
+                    }
+                }
+                Err(
+                    error @ (ImportError::QualityNotAllowed { .. }
+                    | ImportError::NotAnUpgrade { .. }
+                    | ImportError::CutoffMet { .. }),
+                ) => ImportOutcome::Skipped {
+                    reason: error.to_string(),
+                    path,
+                },
+                Err(error) => ImportOutcome::Failed { path, error },
+            };
+            summary.push(outcome);
+        }
+
         tracing::info!(
-            successes = successes.len(),
-            failures = failures.len(),
+            imported = summary.imported,
+            skipped = summary.skipped,
+            failed = summary.failed,
             total,
             "Batch import completed"
         );
 
-        (successes, failures)
+        summary
+    }
+
+    /// Import every audio file below `dir`, matching each to a catalog track.
+    ///
+    /// Hidden files and directories and files without an audio extension are
+    /// ignored. Artist, album and title come from embedded tags, falling back
+    /// to filename heuristics, and are matched case-insensitively against the
+    /// catalog set with [`with_catalog`](Self::with_catalog). Files that cannot
+    /// be identified, have no matching track, or whose artist has no path are
+    /// skipped; matched files go through [`import_batch`](Self::import_batch) and
+    /// are placed below the artist path as `<album>/<track> - <title>` using the
+    /// configured [`ImportMode`].
+    ///
+    /// # Returns
+    /// One outcome per audio file, ordered by path.
+    #[tracing::instrument(skip(self), fields(dir = %dir.display()))]
+    pub async fn scan_directory(&self, dir: &Path) -> ImportResult<Vec<ImportOutcome>> {
+        let catalog = self.catalog.as_ref().ok_or(ImportError::NoCatalog)?;
+
+        let owned = dir.to_path_buf();
+        let extensions = self.audio_extensions.clone();
+        let scanned = tokio::task::spawn_blocking(move || scan_audio_files(owned, &extensions))
+            .await
+            .map_err(|e| ImportError::TaskFailed(e.to_string()))?
+            .map_err(|e| match e {
+                ImportMatchingError::PathNotFound(path) => ImportError::FileNotFound(path),
+                other => ImportError::MetadataError(other.to_string()),
+            })?;
+
+        let mut outcomes: Vec<Option<ImportOutcome>> = Vec::with_capacity(scanned.len());
+        let mut matched = Vec::new();
+        let mut matched_indices = Vec::new();
+        for file in scanned {
+            let path = file.path.display().to_string();
+            let outcome = match identify_file(&file.path).await {
+                Ok(metadata) => match catalog.find_track(&metadata).await? {
+                    Some(found) => match library_destination(&found, &file.path) {
+                        Ok(destination) => {
+                            matched_indices.push(outcomes.len());
+                            matched.push(BatchEntry {
+                                path,
+                                track_id: found.track.id,
+                                destination: Some(destination),
+                            });
+                            None
+                        }
+                        Err(reason) => Some(ImportOutcome::Skipped { reason, path }),
+                    },
+                    None => Some(ImportOutcome::Skipped {
+                        reason: format!(
+                            "no catalog track matches {} - {} - {}",
+                            metadata.artist, metadata.album, metadata.title
+                        ),
+                        path,
+                    }),
+                },
+                Err(e) => Some(ImportOutcome::Skipped {
+                    reason: format!("could not identify file: {e}"),
+                    path,
+                }),
+            };
+            outcomes.push(outcome);
+        }
+
+        let summary = self.import_entries(matched).await;
+        for (index, outcome) in matched_indices.into_iter().zip(summary.outcomes) {
+            outcomes[index] = Some(outcome);
+        }
+
+        Ok(outcomes.into_iter().flatten().collect())
+    }
+
+    /// The track file already recorded at `path`, if a repository was configured.
+    async fn existing_track_file(&self, path: &str) -> ImportResult<Option<TrackFile>> {
+        match &self.track_files {
+            Some(track_files) => track_files
+                .get_by_path(path)
+                .await
+                .map_err(|e| ImportError::DatabaseError(e.to_string())),
+            None => Ok(None),
+        }
+    }
+
+    /// Import one batch file after checking that it decodes, applying the
+    /// artist's quality profile when one is assigned.
+    ///
+    /// The profile is checked against the source path, whose release folder names
+    /// the quality, before anything is transferred to `entry.destination`.
+    async fn import_batch_entry(&self, entry: BatchEntry) -> ImportResult<ImportedFile> {
+        let BatchEntry {
+            path,
+            track_id,
+            destination,
+        } = entry;
+        let owned = PathBuf::from(&path);
+        tokio::task::spawn_blocking(move || probe_decodable(&owned))
+            .await
+            .map_err(|e| ImportError::TaskFailed(e.to_string()))??;
+        let checked = match self.quality_profile_for(track_id).await? {
+            Some(profile) => {
+                let existing = self.current_track_file(track_id).await?;
+                let quality = check_import_quality(Path::new(&path), &profile, existing.as_ref())?;
+                Some((quality, existing))
+            }
+            None => None,
+        };
+        let imported = match &destination {
+            Some(destination) => self.import_file_to(&path, destination, track_id).await?,
+            None => self.import_file(&path, track_id).await?,
+        };
+        Ok(match checked {
+            Some((quality, existing)) => replace_existing(imported, quality, existing.as_ref()),
+            None => imported,
+        })
+    }
+
+    /// The quality profile of the artist `track_id` belongs to, if one is assigned
+    /// and the repositories to resolve it were configured.
+    async fn quality_profile_for(&self, track_id: TrackId) -> ImportResult<Option<QualityProfile>> {
+        let (Some(catalog), Some(quality_profiles)) = (&self.catalog, &self.quality_profiles)
+        else {
+            return Ok(None);
+        };
+        let db_error = |e: anyhow::Error| ImportError::DatabaseError(e.to_string());
+        let Some(track) = catalog
+            .tracks
+            .get_by_id(&track_id.to_string())
+            .await
+            .map_err(db_error)?
+        else {
+            return Ok(None);
+        };
+        let profile_id = catalog
+            .artists
+            .get_by_id(&track.artist_id.to_string())
+            .await
+            .map_err(db_error)?
+            .and_then(|artist| artist.quality_profile_id);
+        match profile_id {
+            Some(profile_id) => quality_profiles
+                .get_by_id(&profile_id.to_string())
+                .await
+                .map_err(db_error),
+            None => Ok(None),
+        }
+    }
+
+    /// The file already recorded for `track_id`, if a repository was configured.
+    async fn current_track_file(&self, track_id: TrackId) -> ImportResult<Option<TrackFile>> {
+        match &self.track_files {
+            Some(track_files) => track_files
+                .get_by_track(track_id, 1, 0)
+                .await
+                .map(|files| files.into_iter().next())
+                .map_err(|e| ImportError::DatabaseError(e.to_string())),
+            None => Ok(None),
+        }
     }
 
     /// Generate a Chromaprint fingerprint for an audio file.
@@ -257,6 +819,293 @@ impl FileImportService {
     }
 }
 
+impl TrackCatalog {
+    /// The track titled `metadata.title` on the matching artist's album, if any.
+    async fn find_track(
+        &self,
+        metadata: &ParsedTrackMetadata,
+    ) -> ImportResult<Option<CatalogTrack>> {
+        let db_error = |e: anyhow::Error| ImportError::DatabaseError(e.to_string());
+        let Some(artist) = self
+            .artists
+            .get_by_name(&metadata.artist)
+            .await
+            .map_err(db_error)?
+        else {
+            return Ok(None);
+        };
+        let Some(album) = self
+            .albums
+            .get_by_artist_and_title(artist.id, &metadata.album)
+            .await
+            .map_err(db_error)?
+        else {
+            return Ok(None);
+        };
+        let tracks = self
+            .tracks
+            .get_by_album(album.id, MAX_ALBUM_TRACKS, 0)
+            .await
+            .map_err(db_error)?;
+        Ok(tracks
+            .into_iter()
+            .find(|track| track.title.to_lowercase() == metadata.title.to_lowercase())
+            .map(|track| CatalogTrack {
+                artist,
+                album,
+                track,
+            }))
+    }
+}
+
+/// Artist, album and title of `path` from its tags, or its filename and folders.
+async fn identify_file(path: &Path) -> Result<ParsedTrackMetadata, ImportMatchingError> {
+    let tags = EmbeddedTagMatchingService
+        .extract_tags(path)
+        .await
+        .unwrap_or_default();
+    let raw = RawTrackMetadata {
+        file_path: path.to_path_buf(),
+        embedded_artist: tags.album_artist.or(tags.artist),
+        embedded_album: tags.album,
+        embedded_title: tags.title,
+        duration_seconds: None,
+        bitrate_kbps: None,
+    };
+    parse_track_metadata(&raw).await
+}
+
+/// Where `source` goes below the matched artist's path, or why it cannot be placed.
+fn library_destination(found: &CatalogTrack, source: &Path) -> Result<PathBuf, String> {
+    let artist_path = found
+        .artist
+        .path
+        .as_deref()
+        .ok_or_else(|| format!("artist {} has no path to import into", found.artist.name))?;
+    let context = TrackPathContext {
+        artist: found.artist.name.clone(),
+        album: found.album.title.clone(),
+        title: found.track.title.clone(),
+        extension: source
+            .extension()
+            .map(|extension| extension.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+        track_number: found.track.track_number,
+        disc_number: None,
+    };
+    let file_pattern = if context.track_number.is_some() {
+        NUMBERED_TRACK_PATTERN
+    } else {
+        UNNUMBERED_TRACK_PATTERN
+    };
+    build_organized_file_path(
+        Path::new(artist_path),
+        ALBUM_FOLDER_PATTERN,
+        file_pattern,
+        &context,
+    )
+    .map_err(|e| format!("could not build a library path: {e}"))
+}
+
+/// Record `quality` on `imported` and, when the track already had a file, make
+/// the import an in-place update of that file's record.
+fn replace_existing(
+    mut imported: ImportedFile,
+    quality: String,
+    existing: Option<&TrackFile>,
+) -> ImportedFile {
+    imported.track_file.quality = Some(quality);
+    if let Some(existing) = existing {
+        imported.track_file.id = existing.id;
+        imported.track_file.created_at = existing.created_at;
+        imported.track_file.updated_at = existing.updated_at;
+        imported.was_created = false;
+        imported.replaced = Some(existing.clone());
+    }
+    imported
+}
+
+/// Path of the already-recorded outcome at `index`.
+fn summary_path(summary: &ImportBatchSummary, index: usize) -> &str {
+    match &summary.outcomes[index] {
+        ImportOutcome::Imported(imported) => &imported.track_file.path,
+        ImportOutcome::Skipped { path, .. } | ImportOutcome::Failed { path, .. } => path,
+    }
+}
+
+/// Check that `path` exists and parses as audio.
+fn probe_decodable(path: &Path) -> ImportResult<()> {
+    std::fs::metadata(path).map_err(|e| {
+        if e.kind() == io::ErrorKind::NotFound {
+            ImportError::FileNotFound(path.display().to_string())
+        } else {
+            ImportError::MetadataError(e.to_string())
+        }
+    })?;
+    lofty::read_from_path(path).map_err(|e| ImportError::Undecodable(e.to_string()))?;
+    Ok(())
+}
+
+/// Decide whether a file may be imported under `profile`, returning its
+/// resolved quality label.
+fn check_import_quality(
+    path: &Path,
+    profile: &QualityProfile,
+    existing: Option<&TrackFile>,
+) -> ImportResult<String> {
+    // Only the release folder and file name describe the quality; earlier
+    // path components (library roots, temp dirs) can contain stray tokens.
+    let release_name: PathBuf = path
+        .iter()
+        .skip(path.iter().count().saturating_sub(2))
+        .collect();
+    let parsed = parse_release_title(&release_name.to_string_lossy());
+    let candidate_quality = resolve_parsed_quality(&parsed.quality, parsed.bitrate_kbps, profile)
+        .ok_or_else(|| ImportError::QualityNotAllowed {
+        quality: describe_quality(&parsed.quality, parsed.bitrate_kbps),
+        profile: profile.name.clone(),
+    })?;
+
+    let Some(existing) = existing else {
+        return Ok(candidate_quality);
+    };
+
+    let Some(existing_quality) = resolve_track_file_quality(existing, profile) else {
+        tracing::info!(
+            candidate_quality = %candidate_quality,
+            "Existing file quality is not in the profile, replacing it"
+        );
+        return Ok(candidate_quality);
+    };
+
+    match QualityUpgradeService::evaluate_upgrade(&existing_quality, &candidate_quality, profile) {
+        UpgradeDecision::Upgrade { reason } => {
+            tracing::info!(
+                existing_quality = %existing_quality,
+                candidate_quality = %candidate_quality,
+                ?reason,
+                "Importing quality upgrade"
+            );
+            Ok(candidate_quality)
+        }
+        UpgradeDecision::Keep => match &profile.cutoff_quality {
+            Some(cutoff)
+                if !profile.upgrade_allowed
+                    && QualityComparer::is_upgrade(
+                        &existing_quality,
+                        &candidate_quality,
+                        profile,
+                    ) =>
+            {
+                Err(ImportError::CutoffMet {
+                    existing_quality,
+                    cutoff: cutoff.clone(),
+                })
+            }
+            _ => Err(ImportError::NotAnUpgrade {
+                existing_quality,
+                candidate_quality,
+            }),
+        },
+    }
+}
+
+fn describe_quality(quality: &AudioQuality, bitrate_kbps: Option<u32>) -> String {
+    let name = quality.as_str().to_ascii_uppercase();
+    match bitrate_kbps {
+        Some(bitrate) if !quality.is_lossless() => format!("{name} {bitrate}"),
+        _ => name,
+    }
+}
+
+fn transfer_error(source: &Path, destination: &Path, error: io::Error) -> ImportError {
+    ImportError::TransferError {
+        source_path: source.display().to_string(),
+        destination: destination.display().to_string(),
+        message: error.to_string(),
+    }
+}
+
+/// Whether a failed link should be retried as a copy: the paths are on
+/// different filesystems, or the filesystem cannot link at all (FAT-style
+/// filesystems report this as a permission error).
+fn is_cross_device_or_unsupported(error: &io::Error) -> bool {
+    matches!(
+        error.kind(),
+        io::ErrorKind::CrossesDevices
+            | io::ErrorKind::Unsupported
+            | io::ErrorKind::PermissionDenied
+    )
+}
+
+async fn copy_file(source: &Path, destination: &Path) -> ImportResult<()> {
+    tokio::fs::copy(source, destination)
+        .await
+        .map(|_| ())
+        .map_err(|e| transfer_error(source, destination, e))
+}
+
+async fn move_file(source: &Path, destination: &Path) -> ImportResult<()> {
+    move_or_copy(source, destination, tokio::fs::rename).await
+}
+
+/// Rename `source` to `destination` with `rename`, moving by copy and delete
+/// only when the paths are on different filesystems.
+///
+/// A failed copy or a source that cannot be deleted removes the destination
+/// again, so the file is never left in both places.
+async fn move_or_copy<F, Fut>(source: &Path, destination: &Path, rename: F) -> ImportResult<()>
+where
+    F: FnOnce(PathBuf, PathBuf) -> Fut,
+    Fut: Future<Output = io::Result<()>>,
+{
+    match rename(source.to_path_buf(), destination.to_path_buf()).await {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == io::ErrorKind::CrossesDevices => {
+            tracing::debug!(error = %e, "rename crosses devices, moving by copy and delete");
+            let moved = match tokio::fs::copy(source, destination).await {
+                Ok(_) => tokio::fs::remove_file(source).await,
+                Err(e) => Err(e),
+            };
+            moved.map_err(|e| {
+                remove_partial_destination(destination);
+                transfer_error(source, destination, e)
+            })
+        }
+        Err(e) => Err(transfer_error(source, destination, e)),
+    }
+}
+
+/// Best-effort removal of a destination left behind by a failed move.
+fn remove_partial_destination(destination: &Path) {
+    if let Err(e) = std::fs::remove_file(destination) {
+        if e.kind() != io::ErrorKind::NotFound {
+            tracing::warn!(
+                destination = %destination.display(),
+                error = %e,
+                "Could not remove destination after a failed move"
+            );
+        }
+    }
+}
+
+/// Hardlink `source` to `destination` with `link`, copying instead when the
+/// link cannot be created across devices or on this filesystem.
+async fn hard_link_or_copy<F, Fut>(source: &Path, destination: &Path, link: F) -> ImportResult<()>
+where
+    F: FnOnce(PathBuf, PathBuf) -> Fut,
+    Fut: Future<Output = io::Result<()>>,
+{
+    match link(source.to_path_buf(), destination.to_path_buf()).await {
+        Ok(()) => Ok(()),
+        Err(e) if is_cross_device_or_unsupported(&e) => {
+            tracing::info!(error = %e, "Hardlink not possible, falling back to copy");
+            copy_file(source, destination).await
+        }
+        Err(e) => Err(transfer_error(source, destination, e)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -295,45 +1144,281 @@ mod tests {
         // Fingerprint will be None since we can't generate for non-audio files
         assert!(!imported.has_fingerprint);
         assert!(imported.track_file.fingerprint_hash.is_none());
+        assert!(imported.track_file.codec.is_none());
     }
 
     #[tokio::test]
-    async fn test_batch_import_handles_mixed_results() {
+    async fn test_import_populates_technical_properties() {
+        use crate::test_fixtures::MINIMAL_MP3;
+
         let service = create_test_service();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("01 - Track.mp3");
+        std::fs::write(&path, MINIMAL_MP3).unwrap();
 
-        let test_file = std::env::current_dir()
-            .unwrap()
-            .join("Cargo.toml")
-            .display()
-            .to_string();
+        let imported = service.import_file(&path, TrackId::new()).await.unwrap();
+
+        assert_eq!(imported.track_file.codec.as_deref(), Some("mp3"));
+        assert_eq!(imported.track_file.channels, Some(2));
+        assert_eq!(imported.track_file.bitrate_kbps, Some(32));
+        assert!(imported.track_file.duration_ms.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_batch_import_reports_each_outcome() {
+        use crate::test_fixtures::MINIMAL_MP3;
+
+        let service = create_test_service();
+        let dir = tempfile::tempdir().unwrap();
+        let good = dir.path().join("01 - Good.mp3");
+        let broken = dir.path().join("02 - Broken.mp3");
+        std::fs::write(&good, MINIMAL_MP3).unwrap();
+        std::fs::write(&broken, b"definitely not audio").unwrap();
+
+        let files = [&good, &good, &broken]
+            .into_iter()
+            .map(|path| (path.display().to_string(), TrackId::new()))
+            .collect();
+        let summary = service.import_batch(files).await;
 
-        let files = vec![
-            (test_file.clone(), TrackId::new()),
-            ("nonexistent.mp3".to_string(), TrackId::new()),
-            (test_file, TrackId::new()),
-        ];
+        assert_eq!(
+            (summary.imported, summary.skipped, summary.failed),
+            (1, 1, 1)
+        );
+        assert!(matches!(
+            &summary.outcomes[0],
+            ImportOutcome::Imported(imported) if imported.track_file.path == good.display().to_string()
+        ));
+        match &summary.outcomes[1] {
+            ImportOutcome::Skipped { path, reason } => {
+                assert_eq!(path, &good.display().to_string());
+                assert!(reason.contains("01 - Good.mp3"), "{reason}");
+            }
+            other => panic!("expected duplicate to be skipped, got {other:?}"),
+        }
+        match &summary.outcomes[2] {
+            ImportOutcome::Failed { path, error } => {
+                assert_eq!(path, &broken.display().to_string());
+                assert!(matches!(error, ImportError::Undecodable(_)), "{error}");
+            }
+            other => panic!("expected undecodable file to fail, got {other:?}"),
+        }
+    }
 
-        let (successes, failures) = service.import_batch(files).await;
+    #[tokio::test]
+    async fn test_batch_import_skips_files_already_in_the_library() {
+        use chorrosion_domain::{Album, Artist, Track};
+        use chorrosion_infrastructure::repositories::Repository;
+        use chorrosion_infrastructure::sqlite_adapters::{
+            SqliteAlbumRepository, SqliteArtistRepository, SqliteTrackFileRepository,
+            SqliteTrackRepository,
+        };
 
-        assert_eq!(successes.len(), 2);
-        assert_eq!(failures.len(), 1);
-        assert!(matches!(failures[0].1, ImportError::FileNotFound(_)));
+        let mut config = chorrosion_config::AppConfig::default();
+        config.database.url = "sqlite::memory:".to_string();
+        config.database.pool_max_size = 1;
+        let pool = chorrosion_infrastructure::init_database(&config)
+            .await
+            .expect("in-memory database");
+        let artist = SqliteArtistRepository::new(pool.clone())
+            .create(Artist::new("Portishead"))
+            .await
+            .unwrap();
+        let album = SqliteAlbumRepository::new(pool.clone())
+            .create(Album::new(artist.id, "Dummy"))
+            .await
+            .unwrap();
+        let track = SqliteTrackRepository::new(pool.clone())
+            .create(Track::new(album.id, artist.id, "Sour Times"))
+            .await
+            .unwrap();
+        let track_files = Arc::new(SqliteTrackFileRepository::new(pool));
+        // Not audio: reaching the decoder would fail the file instead of skipping it.
+        let dir = tempfile::tempdir().unwrap();
+        let known = dir.path().join("01 - Known.mp3");
+        std::fs::write(&known, b"not audio").unwrap();
+        let existing = track_files
+            .create(TrackFile::new(track.id, known.display().to_string(), 9))
+            .await
+            .unwrap();
+        let service = create_test_service().with_track_files(track_files);
+
+        let summary = service
+            .import_batch(vec![(known.display().to_string(), track.id)])
+            .await;
+
+        assert_eq!(
+            (summary.imported, summary.skipped, summary.failed),
+            (0, 1, 0)
+        );
+        match &summary.outcomes[0] {
+            ImportOutcome::Skipped { reason, .. } => {
+                assert!(reason.contains(&existing.id.to_string()), "{reason}");
+            }
+            other => panic!("expected known file to be skipped, got {other:?}"),
+        }
     }
 
     #[tokio::test]
-    async fn test_batch_import_total_equals_successes_plus_failures() {
-        let service = create_test_service();
+    async fn test_batch_import_applies_the_artist_quality_profile() {
+        use crate::test_fixtures::MINIMAL_MP3;
+        use chorrosion_domain::{Album, Artist, Track};
+        use chorrosion_infrastructure::repositories::Repository;
+        use chorrosion_infrastructure::sqlite_adapters::{
+            SqliteAlbumRepository, SqliteArtistRepository, SqliteQualityProfileRepository,
+            SqliteTrackFileRepository, SqliteTrackRepository,
+        };
 
-        let test_file = std::env::current_dir()
-            .unwrap()
-            .join("Cargo.toml")
-            .display()
-            .to_string();
+        let mut config = chorrosion_config::AppConfig::default();
+        config.database.url = "sqlite::memory:".to_string();
+        config.database.pool_max_size = 1;
+        let pool = chorrosion_infrastructure::init_database(&config)
+            .await
+            .expect("in-memory database");
+        let profiles = Arc::new(SqliteQualityProfileRepository::new(pool.clone()));
+        let artists = Arc::new(SqliteArtistRepository::new(pool.clone()));
+        let albums = Arc::new(SqliteAlbumRepository::new(pool.clone()));
+        let tracks = Arc::new(SqliteTrackRepository::new(pool.clone()));
+        let track_files = Arc::new(SqliteTrackFileRepository::new(pool));
+        let profile = profiles
+            .create(quality_profile("FLAC", true))
+            .await
+            .unwrap();
+        let mut artist = Artist::new("Portishead");
+        artist.quality_profile_id = Some(profile.id);
+        let artist = artists.create(artist).await.unwrap();
+        let album = albums.create(Album::new(artist.id, "Dummy")).await.unwrap();
+        let lossless = tracks
+            .create(Track::new(album.id, artist.id, "Sour Times"))
+            .await
+            .unwrap();
+        let missing = tracks
+            .create(Track::new(album.id, artist.id, "Roads"))
+            .await
+            .unwrap();
+        let mut flac = TrackFile::new(lossless.id, "/music/Dummy/01 - Sour Times.flac", 1024);
+        flac.quality = Some("FLAC".to_string());
+        track_files.create(flac).await.unwrap();
+        let service = create_test_service()
+            .with_catalog(artists, albums, tracks)
+            .with_track_files(track_files)
+            .with_quality_profiles(profiles);
+
+        let dir = tempfile::tempdir().unwrap();
+        let release = dir.path().join("Dummy [MP3 320kbps]");
+        std::fs::create_dir_all(&release).unwrap();
+        let downgrade = release.join("01 - Sour Times.mp3");
+        let new_file = release.join("02 - Roads.mp3");
+        std::fs::write(&downgrade, MINIMAL_MP3).unwrap();
+        std::fs::write(&new_file, MINIMAL_MP3).unwrap();
+
+        let summary = service
+            .import_batch(vec![
+                (downgrade.display().to_string(), lossless.id),
+                (new_file.display().to_string(), missing.id),
+            ])
+            .await;
+
+        match &summary.outcomes[0] {
+            ImportOutcome::Skipped { reason, .. } => {
+                assert!(reason.contains("not an upgrade"), "{reason}");
+            }
+            other => panic!("expected the downgrade to be skipped, got {other:?}"),
+        }
+        match &summary.outcomes[1] {
+            ImportOutcome::Imported(imported) => {
+                assert_eq!(imported.track_file.quality.as_deref(), Some("MP3 320"));
+                assert!(imported.replaced.is_none());
+            }
+            other => panic!("expected the new file to be imported, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_library_import_checks_the_source_quality_and_replaces_the_old_file() {
+        use crate::test_fixtures::MINIMAL_MP3;
+        use chorrosion_domain::{Album, Artist, Track};
+        use chorrosion_infrastructure::repositories::Repository;
+        use chorrosion_infrastructure::sqlite_adapters::{
+            SqliteAlbumRepository, SqliteArtistRepository, SqliteQualityProfileRepository,
+            SqliteTrackFileRepository, SqliteTrackRepository,
+        };
+
+        let mut config = chorrosion_config::AppConfig::default();
+        config.database.url = "sqlite::memory:".to_string();
+        config.database.pool_max_size = 1;
+        let pool = chorrosion_infrastructure::init_database(&config)
+            .await
+            .expect("in-memory database");
+        let profiles = Arc::new(SqliteQualityProfileRepository::new(pool.clone()));
+        let artists = Arc::new(SqliteArtistRepository::new(pool.clone()));
+        let albums = Arc::new(SqliteAlbumRepository::new(pool.clone()));
+        let tracks = Arc::new(SqliteTrackRepository::new(pool.clone()));
+        let track_files = Arc::new(SqliteTrackFileRepository::new(pool));
+        let profile = profiles
+            .create(quality_profile("FLAC", true))
+            .await
+            .unwrap();
+        let mut artist = Artist::new("Portishead");
+        artist.quality_profile_id = Some(profile.id);
+        let artist = artists.create(artist).await.unwrap();
+        let album = albums.create(Album::new(artist.id, "Dummy")).await.unwrap();
+        let track = tracks
+            .create(Track::new(album.id, artist.id, "Sour Times"))
+            .await
+            .unwrap();
+        let library = tempfile::tempdir().unwrap();
+        let old_path = library.path().join("Dummy").join("Sour Times.mp3");
+        let mut old = TrackFile::new(track.id, old_path.display().to_string(), 1024);
+        old.quality = Some("MP3 192".to_string());
+        let old = track_files.create(old).await.unwrap();
+        let service = create_test_service()
+            .with_library_roots(vec![library.path().to_path_buf()])
+            .with_catalog(artists, albums, tracks)
+            .with_track_files(track_files)
+            .with_quality_profiles(profiles);
+
+        let downloads = tempfile::tempdir().unwrap();
+        let source = downloads
+            .path()
+            .join("Dummy [MP3 320kbps]")
+            .join("01 - Sour Times.mp3");
+        std::fs::create_dir_all(source.parent().unwrap()).unwrap();
+        std::fs::write(&source, MINIMAL_MP3).unwrap();
+        let destination = library.path().join("Dummy").join("Sour Times (320).mp3");
+
+        let summary = service
+            .import_entries(vec![BatchEntry {
+                path: source.display().to_string(),
+                track_id: track.id,
+                destination: Some(destination.clone()),
+            }])
+            .await;
+
+        match summary.outcomes.as_slice() {
+            [ImportOutcome::Imported(imported)] => {
+                assert_eq!(imported.track_file.id, old.id);
+                assert_eq!(imported.track_file.path, destination.display().to_string());
+                assert_eq!(imported.track_file.quality.as_deref(), Some("MP3 320"));
+                assert_eq!(imported.replaced.as_ref().map(|r| r.id), Some(old.id));
+            }
+            other => panic!("expected the upgrade to be imported, got {other:?}"),
+        }
+        assert!(destination.exists());
+    }
 
+    #[tokio::test]
+    async fn test_batch_import_reports_one_outcome_per_file_in_order() {
+        use crate::test_fixtures::MINIMAL_MP3;
+
+        let service = create_test_service();
+        let dir = tempfile::tempdir().unwrap();
         let files: Vec<(String, TrackId)> = (0..5)
             .map(|i| {
                 if i % 2 == 0 {
-                    (test_file.clone(), TrackId::new())
+                    let path = dir.path().join(format!("{i}.mp3"));
+                    std::fs::write(&path, MINIMAL_MP3).unwrap();
+                    (path.display().to_string(), TrackId::new())
                 } else {
                     (format!("nonexistent_{}.mp3", i), TrackId::new())
                 }
@@ -341,15 +1426,485 @@ mod tests {
             .collect();
         let total = files.len();
 
-        let (successes, failures) = service.import_batch(files).await;
+        let summary = service.import_batch(files).await;
 
+        assert_eq!(summary.outcomes.len(), total);
+        assert_eq!(summary.imported + summary.skipped + summary.failed, total);
+        assert!(matches!(summary.outcomes[0], ImportOutcome::Imported(_)));
+        for (i, outcome) in summary.outcomes.iter().enumerate() {
+            match outcome {
+                // Copies of the same audio are skipped when fingerprinting succeeds.
+                ImportOutcome::Imported(_) | ImportOutcome::Skipped { .. } => {
+                    assert_eq!(i % 2, 0)
+                }
+                ImportOutcome::Failed { path, error } => {
+                    assert_eq!(path, &format!("nonexistent_{i}.mp3"));
+                    assert!(matches!(error, ImportError::FileNotFound(_)));
+                }
+            }
+        }
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_import_file_to_hardlink_shares_inode() {
+        use std::os::unix::fs::MetadataExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("downloads/track.flac");
+        let destination = dir.path().join("library/Artist/Album/01 - Track.flac");
+        std::fs::create_dir_all(source.parent().unwrap()).unwrap();
+        std::fs::write(&source, b"audio").unwrap();
+
+        let service = create_test_service().with_import_mode(ImportMode::Hardlink);
+        let imported = service
+            .import_file_to(&source, &destination, TrackId::new())
+            .await
+            .unwrap();
+
+        assert_eq!(imported.track_file.path, destination.display().to_string());
+        assert!(source.exists());
+        let source_meta = std::fs::metadata(&source).unwrap();
+        let destination_meta = std::fs::metadata(&destination).unwrap();
+        assert_eq!(source_meta.ino(), destination_meta.ino());
+        assert_eq!(source_meta.nlink(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_hardlink_falls_back_to_copy_across_devices() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("track.flac");
+        let destination = dir.path().join("copied.flac");
+        std::fs::write(&source, b"audio").unwrap();
+
+        hard_link_or_copy(&source, &destination, |_, _| async {
+            Err(io::Error::from(io::ErrorKind::CrossesDevices))
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(std::fs::read(&destination).unwrap(), b"audio");
+        assert!(source.exists());
+    }
+
+    #[tokio::test]
+    async fn test_hardlink_does_not_mask_other_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("track.flac");
+        let destination = dir.path().join("linked.flac");
+        std::fs::write(&source, b"audio").unwrap();
+
+        let result = hard_link_or_copy(&source, &destination, |_, _| async {
+            Err(io::Error::from(io::ErrorKind::AlreadyExists))
+        })
+        .await;
+
+        assert!(matches!(result, Err(ImportError::TransferError { .. })));
+        assert!(!destination.exists());
+    }
+
+    #[tokio::test]
+    async fn test_import_file_to_move_removes_source() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("track.mp3");
+        let destination = dir.path().join("library/track.mp3");
+        std::fs::write(&source, b"audio").unwrap();
+
+        let service = create_test_service().with_import_mode(ImportMode::Move);
+        let imported = service
+            .import_file_to(&source, &destination, TrackId::new())
+            .await
+            .unwrap();
+
+        assert!(!source.exists());
+        assert_eq!(std::fs::read(&destination).unwrap(), b"audio");
+        assert_eq!(imported.track_file.path, destination.display().to_string());
+    }
+
+    #[tokio::test]
+    async fn test_move_falls_back_to_copy_and_delete_across_devices() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("track.flac");
+        let destination = dir.path().join("moved.flac");
+        std::fs::write(&source, b"audio").unwrap();
+
+        move_or_copy(&source, &destination, |_, _| async {
+            Err(io::Error::from(io::ErrorKind::CrossesDevices))
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(std::fs::read(&destination).unwrap(), b"audio");
+        assert!(!source.exists());
+    }
+
+    #[tokio::test]
+    async fn test_move_does_not_copy_after_permission_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("track.flac");
+        let destination = dir.path().join("moved.flac");
+        std::fs::write(&source, b"audio").unwrap();
+
+        let result = move_or_copy(&source, &destination, |_, _| async {
+            Err(io::Error::from(io::ErrorKind::PermissionDenied))
+        })
+        .await;
+
+        assert!(matches!(result, Err(ImportError::TransferError { .. })));
+        assert!(source.exists());
+        assert!(!destination.exists());
+    }
+
+    #[tokio::test]
+    async fn test_import_file_to_copy_keeps_source() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("track.mp3");
+        let destination = dir.path().join("library/track.mp3");
+        std::fs::write(&source, b"audio").unwrap();
+
+        let service = create_test_service();
+        assert_eq!(service.import_mode(), ImportMode::Copy);
+        let imported = service
+            .import_file_to(&source, &destination, TrackId::new())
+            .await
+            .unwrap();
+
+        assert!(source.exists());
+        assert_eq!(imported.track_file.size_bytes, 5);
+        assert_eq!(imported.track_file.path, destination.display().to_string());
+    }
+
+    #[tokio::test]
+    async fn test_import_file_to_writes_embedded_artwork() {
+        use lofty::config::WriteOptions;
+        use lofty::file::{AudioFile, TaggedFileExt};
+        use lofty::picture::{MimeType, Picture, PictureType};
+
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("track.mp3");
+        let destination = dir.path().join("library/Album/01 - Track.mp3");
+        std::fs::write(&source, crate::test_fixtures::MINIMAL_MP3).unwrap();
+
+        let mut tagged = lofty::read_from_path(&source).unwrap();
+        let tag_type = tagged.primary_tag_type();
+        tagged.insert_tag(lofty::tag::Tag::new(tag_type));
+        tagged
+            .primary_tag_mut()
+            .unwrap()
+            .push_picture(Picture::new_unchecked(
+                PictureType::CoverFront,
+                Some(MimeType::Jpeg),
+                None,
+                b"front".to_vec(),
+            ));
+        tagged
+            .save_to_path(&source, WriteOptions::default())
+            .unwrap();
+
+        create_test_service()
+            .import_file_to(&source, &destination, TrackId::new())
+            .await
+            .unwrap();
+
+        let cover = dir.path().join("library/Album/cover.jpg");
+        assert_eq!(std::fs::read(cover).unwrap(), b"front");
+    }
+
+    #[tokio::test]
+    async fn test_import_file_to_missing_source_fails() {
+        let dir = tempfile::tempdir().unwrap();
+        let service = create_test_service().with_import_mode(ImportMode::Hardlink);
+
+        let result = service
+            .import_file_to(
+                dir.path().join("missing.flac"),
+                dir.path().join("library/missing.flac"),
+                TrackId::new(),
+            )
+            .await;
+
+        assert!(matches!(result, Err(ImportError::FileNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_import_file_to_rejects_destination_outside_library_roots() {
+        let dir = tempfile::tempdir().unwrap();
+        let library = dir.path().join("library");
+        std::fs::create_dir(&library).unwrap();
+        let source = write_audio(dir.path(), "downloads/01 - Track.flac");
+        let service = create_test_service().with_library_roots(vec![library.clone()]);
+
+        let result = service
+            .import_file_to(
+                &source,
+                library.join("../elsewhere/01 - Track.flac"),
+                TrackId::new(),
+            )
+            .await;
+
+        assert!(matches!(result, Err(ImportError::OutsideLibrary(_))));
+        assert!(!dir.path().join("elsewhere").exists());
+
+        let imported = service
+            .import_file_to(
+                &source,
+                library.join("Artist/01 - Track.flac"),
+                TrackId::new(),
+            )
+            .await
+            .expect("destination inside the library root");
+        assert!(imported
+            .track_file
+            .path
+            .starts_with(&library.display().to_string()));
+    }
+
+    fn quality_profile(cutoff: &str, upgrade_allowed: bool) -> QualityProfile {
+        let mut profile = QualityProfile::new(
+            "Lossless",
+            vec!["MP3 192".into(), "MP3 320".into(), "FLAC".into()],
+        );
+        profile.cutoff_quality = Some(cutoff.to_string());
+        profile.upgrade_allowed = upgrade_allowed;
+        profile
+    }
+
+    fn existing_file(track_id: TrackId, quality: &str) -> TrackFile {
+        let mut track_file = TrackFile::new(track_id, "/music/old/01 - Track.mp3", 1024);
+        track_file.quality = Some(quality.to_string());
+        track_file
+    }
+
+    fn write_audio(dir: &Path, relative: &str) -> PathBuf {
+        let path = dir.join(relative);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, b"audio").unwrap();
+        path
+    }
+
+    #[tokio::test]
+    async fn test_import_with_profile_rejects_disallowed_quality() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_audio(dir.path(), "Album [AAC 256kbps]/01 - Track.m4a");
+        let service = create_test_service();
+
+        let result = service
+            .import_file_with_profile(&path, TrackId::new(), &quality_profile("FLAC", false), None)
+            .await;
+
+        match result {
+            Err(ImportError::QualityNotAllowed { quality, profile }) => {
+                assert_eq!(quality, "AAC 256");
+                assert_eq!(profile, "Lossless");
+            }
+            other => panic!("expected QualityNotAllowed, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_import_with_profile_replaces_lower_quality_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_audio(dir.path(), "Album [FLAC]/01 - Track.flac");
+        let track_id = TrackId::new();
+        let existing = existing_file(track_id, "MP3 320");
+        let service = create_test_service();
+
+        let imported = service
+            .import_file_with_profile(
+                &path,
+                track_id,
+                &quality_profile("FLAC", false),
+                Some(&existing),
+            )
+            .await
+            .unwrap();
+
+        assert!(!imported.was_created);
+        assert_eq!(imported.track_file.id, existing.id);
+        assert_eq!(imported.track_file.path, path.display().to_string());
+        assert_eq!(imported.track_file.quality.as_deref(), Some("FLAC"));
         assert_eq!(
-            successes.len() + failures.len(),
-            total,
-            "successes + failures must equal total"
+            imported.replaced.map(|replaced| replaced.path),
+            Some(existing.path)
         );
     }
 
+    #[tokio::test]
+    async fn test_import_with_profile_keeps_file_already_at_cutoff() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_audio(dir.path(), "Album [FLAC]/01 - Track.flac");
+        let track_id = TrackId::new();
+        let existing = existing_file(track_id, "MP3 320");
+        let service = create_test_service();
+
+        let result = service
+            .import_file_with_profile(
+                &path,
+                track_id,
+                &quality_profile("MP3 320", false),
+                Some(&existing),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ImportError::CutoffMet { ref existing_quality, ref cutoff })
+                if existing_quality == "MP3 320" && cutoff == "MP3 320"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_import_with_profile_rejects_downgrade() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_audio(dir.path(), "Album [MP3 320kbps]/01 - Track.mp3");
+        let track_id = TrackId::new();
+        let existing = existing_file(track_id, "FLAC");
+        let service = create_test_service();
+
+        let result = service
+            .import_file_with_profile(
+                &path,
+                track_id,
+                &quality_profile("FLAC", true),
+                Some(&existing),
+            )
+            .await;
+
+        assert!(matches!(result, Err(ImportError::NotAnUpgrade { .. })));
+    }
+
+    /// A service whose catalog holds Radiohead's "In Rainbows" with the given track titles.
+    async fn create_catalog_service(
+        titles: &[&str],
+        artist_path: Option<&Path>,
+    ) -> (FileImportService, Vec<TrackId>) {
+        use chorrosion_config::AppConfig;
+        use chorrosion_domain::{Album, Artist, Track};
+        use chorrosion_infrastructure::init_database;
+        use chorrosion_infrastructure::repositories::Repository;
+        use chorrosion_infrastructure::sqlite_adapters::{
+            SqliteAlbumRepository, SqliteArtistRepository, SqliteTrackRepository,
+        };
+
+        let mut config = AppConfig::default();
+        config.database.url = "sqlite::memory:".to_string();
+        config.database.pool_max_size = 1;
+        let pool = init_database(&config).await.expect("in-memory database");
+        let artists = Arc::new(SqliteArtistRepository::new(pool.clone()));
+        let albums = Arc::new(SqliteAlbumRepository::new(pool.clone()));
+        let tracks = Arc::new(SqliteTrackRepository::new(pool));
+        let mut artist = Artist::new("Radiohead");
+        artist.path = artist_path.map(|path| path.display().to_string());
+        let artist = artists.create(artist).await.unwrap();
+        let album = albums
+            .create(Album::new(artist.id, "In Rainbows"))
+            .await
+            .unwrap();
+        let mut track_ids = Vec::new();
+        for title in titles {
+            let track = tracks
+                .create(Track::new(album.id, artist.id, *title))
+                .await
+                .unwrap();
+            track_ids.push(track.id);
+        }
+
+        let service = create_test_service().with_catalog(artists, albums, tracks);
+        (service, track_ids)
+    }
+
+    #[tokio::test]
+    async fn test_scan_directory_reports_outcome_per_audio_file() {
+        use crate::test_fixtures::MINIMAL_MP3;
+
+        let library = tempfile::tempdir().unwrap();
+        let artist_path = library.path().join("Radiohead");
+        let (service, track_ids) =
+            create_catalog_service(&["Nude", "Reckoner"], Some(&artist_path)).await;
+        let dir = tempfile::tempdir().unwrap();
+        let album_dir = dir.path().join("Radiohead").join("In Rainbows");
+        std::fs::create_dir_all(&album_dir).unwrap();
+        let nude = album_dir.join("01 - Nude.mp3");
+        let unknown = album_dir.join("02 - Bodysnatchers.mp3");
+        let reckoner = album_dir.join("03 - Reckoner.mp3");
+        std::fs::write(&nude, MINIMAL_MP3).unwrap();
+        let mut other = MINIMAL_MP3.to_vec();
+        other.extend_from_slice(b"tail");
+        std::fs::write(&unknown, other).unwrap();
+        std::fs::write(&reckoner, b"definitely not audio").unwrap();
+        std::fs::write(album_dir.join("cover.jpg"), b"image").unwrap();
+        std::fs::write(album_dir.join("notes.txt"), b"text").unwrap();
+        std::fs::write(album_dir.join(".01 - Nude.mp3"), MINIMAL_MP3).unwrap();
+
+        let outcomes = service.scan_directory(dir.path()).await.unwrap();
+
+        assert_eq!(outcomes.len(), 3, "{outcomes:?}");
+        match &outcomes[0] {
+            ImportOutcome::Imported(imported) => {
+                let destination = artist_path.join("In Rainbows").join("Nude.mp3");
+                assert_eq!(imported.track_file.path, destination.display().to_string());
+                assert_eq!(imported.track_file.track_id, track_ids[0]);
+                assert!(destination.exists());
+                assert!(nude.exists(), "copy mode keeps the downloaded file");
+            }
+            other => panic!("expected Nude to be imported, got {other:?}"),
+        }
+        match &outcomes[1] {
+            ImportOutcome::Skipped { path, reason } => {
+                assert_eq!(path, &unknown.display().to_string());
+                assert!(reason.contains("Bodysnatchers"), "{reason}");
+            }
+            other => panic!("expected unmatched file to be skipped, got {other:?}"),
+        }
+        match &outcomes[2] {
+            ImportOutcome::Failed { path, error } => {
+                assert_eq!(path, &reckoner.display().to_string());
+                assert!(matches!(error, ImportError::Undecodable(_)), "{error}");
+            }
+            other => panic!("expected undecodable file to fail, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_scan_directory_skips_files_of_artists_without_a_path() {
+        use crate::test_fixtures::MINIMAL_MP3;
+
+        let (service, _) = create_catalog_service(&["Nude"], None).await;
+        let dir = tempfile::tempdir().unwrap();
+        let album_dir = dir.path().join("Radiohead").join("In Rainbows");
+        std::fs::create_dir_all(&album_dir).unwrap();
+        std::fs::write(album_dir.join("01 - Nude.mp3"), MINIMAL_MP3).unwrap();
+
+        let outcomes = service.scan_directory(dir.path()).await.unwrap();
+
+        match outcomes.as_slice() {
+            [ImportOutcome::Skipped { reason, .. }] => {
+                assert!(reason.contains("has no path"), "{reason}");
+            }
+            other => panic!("expected the file to be skipped, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_scan_directory_requires_catalog() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let result = create_test_service().scan_directory(dir.path()).await;
+
+        assert!(matches!(result, Err(ImportError::NoCatalog)));
+    }
+
+    #[tokio::test]
+    async fn test_scan_directory_missing_directory_fails() {
+        let (service, _) = create_catalog_service(&[], None).await;
+
+        let result = service
+            .scan_directory(Path::new("/nonexistent/downloads"))
+            .await;
+
+        assert!(matches!(result, Err(ImportError::FileNotFound(_))));
+    }
+
     #[test]
     #[should_panic(expected = "max_concurrent_imports must be >= 1")]
     fn test_zero_concurrency_panics() {
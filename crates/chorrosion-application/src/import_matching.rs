@@ -2,6 +2,7 @@
 
 use crate::filename_heuristics::FilenameHeuristicsService;
 use crate::quality_upgrade::{QualityUpgradeService, UpgradeReason};
+use chorrosion_config::DEFAULT_AUDIO_EXTENSIONS;
 use chorrosion_domain::{AlbumId, ArtistId, QualityProfile, TrackFile};
 use lazy_static::lazy_static;
 use lofty::file::AudioFile;
@@ -26,6 +27,52 @@ pub enum ImportMatchingError {
     MetadataParsing(String),
 }
 
+/// File extensions counted as audio by [`scan_audio_files`], e.g. `library.audio_extensions`.
+///
+/// Extensions are compared case-insensitively and without a leading dot. An
+/// empty list falls back to [`DEFAULT_AUDIO_EXTENSIONS`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AudioExtensions(Vec<String>);
+
+impl AudioExtensions {
+    pub fn new<S: AsRef<str>>(extensions: &[S]) -> Self {
+        let normalized: Vec<String> = extensions
+            .iter()
+            .map(|extension| normalize_extension(extension.as_ref()))
+            .filter(|extension| !extension.is_empty())
+            .collect();
+        if normalized.is_empty() {
+            return Self::default();
+        }
+        Self(normalized)
+    }
+
+    /// Whether `extension` (with or without its leading dot) is an audio extension.
+    pub fn matches(&self, extension: &str) -> bool {
+        let extension = normalize_extension(extension);
+        self.0.contains(&extension)
+    }
+}
+
+impl Default for AudioExtensions {
+    fn default() -> Self {
+        Self(
+            DEFAULT_AUDIO_EXTENSIONS
+                .iter()
+                .map(|extension| extension.to_string())
+                .collect(),
+        )
+    }
+}
+
+fn normalize_extension(extension: &str) -> String {
+    let extension = extension.trim();
+    extension
+        .strip_prefix('.')
+        .unwrap_or(extension)
+        .to_ascii_lowercase()
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ScannedAudioFile {
     pub path: PathBuf,
@@ -124,6 +171,7 @@ pub struct ImportEvaluation {
 
 pub fn scan_audio_files(
     root: impl AsRef<Path>,
+    extensions: &AudioExtensions,
 ) -> Result<Vec<ScannedAudioFile>, ImportMatchingError> {
     let root = root.as_ref();
     if !root.exists() {
@@ -133,7 +181,7 @@ pub fn scan_audio_files(
     }
 
     let mut scanned = Vec::new();
-    visit_directory(root, &mut scanned)?;
+    visit_directory(root, extensions, &mut scanned)?;
     scanned.sort_by(|left, right| left.path.cmp(&right.path));
     Ok(scanned)
 }
@@ -423,6 +471,7 @@ fn find_best_catalog_match(
 
 fn visit_directory(
     directory: &Path,
+    extensions: &AudioExtensions,
     scanned: &mut Vec<ScannedAudioFile>,
 ) -> Result<(), ImportMatchingError> {
     let entries =
@@ -436,12 +485,12 @@ fn visit_directory(
             .file_type()
             .map_err(|err| ImportMatchingError::Io(err.to_string()))?;
 
-        if file_type.is_symlink() {
+        if file_type.is_symlink() || entry.file_name().to_string_lossy().starts_with('.') {
             continue;
         }
 
         if file_type.is_dir() {
-            visit_directory(&path, scanned)?;
+            visit_directory(&path, extensions, scanned)?;
             continue;
         }
 
@@ -449,10 +498,10 @@ fn visit_directory(
             continue;
         };
 
-        let normalized_extension = extension.to_ascii_lowercase();
-        if !is_audio_extension(&normalized_extension) {
+        if !extensions.matches(extension) {
             continue;
         }
+        let normalized_extension = normalize_extension(extension);
 
         let metadata =
             fs::metadata(&path).map_err(|err| ImportMatchingError::Io(err.to_string()))?;
@@ -466,13 +515,6 @@ fn visit_directory(
     Ok(())
 }
 
-fn is_audio_extension(extension: &str) -> bool {
-    matches!(
-        extension,
-        "mp3" | "flac" | "m4a" | "aac" | "ogg" | "opus" | "wav" | "wv" | "ape" | "dsf"
-    )
-}
-
 fn extract_bitrate_from_filename(path: &Path) -> Option<u32> {
     let stem = path.file_stem()?.to_str()?;
     BITRATE_REGEX
@@ -501,7 +543,10 @@ async fn extract_bitrate_from_audio_stream(path: &Path) -> Option<u32> {
     }
 }
 
-fn resolve_track_file_quality(track_file: &TrackFile, profile: &QualityProfile) -> Option<String> {
+pub(crate) fn resolve_track_file_quality(
+    track_file: &TrackFile,
+    profile: &QualityProfile,
+) -> Option<String> {
     if let Some(quality) = track_file.quality.as_deref() {
         if let Some(resolved) = find_allowed_quality(quality, profile) {
             return Some(resolved);
@@ -557,7 +602,7 @@ fn resolve_quality_from_codec_bitrate(
     }
 }
 
-fn resolve_lossy_family_quality(
+pub(crate) fn resolve_lossy_family_quality(
     family: &str,
     bitrate_kbps: u32,
     profile: &QualityProfile,
@@ -589,7 +634,7 @@ fn resolve_lossy_family_quality(
         .map(|(_, label)| label)
 }
 
-fn find_allowed_quality(quality: &str, profile: &QualityProfile) -> Option<String> {
+pub(crate) fn find_allowed_quality(quality: &str, profile: &QualityProfile) -> Option<String> {
     profile
         .allowed_qualities
         .iter()
@@ -604,7 +649,7 @@ fn normalize_optional(value: Option<&str>) -> Option<String> {
         .map(str::to_string)
 }
 
-fn normalized_similarity(left: &str, right: &str) -> f32 {
+pub(crate) fn normalized_similarity(left: &str, right: &str) -> f32 {
     let left = normalize_for_match(left);
     let right = normalize_for_match(right);
     if left.is_empty() || right.is_empty() {
@@ -666,23 +711,71 @@ mod tests {
     use chrono::Utc;
 
     #[test]
-    fn scan_audio_files_recursively_filters_supported_extensions() {
+    fn scan_audio_files_recursively_filters_supported_extensions_and_skips_hidden_entries() {
         let root = tempfile::tempdir().expect("temp dir should be created");
         let album_dir = root.path().join("artist").join("album");
         fs::create_dir_all(&album_dir).expect("nested dir should be created");
 
         let audio = album_dir.join("01 - Track.mp3");
         let image = album_dir.join("cover.jpg");
+        let hidden = album_dir.join("._01 - Track.mp3");
+        let hidden_dir = root.path().join(".trash");
+        fs::create_dir_all(&hidden_dir).expect("hidden dir should be created");
         fs::write(&audio, b"audio-data").expect("audio file should exist");
         fs::write(&image, b"image-data").expect("image file should exist");
+        fs::write(&hidden, b"audio-data").expect("hidden file should exist");
+        fs::write(hidden_dir.join("02 - Deleted.mp3"), b"audio-data")
+            .expect("file in hidden dir should exist");
 
-        let scanned = scan_audio_files(root.path()).expect("scan should succeed");
+        let scanned = scan_audio_files(root.path(), &AudioExtensions::default())
+            .expect("scan should succeed");
 
         assert_eq!(scanned.len(), 1);
         assert_eq!(scanned[0].path, audio);
         assert_eq!(scanned[0].extension, "mp3");
     }
 
+    #[test]
+    fn default_audio_extensions_match_common_formats_only() {
+        let extensions = AudioExtensions::default();
+        for extension in [
+            "flac", "mp3", "m4a", "ogg", "opus", "wav", "aac", "wv", "ape",
+        ] {
+            assert!(extensions.matches(extension), "{extension} should match");
+        }
+        assert!(!extensions.matches("jpg"));
+        assert!(!extensions.matches("cue"));
+        assert_eq!(AudioExtensions::new::<String>(&[]), extensions);
+    }
+
+    #[test]
+    fn custom_audio_extensions_replace_the_defaults_when_scanning() {
+        let root = tempfile::tempdir().expect("temp dir should be created");
+        fs::write(root.path().join("01 - Track.dsf"), b"audio-data").expect("dsf should exist");
+        fs::write(root.path().join("02 - Track.flac"), b"audio-data").expect("flac should exist");
+
+        let extensions = AudioExtensions::new(&[".dsf"]);
+        let scanned = scan_audio_files(root.path(), &extensions).expect("scan should succeed");
+
+        assert_eq!(scanned.len(), 1);
+        assert_eq!(scanned[0].extension, "dsf");
+    }
+
+    #[test]
+    fn audio_extensions_match_case_insensitively() {
+        let root = tempfile::tempdir().expect("temp dir should be created");
+        let audio = root.path().join("01 - Track.FLAC");
+        fs::write(&audio, b"audio-data").expect("audio file should exist");
+
+        let extensions = AudioExtensions::new(&["Flac", ".MP3"]);
+        assert!(extensions.matches(".mp3"));
+        let scanned = scan_audio_files(root.path(), &extensions).expect("scan should succeed");
+
+        assert_eq!(scanned.len(), 1);
+        assert_eq!(scanned[0].path, audio);
+        assert_eq!(scanned[0].extension, "flac");
+    }
+
     #[tokio::test]
     async fn parse_track_metadata_prefers_embedded_tags() {
         let root = tempfile::tempdir().expect("temp dir should be created");
@@ -889,6 +982,7 @@ mod tests {
             ],
             upgrade_allowed: true,
             cutoff_quality: Some("MP3 320".to_string()),
+            is_default: false,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         }
@@ -1,11 +1,19 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
+use crate::http_client::OutboundHttp;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use lazy_static::lazy_static;
 use quick_xml::de::from_str;
+use regex::Regex;
 use reqwest::{Client, Url};
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
 use thiserror::Error;
-use tracing::debug;
+use tokio::sync::Mutex;
+use tokio::task::JoinSet;
+use tokio::time::{sleep, Duration, Instant};
+use tracing::{debug, trace};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -41,13 +49,65 @@ impl std::str::FromStr for IndexerProtocol {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct IndexerConfig {
     pub name: String,
     pub base_url: String,
     pub protocol: IndexerProtocol,
     pub api_key: Option<String>,
     pub enabled: bool,
+    /// Maximum number of requests per minute sent to this indexer. `None` disables limiting.
+    #[serde(default)]
+    pub requests_per_minute: Option<u32>,
+}
+
+impl IndexerConfig {
+    pub fn api_key(&self) -> Option<&str> {
+        self.api_key.as_deref()
+    }
+}
+
+impl TryFrom<&chorrosion_config::IndexerConfig> for IndexerConfig {
+    type Error = String;
+
+    fn try_from(config: &chorrosion_config::IndexerConfig) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: config.name.clone(),
+            base_url: config.base_url.clone(),
+            protocol: config.protocol.parse()?,
+            api_key: config.api_key.clone(),
+            enabled: config.enabled,
+            requests_per_minute: config.requests_per_minute,
+        })
+    }
+}
+
+impl TryFrom<&chorrosion_domain::IndexerDefinition> for IndexerConfig {
+    type Error = String;
+
+    fn try_from(definition: &chorrosion_domain::IndexerDefinition) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: definition.name.clone(),
+            base_url: definition.base_url.clone(),
+            protocol: definition.protocol.parse()?,
+            api_key: definition.api_key.clone(),
+            enabled: definition.enabled,
+            requests_per_minute: definition.requests_per_minute,
+        })
+    }
+}
+
+impl std::fmt::Debug for IndexerConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IndexerConfig")
+            .field("name", &self.name)
+            .field("base_url", &self.base_url)
+            .field("protocol", &self.protocol)
+            .field("api_key", &self.api_key.as_ref().map(|_| "[REDACTED]"))
+            .field("enabled", &self.enabled)
+            .field("requests_per_minute", &self.requests_per_minute)
+            .finish()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -64,6 +124,21 @@ pub struct IndexerTestResult {
     pub success: bool,
     pub message: String,
     pub capabilities: Option<IndexerCapabilities>,
+    /// Why the test failed; `None` when it succeeded.
+    #[serde(default)]
+    pub failure: Option<IndexerTestFailure>,
+}
+
+/// Classification of a failed indexer connection test.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IndexerTestFailure {
+    Unreachable,
+    Timeout,
+    Authentication,
+    MalformedResponse,
+    /// Any other error, such as an unsupported operation or a configuration problem.
+    Error,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -85,6 +160,16 @@ pub struct IndexerSearchResult {
     pub leechers: Option<u32>,
 }
 
+/// One page of search results with the paging metadata the indexer reported.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexerSearchResponse {
+    pub items: Vec<IndexerSearchResult>,
+    /// Total number of matches across all pages, when the indexer reports it.
+    pub total: Option<usize>,
+    /// Offset of the first item of this page, when the indexer reports it.
+    pub offset: Option<usize>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct IndexerRssItem {
     pub title: String,
@@ -105,6 +190,118 @@ pub enum IndexerError {
     RssParse(String),
     #[error("unsupported operation: {0}")]
     Unsupported(String),
+    #[error("connection failed: {0}")]
+    Connection(String),
+    #[error("request timed out: {0}")]
+    Timeout(String),
+    #[error("authentication failed: {0}")]
+    Authentication(String),
+    #[error("malformed response: {0}")]
+    MalformedResponse(String),
+}
+
+impl IndexerError {
+    /// Classification and operator-facing description of a connection test failure,
+    /// or `None` for errors that indicate a configuration problem rather than a
+    /// failed connection.
+    fn connection_test_failure(&self, indexer: &str) -> Option<(IndexerTestFailure, String)> {
+        let failure = match self {
+            Self::Connection(detail) => (
+                IndexerTestFailure::Unreachable,
+                format!("Indexer '{indexer}' is unreachable: {detail}"),
+            ),
+            Self::Timeout(detail) => (
+                IndexerTestFailure::Timeout,
+                format!("Indexer '{indexer}' timed out: {detail}"),
+            ),
+            Self::Authentication(detail) => (
+                IndexerTestFailure::Authentication,
+                format!("Indexer '{indexer}' rejected the API key: {detail}"),
+            ),
+            Self::MalformedResponse(detail) => (
+                IndexerTestFailure::MalformedResponse,
+                format!("Indexer '{indexer}' returned a malformed response: {detail}"),
+            ),
+            Self::Request(_) | Self::Capabilities(_) | Self::RssParse(_) | Self::Unsupported(_) => {
+                return None
+            }
+        };
+        Some(failure)
+    }
+}
+
+/// Page size [`search_all`] uses when the query sets no `limit`.
+pub const DEFAULT_SEARCH_PAGE_SIZE: usize = 100;
+
+/// How long detected indexer capabilities are reused before the indexer is probed again.
+const CAPABILITIES_CACHE_TTL: Duration = Duration::from_secs(3600);
+
+/// How long a failed capabilities probe is remembered before the indexer is probed again.
+const CAPABILITIES_FAILURE_TTL: Duration = Duration::from_secs(60);
+
+lazy_static! {
+    static ref CATEGORY_ID: Regex =
+        Regex::new(r#"<(?:category|subcat)\b[^>]*?\bid\s*=\s*["']([^"']+)["']"#)
+            .expect("category id regex");
+}
+
+/// Capabilities detected for one indexer, reused for [`CAPABILITIES_CACHE_TTL`].
+///
+/// A failed probe is remembered as `None` for the shorter [`CAPABILITIES_FAILURE_TTL`]
+/// so an unreachable `caps` endpoint is not retried on every search, nor ignored for
+/// long after it recovers.
+#[derive(Debug, Clone, Default)]
+struct CapabilitiesCache {
+    detected: Arc<Mutex<Option<DetectedCapabilities>>>,
+}
+
+#[derive(Debug)]
+struct DetectedCapabilities {
+    detected_at: Instant,
+    capabilities: Option<IndexerCapabilities>,
+}
+
+impl DetectedCapabilities {
+    fn is_fresh(&self) -> bool {
+        let ttl = if self.capabilities.is_some() {
+            CAPABILITIES_CACHE_TTL
+        } else {
+            CAPABILITIES_FAILURE_TTL
+        };
+        self.detected_at.elapsed() < ttl
+    }
+}
+
+impl CapabilitiesCache {
+    async fn get_or_detect(
+        &self,
+        client: &Client,
+        config: &IndexerConfig,
+        rate_limiter: &IndexerRateLimiter,
+    ) -> Option<IndexerCapabilities> {
+        if let Some(cached) = self
+            .detected
+            .lock()
+            .await
+            .as_ref()
+            .filter(|cached| cached.is_fresh())
+        {
+            return cached.capabilities.clone();
+        }
+        // Probe without holding the lock so a slow indexer does not stall other searches.
+        let capabilities = match detect_capabilities(client, config, rate_limiter).await {
+            Ok(capabilities) => Some(capabilities),
+            Err(error) => {
+                debug!(target: "indexers", indexer = %config.name, %error, "capability detection failed; sending default categories");
+                None
+            }
+        };
+        *self.detected.lock().await = Some(DetectedCapabilities {
+            detected_at: Instant::now(),
+            capabilities: capabilities.clone(),
+        });
+        capabilities
+    }
 }
 
 #[async_trait]
@@ -118,20 +315,121 @@ pub trait IndexerClient: Send + Sync {
         query: &IndexerSearchQuery,
     ) -> Result<Vec<IndexerSearchResult>, IndexerError>;
 
+    /// Search one page, keeping the indexer's `total`/`offset` metadata when available.
+    async fn search_page(
+        &self,
+        query: &IndexerSearchQuery,
+    ) -> Result<IndexerSearchResponse, IndexerError> {
+        Ok(IndexerSearchResponse {
+            items: self.search(query).await?,
+            total: None,
+            offset: None,
+        })
+    }
+
     async fn fetch_rss_feed(&self) -> Result<Vec<IndexerRssItem>, IndexerError>;
 
     async fn test_connection(&self) -> Result<IndexerTestResult, IndexerError>;
 }
 
-/// Builds a shared `reqwest::Client` configured with the chorrosion user-agent and a 30-second
-/// timeout. Falls back to a default `Client` if the builder fails.
+/// Per-indexer rate limiter enforcing a minimum interval between requests.
+///
+/// Requests made through the same limiter are serialized so that consecutive
+/// requests start at least `min_interval` apart. Clients built through [`IndexerClients`]
+/// share one limiter per indexer, so requests to different indexers are never serialized
+/// against each other.
+#[derive(Debug, Clone)]
+pub struct IndexerRateLimiter {
+    min_interval: Option<Duration>,
+    last_request: Arc<Mutex<Option<Instant>>>,
+}
+
+impl IndexerRateLimiter {
+    /// Create a limiter allowing at most `requests_per_minute` requests per minute.
+    ///
+    /// `None` or `Some(0)` yields a limiter that never waits.
+    pub fn new(requests_per_minute: Option<u32>) -> Self {
+        let min_interval = requests_per_minute
+            .filter(|rpm| *rpm > 0)
+            .map(|rpm| Duration::from_secs(60) / rpm);
+        Self {
+            min_interval,
+            last_request: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Create a limiter from the `requests_per_minute` setting of an indexer config.
+    pub fn from_config(config: &IndexerConfig) -> Self {
+        Self::new(config.requests_per_minute)
+    }
+
+    /// Minimum interval enforced between requests, if any.
+    pub fn min_interval(&self) -> Option<Duration> {
+        self.min_interval
+    }
+
+    /// Wait until the next request to this indexer may be sent.
+    pub async fn acquire(&self) {
+        let Some(min_interval) = self.min_interval else {
+            return;
+        };
+
+        let mut last = self.last_request.lock().await;
+        if let Some(last_instant) = *last {
+            let elapsed = last_instant.elapsed();
+            if elapsed < min_interval {
+                let wait_time = min_interval - elapsed;
+                trace!(target: "indexers", "rate limiting: waiting {:?}", wait_time);
+                sleep(wait_time).await;
+            }
+        }
+        *last = Some(Instant::now());
+    }
+}
+
+/// Per-indexer state shared by every client built for the same indexer: its
+/// request limiter and its detected capabilities.
+#[derive(Debug, Clone)]
+pub struct SharedIndexerState {
+    base_url: String,
+    protocol: IndexerProtocol,
+    requests_per_minute: Option<u32>,
+    rate_limiter: IndexerRateLimiter,
+    capabilities: CapabilitiesCache,
+}
+
+impl SharedIndexerState {
+    pub fn new(config: &IndexerConfig) -> Self {
+        Self {
+            base_url: config.base_url.clone(),
+            protocol: config.protocol.clone(),
+            requests_per_minute: config.requests_per_minute,
+            rate_limiter: IndexerRateLimiter::from_config(config),
+            capabilities: CapabilitiesCache::default(),
+        }
+    }
+
+    /// Whether this state still describes `config`: a new endpoint invalidates the
+    /// cached capabilities and a new limit the limiter.
+    fn matches(&self, config: &IndexerConfig) -> bool {
+        self.base_url == config.base_url
+            && self.protocol == config.protocol
+            && self.requests_per_minute == config.requests_per_minute
+    }
+}
+
+/// Builds a `reqwest::Client` with the chorrosion user-agent and a 30-second timeout but no
+/// proxy; the app hands clients one built from the configured proxy via `with_http_client`.
 fn build_indexer_http_client() -> Client {
-    crate::http_client::build_http_client()
+    OutboundHttp::default().default_client()
 }
 
 pub struct NewznabClient {
     config: IndexerConfig,
     client: Client,
+    rate_limiter: IndexerRateLimiter,
+    category_map: BTreeMap<String, String>,
+    capabilities: CapabilitiesCache,
 }
 
 impl NewznabClient {
@@ -139,7 +437,34 @@ impl NewznabClient {
     pub fn new(config: IndexerConfig) -> Self {
         let client = build_indexer_http_client();
         debug!(target: "indexers", base_url = %config.base_url, "Initialized NewznabClient");
-        Self { config, client }
+        let rate_limiter = IndexerRateLimiter::from_config(&config);
+        Self {
+            config,
+            client,
+            rate_limiter,
+            category_map: BTreeMap::new(),
+            capabilities: CapabilitiesCache::default(),
+        }
+    }
+
+    /// Overrides the indexer category IDs sent for named categories such as `audio/flac`.
+    pub fn with_category_map(mut self, category_map: BTreeMap<String, String>) -> Self {
+        self.category_map = category_map;
+        self
+    }
+
+    /// Sends requests through `client`, sharing its connection pool with other callers.
+    pub fn with_http_client(mut self, client: Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// Paces requests through the limiter in `shared` and reuses its detected
+    /// capabilities instead of client-local ones.
+    pub fn with_shared_state(mut self, shared: &SharedIndexerState) -> Self {
+        self.rate_limiter = shared.rate_limiter.clone();
+        self.capabilities = shared.capabilities.clone();
+        self
     }
 
     /// Creates a new `NewznabClient` with an explicit concurrency limit.
@@ -177,11 +502,15 @@ impl NewznabClient {
 pub struct TorznabClient {
     config: IndexerConfig,
     client: Client,
+    rate_limiter: IndexerRateLimiter,
+    category_map: BTreeMap<String, String>,
+    capabilities: CapabilitiesCache,
 }
 
 pub struct GazelleClient {
     config: IndexerConfig,
     client: Client,
+    rate_limiter: IndexerRateLimiter,
 }
 
 impl TorznabClient {
@@ -189,7 +518,34 @@ impl TorznabClient {
     pub fn new(config: IndexerConfig) -> Self {
         let client = build_indexer_http_client();
         debug!(target: "indexers", base_url = %config.base_url, "Initialized TorznabClient");
-        Self { config, client }
+        let rate_limiter = IndexerRateLimiter::from_config(&config);
+        Self {
+            config,
+            client,
+            rate_limiter,
+            category_map: BTreeMap::new(),
+            capabilities: CapabilitiesCache::default(),
+        }
+    }
+
+    /// Overrides the indexer category IDs sent for named categories such as `audio/flac`.
+    pub fn with_category_map(mut self, category_map: BTreeMap<String, String>) -> Self {
+        self.category_map = category_map;
+        self
+    }
+
+    /// Sends requests through `client`, sharing its connection pool with other callers.
+    pub fn with_http_client(mut self, client: Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// Paces requests through the limiter in `shared` and reuses its detected
+    /// capabilities instead of client-local ones.
+    pub fn with_shared_state(mut self, shared: &SharedIndexerState) -> Self {
+        self.rate_limiter = shared.rate_limiter.clone();
+        self.capabilities = shared.capabilities.clone();
+        self
     }
 
     /// Creates a new `TorznabClient` with an explicit concurrency limit.
@@ -234,10 +590,18 @@ impl TorznabClient {
         }
 
         if let Some(category) = query.category.as_deref() {
-            params.push((
-                "cat",
-                map_category_to_indexer(category, &self.config.protocol).to_string(),
-            ));
+            if let Some(id) = category_param(
+                &self.client,
+                &self.config,
+                &self.rate_limiter,
+                &self.category_map,
+                &self.capabilities,
+                category,
+            )
+            .await
+            {
+                params.push(("cat", id));
+            }
         }
 
         if let Some(limit) = query.limit {
@@ -253,6 +617,7 @@ impl TorznabClient {
             match execute_api_request(
                 &self.client,
                 &self.config,
+                &self.rate_limiter,
                 request_type,
                 Some(params.clone()),
             )
@@ -277,11 +642,64 @@ impl TorznabClient {
     }
 }
 
+/// Client for indexers that only publish a plain RSS feed.
+///
+/// Used for [`IndexerProtocol::Custom`] indexers: `base_url` is fetched as-is
+/// as the feed URL, with no Newznab-style `t=` query API, so search and
+/// capability detection are unsupported.
+pub struct RssFeedClient {
+    config: IndexerConfig,
+    client: Client,
+    rate_limiter: IndexerRateLimiter,
+}
+
+impl RssFeedClient {
+    pub fn new(config: IndexerConfig) -> Self {
+        let client = build_indexer_http_client();
+        debug!(target: "indexers", base_url = %config.base_url, "Initialized RssFeedClient");
+        let rate_limiter = IndexerRateLimiter::from_config(&config);
+        Self {
+            config,
+            client,
+            rate_limiter,
+        }
+    }
+
+    /// Sends requests through `client`, sharing its connection pool with other callers.
+    pub fn with_http_client(mut self, client: Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// Paces requests through the limiter in `shared` instead of a client-local one.
+    pub fn with_shared_state(mut self, shared: &SharedIndexerState) -> Self {
+        self.rate_limiter = shared.rate_limiter.clone();
+        self
+    }
+}
+
 impl GazelleClient {
     pub fn new(config: IndexerConfig) -> Self {
         let client = build_indexer_http_client();
         debug!(target: "indexers", base_url = %config.base_url, "Initialized GazelleClient");
-        Self { config, client }
+        let rate_limiter = IndexerRateLimiter::from_config(&config);
+        Self {
+            config,
+            client,
+            rate_limiter,
+        }
+    }
+
+    /// Sends requests through `client`, sharing its connection pool with other callers.
+    pub fn with_http_client(mut self, client: Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// Paces requests through the limiter in `shared` instead of a client-local one.
+    pub fn with_shared_state(mut self, shared: &SharedIndexerState) -> Self {
+        self.rate_limiter = shared.rate_limiter.clone();
+        self
     }
 }
 
@@ -292,21 +710,39 @@ impl IndexerClient for NewznabClient {
     }
 
     async fn detect_capabilities(&self) -> Result<IndexerCapabilities, IndexerError> {
-        detect_capabilities(&self.client, &self.config).await
+        detect_capabilities(&self.client, &self.config, &self.rate_limiter).await
     }
 
     async fn search(
         &self,
         query: &IndexerSearchQuery,
     ) -> Result<Vec<IndexerSearchResult>, IndexerError> {
-        let xml = execute_search(&self.client, &self.config, query).await?;
-        parse_search_results(&xml)
+        Ok(self.search_page(query).await?.items)
+    }
+
+    async fn search_page(
+        &self,
+        query: &IndexerSearchQuery,
+    ) -> Result<IndexerSearchResponse, IndexerError> {
+        let xml = execute_search(
+            &self.client,
+            &self.config,
+            &self.rate_limiter,
+            &self.category_map,
+            &self.capabilities,
+            query,
+        )
+        .await?;
+        parse_search_response(&xml)
     }
 
     async fn fetch_rss_feed(&self) -> Result<Vec<IndexerRssItem>, IndexerError> {
         let xml = execute_search(
             &self.client,
             &self.config,
+            &self.rate_limiter,
+            &self.category_map,
+            &self.capabilities,
             &IndexerSearchQuery {
                 query: String::new(),
                 category: Some("music".to_string()),
@@ -319,12 +755,7 @@ impl IndexerClient for NewznabClient {
     }
 
     async fn test_connection(&self) -> Result<IndexerTestResult, IndexerError> {
-        let capabilities = self.detect_capabilities().await?;
-        Ok(IndexerTestResult {
-            success: true,
-            message: format!("Indexer '{}' connection successful", self.config.name),
-            capabilities: Some(capabilities),
-        })
+        connection_test_result(&self.config, self.detect_capabilities().await)
     }
 }
 
@@ -335,26 +766,46 @@ impl IndexerClient for TorznabClient {
     }
 
     async fn detect_capabilities(&self) -> Result<IndexerCapabilities, IndexerError> {
-        detect_capabilities(&self.client, &self.config).await
+        detect_capabilities(&self.client, &self.config, &self.rate_limiter).await
     }
 
     async fn search(
         &self,
         query: &IndexerSearchQuery,
     ) -> Result<Vec<IndexerSearchResult>, IndexerError> {
-        let primary = execute_search(&self.client, &self.config, query)
-            .await
-            .and_then(|xml| parse_search_results(&xml));
+        Ok(self.search_page(query).await?.items)
+    }
 
+    async fn search_page(
+        &self,
+        query: &IndexerSearchQuery,
+    ) -> Result<IndexerSearchResponse, IndexerError> {
+        let primary = execute_search(
+            &self.client,
+            &self.config,
+            &self.rate_limiter,
+            &self.category_map,
+            &self.capabilities,
+            query,
+        )
+        .await
+        .and_then(|xml| parse_search_response(&xml));
+
+        // The fallback request types carry no paging metadata worth trusting.
+        let fallback = |items| IndexerSearchResponse {
+            items,
+            total: None,
+            offset: None,
+        };
         match primary {
-            Ok(results) if !results.is_empty() => Ok(results),
+            Ok(response) if !response.items.is_empty() => Ok(response),
             Ok(_) => {
                 debug!(target: "indexers", indexer = %self.config.name, "torznab primary search returned no results, trying fallback");
-                self.search_with_fallback(query).await
+                self.search_with_fallback(query).await.map(fallback)
             }
             Err(error) => {
                 debug!(target: "indexers", indexer = %self.config.name, error = %error, "torznab primary search failed, trying fallback");
-                self.search_with_fallback(query).await
+                self.search_with_fallback(query).await.map(fallback)
             }
         }
     }
@@ -363,6 +814,9 @@ impl IndexerClient for TorznabClient {
         let xml = execute_search(
             &self.client,
             &self.config,
+            &self.rate_limiter,
+            &self.category_map,
+            &self.capabilities,
             &IndexerSearchQuery {
                 query: String::new(),
                 category: Some("music".to_string()),
@@ -375,12 +829,7 @@ impl IndexerClient for TorznabClient {
     }
 
     async fn test_connection(&self) -> Result<IndexerTestResult, IndexerError> {
-        let capabilities = self.detect_capabilities().await?;
-        Ok(IndexerTestResult {
-            success: true,
-            message: format!("Indexer '{}' connection successful", self.config.name),
-            capabilities: Some(capabilities),
-        })
+        connection_test_result(&self.config, self.detect_capabilities().await)
     }
 }
 
@@ -391,7 +840,14 @@ impl IndexerClient for GazelleClient {
     }
 
     async fn detect_capabilities(&self) -> Result<IndexerCapabilities, IndexerError> {
-        execute_gazelle_request(&self.client, &self.config, "index", None).await?;
+        execute_gazelle_request(
+            &self.client,
+            &self.config,
+            &self.rate_limiter,
+            "index",
+            None,
+        )
+        .await?;
         Ok(IndexerCapabilities {
             supports_search: true,
             supports_rss: false,
@@ -409,7 +865,7 @@ impl IndexerClient for GazelleClient {
         &self,
         query: &IndexerSearchQuery,
     ) -> Result<Vec<IndexerSearchResult>, IndexerError> {
-        execute_gazelle_search(&self.client, &self.config, query).await
+        execute_gazelle_search(&self.client, &self.config, &self.rate_limiter, query).await
     }
 
     async fn fetch_rss_feed(&self) -> Result<Vec<IndexerRssItem>, IndexerError> {
@@ -419,34 +875,131 @@ impl IndexerClient for GazelleClient {
     }
 
     async fn test_connection(&self) -> Result<IndexerTestResult, IndexerError> {
-        let capabilities = self.detect_capabilities().await?;
+        connection_test_result(&self.config, self.detect_capabilities().await)
+    }
+}
+
+#[async_trait]
+impl IndexerClient for RssFeedClient {
+    fn config(&self) -> &IndexerConfig {
+        &self.config
+    }
+
+    async fn detect_capabilities(&self) -> Result<IndexerCapabilities, IndexerError> {
+        Err(IndexerError::Unsupported(
+            "plain RSS feeds do not expose capabilities".to_string(),
+        ))
+    }
+
+    async fn search(
+        &self,
+        _query: &IndexerSearchQuery,
+    ) -> Result<Vec<IndexerSearchResult>, IndexerError> {
+        Err(IndexerError::Unsupported(
+            "plain RSS feeds do not support search".to_string(),
+        ))
+    }
+
+    async fn fetch_rss_feed(&self) -> Result<Vec<IndexerRssItem>, IndexerError> {
+        let url = Url::parse(&self.config.base_url)
+            .map_err(|error| IndexerError::Request(format!("invalid feed url: {error}")))?;
+
+        self.rate_limiter.acquire().await;
+        debug!(target: "indexers", base_url = %self.config.base_url, "requesting RSS feed");
+
+        let body = send_indexer_request(self.client.get(url)).await?;
+        parse_rss_feed(&body)
+    }
+
+    async fn test_connection(&self) -> Result<IndexerTestResult, IndexerError> {
+        let items = match self.fetch_rss_feed().await {
+            Ok(items) => items,
+            Err(error) => return connection_test_result(&self.config, Err(error)),
+        };
         Ok(IndexerTestResult {
             success: true,
-            message: format!("Indexer '{}' connection successful", self.config.name),
-            capabilities: Some(capabilities),
+            message: format!(
+                "Indexer '{}' feed returned {} items",
+                self.config.name,
+                items.len()
+            ),
+            capabilities: Some(IndexerCapabilities {
+                supports_search: false,
+                supports_rss: true,
+                supports_capabilities_detection: false,
+                supports_categories: false,
+                supported_categories: vec![],
+            }),
+            failure: None,
         })
     }
 }
 
+/// Turn a capability probe into a test result.
+///
+/// Connectivity, timeout, authentication, and malformed-response failures become an
+/// unsuccessful [`IndexerTestResult`] naming the cause; other errors are returned as-is.
+fn connection_test_result(
+    config: &IndexerConfig,
+    capabilities: Result<IndexerCapabilities, IndexerError>,
+) -> Result<IndexerTestResult, IndexerError> {
+    match capabilities {
+        Ok(capabilities) => Ok(IndexerTestResult {
+            success: true,
+            message: format!("Indexer '{}' connection successful", config.name),
+            capabilities: Some(capabilities),
+            failure: None,
+        }),
+        Err(error) => match error.connection_test_failure(&config.name) {
+            Some((failure, message)) => {
+                debug!(target: "indexers", indexer = %config.name, error = %error, "indexer connection test failed");
+                Ok(IndexerTestResult {
+                    success: false,
+                    message,
+                    capabilities: None,
+                    failure: Some(failure),
+                })
+            }
+            None => Err(error),
+        },
+    }
+}
+
 async fn detect_capabilities(
     client: &Client,
     config: &IndexerConfig,
+    rate_limiter: &IndexerRateLimiter,
 ) -> Result<IndexerCapabilities, IndexerError> {
-    let xml = execute_api_request(client, config, "caps", None).await?;
+    let xml = execute_api_request(client, config, rate_limiter, "caps", None).await?;
+    check_api_error_response(&xml)?;
+    if !xml.trim_start().starts_with('<') {
+        return Err(IndexerError::MalformedResponse(
+            "capabilities response is not XML".to_string(),
+        ));
+    }
     let supports_search = xml.contains("search") || xml.contains("<searching>");
     let supports_rss = true;
     let supports_capabilities_detection = xml.contains("<caps") || xml.contains("<categories");
     let supports_categories = xml.contains("<category");
 
-    let mut supported_categories = Vec::new();
-    if supports_categories {
+    // Prefer the category ids the indexer lists; older feeds only name them.
+    let mut supported_categories: Vec<String> = Vec::new();
+    for id in CATEGORY_ID
+        .captures_iter(&xml)
+        .filter_map(|captures| captures.get(1))
+        .map(|id| id.as_str().trim())
+    {
+        if !supported_categories.iter().any(|known| known == id) {
+            supported_categories.push(id.to_string());
+        }
+    }
+    if supported_categories.is_empty() && supports_categories {
         for token in ["music", "audio/flac", "audio/mp3"] {
             if xml.to_lowercase().contains(token) {
                 supported_categories.push(token.to_string());
             }
         }
     }
-
     if supported_categories.is_empty() {
         supported_categories = vec![
             "music".to_string(),
@@ -467,6 +1020,9 @@ async fn detect_capabilities(
 async fn execute_search(
     client: &Client,
     config: &IndexerConfig,
+    rate_limiter: &IndexerRateLimiter,
+    category_map: &BTreeMap<String, String>,
+    capabilities: &CapabilitiesCache,
     query: &IndexerSearchQuery,
 ) -> Result<String, IndexerError> {
     let mut params: Vec<(&str, String)> = vec![("t", "search".to_string())];
@@ -476,10 +1032,18 @@ async fn execute_search(
     }
 
     if let Some(category) = query.category.as_deref() {
-        params.push((
-            "cat",
-            map_category_to_indexer(category, &config.protocol).to_string(),
-        ));
+        if let Some(id) = category_param(
+            client,
+            config,
+            rate_limiter,
+            category_map,
+            capabilities,
+            category,
+        )
+        .await
+        {
+            params.push(("cat", id));
+        }
     }
 
     if let Some(limit) = query.limit {
@@ -490,22 +1054,95 @@ async fn execute_search(
         params.push(("offset", offset.to_string()));
     }
 
-    execute_api_request(client, config, "search", Some(params)).await
+    execute_api_request(client, config, rate_limiter, "search", Some(params)).await
 }
 
-fn map_category_to_indexer(category: &str, protocol: &IndexerProtocol) -> &'static str {
+/// The `cat` value to send for `category`, consulting the indexer's cached capabilities.
+///
+/// Ids from `category_map` are sent as configured without probing the indexer.
+async fn category_param(
+    client: &Client,
+    config: &IndexerConfig,
+    rate_limiter: &IndexerRateLimiter,
+    category_map: &BTreeMap<String, String>,
+    capabilities: &CapabilitiesCache,
+    category: &str,
+) -> Option<String> {
+    if let Some(id) = category_override(category, category_map) {
+        return Some(id.to_string());
+    }
+    let capabilities = capabilities
+        .get_or_detect(client, config, rate_limiter)
+        .await;
+    select_category(category, &config.protocol, capabilities.as_ref())
+}
+
+/// Pick the category id to send for `category` given what the indexer reported.
+///
+/// Without usable capabilities the standard id is sent unchanged. An indexer that lists
+/// no categories gets none, and one lacking a sub-category such as `3040` gets its
+/// parent `3000` when that is listed instead.
+fn select_category(
+    category: &str,
+    protocol: &IndexerProtocol,
+    capabilities: Option<&IndexerCapabilities>,
+) -> Option<String> {
+    let id = map_category_to_indexer(category, protocol);
+    let Some(capabilities) = capabilities.filter(|caps| caps.supports_capabilities_detection)
+    else {
+        return Some(id);
+    };
+    if !capabilities.supports_categories {
+        debug!(target: "indexers", category, %id, "indexer lists no categories; searching without one");
+        return None;
+    }
+
+    let supported = |candidate: &str| {
+        capabilities.supported_categories.iter().any(|known| {
+            let known = known.trim();
+            known == candidate || known.eq_ignore_ascii_case(category.trim())
+        })
+    };
+    if supported(&id) {
+        return Some(id);
+    }
+    let parent = id
+        .parse::<u32>()
+        .ok()
+        .map(|numeric| (numeric / 1000 * 1000).to_string())
+        .filter(|parent| *parent != id && supported(parent));
+    if parent.is_none() {
+        debug!(target: "indexers", category, %id, "indexer does not list category; searching without it");
+    }
+    parent
+}
+
+fn category_override<'a>(
+    category: &str,
+    category_map: &'a BTreeMap<String, String>,
+) -> Option<&'a str> {
+    category_map
+        .iter()
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case(category.trim()))
+        .map(|(_, id)| id.trim())
+        .filter(|id| !id.is_empty())
+}
+
+fn map_category_to_indexer(category: &str, protocol: &IndexerProtocol) -> String {
     let normalized = category.trim().to_lowercase();
-    match (protocol, normalized.as_str()) {
+    let id = match (protocol, normalized.as_str()) {
         (IndexerProtocol::Newznab | IndexerProtocol::Torznab, "music") => "3000",
         (IndexerProtocol::Newznab | IndexerProtocol::Torznab, "audio/mp3") => "3010",
         (IndexerProtocol::Newznab | IndexerProtocol::Torznab, "audio/flac") => "3040",
         _ => "3000",
-    }
+    };
+    id.to_string()
 }
 
 async fn execute_api_request(
     client: &Client,
     config: &IndexerConfig,
+    rate_limiter: &IndexerRateLimiter,
     request_type: &str,
     extra_params: Option<Vec<(&str, String)>>,
 ) -> Result<String, IndexerError> {
@@ -537,34 +1174,16 @@ async fn execute_api_request(
         }
     }
 
+    rate_limiter.acquire().await;
     debug!(target: "indexers", base_url = %config.base_url, protocol = %config.protocol.as_str(), "requesting indexer endpoint");
 
-    let response = client
-        .get(url)
-        .send()
-        .await
-        .map_err(|error| IndexerError::Request(error.to_string()))?;
-
-    let status = response.status();
-    let body = response
-        .text()
-        .await
-        .map_err(|error| IndexerError::Request(error.to_string()))?;
-
-    if !status.is_success() {
-        return Err(IndexerError::Request(format!(
-            "status {}: {}",
-            status.as_u16(),
-            body
-        )));
-    }
-
-    Ok(body)
+    send_indexer_request(client.get(url)).await
 }
 
 async fn execute_gazelle_request(
     client: &Client,
     config: &IndexerConfig,
+    rate_limiter: &IndexerRateLimiter,
     action: &str,
     extra_params: Option<Vec<(&str, String)>>,
 ) -> Result<String, IndexerError> {
@@ -598,21 +1217,72 @@ async fn execute_gazelle_request(
         }
     }
 
+    rate_limiter.acquire().await;
     debug!(target: "indexers", base_url = %config.base_url, action, "requesting gazelle endpoint");
 
-    let response = client
-        .get(url)
-        .header("Authorization", format!("Bearer {api_key}"))
-        .header("X-API-Key", api_key)
-        .send()
-        .await
-        .map_err(|error| IndexerError::Request(error.to_string()))?;
+    send_indexer_request(
+        client
+            .get(url)
+            .header("Authorization", format!("Bearer {api_key}"))
+            .header("X-API-Key", api_key),
+    )
+    .await
+}
+
+/// Newznab/Torznab report bad credentials as an `<error code="10x">` body with
+/// HTTP 200; surface those as [`IndexerError::Authentication`].
+fn check_api_error_response(xml: &str) -> Result<(), IndexerError> {
+    let trimmed = xml.trim_start();
+    let Some(element) = trimmed
+        .strip_prefix("<?xml")
+        .and_then(|rest| rest.split_once("?>"))
+        .map_or(trimmed, |(_, rest)| rest)
+        .trim_start()
+        .strip_prefix("<error")
+    else {
+        return Ok(());
+    };
+
+    let attribute = |name: &str| {
+        let start = element.find(&format!("{name}=\""))? + name.len() + 2;
+        let end = element[start..].find('"')?;
+        Some(element[start..start + end].to_string())
+    };
+    let code = attribute("code").unwrap_or_default();
+    let description = attribute("description").unwrap_or_else(|| "unknown error".to_string());
+
+    // Codes 100-102 are the Newznab "incorrect credentials", "account suspended",
+    // and "insufficient privileges" errors.
+    if matches!(code.as_str(), "100" | "101" | "102") {
+        Err(IndexerError::Authentication(format!(
+            "code {code}: {description}"
+        )))
+    } else {
+        Err(IndexerError::Request(format!("code {code}: {description}")))
+    }
+}
+
+/// Send an indexer request and return the body, classifying failures.
+///
+/// Connection and timeout errors, HTTP 401/403, and other non-success statuses
+/// map to distinct [`IndexerError`] variants so callers can tell a wrong API key
+/// from an unreachable host.
+async fn send_indexer_request(request: reqwest::RequestBuilder) -> Result<String, IndexerError> {
+    let response = request.send().await.map_err(classify_transport_error)?;
 
     let status = response.status();
-    let body = response
-        .text()
-        .await
-        .map_err(|error| IndexerError::Request(error.to_string()))?;
+    let body = response.text().await.map_err(classify_transport_error)?;
+
+    if matches!(
+        status,
+        reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::FORBIDDEN
+    ) {
+        return Err(IndexerError::Authentication(format!(
+            "status {}: {}",
+            status.as_u16(),
+            body
+        )));
+    }
 
     if !status.is_success() {
         return Err(IndexerError::Request(format!(
@@ -625,11 +1295,27 @@ async fn execute_gazelle_request(
     Ok(body)
 }
 
+fn classify_transport_error(error: reqwest::Error) -> IndexerError {
+    if error.is_timeout() {
+        IndexerError::Timeout(error.to_string())
+    } else if error.is_connect() {
+        IndexerError::Connection(error.to_string())
+    } else {
+        IndexerError::Request(error.to_string())
+    }
+}
+
 pub fn parse_search_results(xml: &str) -> Result<Vec<IndexerSearchResult>, IndexerError> {
+    parse_search_response(xml).map(|response| response.items)
+}
+
+/// Parse a search page along with its `<newznab:response total=".." offset="..">` element.
+pub fn parse_search_response(xml: &str) -> Result<IndexerSearchResponse, IndexerError> {
     let envelope: SearchEnvelope =
         from_str(xml).map_err(|error| IndexerError::RssParse(error.to_string()))?;
+    let paging = envelope.channel.response;
 
-    Ok(envelope
+    let items = envelope
         .channel
         .items
         .into_iter()
@@ -662,19 +1348,264 @@ pub fn parse_search_results(xml: &str) -> Result<Vec<IndexerSearchResult>, Index
                 leechers,
             }
         })
-        .collect())
-}
+        .collect();
 
-#[derive(Debug, Deserialize)]
-struct SearchEnvelope {
-    channel: SearchChannel,
+    Ok(IndexerSearchResponse {
+        items,
+        total: paging.as_ref().and_then(|paging| paging.total),
+        offset: paging.as_ref().and_then(|paging| paging.offset),
+    })
 }
 
-#[derive(Debug, Deserialize)]
-struct SearchChannel {
-    #[serde(rename = "item", default)]
-    items: Vec<SearchItem>,
-}
+/// Run `query` page by page until the indexer's reported total is reached,
+/// a page comes back short or empty, or `max_results` items were collected.
+///
+/// Pages start at `query.offset` and hold `query.limit` items
+/// ([`DEFAULT_SEARCH_PAGE_SIZE`] when unset).
+pub async fn search_all<C>(
+    client: &C,
+    query: &IndexerSearchQuery,
+    max_results: usize,
+) -> Result<Vec<IndexerSearchResult>, IndexerError>
+where
+    C: IndexerClient + ?Sized,
+{
+    let page_size = query
+        .limit
+        .filter(|limit| *limit > 0)
+        .unwrap_or(DEFAULT_SEARCH_PAGE_SIZE);
+    let mut offset = query.offset.unwrap_or(0);
+    let mut results = Vec::new();
+
+    while results.len() < max_results {
+        let page_query = IndexerSearchQuery {
+            limit: Some(page_size),
+            offset: Some(offset),
+            ..query.clone()
+        };
+        let page = client.search_page(&page_query).await?;
+        let page_len = page.items.len();
+        results.extend(page.items);
+        offset = page.offset.unwrap_or(offset) + page_len;
+
+        let exhausted = match page.total {
+            Some(total) => offset >= total,
+            None => page_len < page_size,
+        };
+        if page_len == 0 || exhausted {
+            break;
+        }
+        trace!(target: "indexers", indexer = %client.config().name, offset, total = ?page.total, "fetching next search page");
+    }
+
+    results.truncate(max_results);
+    Ok(results)
+}
+
+/// Build the client that speaks `config.protocol`, sending requests through `http_client`.
+///
+/// `category_map` overrides the category IDs sent by Newznab and Torznab clients; the
+/// other protocols have no category parameter and ignore it.
+pub fn indexer_client_for(
+    config: IndexerConfig,
+    category_map: BTreeMap<String, String>,
+    http_client: &Client,
+    shared: &SharedIndexerState,
+) -> Arc<dyn IndexerClient> {
+    let http_client = http_client.clone();
+    match config.protocol {
+        IndexerProtocol::Newznab => Arc::new(
+            NewznabClient::new(config)
+                .with_category_map(category_map)
+                .with_http_client(http_client)
+                .with_shared_state(shared),
+        ),
+        IndexerProtocol::Torznab => Arc::new(
+            TorznabClient::new(config)
+                .with_category_map(category_map)
+                .with_http_client(http_client)
+                .with_shared_state(shared),
+        ),
+        IndexerProtocol::Gazelle => Arc::new(
+            GazelleClient::new(config)
+                .with_http_client(http_client)
+                .with_shared_state(shared),
+        ),
+        IndexerProtocol::Custom => Arc::new(
+            RssFeedClient::new(config)
+                .with_http_client(http_client)
+                .with_shared_state(shared),
+        ),
+    }
+}
+
+/// Builds indexer clients that send their requests through one shared HTTP client.
+///
+/// Held in the application state and handed to the scheduler so every search, RSS
+/// poll and connection test reuses the same connection pool, and every client for
+/// a stored indexer shares that indexer's [`SharedIndexerState`].
+#[derive(Debug, Clone)]
+pub struct IndexerClients {
+    http_client: Client,
+    shared: Arc<std::sync::Mutex<HashMap<String, SharedIndexerState>>>,
+}
+
+impl Default for IndexerClients {
+    fn default() -> Self {
+        Self::new(build_indexer_http_client())
+    }
+}
+
+impl IndexerClients {
+    pub fn new(http_client: Client) -> Self {
+        Self {
+            http_client,
+            shared: Arc::default(),
+        }
+    }
+
+    /// The client for a stored indexer definition, or an error if its protocol is unknown.
+    ///
+    /// Clients for the same definition id share one rate limiter and capabilities
+    /// cache; both are replaced when the definition's endpoint, protocol or
+    /// `requests_per_minute` changes.
+    pub fn for_definition(
+        &self,
+        definition: &chorrosion_domain::IndexerDefinition,
+    ) -> Result<Arc<dyn IndexerClient>, String> {
+        let config = IndexerConfig::try_from(definition)?;
+        let shared = self.shared_state(&definition.id.to_string(), &config);
+        Ok(indexer_client_for(
+            config,
+            definition.category_map.clone(),
+            &self.http_client,
+            &shared,
+        ))
+    }
+
+    /// A client for an indexer that is not stored yet, with state of its own.
+    pub fn client(
+        &self,
+        config: IndexerConfig,
+        category_map: BTreeMap<String, String>,
+    ) -> Arc<dyn IndexerClient> {
+        let shared = SharedIndexerState::new(&config);
+        indexer_client_for(config, category_map, &self.http_client, &shared)
+    }
+
+    fn shared_state(&self, id: &str, config: &IndexerConfig) -> SharedIndexerState {
+        let mut shared = self.shared.lock().expect("indexer state lock");
+        match shared.get(id) {
+            Some(state) if state.matches(config) => state.clone(),
+            _ => {
+                let state = SharedIndexerState::new(config);
+                shared.insert(id.to_string(), state.clone());
+                state
+            }
+        }
+    }
+}
+
+/// Test every client's connection concurrently, giving each at most `timeout`.
+///
+/// Returns one result per client, keyed by indexer name, in input order. Tests that
+/// error out or exceed `timeout` become unsuccessful results, so a broken or hanging
+/// indexer never hides the others.
+pub async fn test_all_indexers(
+    clients: Vec<Arc<dyn IndexerClient>>,
+    timeout: Duration,
+) -> Vec<(String, IndexerTestResult)> {
+    let names: Vec<String> = clients
+        .iter()
+        .map(|client| client.config().name.clone())
+        .collect();
+    let mut tasks = JoinSet::new();
+    for (index, client) in clients.into_iter().enumerate() {
+        tasks.spawn(async move {
+            (
+                index,
+                tokio::time::timeout(timeout, client.test_connection()).await,
+            )
+        });
+    }
+
+    let mut results: Vec<Option<IndexerTestResult>> = vec![None; names.len()];
+    while let Some(joined) = tasks.join_next().await {
+        let Ok((index, outcome)) = joined else {
+            continue;
+        };
+        let name = &names[index];
+        let result = match outcome {
+            Ok(Ok(result)) => result,
+            Ok(Err(error)) => {
+                let (failure, message) = error.connection_test_failure(name).unwrap_or_else(|| {
+                    (
+                        IndexerTestFailure::Error,
+                        format!("Indexer '{name}' test failed: {error}"),
+                    )
+                });
+                failed_test(failure, message)
+            }
+            Err(_) => failed_test(
+                IndexerTestFailure::Timeout,
+                format!(
+                    "Indexer '{name}' did not respond within {}s",
+                    timeout.as_secs_f32()
+                ),
+            ),
+        };
+        debug!(target: "indexers", indexer = %name, success = result.success, failure = ?result.failure, "indexer connection tested");
+        results[index] = Some(result);
+    }
+
+    names
+        .into_iter()
+        .zip(results)
+        .map(|(name, result)| {
+            let result = result.unwrap_or_else(|| {
+                failed_test(
+                    IndexerTestFailure::Error,
+                    format!("Indexer '{name}' test aborted unexpectedly"),
+                )
+            });
+            (name, result)
+        })
+        .collect()
+}
+
+fn failed_test(failure: IndexerTestFailure, message: String) -> IndexerTestResult {
+    IndexerTestResult {
+        success: false,
+        message,
+        capabilities: None,
+        failure: Some(failure),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchEnvelope {
+    channel: SearchChannel,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchChannel {
+    #[serde(
+        rename = "newznab:response",
+        alias = "torznab:response",
+        alias = "response"
+    )]
+    response: Option<SearchPaging>,
+    #[serde(rename = "item", default)]
+    items: Vec<SearchItem>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchPaging {
+    #[serde(rename = "@total")]
+    total: Option<usize>,
+    #[serde(rename = "@offset")]
+    offset: Option<usize>,
+}
 
 #[derive(Debug, Deserialize)]
 struct SearchItem {
@@ -785,6 +1716,7 @@ struct GazelleTorrent {
 async fn execute_gazelle_search(
     client: &Client,
     config: &IndexerConfig,
+    rate_limiter: &IndexerRateLimiter,
     query: &IndexerSearchQuery,
 ) -> Result<Vec<IndexerSearchResult>, IndexerError> {
     if query.offset.is_some() {
@@ -813,7 +1745,8 @@ async fn execute_gazelle_search(
         }
     }
 
-    let body = execute_gazelle_request(client, config, "browse", Some(params)).await?;
+    let body =
+        execute_gazelle_request(client, config, rate_limiter, "browse", Some(params)).await?;
 
     let parsed: GazelleResponse<GazelleBrowseResponse> = serde_json::from_str(&body)
         .map_err(|error| IndexerError::Request(format!("gazelle JSON parse error: {error}")))?;
@@ -968,12 +1901,63 @@ struct RssRawItem {
 #[cfg(test)]
 mod tests {
     use super::{
-        parse_rss_feed, parse_search_results, GazelleClient, IndexerClient, IndexerConfig,
-        IndexerProtocol, IndexerSearchQuery, NewznabClient, TorznabClient,
+        parse_rss_feed, parse_search_response, parse_search_results, search_all, test_all_indexers,
+        CapabilitiesCache, GazelleClient, IndexerClient, IndexerClients, IndexerConfig,
+        IndexerError, IndexerProtocol, IndexerRateLimiter, IndexerSearchQuery, IndexerTestFailure,
+        NewznabClient, RssFeedClient, TorznabClient, CAPABILITIES_FAILURE_TTL,
     };
-    use wiremock::matchers::{header, method, path, query_param};
+    use chorrosion_domain::IndexerDefinition;
+    use reqwest::Client;
+    use std::collections::BTreeMap;
+    use std::time::Duration;
+    use tokio::time::Instant;
+    use wiremock::matchers::{header, method, path, query_param, query_param_is_missing};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
+    #[test]
+    fn indexer_config_debug_redacts_api_key() {
+        let config = IndexerConfig {
+            name: "indexer".to_string(),
+            base_url: "https://indexer.example".to_string(),
+            protocol: IndexerProtocol::Newznab,
+            api_key: Some("indexer-secret".to_string()),
+            enabled: true,
+            requests_per_minute: None,
+        };
+
+        let debug = format!("{config:?}");
+        assert!(!debug.contains("indexer-secret"), "leaked key: {debug}");
+        assert!(debug.contains("[REDACTED]"));
+        assert_eq!(config.api_key(), Some("indexer-secret"));
+
+        let json = serde_json::to_string(&config).expect("serialize");
+        assert!(json.contains("indexer-secret"));
+    }
+
+    #[test]
+    fn indexer_config_converts_from_config_section() {
+        let section = chorrosion_config::IndexerConfig {
+            name: "Example".to_string(),
+            base_url: "https://torznab.example".to_string(),
+            protocol: "Torznab".to_string(),
+            api_key: Some("key".to_string()),
+            enabled: false,
+            requests_per_minute: Some(10),
+        };
+
+        let config = IndexerConfig::try_from(&section).expect("known protocol");
+        assert_eq!(config.protocol, IndexerProtocol::Torznab);
+        assert_eq!(config.api_key(), Some("key"));
+        assert!(!config.enabled);
+        assert_eq!(config.requests_per_minute, Some(10));
+
+        let unknown = chorrosion_config::IndexerConfig {
+            protocol: "gopher".to_string(),
+            ..section
+        };
+        assert!(IndexerConfig::try_from(&unknown).is_err());
+    }
+
     #[test]
     fn parses_rss_items() {
         let xml = r#"
@@ -1089,6 +2073,7 @@ mod tests {
             protocol: IndexerProtocol::Newznab,
             api_key: Some("secret".to_string()),
             enabled: true,
+            requests_per_minute: None,
         });
 
         let results = client
@@ -1105,6 +2090,113 @@ mod tests {
         assert_eq!(results[0].title, "Nevermind FLAC");
     }
 
+    #[tokio::test]
+    async fn newznab_requests_send_chorrosion_user_agent() {
+        let server = MockServer::start().await;
+        let user_agent = crate::http_client::default_user_agent();
+
+        Mock::given(method("GET"))
+            .and(path("/api"))
+            .and(header("user-agent", user_agent))
+            .respond_with(ResponseTemplate::new(200).set_body_string("<rss><channel/></rss>"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = NewznabClient::new(newznab_config(server.uri()));
+        client
+            .search(&IndexerSearchQuery {
+                query: "nirvana".to_string(),
+                category: None,
+                limit: None,
+                offset: None,
+            })
+            .await
+            .expect("request should carry the user-agent");
+        assert!(user_agent.starts_with("Chorrosion/"));
+    }
+
+    fn paged_newznab_body(titles: &[&str], offset: usize, total: usize) -> String {
+        let items: String = titles
+            .iter()
+            .map(|title| {
+                format!(
+                    "<item><title>{title}</title><link>https://example.com/{title}</link></item>"
+                )
+            })
+            .collect();
+        format!(
+            r#"<rss xmlns:newznab="http://www.newznab.com/DTD/2010/feeds/attributes/"><channel><newznab:response offset="{offset}" total="{total}"/>{items}</channel></rss>"#
+        )
+    }
+
+    async fn mount_page(server: &MockServer, offset: &str, body: String) {
+        Mock::given(method("GET"))
+            .and(path("/api"))
+            .and(query_param("t", "search"))
+            .and(query_param("limit", "2"))
+            .and(query_param("offset", offset))
+            .respond_with(ResponseTemplate::new(200).set_body_string(body))
+            .expect(1)
+            .mount(server)
+            .await;
+    }
+
+    fn paged_client(server: &MockServer) -> NewznabClient {
+        NewznabClient::new(IndexerConfig {
+            name: "paged-newznab".to_string(),
+            base_url: server.uri(),
+            protocol: IndexerProtocol::Newznab,
+            api_key: None,
+            enabled: true,
+            requests_per_minute: None,
+        })
+    }
+
+    fn paged_query() -> IndexerSearchQuery {
+        IndexerSearchQuery {
+            query: "boards of canada".to_string(),
+            category: None,
+            limit: Some(2),
+            offset: None,
+        }
+    }
+
+    #[test]
+    fn parse_search_response_reads_paging_metadata() {
+        let response = parse_search_response(&paged_newznab_body(&["a", "b"], 40, 125)).unwrap();
+
+        assert_eq!(response.items.len(), 2);
+        assert_eq!(response.total, Some(125));
+        assert_eq!(response.offset, Some(40));
+    }
+
+    #[tokio::test]
+    async fn search_all_fetches_every_page_until_total() {
+        let server = MockServer::start().await;
+        mount_page(&server, "0", paged_newznab_body(&["one", "two"], 0, 3)).await;
+        mount_page(&server, "2", paged_newznab_body(&["three"], 2, 3)).await;
+
+        let results = search_all(&paged_client(&server), &paged_query(), 100)
+            .await
+            .unwrap();
+
+        let titles: Vec<&str> = results.iter().map(|result| result.title.as_str()).collect();
+        assert_eq!(titles, ["one", "two", "three"]);
+    }
+
+    #[tokio::test]
+    async fn search_all_stops_at_max_results() {
+        let server = MockServer::start().await;
+        mount_page(&server, "0", paged_newznab_body(&["one", "two"], 0, 500)).await;
+
+        let results = search_all(&paged_client(&server), &paged_query(), 2)
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), 2);
+    }
+
     #[tokio::test]
     async fn torznab_search_prefers_magnet_from_enclosure() {
         let server = MockServer::start().await;
@@ -1138,6 +2230,7 @@ mod tests {
             protocol: IndexerProtocol::Torznab,
             api_key: None,
             enabled: true,
+            requests_per_minute: None,
         });
 
         let results = client
@@ -1196,6 +2289,7 @@ mod tests {
             protocol: IndexerProtocol::Torznab,
             api_key: None,
             enabled: true,
+            requests_per_minute: None,
         });
 
         let results = client
@@ -1256,6 +2350,7 @@ mod tests {
             protocol: IndexerProtocol::Torznab,
             api_key: None,
             enabled: true,
+            requests_per_minute: None,
         });
 
         let results = client
@@ -1303,6 +2398,7 @@ mod tests {
             protocol: IndexerProtocol::Newznab,
             api_key: None,
             enabled: true,
+            requests_per_minute: None,
         });
 
         let rss_items = client
@@ -1335,6 +2431,7 @@ mod tests {
             protocol: IndexerProtocol::Gazelle,
             api_key: Some("gazelle-secret".to_string()),
             enabled: true,
+            requests_per_minute: None,
         });
 
         let result = client
@@ -1356,6 +2453,7 @@ mod tests {
             protocol: IndexerProtocol::Gazelle,
             api_key: None,
             enabled: true,
+            requests_per_minute: None,
         });
 
         let error = client
@@ -1415,6 +2513,7 @@ mod tests {
             protocol: IndexerProtocol::Gazelle,
             api_key: Some("secret".to_string()),
             enabled: true,
+            requests_per_minute: None,
         });
 
         let results = client
@@ -1477,6 +2576,7 @@ mod tests {
             protocol: IndexerProtocol::Gazelle,
             api_key: Some("secret".to_string()),
             enabled: true,
+            requests_per_minute: None,
         });
 
         let results = client
@@ -1507,6 +2607,7 @@ mod tests {
             protocol: IndexerProtocol::Gazelle,
             api_key: Some("secret".to_string()),
             enabled: true,
+            requests_per_minute: None,
         });
 
         let error = client
@@ -1561,6 +2662,7 @@ mod tests {
             protocol: IndexerProtocol::Gazelle,
             api_key: Some("secret".to_string()),
             enabled: true,
+            requests_per_minute: None,
         });
 
         let results = client
@@ -1629,6 +2731,7 @@ mod tests {
             protocol: IndexerProtocol::Gazelle,
             api_key: Some("secret".to_string()),
             enabled: true,
+            requests_per_minute: None,
         });
 
         let results = client
@@ -1649,4 +2752,643 @@ mod tests {
             Some(expected_download.as_str())
         );
     }
+
+    fn empty_search_response() -> ResponseTemplate {
+        ResponseTemplate::new(200).set_body_string(
+            r#"<rss><channel><item><title>Result</title><guid>r-1</guid></item></channel></rss>"#,
+        )
+    }
+
+    fn search_query() -> IndexerSearchQuery {
+        IndexerSearchQuery {
+            query: "nirvana".to_string(),
+            category: None,
+            limit: None,
+            offset: None,
+        }
+    }
+
+    #[test]
+    fn rate_limiter_derives_interval_from_requests_per_minute() {
+        assert_eq!(
+            IndexerRateLimiter::new(Some(120)).min_interval(),
+            Some(Duration::from_millis(500))
+        );
+        assert_eq!(IndexerRateLimiter::new(Some(0)).min_interval(), None);
+        assert_eq!(IndexerRateLimiter::new(None).min_interval(), None);
+    }
+
+    #[tokio::test]
+    async fn back_to_back_searches_against_same_indexer_are_spaced() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api"))
+            .and(query_param("t", "search"))
+            .respond_with(empty_search_response())
+            .mount(&server)
+            .await;
+
+        // 300 requests per minute => 200ms minimum interval.
+        let client = NewznabClient::new(IndexerConfig {
+            name: "limited-newznab".to_string(),
+            base_url: server.uri(),
+            protocol: IndexerProtocol::Newznab,
+            api_key: None,
+            enabled: true,
+            requests_per_minute: Some(300),
+        });
+
+        let start = Instant::now();
+        client
+            .search(&search_query())
+            .await
+            .expect("first search should succeed");
+        client
+            .search(&search_query())
+            .await
+            .expect("second search should succeed");
+        let elapsed = start.elapsed();
+
+        assert!(
+            elapsed >= Duration::from_millis(200),
+            "expected >= 200ms, got {:?}",
+            elapsed
+        );
+    }
+
+    #[tokio::test]
+    async fn clients_built_for_the_same_definition_share_one_limiter() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api"))
+            .and(query_param("t", "search"))
+            .respond_with(empty_search_response())
+            .mount(&server)
+            .await;
+
+        // 300 requests per minute => 200ms minimum interval.
+        let mut definition = IndexerDefinition::new("limited", server.uri(), "newznab");
+        definition.requests_per_minute = Some(300);
+        let indexer_clients = IndexerClients::new(Client::new());
+
+        let start = Instant::now();
+        for _ in 0..2 {
+            indexer_clients
+                .for_definition(&definition)
+                .expect("newznab client")
+                .search(&search_query())
+                .await
+                .expect("search should succeed");
+        }
+        let elapsed = start.elapsed();
+
+        assert!(
+            elapsed >= Duration::from_millis(200),
+            "expected >= 200ms, got {:?}",
+            elapsed
+        );
+
+        definition.requests_per_minute = None;
+        let unlimited = indexer_clients
+            .for_definition(&definition)
+            .expect("newznab client");
+        let start = Instant::now();
+        unlimited
+            .search(&search_query())
+            .await
+            .expect("search should succeed");
+        assert!(
+            start.elapsed() < Duration::from_millis(200),
+            "a changed limit should replace the shared limiter"
+        );
+    }
+
+    #[tokio::test]
+    async fn searches_against_different_indexers_are_not_serialized() {
+        let server_a = MockServer::start().await;
+        let server_b = MockServer::start().await;
+
+        for server in [&server_a, &server_b] {
+            Mock::given(method("GET"))
+                .and(path("/api"))
+                .and(query_param("t", "search"))
+                .respond_with(empty_search_response().set_delay(Duration::from_millis(300)))
+                .mount(server)
+                .await;
+        }
+
+        let client_a = NewznabClient::new(IndexerConfig {
+            name: "indexer-a".to_string(),
+            base_url: server_a.uri(),
+            protocol: IndexerProtocol::Newznab,
+            api_key: None,
+            enabled: true,
+            requests_per_minute: Some(1),
+        });
+        let client_b = NewznabClient::new(IndexerConfig {
+            name: "indexer-b".to_string(),
+            base_url: server_b.uri(),
+            protocol: IndexerProtocol::Newznab,
+            api_key: None,
+            enabled: true,
+            requests_per_minute: Some(1),
+        });
+
+        let query = search_query();
+        let start = Instant::now();
+        let (result_a, result_b) = tokio::join!(client_a.search(&query), client_b.search(&query));
+        let elapsed = start.elapsed();
+
+        result_a.expect("indexer a search should succeed");
+        result_b.expect("indexer b search should succeed");
+        assert!(
+            elapsed < Duration::from_millis(550),
+            "expected concurrent searches to finish in < 550ms, got {:?}",
+            elapsed
+        );
+    }
+
+    fn rss_feed_config(base_url: String) -> IndexerConfig {
+        IndexerConfig {
+            name: "plain-feed".to_string(),
+            base_url,
+            protocol: IndexerProtocol::Custom,
+            api_key: None,
+            enabled: true,
+            requests_per_minute: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn rss_feed_client_fetches_base_url_as_feed() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/feeds/music.xml"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"
+                <rss version="2.0">
+                  <channel>
+                    <title>Music releases</title>
+                    <item>
+                      <title>Artist - Album [FLAC]</title>
+                      <guid>feed-1</guid>
+                      <link>https://example.com/release/1.torrent</link>
+                      <pubDate>Mon, 02 Mar 2026 10:00:00 +0000</pubDate>
+                    </item>
+                    <item>
+                      <title>Other - Record 320kbps MP3</title>
+                      <guid>feed-2</guid>
+                      <enclosure url="magnet:?xt=urn:btih:feed2" length="1" type="application/x-bittorrent" />
+                    </item>
+                  </channel>
+                </rss>
+                "#,
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client =
+            RssFeedClient::new(rss_feed_config(format!("{}/feeds/music.xml", server.uri())));
+
+        let items = client
+            .fetch_rss_feed()
+            .await
+            .expect("plain RSS feed should parse");
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].title, "Artist - Album [FLAC]");
+        assert_eq!(
+            items[0].download_url.as_deref(),
+            Some("https://example.com/release/1.torrent")
+        );
+        assert_eq!(
+            items[0].published_at.as_deref(),
+            Some("2026-03-02T10:00:00+00:00")
+        );
+        assert_eq!(
+            items[1].download_url.as_deref(),
+            Some("magnet:?xt=urn:btih:feed2")
+        );
+    }
+
+    #[tokio::test]
+    async fn rss_feed_client_reports_search_and_capabilities_unsupported() {
+        let client = RssFeedClient::new(rss_feed_config("https://feed.example/rss".to_string()));
+
+        let search = client
+            .search(&IndexerSearchQuery {
+                query: "anything".to_string(),
+                category: None,
+                limit: None,
+                offset: None,
+            })
+            .await;
+        assert!(matches!(search, Err(IndexerError::Unsupported(_))));
+        assert!(matches!(
+            client.detect_capabilities().await,
+            Err(IndexerError::Unsupported(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn rss_feed_client_surfaces_http_errors() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/rss"))
+            .respond_with(ResponseTemplate::new(503).set_body_string("down"))
+            .mount(&server)
+            .await;
+
+        let client = RssFeedClient::new(rss_feed_config(format!("{}/rss", server.uri())));
+
+        match client.fetch_rss_feed().await {
+            Err(IndexerError::Request(message)) => assert!(message.contains("503")),
+            other => panic!("expected request error, got {other:?}"),
+        }
+    }
+
+    fn newznab_config(base_url: String) -> IndexerConfig {
+        IndexerConfig {
+            name: "test-newznab".to_string(),
+            base_url,
+            protocol: IndexerProtocol::Newznab,
+            api_key: Some("wrong-key".to_string()),
+            enabled: true,
+            requests_per_minute: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_connection_reports_auth_failure_for_401() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api"))
+            .and(query_param("t", "caps"))
+            .respond_with(ResponseTemplate::new(401).set_body_string("invalid api key"))
+            .mount(&server)
+            .await;
+
+        let client = NewznabClient::new(newznab_config(server.uri()));
+
+        assert!(matches!(
+            client.detect_capabilities().await,
+            Err(IndexerError::Authentication(_))
+        ));
+        let result = client
+            .test_connection()
+            .await
+            .expect("auth failure should produce a test result");
+        assert!(!result.success);
+        assert!(
+            result.message.contains("rejected the API key"),
+            "message: {}",
+            result.message
+        );
+        assert!(result.capabilities.is_none());
+        assert_eq!(result.failure, Some(IndexerTestFailure::Authentication));
+    }
+
+    #[tokio::test]
+    async fn test_connection_reports_newznab_credential_error_body() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api"))
+            .and(query_param("t", "caps"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"<?xml version="1.0" encoding="UTF-8"?><error code="100" description="Incorrect user credentials"/>"#,
+            ))
+            .mount(&server)
+            .await;
+
+        let client = NewznabClient::new(newznab_config(server.uri()));
+
+        match client.detect_capabilities().await {
+            Err(IndexerError::Authentication(message)) => {
+                assert!(message.contains("Incorrect user credentials"))
+            }
+            other => panic!("expected authentication error, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_connection_reports_unreachable_host() {
+        // Nothing listens on port 1, so the connection is refused immediately.
+        let client = TorznabClient::new(IndexerConfig {
+            protocol: IndexerProtocol::Torznab,
+            ..newznab_config("http://127.0.0.1:1".to_string())
+        });
+
+        assert!(matches!(
+            client.detect_capabilities().await,
+            Err(IndexerError::Connection(_))
+        ));
+        let result = client
+            .test_connection()
+            .await
+            .expect("connection failure should produce a test result");
+        assert!(!result.success);
+        assert!(
+            result.message.contains("unreachable"),
+            "message: {}",
+            result.message
+        );
+        assert_eq!(result.failure, Some(IndexerTestFailure::Unreachable));
+    }
+
+    #[tokio::test]
+    async fn detect_capabilities_rejects_non_xml_response() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api"))
+            .and(query_param("t", "caps"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("Service maintenance"))
+            .mount(&server)
+            .await;
+
+        let client = NewznabClient::new(newznab_config(server.uri()));
+
+        assert!(matches!(
+            client.detect_capabilities().await,
+            Err(IndexerError::MalformedResponse(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn category_map_overrides_default_category_id() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api"))
+            .and(query_param("t", "search"))
+            .and(query_param("cat", "7040"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"<rss><channel><item><title>Album FLAC</title><guid>m-1</guid><link>https://example.com/nzb</link></item></channel></rss>"#,
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = NewznabClient::new(IndexerConfig {
+            name: "test-newznab".to_string(),
+            base_url: server.uri(),
+            protocol: IndexerProtocol::Newznab,
+            api_key: Some("secret".to_string()),
+            enabled: true,
+            requests_per_minute: None,
+        })
+        .with_category_map(
+            [("Audio/FLAC".to_string(), "7040".to_string())]
+                .into_iter()
+                .collect(),
+        );
+
+        let results = client
+            .search(&IndexerSearchQuery {
+                query: "album".to_string(),
+                category: Some("audio/flac".to_string()),
+                limit: None,
+                offset: None,
+            })
+            .await
+            .expect("search with mapped category should succeed");
+
+        assert_eq!(results.len(), 1);
+    }
+
+    async fn mount_caps(server: &MockServer, body: &str) {
+        Mock::given(method("GET"))
+            .and(path("/api"))
+            .and(query_param("t", "caps"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(body.to_string()))
+            .expect(1)
+            .mount(server)
+            .await;
+    }
+
+    fn categorized_query(category: &str) -> IndexerSearchQuery {
+        IndexerSearchQuery {
+            query: "nirvana".to_string(),
+            category: Some(category.to_string()),
+            limit: None,
+            offset: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn search_sends_only_categories_the_indexer_reports() {
+        let server = MockServer::start().await;
+        mount_caps(
+            &server,
+            r#"<caps><searching><search available="yes"/></searching><categories><category id="3000" name="Audio"/></categories></caps>"#,
+        )
+        .await;
+        Mock::given(method("GET"))
+            .and(path("/api"))
+            .and(query_param("t", "search"))
+            .and(query_param("cat", "3000"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("<rss><channel/></rss>"))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = NewznabClient::new(newznab_config(server.uri()));
+        client
+            .search(&categorized_query("music"))
+            .await
+            .expect("music search should succeed");
+        // 3040 is not listed, so the parent category is sent instead; the
+        // capabilities are reused rather than probed again.
+        client
+            .search(&categorized_query("audio/flac"))
+            .await
+            .expect("flac search should succeed");
+    }
+
+    #[tokio::test]
+    async fn clients_for_the_same_definition_share_detected_capabilities() {
+        let server = MockServer::start().await;
+        mount_caps(
+            &server,
+            r#"<caps><searching><search available="yes"/></searching><categories><category id="3000" name="Audio"/></categories></caps>"#,
+        )
+        .await;
+        Mock::given(method("GET"))
+            .and(path("/api"))
+            .and(query_param("t", "search"))
+            .and(query_param("cat", "3000"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("<rss><channel/></rss>"))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let definition = IndexerDefinition::new("shared-caps", server.uri(), "newznab");
+        let indexer_clients = IndexerClients::new(Client::new());
+        for _ in 0..2 {
+            indexer_clients
+                .for_definition(&definition)
+                .expect("newznab client")
+                .search(&categorized_query("audio/flac"))
+                .await
+                .expect("search should succeed");
+        }
+    }
+
+    #[tokio::test]
+    async fn failed_capabilities_probe_is_retried_after_the_failure_ttl() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api"))
+            .and(query_param("t", "caps"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        mount_caps(
+            &server,
+            r#"<caps><searching><search available="yes"/></searching><categories><category id="3000" name="Audio"/></categories></caps>"#,
+        )
+        .await;
+        let config = newznab_config(server.uri());
+        let rate_limiter = IndexerRateLimiter::new(None);
+        let cache = CapabilitiesCache::default();
+        let client = Client::new();
+
+        assert!(cache
+            .get_or_detect(&client, &config, &rate_limiter)
+            .await
+            .is_none());
+        // Still within the failure TTL: the failure is reused without a new probe.
+        assert!(cache
+            .get_or_detect(&client, &config, &rate_limiter)
+            .await
+            .is_none());
+
+        if let Some(cached) = cache.detected.lock().await.as_mut() {
+            cached.detected_at -= CAPABILITIES_FAILURE_TTL;
+        }
+        let capabilities = cache
+            .get_or_detect(&client, &config, &rate_limiter)
+            .await
+            .expect("capabilities after retry");
+
+        assert_eq!(capabilities.supported_categories, ["3000"]);
+    }
+
+    #[tokio::test]
+    async fn detect_capabilities_falls_back_to_default_categories() {
+        let server = MockServer::start().await;
+        mount_caps(
+            &server,
+            r#"<caps><searching><search available="yes"/></searching></caps>"#,
+        )
+        .await;
+
+        let capabilities = NewznabClient::new(newznab_config(server.uri()))
+            .detect_capabilities()
+            .await
+            .expect("capabilities");
+
+        assert_eq!(
+            capabilities.supported_categories,
+            ["music", "audio/flac", "audio/mp3"]
+        );
+    }
+
+    #[tokio::test]
+    async fn search_omits_category_when_indexer_reports_none() {
+        let server = MockServer::start().await;
+        mount_caps(
+            &server,
+            r#"<caps><searching><search available="yes"/></searching></caps>"#,
+        )
+        .await;
+        Mock::given(method("GET"))
+            .and(path("/api"))
+            .and(query_param("t", "search"))
+            .and(query_param("q", "nirvana"))
+            .and(query_param_is_missing("cat"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"<rss><channel><item><title>Nevermind FLAC</title><guid>n-1</guid><link>https://example.com/nzb</link></item></channel></rss>"#,
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = NewznabClient::new(newznab_config(server.uri()));
+        let results = client
+            .search(&categorized_query("music"))
+            .await
+            .expect("search should succeed without a category");
+
+        assert_eq!(results.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_all_indexers_bounds_slow_indexers_by_timeout() {
+        let healthy = MockServer::start().await;
+        mount_caps(
+            &healthy,
+            r#"<caps><categories><category id="3000" name="Audio"/></categories></caps>"#,
+        )
+        .await;
+        let slow = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string("<caps/>")
+                    .set_delay(Duration::from_secs(10)),
+            )
+            .mount(&slow)
+            .await;
+
+        let indexer_clients = IndexerClients::new(Client::new());
+        let clients = vec![
+            indexer_clients.client(
+                IndexerConfig {
+                    name: "healthy".to_string(),
+                    ..newznab_config(healthy.uri())
+                },
+                BTreeMap::new(),
+            ),
+            indexer_clients.client(
+                IndexerConfig {
+                    name: "slow".to_string(),
+                    ..newznab_config(slow.uri())
+                },
+                BTreeMap::new(),
+            ),
+            indexer_clients.client(
+                IndexerConfig {
+                    name: "gazelle-without-key".to_string(),
+                    protocol: IndexerProtocol::Gazelle,
+                    api_key: None,
+                    ..newznab_config(healthy.uri())
+                },
+                BTreeMap::new(),
+            ),
+        ];
+
+        let started = Instant::now();
+        let results = test_all_indexers(clients, Duration::from_millis(300)).await;
+
+        assert!(started.elapsed() < Duration::from_secs(5));
+        let names: Vec<_> = results.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["healthy", "slow", "gazelle-without-key"]);
+        assert!(results[0].1.success, "{:?}", results[0].1);
+        assert_eq!(results[0].1.failure, None);
+        assert!(!results[1].1.success);
+        assert_eq!(results[1].1.failure, Some(IndexerTestFailure::Timeout));
+        assert!(!results[2].1.success);
+        assert_eq!(results[2].1.failure, Some(IndexerTestFailure::Error));
+    }
 }
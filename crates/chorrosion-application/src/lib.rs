@@ -4,19 +4,25 @@ use chorrosion_infrastructure::{
     repositories::{
         AlbumRepository, ArtistRepository, DownloadClientDefinitionRepository, DuplicateRepository,
         IndexerDefinitionRepository, MetadataProfileRepository, QualityProfileRepository,
-        SmartPlaylistRepository, TagRepository, TaggedEntityRepository, TrackRepository,
+        SmartPlaylistRepository, TagRepository, TaggedEntityRepository, TrackFileRepository,
+        TrackRepository,
     },
     ResponseCache,
 };
+use chorrosion_realtime::{SseHub, WebSocketHub};
 use moka::sync::Cache;
 use std::cmp::Reverse;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::sync::Mutex;
 use std::time::{Duration, Instant};
+pub mod album_files;
 pub mod appearance;
+pub mod audio_probe;
 pub mod community_indexers;
+pub mod delay_profile;
 pub mod download_clients;
+pub mod duplicate_report;
 pub mod embedded_tags;
 pub mod events;
 pub mod file_organization;
@@ -26,15 +32,20 @@ mod http_client;
 pub mod import;
 pub mod import_matching;
 pub mod indexers;
+pub mod library_bundle;
 pub mod lists;
 pub mod matching;
 pub mod matching_precedence;
+pub mod metrics;
+pub mod monitoring;
 pub mod notifications;
 pub mod permission;
 pub mod plugins;
 pub mod quality_upgrade;
+pub mod release_groups;
 pub mod release_parsing;
 pub mod release_restrictions;
+pub mod rematch;
 pub mod scan_cache;
 pub mod script_hooks;
 pub mod search_automation;
@@ -43,14 +54,19 @@ pub mod tag_sanitation;
 #[cfg(test)]
 pub(crate) mod test_fixtures;
 
+pub use audio_probe::{probe_audio, AudioProbeError, AudioProbeResult, AudioProperties};
 pub use community_indexers::{CommunityIndexerRegistry, CommunityIndexerTemplate};
+pub use delay_profile::DelayProfile;
 pub use download_clients::{
-    AddTorrentRequest, DelugeClient, DownloadClient, DownloadClientError, DownloadItem,
-    DownloadState, NzbgetClient, QBittorrentClient, SabnzbdClient, TransmissionClient,
+    download_client_for, AddTorrentRequest, DelugeClient, DownloadClient, DownloadClientError,
+    DownloadItem, DownloadState, NzbgetClient, QBittorrentClient, SabnzbdClient,
+    TransmissionClient,
 };
+pub use duplicate_report::{DuplicateCluster, DuplicateClusterFile, DuplicateReportService};
 pub use embedded_tags::{
     EmbeddedTagError, EmbeddedTagMatchingService, EmbeddedTagResult, ExtractedTags,
 };
+pub use events::{channel_for_event, EventDispatcher};
 pub use file_organization::{
     apply_file_operation, build_organized_file_path, render_naming_pattern, FileOperationMode,
     FileOrganizationError, TrackPathContext,
@@ -61,27 +77,44 @@ pub use file_replacement::{
 pub use filename_heuristics::{
     FilenameHeuristicsError, FilenameHeuristicsResult, FilenameHeuristicsService, ParsedFilename,
 };
-pub use import::{FileImportService, ImportError, ImportResult, ImportedFile};
+pub use http_client::OutboundHttp;
+pub use import::{
+    FileImportService, ImportBatchSummary, ImportError, ImportMode, ImportOutcome, ImportResult,
+    ImportedFile,
+};
 pub use import_matching::{
-    evaluate_import_match, parse_track_metadata, scan_audio_files, CatalogAlbum, CatalogAlbumMatch,
-    ImportDecision, ImportEvaluation, ImportMatchingError, MatchStrategy, MetadataSource,
-    ParsedTrackMetadata, RawTrackMetadata, ScannedAudioFile,
+    evaluate_import_match, parse_track_metadata, scan_audio_files, AudioExtensions, CatalogAlbum,
+    CatalogAlbumMatch, ImportDecision, ImportEvaluation, ImportMatchingError, MatchStrategy,
+    MetadataSource, ParsedTrackMetadata, RawTrackMetadata, ScannedAudioFile,
 };
 pub use indexers::{
-    parse_rss_feed, parse_search_results, GazelleClient, IndexerCapabilities, IndexerClient,
+    indexer_client_for, parse_rss_feed, parse_search_response, parse_search_results, search_all,
+    test_all_indexers, GazelleClient, IndexerCapabilities, IndexerClient, IndexerClients,
     IndexerConfig, IndexerError, IndexerProtocol, IndexerRssItem, IndexerSearchQuery,
-    IndexerSearchResult, IndexerTestResult, NewznabClient, TorznabClient,
+    IndexerSearchResponse, IndexerSearchResult, IndexerTestFailure, IndexerTestResult,
+    NewznabClient, RssFeedClient, SharedIndexerState, TorznabClient, DEFAULT_SEARCH_PAGE_SIZE,
+};
+pub use library_bundle::{
+    BundleAction, BundleArtist, BundleImportItem, BundleImportMode, BundleImportReport,
+    BundleIndexer, BundleMetadataProfile, BundleQualityProfile, BundleSection, LibraryBundle,
+    LibraryBundleError, LibraryBundleResult, LibraryBundleService, LIBRARY_BUNDLE_VERSION,
 };
 pub use lists::{
     auto_add_from_list_entries, dedupe_list_entries, ExternalListEntry, LastFmListProvider,
     ListAutoAddSummary, ListEntityType, ListProvider, ListProviderCapabilities, ListProviderHealth,
     MusicBrainzListProvider, SpotifyPlaylistListProvider,
 };
-pub use matching::{MatchResult, MatchingError, MatchingResult, TrackMatchingService};
+pub use matching::{
+    apply_duration_tolerance, explain_track_match, MatchContribution, MatchExplanation,
+    MatchFactor, MatchResult, MatchingError, MatchingResult, TrackMatchingService,
+    DEFAULT_DURATION_TOLERANCE_MS,
+};
 pub use matching_precedence::{
     MatchingStrategy, PrecedenceMatchResult, PrecedenceMatchingEngine, PrecedenceMatchingError,
-    PrecedenceMatchingResult,
+    PrecedenceMatchingResult, PrecedenceWeights,
 };
+pub use metrics::{ActiveJobGuard, AppMetrics, PoolUsage};
+pub use monitoring::{apply_monitoring, set_artist_monitored, MonitorOption, MonitoringSummary};
 pub use notifications::{
     DiscordWebhookProvider, EmailNotificationProvider, NoopNotificationProvider, NotificationEvent,
     NotificationEventKind, NotificationPipeline, NotificationProvider, NotificationProviderConfig,
@@ -92,20 +125,31 @@ pub use plugins::{
     ExtensionApiHandler, ExtensionApiRequest, ExtensionApiResponse, Plugin, PluginCapability,
     PluginManifest, PluginRegistry,
 };
-pub use quality_upgrade::{QualityComparer, QualityUpgradeService, UpgradeDecision, UpgradeReason};
+pub use quality_upgrade::{
+    QualityComparer, QualityRank, QualityUpgradeService, UpgradeDecision, UpgradeReason,
+};
+pub use release_groups::{sync_release_groups, ReleaseGroupSyncSummary};
 pub use release_parsing::{
-    deduplicate_releases, filter_releases, find_duplicate_keys, parse_release_title, rank_releases,
-    AudioQuality, CustomFormatRule, ParsedReleaseTitle, ReleaseFilterOptions,
+    deduplicate_releases, deduplicate_releases_with_strategy, filter_releases, find_duplicate_keys,
+    find_duplicate_keys_with_strategy, parse_release_title, rank_releases,
+    rank_releases_for_profile, score_release, AudioQuality, CustomFormatRule, DedupeStrategy,
+    ParsedReleaseTitle, ReleaseFilterOptions, TorrentHealth,
 };
 pub use release_restrictions::{ReleaseRestrictionSet, RestrictionRule};
+pub use rematch::{
+    RematchError, RematchOutcome, RematchResult, TrackFileRematchService, TrackMatch,
+};
 pub use scan_cache::{cached_scan_audio_files, DirScanCache};
 pub use script_hooks::{
     ScriptHookContext, ScriptHookDefinition, ScriptHookError, ScriptHookRegistry, ScriptHookResult,
     ScriptHookRunner, ScriptHookType,
 };
 pub use search_automation::{
-    automatic_search_missing_albums, detect_missing_albums, manual_search, AlbumSearchTarget,
-    AutomaticSearchDecision, ManualSearchRequest, RankedRelease,
+    active_download_album_keys, album_search_target, automatic_search_missing_albums,
+    build_album_query, deduplicate_search_results, detect_missing_albums, manual_search,
+    parsed_release_key, release_match_key, release_torrent_health, AlbumSearchStatus,
+    AlbumSearchTarget, AutomaticSearchDecision, AutomaticSearchReason, ManualSearchRequest,
+    QueryBuildOptions, RankedRelease, SearchDecisionStore,
 };
 pub use tag_embedding::{
     ArtworkData, EmbeddedTagPreference, LoftyTagEmbeddingBackend, TagEmbeddingBackend,
@@ -125,7 +169,7 @@ pub use chorrosion_domain::{
     SmartPlaylistCriteria, SmartPlaylistId, Tag, TagId, TaggedEntity,
 };
 
-use tracing::info;
+use tracing::{debug, info};
 
 /// A single download item cached from a download client poll, tagged with the
 /// originating client definition metadata.
@@ -183,6 +227,126 @@ impl Default for ActivitySnapshotCache {
     }
 }
 
+/// JWKS documents fetched to verify bearer tokens, keyed by JWKS URL.
+///
+/// Held in [`AppState`] so every request verifies against the same fetched keys;
+/// the bearer middleware decides when an entry is stale.
+#[derive(Clone, Debug, Default)]
+pub struct JwksCache {
+    inner: Arc<tokio::sync::RwLock<HashMap<String, CachedJwks>>>,
+}
+
+/// A fetched JWKS document and when it was fetched.
+#[derive(Clone, Debug)]
+pub struct CachedJwks {
+    pub fetched_at: Instant,
+    pub keys: jsonwebtoken::jwk::JwkSet,
+}
+
+impl JwksCache {
+    /// The document last fetched from `jwks_url`, if any.
+    pub async fn get(&self, jwks_url: &str) -> Option<CachedJwks> {
+        self.inner.read().await.get(jwks_url).cloned()
+    }
+
+    /// Record `keys` as freshly fetched from `jwks_url`.
+    pub async fn insert(&self, jwks_url: &str, keys: jsonwebtoken::jwk::JwkSet) {
+        self.inner.write().await.insert(
+            jwks_url.to_string(),
+            CachedJwks {
+                fetched_at: Instant::now(),
+                keys,
+            },
+        );
+    }
+}
+
+/// External service whose reachability is reported by the system status endpoint.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DependencyKind {
+    Indexer,
+    DownloadClient,
+}
+
+/// Outcome of the most recent reachability probe of one external dependency.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DependencyHealth {
+    pub kind: DependencyKind,
+    pub id: String,
+    pub name: String,
+    pub healthy: bool,
+    pub message: Option<String>,
+    pub checked_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Short-lived, in-memory cache for external dependency probe results.
+///
+/// Probing every indexer and download client on each status request would
+/// hammer them, so results are reused for a brief TTL (default 30 s).
+#[derive(Clone, Debug)]
+pub struct DependencyHealthCache {
+    inner: Cache<(), Vec<DependencyHealth>>,
+    /// Held while a probe runs so concurrent misses share one probe.
+    probe_lock: Arc<tokio::sync::Mutex<()>>,
+}
+
+/// Default dependency health TTL in seconds.
+const DEPENDENCY_HEALTH_TTL_SECONDS: u64 = 30;
+
+impl DependencyHealthCache {
+    /// Create a new cache with the given TTL (clamped to ≥ 1 s).
+    pub fn new(ttl_seconds: u64) -> Self {
+        let ttl = Duration::from_secs(ttl_seconds.max(1));
+        Self {
+            inner: Cache::builder().max_capacity(1).time_to_live(ttl).build(),
+            probe_lock: Arc::default(),
+        }
+    }
+
+    /// Return the cached probe results if still within TTL.
+    pub fn get(&self) -> Option<Vec<DependencyHealth>> {
+        self.inner.get(&())
+    }
+
+    /// Return the cached results, or run `probe` and cache what it returns.
+    ///
+    /// Only one probe runs at a time; callers that miss the cache while it runs
+    /// wait for it and reuse its results instead of probing again.
+    pub async fn get_or_probe<F, Fut>(&self, probe: F) -> Vec<DependencyHealth>
+    where
+        F: FnOnce() -> Fut,
+        Fut: std::future::Future<Output = Vec<DependencyHealth>>,
+    {
+        if let Some(cached) = self.get() {
+            debug!(target: "application", "dependency health cache HIT");
+            return cached;
+        }
+        let _probing = self.probe_lock.lock().await;
+        if let Some(cached) = self.get() {
+            return cached;
+        }
+        let results = probe().await;
+        self.set(results.clone());
+        results
+    }
+
+    /// Replace the cached probe results.
+    pub fn set(&self, results: Vec<DependencyHealth>) {
+        self.inner.insert((), results);
+    }
+
+    /// Clear the cached results so the next request probes again.
+    pub fn clear(&self) {
+        self.inner.invalidate(&());
+    }
+}
+
+impl Default for DependencyHealthCache {
+    fn default() -> Self {
+        Self::new(DEPENDENCY_HEALTH_TTL_SECONDS)
+    }
+}
+
 #[derive(Clone, Debug)]
 struct ActivityHistoryRecord {
     item: CachedActivityItem,
@@ -374,16 +538,37 @@ pub struct AppState {
     pub tagged_entity_repository: Arc<dyn TaggedEntityRepository>,
     pub smart_playlist_repository: Arc<dyn SmartPlaylistRepository>,
     pub duplicate_repository: Arc<dyn DuplicateRepository>,
+    pub track_file_repository: Arc<dyn TrackFileRepository>,
     /// In-memory cache for serialized API GET responses.
     pub response_cache: ResponseCache,
     /// Short-lived cache for the polled download-client activity snapshot.
     pub activity_snapshot_cache: ActivitySnapshotCache,
+    /// Short-lived cache for indexer and download client reachability probes.
+    pub dependency_health_cache: DependencyHealthCache,
     /// In-memory terminal-state history accumulated across fresh polls.
     pub activity_history_store: ActivityHistoryStore,
     /// In-memory tracker used to detect downloads that stop making progress.
     pub activity_stall_tracker: ActivityStallTracker,
     /// In-memory appearance settings for UI-related preferences.
     pub appearance_settings: Arc<Mutex<crate::appearance::AppearanceSettings>>,
+    /// Hub that WebSocket clients on `/api/v1/ws` subscribe to.
+    pub realtime_hub: WebSocketHub,
+    /// Hub that `/api/v1/events` SSE streams subscribe to.
+    pub sse_hub: SseHub,
+    /// Publishes domain events to realtime clients.
+    pub event_dispatcher: EventDispatcher,
+    /// Job, scheduler, and database pool metrics exposed at `/metrics`.
+    pub metrics: AppMetrics,
+    /// Last automatic search decision per album, recorded by the scheduler.
+    pub search_decision_store: SearchDecisionStore,
+    /// Outbound HTTP client shared by handlers so requests reuse one connection pool.
+    pub http_client: reqwest::Client,
+    /// Builds indexer clients over `http_client`; shared with the scheduler.
+    pub indexer_clients: IndexerClients,
+    /// Proxy settings for clients built per request, such as download clients.
+    pub outbound_http: OutboundHttp,
+    /// JWKS documents the bearer-token middleware verifies against.
+    pub jwks_cache: JwksCache,
 }
 
 impl AppState {
@@ -401,15 +586,31 @@ impl AppState {
         tagged_entity_repository: Arc<dyn TaggedEntityRepository>,
         smart_playlist_repository: Arc<dyn SmartPlaylistRepository>,
         duplicate_repository: Arc<dyn DuplicateRepository>,
+        track_file_repository: Arc<dyn TrackFileRepository>,
         response_cache: ResponseCache,
     ) -> Self {
+        let outbound_http = OutboundHttp::from_config(&config.http);
+        let http_client = outbound_http.default_client();
+        let realtime_hub = WebSocketHub::default();
+        let sse_hub = SseHub::default();
         Self {
             activity_snapshot_cache: ActivitySnapshotCache::default(),
+            dependency_health_cache: DependencyHealthCache::default(),
             activity_history_store: ActivityHistoryStore::default(),
             activity_stall_tracker: ActivityStallTracker::new(config.activity.stall_after_seconds),
             appearance_settings: Arc::new(Mutex::new(
                 crate::appearance::AppearanceSettings::default(),
             )),
+            event_dispatcher: EventDispatcher::new(Arc::new(realtime_hub.clone()))
+                .with_hub(Arc::new(sse_hub.clone())),
+            realtime_hub,
+            sse_hub,
+            metrics: AppMetrics::default(),
+            search_decision_store: SearchDecisionStore::default(),
+            http_client: http_client.clone(),
+            indexer_clients: IndexerClients::new(http_client),
+            outbound_http,
+            jwks_cache: JwksCache::default(),
             config,
             artist_repository,
             album_repository,
@@ -422,10 +623,74 @@ impl AppState {
             tagged_entity_repository,
             smart_playlist_repository,
             duplicate_repository,
+            track_file_repository,
             response_cache,
         }
     }
 
+    /// A [`FileImportService`] configured from this state that matches scanned
+    /// files against the artist, album and track repositories and skips files
+    /// the track file repository already records.
+    pub fn file_import_service(&self) -> anyhow::Result<FileImportService> {
+        // Imports only fingerprint locally; the client is never used for lookups here.
+        let acoustid_client = chorrosion_fingerprint::AcoustidClient::new(String::new())?;
+        Ok(FileImportService::new(
+            Arc::new(acoustid_client),
+            self.config.scheduler.max_concurrent_imports.max(1),
+        )
+        .with_import_mode(self.config.library.import_mode)
+        .with_library_roots(self.config.library.root_paths.clone())
+        .with_audio_extensions(AudioExtensions::new(&self.config.library.audio_extensions))
+        .with_catalog(
+            self.artist_repository.clone(),
+            self.album_repository.clone(),
+            self.track_repository.clone(),
+        )
+        .with_track_files(self.track_file_repository.clone())
+        .with_quality_profiles(self.quality_profile_repository.clone()))
+    }
+
+    /// A [`TrackFileRematchService`] over this state's track and track file repositories.
+    pub fn track_file_rematch_service(&self) -> anyhow::Result<TrackFileRematchService> {
+        let acoustid_client = chorrosion_fingerprint::AcoustidClient::new(String::new())?;
+        let engine = PrecedenceMatchingEngine::new(
+            TrackMatchingService::new(acoustid_client),
+            EmbeddedTagMatchingService,
+            FilenameHeuristicsService,
+        )
+        .with_track_repository(self.track_repository.clone());
+        Ok(TrackFileRematchService::new(
+            Arc::new(engine),
+            self.track_file_repository.clone(),
+            self.track_repository.clone(),
+        ))
+    }
+
+    /// Replace the default dispatcher, which publishes to `realtime_hub` and `sse_hub`.
+    pub fn with_event_dispatcher(mut self, event_dispatcher: EventDispatcher) -> Self {
+        self.event_dispatcher = event_dispatcher;
+        self
+    }
+
+    /// Share `metrics` with the scheduler so one registry backs `/metrics`.
+    pub fn with_metrics(mut self, metrics: AppMetrics) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// Share `store` with the scheduler so search decisions are visible to the API.
+    pub fn with_search_decision_store(mut self, store: SearchDecisionStore) -> Self {
+        self.search_decision_store = store;
+        self
+    }
+
+    /// Replace the default outbound HTTP client, e.g. to share one built at startup.
+    pub fn with_http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.indexer_clients = IndexerClients::new(http_client.clone());
+        self.http_client = http_client;
+        self
+    }
+
     pub fn on_start(&self) {
         info!(target: "application", "application state initialized");
     }
@@ -484,3 +749,81 @@ impl AppState {
 
 #[cfg(test)]
 mod matching_precedence_tests;
+
+#[cfg(test)]
+mod tests {
+    use super::{AppState, DependencyHealthCache};
+    use chorrosion_config::AppConfig;
+    use chorrosion_domain::Artist;
+    use chorrosion_infrastructure::sqlite_adapters::{
+        SqliteAlbumRepository, SqliteArtistRepository, SqliteDownloadClientDefinitionRepository,
+        SqliteDuplicateRepository, SqliteIndexerDefinitionRepository,
+        SqliteMetadataProfileRepository, SqliteQualityProfileRepository,
+        SqliteSmartPlaylistRepository, SqliteTagRepository, SqliteTaggedEntityRepository,
+        SqliteTrackFileRepository, SqliteTrackRepository,
+    };
+    use chorrosion_infrastructure::{init_database, ResponseCache};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn state_built_from_one_pool_serves_its_repositories() {
+        let mut config = AppConfig::default();
+        config.database.url = "sqlite::memory:".to_string();
+        config.database.pool_max_size = 1;
+        let pool = init_database(&config).await.expect("in-memory database");
+
+        let state = AppState::new(
+            config,
+            Arc::new(SqliteArtistRepository::new(pool.clone())),
+            Arc::new(SqliteAlbumRepository::new(pool.clone())),
+            Arc::new(SqliteTrackRepository::new(pool.clone())),
+            Arc::new(SqliteQualityProfileRepository::new(pool.clone())),
+            Arc::new(SqliteMetadataProfileRepository::new(pool.clone())),
+            Arc::new(SqliteIndexerDefinitionRepository::new(pool.clone())),
+            Arc::new(SqliteDownloadClientDefinitionRepository::new(pool.clone())),
+            Arc::new(SqliteTagRepository::new(pool.clone())),
+            Arc::new(SqliteTaggedEntityRepository::new(pool.clone())),
+            Arc::new(SqliteSmartPlaylistRepository::new(pool.clone())),
+            Arc::new(SqliteDuplicateRepository::new(pool.clone())),
+            Arc::new(SqliteTrackFileRepository::new(pool)),
+            ResponseCache::new(10, 60),
+        );
+
+        let created = state
+            .artist_repository
+            .create(Artist::new("Portishead"))
+            .await
+            .expect("create artist");
+
+        // Handlers receive clones of the state, which must see the same database.
+        let handler_state = state.clone();
+        let fetched = handler_state
+            .artist_repository
+            .get_by_id(&created.id.to_string())
+            .await
+            .expect("fetch artist")
+            .expect("artist exists");
+        assert_eq!(fetched.name, "Portishead");
+    }
+
+    #[tokio::test]
+    async fn concurrent_dependency_health_misses_share_one_probe() {
+        let cache = DependencyHealthCache::default();
+        let probes = AtomicUsize::new(0);
+        let probe = || async {
+            probes.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            Vec::new()
+        };
+
+        tokio::join!(
+            cache.get_or_probe(probe),
+            cache.get_or_probe(probe),
+            cache.get_or_probe(probe),
+        );
+
+        assert_eq!(probes.load(Ordering::SeqCst), 1);
+    }
+}
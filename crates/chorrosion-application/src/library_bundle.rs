@@ -0,0 +1,884 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+//! Portable library configuration bundle.
+//!
+//! [`LibraryBundleService::export_bundle`] captures quality profiles, metadata
+//! profiles, indexer definitions and monitored artists in a versioned JSON
+//! document that [`LibraryBundleService::import_bundle`] can replay on another
+//! instance. Entries are matched by name (artists by foreign ID first), and
+//! instance-local IDs never leave the bundle: artists refer to their profiles
+//! by name. Indexer API keys are not exported.
+
+use anyhow::Result as AnyResult;
+use chorrosion_domain::{Artist, IndexerDefinition, MetadataProfile, ProfileId, QualityProfile};
+use chorrosion_infrastructure::pagination::{collect_all, DEFAULT_PAGE_SIZE};
+use chorrosion_infrastructure::repositories::{
+    ArtistRepository, IndexerDefinitionRepository, MetadataProfileRepository,
+    QualityProfileRepository,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+use thiserror::Error;
+use tracing::info;
+
+/// Bundle format written by [`LibraryBundleService::export_bundle`].
+pub const LIBRARY_BUNDLE_VERSION: u32 = 1;
+
+/// Errors that can occur while exporting or importing a bundle.
+#[derive(Debug, Error)]
+pub enum LibraryBundleError {
+    #[error("Unsupported bundle version {0} (expected {LIBRARY_BUNDLE_VERSION})")]
+    UnsupportedVersion(u32),
+
+    #[error("Repository error: {0}")]
+    Repository(#[from] anyhow::Error),
+}
+
+pub type LibraryBundleResult<T> = Result<T, LibraryBundleError>;
+
+/// Versioned document holding a library's portable configuration.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LibraryBundle {
+    pub version: u32,
+    pub exported_at: DateTime<Utc>,
+    #[serde(default)]
+    pub quality_profiles: Vec<BundleQualityProfile>,
+    #[serde(default)]
+    pub metadata_profiles: Vec<BundleMetadataProfile>,
+    #[serde(default)]
+    pub indexers: Vec<BundleIndexer>,
+    #[serde(default)]
+    pub artists: Vec<BundleArtist>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleQualityProfile {
+    pub name: String,
+    pub allowed_qualities: Vec<String>,
+    pub upgrade_allowed: bool,
+    pub cutoff_quality: Option<String>,
+    #[serde(default)]
+    pub is_default: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleMetadataProfile {
+    pub name: String,
+    pub primary_album_types: Vec<String>,
+    pub secondary_album_types: Vec<String>,
+    pub release_statuses: Vec<String>,
+    #[serde(default)]
+    pub is_default: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleIndexer {
+    pub name: String,
+    pub base_url: String,
+    pub protocol: String,
+    pub enabled: bool,
+    #[serde(default)]
+    pub category_map: BTreeMap<String, String>,
+    #[serde(default)]
+    pub requests_per_minute: Option<u32>,
+}
+
+/// A monitored artist; profiles are referenced by name.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleArtist {
+    pub name: String,
+    pub foreign_artist_id: Option<String>,
+    pub musicbrainz_artist_id: Option<String>,
+    pub quality_profile: Option<String>,
+    pub metadata_profile: Option<String>,
+}
+
+/// Whether [`LibraryBundleService::import_bundle`] writes anything.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BundleImportMode {
+    /// Report what would change without writing.
+    DryRun,
+    /// Create and update entries; conflicts are skipped.
+    Apply,
+}
+
+/// Section of the bundle an import item belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BundleSection {
+    QualityProfile,
+    MetadataProfile,
+    Indexer,
+    Artist,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BundleAction {
+    Create,
+    Update,
+    Unchanged,
+    /// Cannot be imported as-is; `reason` says why.
+    Conflict,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleImportItem {
+    pub section: BundleSection,
+    pub name: String,
+    pub action: BundleAction,
+    pub reason: Option<String>,
+}
+
+/// Per-entry outcome of an import, identical for a dry run and the apply
+/// that follows it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleImportReport {
+    pub mode: BundleImportMode,
+    pub items: Vec<BundleImportItem>,
+}
+
+impl BundleImportReport {
+    /// Number of items with `action`.
+    pub fn count(&self, action: BundleAction) -> usize {
+        self.items
+            .iter()
+            .filter(|item| item.action == action)
+            .count()
+    }
+}
+
+/// Exports and imports [`LibraryBundle`]s against the library repositories.
+pub struct LibraryBundleService {
+    quality_profiles: Arc<dyn QualityProfileRepository>,
+    metadata_profiles: Arc<dyn MetadataProfileRepository>,
+    indexers: Arc<dyn IndexerDefinitionRepository>,
+    artists: Arc<dyn ArtistRepository>,
+    page_size: i64,
+}
+
+impl LibraryBundleService {
+    pub fn new(
+        quality_profiles: Arc<dyn QualityProfileRepository>,
+        metadata_profiles: Arc<dyn MetadataProfileRepository>,
+        indexers: Arc<dyn IndexerDefinitionRepository>,
+        artists: Arc<dyn ArtistRepository>,
+    ) -> Self {
+        Self {
+            quality_profiles,
+            metadata_profiles,
+            indexers,
+            artists,
+            page_size: DEFAULT_PAGE_SIZE,
+        }
+    }
+
+    /// Read each exported section in pages of `page_size` rows.
+    pub fn with_page_size(mut self, page_size: i64) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Capture the current configuration and monitored artists.
+    pub async fn export_bundle(&self) -> LibraryBundleResult<LibraryBundle> {
+        let quality_profiles = collect_all(self.page_size, |limit, offset| {
+            self.quality_profiles.list(limit, offset)
+        })
+        .await?;
+        let metadata_profiles = collect_all(self.page_size, |limit, offset| {
+            self.metadata_profiles.list(limit, offset)
+        })
+        .await?;
+        let indexers = collect_all(self.page_size, |limit, offset| {
+            self.indexers.list(limit, offset)
+        })
+        .await?;
+        let artists = collect_all(self.page_size, |limit, offset| {
+            self.artists.list_monitored(limit, offset)
+        })
+        .await?;
+
+        let quality_names: HashMap<ProfileId, String> = quality_profiles
+            .iter()
+            .map(|profile| (profile.id, profile.name.clone()))
+            .collect();
+        let metadata_names: HashMap<ProfileId, String> = metadata_profiles
+            .iter()
+            .map(|profile| (profile.id, profile.name.clone()))
+            .collect();
+
+        Ok(LibraryBundle {
+            version: LIBRARY_BUNDLE_VERSION,
+            exported_at: Utc::now(),
+            quality_profiles: quality_profiles
+                .into_iter()
+                .map(|profile| BundleQualityProfile {
+                    name: profile.name,
+                    allowed_qualities: profile.allowed_qualities,
+                    upgrade_allowed: profile.upgrade_allowed,
+                    cutoff_quality: profile.cutoff_quality,
+                    is_default: profile.is_default,
+                })
+                .collect(),
+            metadata_profiles: metadata_profiles
+                .into_iter()
+                .map(|profile| BundleMetadataProfile {
+                    name: profile.name,
+                    primary_album_types: profile.primary_album_types,
+                    secondary_album_types: profile.secondary_album_types,
+                    release_statuses: profile.release_statuses,
+                    is_default: profile.is_default,
+                })
+                .collect(),
+            indexers: indexers
+                .into_iter()
+                .map(|indexer| BundleIndexer {
+                    name: indexer.name,
+                    base_url: indexer.base_url,
+                    protocol: indexer.protocol,
+                    enabled: indexer.enabled,
+                    category_map: indexer.category_map,
+                    requests_per_minute: indexer.requests_per_minute,
+                })
+                .collect(),
+            artists: artists
+                .into_iter()
+                .map(|artist| BundleArtist {
+                    quality_profile: artist
+                        .quality_profile_id
+                        .and_then(|id| quality_names.get(&id).cloned()),
+                    metadata_profile: artist
+                        .metadata_profile_id
+                        .and_then(|id| metadata_names.get(&id).cloned()),
+                    name: artist.name,
+                    foreign_artist_id: artist.foreign_artist_id,
+                    musicbrainz_artist_id: artist.musicbrainz_artist_id,
+                })
+                .collect(),
+        })
+    }
+
+    /// Replay `bundle` against this library.
+    ///
+    /// Every entry is reported as created, updated, unchanged or conflicting.
+    /// With [`BundleImportMode::Apply`] creates and updates are written in
+    /// section order (profiles, indexers, artists) and conflicts are skipped.
+    /// Bundles with a version other than [`LIBRARY_BUNDLE_VERSION`] are rejected
+    /// before anything is read.
+    pub async fn import_bundle(
+        &self,
+        bundle: &LibraryBundle,
+        mode: BundleImportMode,
+    ) -> LibraryBundleResult<BundleImportReport> {
+        if bundle.version != LIBRARY_BUNDLE_VERSION {
+            return Err(LibraryBundleError::UnsupportedVersion(bundle.version));
+        }
+        let apply = mode == BundleImportMode::Apply;
+        let mut items = Vec::new();
+
+        let mut quality_ids = HashMap::new();
+        let mut seen = HashSet::new();
+        for entry in &bundle.quality_profiles {
+            if let Some(item) =
+                duplicate_item(&mut seen, BundleSection::QualityProfile, &entry.name)
+            {
+                items.push(item);
+                continue;
+            }
+            let existing = self.quality_profiles.get_by_name(&entry.name).await?;
+            let action = match &existing {
+                None => BundleAction::Create,
+                Some(current) if quality_profile_matches(current, entry) => BundleAction::Unchanged,
+                Some(_) => BundleAction::Update,
+            };
+            let id = existing.as_ref().map(|profile| profile.id);
+            quality_ids.insert(entry.name.to_lowercase(), id);
+            if apply {
+                let id = self.apply_quality_profile(existing, entry, action).await?;
+                quality_ids.insert(entry.name.to_lowercase(), Some(id));
+            }
+            items.push(item(BundleSection::QualityProfile, &entry.name, action));
+        }
+
+        let mut metadata_ids = HashMap::new();
+        let mut seen = HashSet::new();
+        for entry in &bundle.metadata_profiles {
+            if let Some(item) =
+                duplicate_item(&mut seen, BundleSection::MetadataProfile, &entry.name)
+            {
+                items.push(item);
+                continue;
+            }
+            let existing = self.metadata_profiles.get_by_name(&entry.name).await?;
+            let action = match &existing {
+                None => BundleAction::Create,
+                Some(current) if metadata_profile_matches(current, entry) => {
+                    BundleAction::Unchanged
+                }
+                Some(_) => BundleAction::Update,
+            };
+            let id = existing.as_ref().map(|profile| profile.id);
+            metadata_ids.insert(entry.name.to_lowercase(), id);
+            if apply {
+                let id = self.apply_metadata_profile(existing, entry, action).await?;
+                metadata_ids.insert(entry.name.to_lowercase(), Some(id));
+            }
+            items.push(item(BundleSection::MetadataProfile, &entry.name, action));
+        }
+
+        let mut seen = HashSet::new();
+        for entry in &bundle.indexers {
+            if let Some(item) = duplicate_item(&mut seen, BundleSection::Indexer, &entry.name) {
+                items.push(item);
+                continue;
+            }
+            let existing = self.indexers.get_by_name(&entry.name).await?;
+            let action = match &existing {
+                None => BundleAction::Create,
+                Some(current) if indexer_matches(current, entry) => BundleAction::Unchanged,
+                Some(_) => BundleAction::Update,
+            };
+            if apply {
+                self.apply_indexer(existing, entry, action).await?;
+            }
+            items.push(item(BundleSection::Indexer, &entry.name, action));
+        }
+
+        let mut seen = HashSet::new();
+        for entry in &bundle.artists {
+            let key = entry
+                .foreign_artist_id
+                .clone()
+                .unwrap_or_else(|| entry.name.clone());
+            if let Some(item) = duplicate_item(&mut seen, BundleSection::Artist, &key) {
+                items.push(BundleImportItem {
+                    name: entry.name.clone(),
+                    ..item
+                });
+                continue;
+            }
+            let (action, reason, plan) =
+                self.plan_artist(entry, &quality_ids, &metadata_ids).await?;
+            if apply {
+                if let Some(artist) = plan {
+                    match action {
+                        BundleAction::Create => {
+                            self.artists.create(artist).await?;
+                        }
+                        BundleAction::Update => {
+                            self.artists.update(artist).await?;
+                        }
+                        BundleAction::Unchanged | BundleAction::Conflict => {}
+                    }
+                }
+            }
+            items.push(BundleImportItem {
+                section: BundleSection::Artist,
+                name: entry.name.clone(),
+                action,
+                reason,
+            });
+        }
+
+        let report = BundleImportReport { mode, items };
+        info!(
+            target: "application",
+            ?mode,
+            created = report.count(BundleAction::Create),
+            updated = report.count(BundleAction::Update),
+            unchanged = report.count(BundleAction::Unchanged),
+            conflicts = report.count(BundleAction::Conflict),
+            "processed library bundle"
+        );
+        Ok(report)
+    }
+
+    async fn apply_quality_profile(
+        &self,
+        existing: Option<QualityProfile>,
+        entry: &BundleQualityProfile,
+        action: BundleAction,
+    ) -> AnyResult<ProfileId> {
+        let mut profile = existing
+            .unwrap_or_else(|| QualityProfile::new(&entry.name, entry.allowed_qualities.clone()));
+        let id = profile.id;
+        match action {
+            BundleAction::Create | BundleAction::Update => {
+                profile.allowed_qualities = entry.allowed_qualities.clone();
+                profile.upgrade_allowed = entry.upgrade_allowed;
+                profile.cutoff_quality = entry.cutoff_quality.clone();
+                if action == BundleAction::Create {
+                    self.quality_profiles.create(profile).await?;
+                } else {
+                    self.quality_profiles.update(profile).await?;
+                }
+                if entry.is_default {
+                    self.quality_profiles.set_default(&id.to_string()).await?;
+                }
+            }
+            BundleAction::Unchanged | BundleAction::Conflict => {}
+        }
+        Ok(id)
+    }
+
+    async fn apply_metadata_profile(
+        &self,
+        existing: Option<MetadataProfile>,
+        entry: &BundleMetadataProfile,
+        action: BundleAction,
+    ) -> AnyResult<ProfileId> {
+        let mut profile = existing.unwrap_or_else(|| MetadataProfile::new(&entry.name));
+        let id = profile.id;
+        match action {
+            BundleAction::Create | BundleAction::Update => {
+                profile.primary_album_types = entry.primary_album_types.clone();
+                profile.secondary_album_types = entry.secondary_album_types.clone();
+                profile.release_statuses = entry.release_statuses.clone();
+                if action == BundleAction::Create {
+                    self.metadata_profiles.create(profile).await?;
+                } else {
+                    self.metadata_profiles.update(profile).await?;
+                }
+                if entry.is_default {
+                    self.metadata_profiles.set_default(&id.to_string()).await?;
+                }
+            }
+            BundleAction::Unchanged | BundleAction::Conflict => {}
+        }
+        Ok(id)
+    }
+
+    async fn apply_indexer(
+        &self,
+        existing: Option<IndexerDefinition>,
+        entry: &BundleIndexer,
+        action: BundleAction,
+    ) -> AnyResult<()> {
+        // An existing indexer keeps its API key; the bundle never carries one.
+        let mut indexer = existing.unwrap_or_else(|| {
+            IndexerDefinition::new(&entry.name, &entry.base_url, &entry.protocol)
+        });
+        indexer.base_url = entry.base_url.clone();
+        indexer.protocol = entry.protocol.clone();
+        indexer.enabled = entry.enabled;
+        indexer.category_map = entry.category_map.clone();
+        indexer.requests_per_minute = entry.requests_per_minute;
+        match action {
+            BundleAction::Create => {
+                self.indexers.create(indexer).await?;
+            }
+            BundleAction::Update => {
+                self.indexers.update(indexer).await?;
+            }
+            BundleAction::Unchanged | BundleAction::Conflict => {}
+        }
+        Ok(())
+    }
+
+    /// Decide what importing `entry` does and build the artist to write.
+    async fn plan_artist(
+        &self,
+        entry: &BundleArtist,
+        quality_ids: &HashMap<String, Option<ProfileId>>,
+        metadata_ids: &HashMap<String, Option<ProfileId>>,
+    ) -> AnyResult<(BundleAction, Option<String>, Option<Artist>)> {
+        let quality_profile_id = match &entry.quality_profile {
+            None => None,
+            Some(name) => match self.resolve_quality_profile(name, quality_ids).await? {
+                Some(id) => id,
+                None => {
+                    return Ok(conflict(format!("unknown quality profile '{name}'")));
+                }
+            },
+        };
+        let metadata_profile_id = match &entry.metadata_profile {
+            None => None,
+            Some(name) => match self.resolve_metadata_profile(name, metadata_ids).await? {
+                Some(id) => id,
+                None => {
+                    return Ok(conflict(format!("unknown metadata profile '{name}'")));
+                }
+            },
+        };
+
+        let existing = match &entry.foreign_artist_id {
+            Some(foreign_id) => match self.artists.get_by_foreign_id(foreign_id).await? {
+                Some(artist) => Some(artist),
+                None => self.artists.get_by_name(&entry.name).await?,
+            },
+            None => self.artists.get_by_name(&entry.name).await?,
+        };
+
+        let Some(mut artist) = existing else {
+            let mut artist = Artist::new(&entry.name);
+            artist.foreign_artist_id = entry.foreign_artist_id.clone();
+            artist.musicbrainz_artist_id = entry.musicbrainz_artist_id.clone();
+            artist.quality_profile_id = quality_profile_id;
+            artist.metadata_profile_id = metadata_profile_id;
+            artist.monitored = true;
+            return Ok((BundleAction::Create, None, Some(artist)));
+        };
+
+        if let (Some(current), Some(incoming)) =
+            (&artist.foreign_artist_id, &entry.foreign_artist_id)
+        {
+            if current != incoming {
+                return Ok(conflict(format!(
+                    "an artist named '{}' already exists with foreign id {current}",
+                    artist.name
+                )));
+            }
+        }
+
+        // Profiles that only exist after an apply have no ID yet in a dry run.
+        let unchanged = artist.monitored
+            && (entry.foreign_artist_id.is_none() || artist.foreign_artist_id.is_some())
+            && (entry.quality_profile.is_none() || quality_profile_id.is_some())
+            && (entry.metadata_profile.is_none() || metadata_profile_id.is_some())
+            && quality_profile_id.is_none_or(|id| artist.quality_profile_id == Some(id))
+            && metadata_profile_id.is_none_or(|id| artist.metadata_profile_id == Some(id));
+        if unchanged {
+            return Ok((BundleAction::Unchanged, None, None));
+        }
+
+        artist.monitored = true;
+        artist.foreign_artist_id = artist
+            .foreign_artist_id
+            .or_else(|| entry.foreign_artist_id.clone());
+        artist.musicbrainz_artist_id = artist
+            .musicbrainz_artist_id
+            .or_else(|| entry.musicbrainz_artist_id.clone());
+        if quality_profile_id.is_some() {
+            artist.quality_profile_id = quality_profile_id;
+        }
+        if metadata_profile_id.is_some() {
+            artist.metadata_profile_id = metadata_profile_id;
+        }
+        Ok((BundleAction::Update, None, Some(artist)))
+    }
+
+    /// `Some(id)` when the profile exists here or earlier in the bundle; the
+    /// inner `None` marks a profile a dry run would create.
+    async fn resolve_quality_profile(
+        &self,
+        name: &str,
+        bundle_ids: &HashMap<String, Option<ProfileId>>,
+    ) -> AnyResult<Option<Option<ProfileId>>> {
+        if let Some(id) = bundle_ids.get(&name.to_lowercase()) {
+            return Ok(Some(*id));
+        }
+        Ok(self
+            .quality_profiles
+            .get_by_name(name)
+            .await?
+            .map(|profile| Some(profile.id)))
+    }
+
+    async fn resolve_metadata_profile(
+        &self,
+        name: &str,
+        bundle_ids: &HashMap<String, Option<ProfileId>>,
+    ) -> AnyResult<Option<Option<ProfileId>>> {
+        if let Some(id) = bundle_ids.get(&name.to_lowercase()) {
+            return Ok(Some(*id));
+        }
+        Ok(self
+            .metadata_profiles
+            .get_by_name(name)
+            .await?
+            .map(|profile| Some(profile.id)))
+    }
+}
+
+fn item(section: BundleSection, name: &str, action: BundleAction) -> BundleImportItem {
+    BundleImportItem {
+        section,
+        name: name.to_string(),
+        action,
+        reason: None,
+    }
+}
+
+fn conflict(reason: String) -> (BundleAction, Option<String>, Option<Artist>) {
+    (BundleAction::Conflict, Some(reason), None)
+}
+
+/// Conflict item for a name already seen in the same section.
+fn duplicate_item(
+    seen: &mut HashSet<String>,
+    section: BundleSection,
+    name: &str,
+) -> Option<BundleImportItem> {
+    if seen.insert(name.to_lowercase()) {
+        return None;
+    }
+    Some(BundleImportItem {
+        section,
+        name: name.to_string(),
+        action: BundleAction::Conflict,
+        reason: Some("duplicate entry in bundle".to_string()),
+    })
+}
+
+fn quality_profile_matches(current: &QualityProfile, entry: &BundleQualityProfile) -> bool {
+    current.allowed_qualities == entry.allowed_qualities
+        && current.upgrade_allowed == entry.upgrade_allowed
+        && current.cutoff_quality == entry.cutoff_quality
+        && (current.is_default || !entry.is_default)
+}
+
+fn metadata_profile_matches(current: &MetadataProfile, entry: &BundleMetadataProfile) -> bool {
+    current.primary_album_types == entry.primary_album_types
+        && current.secondary_album_types == entry.secondary_album_types
+        && current.release_statuses == entry.release_statuses
+        && (current.is_default || !entry.is_default)
+}
+
+fn indexer_matches(current: &IndexerDefinition, entry: &BundleIndexer) -> bool {
+    current.base_url == entry.base_url
+        && current.protocol == entry.protocol
+        && current.enabled == entry.enabled
+        && current.category_map == entry.category_map
+        && current.requests_per_minute == entry.requests_per_minute
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chorrosion_config::AppConfig;
+    use chorrosion_infrastructure::init_database;
+    use chorrosion_infrastructure::sqlite_adapters::{
+        SqliteArtistRepository, SqliteIndexerDefinitionRepository, SqliteMetadataProfileRepository,
+        SqliteQualityProfileRepository,
+    };
+
+    async fn create_service() -> LibraryBundleService {
+        let mut config = AppConfig::default();
+        config.database.url = "sqlite::memory:".to_string();
+        config.database.pool_max_size = 1;
+        let pool = init_database(&config).await.expect("in-memory database");
+        LibraryBundleService::new(
+            Arc::new(SqliteQualityProfileRepository::new(pool.clone())),
+            Arc::new(SqliteMetadataProfileRepository::new(pool.clone())),
+            Arc::new(SqliteIndexerDefinitionRepository::new(pool.clone())),
+            Arc::new(SqliteArtistRepository::new(pool)),
+        )
+    }
+
+    async fn populate(service: &LibraryBundleService) {
+        let mut lossless = QualityProfile::new("Bundle Lossless", vec!["FLAC".to_string()]);
+        lossless.upgrade_allowed = true;
+        lossless.cutoff_quality = Some("FLAC".to_string());
+        let lossless = service.quality_profiles.create(lossless).await.unwrap();
+
+        let mut albums = MetadataProfile::new("Bundle Albums");
+        albums.primary_album_types = vec!["Album".to_string()];
+        let albums = service.metadata_profiles.create(albums).await.unwrap();
+
+        let mut indexer =
+            IndexerDefinition::new("Bundle Indexer", "https://indexer.example", "torznab");
+        indexer.api_key = Some("secret".to_string());
+        indexer
+            .category_map
+            .insert("audio/flac".to_string(), "3040".to_string());
+        indexer.requests_per_minute = Some(30);
+        service.indexers.create(indexer).await.unwrap();
+
+        let mut monitored = Artist::new("Monitored Artist");
+        monitored.foreign_artist_id = Some("mb-artist-1".to_string());
+        monitored.quality_profile_id = Some(lossless.id);
+        monitored.metadata_profile_id = Some(albums.id);
+        service.artists.create(monitored).await.unwrap();
+
+        let mut ignored = Artist::new("Unmonitored Artist");
+        ignored.monitored = false;
+        service.artists.create(ignored).await.unwrap();
+    }
+
+    fn actions(report: &BundleImportReport, section: BundleSection) -> Vec<(String, BundleAction)> {
+        report
+            .items
+            .iter()
+            .filter(|item| item.section == section)
+            .map(|item| (item.name.clone(), item.action))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn bundle_round_trips_through_dry_run_and_apply() {
+        let source = create_service().await;
+        populate(&source).await;
+        let bundle = source.export_bundle().await.unwrap();
+        assert_eq!(bundle.version, LIBRARY_BUNDLE_VERSION);
+        assert_eq!(bundle.artists.len(), 1);
+        assert_eq!(
+            bundle.artists[0].quality_profile.as_deref(),
+            Some("Bundle Lossless")
+        );
+
+        // The bundle travels as JSON between instances.
+        let json = serde_json::to_string(&bundle).unwrap();
+        let bundle: LibraryBundle = serde_json::from_str(&json).unwrap();
+
+        let target = create_service().await;
+        let dry_run = target
+            .import_bundle(&bundle, BundleImportMode::DryRun)
+            .await
+            .unwrap();
+        assert_eq!(
+            actions(&dry_run, BundleSection::Artist),
+            [("Monitored Artist".to_string(), BundleAction::Create)]
+        );
+        assert_eq!(
+            actions(&dry_run, BundleSection::Indexer),
+            [("Bundle Indexer".to_string(), BundleAction::Create)]
+        );
+        assert_eq!(dry_run.count(BundleAction::Conflict), 0);
+        assert!(target
+            .artists
+            .get_by_name("Monitored Artist")
+            .await
+            .unwrap()
+            .is_none());
+
+        let applied = target
+            .import_bundle(&bundle, BundleImportMode::Apply)
+            .await
+            .unwrap();
+        assert_eq!(applied.items, dry_run.items);
+
+        let artist = target
+            .artists
+            .get_by_foreign_id("mb-artist-1")
+            .await
+            .unwrap()
+            .expect("imported artist");
+        assert!(artist.monitored);
+        let profile = target
+            .quality_profiles
+            .get_by_name("Bundle Lossless")
+            .await
+            .unwrap()
+            .expect("imported profile");
+        assert_eq!(artist.quality_profile_id, Some(profile.id));
+        let indexer = target
+            .indexers
+            .get_by_name("Bundle Indexer")
+            .await
+            .unwrap()
+            .expect("imported indexer");
+        assert_eq!(indexer.api_key, None);
+        assert_eq!(indexer.category_map["audio/flac"], "3040");
+        assert_eq!(indexer.requests_per_minute, Some(30));
+
+        let again = target
+            .import_bundle(&bundle, BundleImportMode::DryRun)
+            .await
+            .unwrap();
+        assert!(again
+            .items
+            .iter()
+            .all(|item| item.action == BundleAction::Unchanged));
+    }
+
+    #[tokio::test]
+    async fn export_reads_every_page_of_each_section() {
+        let service = create_service().await.with_page_size(2);
+        for index in 0..5 {
+            service
+                .quality_profiles
+                .create(QualityProfile::new(
+                    format!("Paged Quality {index}"),
+                    vec!["FLAC".to_string()],
+                ))
+                .await
+                .unwrap();
+            service
+                .artists
+                .create(Artist::new(format!("Paged Artist {index}")))
+                .await
+                .unwrap();
+        }
+
+        let bundle = service.export_bundle().await.unwrap();
+
+        assert_eq!(bundle.quality_profiles.len(), 5);
+        assert_eq!(bundle.artists.len(), 5);
+    }
+
+    #[tokio::test]
+    async fn import_reports_conflicts_and_updates() {
+        let service = create_service().await;
+        populate(&service).await;
+        let mut bundle = service.export_bundle().await.unwrap();
+        bundle.indexers[0].enabled = false;
+        bundle.artists.push(BundleArtist {
+            name: "Monitored Artist".to_string(),
+            foreign_artist_id: None,
+            musicbrainz_artist_id: None,
+            quality_profile: Some("Missing Profile".to_string()),
+            metadata_profile: None,
+        });
+
+        let report = service
+            .import_bundle(&bundle, BundleImportMode::DryRun)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            actions(&report, BundleSection::Indexer),
+            [("Bundle Indexer".to_string(), BundleAction::Update)]
+        );
+        let conflict = report
+            .items
+            .iter()
+            .find(|item| item.action == BundleAction::Conflict)
+            .expect("conflict");
+        assert!(conflict
+            .reason
+            .as_deref()
+            .unwrap()
+            .contains("Missing Profile"));
+    }
+
+    #[tokio::test]
+    async fn import_updates_an_indexer_whose_rate_limit_changed() {
+        let service = create_service().await;
+        populate(&service).await;
+        let mut bundle = service.export_bundle().await.unwrap();
+        bundle.indexers[0].requests_per_minute = Some(10);
+
+        let report = service
+            .import_bundle(&bundle, BundleImportMode::Apply)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            actions(&report, BundleSection::Indexer),
+            [("Bundle Indexer".to_string(), BundleAction::Update)]
+        );
+        let indexer = service
+            .indexers
+            .get_by_name("Bundle Indexer")
+            .await
+            .unwrap()
+            .expect("indexer");
+        assert_eq!(indexer.requests_per_minute, Some(10));
+    }
+
+    #[tokio::test]
+    async fn import_rejects_unknown_bundle_version() {
+        let service = create_service().await;
+        let mut bundle = service.export_bundle().await.unwrap();
+        bundle.version = LIBRARY_BUNDLE_VERSION + 1;
+
+        let err = service
+            .import_bundle(&bundle, BundleImportMode::Apply)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, LibraryBundleError::UnsupportedVersion(2)));
+    }
+}
@@ -1,14 +1,14 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
+use crate::http_client::OutboundHttp;
 use anyhow::Result;
 use async_trait::async_trait;
 use chorrosion_config::AppConfig;
 use chorrosion_domain::{Album, Artist};
 use chorrosion_infrastructure::repositories::{AlbumRepository, ArtistRepository};
 use chorrosion_metadata::lastfm::LastFmClient;
-use chorrosion_musicbrainz::MusicBrainzClient;
+use chorrosion_musicbrainz::{MusicBrainzClient, RateLimiter};
 use chrono::{DateTime, Utc};
 use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
-use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
@@ -125,7 +125,6 @@ where
             if existing.foreign_artist_id.is_none() {
                 let mut updated = existing;
                 updated.foreign_artist_id = Some(entry.external_id.clone());
-                updated.updated_at = Utc::now();
                 artist_repo.update(updated).await?;
                 summary.artists_updated += 1;
             } else {
@@ -171,7 +170,6 @@ where
             if existing_album.foreign_album_id.is_none() {
                 let mut updated = existing_album;
                 updated.foreign_album_id = Some(entry.external_id.clone());
-                updated.updated_at = Utc::now();
                 album_repo.update(updated).await?;
                 summary.albums_updated += 1;
             } else {
@@ -205,10 +203,6 @@ pub struct SpotifyPlaylistListProvider {
     market: Option<String>,
 }
 
-fn build_spotify_http_client() -> Client {
-    crate::http_client::build_http_client()
-}
-
 impl SpotifyPlaylistListProvider {
     pub fn from_config(config: &AppConfig) -> Self {
         let spotify = &config.lists.spotify;
@@ -245,7 +239,7 @@ impl SpotifyPlaylistListProvider {
 
         Self {
             enabled: spotify.enabled,
-            http_client: build_spotify_http_client(),
+            http_client: OutboundHttp::from_config(&config.http).default_client(),
             base_url,
             access_token,
             playlist_ids,
@@ -463,7 +457,10 @@ impl ListProvider for SpotifyPlaylistListProvider {
 }
 
 impl MusicBrainzListProvider {
-    pub fn from_config(config: &AppConfig) -> Result<Self> {
+    /// Build the provider from `config`, pacing its requests through
+    /// `rate_limiter` so list imports share the process-wide MusicBrainz budget
+    /// with the refresh jobs.
+    pub fn from_config(config: &AppConfig, rate_limiter: RateLimiter) -> Result<Self> {
         let musicbrainz = &config.lists.musicbrainz;
         let base_url = musicbrainz
             .base_url
@@ -475,11 +472,17 @@ impl MusicBrainzListProvider {
         let artist_mbids = parse_mbids(&musicbrainz.artist_mbids);
         let album_mbids = parse_mbids(&musicbrainz.album_mbids);
 
-        let client = if let Some(url) = base_url {
-            MusicBrainzClient::builder().base_url(url).build()?
-        } else {
-            MusicBrainzClient::new()?
-        };
+        let mut builder = MusicBrainzClient::builder().rate_limiter(rate_limiter);
+        if let Some(url) = base_url {
+            builder = builder.base_url(url);
+        }
+        if let Some(proxy_url) = config.http.proxy_url.as_deref() {
+            builder = builder.proxy_url(proxy_url);
+        }
+        if let Some(user_agent) = config.http.user_agent.as_deref() {
+            builder = builder.user_agent(user_agent);
+        }
+        let client = builder.build()?;
 
         Ok(Self {
             enabled: musicbrainz.enabled,
@@ -632,9 +635,10 @@ impl LastFmListProvider {
             .filter(|s| !s.is_empty())
             .map(str::to_string);
         let client = if lfm.enabled {
-            api_key
-                .as_ref()
-                .map(|key| LastFmClient::new(key.clone(), base_url))
+            api_key.as_ref().map(|key| {
+                LastFmClient::new(key.clone(), base_url)
+                    .with_http_client(OutboundHttp::from_config(&config.http).default_client())
+            })
         } else {
             None
         };
@@ -781,6 +785,10 @@ mod tests {
         Mock, MockServer, ResponseTemplate,
     };
 
+    fn test_rate_limiter() -> RateLimiter {
+        RateLimiter::new(std::time::Duration::from_millis(1))
+    }
+
     #[derive(Clone, Default)]
     struct InMemoryArtistRepo {
         artists: Arc<Mutex<Vec<Artist>>>,
@@ -861,6 +869,54 @@ mod tests {
                 .collect())
         }
 
+        async fn autocomplete(&self, prefix: &str, limit: i64) -> Result<Vec<Artist>> {
+            let prefix = prefix.to_lowercase();
+            let artists = self.artists.lock().unwrap();
+            Ok(artists
+                .iter()
+                .filter(|artist| artist.name.to_lowercase().starts_with(&prefix))
+                .take(limit.max(0) as usize)
+                .cloned()
+                .collect())
+        }
+
+        async fn set_monitored(
+            &self,
+            ids: &[chorrosion_domain::ArtistId],
+            monitored: bool,
+        ) -> Result<u64> {
+            let mut artists = self.artists.lock().unwrap();
+            let mut updated = 0;
+            for artist in artists.iter_mut().filter(|artist| ids.contains(&artist.id)) {
+                artist.monitored = monitored;
+                updated += 1;
+            }
+            Ok(updated)
+        }
+
+        async fn set_monitored_cascade(
+            &self,
+            ids: &[chorrosion_domain::ArtistId],
+            monitored: bool,
+        ) -> Result<chorrosion_infrastructure::repositories::MonitoredCascade> {
+            Ok(chorrosion_infrastructure::repositories::MonitoredCascade {
+                artists: self.set_monitored(ids, monitored).await?,
+                ..Default::default()
+            })
+        }
+
+        async fn record_metadata_refresh(
+            &self,
+            id: chorrosion_domain::ArtistId,
+            at: chrono::DateTime<chrono::Utc>,
+        ) -> Result<()> {
+            let mut artists = self.artists.lock().unwrap();
+            if let Some(artist) = artists.iter_mut().find(|artist| artist.id == id) {
+                artist.last_metadata_refresh_at = Some(at);
+            }
+            Ok(())
+        }
+
         async fn get_by_status(
             &self,
             status: ArtistStatus,
@@ -876,6 +932,33 @@ mod tests {
                 .cloned()
                 .collect())
         }
+
+        async fn list_recently_added(&self, _limit: i64, _offset: i64) -> Result<Vec<Artist>> {
+            Ok(vec![])
+        }
+
+        async fn list_updated_since(
+            &self,
+            _since: chrono::DateTime<chrono::Utc>,
+            _limit: i64,
+            _offset: i64,
+        ) -> Result<Vec<Artist>> {
+            Ok(vec![])
+        }
+
+        async fn merge_artists(
+            &self,
+            _keep_id: chorrosion_domain::ArtistId,
+            _remove_id: chorrosion_domain::ArtistId,
+        ) -> Result<Artist> {
+            Err(anyhow::anyhow!(
+                "merge not supported by in-memory repository"
+            ))
+        }
+
+        async fn upsert_by_foreign_id(&self, entity: Artist) -> Result<Artist> {
+            self.create(entity).await
+        }
     }
 
     #[derive(Clone, Default)]
@@ -996,6 +1079,32 @@ mod tests {
                 .collect())
         }
 
+        async fn set_monitored(
+            &self,
+            ids: &[chorrosion_domain::AlbumId],
+            monitored: bool,
+        ) -> Result<u64> {
+            let mut albums = self.albums.lock().unwrap();
+            let mut updated = 0;
+            for album in albums.iter_mut().filter(|album| ids.contains(&album.id)) {
+                album.monitored = monitored;
+                updated += 1;
+            }
+            Ok(updated)
+        }
+
+        async fn record_metadata_refresh(
+            &self,
+            id: chorrosion_domain::AlbumId,
+            at: chrono::DateTime<chrono::Utc>,
+        ) -> Result<()> {
+            let mut albums = self.albums.lock().unwrap();
+            if let Some(album) = albums.iter_mut().find(|album| album.id == id) {
+                album.last_metadata_refresh_at = Some(at);
+            }
+            Ok(())
+        }
+
         async fn get_by_album_type(
             &self,
             album_type: &str,
@@ -1029,6 +1138,45 @@ mod tests {
         ) -> Result<Vec<Album>> {
             Ok(vec![])
         }
+
+        async fn list_recently_added(&self, _limit: i64, _offset: i64) -> Result<Vec<Album>> {
+            Ok(vec![])
+        }
+
+        async fn list_updated_since(
+            &self,
+            _since: chrono::DateTime<chrono::Utc>,
+            _limit: i64,
+            _offset: i64,
+        ) -> Result<Vec<Album>> {
+            Ok(vec![])
+        }
+
+        async fn upsert_by_foreign_id(&self, entity: Album) -> Result<Album> {
+            self.create(entity).await
+        }
+
+        async fn track_stats(
+            &self,
+            _album_id: chorrosion_domain::AlbumId,
+        ) -> Result<chorrosion_infrastructure::repositories::TrackStats> {
+            Ok(Default::default())
+        }
+
+        async fn track_stats_for(
+            &self,
+            album_ids: &[chorrosion_domain::AlbumId],
+        ) -> Result<
+            HashMap<
+                chorrosion_domain::AlbumId,
+                chorrosion_infrastructure::repositories::TrackStats,
+            >,
+        > {
+            Ok(album_ids
+                .iter()
+                .map(|id| (*id, Default::default()))
+                .collect())
+        }
     }
 
     #[test]
@@ -1146,7 +1294,7 @@ mod tests {
             ..AppConfig::default()
         };
 
-        let provider = MusicBrainzListProvider::from_config(&config).unwrap();
+        let provider = MusicBrainzListProvider::from_config(&config, test_rate_limiter()).unwrap();
         let artists = provider.fetch_followed_artists().await.unwrap();
         let albums = provider.fetch_saved_albums().await.unwrap();
 
@@ -1176,7 +1324,7 @@ mod tests {
             ..AppConfig::default()
         };
 
-        let provider = MusicBrainzListProvider::from_config(&config).unwrap();
+        let provider = MusicBrainzListProvider::from_config(&config, test_rate_limiter()).unwrap();
         let health = provider.health_check().await.unwrap();
         assert!(!health.ok);
         assert_eq!(
@@ -17,7 +17,18 @@
 //!
 //! Each fallback step is attempted if the previous step fails or is unavailable.
 //! Confidence scores decrease at each level, allowing confidence-based filtering.
+//!
+//! When both the matched recording and the local file report a duration, the
+//! confidence is penalized in proportion to the difference, and matches outside
+//! the configured tolerance (±10s by default) are rejected.
+//!
+//! Matches carry a [`MatchExplanation`] listing the factors that produced their
+//! confidence, so a wrong match can be traced back to its evidence. The
+//! precedence engine builds one for tag and filename matches with
+//! [`explain_track_match`].
 
+use crate::import_matching::normalized_similarity;
+use crate::matching_precedence::MatchingStrategy;
 use chorrosion_domain::{Track, TrackFile, TrackFileId, TrackId};
 use chorrosion_fingerprint::{AcoustidClient, Fingerprint, FingerprintError};
 use chorrosion_musicbrainz::{MusicBrainzClient, MusicBrainzError, Recording};
@@ -46,11 +57,190 @@ pub enum MatchingError {
 
     #[error("MusicBrainz error: {0}")]
     MusicBrainzError(#[from] MusicBrainzError),
+
+    #[error("Duration differs by {delta_ms}ms, exceeding tolerance of {tolerance_ms}ms")]
+    DurationMismatch { delta_ms: i64, tolerance_ms: u32 },
 }
 
 /// Result type for matching operations
 pub type MatchingResult<T> = Result<T, MatchingError>;
 
+/// Default maximum allowed difference between candidate and target durations.
+pub const DEFAULT_DURATION_TOLERANCE_MS: u32 = 10_000;
+
+/// Fraction of the confidence removed for a duration difference at the tolerance limit.
+const MAX_DURATION_PENALTY: f32 = 0.5;
+
+/// Adjust a match confidence for the duration difference between candidate and target.
+///
+/// When either duration is unknown the confidence is returned unchanged with no delta.
+/// Otherwise the confidence is reduced linearly with the difference (by up to half at
+/// the tolerance limit) and the signed delta (`candidate - target`) is returned.
+///
+/// # Returns
+///
+/// * `Ok((confidence, delta_ms))` - Adjusted confidence and duration delta
+/// * `Err(MatchingError::DurationMismatch)` - Difference exceeds `tolerance_ms`
+pub fn apply_duration_tolerance(
+    confidence: f32,
+    candidate_duration_ms: Option<u32>,
+    target_duration_ms: Option<u32>,
+    tolerance_ms: u32,
+) -> MatchingResult<(f32, Option<i64>)> {
+    let (Some(candidate), Some(target)) = (candidate_duration_ms, target_duration_ms) else {
+        return Ok((confidence, None));
+    };
+
+    let delta_ms = i64::from(candidate) - i64::from(target);
+    let abs_delta = delta_ms.unsigned_abs();
+    if abs_delta > u64::from(tolerance_ms) {
+        return Err(MatchingError::DurationMismatch {
+            delta_ms,
+            tolerance_ms,
+        });
+    }
+
+    let penalty = if tolerance_ms == 0 {
+        0.0
+    } else {
+        (abs_delta as f32 / tolerance_ms as f32) * MAX_DURATION_PENALTY
+    };
+
+    Ok((confidence * (1.0 - penalty), Some(delta_ms)))
+}
+
+/// Confidence contributed by an exact (normalized) title match.
+pub const TITLE_EXACT_WEIGHT: f32 = 0.5;
+
+/// Confidence contributed by a fuzzy title match, scaled by similarity.
+pub const TITLE_FUZZY_WEIGHT: f32 = 0.4;
+
+/// Minimum title similarity (0.0-1.0) counted as a fuzzy match.
+pub const FUZZY_TITLE_THRESHOLD: f32 = 0.6;
+
+/// Confidence contributed by a matching track number.
+pub const TRACK_NUMBER_WEIGHT: f32 = 0.3;
+
+/// Confidence contributed by identical durations, reduced linearly up to the tolerance.
+pub const DURATION_WEIGHT: f32 = 0.2;
+
+/// A piece of evidence that contributed to a match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MatchFactor {
+    /// AcoustID fingerprint lookup score
+    Fingerprint,
+    /// MusicBrainz recording ID read from the file's tags
+    RecordingIdTag,
+    /// Titles are identical after normalization
+    TitleExact,
+    /// Titles are similar but not identical
+    TitleFuzzy,
+    /// Track numbers are equal
+    TrackNumber,
+    /// Durations differ by no more than the tolerance
+    DurationWithinTolerance,
+}
+
+/// One factor and how much it added to (or, for penalties, removed from) the confidence.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MatchContribution {
+    pub factor: MatchFactor,
+    pub contribution: f32,
+}
+
+/// Why a match was made: the strategy that produced it and each contributing factor.
+///
+/// Contributions sum to the match's confidence.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchExplanation {
+    /// Strategy that produced the match
+    pub strategy: MatchingStrategy,
+    /// Factors in the order they were evaluated
+    pub contributions: Vec<MatchContribution>,
+}
+
+impl MatchExplanation {
+    pub fn new(strategy: MatchingStrategy) -> Self {
+        Self {
+            strategy,
+            contributions: Vec::new(),
+        }
+    }
+
+    /// Record that `factor` contributed `contribution` to the confidence.
+    pub fn add(&mut self, factor: MatchFactor, contribution: f32) {
+        self.contributions.push(MatchContribution {
+            factor,
+            contribution,
+        });
+    }
+
+    /// Factors that contributed, in evaluation order.
+    pub fn factors(&self) -> Vec<MatchFactor> {
+        self.contributions.iter().map(|c| c.factor).collect()
+    }
+
+    /// Contribution of `factor`, if it took part in the match.
+    pub fn contribution(&self, factor: MatchFactor) -> Option<f32> {
+        self.contributions
+            .iter()
+            .find(|c| c.factor == factor)
+            .map(|c| c.contribution)
+    }
+
+    /// Sum of all contributions.
+    pub fn total(&self) -> f32 {
+        self.contributions.iter().map(|c| c.contribution).sum()
+    }
+}
+
+/// Explain how well a file's metadata matches `candidate`.
+///
+/// Title (exact or fuzzy), track number and duration are compared independently;
+/// only factors that matched are listed. The explanation's [`total`] is the
+/// resulting confidence, at most 1.0.
+///
+/// [`total`]: MatchExplanation::total
+pub fn explain_track_match(
+    strategy: MatchingStrategy,
+    title: Option<&str>,
+    track_number: Option<u32>,
+    duration_ms: Option<u32>,
+    candidate: &Track,
+    duration_tolerance_ms: u32,
+) -> MatchExplanation {
+    let mut explanation = MatchExplanation::new(strategy);
+
+    if let Some(title) = title {
+        let similarity = normalized_similarity(title, &candidate.title);
+        if similarity >= 1.0 {
+            explanation.add(MatchFactor::TitleExact, TITLE_EXACT_WEIGHT);
+        } else if similarity >= FUZZY_TITLE_THRESHOLD {
+            explanation.add(MatchFactor::TitleFuzzy, TITLE_FUZZY_WEIGHT * similarity);
+        }
+    }
+
+    if track_number.is_some() && track_number == candidate.track_number {
+        explanation.add(MatchFactor::TrackNumber, TRACK_NUMBER_WEIGHT);
+    }
+
+    if let Ok((closeness, Some(_))) = apply_duration_tolerance(
+        1.0,
+        candidate.duration_ms,
+        duration_ms,
+        duration_tolerance_ms,
+    ) {
+        // The tolerance penalty removes up to half; rescale so the limit scores zero.
+        let scaled = ((closeness - (1.0 - MAX_DURATION_PENALTY)) / MAX_DURATION_PENALTY).max(0.0);
+        explanation.add(
+            MatchFactor::DurationWithinTolerance,
+            DURATION_WEIGHT * scaled,
+        );
+    }
+
+    explanation
+}
+
 fn extract_artist_album_links(recording: &Recording) -> (Option<String>, Option<String>) {
     let artist_id = recording
         .artist_credit
@@ -79,7 +269,7 @@ fn extract_artist_album_links(recording: &Recording) -> (Option<String>, Option<
 }
 
 /// Metadata about a successful match
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct MatchResult {
     /// MusicBrainz recording ID from AcoustID lookup
     pub musicbrainz_recording_id: String,
@@ -87,8 +277,13 @@ pub struct MatchResult {
     pub musicbrainz_artist_id: Option<String>,
     /// Linked MusicBrainz release-group ID (album) resolved from the matched recording.
     pub musicbrainz_release_group_id: Option<String>,
-    /// Confidence score from AcoustID (0.0-1.0)
+    /// Confidence score from AcoustID (0.0-1.0), after any duration penalty
     pub confidence_score: f32,
+    /// Recording duration minus file duration in milliseconds, when both are known.
+    pub duration_delta_ms: Option<i64>,
+    /// Fingerprint score and any duration penalty that make up `confidence_score`,
+    /// when the producer recorded them
+    pub explanation: Option<MatchExplanation>,
 }
 
 /// Track matching engine using fingerprints as primary lookup.
@@ -101,6 +296,7 @@ pub struct MatchResult {
 pub struct TrackMatchingService {
     acoustid_client: Arc<AcoustidClient>,
     musicbrainz_client: Option<Arc<MusicBrainzClient>>,
+    duration_tolerance_ms: u32,
 }
 
 impl TrackMatchingService {
@@ -113,6 +309,7 @@ impl TrackMatchingService {
         Self {
             acoustid_client: Arc::new(acoustid_client),
             musicbrainz_client: None,
+            duration_tolerance_ms: DEFAULT_DURATION_TOLERANCE_MS,
         }
     }
 
@@ -124,9 +321,21 @@ impl TrackMatchingService {
         Self {
             acoustid_client: Arc::new(acoustid_client),
             musicbrainz_client: Some(Arc::new(musicbrainz_client)),
+            duration_tolerance_ms: DEFAULT_DURATION_TOLERANCE_MS,
         }
     }
 
+    /// Override the maximum allowed duration difference (default ±10s).
+    pub fn with_duration_tolerance_ms(mut self, tolerance_ms: u32) -> Self {
+        self.duration_tolerance_ms = tolerance_ms;
+        self
+    }
+
+    /// How far a file's duration may stray from a recording's before it stops matching.
+    pub(crate) fn duration_tolerance_ms(&self) -> u32 {
+        self.duration_tolerance_ms
+    }
+
     /// Match a track file using its fingerprint via AcoustID.
     ///
     /// This is the primary matching strategy. Returns the first match with confidence
@@ -143,6 +352,8 @@ impl TrackMatchingService {
     /// * `Err(MatchingError::NoFingerprint)` - Track file has no fingerprint
     /// * `Err(MatchingError::NoMatches)` - AcoustID returned no results
     /// * `Err(MatchingError::LowConfidence)` - Best match below threshold
+    /// * `Err(MatchingError::DurationMismatch)` - Recording and file durations differ
+    ///   by more than the configured tolerance
     pub async fn match_track(
         &self,
         track_file: &TrackFile,
@@ -181,7 +392,7 @@ impl TrackMatchingService {
             .await?;
 
         let recording_uuid = recording_match.id;
-        let (musicbrainz_artist_id, musicbrainz_release_group_id) = self
+        let (musicbrainz_artist_id, musicbrainz_release_group_id, recording_length_ms) = self
             .resolve_recording_links(recording_uuid)
             .await
             .unwrap_or_else(|error| {
@@ -191,16 +402,40 @@ impl TrackMatchingService {
                     error = %error,
                     "unable to resolve recording artist/album links"
                 );
-                (None, None)
+                (None, None, None)
+            });
+
+        let (confidence_score, duration_delta_ms) = apply_duration_tolerance(
+            recording_match.score,
+            recording_length_ms,
+            track_file.duration_ms,
+            self.duration_tolerance_ms,
+        )?;
+
+        if confidence_score < min_confidence {
+            return Err(MatchingError::LowConfidence {
+                score: confidence_score,
+                threshold: min_confidence,
             });
+        }
 
         let recording_id = recording_uuid.to_string();
 
+        let mut explanation = MatchExplanation::new(MatchingStrategy::Fingerprint);
+        explanation.add(MatchFactor::Fingerprint, recording_match.score);
+        if duration_delta_ms.is_some() {
+            explanation.add(
+                MatchFactor::DurationWithinTolerance,
+                confidence_score - recording_match.score,
+            );
+        }
+
         debug!(
             target: "matching",
             track_id = %track_file.track_id,
             recording_id = %recording_id,
-            confidence = recording_match.score,
+            confidence = confidence_score,
+            duration_delta_ms = ?duration_delta_ms,
             "fingerprint match successful"
         );
 
@@ -208,20 +443,24 @@ impl TrackMatchingService {
             musicbrainz_recording_id: recording_id,
             musicbrainz_artist_id,
             musicbrainz_release_group_id,
-            confidence_score: recording_match.score,
+            confidence_score,
+            duration_delta_ms,
+            explanation: Some(explanation),
         })
     }
 
+    /// Resolve artist/album links and the recording length (ms) from MusicBrainz.
     async fn resolve_recording_links(
         &self,
         recording_id: Uuid,
-    ) -> MatchingResult<(Option<String>, Option<String>)> {
+    ) -> MatchingResult<(Option<String>, Option<String>, Option<u32>)> {
         let Some(client) = &self.musicbrainz_client else {
-            return Ok((None, None));
+            return Ok((None, None, None));
         };
 
         let recording = client.lookup_recording(recording_id).await?;
-        Ok(extract_artist_album_links(&recording))
+        let (artist_id, release_group_id) = extract_artist_album_links(&recording);
+        Ok((artist_id, release_group_id, recording.length))
     }
 
     /// Update a track with matching results.
@@ -236,7 +475,6 @@ impl TrackMatchingService {
     pub fn apply_match(track: &mut Track, match_result: &MatchResult) {
         track.musicbrainz_recording_id = Some(match_result.musicbrainz_recording_id.clone());
         track.match_confidence = Some(match_result.confidence_score);
-        track.updated_at = chrono::Utc::now();
     }
 
     /// Batch match multiple track files with progress tracking.
@@ -316,6 +554,8 @@ mod tests {
             musicbrainz_artist_id: Some("a74b1b7f-71a5-4011-9441-d0b5e4122711".to_string()),
             musicbrainz_release_group_id: Some("b1392450-e666-3926-a536-22c65f834433".to_string()),
             confidence_score: 0.95,
+            duration_delta_ms: None,
+            explanation: None,
         };
 
         assert_eq!(track.musicbrainz_recording_id, None);
@@ -498,4 +738,128 @@ mod tests {
         assert!(artist_id.is_none());
         assert!(release_group_id.is_none());
     }
+
+    #[test]
+    fn duration_within_tolerance_is_penalized_proportionally() {
+        // Candidate is 5s longer than the file: half the tolerance, a quarter penalty.
+        let (confidence, delta) =
+            apply_duration_tolerance(0.8, Some(185_000), Some(180_000), 10_000)
+                .expect("within tolerance");
+
+        assert_eq!(delta, Some(5_000));
+        assert!((confidence - 0.6).abs() < 1e-6);
+
+        let (exact, delta) = apply_duration_tolerance(0.8, Some(180_000), Some(180_000), 10_000)
+            .expect("identical durations");
+        assert_eq!(delta, Some(0));
+        assert_eq!(exact, 0.8);
+    }
+
+    #[test]
+    fn duration_outside_tolerance_is_rejected() {
+        // A 3:05 album edit must not match a 7:40 extended version.
+        let err = apply_duration_tolerance(0.95, Some(460_000), Some(185_000), 10_000)
+            .expect_err("outside tolerance");
+
+        assert!(matches!(
+            err,
+            MatchingError::DurationMismatch {
+                delta_ms: 275_000,
+                tolerance_ms: 10_000
+            }
+        ));
+    }
+
+    #[test]
+    fn missing_durations_leave_confidence_unchanged() {
+        assert_eq!(
+            apply_duration_tolerance(0.9, None, None, DEFAULT_DURATION_TOLERANCE_MS)
+                .expect("both missing"),
+            (0.9, None)
+        );
+        assert_eq!(
+            apply_duration_tolerance(0.9, Some(200_000), None, DEFAULT_DURATION_TOLERANCE_MS)
+                .expect("target missing"),
+            (0.9, None)
+        );
+    }
+
+    fn candidate_track(title: &str, track_number: u32, duration_ms: u32) -> Track {
+        let mut track = Track::new(Default::default(), Default::default(), title);
+        track.track_number = Some(track_number);
+        track.duration_ms = Some(duration_ms);
+        track
+    }
+
+    #[test]
+    fn strong_match_explains_title_number_and_duration() {
+        let candidate = candidate_track("Paranoid Android", 2, 383_000);
+
+        let explanation = explain_track_match(
+            MatchingStrategy::EmbeddedTags,
+            Some("paranoid android"),
+            Some(2),
+            Some(383_000),
+            &candidate,
+            DEFAULT_DURATION_TOLERANCE_MS,
+        );
+
+        assert_eq!(explanation.strategy, MatchingStrategy::EmbeddedTags);
+        assert_eq!(
+            explanation.factors(),
+            vec![
+                MatchFactor::TitleExact,
+                MatchFactor::TrackNumber,
+                MatchFactor::DurationWithinTolerance,
+            ]
+        );
+        assert_eq!(
+            explanation.contribution(MatchFactor::TitleExact),
+            Some(TITLE_EXACT_WEIGHT)
+        );
+        assert_eq!(
+            explanation.contribution(MatchFactor::TrackNumber),
+            Some(TRACK_NUMBER_WEIGHT)
+        );
+        assert!((explanation.total() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn weak_match_explains_fuzzy_title_only() {
+        let candidate = candidate_track("Paranoid Android", 2, 383_000);
+
+        let explanation = explain_track_match(
+            MatchingStrategy::FilenameHeuristics,
+            Some("Paranoid Androids"),
+            Some(7),
+            None,
+            &candidate,
+            DEFAULT_DURATION_TOLERANCE_MS,
+        );
+
+        assert_eq!(explanation.factors(), vec![MatchFactor::TitleFuzzy]);
+        let fuzzy = explanation
+            .contribution(MatchFactor::TitleFuzzy)
+            .expect("fuzzy contribution");
+        assert!(fuzzy > 0.0 && fuzzy < TITLE_FUZZY_WEIGHT);
+        assert!(explanation.contribution(MatchFactor::TrackNumber).is_none());
+        assert!(explanation.total() < 0.5);
+    }
+
+    #[test]
+    fn dissimilar_title_and_far_duration_contribute_nothing() {
+        let candidate = candidate_track("Paranoid Android", 2, 383_000);
+
+        let explanation = explain_track_match(
+            MatchingStrategy::FilenameHeuristics,
+            Some("Karma Police"),
+            None,
+            Some(261_000),
+            &candidate,
+            DEFAULT_DURATION_TOLERANCE_MS,
+        );
+
+        assert!(explanation.contributions.is_empty());
+        assert_eq!(explanation.total(), 0.0);
+    }
 }
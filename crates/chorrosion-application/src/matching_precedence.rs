@@ -8,8 +8,9 @@
 //! 3. **Final Fallback**: Filename heuristics (lowest confidence)
 //!
 //! The precedence engine ensures that each strategy is only attempted if
-//! the previous one fails or is unavailable, with clear confidence scoring
-//! at each level to allow confidence-based filtering.
+//! the previous one fails, is unavailable, or could still be outscored, with
+//! clear confidence scoring at each level to allow confidence-based filtering.
+//! How much each strategy is trusted is controlled by [`PrecedenceWeights`].
 //!
 //! ## Usage
 //!
@@ -40,8 +41,12 @@
 
 use crate::embedded_tags::EmbeddedTagMatchingService;
 use crate::filename_heuristics::FilenameHeuristicsService;
-use crate::matching::{MatchingError, TrackMatchingService};
+use crate::matching::{
+    explain_track_match, MatchExplanation, MatchFactor, MatchingError, TrackMatchingService,
+};
 use chorrosion_domain::TrackFile;
+use chorrosion_infrastructure::repositories::TrackRepository;
+use chorrosion_infrastructure::{collect_all, pagination::DEFAULT_PAGE_SIZE};
 use std::sync::Arc;
 use thiserror::Error;
 use tracing::{debug, info, warn};
@@ -67,6 +72,131 @@ impl std::fmt::Display for MatchingStrategy {
     }
 }
 
+impl MatchingStrategy {
+    /// All strategies in precedence order (highest priority first).
+    pub const ALL: [MatchingStrategy; 3] = [
+        MatchingStrategy::Fingerprint,
+        MatchingStrategy::EmbeddedTags,
+        MatchingStrategy::FilenameHeuristics,
+    ];
+}
+
+/// Relative trust placed in each matching strategy.
+///
+/// A strategy's raw confidence is multiplied by its weight to produce a weighted
+/// score. The candidate with the highest weighted score wins (ties go to the
+/// higher-precedence strategy), and candidates scoring below `min_score` are
+/// rejected. The defaults mirror the fixed fingerprint > tags > filename ordering.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PrecedenceWeights {
+    /// Weight applied to fingerprint (AcoustID) matches (default 1.0)
+    pub fingerprint: f32,
+    /// Weight applied to embedded tag matches (default 0.75)
+    pub embedded_tags: f32,
+    /// Weight applied to filename heuristic matches (default 0.5)
+    pub filename_heuristics: f32,
+    /// Minimum weighted score a candidate needs to be accepted
+    pub min_score: f32,
+}
+
+impl Default for PrecedenceWeights {
+    fn default() -> Self {
+        Self {
+            fingerprint: 1.0,
+            embedded_tags: 0.75,
+            filename_heuristics: 0.5,
+            min_score: 0.0,
+        }
+    }
+}
+
+impl PrecedenceWeights {
+    /// Weight configured for `strategy`.
+    pub fn weight_for(&self, strategy: MatchingStrategy) -> f32 {
+        match strategy {
+            MatchingStrategy::Fingerprint => self.fingerprint,
+            MatchingStrategy::EmbeddedTags => self.embedded_tags,
+            MatchingStrategy::FilenameHeuristics => self.filename_heuristics,
+        }
+    }
+
+    /// Weighted score for a match obtained by `strategy` with the given raw confidence.
+    pub fn weighted_score(&self, strategy: MatchingStrategy, confidence: f32) -> f32 {
+        confidence * self.weight_for(strategy)
+    }
+
+    /// Pick the best candidate by weighted score.
+    ///
+    /// Each returned result has its `weighted_score` recomputed from these weights.
+    /// Returns `None` when no candidate reaches `min_score`.
+    pub fn select(
+        &self,
+        candidates: impl IntoIterator<Item = PrecedenceMatchResult>,
+    ) -> Option<PrecedenceMatchResult> {
+        let mut best: Option<PrecedenceMatchResult> = None;
+
+        for mut candidate in candidates {
+            candidate.weighted_score =
+                self.weighted_score(candidate.strategy, candidate.confidence);
+            if candidate.weighted_score < self.min_score {
+                continue;
+            }
+
+            let replaces_best = match &best {
+                None => true,
+                Some(current) => {
+                    candidate.weighted_score > current.weighted_score
+                        || (candidate.weighted_score == current.weighted_score
+                            && precedence_rank(candidate.strategy)
+                                < precedence_rank(current.strategy))
+                }
+            };
+
+            if replaces_best {
+                best = Some(candidate);
+            }
+        }
+
+        best
+    }
+
+    fn validate(&self) -> PrecedenceMatchingResult<()> {
+        for strategy in MatchingStrategy::ALL {
+            let weight = self.weight_for(strategy);
+            if !weight.is_finite() || weight < 0.0 {
+                return Err(PrecedenceMatchingError::InvalidWeight(strategy, weight));
+            }
+        }
+
+        if !self.min_score.is_finite() || self.min_score < 0.0 {
+            return Err(PrecedenceMatchingError::InvalidThreshold(self.min_score));
+        }
+
+        Ok(())
+    }
+
+    /// Highest weighted score any strategy after `strategy` could still produce.
+    fn max_remaining_score(&self, strategy: MatchingStrategy) -> f32 {
+        MatchingStrategy::ALL
+            .iter()
+            .skip(precedence_rank(strategy) + 1)
+            .map(|remaining| self.weight_for(*remaining))
+            .fold(0.0, f32::max)
+    }
+}
+
+fn precedence_rank(strategy: MatchingStrategy) -> usize {
+    MatchingStrategy::ALL
+        .iter()
+        .position(|candidate| *candidate == strategy)
+        .unwrap_or(MatchingStrategy::ALL.len())
+}
+
+/// Confidence given to a recording ID read from the file's own tags
+/// (`MUSICBRAINZ_TRACKID` and equivalents).  Taggers write it after a
+/// confirmed lookup, but the file may have been retagged by hand since.
+pub const TAGGED_RECORDING_ID_CONFIDENCE: f32 = 0.9;
+
 /// Result from precedence-based matching including strategy information
 #[derive(Debug, Clone)]
 pub struct PrecedenceMatchResult {
@@ -76,6 +206,10 @@ pub struct PrecedenceMatchResult {
     pub confidence: f32,
     /// Which strategy was used to obtain this match
     pub strategy: MatchingStrategy,
+    /// Confidence multiplied by the strategy's weight in [`PrecedenceWeights`]
+    pub weighted_score: f32,
+    /// Factors behind `confidence` and the strategy that produced them
+    pub explanation: MatchExplanation,
 }
 
 /// Errors that can occur during precedence matching
@@ -89,6 +223,9 @@ pub enum PrecedenceMatchingError {
 
     #[error("Invalid confidence threshold: {0}")]
     InvalidThreshold(f32),
+
+    #[error("Invalid weight for {0} strategy: {1}")]
+    InvalidWeight(MatchingStrategy, f32),
 }
 
 pub type PrecedenceMatchingResult<T> = Result<T, PrecedenceMatchingError>;
@@ -102,6 +239,8 @@ pub struct PrecedenceMatchingEngine {
     fingerprint_service: Arc<TrackMatchingService>,
     embedded_tags_service: Arc<EmbeddedTagMatchingService>,
     filename_heuristics_service: Arc<FilenameHeuristicsService>,
+    weights: PrecedenceWeights,
+    tracks: Option<Arc<dyn TrackRepository>>,
 }
 
 impl PrecedenceMatchingEngine {
@@ -116,14 +255,44 @@ impl PrecedenceMatchingEngine {
         fingerprint_service: TrackMatchingService,
         embedded_tags_service: EmbeddedTagMatchingService,
         filename_heuristics_service: FilenameHeuristicsService,
+    ) -> Self {
+        Self::new_with_weights(
+            fingerprint_service,
+            embedded_tags_service,
+            filename_heuristics_service,
+            PrecedenceWeights::default(),
+        )
+    }
+
+    /// Create a new precedence matching engine with custom strategy weights.
+    pub fn new_with_weights(
+        fingerprint_service: TrackMatchingService,
+        embedded_tags_service: EmbeddedTagMatchingService,
+        filename_heuristics_service: FilenameHeuristicsService,
+        weights: PrecedenceWeights,
     ) -> Self {
         Self {
             fingerprint_service: Arc::new(fingerprint_service),
             embedded_tags_service: Arc::new(embedded_tags_service),
             filename_heuristics_service: Arc::new(filename_heuristics_service),
+            weights,
+            tracks: None,
         }
     }
 
+    /// Let the tags and filename strategies match a file against the other tracks
+    /// on its album in `tracks`. Without it those strategies only accept a tagged
+    /// recording ID.
+    pub fn with_track_repository(mut self, tracks: Arc<dyn TrackRepository>) -> Self {
+        self.tracks = Some(tracks);
+        self
+    }
+
+    /// Strategy weights used by this engine.
+    pub fn weights(&self) -> &PrecedenceWeights {
+        &self.weights
+    }
+
     /// Execute matching with enforced precedence (fingerprint > tags > filename).
     ///
     /// Attempts each matching strategy in order:
@@ -131,7 +300,9 @@ impl PrecedenceMatchingEngine {
     /// 2. Embedded tags (if available)
     /// 3. Filename heuristics (fallback)
     ///
-    /// Returns the first successful match that meets the confidence threshold.
+    /// Each successful match is scored with the engine's [`PrecedenceWeights`].
+    /// Lower-precedence strategies are skipped once the best weighted score so
+    /// far can no longer be beaten, and the best-scoring candidate is returned.
     ///
     /// # Arguments
     ///
@@ -143,7 +314,8 @@ impl PrecedenceMatchingEngine {
     /// # Returns
     ///
     /// * `Ok(PrecedenceMatchResult)` - Successfully matched with strategy info
-    /// * `Err(PrecedenceMatchingError::AllStrategiesFailed)` - All strategies failed
+    /// * `Err(PrecedenceMatchingError::AllStrategiesFailed)` - All strategies failed or
+    ///   scored below the weighted threshold
     pub async fn match_with_precedence(
         &self,
         track_file: &TrackFile,
@@ -164,15 +336,22 @@ impl PrecedenceMatchingEngine {
             "starting precedence-based matching"
         );
 
+        self.weights.validate()?;
+
+        let mut candidates = Vec::new();
+
         // Strategy 1: Fingerprint-based lookup (highest confidence)
         if let Some(result) = self.try_fingerprint_match(track_file, min_confidence).await {
-            return result;
+            candidates.push(result?);
+        }
+        if let Some(best) = self.accept_if_unbeatable(&candidates, MatchingStrategy::Fingerprint) {
+            return Ok(best);
         }
 
         debug!(
             target: "precedence_matching",
             track_file_id = %track_file.id,
-            "fingerprint matching unavailable, falling back to embedded tags"
+            "fingerprint matching unavailable or beatable, trying embedded tags"
         );
 
         // Strategy 2: Embedded tags (medium confidence)
@@ -180,13 +359,16 @@ impl PrecedenceMatchingEngine {
             .try_embedded_tags_match(track_file, min_confidence, folder_artist, folder_album)
             .await
         {
-            return result;
+            candidates.push(result?);
+        }
+        if let Some(best) = self.accept_if_unbeatable(&candidates, MatchingStrategy::EmbeddedTags) {
+            return Ok(best);
         }
 
         debug!(
             target: "precedence_matching",
             track_file_id = %track_file.id,
-            "embedded tags matching unavailable, falling back to filename heuristics"
+            "embedded tags matching unavailable or beatable, trying filename heuristics"
         );
 
         // Strategy 3: Filename heuristics (lowest confidence, final fallback)
@@ -194,18 +376,34 @@ impl PrecedenceMatchingEngine {
             .try_filename_heuristics_match(track_file, min_confidence, folder_artist, folder_album)
             .await
         {
-            return result;
+            candidates.push(result?);
+        }
+
+        if let Some(best) = self.weights.select(candidates) {
+            return Ok(best);
         }
 
         warn!(
             target: "precedence_matching",
             track_file_id = %track_file.id,
-            "all matching strategies exhausted without success"
+            min_score = self.weights.min_score,
+            "all matching strategies exhausted without an acceptable match"
         );
 
         Err(PrecedenceMatchingError::AllStrategiesFailed)
     }
 
+    /// Return the best candidate so far if no strategy after `strategy` could outscore it.
+    fn accept_if_unbeatable(
+        &self,
+        candidates: &[PrecedenceMatchResult],
+        strategy: MatchingStrategy,
+    ) -> Option<PrecedenceMatchResult> {
+        self.weights
+            .select(candidates.iter().cloned())
+            .filter(|best| best.weighted_score >= self.weights.max_remaining_score(strategy))
+    }
+
     /// Attempt fingerprint-based matching (primary strategy).
     async fn try_fingerprint_match(
         &self,
@@ -237,6 +435,15 @@ impl PrecedenceMatchingEngine {
                     musicbrainz_recording_id: match_result.musicbrainz_recording_id,
                     confidence: match_result.confidence_score,
                     strategy: MatchingStrategy::Fingerprint,
+                    weighted_score: self.weights.weighted_score(
+                        MatchingStrategy::Fingerprint,
+                        match_result.confidence_score,
+                    ),
+                    explanation: match_result.explanation.unwrap_or_else(|| {
+                        let mut explanation = MatchExplanation::new(MatchingStrategy::Fingerprint);
+                        explanation.add(MatchFactor::Fingerprint, match_result.confidence_score);
+                        explanation
+                    }),
                 }))
             }
             Err(e) => {
@@ -255,9 +462,9 @@ impl PrecedenceMatchingEngine {
     async fn try_embedded_tags_match(
         &self,
         track_file: &TrackFile,
-        _min_confidence: f32, // TODO: Will be used for MusicBrainz confidence filtering
+        min_confidence: f32,
         _folder_artist: Option<&str>, // TODO: Will be used for MusicBrainz search context
-        _folder_album: Option<&str>, // TODO: Will be used for MusicBrainz search context
+        _folder_album: Option<&str>,  // TODO: Will be used for MusicBrainz search context
     ) -> Option<PrecedenceMatchingResult<PrecedenceMatchResult>> {
         debug!(
             target: "precedence_matching",
@@ -289,6 +496,33 @@ impl PrecedenceMatchingEngine {
             }
         };
 
+        // A MusicBrainz recording ID written by a tagger identifies the recording directly
+        if let Some(mbid) = tags.musicbrainz_recording_id {
+            let confidence = TAGGED_RECORDING_ID_CONFIDENCE;
+            if confidence < min_confidence {
+                return None;
+            }
+            info!(
+                target: "precedence_matching",
+                track_file_id = %track_file.id,
+                strategy = "EmbeddedTags",
+                mbid = %mbid,
+                confidence,
+                "embedded recording ID match"
+            );
+            let mut explanation = MatchExplanation::new(MatchingStrategy::EmbeddedTags);
+            explanation.add(MatchFactor::RecordingIdTag, confidence);
+            return Some(Ok(PrecedenceMatchResult {
+                musicbrainz_recording_id: mbid,
+                confidence,
+                strategy: MatchingStrategy::EmbeddedTags,
+                weighted_score: self
+                    .weights
+                    .weighted_score(MatchingStrategy::EmbeddedTags, confidence),
+                explanation,
+            }));
+        }
+
         // Check if we have enough metadata to attempt matching
         if tags.artist.is_none() || tags.album.is_none() || tags.title.is_none() {
             debug!(
@@ -299,16 +533,21 @@ impl PrecedenceMatchingEngine {
             return None;
         }
 
-        // TODO: Implement MusicBrainz lookup with extracted tags
-        // For now, return None to allow fallback to filename heuristics
-        None
+        self.match_album_tracks(
+            track_file,
+            MatchingStrategy::EmbeddedTags,
+            tags.title.as_deref(),
+            tags.track_number,
+            min_confidence,
+        )
+        .await
     }
 
     /// Attempt filename heuristics-based matching (final fallback).
     async fn try_filename_heuristics_match(
         &self,
         track_file: &TrackFile,
-        _min_confidence: f32, // TODO: Will be used for MusicBrainz confidence filtering
+        min_confidence: f32,
         folder_artist: Option<&str>,
         folder_album: Option<&str>,
     ) -> Option<PrecedenceMatchingResult<PrecedenceMatchResult>> {
@@ -346,10 +585,114 @@ impl PrecedenceMatchingEngine {
             return None;
         }
 
-        // TODO: Implement MusicBrainz lookup with parsed filename data
-        // Lower confidence scores for filename-based matches
-        // For now, return None as placeholder
-        None
+        // An ambiguous parse cannot produce a match above the threshold.
+        if parsed.confidence < min_confidence {
+            debug!(
+                target: "precedence_matching",
+                track_file_id = %track_file.id,
+                confidence = parsed.confidence,
+                min_confidence,
+                "filename heuristics parse below confidence threshold"
+            );
+            return None;
+        }
+
+        self.match_album_tracks(
+            track_file,
+            MatchingStrategy::FilenameHeuristics,
+            parsed.title.as_deref(),
+            parsed.track_number,
+            min_confidence,
+        )
+        .await
+    }
+
+    /// Score the tracks on `track_file`'s album that carry a recording ID against
+    /// `title`, `track_number` and the file's duration, returning the best one
+    /// that reaches `min_confidence` with its explanation.
+    async fn match_album_tracks(
+        &self,
+        track_file: &TrackFile,
+        strategy: MatchingStrategy,
+        title: Option<&str>,
+        track_number: Option<u32>,
+        min_confidence: f32,
+    ) -> Option<PrecedenceMatchingResult<PrecedenceMatchResult>> {
+        let tracks = self.tracks.as_ref()?;
+        let album_tracks = match self.album_tracks(tracks, track_file).await {
+            Ok(album_tracks) => album_tracks,
+            Err(error) => {
+                warn!(
+                    target: "precedence_matching",
+                    track_file_id = %track_file.id,
+                    %strategy,
+                    error = %error,
+                    "failed to load candidate tracks"
+                );
+                return None;
+            }
+        };
+
+        let tolerance_ms = self.fingerprint_service.duration_tolerance_ms();
+        let (recording_id, explanation) = album_tracks
+            .iter()
+            .filter_map(|candidate| {
+                let recording_id = candidate.musicbrainz_recording_id.as_ref()?;
+                let explanation = explain_track_match(
+                    strategy,
+                    title,
+                    track_number,
+                    track_file.duration_ms,
+                    candidate,
+                    tolerance_ms,
+                );
+                Some((recording_id, explanation))
+            })
+            .max_by(|(_, left), (_, right)| left.total().total_cmp(&right.total()))?;
+
+        let confidence = explanation.total();
+        if confidence < min_confidence {
+            debug!(
+                target: "precedence_matching",
+                track_file_id = %track_file.id,
+                %strategy,
+                confidence,
+                min_confidence,
+                "best album track below confidence threshold"
+            );
+            return None;
+        }
+
+        info!(
+            target: "precedence_matching",
+            track_file_id = %track_file.id,
+            %strategy,
+            mbid = %recording_id,
+            confidence,
+            "album track match"
+        );
+        Some(Ok(PrecedenceMatchResult {
+            musicbrainz_recording_id: recording_id.clone(),
+            confidence,
+            strategy,
+            weighted_score: self.weights.weighted_score(strategy, confidence),
+            explanation,
+        }))
+    }
+
+    /// Tracks on the album of the track `track_file` currently belongs to.
+    async fn album_tracks(
+        &self,
+        tracks: &Arc<dyn TrackRepository>,
+        track_file: &TrackFile,
+    ) -> anyhow::Result<Vec<chorrosion_domain::Track>> {
+        let Some(current) = tracks.get_by_id(&track_file.track_id.to_string()).await? else {
+            return Ok(Vec::new());
+        };
+        collect_all(DEFAULT_PAGE_SIZE, |limit, offset| {
+            tracks.get_by_album(current.album_id, limit, offset)
+        })
+        .await
     }
 }
 
@@ -397,10 +740,121 @@ mod tests {
             musicbrainz_recording_id: "test-id".to_string(),
             confidence: 0.95,
             strategy: MatchingStrategy::Fingerprint,
+            weighted_score: 0.95,
+            explanation: MatchExplanation::new(MatchingStrategy::Fingerprint),
         };
 
         assert_eq!(result.musicbrainz_recording_id, "test-id");
         assert_eq!(result.confidence, 0.95);
         assert_eq!(result.strategy, MatchingStrategy::Fingerprint);
     }
+
+    #[tokio::test]
+    async fn tagged_recording_id_match_is_explained() {
+        use crate::embedded_tags::EmbeddedTagMatchingService;
+        use crate::filename_heuristics::FilenameHeuristicsService;
+        use crate::test_fixtures::minimal_opus;
+        use chorrosion_fingerprint::AcoustidClient;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("01 - Song.opus");
+        std::fs::write(
+            &path,
+            minimal_opus(&[(
+                "MUSICBRAINZ_TRACKID",
+                "f1e2d3c4-b5a6-4978-8695-a4b3c2d1e0f9",
+            )]),
+        )
+        .unwrap();
+        let track_file = TrackFile::new(Default::default(), path.display().to_string(), 128);
+        let engine = PrecedenceMatchingEngine::new(
+            TrackMatchingService::new(AcoustidClient::new("test_key".to_string()).unwrap()),
+            EmbeddedTagMatchingService,
+            FilenameHeuristicsService,
+        );
+
+        let result = engine
+            .match_with_precedence(&track_file, 0.5, None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(result.explanation.strategy, MatchingStrategy::EmbeddedTags);
+        assert_eq!(
+            result.explanation.factors(),
+            vec![MatchFactor::RecordingIdTag]
+        );
+        assert_eq!(result.explanation.total(), result.confidence);
+    }
+
+    #[tokio::test]
+    async fn filename_match_against_album_tracks_is_explained() {
+        use crate::embedded_tags::EmbeddedTagMatchingService;
+        use crate::filename_heuristics::FilenameHeuristicsService;
+        use chorrosion_config::AppConfig;
+        use chorrosion_domain::{Album, Artist, Track};
+        use chorrosion_fingerprint::AcoustidClient;
+        use chorrosion_infrastructure::init_database;
+        use chorrosion_infrastructure::repositories::Repository;
+        use chorrosion_infrastructure::sqlite_adapters::{
+            SqliteAlbumRepository, SqliteArtistRepository, SqliteTrackRepository,
+        };
+
+        let mut config = AppConfig::default();
+        config.database.url = "sqlite::memory:".to_string();
+        config.database.pool_max_size = 1;
+        let pool = init_database(&config).await.expect("in-memory database");
+        let artist = SqliteArtistRepository::new(pool.clone())
+            .create(Artist::new("Artist"))
+            .await
+            .unwrap();
+        let album = SqliteAlbumRepository::new(pool.clone())
+            .create(Album::new(artist.id, "Album"))
+            .await
+            .unwrap();
+        let tracks = Arc::new(SqliteTrackRepository::new(pool));
+        let mut album_tracks = Vec::new();
+        for (number, title, recording_id) in [
+            (1, "First Song", "0b1f4d2e-1111-4c1e-9a56-0f3a2f8b9d11"),
+            (2, "Second Song", "0b1f4d2e-2222-4c1e-9a56-0f3a2f8b9d11"),
+        ] {
+            let mut track = tracks
+                .create(Track::new(album.id, artist.id, title))
+                .await
+                .unwrap();
+            track.track_number = Some(number);
+            track.musicbrainz_recording_id = Some(recording_id.to_string());
+            album_tracks.push(tracks.update(track).await.unwrap());
+        }
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("02 - Second Song.mp3");
+        std::fs::write(&path, b"not audio").unwrap();
+        let track_file = TrackFile::new(album_tracks[0].id, path.display().to_string(), 9);
+        let engine = PrecedenceMatchingEngine::new(
+            TrackMatchingService::new(AcoustidClient::new("test_key".to_string()).unwrap()),
+            EmbeddedTagMatchingService,
+            FilenameHeuristicsService,
+        )
+        .with_track_repository(tracks);
+
+        let result = engine
+            .match_with_precedence(&track_file, 0.5, Some("Artist"), Some("Album"))
+            .await
+            .unwrap();
+
+        assert_eq!(result.strategy, MatchingStrategy::FilenameHeuristics);
+        assert_eq!(
+            Some(result.musicbrainz_recording_id.as_str()),
+            album_tracks[1].musicbrainz_recording_id.as_deref()
+        );
+        assert_eq!(
+            result.explanation.strategy,
+            MatchingStrategy::FilenameHeuristics
+        );
+        assert_eq!(
+            result.explanation.factors(),
+            vec![MatchFactor::TitleExact, MatchFactor::TrackNumber]
+        );
+        assert_eq!(result.explanation.total(), result.confidence);
+    }
 }
@@ -3,10 +3,21 @@
 //! Unit tests for matching precedence types, errors, and display formatting.
 
 mod integration_tests {
+    use crate::matching::MatchExplanation;
     use crate::matching_precedence::{
-        MatchingStrategy, PrecedenceMatchResult, PrecedenceMatchingError,
+        MatchingStrategy, PrecedenceMatchResult, PrecedenceMatchingError, PrecedenceWeights,
     };
 
+    fn candidate(strategy: MatchingStrategy, id: &str, confidence: f32) -> PrecedenceMatchResult {
+        PrecedenceMatchResult {
+            musicbrainz_recording_id: id.to_string(),
+            confidence,
+            strategy,
+            weighted_score: confidence,
+            explanation: MatchExplanation::new(strategy),
+        }
+    }
+
     #[test]
     fn matching_strategy_precedence_ordering() {
         let strategies = [
@@ -55,6 +66,8 @@ mod integration_tests {
             musicbrainz_recording_id: "test-recording-123".to_string(),
             confidence: 0.87,
             strategy: MatchingStrategy::EmbeddedTags,
+            weighted_score: 0.87,
+            explanation: MatchExplanation::new(MatchingStrategy::EmbeddedTags),
         };
 
         assert_eq!(result.musicbrainz_recording_id, "test-recording-123");
@@ -71,6 +84,8 @@ mod integration_tests {
                 musicbrainz_recording_id: "id".to_string(),
                 confidence: conf,
                 strategy: MatchingStrategy::Fingerprint,
+                weighted_score: conf,
+                explanation: MatchExplanation::new(MatchingStrategy::Fingerprint),
             };
             assert!((0.0..=1.0).contains(&result.confidence));
         }
@@ -117,18 +132,24 @@ mod integration_tests {
             musicbrainz_recording_id: "fp-123".to_string(),
             confidence: 0.95,
             strategy: MatchingStrategy::Fingerprint,
+            weighted_score: 0.95,
+            explanation: MatchExplanation::new(MatchingStrategy::Fingerprint),
         };
 
         let tags_result = PrecedenceMatchResult {
             musicbrainz_recording_id: "tag-456".to_string(),
             confidence: 0.72,
             strategy: MatchingStrategy::EmbeddedTags,
+            weighted_score: 0.72,
+            explanation: MatchExplanation::new(MatchingStrategy::EmbeddedTags),
         };
 
         let filename_result = PrecedenceMatchResult {
             musicbrainz_recording_id: "fn-789".to_string(),
             confidence: 0.45,
             strategy: MatchingStrategy::FilenameHeuristics,
+            weighted_score: 0.45,
+            explanation: MatchExplanation::new(MatchingStrategy::FilenameHeuristics),
         };
 
         // Each result clearly identifies its source
@@ -156,6 +177,8 @@ mod integration_tests {
             musicbrainz_recording_id: "mb-id-123".to_string(),
             confidence: 0.92,
             strategy: MatchingStrategy::EmbeddedTags,
+            weighted_score: 0.92,
+            explanation: MatchExplanation::new(MatchingStrategy::EmbeddedTags),
         };
 
         // Verify all fields are accessible
@@ -225,6 +248,8 @@ mod integration_tests {
             musicbrainz_recording_id: "clone-test".to_string(),
             confidence: 0.88,
             strategy: MatchingStrategy::Fingerprint,
+            weighted_score: 0.88,
+            explanation: MatchExplanation::new(MatchingStrategy::Fingerprint),
         };
 
         let cloned = original.clone();
@@ -246,4 +271,90 @@ mod integration_tests {
 
         assert_eq!(msg1, msg2);
     }
+
+    #[test]
+    fn default_weights_prefer_fingerprint_over_tags() {
+        let weights = PrecedenceWeights::default();
+        assert!(
+            weights.fingerprint > weights.embedded_tags
+                && weights.embedded_tags > weights.filename_heuristics,
+            "default weights must follow the documented precedence order: {weights:?}"
+        );
+
+        let candidates = vec![
+            candidate(MatchingStrategy::Fingerprint, "fp", 0.8),
+            candidate(MatchingStrategy::EmbeddedTags, "tags", 0.9),
+        ];
+
+        let best = weights
+            .select(candidates)
+            .expect("a candidate should be selected");
+
+        assert_eq!(best.strategy, MatchingStrategy::Fingerprint);
+        assert_eq!(best.weighted_score, 0.8);
+    }
+
+    #[test]
+    fn reweighting_flips_winning_candidate() {
+        let candidates = vec![
+            candidate(MatchingStrategy::Fingerprint, "fp", 0.8),
+            candidate(MatchingStrategy::EmbeddedTags, "tags", 0.9),
+        ];
+
+        let weights = PrecedenceWeights {
+            fingerprint: 0.5,
+            embedded_tags: 1.0,
+            ..PrecedenceWeights::default()
+        };
+        let best = weights
+            .select(candidates)
+            .expect("a candidate should be selected");
+
+        assert_eq!(best.strategy, MatchingStrategy::EmbeddedTags);
+        assert_eq!(best.musicbrainz_recording_id, "tags");
+        assert!((best.weighted_score - 0.9).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn equal_weighted_scores_fall_back_to_precedence_order() {
+        let weights = PrecedenceWeights {
+            fingerprint: 1.0,
+            embedded_tags: 1.0,
+            filename_heuristics: 1.0,
+            min_score: 0.0,
+        };
+        let best = weights
+            .select(vec![
+                candidate(MatchingStrategy::FilenameHeuristics, "fn", 0.7),
+                candidate(MatchingStrategy::EmbeddedTags, "tags", 0.7),
+            ])
+            .expect("a candidate should be selected");
+
+        assert_eq!(best.strategy, MatchingStrategy::EmbeddedTags);
+    }
+
+    #[test]
+    fn sub_threshold_weighted_score_yields_no_match() {
+        let weights = PrecedenceWeights {
+            min_score: 0.6,
+            ..PrecedenceWeights::default()
+        };
+
+        // 0.9 * 0.5 = 0.45 and 0.7 * 0.75 = 0.525, both below 0.6.
+        let best = weights.select(vec![
+            candidate(MatchingStrategy::FilenameHeuristics, "fn", 0.9),
+            candidate(MatchingStrategy::EmbeddedTags, "tags", 0.7),
+        ]);
+
+        assert!(best.is_none());
+    }
+
+    #[test]
+    fn precedence_error_invalid_weight() {
+        let err = PrecedenceMatchingError::InvalidWeight(MatchingStrategy::EmbeddedTags, -1.0);
+        assert_eq!(
+            err.to_string(),
+            "Invalid weight for Embedded Tags strategy: -1"
+        );
+    }
 }
@@ -0,0 +1,240 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+//! Process metrics shared by the scheduler and the API's `/metrics` endpoint.
+
+use prometheus::{
+    proto::MetricFamily, HistogramOpts, HistogramVec, IntCounterVec, IntGauge, IntGaugeVec, Opts,
+    Registry,
+};
+use std::sync::Arc;
+
+/// Snapshot of database pool usage, sampled whenever metrics are gathered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoolUsage {
+    /// Open connections, idle or in use.
+    pub size: u32,
+    /// Open connections not currently checked out.
+    pub idle: usize,
+}
+
+type PoolSampler = Arc<dyn Fn() -> PoolUsage + Send + Sync>;
+
+/// Prometheus registry for HTTP, job, scheduler, and database pool metrics.
+#[derive(Clone)]
+pub struct AppMetrics {
+    registry: Registry,
+    http_requests_total: IntCounterVec,
+    http_request_duration_seconds: HistogramVec,
+    job_executions_total: IntCounterVec,
+    active_jobs: IntGauge,
+    db_pool_connections: IntGaugeVec,
+    pool_sampler: Option<PoolSampler>,
+}
+
+impl AppMetrics {
+    pub fn new() -> Self {
+        let registry = Registry::new();
+        let http_requests_total = IntCounterVec::new(
+            Opts::new(
+                "chorrosion_http_requests_total",
+                "Total number of HTTP requests handled by Chorrosion",
+            ),
+            &["method", "path", "status"],
+        )
+        .expect("request counter should be created");
+        let http_request_duration_seconds = HistogramVec::new(
+            HistogramOpts::new(
+                "chorrosion_http_request_duration_seconds",
+                "HTTP request duration in seconds for Chorrosion endpoints",
+            ),
+            &["method", "path", "status"],
+        )
+        .expect("request duration histogram should be created");
+        let job_executions_total = IntCounterVec::new(
+            Opts::new(
+                "chorrosion_job_executions_total",
+                "Total number of scheduled job execution attempts",
+            ),
+            &["job_type", "result"],
+        )
+        .expect("job execution counter should be created");
+        let active_jobs = IntGauge::new(
+            "chorrosion_scheduler_active_jobs",
+            "Number of scheduler jobs currently executing",
+        )
+        .expect("active jobs gauge should be created");
+        let db_pool_connections = IntGaugeVec::new(
+            Opts::new(
+                "chorrosion_db_pool_connections",
+                "Database pool connections by state",
+            ),
+            &["state"],
+        )
+        .expect("db pool gauge should be created");
+
+        registry
+            .register(Box::new(http_requests_total.clone()))
+            .expect("request counter should be registered");
+        registry
+            .register(Box::new(http_request_duration_seconds.clone()))
+            .expect("request duration histogram should be registered");
+        registry
+            .register(Box::new(job_executions_total.clone()))
+            .expect("job execution counter should be registered");
+        registry
+            .register(Box::new(active_jobs.clone()))
+            .expect("active jobs gauge should be registered");
+        registry
+            .register(Box::new(db_pool_connections.clone()))
+            .expect("db pool gauge should be registered");
+
+        Self {
+            registry,
+            http_requests_total,
+            http_request_duration_seconds,
+            job_executions_total,
+            active_jobs,
+            db_pool_connections,
+            pool_sampler: None,
+        }
+    }
+
+    /// Sample database pool usage from `sampler` each time metrics are gathered.
+    pub fn with_pool_sampler(
+        mut self,
+        sampler: impl Fn() -> PoolUsage + Send + Sync + 'static,
+    ) -> Self {
+        self.pool_sampler = Some(Arc::new(sampler));
+        self
+    }
+
+    /// Count one HTTP request to the route template `path` and record its duration.
+    pub fn record_http_request(
+        &self,
+        method: &str,
+        path: &str,
+        status: u16,
+        duration_seconds: f64,
+    ) {
+        let status = status.to_string();
+        let labels = [method, path, status.as_str()];
+        self.http_requests_total.with_label_values(&labels).inc();
+        self.http_request_duration_seconds
+            .with_label_values(&labels)
+            .observe(duration_seconds);
+    }
+
+    /// Count one execution attempt of `job_type` ending in `result`
+    /// (`success`, `failure`, or `error`).
+    pub fn record_job_execution(&self, job_type: &str, result: &str) {
+        self.job_executions_total
+            .with_label_values(&[job_type, result])
+            .inc();
+    }
+
+    pub fn job_executions(&self, job_type: &str, result: &str) -> u64 {
+        self.job_executions_total
+            .with_label_values(&[job_type, result])
+            .get()
+    }
+
+    /// Mark a job as running until the returned guard is dropped.
+    pub fn job_started(&self) -> ActiveJobGuard {
+        self.active_jobs.inc();
+        ActiveJobGuard {
+            gauge: self.active_jobs.clone(),
+        }
+    }
+
+    pub fn active_jobs(&self) -> i64 {
+        self.active_jobs.get()
+    }
+
+    /// Collect every metric family, refreshing sampled gauges first.
+    pub fn gather(&self) -> Vec<MetricFamily> {
+        if let Some(sampler) = &self.pool_sampler {
+            let usage = sampler();
+            let idle = i64::try_from(usage.idle).unwrap_or(i64::MAX);
+            self.db_pool_connections
+                .with_label_values(&["idle"])
+                .set(idle);
+            self.db_pool_connections
+                .with_label_values(&["active"])
+                .set(i64::from(usage.size).saturating_sub(idle).max(0));
+        }
+        self.registry.gather()
+    }
+}
+
+impl Default for AppMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for AppMetrics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AppMetrics")
+            .field("active_jobs", &self.active_jobs.get())
+            .field("pool_sampler", &self.pool_sampler.is_some())
+            .finish()
+    }
+}
+
+/// Decrements the active-jobs gauge when dropped.
+pub struct ActiveJobGuard {
+    gauge: IntGauge,
+}
+
+impl Drop for ActiveJobGuard {
+    fn drop(&mut self) {
+        self.gauge.dec();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn family<'a>(families: &'a [MetricFamily], name: &str) -> &'a MetricFamily {
+        families
+            .iter()
+            .find(|family| family.get_name() == name)
+            .unwrap_or_else(|| panic!("missing metric family {name}"))
+    }
+
+    #[test]
+    fn active_job_guard_tracks_running_jobs() {
+        let metrics = AppMetrics::new();
+        let guard = metrics.job_started();
+        assert_eq!(metrics.active_jobs(), 1);
+        drop(guard);
+        assert_eq!(metrics.active_jobs(), 0);
+    }
+
+    #[test]
+    fn job_executions_are_counted_by_type_and_result() {
+        let metrics = AppMetrics::new();
+        metrics.record_job_execution("rss_sync", "success");
+        metrics.record_job_execution("rss_sync", "success");
+        metrics.record_job_execution("rss_sync", "failure");
+
+        assert_eq!(metrics.job_executions("rss_sync", "success"), 2);
+        assert_eq!(metrics.job_executions("rss_sync", "failure"), 1);
+    }
+
+    #[test]
+    fn gather_samples_pool_usage() {
+        let metrics = AppMetrics::new().with_pool_sampler(|| PoolUsage { size: 5, idle: 2 });
+
+        let families = metrics.gather();
+        let pool = family(&families, "chorrosion_db_pool_connections");
+        let value_for = |state: &str| {
+            pool.get_metric()
+                .iter()
+                .find(|metric| metric.get_label()[0].get_value() == state)
+                .map(|metric| metric.get_gauge().get_value())
+        };
+        assert_eq!(value_for("idle"), Some(2.0));
+        assert_eq!(value_for("active"), Some(3.0));
+    }
+}
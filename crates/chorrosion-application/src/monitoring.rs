@@ -0,0 +1,347 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+//! Which of an artist's albums are monitored.
+//!
+//! [`apply_monitoring`] sets the `monitored` flag on every album of an artist
+//! according to a [`MonitorOption`], usually right after the artist is added.
+//! [`set_artist_monitored`] monitors or unmonitors an artist, optionally
+//! together with all of its albums and tracks.
+
+use anyhow::Result;
+use chorrosion_domain::{Album, ArtistId};
+use chorrosion_infrastructure::collect_all;
+use chorrosion_infrastructure::repositories::{
+    AlbumRepository, ArtistRepository, MonitoredCascade,
+};
+use chrono::{NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+const PAGE_SIZE: i64 = 500;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum MonitorOption {
+    /// Every album.
+    #[default]
+    All,
+    /// Albums releasing after today.
+    Future,
+    /// The most recently released album, ignoring announced future releases.
+    Latest,
+    /// No album.
+    None,
+}
+
+/// How many of the artist's albums ended up monitored and unmonitored.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MonitoringSummary {
+    pub monitored: usize,
+    pub unmonitored: usize,
+}
+
+/// Set `monitored` on every album of `artist_id` according to `option`.
+///
+/// Release dates known only to the month or year count from their first day,
+/// and albums without a release date are never "future" or "latest". Only
+/// albums whose flag changes are written back.
+pub async fn apply_monitoring<R>(
+    album_repo: &R,
+    artist_id: ArtistId,
+    option: MonitorOption,
+) -> Result<MonitoringSummary>
+where
+    R: AlbumRepository + ?Sized,
+{
+    apply_monitoring_as_of(album_repo, artist_id, option, Utc::now().date_naive()).await
+}
+
+async fn apply_monitoring_as_of<R>(
+    album_repo: &R,
+    artist_id: ArtistId,
+    option: MonitorOption,
+    today: NaiveDate,
+) -> Result<MonitoringSummary>
+where
+    R: AlbumRepository + ?Sized,
+{
+    let albums = collect_all(PAGE_SIZE, |limit, offset| {
+        album_repo.get_by_artist(artist_id, limit, offset)
+    })
+    .await?;
+
+    let latest = albums
+        .iter()
+        .filter_map(|album| release_day(album).map(|day| (day, album)))
+        .filter(|(day, _)| *day <= today)
+        .max_by(|(a_day, a), (b_day, b)| a_day.cmp(b_day).then_with(|| b.title.cmp(&a.title)))
+        .map(|(_, album)| album.id);
+
+    let mut summary = MonitoringSummary::default();
+    for mut album in albums {
+        let monitored = match option {
+            MonitorOption::All => true,
+            MonitorOption::Future => release_day(&album).is_some_and(|day| day > today),
+            MonitorOption::Latest => latest == Some(album.id),
+            MonitorOption::None => false,
+        };
+        if monitored {
+            summary.monitored += 1;
+        } else {
+            summary.unmonitored += 1;
+        }
+        if album.monitored != monitored {
+            debug!(target: "application", %artist_id, album_id = %album.id, monitored, ?option, "updating album monitoring");
+            album.monitored = monitored;
+            album_repo.update(album).await?;
+        }
+    }
+
+    Ok(summary)
+}
+
+fn release_day(album: &Album) -> Option<NaiveDate> {
+    album
+        .release_date
+        .and_then(|release_date| release_date.to_naive_date_opt())
+}
+
+/// Set `monitored` on `artist_id`, and with `cascade` on all of its albums and
+/// tracks in the same transaction.
+///
+/// Wanted queries skip everything under an unmonitored artist either way;
+/// without `cascade` the albums and tracks keep their own flags and become
+/// wanted again once the artist is monitored. Returns `None` for an unknown artist.
+pub async fn set_artist_monitored<R>(
+    artist_repo: &R,
+    artist_id: ArtistId,
+    monitored: bool,
+    cascade: bool,
+) -> Result<Option<MonitoredCascade>>
+where
+    R: ArtistRepository + ?Sized,
+{
+    let updated = if cascade {
+        artist_repo
+            .set_monitored_cascade(&[artist_id], monitored)
+            .await?
+    } else {
+        MonitoredCascade {
+            artists: artist_repo.set_monitored(&[artist_id], monitored).await?,
+            ..MonitoredCascade::default()
+        }
+    };
+    debug!(target: "application", %artist_id, monitored, cascade, albums = updated.albums, tracks = updated.tracks, "set artist monitored");
+    Ok((updated.artists > 0).then_some(updated))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply_monitoring_as_of, set_artist_monitored, MonitorOption, MonitoringSummary};
+    use chorrosion_config::AppConfig;
+    use chorrosion_domain::{Album, Artist, ArtistId, ReleaseDate, Track};
+    use chorrosion_infrastructure::init_database;
+    use chorrosion_infrastructure::repositories::{
+        AlbumRepository, MonitoredCascade, Repository, TrackRepository,
+    };
+    use chorrosion_infrastructure::sqlite_adapters::{
+        SqliteAlbumRepository, SqliteArtistRepository, SqliteTrackRepository,
+    };
+    use chrono::NaiveDate;
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 6, 1).unwrap()
+    }
+
+    /// An artist with two past albums, one announced album, and one undated album.
+    async fn fixture() -> (SqliteAlbumRepository, ArtistId) {
+        let mut config = AppConfig::default();
+        config.database.url = "sqlite::memory:".to_string();
+        config.database.pool_max_size = 1;
+        let pool = init_database(&config).await.expect("in-memory database");
+        let artist = SqliteArtistRepository::new(pool.clone())
+            .create(Artist::new("Massive Attack"))
+            .await
+            .unwrap();
+        let albums = SqliteAlbumRepository::new(pool);
+        for (title, release_date) in [
+            ("Blue Lines", Some(ReleaseDate::new(1991, Some(4), Some(8)))),
+            ("Heligoland", Some(ReleaseDate::new(2010, Some(2), None))),
+            ("Next", Some(ReleaseDate::new(2027, None, None))),
+            ("Untitled", None),
+        ] {
+            let mut album = Album::new(artist.id, title);
+            album.release_date = release_date;
+            albums.create(album).await.unwrap();
+        }
+        (albums, artist.id)
+    }
+
+    async fn monitored_titles(albums: &SqliteAlbumRepository, artist_id: ArtistId) -> Vec<String> {
+        let mut titles: Vec<String> = albums
+            .get_by_artist(artist_id, 100, 0)
+            .await
+            .unwrap()
+            .into_iter()
+            .filter(|album| album.monitored)
+            .map(|album| album.title)
+            .collect();
+        titles.sort();
+        titles
+    }
+
+    async fn apply(option: MonitorOption) -> (MonitoringSummary, Vec<String>) {
+        let (albums, artist_id) = fixture().await;
+        let summary = apply_monitoring_as_of(&albums, artist_id, option, today())
+            .await
+            .unwrap();
+        (summary, monitored_titles(&albums, artist_id).await)
+    }
+
+    #[tokio::test]
+    async fn all_monitors_every_album() {
+        let (summary, titles) = apply(MonitorOption::All).await;
+
+        assert_eq!(titles, ["Blue Lines", "Heligoland", "Next", "Untitled"]);
+        assert_eq!(
+            summary,
+            MonitoringSummary {
+                monitored: 4,
+                unmonitored: 0
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn future_monitors_only_unreleased_albums() {
+        let (summary, titles) = apply(MonitorOption::Future).await;
+
+        assert_eq!(titles, ["Next"]);
+        assert_eq!(summary.unmonitored, 3);
+    }
+
+    #[tokio::test]
+    async fn latest_monitors_the_most_recent_release() {
+        let (_, titles) = apply(MonitorOption::Latest).await;
+
+        assert_eq!(titles, ["Heligoland"]);
+    }
+
+    #[tokio::test]
+    async fn none_unmonitors_every_album() {
+        let (summary, titles) = apply(MonitorOption::None).await;
+
+        assert!(titles.is_empty());
+        assert_eq!(summary.unmonitored, 4);
+    }
+
+    struct Library {
+        artists: SqliteArtistRepository,
+        albums: SqliteAlbumRepository,
+        tracks: SqliteTrackRepository,
+        artist_id: ArtistId,
+    }
+
+    /// An artist with two albums of two missing tracks each, all monitored.
+    async fn wanted_library() -> Library {
+        let mut config = AppConfig::default();
+        config.database.url = "sqlite::memory:".to_string();
+        config.database.pool_max_size = 1;
+        let pool = init_database(&config).await.expect("in-memory database");
+        let artists = SqliteArtistRepository::new(pool.clone());
+        let albums = SqliteAlbumRepository::new(pool.clone());
+        let tracks = SqliteTrackRepository::new(pool);
+        let artist = artists.create(Artist::new("Broadcast")).await.unwrap();
+        for title in ["The Noise Made by People", "Tender Buttons"] {
+            let album = albums.create(Album::new(artist.id, title)).await.unwrap();
+            for number in 1..=2 {
+                tracks
+                    .create(Track::new(album.id, artist.id, format!("Track {number}")))
+                    .await
+                    .unwrap();
+            }
+        }
+        Library {
+            artists,
+            albums,
+            tracks,
+            artist_id: artist.id,
+        }
+    }
+
+    async fn monitored_album_count(library: &Library) -> usize {
+        library
+            .albums
+            .get_by_artist(library.artist_id, 100, 0)
+            .await
+            .unwrap()
+            .iter()
+            .filter(|album| album.monitored)
+            .count()
+    }
+
+    #[tokio::test]
+    async fn cascade_unmonitor_hides_every_descendant_from_wanted() {
+        let library = wanted_library().await;
+        assert_eq!(library.tracks.list_wanted(100, 0).await.unwrap().len(), 4);
+
+        let updated = set_artist_monitored(&library.artists, library.artist_id, false, true)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            updated,
+            Some(MonitoredCascade {
+                artists: 1,
+                albums: 2,
+                tracks: 4
+            })
+        );
+        assert_eq!(monitored_album_count(&library).await, 0);
+        assert!(library.tracks.list_wanted(100, 0).await.unwrap().is_empty());
+
+        // Re-monitoring only the artist leaves the cascaded albums unmonitored.
+        set_artist_monitored(&library.artists, library.artist_id, true, false)
+            .await
+            .unwrap();
+        assert!(library.tracks.list_wanted(100, 0).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn unmonitor_without_cascade_keeps_albums_monitored() {
+        let library = wanted_library().await;
+
+        let updated = set_artist_monitored(&library.artists, library.artist_id, false, false)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            updated,
+            Some(MonitoredCascade {
+                artists: 1,
+                ..MonitoredCascade::default()
+            })
+        );
+        assert_eq!(monitored_album_count(&library).await, 2);
+        assert!(
+            library.tracks.list_wanted(100, 0).await.unwrap().is_empty(),
+            "an unmonitored artist's tracks are not wanted"
+        );
+
+        set_artist_monitored(&library.artists, library.artist_id, true, false)
+            .await
+            .unwrap();
+        assert_eq!(library.tracks.list_wanted(100, 0).await.unwrap().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn unknown_artist_is_not_updated() {
+        let library = wanted_library().await;
+
+        let updated = set_artist_monitored(&library.artists, ArtistId::new(), false, true)
+            .await
+            .unwrap();
+
+        assert_eq!(updated, None);
+        assert_eq!(library.tracks.list_wanted(100, 0).await.unwrap().len(), 4);
+    }
+}
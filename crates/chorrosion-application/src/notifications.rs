@@ -1,4 +1,5 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
+use crate::http_client::OutboundHttp;
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
 use chorrosion_config::AppConfig;
@@ -156,7 +157,7 @@ impl DiscordWebhookProvider {
             .map(str::trim)
             .filter(|s| !s.is_empty())
             .map(str::to_string);
-        let client = crate::http_client::build_http_client();
+        let client = OutboundHttp::from_config(&config.http).default_client();
         Self {
             enabled: discord.enabled && webhook_url.is_some(),
             webhook_url,
@@ -197,7 +198,7 @@ impl SlackWebhookProvider {
             .map(str::trim)
             .filter(|s| !s.is_empty())
             .map(str::to_string);
-        let client = crate::http_client::build_http_client();
+        let client = OutboundHttp::from_config(&config.http).default_client();
         Self {
             enabled: slack.enabled && webhook_url.is_some(),
             webhook_url,
@@ -247,7 +248,7 @@ impl PushoverProvider {
             );
         }
 
-        let client = crate::http_client::build_http_client();
+        let client = OutboundHttp::from_config(&config.http).default_client();
 
         Self {
             enabled: pushover.enabled
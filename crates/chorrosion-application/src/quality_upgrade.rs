@@ -17,6 +17,11 @@
 //!
 //! would rank them 0 → 1 → 2 → 3.
 //!
+//! [`QualityRank`] turns that position into a comparable value, placing
+//! qualities the profile does not allow below every allowed one. Import upgrade
+//! decisions and [`rank_releases_for_profile`](crate::release_parsing::rank_releases_for_profile)
+//! both order qualities through it.
+//!
 //! # Upgrade policy
 //!
 //! | Existing quality | Upgrade allowed? | Decision |
@@ -26,12 +31,46 @@
 //! | At/above `cutoff_quality` and `upgrade_allowed = false` | no | `Keep` |
 //! | Candidate is not better | — | `Keep` |
 
+use crate::import_matching::{find_allowed_quality, resolve_lossy_family_quality};
+use crate::release_parsing::AudioQuality;
 use chorrosion_domain::QualityProfile;
 
 // ============================================================================
 // Quality comparison helpers
 // ============================================================================
 
+/// Where a quality sits in a profile's `allowed_qualities`, ordered worst to best.
+///
+/// Every [`QualityRank::NotAllowed`] compares below every [`QualityRank::Allowed`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum QualityRank {
+    /// The quality is not in the profile's allowed list.
+    NotAllowed,
+    /// Index into `allowed_qualities`; higher is better.
+    Allowed(usize),
+}
+
+impl QualityRank {
+    /// Rank a quality label such as `"MP3 320"`, matched exactly.
+    pub fn of_label(quality: &str, profile: &QualityProfile) -> Self {
+        QualityComparer::rank(quality, profile).map_or(Self::NotAllowed, Self::Allowed)
+    }
+
+    /// Rank a quality parsed from a release or file name, via the profile label it maps to.
+    pub fn of_parsed(
+        quality: &AudioQuality,
+        bitrate_kbps: Option<u32>,
+        profile: &QualityProfile,
+    ) -> Self {
+        resolve_parsed_quality(quality, bitrate_kbps, profile)
+            .map_or(Self::NotAllowed, |label| Self::of_label(&label, profile))
+    }
+
+    pub fn is_allowed(self) -> bool {
+        matches!(self, Self::Allowed(_))
+    }
+}
+
 /// Quality ranking and comparison utilities based on a [`QualityProfile`].
 pub struct QualityComparer;
 
@@ -48,13 +87,8 @@ impl QualityComparer {
     /// Returns `true` if `candidate` is strictly ranked higher than `existing`
     /// within the profile.  Returns `false` if either quality is unknown.
     pub fn is_upgrade(existing: &str, candidate: &str, profile: &QualityProfile) -> bool {
-        match (
-            Self::rank(existing, profile),
-            Self::rank(candidate, profile),
-        ) {
-            (Some(e), Some(c)) => c > e,
-            _ => false,
-        }
+        let existing = QualityRank::of_label(existing, profile);
+        existing.is_allowed() && QualityRank::of_label(candidate, profile) > existing
     }
 
     /// Returns `true` if `quality` meets or exceeds the profile's
@@ -72,6 +106,38 @@ impl QualityComparer {
     }
 }
 
+/// Map a parsed quality onto a label from `profile.allowed_qualities`.
+pub(crate) fn resolve_parsed_quality(
+    quality: &AudioQuality,
+    bitrate_kbps: Option<u32>,
+    profile: &QualityProfile,
+) -> Option<String> {
+    let find_first = |labels: &[&str]| {
+        labels
+            .iter()
+            .find_map(|label| find_allowed_quality(label, profile))
+    };
+
+    match quality {
+        AudioQuality::Flac => find_first(&["FLAC"]),
+        AudioQuality::FlacHiRes => find_first(&["FLAC 24bit", "FLAC Hi-Res", "FLAC"]),
+        AudioQuality::Dsd => find_first(&["DSD"]),
+        AudioQuality::Sacd => find_first(&["SACD", "DSD"]),
+        AudioQuality::Alac => find_first(&["ALAC"]),
+        AudioQuality::Mp3 | AudioQuality::Aac => {
+            let family = if *quality == AudioQuality::Mp3 {
+                "MP3"
+            } else {
+                "AAC"
+            };
+            bitrate_kbps
+                .and_then(|bitrate| resolve_lossy_family_quality(family, bitrate, profile))
+                .or_else(|| find_allowed_quality(family, profile))
+        }
+        AudioQuality::Unknown => None,
+    }
+}
+
 // ============================================================================
 // Upgrade decision
 // ============================================================================
@@ -158,6 +224,9 @@ impl QualityUpgradeService {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::release_parsing::{
+        parse_release_title, rank_releases_for_profile, ReleaseFilterOptions,
+    };
     use chorrosion_domain::{ProfileId, QualityProfile};
     use chrono::Utc;
 
@@ -172,6 +241,7 @@ mod tests {
             allowed_qualities: allowed.iter().map(|s| s.to_string()).collect(),
             upgrade_allowed,
             cutoff_quality: cutoff.map(str::to_string),
+            is_default: false,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         }
@@ -193,6 +263,69 @@ mod tests {
         assert_eq!(QualityComparer::rank("OGG 192", &profile), None);
     }
 
+    // ---- QualityRank ----
+
+    #[test]
+    fn quality_outside_profile_ranks_below_every_allowed_quality() {
+        let profile = make_profile(&["MP3 128", "MP3 320"], None, true);
+        assert!(
+            QualityRank::of_label("OGG 192", &profile) < QualityRank::of_label("MP3 128", &profile)
+        );
+        assert_eq!(
+            QualityRank::of_parsed(&AudioQuality::Flac, None, &profile),
+            QualityRank::NotAllowed
+        );
+        assert_eq!(
+            QualityRank::of_parsed(&AudioQuality::Mp3, Some(320), &profile),
+            QualityRank::Allowed(1)
+        );
+    }
+
+    #[test]
+    fn grab_ranking_and_import_upgrades_agree_on_order() {
+        let profile = make_profile(&["MP3 128", "MP3 320", "FLAC", "FLAC 24bit"], None, true);
+        let releases = [
+            "Artist - Album [MP3 128kbps]",
+            "Artist - Album [ALAC]",
+            "Artist - Album [FLAC 24bit 96kHz]",
+            "Artist - Album [MP3 320kbps]",
+            "Artist - Album [FLAC]",
+        ]
+        .into_iter()
+        .map(parse_release_title)
+        .collect();
+
+        let ranked =
+            rank_releases_for_profile(releases, &ReleaseFilterOptions::default(), &profile);
+        let labels: Vec<Option<String>> = ranked
+            .iter()
+            .map(|release| resolve_parsed_quality(&release.quality, release.bitrate_kbps, &profile))
+            .collect();
+
+        assert_eq!(
+            labels,
+            [
+                Some("FLAC 24bit".to_string()),
+                Some("FLAC".to_string()),
+                Some("MP3 320".to_string()),
+                Some("MP3 128".to_string()),
+                None,
+            ],
+            "ALAC is not allowed, so it ranks last despite being lossless"
+        );
+        for pair in labels[..4].windows(2) {
+            let (better, worse) = (pair[0].as_deref().unwrap(), pair[1].as_deref().unwrap());
+            assert!(matches!(
+                QualityUpgradeService::evaluate_upgrade(worse, better, &profile),
+                UpgradeDecision::Upgrade { .. }
+            ));
+            assert_eq!(
+                QualityUpgradeService::evaluate_upgrade(better, worse, &profile),
+                UpgradeDecision::Keep
+            );
+        }
+    }
+
     // ---- QualityComparer::is_upgrade ----
 
     #[test]
@@ -0,0 +1,317 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+//! Which of an artist's MusicBrainz release groups become library albums.
+//!
+//! [`sync_release_groups`] adds release groups that are not in the library yet,
+//! monitoring only those the artist's [`MetadataProfile`] allows. Release groups
+//! the profile rejects are added unmonitored, or left out entirely when the
+//! caller asks to skip them.
+
+use anyhow::Result;
+use chorrosion_domain::{Album, Artist, MetadataProfile, ReleaseDate};
+use chorrosion_infrastructure::collect_all;
+use chorrosion_infrastructure::repositories::AlbumRepository;
+use chorrosion_musicbrainz::models::Album as ReleaseGroup;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use tracing::debug;
+use uuid::Uuid;
+
+const PAGE_SIZE: i64 = 500;
+
+/// How many release groups [`sync_release_groups`] added, and how.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReleaseGroupSyncSummary {
+    /// Albums added and monitored.
+    pub monitored: usize,
+    /// Albums added unmonitored because the profile rejects them.
+    pub unmonitored: usize,
+    /// Release groups the profile rejects that were not added.
+    pub skipped: usize,
+    /// Release groups already in the library, left untouched.
+    pub existing: usize,
+}
+
+/// Add `release_groups` missing from `artist`'s albums, filtered through `profile`.
+///
+/// Albums already linked to a release group are never modified. Without a
+/// profile every release group is monitored. MusicBrainz reports statuses per
+/// release, so `release_statuses` maps a release group to the statuses of its
+/// releases; the group passes when any of them is allowed, and a group with no
+/// known status is not checked.
+pub async fn sync_release_groups<R>(
+    album_repo: &R,
+    artist: &Artist,
+    profile: Option<&MetadataProfile>,
+    release_groups: &[ReleaseGroup],
+    release_statuses: &HashMap<Uuid, Vec<String>>,
+    skip_filtered: bool,
+) -> Result<ReleaseGroupSyncSummary>
+where
+    R: AlbumRepository + ?Sized,
+{
+    let known: HashSet<String> = collect_all(PAGE_SIZE, |limit, offset| {
+        album_repo.get_by_artist(artist.id, limit, offset)
+    })
+    .await?
+    .into_iter()
+    .filter_map(|album| album.musicbrainz_release_group_id)
+    .collect();
+
+    let mut summary = ReleaseGroupSyncSummary::default();
+    for release_group in release_groups {
+        let mbid = release_group.id.to_string();
+        if known.contains(&mbid) {
+            summary.existing += 1;
+            continue;
+        }
+
+        let allowed = profile.is_none_or(|profile| {
+            let allows = |status: Option<&str>| {
+                profile.allows_album(
+                    release_group.primary_type.as_deref(),
+                    &release_group.secondary_types,
+                    status,
+                )
+            };
+            match release_statuses.get(&release_group.id) {
+                Some(statuses) if !statuses.is_empty() => {
+                    statuses.iter().any(|status| allows(Some(status)))
+                }
+                _ => allows(None),
+            }
+        });
+        if !allowed && skip_filtered {
+            debug!(target: "application", artist_id = %artist.id, release_group = %mbid, title = %release_group.title, "metadata profile rejects release group; skipping");
+            summary.skipped += 1;
+            continue;
+        }
+
+        let mut album = Album::new(artist.id, release_group.title.clone());
+        album.musicbrainz_release_group_id = Some(mbid);
+        album.primary_type = release_group.primary_type.clone();
+        if !release_group.secondary_types.is_empty() {
+            album.secondary_types = Some(release_group.secondary_types.join(","));
+        }
+        album.first_release_date = release_group.first_release_date.clone();
+        album.release_date = release_group
+            .first_release_date
+            .as_deref()
+            .and_then(ReleaseDate::parse_str);
+        album.monitored = allowed;
+        album_repo.create(album).await?;
+
+        if allowed {
+            summary.monitored += 1;
+        } else {
+            debug!(target: "application", artist_id = %artist.id, release_group = %release_group.id, title = %release_group.title, "metadata profile rejects release group; adding unmonitored");
+            summary.unmonitored += 1;
+        }
+    }
+
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sync_release_groups, ReleaseGroupSyncSummary};
+    use chorrosion_config::AppConfig;
+    use chorrosion_domain::{Artist, MetadataProfile, STUDIO_SECONDARY_TYPE};
+    use chorrosion_infrastructure::init_database;
+    use chorrosion_infrastructure::repositories::{AlbumRepository, Repository};
+    use chorrosion_infrastructure::sqlite_adapters::{
+        SqliteAlbumRepository, SqliteArtistRepository,
+    };
+    use chorrosion_musicbrainz::models::Album as ReleaseGroup;
+    use std::collections::HashMap;
+    use uuid::Uuid;
+
+    async fn fixture() -> (SqliteAlbumRepository, Artist) {
+        let mut config = AppConfig::default();
+        config.database.url = "sqlite::memory:".to_string();
+        config.database.pool_max_size = 1;
+        let pool = init_database(&config).await.expect("in-memory database");
+        let artist = SqliteArtistRepository::new(pool.clone())
+            .create(Artist::new("Portishead"))
+            .await
+            .unwrap();
+        (SqliteAlbumRepository::new(pool), artist)
+    }
+
+    fn release_group(title: &str, primary: &str, secondary: &[&str]) -> ReleaseGroup {
+        ReleaseGroup {
+            id: Uuid::new_v4(),
+            title: title.to_string(),
+            primary_type: Some(primary.to_string()),
+            secondary_types: secondary.iter().map(|s| s.to_string()).collect(),
+            first_release_date: Some("1994-08-22".to_string()),
+            artist_credit: vec![],
+            score: None,
+        }
+    }
+
+    fn release_groups() -> Vec<ReleaseGroup> {
+        vec![
+            release_group("Dummy", "Album", &[]),
+            release_group("Roseland NYC Live", "Album", &["Live"]),
+        ]
+    }
+
+    fn studio_albums_only() -> MetadataProfile {
+        let mut profile = MetadataProfile::new("Studio Albums");
+        profile.primary_album_types = vec!["Album".to_string()];
+        profile.secondary_album_types = vec![STUDIO_SECONDARY_TYPE.to_string()];
+        profile
+    }
+
+    async fn albums_by_title(
+        albums: &SqliteAlbumRepository,
+        artist: &Artist,
+    ) -> Vec<(String, bool)> {
+        let mut albums: Vec<_> = albums
+            .get_by_artist(artist.id, 100, 0)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|album| (album.title, album.monitored))
+            .collect();
+        albums.sort();
+        albums
+    }
+
+    #[tokio::test]
+    async fn album_only_profile_adds_live_release_group_unmonitored() {
+        let (albums, artist) = fixture().await;
+
+        let summary = sync_release_groups(
+            &albums,
+            &artist,
+            Some(&studio_albums_only()),
+            &release_groups(),
+            &HashMap::new(),
+            false,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(summary.monitored, 1);
+        assert_eq!(summary.unmonitored, 1);
+        assert_eq!(
+            albums_by_title(&albums, &artist).await,
+            [
+                ("Dummy".to_string(), true),
+                ("Roseland NYC Live".to_string(), false)
+            ]
+        );
+        let dummy = &albums.get_by_artist(artist.id, 100, 0).await.unwrap()[0];
+        assert_eq!(dummy.primary_type.as_deref(), Some("Album"));
+        assert_eq!(dummy.release_date.map(|date| date.year), Some(1994));
+    }
+
+    #[tokio::test]
+    async fn album_only_profile_skips_live_release_group_when_asked() {
+        let (albums, artist) = fixture().await;
+
+        let summary = sync_release_groups(
+            &albums,
+            &artist,
+            Some(&studio_albums_only()),
+            &release_groups(),
+            &HashMap::new(),
+            true,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(summary.skipped, 1);
+        assert_eq!(
+            albums_by_title(&albums, &artist).await,
+            [("Dummy".to_string(), true)]
+        );
+    }
+
+    #[tokio::test]
+    async fn empty_profile_monitors_every_release_group_once() {
+        let (albums, artist) = fixture().await;
+        let groups = release_groups();
+        let empty = MetadataProfile::new("Everything");
+
+        let first = sync_release_groups(
+            &albums,
+            &artist,
+            Some(&empty),
+            &groups,
+            &HashMap::new(),
+            true,
+        )
+        .await
+        .unwrap();
+        let second = sync_release_groups(
+            &albums,
+            &artist,
+            Some(&empty),
+            &groups,
+            &HashMap::new(),
+            true,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            first,
+            ReleaseGroupSyncSummary {
+                monitored: 2,
+                ..Default::default()
+            }
+        );
+        assert_eq!(second.existing, 2);
+        assert_eq!(
+            albums_by_title(&albums, &artist).await,
+            [
+                ("Dummy".to_string(), true),
+                ("Roseland NYC Live".to_string(), true)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn release_statuses_filter_groups_and_studio_albums_pass_secondary_types() {
+        let (albums, artist) = fixture().await;
+        let groups = release_groups();
+        let mut profile = MetadataProfile::new("Official Compilations");
+        profile.secondary_album_types = vec!["Compilation".to_string()];
+        profile.release_statuses = vec!["Official".to_string()];
+        let statuses = HashMap::from([(
+            groups[0].id,
+            vec!["Bootleg".to_string(), "Official".to_string()],
+        )]);
+
+        let summary =
+            sync_release_groups(&albums, &artist, Some(&profile), &groups, &statuses, false)
+                .await
+                .unwrap();
+
+        assert_eq!(summary.monitored, 1);
+        assert_eq!(
+            albums_by_title(&albums, &artist).await,
+            [
+                ("Dummy".to_string(), true),
+                ("Roseland NYC Live".to_string(), false)
+            ]
+        );
+
+        let (albums, artist) = fixture().await;
+        let bootleg_only = HashMap::from([(groups[0].id, vec!["Bootleg".to_string()])]);
+        let summary = sync_release_groups(
+            &albums,
+            &artist,
+            Some(&profile),
+            &groups,
+            &bootleg_only,
+            true,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(summary.skipped, 2);
+    }
+}
@@ -1,4 +1,8 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
+use crate::quality_upgrade::QualityRank;
+use chorrosion_config::SearchConfig;
+use chorrosion_domain::QualityProfile;
+use chrono::{DateTime, Duration, Utc};
 use lazy_static::lazy_static;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
@@ -8,6 +12,13 @@ use std::collections::{HashMap, HashSet};
 #[serde(rename_all = "lowercase")]
 pub enum AudioQuality {
     Flac,
+    /// FLAC above CD quality (24-bit and/or a sample rate above 48 kHz).
+    #[serde(rename = "flac_hires")]
+    FlacHiRes,
+    /// Direct Stream Digital (DSD64, DSD128, ... or DSF/DFF files).
+    Dsd,
+    /// Super Audio CD rips.
+    Sacd,
     Mp3,
     Aac,
     Alac,
@@ -19,9 +30,46 @@ pub struct ParsedReleaseTitle {
     pub original_title: String,
     pub artist: Option<String>,
     pub album: Option<String>,
+    /// Edition marker such as `Deluxe Edition` or `2011 Remaster`, when present.
+    pub edition: Option<String>,
     pub quality: AudioQuality,
     pub bitrate_kbps: Option<u32>,
+    pub bit_depth: Option<u8>,
+    pub sample_rate_hz: Option<u32>,
     pub release_group: Option<String>,
+    /// Total release size reported by the indexer; titles alone never carry it.
+    #[serde(default)]
+    pub size_bytes: Option<u64>,
+    /// Name of the indexer (tracker) the release was found on, when known.
+    #[serde(default)]
+    pub indexer: Option<String>,
+    /// The title carries a bootleg or unofficial-source marker such as `bootleg`,
+    /// `unofficial`, `soundboard` or `webrip of radio`.
+    #[serde(default)]
+    pub likely_unofficial: bool,
+    /// Swarm and age details for releases found on a torrent indexer; `None` for Usenet.
+    #[serde(default)]
+    pub torrent: Option<TorrentHealth>,
+}
+
+/// What a torrent indexer reported about a torrent.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TorrentHealth {
+    pub seeders: Option<u32>,
+    pub published_at: Option<DateTime<Utc>>,
+}
+
+/// Which releases [`deduplicate_releases_with_strategy`] treats as the same release.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum DedupeStrategy {
+    /// Same artist, album, quality and edition, whichever indexer it came from.
+    #[default]
+    ByContent,
+    /// As [`Self::ByContent`], but copies on different indexers are all kept.
+    ByContentAndTracker,
+    /// Nothing is collapsed.
+    KeepAll,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
@@ -30,7 +78,106 @@ pub struct ReleaseFilterOptions {
     pub min_bitrate_kbps: Option<u32>,
     pub preferred_release_groups: Vec<String>,
     pub preferred_words: Vec<String>,
+    /// Releases whose title contains any of these words (case-insensitive) are dropped.
+    #[serde(default)]
+    pub rejected_words: Vec<String>,
     pub custom_format_rules: Vec<CustomFormatRule>,
+    /// Releases smaller than this are dropped, e.g. single tracks posing as albums.
+    #[serde(default)]
+    pub min_size_bytes: Option<u64>,
+    /// Releases larger than this are dropped, e.g. mislabeled discographies.
+    #[serde(default)]
+    pub max_size_bytes: Option<u64>,
+    /// Drop releases without a reported size when a size range is set, instead of passing them.
+    #[serde(default)]
+    pub reject_unknown_size: bool,
+    /// Drop releases flagged [`ParsedReleaseTitle::likely_unofficial`].
+    #[serde(default)]
+    pub official_only: bool,
+    /// Torrents with fewer seeders than this are dropped. Usenet releases are never checked.
+    #[serde(default)]
+    pub min_seeders: Option<u32>,
+    /// Drop torrents without a reported seeder count when `min_seeders` is set, instead of passing them.
+    #[serde(default)]
+    pub reject_unknown_seeders: bool,
+    /// Torrents published more than this many days ago are dropped. Torrents
+    /// without a publish date pass.
+    #[serde(default)]
+    pub max_age_days: Option<u32>,
+    /// Which releases count as duplicates when search results are ranked.
+    #[serde(default)]
+    pub dedupe_strategy: DedupeStrategy,
+    /// Rank releases by this profile's allowed-quality order before their score,
+    /// as [`rank_releases_for_profile`] does.
+    #[serde(default)]
+    pub quality_profile: Option<QualityProfile>,
+}
+
+impl From<&SearchConfig> for ReleaseFilterOptions {
+    /// Default preferences with the configured torrent seeder and age limits.
+    fn from(config: &SearchConfig) -> Self {
+        Self {
+            min_seeders: config.min_seeders,
+            reject_unknown_seeders: config.reject_unknown_seeders,
+            max_age_days: config.max_age_days,
+            ..Self::default()
+        }
+    }
+}
+
+impl ReleaseFilterOptions {
+    /// Derive the size range from per-track bounds for an album of `track_count` tracks.
+    pub fn with_per_track_size_range(
+        mut self,
+        track_count: u32,
+        min_bytes_per_track: u64,
+        max_bytes_per_track: u64,
+    ) -> Self {
+        let track_count = u64::from(track_count.max(1));
+        self.min_size_bytes = Some(min_bytes_per_track.saturating_mul(track_count));
+        self.max_size_bytes = Some(max_bytes_per_track.saturating_mul(track_count));
+        self
+    }
+
+    /// Set [`Self::official_only`] from a metadata profile's release statuses: only a
+    /// non-empty list allowing nothing but `Official` rejects unofficial releases.
+    pub fn with_release_statuses(mut self, release_statuses: &[String]) -> Self {
+        self.official_only = !release_statuses.is_empty()
+            && release_statuses
+                .iter()
+                .all(|status| status.trim().eq_ignore_ascii_case("official"));
+        self
+    }
+
+    fn accepts_size(&self, size_bytes: Option<u64>) -> bool {
+        if self.min_size_bytes.is_none() && self.max_size_bytes.is_none() {
+            return true;
+        }
+        let Some(size_bytes) = size_bytes else {
+            return !self.reject_unknown_size;
+        };
+        self.min_size_bytes.is_none_or(|min| size_bytes >= min)
+            && self.max_size_bytes.is_none_or(|max| size_bytes <= max)
+    }
+
+    fn accepts_torrent(&self, torrent: Option<&TorrentHealth>, now: DateTime<Utc>) -> bool {
+        let Some(torrent) = torrent else {
+            return true;
+        };
+        if let Some(min_seeders) = self.min_seeders {
+            match torrent.seeders {
+                Some(seeders) if seeders < min_seeders => return false,
+                None if self.reject_unknown_seeders => return false,
+                _ => {}
+            }
+        }
+        match (self.max_age_days, torrent.published_at) {
+            (Some(max_age_days), Some(published_at)) => {
+                now - published_at <= Duration::days(i64::from(max_age_days))
+            }
+            _ => true,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -51,18 +198,28 @@ const SCORE_MAX: i64 = i32::MAX as i64;
 
 pub fn parse_release_title(title: &str) -> ParsedReleaseTitle {
     let normalized = normalize_whitespace(title);
-    let quality = detect_quality(&normalized);
+    let (bit_depth, sample_rate_hz) = detect_resolution(&normalized);
+    let quality = detect_quality(&normalized, bit_depth, sample_rate_hz);
     let bitrate_kbps = detect_bitrate_kbps(&normalized, &quality);
     let release_group = detect_release_group(&normalized);
     let (artist, album) = extract_artist_album(&normalized);
+    let edition = detect_edition(&normalized);
+    let likely_unofficial = detect_unofficial(&normalized);
 
     ParsedReleaseTitle {
         original_title: title.to_string(),
         artist,
         album,
+        edition,
         quality,
         bitrate_kbps,
+        bit_depth,
+        sample_rate_hz,
         release_group,
+        size_bytes: None,
+        indexer: None,
+        likely_unofficial,
+        torrent: None,
     }
 }
 
@@ -70,20 +227,49 @@ pub fn filter_releases(
     releases: &[ParsedReleaseTitle],
     options: &ReleaseFilterOptions,
 ) -> Vec<ParsedReleaseTitle> {
+    let rejected_word_tokens: Vec<Vec<String>> = options
+        .rejected_words
+        .iter()
+        .map(|word| word_tokens(word))
+        .filter(|tokens| !tokens.is_empty())
+        .collect();
+    let now = Utc::now();
     releases
         .iter()
         .filter(|release| {
+            if !rejected_word_tokens.is_empty() {
+                let title_tokens = word_tokens(&release.original_title);
+                if rejected_word_tokens
+                    .iter()
+                    .any(|word| contains_token_run(&title_tokens, word))
+                {
+                    return false;
+                }
+            }
+
+            if options.official_only && release.likely_unofficial {
+                return false;
+            }
+
             if !options.preferred_qualities.is_empty()
                 && !options.preferred_qualities.contains(&release.quality)
             {
                 return false;
             }
 
+            if !options.accepts_size(release.size_bytes) {
+                return false;
+            }
+
+            if !options.accepts_torrent(release.torrent.as_ref(), now) {
+                return false;
+            }
+
             if let Some(min_bitrate) = options.min_bitrate_kbps {
                 match (&release.quality, release.bitrate_kbps) {
                     // Treat lossless formats as always satisfying the bitrate requirement,
                     // even when `detect_bitrate_kbps` returns None.
-                    (&AudioQuality::Flac | &AudioQuality::Alac, _) => {}
+                    (quality, _) if quality.is_lossless() => {}
                     // For other formats, enforce the minimum bitrate if we have a value.
                     (_, Some(bitrate)) if bitrate >= min_bitrate => {}
                     _ => return false,
@@ -96,10 +282,15 @@ pub fn filter_releases(
         .collect()
 }
 
+/// Order releases best first: by [`ReleaseFilterOptions::quality_profile`] when one is
+/// set, otherwise by [`score_release`] alone.
 pub fn rank_releases(
     mut releases: Vec<ParsedReleaseTitle>,
     options: &ReleaseFilterOptions,
 ) -> Vec<ParsedReleaseTitle> {
+    if let Some(profile) = &options.quality_profile {
+        return rank_releases_for_profile(releases, options, profile);
+    }
     let normalized_preferred_words = normalize_preferred_words(&options.preferred_words);
     let normalized_custom_rules = normalize_custom_format_rules(&options.custom_format_rules);
     releases.sort_by_cached_key(|release| {
@@ -113,7 +304,49 @@ pub fn rank_releases(
     releases
 }
 
+/// Order releases by their [`QualityRank`] in `profile`, best first, breaking
+/// ties with [`score_release`].
+///
+/// Uses the same ordering as import upgrade decisions, so a release ranked
+/// higher here is also an upgrade over a lower one once imported. Releases
+/// whose quality the profile does not allow sort after every allowed one.
+pub fn rank_releases_for_profile(
+    mut releases: Vec<ParsedReleaseTitle>,
+    options: &ReleaseFilterOptions,
+    profile: &QualityProfile,
+) -> Vec<ParsedReleaseTitle> {
+    let normalized_preferred_words = normalize_preferred_words(&options.preferred_words);
+    let normalized_custom_rules = normalize_custom_format_rules(&options.custom_format_rules);
+    releases.sort_by_cached_key(|release| {
+        std::cmp::Reverse((
+            QualityRank::of_parsed(&release.quality, release.bitrate_kbps, profile),
+            score_release_with_words(
+                release,
+                options,
+                &normalized_preferred_words,
+                &normalized_custom_rules,
+            ),
+        ))
+    });
+    releases
+}
+
+/// Collapse duplicates of the same content, keeping the best-scored copy of each.
 pub fn deduplicate_releases(releases: &[ParsedReleaseTitle]) -> Vec<ParsedReleaseTitle> {
+    deduplicate_releases_with_strategy(releases, DedupeStrategy::default())
+}
+
+/// Keep the best-scored release among each group of duplicates under `strategy`.
+pub fn deduplicate_releases_with_strategy(
+    releases: &[ParsedReleaseTitle],
+    strategy: DedupeStrategy,
+) -> Vec<ParsedReleaseTitle> {
+    if strategy == DedupeStrategy::KeepAll {
+        let mut kept = releases.to_vec();
+        kept.sort_by_key(|release| release.original_title.to_lowercase());
+        return kept;
+    }
+
     let mut best_by_key: HashMap<String, ParsedReleaseTitle> = HashMap::new();
     let default_options = ReleaseFilterOptions::default();
     let normalized_default_words = normalize_preferred_words(&default_options.preferred_words);
@@ -121,7 +354,7 @@ pub fn deduplicate_releases(releases: &[ParsedReleaseTitle]) -> Vec<ParsedReleas
         normalize_custom_format_rules(&default_options.custom_format_rules);
 
     for release in releases {
-        let key = duplicate_key(release);
+        let key = duplicate_key(release, strategy);
         match best_by_key.get(&key) {
             Some(existing) => {
                 let existing_score = score_release_with_words(
@@ -151,10 +384,23 @@ pub fn deduplicate_releases(releases: &[ParsedReleaseTitle]) -> Vec<ParsedReleas
     deduped
 }
 
+/// Keys shared by more than one release under the default [`DedupeStrategy`].
 pub fn find_duplicate_keys(releases: &[ParsedReleaseTitle]) -> Vec<String> {
+    find_duplicate_keys_with_strategy(releases, DedupeStrategy::default())
+}
+
+/// Keys shared by more than one release under `strategy`, sorted.
+pub fn find_duplicate_keys_with_strategy(
+    releases: &[ParsedReleaseTitle],
+    strategy: DedupeStrategy,
+) -> Vec<String> {
+    if strategy == DedupeStrategy::KeepAll {
+        return Vec::new();
+    }
+
     let mut counts: HashMap<String, usize> = HashMap::new();
     for release in releases {
-        let key = duplicate_key(release);
+        let key = duplicate_key(release, strategy);
         *counts.entry(key).or_insert(0) += 1;
     }
 
@@ -166,8 +412,8 @@ pub fn find_duplicate_keys(releases: &[ParsedReleaseTitle]) -> Vec<String> {
     duplicates
 }
 
-fn duplicate_key(release: &ParsedReleaseTitle) -> String {
-    format!(
+fn duplicate_key(release: &ParsedReleaseTitle, strategy: DedupeStrategy) -> String {
+    let key = format!(
         "{}|{}|{}",
         release
             .artist
@@ -182,6 +428,35 @@ fn duplicate_key(release: &ParsedReleaseTitle) -> String {
             .trim()
             .to_lowercase(),
         release.quality_key(),
+    );
+
+    // Differing editions (e.g. a remaster vs the original) are distinct releases.
+    let key = match release.edition.as_deref() {
+        Some(edition) => format!("{key}|{}", edition.to_lowercase()),
+        None => key,
+    };
+
+    match strategy {
+        DedupeStrategy::ByContentAndTracker => format!(
+            "{key}@{}",
+            release
+                .indexer
+                .as_deref()
+                .unwrap_or_default()
+                .trim()
+                .to_lowercase()
+        ),
+        DedupeStrategy::ByContent | DedupeStrategy::KeepAll => key,
+    }
+}
+
+/// Score a single release the same way [`rank_releases`] orders them; higher is better.
+pub fn score_release(release: &ParsedReleaseTitle, options: &ReleaseFilterOptions) -> i32 {
+    score_release_with_words(
+        release,
+        options,
+        &normalize_preferred_words(&options.preferred_words),
+        &normalize_custom_format_rules(&options.custom_format_rules),
     )
 }
 
@@ -192,6 +467,7 @@ fn score_release_with_words(
     normalized_custom_rules: &[NormalizedCustomFormatRule],
 ) -> i32 {
     let quality_score = match release.quality {
+        AudioQuality::FlacHiRes | AudioQuality::Dsd | AudioQuality::Sacd => 240,
         AudioQuality::Flac | AudioQuality::Alac => 200,
         AudioQuality::Mp3 => 120,
         AudioQuality::Aac => 100,
@@ -316,11 +592,45 @@ fn normalize_whitespace(input: &str) -> String {
     input.split_whitespace().collect::<Vec<&str>>().join(" ")
 }
 
-fn detect_quality(title: &str) -> AudioQuality {
+/// Lowercased alphanumeric runs of `input`, so punctuation and brackets act as
+/// word boundaries ("[EXPLICIT]" yields `["explicit"]`).
+fn word_tokens(input: &str) -> Vec<String> {
+    input
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Whether `needle` appears as a contiguous run of whole tokens in `haystack`.
+fn contains_token_run(haystack: &[String], needle: &[String]) -> bool {
+    !needle.is_empty()
+        && haystack
+            .windows(needle.len())
+            .any(|window| window == needle)
+}
+
+fn detect_quality(title: &str, bit_depth: Option<u8>, sample_rate_hz: Option<u32>) -> AudioQuality {
+    lazy_static! {
+        static ref SACD_REGEX: Regex = Regex::new(r"(?i)\bsacd\b").expect("valid sacd regex");
+        static ref DSD_REGEX: Regex =
+            Regex::new(r"(?i)\b(?:dsd(?:64|128|256|512)?|dsf|dff)\b").expect("valid dsd regex");
+    }
+
     let lowercase = title.to_lowercase();
 
-    if lowercase.contains("flac") {
-        AudioQuality::Flac
+    if SACD_REGEX.is_match(title) {
+        AudioQuality::Sacd
+    } else if DSD_REGEX.is_match(title) {
+        AudioQuality::Dsd
+    } else if lowercase.contains("flac") {
+        let hi_res = bit_depth.is_some_and(|depth| depth >= 24)
+            || sample_rate_hz.is_some_and(|rate| rate > 48_000);
+        if hi_res {
+            AudioQuality::FlacHiRes
+        } else {
+            AudioQuality::Flac
+        }
     } else if lowercase.contains("alac") {
         AudioQuality::Alac
     } else if lowercase.contains("mp3") || lowercase.contains("v0") || lowercase.contains("v2") {
@@ -360,20 +670,160 @@ fn detect_bitrate_kbps(title: &str, quality: &AudioQuality) -> Option<u32> {
     }
 }
 
-fn detect_release_group(title: &str) -> Option<String> {
+/// Detect bit depth and sample rate from tokens such as `24-96`, `24bit/96kHz`,
+/// `192kHz`, or `DSD128`.
+fn detect_resolution(title: &str) -> (Option<u8>, Option<u32>) {
     lazy_static! {
-        static ref GROUP_REGEX: Regex =
-            Regex::new(r"-(?P<group>[A-Za-z0-9][A-Za-z0-9_.-]{1,31})$").expect("valid group regex");
+        static ref DEPTH_RATE_REGEX: Regex = Regex::new(
+            r"(?i)\b(?P<depth>16|24|32)\s?(?:-?\s?bits?)?\s?[/-]\s?(?P<rate>44\.1|48|88\.2|96|176\.4|192|352\.8|384)(?:\s?khz)?\b"
+        )
+        .expect("valid depth/rate regex");
+        static ref DEPTH_REGEX: Regex =
+            Regex::new(r"(?i)\b(?P<depth>16|24|32)\s?-?\s?bits?\b").expect("valid depth regex");
+        static ref RATE_REGEX: Regex =
+            Regex::new(r"(?i)\b(?P<rate>44\.1|48|88\.2|96|176\.4|192|352\.8|384)\s?khz\b")
+                .expect("valid sample rate regex");
+        static ref DSD_RATE_REGEX: Regex =
+            Regex::new(r"(?i)\bdsd(?P<multiplier>64|128|256|512)\b").expect("valid dsd rate regex");
+    }
+
+    if let Some(captures) = DSD_RATE_REGEX.captures(title) {
+        let sample_rate = captures["multiplier"]
+            .parse::<u32>()
+            .ok()
+            .map(|multiplier| multiplier * 44_100);
+        return (Some(1), sample_rate);
+    }
+
+    if let Some(captures) = DEPTH_RATE_REGEX.captures(title) {
+        return (
+            captures["depth"].parse().ok(),
+            parse_sample_rate_khz(&captures["rate"]),
+        );
     }
 
-    GROUP_REGEX
+    let bit_depth = DEPTH_REGEX
         .captures(title)
-        .and_then(|captures| captures.name("group").map(|m| m.as_str().to_string()))
+        .and_then(|captures| captures["depth"].parse().ok());
+    let sample_rate_hz = RATE_REGEX
+        .captures(title)
+        .and_then(|captures| parse_sample_rate_khz(&captures["rate"]));
+
+    (bit_depth, sample_rate_hz)
+}
+
+fn parse_sample_rate_khz(value: &str) -> Option<u32> {
+    value
+        .parse::<f64>()
+        .ok()
+        .map(|khz| (khz * 1000.0).round() as u32)
+}
+
+/// Detect the scene (`...FLAC-GROUP`) or p2p (`... {GROUP}`) release group.
+fn detect_release_group(title: &str) -> Option<String> {
+    lazy_static! {
+        static ref P2P_GROUP_REGEX: Regex =
+            Regex::new(r"\{(?P<group>[A-Za-z0-9][A-Za-z0-9_.-]{1,31})\}$")
+                .expect("valid p2p group regex");
+    }
+
+    if let Some(captures) = P2P_GROUP_REGEX.captures(title) {
+        return Some(captures["group"].to_string());
+    }
+
+    split_scene_group(title).1
+}
+
+/// Split a trailing scene `-GROUP` suffix off `title`.
+///
+/// The suffix only counts as a group when the token in front of it looks like release
+/// metadata (a format, source, year, or closing bracket), so hyphenated artist and album
+/// names such as `Back-In-Black` are left intact.
+fn split_scene_group(title: &str) -> (&str, Option<String>) {
+    lazy_static! {
+        static ref SCENE_GROUP_REGEX: Regex =
+            Regex::new(r"(?P<prefix>\S+)-(?P<group>[A-Za-z0-9][A-Za-z0-9_.]{1,31})$")
+                .expect("valid scene group regex");
+        static ref RELEASE_MARKER_REGEX: Regex = Regex::new(concat!(
+            r"(?i)^(?:flac|alac|mp3|aac|m4a|ogg|opus|wav|ape|wv|dsf|dff|sacd|dsd(?:64|128|256|512)?",
+            r"|v0|v2|cbr|vbr|web|cd|cdr|cdda|vinyl|lp|ep|\d{4}|\d{2,4}(?:kbps|k)",
+            r"|(?:16|24|32)bits?|\d{2,3}(?:\.\d)?khz)$",
+        ))
+        .expect("valid release marker regex");
+    }
+
+    let Some(captures) = SCENE_GROUP_REGEX.captures(title) else {
+        return (title, None);
+    };
+
+    let group = &captures["group"];
+    let prefix = &captures["prefix"];
+    let marker = prefix.rsplit('-').next().unwrap_or(prefix);
+    let follows_marker = prefix.ends_with([']', ')', '}']) || RELEASE_MARKER_REGEX.is_match(marker);
+
+    // A trailing `-96` or `-44.1` belongs to a resolution token such as `24-96`.
+    let is_numeric = group.chars().all(|c| c.is_ascii_digit() || c == '.');
+
+    if !follows_marker || is_numeric {
+        return (title, None);
+    }
+
+    let group_start = captures.name("group").map_or(title.len(), |m| m.start());
+    (&title[..group_start - 1], Some(group.to_string()))
+}
+
+/// Edition marker from a bracketed chunk of `title`.
+///
+/// Only `(...)`, `[...]` and `{...}` chunks are searched, so album names such as
+/// "Special Brew" or "Limited Time" are not mistaken for editions.
+fn detect_edition(title: &str) -> Option<String> {
+    lazy_static! {
+        static ref BRACKETED_CHUNK_REGEX: Regex =
+            Regex::new(r"\[([^\]]*)\]|\(([^\)]*)\)|\{([^\}]*)\}")
+                .expect("valid bracketed chunk regex");
+        static ref EDITION_REGEX: Regex = Regex::new(concat!(
+            r"(?i)\b(?P<edition>(?:\d{4}\s+|\d{1,3}(?:st|nd|rd|th)\s+)?",
+            r"(?:super\s+deluxe|deluxe|remaster(?:ed)?|anniversary|expanded|special|limited|collector'?s|legacy)",
+            r"(?:\s+\d{4})?(?:\s+(?:edition|version))?)\b",
+        ))
+        .expect("valid edition regex");
+    }
+
+    BRACKETED_CHUNK_REGEX
+        .captures_iter(title)
+        .filter_map(|chunk| chunk.iter().skip(1).flatten().next())
+        .find_map(|inner| {
+            EDITION_REGEX
+                .captures(inner.as_str())
+                .and_then(|captures| captures.name("edition"))
+                .map(|m| m.as_str().to_string())
+        })
+}
+
+/// Whether `title` marks itself as a bootleg or unofficial recording.
+///
+/// Markers must be whole words, and `bootleg` followed by `series` is ignored so
+/// official archival sets such as "The Bootleg Series" are not flagged.
+fn detect_unofficial(title: &str) -> bool {
+    lazy_static! {
+        static ref UNOFFICIAL_REGEX: Regex = Regex::new(
+            r"(?i)\b(?:unofficial|soundboard|webrip\s+of\s+radio|bootleg(?:\s+series\b)?)\b"
+        )
+        .expect("valid unofficial regex");
+    }
+
+    UNOFFICIAL_REGEX.find_iter(title).any(|marker| {
+        !marker
+            .as_str()
+            .to_lowercase()
+            .split_whitespace()
+            .eq(["bootleg", "series"])
+    })
 }
 
 fn extract_artist_album(title: &str) -> (Option<String>, Option<String>) {
-    let stripped = strip_bracketed_chunks(title);
-    let stripped = strip_release_group_suffix(&stripped);
+    let (without_group, _) = split_scene_group(title);
+    let stripped = strip_bracketed_chunks(without_group);
 
     let Some((artist_raw, album_raw)) = stripped.split_once(" - ") else {
         return (None, None);
@@ -387,9 +837,13 @@ fn extract_artist_album(title: &str) -> (Option<String>, Option<String>) {
 
 fn strip_quality_bitrate_tokens(value: &str) -> String {
     lazy_static! {
-        static ref QUALITY_TOKEN_REGEX: Regex =
-            Regex::new(r"(?i)\b(flac|alac|mp3|aac|m4a|v0|v2)\b|\b\d{2,4}\s?(?:kbps|k)\b")
-                .expect("valid quality token regex");
+        static ref QUALITY_TOKEN_REGEX: Regex = Regex::new(concat!(
+            r"(?i)\b(flac|alac|mp3|aac|m4a|v0|v2|sacd|dsf|dff|dsd(?:64|128|256|512)?)\b",
+            r"|\b\d{2,4}\s?(?:kbps|k)\b",
+            r"|\b(?:16|24|32)\s?(?:-?\s?bits?)?\s?[/-]\s?\d{2,3}(?:\.\d)?(?:\s?khz)?\b",
+            r"|\b(?:16|24|32)\s?-?\s?bits?\b|\b\d{2,3}(?:\.\d)?\s?khz\b",
+        ))
+        .expect("valid quality token regex");
     }
 
     normalize_whitespace(QUALITY_TOKEN_REGEX.replace_all(value, "").trim())
@@ -398,21 +852,12 @@ fn strip_quality_bitrate_tokens(value: &str) -> String {
 fn strip_bracketed_chunks(value: &str) -> String {
     lazy_static! {
         static ref BRACKETED_REGEX: Regex =
-            Regex::new(r"\[[^\]]*\]|\([^\)]*\)").expect("valid bracketed regex");
+            Regex::new(r"\[[^\]]*\]|\([^\)]*\)|\{[^\}]*\}").expect("valid bracketed regex");
     }
 
     BRACKETED_REGEX.replace_all(value, "").to_string()
 }
 
-fn strip_release_group_suffix(value: &str) -> String {
-    lazy_static! {
-        static ref GROUP_SUFFIX_REGEX: Regex =
-            Regex::new(r"\s*-[A-Za-z0-9][A-Za-z0-9_.-]{1,31}$").expect("valid group suffix regex");
-    }
-
-    GROUP_SUFFIX_REGEX.replace(value, "").to_string()
-}
-
 fn clean_component(value: &str) -> Option<String> {
     let cleaned = value.trim().trim_matches('-').trim();
     if cleaned.is_empty() {
@@ -426,15 +871,47 @@ impl AudioQuality {
     pub fn as_str(&self) -> &'static str {
         match self {
             AudioQuality::Flac => "flac",
+            AudioQuality::FlacHiRes => "flac_hires",
+            AudioQuality::Dsd => "dsd",
+            AudioQuality::Sacd => "sacd",
             AudioQuality::Mp3 => "mp3",
             AudioQuality::Aac => "aac",
             AudioQuality::Alac => "alac",
             AudioQuality::Unknown => "unknown",
         }
     }
+
+    pub fn is_lossless(&self) -> bool {
+        matches!(
+            self,
+            AudioQuality::Flac
+                | AudioQuality::FlacHiRes
+                | AudioQuality::Dsd
+                | AudioQuality::Sacd
+                | AudioQuality::Alac
+        )
+    }
 }
 
 impl ParsedReleaseTitle {
+    /// Attach the indexer-reported size so [`filter_releases`] can apply size limits.
+    pub fn with_size_bytes(mut self, size_bytes: Option<u64>) -> Self {
+        self.size_bytes = size_bytes;
+        self
+    }
+
+    /// Record which indexer returned the release, for [`DedupeStrategy::ByContentAndTracker`].
+    pub fn with_indexer(mut self, indexer: impl Into<String>) -> Self {
+        self.indexer = Some(indexer.into());
+        self
+    }
+
+    /// Mark the release as a torrent, for the seeder and age limits in [`filter_releases`].
+    pub fn with_torrent_health(mut self, torrent: TorrentHealth) -> Self {
+        self.torrent = Some(torrent);
+        self
+    }
+
     fn quality_key(&self) -> &'static str {
         self.quality.as_str()
     }
@@ -443,9 +920,12 @@ impl ParsedReleaseTitle {
 #[cfg(test)]
 mod tests {
     use super::{
-        deduplicate_releases, filter_releases, find_duplicate_keys, parse_release_title,
-        rank_releases, AudioQuality, CustomFormatRule, ParsedReleaseTitle, ReleaseFilterOptions,
+        deduplicate_releases, deduplicate_releases_with_strategy, filter_releases,
+        find_duplicate_keys, find_duplicate_keys_with_strategy, parse_release_title, rank_releases,
+        score_release, AudioQuality, CustomFormatRule, DedupeStrategy, ParsedReleaseTitle,
+        ReleaseFilterOptions, TorrentHealth,
     };
+    use chrono::{Duration, Utc};
 
     #[test]
     fn parses_artist_album_quality_and_group() {
@@ -482,7 +962,9 @@ mod tests {
             min_bitrate_kbps: Some(256),
             preferred_release_groups: vec![],
             preferred_words: vec![],
+            rejected_words: vec![],
             custom_format_rules: vec![],
+            ..ReleaseFilterOptions::default()
         };
 
         let filtered = filter_releases(&releases, &options);
@@ -503,7 +985,9 @@ mod tests {
             min_bitrate_kbps: Some(256),
             preferred_release_groups: vec![],
             preferred_words: vec![],
+            rejected_words: vec![],
             custom_format_rules: vec![],
+            ..ReleaseFilterOptions::default()
         };
 
         let filtered = filter_releases(&releases, &options);
@@ -532,7 +1016,9 @@ mod tests {
             min_bitrate_kbps: None,
             preferred_release_groups: vec!["Preferred".to_string()],
             preferred_words: vec![],
+            rejected_words: vec![],
             custom_format_rules: vec![],
+            ..ReleaseFilterOptions::default()
         };
 
         let ranked = rank_releases(releases, &options);
@@ -546,17 +1032,31 @@ mod tests {
                 original_title: "A".to_string(),
                 artist: Some("Artist".to_string()),
                 album: Some("Album".to_string()),
+                edition: None,
                 quality: AudioQuality::Mp3,
                 bitrate_kbps: Some(320),
+                bit_depth: None,
+                sample_rate_hz: None,
                 release_group: Some("Group1".to_string()),
+                size_bytes: None,
+                indexer: None,
+                likely_unofficial: false,
+                torrent: None,
             },
             ParsedReleaseTitle {
                 original_title: "B".to_string(),
                 artist: Some("Artist".to_string()),
                 album: Some("Album".to_string()),
+                edition: None,
                 quality: AudioQuality::Flac,
                 bitrate_kbps: None,
+                bit_depth: None,
+                sample_rate_hz: None,
                 release_group: Some("Group2".to_string()),
+                size_bytes: None,
+                indexer: None,
+                likely_unofficial: false,
+                torrent: None,
             },
         ];
 
@@ -576,7 +1076,9 @@ mod tests {
             min_bitrate_kbps: None,
             preferred_release_groups: vec![],
             preferred_words: vec!["DELUXE".to_string()],
+            rejected_words: vec![],
             custom_format_rules: vec![],
+            ..ReleaseFilterOptions::default()
         };
 
         let ranked = rank_releases(releases, &options);
@@ -595,7 +1097,9 @@ mod tests {
             min_bitrate_kbps: None,
             preferred_release_groups: vec![],
             preferred_words: vec!["sceneprime".to_string()],
+            rejected_words: vec![],
             custom_format_rules: vec![],
+            ..ReleaseFilterOptions::default()
         };
 
         let ranked = rank_releases(releases, &options);
@@ -614,13 +1118,249 @@ mod tests {
             min_bitrate_kbps: None,
             preferred_release_groups: vec![],
             preferred_words: vec!["daft punk".to_string()],
+            rejected_words: vec![],
             custom_format_rules: vec![],
+            ..ReleaseFilterOptions::default()
         };
 
         let ranked = rank_releases(releases, &options);
         assert!(ranked[0].original_title.contains("Daft"));
     }
 
+    #[test]
+    fn preferred_word_lifts_lower_bitrate_release() {
+        let releases = vec![
+            parse_release_title("Artist - Album 320kbps MP3-GroupA"),
+            parse_release_title("Artist - Album Remastered 256kbps MP3-GroupB"),
+        ];
+
+        let options = ReleaseFilterOptions {
+            preferred_words: vec!["remastered".to_string()],
+            ..ReleaseFilterOptions::default()
+        };
+
+        let ranked = rank_releases(releases, &options);
+        assert_eq!(ranked[0].release_group.as_deref(), Some("GroupB"));
+    }
+
+    #[test]
+    fn rejected_word_drops_otherwise_best_release() {
+        let releases = vec![
+            parse_release_title("Artist - Album (Live) [FLAC]-GroupA"),
+            parse_release_title("Artist - Album [EXPLICIT] 320kbps MP3-GroupB"),
+            parse_release_title("Artist - Album 256kbps MP3-GroupC"),
+        ];
+
+        let options = ReleaseFilterOptions {
+            rejected_words: vec!["LIVE".to_string(), "[explicit]".to_string()],
+            ..ReleaseFilterOptions::default()
+        };
+
+        let filtered = filter_releases(&releases, &options);
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].release_group.as_deref(), Some("GroupC"));
+    }
+
+    #[test]
+    fn rejected_word_matches_whole_words_only() {
+        let releases = vec![
+            parse_release_title("Alive - Greatest Hits [FLAC]-GroupA"),
+            parse_release_title("Oliver - Sunday 320kbps MP3-GroupB"),
+            parse_release_title("Artist - Live at Wembley [FLAC]-GroupC"),
+            parse_release_title("Artist - Album Web Rip 256kbps MP3-GroupD"),
+        ];
+
+        let options = ReleaseFilterOptions {
+            rejected_words: vec!["live".to_string(), "web  rip".to_string()],
+            ..ReleaseFilterOptions::default()
+        };
+
+        let groups: Vec<_> = filter_releases(&releases, &options)
+            .into_iter()
+            .filter_map(|release| release.release_group)
+            .collect();
+        assert_eq!(groups, vec!["GroupA", "GroupB"]);
+    }
+
+    #[test]
+    fn flags_bootleg_and_unofficial_markers() {
+        for title in [
+            "Radiohead - Live in Paris 1995 (Bootleg) [FLAC]-GRP",
+            "Pearl Jam - 2000-06-26 Katowice Soundboard MP3 320kbps",
+            "Nirvana - Rarities [Unofficial Release] FLAC",
+            "Portishead - BBC Session Webrip of Radio 256kbps MP3",
+        ] {
+            assert!(parse_release_title(title).likely_unofficial, "{title}");
+        }
+    }
+
+    #[test]
+    fn bootleg_series_and_plain_titles_are_not_flagged() {
+        for title in [
+            "Bob Dylan - The Bootleg Series Vol 1-3 [FLAC]-GRP",
+            "Cypress Hill - Bootlegs & B-Sides 320kbps MP3",
+            "Radiohead - OK Computer 1997 FLAC",
+        ] {
+            assert!(!parse_release_title(title).likely_unofficial, "{title}");
+        }
+    }
+
+    #[test]
+    fn official_only_profile_drops_unofficial_releases() {
+        let releases = vec![
+            parse_release_title("Artist - Album (Bootleg) [FLAC]-GroupA"),
+            parse_release_title("Artist - Album 320kbps MP3-GroupB"),
+        ];
+
+        let official = ReleaseFilterOptions::default().with_release_statuses(&["Official".into()]);
+        let filtered = filter_releases(&releases, &official);
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].release_group.as_deref(), Some("GroupB"));
+
+        let any = ReleaseFilterOptions::default()
+            .with_release_statuses(&["Official".into(), "Bootleg".into()]);
+        assert!(!any.official_only);
+        assert_eq!(filter_releases(&releases, &any).len(), 2);
+        assert!(
+            !ReleaseFilterOptions::default()
+                .with_release_statuses(&[])
+                .official_only
+        );
+    }
+
+    #[test]
+    fn multiple_preferred_word_matches_stack() {
+        let release = parse_release_title("Artist - Album Remastered Deluxe 320kbps MP3-GRP");
+        let one_word = ReleaseFilterOptions {
+            preferred_words: vec!["remastered".to_string()],
+            ..ReleaseFilterOptions::default()
+        };
+        let two_words = ReleaseFilterOptions {
+            preferred_words: vec!["remastered".to_string(), "deluxe".to_string()],
+            ..ReleaseFilterOptions::default()
+        };
+
+        let base = score_release(&release, &ReleaseFilterOptions::default());
+        assert_eq!(score_release(&release, &one_word), base + 30);
+        assert_eq!(score_release(&release, &two_words), base + 60);
+    }
+
+    const MB: u64 = 1024 * 1024;
+
+    #[test]
+    fn filters_releases_outside_size_range() {
+        let releases = vec![
+            parse_release_title("Artist - Album [FLAC]-Tiny").with_size_bytes(Some(5 * MB)),
+            parse_release_title("Artist - Album [FLAC]-Huge").with_size_bytes(Some(4096 * MB)),
+            parse_release_title("Artist - Album [FLAC]-Right").with_size_bytes(Some(350 * MB)),
+            parse_release_title("Artist - Album [FLAC]-Unknown"),
+        ];
+
+        let options = ReleaseFilterOptions {
+            min_size_bytes: Some(30 * MB),
+            max_size_bytes: Some(2048 * MB),
+            ..ReleaseFilterOptions::default()
+        };
+
+        let kept: Vec<_> = filter_releases(&releases, &options)
+            .into_iter()
+            .filter_map(|release| release.release_group)
+            .collect();
+        assert_eq!(kept, vec!["Right", "Unknown"]);
+    }
+
+    #[test]
+    fn unknown_size_can_be_rejected() {
+        let releases = vec![parse_release_title("Artist - Album [FLAC]-Unknown")];
+
+        let options = ReleaseFilterOptions {
+            min_size_bytes: Some(30 * MB),
+            reject_unknown_size: true,
+            ..ReleaseFilterOptions::default()
+        };
+
+        assert!(filter_releases(&releases, &options).is_empty());
+    }
+
+    fn torrent(title: &str, seeders: Option<u32>, age_days: i64) -> ParsedReleaseTitle {
+        parse_release_title(title).with_torrent_health(TorrentHealth {
+            seeders,
+            published_at: Some(Utc::now() - Duration::days(age_days)),
+        })
+    }
+
+    fn release_groups(releases: Vec<ParsedReleaseTitle>) -> Vec<String> {
+        releases
+            .into_iter()
+            .filter_map(|release| release.release_group)
+            .collect()
+    }
+
+    #[test]
+    fn drops_dead_and_old_torrents_but_keeps_healthy_recent_ones() {
+        let releases = vec![
+            torrent("Artist - Album [FLAC]-Dead", Some(0), 3),
+            torrent("Artist - Album [FLAC]-Old", Some(40), 400),
+            torrent("Artist - Album [FLAC]-Healthy", Some(12), 30),
+        ];
+
+        let options = ReleaseFilterOptions {
+            min_seeders: Some(1),
+            max_age_days: Some(365),
+            ..ReleaseFilterOptions::default()
+        };
+
+        assert_eq!(
+            release_groups(filter_releases(&releases, &options)),
+            vec!["Healthy"]
+        );
+    }
+
+    #[test]
+    fn usenet_releases_bypass_torrent_limits() {
+        let releases = vec![parse_release_title("Artist - Album [FLAC]-Usenet")];
+
+        let options = ReleaseFilterOptions {
+            min_seeders: Some(5),
+            reject_unknown_seeders: true,
+            max_age_days: Some(1),
+            ..ReleaseFilterOptions::default()
+        };
+
+        assert_eq!(
+            release_groups(filter_releases(&releases, &options)),
+            vec!["Usenet"]
+        );
+    }
+
+    #[test]
+    fn unknown_seeders_follow_the_configured_policy() {
+        let releases = vec![torrent("Artist - Album [FLAC]-Unknown", None, 1)];
+        let keep = ReleaseFilterOptions {
+            min_seeders: Some(1),
+            ..ReleaseFilterOptions::default()
+        };
+        let drop = ReleaseFilterOptions {
+            reject_unknown_seeders: true,
+            ..keep.clone()
+        };
+
+        assert_eq!(filter_releases(&releases, &keep).len(), 1);
+        assert!(filter_releases(&releases, &drop).is_empty());
+    }
+
+    #[test]
+    fn per_track_size_range_scales_with_track_count() {
+        let options =
+            ReleaseFilterOptions::default().with_per_track_size_range(10, 2 * MB, 150 * MB);
+
+        assert_eq!(options.min_size_bytes, Some(20 * MB));
+        assert_eq!(options.max_size_bytes, Some(1500 * MB));
+        let single_track =
+            parse_release_title("Artist - Album 320kbps MP3-GRP").with_size_bytes(Some(9 * MB));
+        assert!(filter_releases(&[single_track], &options).is_empty());
+    }
+
     #[test]
     fn duplicate_key_detection_finds_matching_artist_album_quality() {
         let releases = vec![
@@ -670,11 +1410,13 @@ mod tests {
             min_bitrate_kbps: None,
             preferred_release_groups: vec![],
             preferred_words: vec![],
+            rejected_words: vec![],
             custom_format_rules: vec![CustomFormatRule {
                 name: "MQA".to_string(),
                 keywords: vec!["mqa".to_string()],
                 score_bonus: 60,
             }],
+            ..ReleaseFilterOptions::default()
         };
 
         let ranked = rank_releases(releases, &options);
@@ -693,11 +1435,13 @@ mod tests {
             min_bitrate_kbps: None,
             preferred_release_groups: vec![],
             preferred_words: vec![],
+            rejected_words: vec![],
             custom_format_rules: vec![CustomFormatRule {
                 name: "MQA Deluxe".to_string(),
                 keywords: vec!["mqa   deluxe".to_string()],
                 score_bonus: 80,
             }],
+            ..ReleaseFilterOptions::default()
         };
 
         let ranked = rank_releases(releases, &options);
@@ -719,6 +1463,7 @@ mod tests {
             min_bitrate_kbps: None,
             preferred_release_groups: vec![],
             preferred_words: vec![],
+            rejected_words: vec![],
             custom_format_rules: vec![
                 CustomFormatRule {
                     name: "Rule 1".to_string(),
@@ -731,9 +1476,236 @@ mod tests {
                     score_bonus: i32::MAX,
                 },
             ],
+            ..ReleaseFilterOptions::default()
         };
 
         let ranked = rank_releases(releases, &options);
         assert!(ranked[0].original_title.to_lowercase().contains("mqa"));
     }
+
+    #[test]
+    fn parses_dsd64_release() {
+        let parsed = parse_release_title("Miles Davis - Kind of Blue (1959) [DSD64]");
+
+        assert_eq!(parsed.artist.as_deref(), Some("Miles Davis"));
+        assert_eq!(parsed.album.as_deref(), Some("Kind of Blue"));
+        assert_eq!(parsed.quality, AudioQuality::Dsd);
+        assert_eq!(parsed.bit_depth, Some(1));
+        assert_eq!(parsed.sample_rate_hz, Some(2_822_400));
+    }
+
+    #[test]
+    fn parses_24_96_flac_as_hi_res() {
+        let parsed = parse_release_title("Artist - Album 2024 FLAC 24-96");
+
+        assert_eq!(parsed.artist.as_deref(), Some("Artist"));
+        assert_eq!(parsed.album.as_deref(), Some("Album 2024"));
+        assert_eq!(parsed.quality, AudioQuality::FlacHiRes);
+        assert_eq!(parsed.bit_depth, Some(24));
+        assert_eq!(parsed.sample_rate_hz, Some(96_000));
+        assert_eq!(parsed.release_group, None);
+    }
+
+    #[test]
+    fn parses_bit_depth_and_sample_rate_tokens() {
+        let parsed = parse_release_title("Artist - Album [FLAC 24bit/192kHz]-GRP");
+
+        assert_eq!(parsed.quality, AudioQuality::FlacHiRes);
+        assert_eq!(parsed.bit_depth, Some(24));
+        assert_eq!(parsed.sample_rate_hz, Some(192_000));
+        assert_eq!(parsed.release_group.as_deref(), Some("GRP"));
+    }
+
+    #[test]
+    fn parses_sacd_release() {
+        let parsed = parse_release_title("Artist - Album (2024) [SACD]");
+
+        assert_eq!(parsed.artist.as_deref(), Some("Artist"));
+        assert_eq!(parsed.album.as_deref(), Some("Album"));
+        assert_eq!(parsed.quality, AudioQuality::Sacd);
+    }
+
+    #[test]
+    fn cd_quality_flac_is_not_hi_res() {
+        let parsed = parse_release_title("Artist - Album FLAC 16-44.1");
+
+        assert_eq!(parsed.quality, AudioQuality::Flac);
+        assert_eq!(parsed.bit_depth, Some(16));
+        assert_eq!(parsed.sample_rate_hz, Some(44_100));
+    }
+
+    #[test]
+    fn ranks_hi_res_above_cd_flac_above_mp3() {
+        let releases = vec![
+            parse_release_title("Artist - Album 320kbps MP3-GRP1"),
+            parse_release_title("Artist - Album [FLAC]-GRP2"),
+            parse_release_title("Artist - Album FLAC 24-96"),
+        ];
+
+        let ranked = rank_releases(releases, &ReleaseFilterOptions::default());
+        let qualities: Vec<AudioQuality> = ranked.into_iter().map(|r| r.quality).collect();
+        assert_eq!(
+            qualities,
+            vec![
+                AudioQuality::FlacHiRes,
+                AudioQuality::Flac,
+                AudioQuality::Mp3
+            ]
+        );
+    }
+
+    #[test]
+    fn deluxe_and_standard_editions_are_not_deduped() {
+        let releases = vec![
+            parse_release_title("Artist - Album [FLAC]-GRP1"),
+            parse_release_title("Artist - Album (Deluxe Edition) [FLAC]-GRP2"),
+        ];
+
+        assert_eq!(releases[0].edition, None);
+        assert_eq!(releases[1].edition.as_deref(), Some("Deluxe Edition"));
+        assert!(find_duplicate_keys(&releases).is_empty());
+        assert_eq!(deduplicate_releases(&releases).len(), 2);
+    }
+
+    #[test]
+    fn captures_remaster_year_in_edition() {
+        let parsed = parse_release_title("Artist - Album (2011 Remaster) [FLAC]-GRP");
+
+        assert_eq!(parsed.album.as_deref(), Some("Album"));
+        assert_eq!(parsed.edition.as_deref(), Some("2011 Remaster"));
+    }
+
+    #[test]
+    fn edition_words_outside_brackets_are_part_of_the_album() {
+        for title in [
+            "Artist - Special Brew [FLAC]-GRP",
+            "Artist - Limited Time 320kbps MP3-GRP",
+            "Artist - Legacy FLAC-GRP",
+        ] {
+            assert_eq!(parse_release_title(title).edition, None, "{title}");
+        }
+
+        let parsed = parse_release_title("Artist - Legacy [Deluxe Edition] [FLAC]-GRP");
+        assert_eq!(parsed.album.as_deref(), Some("Legacy"));
+        assert_eq!(parsed.edition.as_deref(), Some("Deluxe Edition"));
+    }
+
+    #[test]
+    fn same_edition_still_dedupes() {
+        let releases = vec![
+            parse_release_title("Artist - Album (2011 Remaster) [FLAC]-GRP1"),
+            parse_release_title("Artist - Album (2011 remaster) [FLAC]-GRP2"),
+            parse_release_title("Artist - Album 320kbps MP3-GRP3"),
+            parse_release_title("Artist - Album 192kbps MP3-GRP4"),
+        ];
+
+        let keys = find_duplicate_keys(&releases);
+        assert_eq!(
+            keys,
+            vec!["artist|album|flac|2011 remaster", "artist|album|mp3"]
+        );
+        assert_eq!(deduplicate_releases(&releases).len(), 2);
+    }
+
+    #[test]
+    fn same_release_on_two_trackers_collapses_by_content() {
+        let releases = vec![
+            parse_release_title("Artist - Album [FLAC]-GRP").with_indexer("Tracker A"),
+            parse_release_title("Artist - Album [FLAC]-GRP").with_indexer("Tracker B"),
+        ];
+
+        assert_eq!(
+            find_duplicate_keys_with_strategy(&releases, DedupeStrategy::ByContent),
+            vec!["artist|album|flac"]
+        );
+        let deduped = deduplicate_releases_with_strategy(&releases, DedupeStrategy::ByContent);
+        assert_eq!(deduped.len(), 1);
+        assert_eq!(deduped[0].indexer.as_deref(), Some("Tracker A"));
+        assert_eq!(deduplicate_releases(&releases), deduped);
+    }
+
+    #[test]
+    fn same_release_on_two_trackers_kept_by_content_and_tracker() {
+        let releases = vec![
+            parse_release_title("Artist - Album [FLAC]-GRP1").with_indexer("Tracker A"),
+            parse_release_title("Artist - Album [FLAC]-GRP2").with_indexer("Tracker B"),
+            parse_release_title("Artist - Album [FLAC]-GRP3").with_indexer("tracker b"),
+        ];
+
+        let keys =
+            find_duplicate_keys_with_strategy(&releases, DedupeStrategy::ByContentAndTracker);
+        assert_eq!(keys, vec!["artist|album|flac@tracker b"]);
+        let deduped =
+            deduplicate_releases_with_strategy(&releases, DedupeStrategy::ByContentAndTracker);
+        let mut indexers: Vec<_> = deduped
+            .iter()
+            .map(|release| release.indexer.as_deref().unwrap())
+            .collect();
+        indexers.sort();
+        assert_eq!(indexers, vec!["Tracker A", "Tracker B"]);
+    }
+
+    #[test]
+    fn keep_all_never_collapses() {
+        let releases = vec![
+            parse_release_title("Artist - Album [FLAC]-GRP2").with_indexer("Tracker A"),
+            parse_release_title("Artist - Album [FLAC]-GRP1").with_indexer("Tracker A"),
+        ];
+
+        assert!(find_duplicate_keys_with_strategy(&releases, DedupeStrategy::KeepAll).is_empty());
+        let kept = deduplicate_releases_with_strategy(&releases, DedupeStrategy::KeepAll);
+        assert_eq!(
+            kept.iter()
+                .map(|release| release.original_title.as_str())
+                .collect::<Vec<_>>(),
+            vec!["Artist - Album [FLAC]-GRP1", "Artist - Album [FLAC]-GRP2"]
+        );
+    }
+
+    #[test]
+    fn parses_scene_group_after_format_token() {
+        let parsed = parse_release_title("Artist - Album 2024 FLAC-GROUP");
+
+        assert_eq!(parsed.artist.as_deref(), Some("Artist"));
+        assert_eq!(parsed.album.as_deref(), Some("Album 2024"));
+        assert_eq!(parsed.release_group.as_deref(), Some("GROUP"));
+    }
+
+    #[test]
+    fn parses_dotted_scene_title_group() {
+        let parsed = parse_release_title("Artist-Album-WEB-2024-PERFECT");
+
+        assert_eq!(parsed.release_group.as_deref(), Some("PERFECT"));
+    }
+
+    #[test]
+    fn parses_p2p_group_in_braces() {
+        let parsed = parse_release_title("Artist - Album (2024) [FLAC] {RLSGRP}");
+
+        assert_eq!(parsed.artist.as_deref(), Some("Artist"));
+        assert_eq!(parsed.album.as_deref(), Some("Album"));
+        assert_eq!(parsed.release_group.as_deref(), Some("RLSGRP"));
+    }
+
+    #[test]
+    fn title_without_group_has_no_release_group() {
+        let parsed = parse_release_title("Artist - Album (2024) [FLAC]");
+
+        assert_eq!(parsed.album.as_deref(), Some("Album"));
+        assert_eq!(parsed.release_group, None);
+    }
+
+    #[test]
+    fn hyphenated_artist_and_album_are_not_read_as_group() {
+        let parsed = parse_release_title("AC-DC - Back-In-Black");
+
+        assert_eq!(parsed.artist.as_deref(), Some("AC-DC"));
+        assert_eq!(parsed.album.as_deref(), Some("Back-In-Black"));
+        assert_eq!(parsed.release_group, None);
+
+        let parsed = parse_release_title("Jay-Z - The Blueprint FLAC-GRP");
+        assert_eq!(parsed.artist.as_deref(), Some("Jay-Z"));
+        assert_eq!(parsed.album.as_deref(), Some("The Blueprint"));
+        assert_eq!(parsed.release_group.as_deref(), Some("GRP"));
+    }
 }
@@ -179,9 +179,16 @@ mod tests {
             original_title: title.to_string(),
             artist: artist.map(|s| s.to_string()),
             album: album.map(|s| s.to_string()),
+            edition: None,
             quality,
             bitrate_kbps: bitrate,
+            bit_depth: None,
+            sample_rate_hz: None,
             release_group: group.map(|s| s.to_string()),
+            size_bytes: None,
+            indexer: None,
+            likely_unofficial: false,
+            torrent: None,
         }
     }
 
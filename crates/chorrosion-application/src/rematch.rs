@@ -0,0 +1,385 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+//! Re-run matching for a track file that is already in the library.
+//!
+//! [`TrackFileRematchService`] re-reads a file's fingerprint and embedded tags
+//! through the [`PrecedenceMatchingEngine`] and moves the file to the track
+//! whose `musicbrainz_recording_id` matches, but only when the new match is
+//! more confident than the one recorded on the file's current track.
+
+use crate::matching_precedence::{
+    MatchingStrategy, PrecedenceMatchingEngine, PrecedenceMatchingError,
+};
+use chorrosion_domain::{
+    DomainEvent, TrackFile, TrackFileId, TrackFileRematched, TrackFileRematchedPayload, TrackId,
+};
+use chorrosion_infrastructure::repositories::{TrackFileRepository, TrackRepository};
+use std::sync::Arc;
+use thiserror::Error;
+use tracing::{debug, info};
+
+/// Errors that can occur while re-matching a track file.
+#[derive(Debug, Error)]
+pub enum RematchError {
+    #[error("Track file not found: {0}")]
+    TrackFileNotFound(TrackFileId),
+
+    #[error("Matching failed: {0}")]
+    Matching(#[from] PrecedenceMatchingError),
+
+    #[error("Repository error: {0}")]
+    Repository(#[from] anyhow::Error),
+}
+
+pub type RematchResult<T> = Result<T, RematchError>;
+
+/// A track association and the confidence recorded for it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackMatch {
+    pub track_id: TrackId,
+    pub confidence: Option<f32>,
+}
+
+/// Result of re-matching one track file.
+#[derive(Debug, Clone)]
+pub struct RematchOutcome {
+    pub track_file: TrackFile,
+    /// The association before the rematch.
+    pub previous: TrackMatch,
+    /// The association after the rematch; equal to `previous` when unchanged.
+    pub current: TrackMatch,
+    /// Strategy that produced the candidate match, if any.
+    pub strategy: Option<MatchingStrategy>,
+    /// Recording ID of the candidate match, if any.
+    pub musicbrainz_recording_id: Option<String>,
+    /// Whether the file was moved to a different track.
+    pub changed: bool,
+}
+
+impl RematchOutcome {
+    /// `track.file.rematched` event describing the move, if the file moved.
+    pub fn rematched_event(&self) -> Option<TrackFileRematched> {
+        if !self.changed {
+            return None;
+        }
+        Some(DomainEvent::new(
+            "track.file.rematched",
+            TrackFileRematchedPayload {
+                track_id: self.current.track_id,
+                previous_track_id: self.previous.track_id,
+                track_file_id: self.track_file.id,
+                path: self.track_file.path.clone(),
+                confidence: self.current.confidence.unwrap_or_default(),
+            },
+        ))
+    }
+}
+
+/// Re-runs precedence matching for stored track files.
+pub struct TrackFileRematchService {
+    engine: Arc<PrecedenceMatchingEngine>,
+    track_files: Arc<dyn TrackFileRepository>,
+    tracks: Arc<dyn TrackRepository>,
+    min_confidence: f32,
+}
+
+impl TrackFileRematchService {
+    pub fn new(
+        engine: Arc<PrecedenceMatchingEngine>,
+        track_files: Arc<dyn TrackFileRepository>,
+        tracks: Arc<dyn TrackRepository>,
+    ) -> Self {
+        Self {
+            engine,
+            track_files,
+            tracks,
+            min_confidence: 0.5,
+        }
+    }
+
+    /// Ignore candidate matches below `min_confidence` (0.0-1.0, default 0.5).
+    pub fn with_min_confidence(mut self, min_confidence: f32) -> Self {
+        self.min_confidence = min_confidence;
+        self
+    }
+
+    /// Re-match the track file `track_file_id`.
+    ///
+    /// The file moves to the track carrying the matched recording ID when the
+    /// match confidence beats the confidence recorded on its current track (a
+    /// track without one, or a missing track, is always beaten). The new track
+    /// is marked as having a file and the old one is cleared once no other
+    /// file references it. Otherwise nothing is written.
+    pub async fn rematch_track_file(
+        &self,
+        track_file_id: TrackFileId,
+    ) -> RematchResult<RematchOutcome> {
+        let mut track_file = self
+            .track_files
+            .get_by_id(&track_file_id.to_string())
+            .await?
+            .ok_or(RematchError::TrackFileNotFound(track_file_id))?;
+
+        let current_track = self
+            .tracks
+            .get_by_id(&track_file.track_id.to_string())
+            .await?;
+        let previous = TrackMatch {
+            track_id: track_file.track_id,
+            confidence: current_track.as_ref().and_then(|t| t.match_confidence),
+        };
+
+        let candidate = match self
+            .engine
+            .match_with_precedence(&track_file, self.min_confidence, None, None)
+            .await
+        {
+            Ok(candidate) => candidate,
+            Err(PrecedenceMatchingError::AllStrategiesFailed) => {
+                debug!(target: "application", %track_file_id, "rematch found no candidate");
+                return Ok(unchanged(track_file, previous, None, None));
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        let strategy = Some(candidate.strategy);
+        let recording_id = Some(candidate.musicbrainz_recording_id.clone());
+
+        let Some(mut target) = self
+            .tracks
+            .get_by_musicbrainz_recording_id(&candidate.musicbrainz_recording_id)
+            .await?
+        else {
+            debug!(
+                target: "application",
+                %track_file_id,
+                mbid = %candidate.musicbrainz_recording_id,
+                "matched recording is not in the library"
+            );
+            return Ok(unchanged(track_file, previous, strategy, recording_id));
+        };
+
+        let is_better = current_track.is_none()
+            || previous
+                .confidence
+                .is_none_or(|confidence| candidate.confidence > confidence);
+        if target.id == track_file.track_id || !is_better {
+            return Ok(unchanged(track_file, previous, strategy, recording_id));
+        }
+
+        track_file.track_id = target.id;
+        let track_file = self.track_files.update(track_file).await?;
+
+        target.has_file = true;
+        target.match_confidence = Some(candidate.confidence);
+        self.tracks.update(target).await?;
+
+        if let Some(mut old_track) = current_track {
+            let remaining = self.track_files.get_by_track(old_track.id, 1, 0).await?;
+            if remaining.is_empty() && old_track.has_file {
+                old_track.has_file = false;
+                self.tracks.update(old_track).await?;
+            }
+        }
+
+        info!(
+            target: "application",
+            %track_file_id,
+            previous_track_id = %previous.track_id,
+            track_id = %track_file.track_id,
+            confidence = candidate.confidence,
+            "rematched track file"
+        );
+
+        Ok(RematchOutcome {
+            current: TrackMatch {
+                track_id: track_file.track_id,
+                confidence: Some(candidate.confidence),
+            },
+            track_file,
+            previous,
+            strategy,
+            musicbrainz_recording_id: recording_id,
+            changed: true,
+        })
+    }
+}
+
+fn unchanged(
+    track_file: TrackFile,
+    previous: TrackMatch,
+    strategy: Option<MatchingStrategy>,
+    musicbrainz_recording_id: Option<String>,
+) -> RematchOutcome {
+    RematchOutcome {
+        track_file,
+        current: previous.clone(),
+        previous,
+        strategy,
+        musicbrainz_recording_id,
+        changed: false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::embedded_tags::EmbeddedTagMatchingService;
+    use crate::filename_heuristics::FilenameHeuristicsService;
+    use crate::matching::TrackMatchingService;
+    use crate::test_fixtures::minimal_opus;
+    use chorrosion_config::AppConfig;
+    use chorrosion_domain::{Album, Artist, Track};
+    use chorrosion_fingerprint::AcoustidClient;
+    use chorrosion_infrastructure::init_database;
+    use chorrosion_infrastructure::repositories::Repository;
+    use chorrosion_infrastructure::sqlite_adapters::{
+        SqliteAlbumRepository, SqliteArtistRepository, SqliteTrackFileRepository,
+        SqliteTrackRepository,
+    };
+    use tempfile::TempDir;
+
+    const RECORDING_ID: &str = "6f1c1a3e-52a4-4c1e-9a56-0f3a2f8b9d11";
+
+    struct Fixture {
+        service: TrackFileRematchService,
+        track_files: Arc<SqliteTrackFileRepository>,
+        tracks: Arc<SqliteTrackRepository>,
+        old_track: TrackId,
+        better_track: TrackId,
+        track_file_id: TrackFileId,
+        _dir: TempDir,
+    }
+
+    /// A file tagged with [`RECORDING_ID`] attached to a track recorded with
+    /// `old_confidence`, plus the track that carries that recording ID.
+    async fn fixture(old_confidence: f32) -> Fixture {
+        let mut config = AppConfig::default();
+        config.database.url = "sqlite::memory:".to_string();
+        config.database.pool_max_size = 1;
+        let pool = init_database(&config).await.expect("in-memory database");
+        let artists = SqliteArtistRepository::new(pool.clone());
+        let albums = SqliteAlbumRepository::new(pool.clone());
+        let tracks = Arc::new(SqliteTrackRepository::new(pool.clone()));
+        let track_files = Arc::new(SqliteTrackFileRepository::new(pool));
+
+        let artist = artists.create(Artist::new("Artist")).await.unwrap();
+        let album = albums.create(Album::new(artist.id, "Album")).await.unwrap();
+
+        // `create` does not store match results, so they are written with `update`.
+        let mut old = tracks
+            .create(Track::new(album.id, artist.id, "Wrong Song"))
+            .await
+            .unwrap();
+        old.has_file = true;
+        old.match_confidence = Some(old_confidence);
+        let old = tracks.update(old).await.unwrap();
+        let mut better = tracks
+            .create(Track::new(album.id, artist.id, "Right Song"))
+            .await
+            .unwrap();
+        better.musicbrainz_recording_id = Some(RECORDING_ID.to_string());
+        let better = tracks.update(better).await.unwrap();
+
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("01 - Right Song.opus");
+        std::fs::write(
+            &path,
+            minimal_opus(&[("MUSICBRAINZ_TRACKID", RECORDING_ID)]),
+        )
+        .unwrap();
+        let track_file = track_files
+            .create(TrackFile::new(old.id, path.display().to_string(), 128))
+            .await
+            .unwrap();
+
+        let engine = PrecedenceMatchingEngine::new(
+            TrackMatchingService::new(AcoustidClient::new("test_key".to_string()).unwrap()),
+            EmbeddedTagMatchingService,
+            FilenameHeuristicsService,
+        );
+        let service =
+            TrackFileRematchService::new(Arc::new(engine), track_files.clone(), tracks.clone());
+
+        Fixture {
+            service,
+            track_files,
+            tracks,
+            old_track: old.id,
+            better_track: better.id,
+            track_file_id: track_file.id,
+            _dir: dir,
+        }
+    }
+
+    #[tokio::test]
+    async fn rematch_moves_file_to_better_matching_track() {
+        let f = fixture(0.4).await;
+
+        let outcome = f.service.rematch_track_file(f.track_file_id).await.unwrap();
+
+        assert!(outcome.changed);
+        assert_eq!(outcome.previous.track_id, f.old_track);
+        assert_eq!(outcome.previous.confidence, Some(0.4));
+        assert_eq!(outcome.current.track_id, f.better_track);
+        assert_eq!(outcome.strategy, Some(MatchingStrategy::EmbeddedTags));
+        assert!(outcome.rematched_event().is_some());
+
+        let stored = f
+            .track_files
+            .get_by_id(&f.track_file_id.to_string())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.track_id, f.better_track);
+        let better = f
+            .tracks
+            .get_by_id(&f.better_track.to_string())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(better.has_file);
+        assert_eq!(better.match_confidence, outcome.current.confidence);
+        let old = f
+            .tracks
+            .get_by_id(&f.old_track.to_string())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!old.has_file);
+    }
+
+    #[tokio::test]
+    async fn rematch_leaves_file_when_new_match_is_worse() {
+        let f = fixture(0.99).await;
+
+        let outcome = f.service.rematch_track_file(f.track_file_id).await.unwrap();
+
+        assert!(!outcome.changed);
+        assert_eq!(outcome.current, outcome.previous);
+        assert_eq!(
+            outcome.musicbrainz_recording_id.as_deref(),
+            Some(RECORDING_ID)
+        );
+        assert!(outcome.rematched_event().is_none());
+        let stored = f
+            .track_files
+            .get_by_id(&f.track_file_id.to_string())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.track_id, f.old_track);
+    }
+
+    #[tokio::test]
+    async fn rematch_unknown_track_file_is_not_found() {
+        let f = fixture(0.4).await;
+
+        let err = f
+            .service
+            .rematch_track_file(TrackFileId::new())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, RematchError::TrackFileNotFound(_)));
+    }
+}
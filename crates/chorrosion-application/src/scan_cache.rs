@@ -9,7 +9,9 @@
 //! [`cached_scan_audio_files`] is a drop-in wrapper around the bare [`scan_audio_files`]
 //! function that checks and populates the cache automatically.
 
-use crate::import_matching::{scan_audio_files, ImportMatchingError, ScannedAudioFile};
+use crate::import_matching::{
+    scan_audio_files, AudioExtensions, ImportMatchingError, ScannedAudioFile,
+};
 use moka::sync::Cache;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -71,8 +73,11 @@ impl Default for DirScanCache {
 /// On a cache miss the directory tree is walked normally and the results are stored
 /// for future calls within the TTL window.  Pass a `&DirScanCache` obtained from
 /// [`AppState`][crate::AppState] so all callers share the same cache.
+/// Entries are keyed on the root alone, so every caller sharing a cache must
+/// pass the same `extensions`.
 pub fn cached_scan_audio_files(
     root: impl AsRef<Path>,
+    extensions: &AudioExtensions,
     cache: &DirScanCache,
 ) -> Result<Arc<Vec<ScannedAudioFile>>, ImportMatchingError> {
     let root = root.as_ref();
@@ -84,7 +89,7 @@ pub fn cached_scan_audio_files(
     }
 
     debug!(target: "cache", path = %root.display(), "directory scan cache MISS");
-    let files = scan_audio_files(root)?;
+    let files = scan_audio_files(root, extensions)?;
     let arc = Arc::new(files);
     cache.insert(key, arc.clone());
     Ok(arc)
@@ -106,8 +111,10 @@ mod tests {
         let dir = make_temp_dir_with_mp3();
         let cache = DirScanCache::new();
 
-        let first = cached_scan_audio_files(dir.path(), &cache).expect("first scan ok");
-        let second = cached_scan_audio_files(dir.path(), &cache).expect("second scan ok");
+        let first = cached_scan_audio_files(dir.path(), &AudioExtensions::default(), &cache)
+            .expect("first scan ok");
+        let second = cached_scan_audio_files(dir.path(), &AudioExtensions::default(), &cache)
+            .expect("second scan ok");
 
         assert_eq!(first.len(), second.len());
         // Both calls return the same Arc — pointer equality confirms cache hit.
@@ -119,9 +126,11 @@ mod tests {
         let dir = make_temp_dir_with_mp3();
         let cache = DirScanCache::new();
 
-        let first = cached_scan_audio_files(dir.path(), &cache).expect("first ok");
+        let first = cached_scan_audio_files(dir.path(), &AudioExtensions::default(), &cache)
+            .expect("first ok");
         cache.invalidate(dir.path());
-        let second = cached_scan_audio_files(dir.path(), &cache).expect("second ok");
+        let second = cached_scan_audio_files(dir.path(), &AudioExtensions::default(), &cache)
+            .expect("second ok");
 
         // After invalidation a fresh scan is performed — different Arc, same content.
         assert!(!Arc::ptr_eq(&first, &second));
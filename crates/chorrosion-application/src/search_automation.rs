@@ -10,19 +10,30 @@
 //! - **Automatic search** ([`automatic_search_missing_albums`]): library-driven search that
 //!   accepts a list of [`AlbumSearchTarget`]s, skips albums already owned, and for each missing
 //!   album queries the indexer and returns the best-ranked release as an
-//!   [`AutomaticSearchDecision`].
+//!   [`AutomaticSearchDecision`]. Releases younger than the [`DelayProfile`] minimum age are
+//!   deferred rather than grabbed.
 //!
-//! Both flows share the `filter → dedupe → rank` pipeline from [`crate::release_parsing`].
+//! Both flows share the `filter → dedupe → rank` pipeline from [`crate::release_parsing`],
+//! after [`deduplicate_search_results`] has collapsed the same release posted several times.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
 
+use chorrosion_domain::TrackFile;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use tracing::debug;
 
-use crate::indexers::{IndexerClient, IndexerError, IndexerSearchQuery, IndexerSearchResult};
+use crate::delay_profile::DelayProfile;
+use crate::download_clients::{DownloadItem, DownloadState};
+use crate::indexers::{
+    IndexerClient, IndexerConfig, IndexerError, IndexerProtocol, IndexerSearchQuery,
+    IndexerSearchResult,
+};
+use crate::quality_upgrade::QualityComparer;
 use crate::release_parsing::{
-    deduplicate_releases, filter_releases, parse_release_title, rank_releases, ParsedReleaseTitle,
-    ReleaseFilterOptions,
+    deduplicate_releases_with_strategy, filter_releases, parse_release_title, rank_releases,
+    score_release, ParsedReleaseTitle, ReleaseFilterOptions, TorrentHealth,
 };
 
 /// Parameters for a manually initiated search against an indexer.
@@ -54,6 +65,8 @@ pub struct RankedRelease {
     pub parsed: ParsedReleaseTitle,
     /// The original search result as returned by the indexer client.
     pub search_result: IndexerSearchResult,
+    /// Ranking score from [`score_release`]; results are sorted by it, highest first.
+    pub score: i32,
 }
 
 /// A specific album that the system should search for automatically.
@@ -71,19 +84,179 @@ pub struct AlbumSearchTarget {
     ///
     /// Targets marked as already owned are skipped by automated searches.
     pub already_owned: bool,
+    /// Whether a download for this album is already queued or in progress.
+    ///
+    /// Targets marked as downloading are skipped by automated searches.
+    #[serde(default)]
+    pub already_downloading: bool,
+    /// Minimum [`RankedRelease::score`] the best release must reach to be grabbed.
+    #[serde(default)]
+    pub cutoff_score: Option<i32>,
+}
+
+/// Words dropped from album queries unless a caller supplies its own list.
+///
+/// Edition markers rarely appear in release names the way MusicBrainz spells
+/// them, so keeping them only narrows the indexer's full-text match.
+pub const DEFAULT_QUERY_NOISE_WORDS: &[&str] =
+    &["deluxe", "edition", "expanded", "remaster", "remastered"];
+
+/// How [`build_album_query`] turns an artist and album title into an indexer query.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueryBuildOptions {
+    /// Release year appended to the query; `None` leaves the year out.
+    #[serde(default)]
+    pub year: Option<i32>,
+    /// Whole words removed from the query, compared case-insensitively.
+    #[serde(default)]
+    pub noise_words: Vec<String>,
+}
+
+impl Default for QueryBuildOptions {
+    fn default() -> Self {
+        Self {
+            year: None,
+            noise_words: DEFAULT_QUERY_NOISE_WORDS
+                .iter()
+                .map(|word| word.to_string())
+                .collect(),
+        }
+    }
+}
+
+impl QueryBuildOptions {
+    /// Append `year` to built queries.
+    pub fn with_year(mut self, year: Option<i32>) -> Self {
+        self.year = year;
+        self
+    }
+}
+
+/// Why an automatic search did or did not pick a release for an album.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "reason", rename_all = "snake_case")]
+pub enum AutomaticSearchReason {
+    /// The album is already in the library, so it was not searched.
+    AlreadyOwned,
+    /// A download for the album is already queued or in progress.
+    AlreadyDownloading,
+    /// The album's artist is not monitored.
+    Unmonitored,
+    /// No release survived filtering on any indexer.
+    NoResults,
+    /// The best release scored below the target's cutoff.
+    BelowCutoff {
+        release: RankedRelease,
+        cutoff_score: i32,
+    },
+    /// Every acceptable release is younger than the delay profile allows; the
+    /// best of them becomes eligible at `available_at`.
+    Deferred {
+        release: RankedRelease,
+        available_at: DateTime<Utc>,
+    },
+    /// The release was picked for download.
+    Grabbed { release: RankedRelease },
+    /// The release was picked but the download client rejected it.
+    GrabFailed {
+        release: RankedRelease,
+        error: String,
+    },
+}
+
+impl AutomaticSearchReason {
+    /// Stable snake_case name of the variant, matching its serialized `reason` tag.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::AlreadyOwned => "already_owned",
+            Self::AlreadyDownloading => "already_downloading",
+            Self::Unmonitored => "unmonitored",
+            Self::NoResults => "no_results",
+            Self::BelowCutoff { .. } => "below_cutoff",
+            Self::Deferred { .. } => "deferred",
+            Self::Grabbed { .. } => "grabbed",
+            Self::GrabFailed { .. } => "grab_failed",
+        }
+    }
+
+    /// The best release considered for this decision, if the album was searched
+    /// and anything was found.
+    pub fn release(&self) -> Option<&RankedRelease> {
+        match self {
+            Self::BelowCutoff { release, .. }
+            | Self::Deferred { release, .. }
+            | Self::Grabbed { release }
+            | Self::GrabFailed { release, .. } => Some(release),
+            _ => None,
+        }
+    }
 }
 
 /// The outcome of running an automatic search for a single album target.
 ///
-/// Contains the original [`AlbumSearchTarget`] and, if any were found, the
-/// highest-ranked release that matched that target.
+/// Contains the original [`AlbumSearchTarget`] and the structured reason the
+/// album was or wasn't grabbed.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct AutomaticSearchDecision {
     /// The album that was evaluated by the automated search.
     pub target: AlbumSearchTarget,
-    /// The best-ranked release candidate found for this target, or `None` if
-    /// nothing suitable was found.
-    pub best_release: Option<RankedRelease>,
+    /// Why the album was grabbed, skipped, or left without a release.
+    pub reason: AutomaticSearchReason,
+}
+
+impl AutomaticSearchDecision {
+    /// The release picked for download, if the decision was to grab one.
+    pub fn best_release(&self) -> Option<&RankedRelease> {
+        match &self.reason {
+            AutomaticSearchReason::Grabbed { release } => Some(release),
+            _ => None,
+        }
+    }
+}
+
+/// The most recent automatic search decision recorded for an album.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlbumSearchStatus {
+    pub reason: AutomaticSearchReason,
+    pub decided_at: DateTime<Utc>,
+}
+
+/// In-memory record of the last automatic search decision per album ID.
+///
+/// Shared between the scheduler, which records decisions, and the API, which
+/// reports them.
+#[derive(Clone, Debug, Default)]
+pub struct SearchDecisionStore {
+    inner: Arc<Mutex<HashMap<String, AlbumSearchStatus>>>,
+}
+
+impl SearchDecisionStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record `reason` as the latest decision for `album_id`, replacing any earlier one.
+    pub fn record(&self, album_id: impl Into<String>, reason: AutomaticSearchReason) {
+        self.inner
+            .lock()
+            .expect("search decision store lock")
+            .insert(
+                album_id.into(),
+                AlbumSearchStatus {
+                    reason,
+                    decided_at: Utc::now(),
+                },
+            );
+    }
+
+    /// The latest decision recorded for `album_id`, if it has been searched.
+    pub fn get(&self, album_id: &str) -> Option<AlbumSearchStatus> {
+        self.inner
+            .lock()
+            .expect("search decision store lock")
+            .get(album_id)
+            .cloned()
+    }
 }
 
 /// Execute a user-driven search against an indexer and return ranked results.
@@ -102,7 +275,7 @@ pub struct AutomaticSearchDecision {
 ///
 /// * `Ok(Vec<RankedRelease>)` – Ranked list of matching releases (may be empty).
 /// * `Err(IndexerError)` – Query could not be built or the indexer returned an error.
-pub async fn manual_search<I: IndexerClient>(
+pub async fn manual_search<I: IndexerClient + ?Sized>(
     indexer: &I,
     request: &ManualSearchRequest,
     options: &ReleaseFilterOptions,
@@ -123,41 +296,63 @@ pub async fn manual_search<I: IndexerClient>(
         })
         .await?;
 
-    Ok(rank_results(raw_results, options))
+    Ok(rank_results(raw_results, options, indexer.config()))
 }
 
 /// Search for all missing albums in `targets` and return one decision per target.
 ///
-/// Albums marked as `already_owned` are skipped. For each remaining target, the
-/// indexer is queried using `"<artist> <album>"` and the top-ranked result is
-/// selected as the best release candidate.
+/// Albums marked as `already_owned` or `already_downloading` are skipped without
+/// querying the indexer. For each remaining target, the indexer is queried using
+/// `"<artist> <album>"` and the top-ranked result old enough for `delay_profile`
+/// is grabbed unless it scores below the target's `cutoff_score`. When every
+/// result is still too new, the album is reported as
+/// [`AutomaticSearchReason::Deferred`] so a later run can pick it up.
 ///
 /// # Arguments
 ///
 /// * `indexer` – The indexer client to query.
 /// * `targets` – Slice of album targets to evaluate.
 /// * `options` – Filter and ranking preferences applied to each search.
+/// * `delay_profile` – Minimum release age before a result may be grabbed.
 ///
 /// # Returns
 ///
-/// * `Ok(Vec<AutomaticSearchDecision>)` – One decision per missing target (may be empty).
+/// * `Ok(Vec<AutomaticSearchDecision>)` – One decision per target, in input order.
 /// * `Err(IndexerError)` – The indexer returned an error for one of the queries.
-pub async fn automatic_search_missing_albums<I: IndexerClient>(
+pub async fn automatic_search_missing_albums<I: IndexerClient + ?Sized>(
     indexer: &I,
     targets: &[AlbumSearchTarget],
     options: &ReleaseFilterOptions,
+    delay_profile: &DelayProfile,
 ) -> Result<Vec<AutomaticSearchDecision>, IndexerError> {
-    let missing_targets = detect_missing_albums(targets);
+    let config = indexer.config();
+    let protocol = &config.protocol;
     debug!(
         target: "search_automation",
         indexer = %indexer.config().name,
-        missing_count = missing_targets.len(),
+        target_count = targets.len(),
+        missing_count = targets.iter().filter(|target| !target.already_owned).count(),
         "starting automatic search for missing albums"
     );
 
-    let mut decisions = Vec::with_capacity(missing_targets.len());
-    for target in missing_targets {
-        let query = format!("{} {}", &target.artist, &target.album);
+    let mut decisions = Vec::with_capacity(targets.len());
+    for target in targets.iter().cloned() {
+        if target.already_owned {
+            decisions.push(AutomaticSearchDecision {
+                target,
+                reason: AutomaticSearchReason::AlreadyOwned,
+            });
+            continue;
+        }
+        if target.already_downloading {
+            decisions.push(AutomaticSearchDecision {
+                target,
+                reason: AutomaticSearchReason::AlreadyDownloading,
+            });
+            continue;
+        }
+
+        let query = build_album_query(&target.artist, &target.album, &QueryBuildOptions::default());
         debug!(
             target: "search_automation",
             artist = %target.artist,
@@ -174,20 +369,49 @@ pub async fn automatic_search_missing_albums<I: IndexerClient>(
             })
             .await?;
 
-        let ranked = rank_results(raw_results, options);
-        let best_release = ranked.into_iter().next();
+        let now = Utc::now();
+        let mut deferred = None;
+        let mut ready = None;
+        for release in rank_results(raw_results, options, config) {
+            let published_at = release.search_result.published_at.as_deref();
+            match delay_profile.deferred_until(protocol, published_at, now) {
+                None => {
+                    ready = Some(release);
+                    break;
+                }
+                Some(available_at) => {
+                    deferred.get_or_insert((release, available_at));
+                }
+            }
+        }
+
+        let reason = match ready {
+            None => match deferred {
+                Some((release, available_at)) => AutomaticSearchReason::Deferred {
+                    release,
+                    available_at,
+                },
+                None => AutomaticSearchReason::NoResults,
+            },
+            Some(release) => match target.cutoff_score {
+                Some(cutoff_score) if release.score < cutoff_score => {
+                    AutomaticSearchReason::BelowCutoff {
+                        release,
+                        cutoff_score,
+                    }
+                }
+                _ => AutomaticSearchReason::Grabbed { release },
+            },
+        };
         debug!(
             target: "search_automation",
             artist = %target.artist,
             album = %target.album,
-            found = best_release.is_some(),
+            reason = reason.as_str(),
             "automatic search decision made"
         );
 
-        decisions.push(AutomaticSearchDecision {
-            target,
-            best_release,
-        });
+        decisions.push(AutomaticSearchDecision { target, reason });
     }
 
     Ok(decisions)
@@ -211,6 +435,118 @@ pub fn detect_missing_albums(targets: &[AlbumSearchTarget]) -> Vec<AlbumSearchTa
         .collect()
 }
 
+/// Search target for `album` by `artist`, given the files of each of its monitored tracks
+/// and the [`active_download_album_keys`] of the download queue.
+///
+/// The album is owned once every monitored track has a file meeting the cutoff of
+/// `options.quality_profile`. An album whose files fall short of the cutoff is searched
+/// again, grabbing only releases that score at least as well as one at the cutoff quality.
+pub fn album_search_target(
+    artist: &str,
+    album: &str,
+    track_files: &[Vec<TrackFile>],
+    active_downloads: &HashSet<(String, String)>,
+    options: &ReleaseFilterOptions,
+) -> AlbumSearchTarget {
+    let has_files = !track_files.is_empty() && track_files.iter().all(|files| !files.is_empty());
+    let cutoff = options.quality_profile.as_ref().and_then(|profile| {
+        profile
+            .cutoff_quality
+            .as_deref()
+            .map(|cutoff| (profile, cutoff))
+    });
+    let below_cutoff = has_files
+        && cutoff.is_some_and(|(profile, _)| {
+            track_files.iter().any(|files| {
+                !files.iter().any(|file| {
+                    file.quality
+                        .as_deref()
+                        .is_some_and(|quality| QualityComparer::meets_cutoff(quality, profile))
+                })
+            })
+        });
+    let cutoff_score = cutoff.filter(|_| below_cutoff).map(|(_, cutoff)| {
+        score_release(
+            &parse_release_title(&format!("{artist} - {album} [{cutoff}]")),
+            options,
+        )
+    });
+
+    AlbumSearchTarget {
+        artist: artist.to_string(),
+        album: album.to_string(),
+        already_owned: has_files && !below_cutoff,
+        already_downloading: active_downloads.contains(&release_match_key(artist, album)),
+        cutoff_score,
+    }
+}
+
+/// Case- and whitespace-insensitive key matching an album to releases and downloads.
+pub fn release_match_key(artist: &str, album: &str) -> (String, String) {
+    (artist.trim().to_lowercase(), album.trim().to_lowercase())
+}
+
+/// The [`release_match_key`] of a parsed release title, if both artist and album were recognized.
+pub fn parsed_release_key(parsed: &ParsedReleaseTitle) -> Option<(String, String)> {
+    Some(release_match_key(
+        parsed.artist.as_deref()?,
+        parsed.album.as_deref()?,
+    ))
+}
+
+/// Artist and album keys of downloads that are still queued or in progress.
+pub fn active_download_album_keys<'a>(
+    downloads: impl IntoIterator<Item = &'a DownloadItem>,
+) -> HashSet<(String, String)> {
+    downloads
+        .into_iter()
+        .filter(|item| {
+            matches!(
+                item.state,
+                DownloadState::Queued | DownloadState::Downloading | DownloadState::Paused
+            )
+        })
+        .filter_map(|item| parsed_release_key(&parse_release_title(&item.name)))
+        .collect()
+}
+
+/// Build the [`IndexerSearchQuery::query`] string for an album.
+///
+/// Punctuation is replaced by spaces (apostrophes are dropped so `Don't`
+/// becomes `Dont`), noise words are removed, whitespace is collapsed and the
+/// year from `opts` is appended when set. A name made up only of noise words
+/// keeps them, so an album titled `Deluxe Edition` is still searched by title.
+pub fn build_album_query(artist: &str, album: &str, opts: &QueryBuildOptions) -> String {
+    let mut words: Vec<String> = [artist, album]
+        .into_iter()
+        .flat_map(|part| normalize_query_words(part, &opts.noise_words))
+        .collect();
+    if let Some(year) = opts.year {
+        words.push(year.to_string());
+    }
+    words.join(" ")
+}
+
+fn normalize_query_words(text: &str, noise_words: &[String]) -> Vec<String> {
+    let cleaned: String = text
+        .chars()
+        .filter(|c| *c != '\'' && *c != '\u{2019}')
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect();
+    let words: Vec<&str> = cleaned.split_whitespace().collect();
+    let kept: Vec<&str> = words
+        .iter()
+        .copied()
+        .filter(|word| {
+            !noise_words
+                .iter()
+                .any(|noise| noise.eq_ignore_ascii_case(word))
+        })
+        .collect();
+    let kept = if kept.is_empty() { words } else { kept };
+    kept.into_iter().map(str::to_string).collect()
+}
+
 fn build_manual_query(request: &ManualSearchRequest) -> Result<String, IndexerError> {
     if let Some(query) = request.query.as_deref() {
         let query = query.trim();
@@ -219,59 +555,179 @@ fn build_manual_query(request: &ManualSearchRequest) -> Result<String, IndexerEr
         }
     }
 
-    let mut parts = Vec::new();
-    if let Some(artist) = request.artist.as_deref() {
-        let artist = artist.trim();
-        if !artist.is_empty() {
-            parts.push(artist.to_string());
-        }
-    }
-    if let Some(album) = request.album.as_deref() {
-        let album = album.trim();
-        if !album.is_empty() {
-            parts.push(album.to_string());
-        }
-    }
-
-    if parts.is_empty() {
+    let query = build_album_query(
+        request.artist.as_deref().unwrap_or_default(),
+        request.album.as_deref().unwrap_or_default(),
+        &QueryBuildOptions::default(),
+    );
+    if query.is_empty() {
         return Err(IndexerError::Request(
             "manual search requires either query or artist/album".to_string(),
         ));
     }
 
-    Ok(parts.join(" "))
+    Ok(query)
+}
+
+/// Results whose sizes round to the same multiple of this are treated as equally sized.
+const DUPLICATE_SIZE_BUCKET_BYTES: u64 = 5 * 1024 * 1024;
+
+/// Collapse search results that are the same release posted more than once.
+///
+/// Results sharing a `guid` or a `download_url` are exact duplicates. Results
+/// whose normalized title (lowercased, punctuation and spacing collapsed) and
+/// size rounded to [`DUPLICATE_SIZE_BUCKET_BYTES`] match are near duplicates.
+/// Each group keeps the result with the most seeders, then the best score
+/// under `options`; results keep the order in which their group first appeared.
+pub fn deduplicate_search_results(
+    results: Vec<IndexerSearchResult>,
+    options: &ReleaseFilterOptions,
+) -> Vec<IndexerSearchResult> {
+    let mut kept: Vec<IndexerSearchResult> = Vec::with_capacity(results.len());
+    let mut index_by_key: HashMap<DuplicateKey, usize> = HashMap::new();
+    for result in results {
+        let keys = duplicate_keys(&result);
+        let Some(index) = keys.iter().find_map(|key| index_by_key.get(key).copied()) else {
+            for key in keys {
+                index_by_key.insert(key, kept.len());
+            }
+            kept.push(result);
+            continue;
+        };
+
+        for key in keys {
+            index_by_key.entry(key).or_insert(index);
+        }
+        let existing = &mut kept[index];
+        if duplicate_preference(&result, options) > duplicate_preference(existing, options) {
+            debug!(target: "search_automation", kept = %result.title, dropped = %existing.title, "collapsing duplicate search result");
+            *existing = result;
+        } else {
+            debug!(target: "search_automation", kept = %existing.title, dropped = %result.title, "collapsing duplicate search result");
+        }
+    }
+    kept
+}
+
+/// A property shared by copies of the same release.
+#[derive(PartialEq, Eq, Hash)]
+enum DuplicateKey {
+    Guid(String),
+    DownloadUrl(String),
+    Title(String, Option<u64>),
+}
+
+fn duplicate_keys(result: &IndexerSearchResult) -> Vec<DuplicateKey> {
+    let title = normalize_result_title(&result.title);
+    [
+        non_empty(&result.guid).map(DuplicateKey::Guid),
+        non_empty(&result.download_url).map(DuplicateKey::DownloadUrl),
+        (!title.is_empty()).then(|| DuplicateKey::Title(title, result.size_bytes.map(size_bucket))),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+fn non_empty(value: &Option<String>) -> Option<String> {
+    value
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+fn normalize_result_title(title: &str) -> String {
+    title
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn size_bucket(size_bytes: u64) -> u64 {
+    size_bytes.saturating_add(DUPLICATE_SIZE_BUCKET_BYTES / 2) / DUPLICATE_SIZE_BUCKET_BYTES
+}
+
+fn duplicate_preference(
+    result: &IndexerSearchResult,
+    options: &ReleaseFilterOptions,
+) -> (u32, i32) {
+    let quality = score_release(&parse_release_title(&result.title), options);
+    (result.seeders.unwrap_or(0), quality)
+}
+
+/// Seeders and age of a release when `protocol` serves torrents. Custom indexers may
+/// serve either protocol, so their releases count as torrents when they report seeders.
+pub fn release_torrent_health(
+    protocol: &IndexerProtocol,
+    seeders: Option<u32>,
+    published_at: Option<&str>,
+) -> Option<TorrentHealth> {
+    let torrent = match protocol {
+        IndexerProtocol::Torznab | IndexerProtocol::Gazelle => true,
+        IndexerProtocol::Custom => seeders.is_some(),
+        IndexerProtocol::Newznab => false,
+    };
+    torrent.then(|| TorrentHealth {
+        seeders,
+        published_at: published_at
+            .and_then(|published_at| DateTime::parse_from_rfc3339(published_at).ok())
+            .map(|published_at| published_at.with_timezone(&Utc)),
+    })
+}
+
+fn torrent_health(
+    result: &IndexerSearchResult,
+    protocol: &IndexerProtocol,
+) -> Option<TorrentHealth> {
+    release_torrent_health(protocol, result.seeders, result.published_at.as_deref())
 }
 
 fn rank_results(
     raw_results: Vec<IndexerSearchResult>,
     options: &ReleaseFilterOptions,
+    indexer: &IndexerConfig,
 ) -> Vec<RankedRelease> {
+    let raw_results = deduplicate_search_results(raw_results, options);
+
     // Parse titles before consuming the vec so we avoid an extra clone.
     let parsed_titles: Vec<ParsedReleaseTitle> = raw_results
         .iter()
-        .map(|r| parse_release_title(&r.title))
+        .map(|r| {
+            let parsed = parse_release_title(&r.title)
+                .with_size_bytes(r.size_bytes)
+                .with_indexer(indexer.name.clone());
+            match torrent_health(r, &indexer.protocol) {
+                Some(torrent) => parsed.with_torrent_health(torrent),
+                None => parsed,
+            }
+        })
         .collect();
 
-    // Build a title→result map for O(1) lookup when pairing ranked titles back
-    // to their original IndexerSearchResult (avoids O(n*m) nested scan).
-    // Use first-win semantics: if multiple results share the same title, keep
-    // the first one encountered rather than overwriting with later entries.
-    let mut result_map: HashMap<String, IndexerSearchResult> = HashMap::new();
-    for r in raw_results {
-        result_map.entry(r.title.clone()).or_insert(r);
+    // Pair ranked titles back to their IndexerSearchResult by title and size, so
+    // distinct results sharing a title each keep their own URL.
+    let mut result_map: HashMap<(String, Option<u64>), Vec<IndexerSearchResult>> = HashMap::new();
+    for r in raw_results.into_iter().rev() {
+        result_map
+            .entry((r.title.clone(), r.size_bytes))
+            .or_default()
+            .push(r);
     }
 
     let filtered = filter_releases(&parsed_titles, options);
-    let deduped = deduplicate_releases(&filtered);
+    let deduped = deduplicate_releases_with_strategy(&filtered, options.dedupe_strategy);
     let ranked = rank_releases(deduped, options);
 
     ranked
         .into_iter()
         .filter_map(|parsed| {
             result_map
-                .get(&parsed.original_title)
-                .cloned()
+                .get_mut(&(parsed.original_title.clone(), parsed.size_bytes))
+                .and_then(Vec::pop)
                 .map(|search_result| RankedRelease {
+                    score: score_release(&parsed, options),
                     parsed,
                     search_result,
                 })
@@ -282,19 +738,26 @@ fn rank_results(
 #[cfg(test)]
 mod tests {
     use super::{
-        automatic_search_missing_albums, detect_missing_albums, manual_search, AlbumSearchTarget,
-        ManualSearchRequest,
+        album_search_target, automatic_search_missing_albums, build_album_query,
+        build_manual_query, deduplicate_search_results, detect_missing_albums, manual_search,
+        rank_results, AlbumSearchTarget, AutomaticSearchReason, ManualSearchRequest,
+        QueryBuildOptions, SearchDecisionStore,
     };
+    use crate::delay_profile::DelayProfile;
     use crate::indexers::{
         IndexerCapabilities, IndexerClient, IndexerConfig, IndexerError, IndexerProtocol,
         IndexerRssItem, IndexerSearchQuery, IndexerSearchResult, IndexerTestResult,
     };
-    use crate::release_parsing::{AudioQuality, ReleaseFilterOptions};
+    use crate::release_parsing::{AudioQuality, DedupeStrategy, ReleaseFilterOptions};
     use async_trait::async_trait;
+    use chorrosion_domain::{QualityProfile, TrackFile};
+    use chrono::{Duration, Utc};
+    use std::collections::HashSet;
 
     #[derive(Clone)]
     struct FakeIndexer {
         config: IndexerConfig,
+        published_at: Option<String>,
     }
 
     impl FakeIndexer {
@@ -306,9 +769,19 @@ mod tests {
                     protocol: IndexerProtocol::Custom,
                     api_key: None,
                     enabled: true,
+                    requests_per_minute: None,
                 },
+                published_at: None,
             }
         }
+
+        /// A fake `protocol` indexer whose releases were all published `age_minutes` ago.
+        fn with_release_age(protocol: IndexerProtocol, age_minutes: i64) -> Self {
+            let mut indexer = Self::new();
+            indexer.config.protocol = protocol;
+            indexer.published_at = Some((Utc::now() - Duration::minutes(age_minutes)).to_rfc3339());
+            indexer
+        }
     }
 
     #[async_trait]
@@ -337,7 +810,7 @@ mod tests {
                         title: "Daft Punk - Discovery [FLAC]-A".to_string(),
                         guid: Some("1".to_string()),
                         download_url: Some("magnet:?xt=1".to_string()),
-                        published_at: None,
+                        published_at: self.published_at.clone(),
                         size_bytes: None,
                         seeders: Some(10),
                         leechers: Some(1),
@@ -346,7 +819,7 @@ mod tests {
                         title: "Daft Punk - Discovery 320kbps MP3-B".to_string(),
                         guid: Some("2".to_string()),
                         download_url: Some("magnet:?xt=2".to_string()),
-                        published_at: None,
+                        published_at: self.published_at.clone(),
                         size_bytes: None,
                         seeders: Some(8),
                         leechers: Some(2),
@@ -359,7 +832,7 @@ mod tests {
                     title: "Radiohead - OK Computer 320kbps MP3-RLS".to_string(),
                     guid: Some("3".to_string()),
                     download_url: Some("magnet:?xt=3".to_string()),
-                    published_at: None,
+                    published_at: self.published_at.clone(),
                     size_bytes: None,
                     seeders: Some(4),
                     leechers: Some(1),
@@ -378,6 +851,7 @@ mod tests {
                 success: true,
                 message: "ok".to_string(),
                 capabilities: None,
+                failure: None,
             })
         }
     }
@@ -389,11 +863,15 @@ mod tests {
                 artist: "Daft Punk".to_string(),
                 album: "Discovery".to_string(),
                 already_owned: false,
+                already_downloading: false,
+                cutoff_score: None,
             },
             AlbumSearchTarget {
                 artist: "Radiohead".to_string(),
                 album: "OK Computer".to_string(),
                 already_owned: true,
+                already_downloading: false,
+                cutoff_score: None,
             },
         ];
 
@@ -417,6 +895,29 @@ mod tests {
 
         assert_eq!(results.len(), 2);
         assert_eq!(results[0].parsed.quality, AudioQuality::Flac);
+        assert!(results[0].score > results[1].score);
+    }
+
+    #[tokio::test]
+    async fn manual_search_applies_min_seeders_to_torrent_indexers_only() {
+        let request = ManualSearchRequest {
+            artist: Some("Daft Punk".to_string()),
+            album: Some("Discovery".to_string()),
+            query: None,
+        };
+        let options = ReleaseFilterOptions {
+            min_seeders: Some(9),
+            ..ReleaseFilterOptions::default()
+        };
+
+        let torrent = FakeIndexer::with_release_age(IndexerProtocol::Torznab, 60);
+        let results = manual_search(&torrent, &request, &options).await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].search_result.seeders, Some(10));
+
+        let usenet = FakeIndexer::with_release_age(IndexerProtocol::Newznab, 60);
+        let results = manual_search(&usenet, &request, &options).await.unwrap();
+        assert_eq!(results.len(), 2);
     }
 
     #[tokio::test]
@@ -426,6 +927,8 @@ mod tests {
             artist: "Radiohead".to_string(),
             album: "OK Computer".to_string(),
             already_owned: false,
+            already_downloading: false,
+            cutoff_score: None,
         }];
 
         let decisions = automatic_search_missing_albums(
@@ -436,18 +939,20 @@ mod tests {
                 min_bitrate_kbps: Some(256),
                 preferred_release_groups: vec![],
                 preferred_words: vec![],
+                rejected_words: vec![],
                 custom_format_rules: vec![],
+                ..ReleaseFilterOptions::default()
             },
+            &DelayProfile::default(),
         )
         .await
         .expect("automatic search should succeed");
 
         assert_eq!(decisions.len(), 1);
-        assert!(decisions[0].best_release.is_some());
+        assert!(decisions[0].best_release().is_some());
         assert_eq!(
             decisions[0]
-                .best_release
-                .as_ref()
+                .best_release()
                 .and_then(|r| r.parsed.album.as_deref()),
             Some("OK Computer")
         );
@@ -484,16 +989,22 @@ mod tests {
             artist: "Unknown Artist".to_string(),
             album: "Nonexistent Album".to_string(),
             already_owned: false,
+            already_downloading: false,
+            cutoff_score: None,
         }];
 
-        let decisions =
-            automatic_search_missing_albums(&indexer, &targets, &ReleaseFilterOptions::default())
-                .await
-                .expect("automatic search should succeed even with no results");
+        let decisions = automatic_search_missing_albums(
+            &indexer,
+            &targets,
+            &ReleaseFilterOptions::default(),
+            &DelayProfile::default(),
+        )
+        .await
+        .expect("automatic search should succeed even with no results");
 
         assert_eq!(decisions.len(), 1);
         assert!(
-            decisions[0].best_release.is_none(),
+            decisions[0].best_release().is_none(),
             "expected no best release when indexer returns no results"
         );
     }
@@ -537,4 +1048,504 @@ mod tests {
             "expected error when all fields are whitespace"
         );
     }
+
+    fn target(artist: &str, album: &str) -> AlbumSearchTarget {
+        AlbumSearchTarget {
+            artist: artist.to_string(),
+            album: album.to_string(),
+            already_owned: false,
+            already_downloading: false,
+            cutoff_score: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn automatic_search_reports_a_reason_for_every_target() {
+        let indexer = FakeIndexer::new();
+        let targets = vec![
+            AlbumSearchTarget {
+                already_owned: true,
+                ..target("Daft Punk", "Discovery")
+            },
+            AlbumSearchTarget {
+                already_downloading: true,
+                ..target("Daft Punk", "Discovery")
+            },
+            target("Unknown Artist", "Nonexistent Album"),
+            AlbumSearchTarget {
+                cutoff_score: Some(i32::MAX),
+                ..target("Radiohead", "OK Computer")
+            },
+            AlbumSearchTarget {
+                cutoff_score: Some(0),
+                ..target("Daft Punk", "Discovery")
+            },
+        ];
+
+        let decisions = automatic_search_missing_albums(
+            &indexer,
+            &targets,
+            &ReleaseFilterOptions::default(),
+            &DelayProfile::default(),
+        )
+        .await
+        .expect("automatic search should succeed");
+
+        let reasons: Vec<&str> = decisions.iter().map(|d| d.reason.as_str()).collect();
+        assert_eq!(
+            reasons,
+            vec![
+                "already_owned",
+                "already_downloading",
+                "no_results",
+                "below_cutoff",
+                "grabbed"
+            ]
+        );
+        assert_eq!(decisions[0].target, targets[0]);
+        assert!(decisions[0].reason.release().is_none());
+
+        match &decisions[3].reason {
+            AutomaticSearchReason::BelowCutoff {
+                release,
+                cutoff_score,
+            } => {
+                assert_eq!(*cutoff_score, i32::MAX);
+                assert_eq!(release.parsed.album.as_deref(), Some("OK Computer"));
+            }
+            other => panic!("expected BelowCutoff, got {other:?}"),
+        }
+        assert!(decisions[3].best_release().is_none());
+
+        let grabbed = decisions[4].best_release().expect("grabbed release");
+        assert_eq!(grabbed.parsed.quality, AudioQuality::Flac);
+    }
+
+    #[test]
+    fn automatic_search_reason_serializes_with_snake_case_tag() {
+        let value = serde_json::to_value(AutomaticSearchReason::AlreadyDownloading)
+            .expect("serialize reason");
+        assert_eq!(
+            value,
+            serde_json::json!({ "reason": "already_downloading" })
+        );
+    }
+
+    #[test]
+    fn search_decision_store_keeps_latest_decision_per_album() {
+        let store = SearchDecisionStore::new();
+        assert!(store.get("album-1").is_none());
+
+        store.record("album-1", AutomaticSearchReason::NoResults);
+        store.record("album-1", AutomaticSearchReason::AlreadyDownloading);
+        store.record("album-2", AutomaticSearchReason::Unmonitored);
+
+        assert_eq!(
+            store.get("album-1").map(|status| status.reason),
+            Some(AutomaticSearchReason::AlreadyDownloading)
+        );
+        assert_eq!(
+            store.clone().get("album-2").map(|status| status.reason),
+            Some(AutomaticSearchReason::Unmonitored)
+        );
+    }
+
+    #[test]
+    fn build_album_query_passes_clean_names_through() {
+        assert_eq!(
+            build_album_query("Radiohead", "OK Computer", &QueryBuildOptions::default()),
+            "Radiohead OK Computer"
+        );
+    }
+
+    #[test]
+    fn build_album_query_strips_punctuation() {
+        assert_eq!(
+            build_album_query(
+                "Simon & Garfunkel",
+                "Bridge over Troubled Water: (Live!)",
+                &QueryBuildOptions::default(),
+            ),
+            "Simon Garfunkel Bridge over Troubled Water Live"
+        );
+        assert_eq!(
+            build_album_query(
+                "Guns N' Roses",
+                "Don\u{2019}t Cry",
+                &QueryBuildOptions::default()
+            ),
+            "Guns N Roses Dont Cry"
+        );
+    }
+
+    #[test]
+    fn build_album_query_appends_year_only_when_set() {
+        let without_year = QueryBuildOptions::default();
+        let with_year = QueryBuildOptions::default().with_year(Some(1997));
+
+        assert_eq!(
+            build_album_query("Radiohead", "OK Computer", &without_year),
+            "Radiohead OK Computer"
+        );
+        assert_eq!(
+            build_album_query("Radiohead", "OK Computer", &with_year),
+            "Radiohead OK Computer 1997"
+        );
+    }
+
+    #[test]
+    fn build_album_query_strips_noise_words() {
+        assert_eq!(
+            build_album_query(
+                "Radiohead",
+                "OK Computer (Deluxe Edition)",
+                &QueryBuildOptions::default(),
+            ),
+            "Radiohead OK Computer"
+        );
+
+        let custom = QueryBuildOptions {
+            year: None,
+            noise_words: vec!["live".to_string()],
+        };
+        assert_eq!(
+            build_album_query("Nirvana", "Live at Reading (Deluxe)", &custom),
+            "Nirvana at Reading Deluxe"
+        );
+    }
+
+    #[test]
+    fn build_album_query_keeps_a_title_made_only_of_noise_words() {
+        assert_eq!(
+            build_album_query(
+                "The Lemon Twigs",
+                "Deluxe Edition",
+                &QueryBuildOptions::default()
+            ),
+            "The Lemon Twigs Deluxe Edition"
+        );
+    }
+
+    #[test]
+    fn build_manual_query_normalizes_artist_and_album_like_album_queries() {
+        let request = ManualSearchRequest {
+            artist: Some("Guns N' Roses".to_string()),
+            album: Some("Appetite for Destruction (Remastered)".to_string()),
+            query: None,
+        };
+        assert_eq!(
+            build_manual_query(&request).unwrap(),
+            build_album_query(
+                "Guns N' Roses",
+                "Appetite for Destruction (Remastered)",
+                &QueryBuildOptions::default()
+            )
+        );
+    }
+
+    fn torrent_delay_profile() -> DelayProfile {
+        DelayProfile {
+            usenet_delay_minutes: 0,
+            torrent_delay_minutes: 60,
+        }
+    }
+
+    #[tokio::test]
+    async fn automatic_search_defers_torrent_younger_than_delay() {
+        let indexer = FakeIndexer::with_release_age(IndexerProtocol::Torznab, 15);
+        let targets = vec![target("Radiohead", "OK Computer")];
+
+        let decisions = automatic_search_missing_albums(
+            &indexer,
+            &targets,
+            &ReleaseFilterOptions::default(),
+            &torrent_delay_profile(),
+        )
+        .await
+        .expect("automatic search should succeed");
+
+        assert!(decisions[0].best_release().is_none());
+        match &decisions[0].reason {
+            AutomaticSearchReason::Deferred {
+                release,
+                available_at,
+            } => {
+                assert_eq!(release.parsed.album.as_deref(), Some("OK Computer"));
+                let wait = *available_at - Utc::now();
+                assert!(wait > Duration::minutes(44) && wait <= Duration::minutes(45));
+            }
+            other => panic!("expected Deferred, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn automatic_search_grabs_torrent_older_than_delay() {
+        let indexer = FakeIndexer::with_release_age(IndexerProtocol::Torznab, 90);
+        let targets = vec![target("Radiohead", "OK Computer")];
+
+        let decisions = automatic_search_missing_albums(
+            &indexer,
+            &targets,
+            &ReleaseFilterOptions::default(),
+            &torrent_delay_profile(),
+        )
+        .await
+        .expect("automatic search should succeed");
+
+        assert_eq!(decisions[0].reason.as_str(), "grabbed");
+    }
+
+    #[tokio::test]
+    async fn automatic_search_grabs_usenet_sooner_than_torrent() {
+        let targets = vec![target("Daft Punk", "Discovery")];
+        let profile = torrent_delay_profile();
+
+        let usenet = FakeIndexer::with_release_age(IndexerProtocol::Newznab, 5);
+        let usenet_decisions = automatic_search_missing_albums(
+            &usenet,
+            &targets,
+            &ReleaseFilterOptions::default(),
+            &profile,
+        )
+        .await
+        .expect("usenet search should succeed");
+
+        let torrent = FakeIndexer::with_release_age(IndexerProtocol::Torznab, 5);
+        let torrent_decisions = automatic_search_missing_albums(
+            &torrent,
+            &targets,
+            &ReleaseFilterOptions::default(),
+            &profile,
+        )
+        .await
+        .expect("torrent search should succeed");
+
+        let grabbed = usenet_decisions[0]
+            .best_release()
+            .expect("usenet release should be grabbed");
+        assert_eq!(grabbed.parsed.quality, AudioQuality::Flac);
+        assert_eq!(torrent_decisions[0].reason.as_str(), "deferred");
+        assert_eq!(
+            torrent_decisions[0]
+                .reason
+                .release()
+                .map(|release| &release.search_result.title),
+            Some(&grabbed.search_result.title)
+        );
+    }
+
+    fn search_result(
+        title: &str,
+        guid: &str,
+        size_bytes: Option<u64>,
+        seeders: u32,
+    ) -> IndexerSearchResult {
+        IndexerSearchResult {
+            title: title.to_string(),
+            guid: Some(guid.to_string()),
+            download_url: Some(format!("https://example.invalid/download/{guid}")),
+            published_at: None,
+            size_bytes,
+            seeders: Some(seeders),
+            leechers: None,
+        }
+    }
+
+    fn titles(results: &[IndexerSearchResult]) -> Vec<&str> {
+        results.iter().map(|result| result.title.as_str()).collect()
+    }
+
+    #[test]
+    fn rank_results_tags_releases_with_their_indexer_and_honours_the_dedupe_strategy() {
+        let indexer = FakeIndexer::new();
+        let results = vec![
+            search_result("Air - Moon Safari [FLAC]", "small", Some(300_000_000), 4),
+            search_result("Air - Moon Safari [FLAC]", "large", Some(900_000_000), 2),
+        ];
+
+        let by_content = rank_results(
+            results.clone(),
+            &ReleaseFilterOptions::default(),
+            &indexer.config,
+        );
+        assert_eq!(by_content.len(), 1);
+        assert_eq!(by_content[0].parsed.indexer.as_deref(), Some("fake"));
+
+        let keep_all = rank_results(
+            results,
+            &ReleaseFilterOptions {
+                dedupe_strategy: DedupeStrategy::KeepAll,
+                ..ReleaseFilterOptions::default()
+            },
+            &indexer.config,
+        );
+        let mut urls = keep_all
+            .iter()
+            .map(|release| release.search_result.download_url.clone().unwrap())
+            .collect::<Vec<_>>();
+        urls.sort();
+        assert_eq!(
+            urls,
+            [
+                "https://example.invalid/download/large",
+                "https://example.invalid/download/small",
+            ],
+            "results sharing a title keep their own download URL"
+        );
+    }
+
+    #[test]
+    fn album_search_target_derives_ownership_downloads_and_cutoff() {
+        let track_id = chorrosion_domain::TrackId::new();
+        let mut mp3 = TrackFile::new(track_id, "/music/01.mp3", 1_000);
+        mp3.quality = Some("MP3 320".to_string());
+        let mut flac = TrackFile::new(track_id, "/music/01.flac", 1_000);
+        flac.quality = Some("FLAC".to_string());
+        let mut profile =
+            QualityProfile::new("Lossless", vec!["MP3 320".to_string(), "FLAC".to_string()]);
+        profile.cutoff_quality = Some("FLAC".to_string());
+        let options = ReleaseFilterOptions {
+            quality_profile: Some(profile),
+            ..ReleaseFilterOptions::default()
+        };
+        let downloading = HashSet::from([("air".to_string(), "talkie walkie".to_string())]);
+
+        let missing = album_search_target("Air", "Moon Safari", &[vec![]], &downloading, &options);
+        assert!(!missing.already_owned);
+        assert!(!missing.already_downloading);
+        assert_eq!(missing.cutoff_score, None);
+
+        let owned = album_search_target(
+            "Air",
+            "Moon Safari",
+            &[vec![mp3.clone(), flac]],
+            &downloading,
+            &options,
+        );
+        assert!(owned.already_owned);
+
+        let below_cutoff =
+            album_search_target("Air", "Moon Safari", &[vec![mp3]], &downloading, &options);
+        assert!(!below_cutoff.already_owned);
+        assert!(below_cutoff.cutoff_score.is_some());
+
+        let queued = album_search_target("Air", "Talkie Walkie", &[], &downloading, &options);
+        assert!(queued.already_downloading);
+    }
+
+    #[test]
+    fn rank_results_orders_by_the_quality_profile_when_one_is_set() {
+        let indexer = FakeIndexer::new();
+        let results = vec![
+            search_result("Air - Moon Safari [FLAC]", "flac", Some(400_000_000), 5),
+            search_result(
+                "Air - Moon Safari [MP3 320kbps]",
+                "mp3",
+                Some(120_000_000),
+                5,
+            ),
+        ];
+
+        let by_score = rank_results(
+            results.clone(),
+            &ReleaseFilterOptions::default(),
+            &indexer.config,
+        );
+        assert_eq!(by_score[0].search_result.title, "Air - Moon Safari [FLAC]");
+
+        let mp3_preferred = QualityProfile::new(
+            "MP3 Preferred",
+            vec!["FLAC".to_string(), "MP3 320".to_string()],
+        );
+        let by_profile = rank_results(
+            results,
+            &ReleaseFilterOptions {
+                quality_profile: Some(mp3_preferred),
+                ..ReleaseFilterOptions::default()
+            },
+            &indexer.config,
+        );
+        assert_eq!(
+            by_profile[0].search_result.title,
+            "Air - Moon Safari [MP3 320kbps]"
+        );
+    }
+
+    #[test]
+    fn deduplicate_search_results_merges_exact_guid_and_download_url_matches() {
+        let mut same_url = search_result("Air - Moon Safari [FLAC]", "c", Some(400_000_000), 3);
+        same_url.download_url = Some("https://example.invalid/download/a".to_string());
+
+        let deduped = deduplicate_search_results(
+            vec![
+                search_result("Air - Moon Safari [FLAC]", "a", Some(400_000_000), 5),
+                search_result(
+                    "Air - Moon Safari (Repack) FLAC",
+                    "a",
+                    Some(410_000_000),
+                    12,
+                ),
+                same_url,
+            ],
+            &ReleaseFilterOptions::default(),
+        );
+
+        assert_eq!(deduped.len(), 1);
+        assert_eq!(deduped[0].title, "Air - Moon Safari (Repack) FLAC");
+        assert_eq!(deduped[0].seeders, Some(12));
+    }
+
+    #[test]
+    fn deduplicate_search_results_collapses_near_identical_titles() {
+        let deduped = deduplicate_search_results(
+            vec![
+                search_result("Air - Moon Safari (1998) [FLAC]", "a", Some(300_000_000), 4),
+                search_result("Air.-.Moon.Safari.1998.FLAC", "b", Some(300_100_000), 20),
+                search_result("air  moon safari 1998 flac", "c", Some(299_900_000), 7),
+            ],
+            &ReleaseFilterOptions::default(),
+        );
+
+        assert_eq!(titles(&deduped), ["Air.-.Moon.Safari.1998.FLAC"]);
+    }
+
+    #[test]
+    fn deduplicate_search_results_breaks_seeder_ties_with_the_callers_preferences() {
+        let results = vec![
+            search_result("Air - Moon Safari [FLAC]-GRPA", "a", Some(400_000_000), 5),
+            search_result("Air - Moon Safari [FLAC]-GRPB", "a", Some(400_000_000), 5),
+        ];
+        let options = ReleaseFilterOptions {
+            preferred_release_groups: vec!["GRPB".to_string()],
+            ..ReleaseFilterOptions::default()
+        };
+
+        let deduped = deduplicate_search_results(results, &options);
+
+        assert_eq!(titles(&deduped), ["Air - Moon Safari [FLAC]-GRPB"]);
+    }
+
+    #[test]
+    fn deduplicate_search_results_keeps_genuinely_different_releases() {
+        let results = vec![
+            search_result("Air - Moon Safari (1998) [FLAC]", "a", Some(300_000_000), 4),
+            search_result(
+                "Air - Moon Safari (1998) [MP3 320]",
+                "b",
+                Some(120_000_000),
+                9,
+            ),
+            search_result("Air - Moon Safari (1998) [FLAC]", "c", Some(900_000_000), 2),
+            search_result(
+                "Air - Talkie Walkie (2004) [FLAC]",
+                "d",
+                Some(300_000_000),
+                4,
+            ),
+        ];
+
+        let deduped = deduplicate_search_results(results.clone(), &ReleaseFilterOptions::default());
+
+        assert_eq!(deduped, results);
+    }
 }
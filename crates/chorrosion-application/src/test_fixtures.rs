@@ -56,3 +56,160 @@ pub(crate) const MINIMAL_FLAC: &[u8] = &[
     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // MD5 signature (bytes 9–16)
     0x81, 0x00, 0x00, 0x00, // last block + PADDING type 1 + size=0
 ];
+
+/// Build a minimal Ogg Opus stream: an `OpusHead` page followed by an
+/// `OpusTags` page carrying the given Vorbis comments.  Page CRCs are
+/// computed so lofty accepts the stream.
+pub(crate) fn minimal_opus(comments: &[(&str, &str)]) -> Vec<u8> {
+    let mut head = b"OpusHead".to_vec();
+    head.extend_from_slice(&[1, 2]); // version 1, 2 channels
+    head.extend_from_slice(&312u16.to_le_bytes()); // pre-skip
+    head.extend_from_slice(&48_000u32.to_le_bytes()); // input sample rate
+    head.extend_from_slice(&0i16.to_le_bytes()); // output gain
+    head.push(0); // channel mapping family
+
+    let vendor = b"chorrosion";
+    let mut tags = b"OpusTags".to_vec();
+    tags.extend_from_slice(&(vendor.len() as u32).to_le_bytes());
+    tags.extend_from_slice(vendor);
+    tags.extend_from_slice(&(comments.len() as u32).to_le_bytes());
+    for (key, value) in comments {
+        let comment = format!("{key}={value}");
+        tags.extend_from_slice(&(comment.len() as u32).to_le_bytes());
+        tags.extend_from_slice(comment.as_bytes());
+    }
+
+    let mut stream = ogg_page(0x02, 0, &head);
+    stream.extend(ogg_page(0x04, 1, &tags));
+    stream
+}
+
+/// Build a minimal M4A file: `ftyp`, then a `moov` holding one sound track
+/// (`mdhd` + `hdlr` only, no samples) and a `udta.meta.ilst` with the given
+/// items.
+///
+/// Keys are atom names: `©ART` style text atoms, `trkn`/`disk` with an
+/// `N/TOTAL` value, or `----:mean:name` freeform atoms such as
+/// `----:com.apple.iTunes:MusicBrainz Track Id`.
+pub(crate) fn minimal_m4a(items: &[(&str, &str)]) -> Vec<u8> {
+    let mut ftyp = b"M4A ".to_vec();
+    ftyp.extend_from_slice(&0u32.to_be_bytes()); // minor version
+    ftyp.extend_from_slice(b"M4A isom");
+
+    let mut mdhd = vec![0; 12]; // version/flags, creation and modification time
+    mdhd.extend_from_slice(&44_100u32.to_be_bytes()); // timescale
+    mdhd.extend_from_slice(&0u32.to_be_bytes()); // duration
+    mdhd.extend_from_slice(&[0; 4]); // language, quality
+    let mut hdlr = vec![0; 8]; // version/flags, pre-defined
+    hdlr.extend_from_slice(b"soun");
+    hdlr.extend_from_slice(&[0; 13]); // reserved, empty name
+    let mdia = mp4_atom(
+        b"mdia",
+        &[mp4_atom(b"mdhd", &mdhd), mp4_atom(b"hdlr", &hdlr)].concat(),
+    );
+    let trak = mp4_atom(b"trak", &mdia);
+
+    let ilst: Vec<u8> = items
+        .iter()
+        .flat_map(|(key, value)| mp4_ilst_item(key, value))
+        .collect();
+    let mut meta = vec![0; 4]; // full atom version/flags
+    meta.extend(mp4_atom(b"ilst", &ilst));
+    let udta = mp4_atom(b"udta", &mp4_atom(b"meta", &meta));
+
+    let mut file = mp4_atom(b"ftyp", &ftyp);
+    file.extend(mp4_atom(b"moov", &[trak, udta].concat()));
+    file
+}
+
+/// One `ilst` item atom with its `data` child.
+fn mp4_ilst_item(key: &str, value: &str) -> Vec<u8> {
+    if let Some(freeform) = key.strip_prefix("----:") {
+        let (mean, name) = freeform.split_once(':').expect("----:mean:name key");
+        let full = |ident: &[u8; 4], text: &str| {
+            let mut body = vec![0; 4];
+            body.extend_from_slice(text.as_bytes());
+            mp4_atom(ident, &body)
+        };
+        let body = [
+            full(b"mean", mean),
+            full(b"name", name),
+            mp4_data(1, value.as_bytes()),
+        ];
+        return mp4_atom(b"----", &body.concat());
+    }
+
+    let ident: [u8; 4] = match key.strip_prefix('©') {
+        Some(rest) => [
+            0xA9,
+            rest.as_bytes()[0],
+            rest.as_bytes()[1],
+            rest.as_bytes()[2],
+        ],
+        None => key.as_bytes().try_into().expect("four-byte atom name"),
+    };
+    let data = match &ident {
+        b"trkn" | b"disk" => {
+            let (number, total) = value.split_once('/').unwrap_or((value, "0"));
+            let mut pair = vec![0, 0];
+            pair.extend_from_slice(&number.parse::<u16>().unwrap().to_be_bytes());
+            pair.extend_from_slice(&total.parse::<u16>().unwrap().to_be_bytes());
+            if &ident == b"trkn" {
+                pair.extend_from_slice(&[0, 0]);
+            }
+            mp4_data(0, &pair)
+        }
+        _ => mp4_data(1, value.as_bytes()),
+    };
+    mp4_atom(&ident, &data)
+}
+
+/// A `data` atom: type indicator (0 implicit, 1 UTF-8), empty locale, payload.
+fn mp4_data(type_indicator: u32, payload: &[u8]) -> Vec<u8> {
+    let mut body = type_indicator.to_be_bytes().to_vec();
+    body.extend_from_slice(&[0; 4]);
+    body.extend_from_slice(payload);
+    mp4_atom(b"data", &body)
+}
+
+/// Encode an MP4 atom: 32-bit big-endian size, identifier, body.
+fn mp4_atom(ident: &[u8; 4], body: &[u8]) -> Vec<u8> {
+    let mut atom = ((body.len() + 8) as u32).to_be_bytes().to_vec();
+    atom.extend_from_slice(ident);
+    atom.extend_from_slice(body);
+    atom
+}
+
+/// Encode a single-packet Ogg page.
+fn ogg_page(header_type: u8, sequence: u32, packet: &[u8]) -> Vec<u8> {
+    let mut lacing = vec![255u8; packet.len() / 255];
+    lacing.push((packet.len() % 255) as u8);
+
+    let mut page = b"OggS".to_vec();
+    page.push(0); // stream structure version
+    page.push(header_type);
+    page.extend_from_slice(&0u64.to_le_bytes()); // granule position
+    page.extend_from_slice(&0x4348_4f52u32.to_le_bytes()); // serial number
+    page.extend_from_slice(&sequence.to_le_bytes());
+    page.extend_from_slice(&[0; 4]); // CRC placeholder
+    page.push(lacing.len() as u8);
+    page.extend_from_slice(&lacing);
+    page.extend_from_slice(packet);
+
+    let crc = ogg_crc(&page);
+    page[22..26].copy_from_slice(&crc.to_le_bytes());
+    page
+}
+
+/// Ogg page checksum: CRC-32 with polynomial 0x04C11DB7, no reflection.
+fn ogg_crc(data: &[u8]) -> u32 {
+    data.iter().fold(0u32, |crc, byte| {
+        (0..8).fold(crc ^ (u32::from(*byte) << 24), |crc, _| {
+            if crc & 0x8000_0000 != 0 {
+                (crc << 1) ^ 0x04C1_1DB7
+            } else {
+                crc << 1
+            }
+        })
+    })
+}
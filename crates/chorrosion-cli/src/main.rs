@@ -4,23 +4,24 @@ use std::net::SocketAddr;
 use anyhow::Result;
 use axum::serve;
 use chorrosion_api::router;
-use chorrosion_application::AppState;
+use chorrosion_application::{AppMetrics, AppState, PoolUsage, SearchDecisionStore};
 use chorrosion_config::load as load_config;
 use chorrosion_infrastructure::{
     init_database,
+    repositories::StatusParsing,
     sqlite_adapters::{
         SqliteAlbumRepository, SqliteArtistRepository, SqliteDownloadClientDefinitionRepository,
         SqliteDuplicateRepository, SqliteIndexerDefinitionRepository,
         SqliteMetadataProfileRepository, SqliteQualityProfileRepository,
         SqliteSmartPlaylistRepository, SqliteTagRepository, SqliteTaggedEntityRepository,
-        SqliteTrackRepository,
+        SqliteTrackFileRepository, SqliteTrackRepository,
     },
     ResponseCache,
 };
 use chorrosion_scheduler::Scheduler;
 use std::sync::Arc;
 use tokio::net::TcpListener;
-use tracing::info;
+use tracing::{info, warn};
 use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 
 #[tokio::main]
@@ -28,15 +29,24 @@ async fn main() -> Result<()> {
     init_tracing();
 
     let config = load_config(None)?;
+    if config.http.proxy_url.is_some() {
+        info!(target: "cli", "routing outbound HTTP requests through the configured proxy");
+    }
     let pool = init_database(&config).await?;
-    let artist_repository = Arc::new(SqliteArtistRepository::new_with_threshold(
-        pool.clone(),
-        config.database.slow_query_threshold_ms,
-    ));
-    let album_repository = Arc::new(SqliteAlbumRepository::new_with_threshold(
-        pool.clone(),
-        config.database.slow_query_threshold_ms,
-    ));
+    let artist_repository = Arc::new(
+        SqliteArtistRepository::new_with_threshold(
+            pool.clone(),
+            config.database.slow_query_threshold_ms,
+        )
+        .with_status_parsing(StatusParsing::from(&config.database)),
+    );
+    let album_repository = Arc::new(
+        SqliteAlbumRepository::new_with_threshold(
+            pool.clone(),
+            config.database.slow_query_threshold_ms,
+        )
+        .with_status_parsing(StatusParsing::from(&config.database)),
+    );
     let track_repository = Arc::new(SqliteTrackRepository::new_with_threshold(
         pool.clone(),
         config.database.slow_query_threshold_ms,
@@ -51,12 +61,22 @@ async fn main() -> Result<()> {
     let tagged_entity_repository = Arc::new(SqliteTaggedEntityRepository::new(pool.clone()));
     let smart_playlist_repository = Arc::new(SqliteSmartPlaylistRepository::new(pool.clone()));
     let duplicate_repository = Arc::new(SqliteDuplicateRepository::new(pool.clone()));
+    let track_file_repository = Arc::new(SqliteTrackFileRepository::new(pool.clone()));
 
     let response_cache = ResponseCache::new(
         config.cache.api_response_max_capacity,
         config.cache.api_response_ttl_seconds,
     );
 
+    let metrics = AppMetrics::new().with_pool_sampler({
+        let pool = pool.clone();
+        move || PoolUsage {
+            size: pool.size(),
+            idle: pool.num_idle(),
+        }
+    });
+
+    let search_decisions = SearchDecisionStore::new();
     let state = AppState::new(
         config.clone(),
         artist_repository,
@@ -70,11 +90,18 @@ async fn main() -> Result<()> {
         tagged_entity_repository,
         smart_playlist_repository,
         duplicate_repository,
+        track_file_repository,
         response_cache,
-    );
+    )
+    .with_metrics(metrics.clone())
+    .with_search_decision_store(search_decisions.clone());
     state.on_start();
 
-    let scheduler = Scheduler::new(config.clone(), pool.clone());
+    let scheduler = Scheduler::new(config.clone(), pool.clone())
+        .with_metrics(metrics)
+        .with_event_dispatcher(state.event_dispatcher.clone())
+        .with_search_decision_store(search_decisions)
+        .with_indexer_clients(state.indexer_clients.clone());
     scheduler.register_jobs().await;
     let _scheduler_handle = scheduler.start();
 
@@ -82,9 +109,16 @@ async fn main() -> Result<()> {
     let addr = listener.local_addr()?;
     info!(target: "cli", "listening on {}", addr);
 
-    serve(listener, router(state))
-        .with_graceful_shutdown(shutdown_signal())
-        .await?;
+    serve(
+        listener,
+        router(state).into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown(shutdown_signal())
+    .await?;
+
+    if !scheduler.shutdown().await {
+        warn!(target: "cli", "scheduler shutdown timed out; some jobs were abandoned");
+    }
 
     Ok(())
 }
@@ -142,6 +176,9 @@ mod tests {
         let http = chorrosion_config::HttpConfig {
             host: "127.0.0.1".to_string(),
             port: 5150,
+            proxy_url: None,
+            user_agent: None,
+            ..Default::default()
         };
         let addr = bind_addr(&http);
         assert_eq!(addr.port(), 5150);
@@ -153,6 +190,9 @@ mod tests {
         let http = chorrosion_config::HttpConfig {
             host: "[::1]".to_string(),
             port: 8080,
+            proxy_url: None,
+            user_agent: None,
+            ..Default::default()
         };
         let addr = bind_addr(&http);
         assert_eq!(addr.port(), 8080);
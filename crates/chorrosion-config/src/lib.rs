@@ -1,12 +1,14 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
-use std::path::Path;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use figment::{
     providers::{Env, Format, Serialized, Toml},
     value::{Dict, Map, Value},
     Figment, Metadata, Profile, Provider,
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use tracing::info;
 use utoipa::ToSchema;
@@ -18,7 +20,8 @@ use utoipa::ToSchema;
 /// stay consistent with each other.
 pub const DEFAULT_METADATA_REQUEST_TIMEOUT_SECS: u64 = 15;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(default)]
 pub struct DatabaseConfig {
     pub url: String,
     pub pool_max_size: u32,
@@ -39,6 +42,17 @@ pub struct DatabaseConfig {
     /// Queries that take longer than this threshold (in milliseconds) are logged at WARN level.
     /// Set to 0 to disable slow-query logging.
     pub slow_query_threshold_ms: u64,
+    /// Fail artist/album queries when a row carries an unknown `status` value instead of
+    /// logging a warning and coercing it to a default (`continuing` / `wanted`).
+    /// Env override: `CHORROSION_DATABASE__STRICT_STATUS_PARSING`.
+    pub strict_status_parsing: bool,
+    /// Directory the housekeeping job writes SQLite snapshots to. Unset disables backups.
+    /// Env override: `CHORROSION_DATABASE__BACKUP_DIR`.
+    pub backup_dir: Option<PathBuf>,
+    /// Number of most recent snapshots kept in `backup_dir`; older ones are deleted
+    /// after each backup.
+    /// Env override: `CHORROSION_DATABASE__BACKUP_RETENTION`.
+    pub backup_retention: usize,
 }
 
 impl Default for DatabaseConfig {
@@ -51,14 +65,34 @@ impl Default for DatabaseConfig {
             pool_idle_timeout_secs: 600,
             pool_max_lifetime_secs: 1800,
             slow_query_threshold_ms: 50,
+            strict_status_parsing: false,
+            backup_dir: None,
+            backup_retention: 7,
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(default)]
 pub struct HttpConfig {
     pub host: String,
     pub port: u16,
+    /// Proxy for outbound requests to MusicBrainz, AcoustID, indexers and
+    /// download clients, e.g. `http://proxy.internal:3128`. Unset connects directly.
+    /// Env override: `CHORROSION_HTTP__PROXY_URL`.
+    pub proxy_url: Option<String>,
+    /// User-Agent sent to MusicBrainz, indexers and download clients
+    /// instead of the default `Chorrosion/<version> (+https://github.com/SvetaKrava/chorrosion)`.
+    /// MusicBrainz expects it to include contact details.
+    /// Env override: `CHORROSION_HTTP__USER_AGENT`.
+    pub user_agent: Option<String>,
+    /// Requests each caller may make to `/api/v1` per rate-limit window. `0`
+    /// disables rate limiting.
+    /// Env override: `CHORROSION_HTTP__RATE_LIMIT_REQUESTS`.
+    pub rate_limit_requests: u32,
+    /// Length of the rate-limit window in seconds.
+    /// Env override: `CHORROSION_HTTP__RATE_LIMIT_WINDOW_SECONDS`.
+    pub rate_limit_window_seconds: u64,
 }
 
 impl Default for HttpConfig {
@@ -66,17 +100,26 @@ impl Default for HttpConfig {
         Self {
             host: "127.0.0.1".to_string(),
             port: 5150,
+            proxy_url: None,
+            user_agent: None,
+            rate_limit_requests: 0,
+            rate_limit_window_seconds: 60,
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(default)]
 pub struct TelemetryConfig {
     pub log_level: String,
     /// HTTP requests that take longer than this threshold (in milliseconds) are logged at WARN
     /// level.  Set to `0` to disable slow-request logging.
     /// Env override: `CHORROSION_TELEMETRY__SLOW_REQUEST_THRESHOLD_MS`.
     pub slow_request_threshold_ms: u64,
+    /// Require the usual API credentials on `GET /metrics`. Off by default so
+    /// Prometheus scrapers can reach it.
+    /// Env override: `CHORROSION_TELEMETRY__METRICS_REQUIRE_AUTH`.
+    pub metrics_require_auth: bool,
 }
 
 impl Default for TelemetryConfig {
@@ -84,11 +127,13 @@ impl Default for TelemetryConfig {
         Self {
             log_level: "info".to_string(),
             slow_request_threshold_ms: 500,
+            metrics_require_auth: false,
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(default)]
 pub struct SchedulerConfig {
     pub max_concurrent_jobs: usize,
     /// Maximum number of files to process concurrently in a batch import.
@@ -100,6 +145,22 @@ pub struct SchedulerConfig {
     /// constructing a `FileImportService` in the application layer.
     /// Env override: `CHORROSION_SCHEDULER__MAX_CONCURRENT_IMPORTS`.
     pub max_concurrent_imports: usize,
+    /// Maximum time (in seconds) to wait for running jobs to finish on shutdown
+    /// before abandoning them.
+    /// Env override: `CHORROSION_SCHEDULER__SHUTDOWN_TIMEOUT_SECS`.
+    pub shutdown_timeout_secs: u64,
+    /// Number of most recent `job_runs` history rows kept by the housekeeping job;
+    /// older rows are deleted on each housekeeping run.
+    /// Env override: `CHORROSION_SCHEDULER__JOB_HISTORY_RETENTION`.
+    pub job_history_retention: usize,
+    /// Upper bound (in seconds) for the exponential backoff between job retries.
+    /// Env override: `CHORROSION_SCHEDULER__MAX_RETRY_DELAY_SECS`.
+    pub max_retry_delay_secs: u64,
+    /// Per-job-type concurrency limits, keyed by job type (e.g. `refresh_artist`).
+    /// Jobs over their type's limit wait for a slot; `max_concurrent_jobs`
+    /// still caps the total. Types not listed are only bound by the global cap.
+    /// Env override: `CHORROSION_SCHEDULER__JOB_TYPE_CONCURRENCY__<JOB_TYPE>`.
+    pub job_type_concurrency: BTreeMap<String, usize>,
 }
 
 impl Default for SchedulerConfig {
@@ -107,11 +168,22 @@ impl Default for SchedulerConfig {
         Self {
             max_concurrent_jobs: 8,
             max_concurrent_imports: 8,
+            shutdown_timeout_secs: 30,
+            job_history_retention: 1000,
+            max_retry_delay_secs: 3600,
+            // MusicBrainz allows one request per second, so parallel refreshes only queue on the rate limiter.
+            job_type_concurrency: BTreeMap::from([
+                ("refresh_album".to_string(), 1),
+                ("refresh_artist".to_string(), 1),
+                ("rss_sync".to_string(), 2),
+            ]),
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default, ToSchema)]
+#[derive(
+    Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Default, ToSchema,
+)]
 #[serde(rename_all = "snake_case")]
 pub enum PermissionLevel {
     ReadOnly,
@@ -119,8 +191,89 @@ pub enum PermissionLevel {
     Admin,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl PermissionLevel {
+    /// Highest [`AuthScope`] granted by this permission level.
+    pub fn scope(self) -> AuthScope {
+        match self {
+            PermissionLevel::ReadOnly => AuthScope::Read,
+            PermissionLevel::Admin => AuthScope::Admin,
+        }
+    }
+}
+
+/// Access scope needed by an API route and granted to a credential.
+///
+/// Scopes are ordered: `admin` implies `write`, which implies `read`.
+#[derive(
+    Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthScope {
+    /// Non-mutating requests
+    Read,
+    /// Requests that create, change or delete library data
+    Write,
+    /// System, job and API key management
+    Admin,
+}
+
+/// Credential scheme accepted from programmatic API clients.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthScheme {
+    /// `X-Api-Key` (or `Authorization: Bearer <key>`) matching an issued API key.
+    ApiKey,
+    /// `Authorization: Bearer <jwt>` verified against [`BearerAuthConfig`].
+    BearerJwt,
+}
+
+/// Verification settings for bearer JWTs issued by an external identity provider.
+#[derive(Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[schemars(default)]
+pub struct BearerAuthConfig {
+    /// Shared secret for HMAC-signed (`HS256`/`HS384`/`HS512`) tokens.
+    /// Env override: `CHORROSION_AUTH__BEARER__SHARED_SECRET`.
+    pub shared_secret: Option<String>,
+    /// JWKS endpoint publishing the keys for asymmetrically signed tokens.
+    /// Env override: `CHORROSION_AUTH__BEARER__JWKS_URL`.
+    pub jwks_url: Option<String>,
+    /// Required `iss` claim; any issuer is accepted when unset.
+    pub issuer: Option<String>,
+    /// Required `aud` claim; the audience is not checked when unset.
+    pub audience: Option<String>,
+    /// Permission granted to requests carrying a valid token.
+    pub permission_level: PermissionLevel,
+}
+
+impl std::fmt::Debug for BearerAuthConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BearerAuthConfig")
+            .field(
+                "shared_secret",
+                &self.shared_secret.as_ref().map(|_| "[REDACTED]"),
+            )
+            .field("jwks_url", &self.jwks_url)
+            .field("issuer", &self.issuer)
+            .field("audience", &self.audience)
+            .field("permission_level", &self.permission_level)
+            .finish()
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(default)]
 pub struct AuthConfig {
+    /// When `false`, every request is let through without credentials.
+    /// Env override: `CHORROSION_AUTH__ENABLED`.
+    pub enabled: bool,
+    /// Schemes accepted from API clients; Basic auth and forms sessions are
+    /// governed by their own settings.
+    /// Env override: `CHORROSION_AUTH__SCHEMES` (e.g. `["api_key","bearer_jwt"]`).
+    pub schemes: Vec<AuthScheme>,
+    pub bearer: BearerAuthConfig,
+    /// API keys defined in configuration, mapped to the scopes they grant.
+    /// While any are defined, the unauthenticated first-key bootstrap is disabled.
+    pub api_key_scopes: BTreeMap<String, Vec<AuthScope>>,
     pub basic_username: Option<String>,
     pub basic_password: Option<String>,
     pub basic_permission_level: PermissionLevel,
@@ -137,9 +290,37 @@ impl AuthConfig {
     }
 }
 
+impl std::fmt::Debug for AuthConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The scope map is keyed by the raw API keys, so only its size and the
+        // granted scopes are printed.
+        f.debug_struct("AuthConfig")
+            .field("enabled", &self.enabled)
+            .field("schemes", &self.schemes)
+            .field("bearer", &self.bearer)
+            .field("api_key_count", &self.api_key_scopes.len())
+            .field(
+                "api_key_scopes",
+                &self.api_key_scopes.values().collect::<Vec<_>>(),
+            )
+            .field("basic_username", &self.basic_username)
+            .field(
+                "basic_password",
+                &self.basic_password.as_ref().map(|_| "[REDACTED]"),
+            )
+            .field("basic_permission_level", &self.basic_permission_level)
+            .field("forms_cookie_secure", &self.forms_cookie_secure)
+            .finish()
+    }
+}
+
 impl Default for AuthConfig {
     fn default() -> Self {
         Self {
+            enabled: true,
+            schemes: vec![AuthScheme::ApiKey],
+            bearer: BearerAuthConfig::default(),
+            api_key_scopes: BTreeMap::new(),
             basic_username: None,
             basic_password: None,
             basic_permission_level: PermissionLevel::default(),
@@ -148,7 +329,8 @@ impl Default for AuthConfig {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(default)]
 pub struct WebConfig {
     /// Browser origins allowed by API CORS policy.
     /// Env override: `CHORROSION_WEB__ALLOWED_ORIGINS` accepts either a
@@ -174,25 +356,26 @@ impl Default for WebConfig {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct LastFmAlbumSeed {
     pub artist: String,
     pub album: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DiscogsAlbumSeed {
     pub artist: String,
     pub album: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct LyricsTrackSeed {
     pub artist: String,
     pub title: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(default)]
 pub struct LastFmConfig {
     pub api_key: Option<String>,
     pub base_url: Option<String>,
@@ -202,6 +385,25 @@ pub struct LastFmConfig {
     pub seed_albums: Vec<LastFmAlbumSeed>,
 }
 
+impl LastFmConfig {
+    pub fn api_key(&self) -> Option<&str> {
+        self.api_key.as_deref()
+    }
+}
+
+impl std::fmt::Debug for LastFmConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LastFmConfig")
+            .field("api_key", &self.api_key.as_ref().map(|_| "[REDACTED]"))
+            .field("base_url", &self.base_url)
+            .field("max_concurrent_requests", &self.max_concurrent_requests)
+            .field("request_timeout_seconds", &self.request_timeout_seconds)
+            .field("seed_artists", &self.seed_artists)
+            .field("seed_albums", &self.seed_albums)
+            .finish()
+    }
+}
+
 impl Default for LastFmConfig {
     fn default() -> Self {
         Self {
@@ -215,7 +417,8 @@ impl Default for LastFmConfig {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(default)]
 pub struct DiscogsConfig {
     pub token: Option<String>,
     pub base_url: Option<String>,
@@ -238,7 +441,8 @@ impl Default for DiscogsConfig {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(default)]
 pub struct LyricsConfig {
     pub base_url: Option<String>,
     pub max_concurrent_requests: usize,
@@ -257,7 +461,8 @@ impl Default for LyricsConfig {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(default)]
 pub struct CoverArtConfig {
     pub fanart_api_key: Option<String>,
     pub fanart_client_key: Option<String>,
@@ -282,36 +487,61 @@ impl Default for CoverArtConfig {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(default)]
+pub struct MusicBrainzConfig {
+    /// Minimum milliseconds between MusicBrainz requests, shared by every job
+    /// that talks to MusicBrainz. MusicBrainz allows one request per second.
+    pub rate_limit_interval_ms: u64,
+}
+
+impl Default for MusicBrainzConfig {
+    fn default() -> Self {
+        Self {
+            rate_limit_interval_ms: 1000,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Default)]
+#[schemars(default)]
 pub struct MetadataConfig {
+    pub musicbrainz: MusicBrainzConfig,
     pub lastfm: LastFmConfig,
     pub discogs: DiscogsConfig,
     pub lyrics: LyricsConfig,
     pub cover_art: CoverArtConfig,
+    /// Leave release groups the artist's metadata profile rejects out of the
+    /// library on refresh, instead of adding them unmonitored.
+    pub skip_filtered_release_groups: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Default)]
+#[schemars(default)]
 pub struct EmailNotificationConfig {
     pub enabled: bool,
     pub from: Option<String>,
     pub to: Vec<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Default)]
+#[schemars(default)]
 pub struct DiscordNotificationConfig {
     pub enabled: bool,
     pub webhook_url: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Default)]
+#[schemars(default)]
 pub struct SlackNotificationConfig {
     pub enabled: bool,
     pub webhook_url: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Default)]
+#[schemars(default)]
 pub struct PushoverNotificationConfig {
     pub enabled: bool,
     pub api_token: Option<String>,
@@ -319,7 +549,8 @@ pub struct PushoverNotificationConfig {
     pub api_url: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Default)]
+#[schemars(default)]
 pub struct ScriptNotificationConfig {
     pub enabled: bool,
     pub command: Option<String>,
@@ -327,7 +558,8 @@ pub struct ScriptNotificationConfig {
     pub working_dir: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Default)]
+#[schemars(default)]
 pub struct MusicBrainzListsConfig {
     pub enabled: bool,
     pub base_url: Option<String>,
@@ -335,7 +567,8 @@ pub struct MusicBrainzListsConfig {
     pub album_mbids: Vec<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Default)]
+#[schemars(default)]
 pub struct SpotifyListsConfig {
     pub enabled: bool,
     #[serde(alias = "api_base_url")]
@@ -345,13 +578,14 @@ pub struct SpotifyListsConfig {
     pub market: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct LastFmListsAlbumSeed {
     pub artist: String,
     pub album: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Default)]
+#[schemars(default)]
 pub struct LastFmListsConfig {
     pub enabled: bool,
     pub api_key: Option<String>,
@@ -360,14 +594,16 @@ pub struct LastFmListsConfig {
     pub album_seeds: Vec<LastFmListsAlbumSeed>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Default)]
+#[schemars(default)]
 pub struct ListsConfig {
     pub musicbrainz: MusicBrainzListsConfig,
     pub spotify: SpotifyListsConfig,
     pub lastfm: LastFmListsConfig,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Default)]
+#[schemars(default)]
 pub struct NotificationsConfig {
     pub email: EmailNotificationConfig,
     pub discord: DiscordNotificationConfig,
@@ -376,7 +612,8 @@ pub struct NotificationsConfig {
     pub script: ScriptNotificationConfig,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(default)]
 pub struct CacheConfig {
     /// TTL in seconds for cached API GET responses. Set to 0 to disable.
     pub api_response_ttl_seconds: u64,
@@ -414,8 +651,63 @@ impl Default for CacheConfig {
     }
 }
 
+/// Extensions treated as audio when `library.audio_extensions` is empty.
+pub const DEFAULT_AUDIO_EXTENSIONS: &[&str] = &[
+    "flac", "mp3", "m4a", "ogg", "opus", "wav", "aac", "wv", "ape",
+];
+
+/// How a file is placed into the library when imported from another location.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportMode {
+    /// Copy the file, leaving the source untouched.
+    #[default]
+    Copy,
+    /// Move the file, removing the source.
+    Move,
+    /// Hardlink the file, falling back to a copy when the source and
+    /// destination are on different filesystems or links are unsupported.
+    Hardlink,
+}
+
+/// Directories that hold the music library. File operations are confined to these.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(default)]
+pub struct LibraryConfig {
+    /// Absolute library root directories. Imports, generated paths and file
+    /// deletions must resolve inside one of them; when empty, only the
+    /// artist's own path confines file deletion.
+    pub root_paths: Vec<PathBuf>,
+    /// Absolute directories, such as finished-download folders, that directory
+    /// imports may scan in addition to `root_paths`.
+    /// Env override: `CHORROSION_LIBRARY__IMPORT_PATHS` (an array, e.g. `["/downloads/music"]`).
+    pub import_paths: Vec<PathBuf>,
+    /// File extensions import scans treat as audio, matched case-insensitively
+    /// with or without a leading dot. An empty list means the defaults.
+    /// Env override: `CHORROSION_LIBRARY__AUDIO_EXTENSIONS` (an array, e.g. `["flac","mp3"]`).
+    pub audio_extensions: Vec<String>,
+    /// How imported files are placed into the library: `copy`, `move` or `hardlink`.
+    /// Env override: `CHORROSION_LIBRARY__IMPORT_MODE`.
+    pub import_mode: ImportMode,
+}
+
+impl Default for LibraryConfig {
+    fn default() -> Self {
+        Self {
+            root_paths: Vec::new(),
+            import_paths: Vec::new(),
+            import_mode: ImportMode::default(),
+            audio_extensions: DEFAULT_AUDIO_EXTENSIONS
+                .iter()
+                .map(|extension| extension.to_string())
+                .collect(),
+        }
+    }
+}
+
 /// Configuration for the activity monitoring subsystem.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(default)]
 pub struct ActivityConfig {
     /// Number of seconds without progress before a download is considered stalled.
     ///
@@ -431,7 +723,79 @@ impl Default for ActivityConfig {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+/// Automatic search settings.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Default)]
+#[schemars(default)]
+pub struct SearchConfig {
+    /// Minutes a Usenet release must have been published before automatic search grabs it.
+    ///
+    /// Env override: `CHORROSION_SEARCH__USENET_DELAY_MINUTES`.
+    pub usenet_delay_minutes: u32,
+    /// Minutes a torrent release must have been published before automatic search grabs it.
+    ///
+    /// Env override: `CHORROSION_SEARCH__TORRENT_DELAY_MINUTES`.
+    pub torrent_delay_minutes: u32,
+    /// Torrents with fewer seeders than this are never grabbed. Usenet releases are not checked.
+    ///
+    /// Env override: `CHORROSION_SEARCH__MIN_SEEDERS`.
+    pub min_seeders: Option<u32>,
+    /// Drop torrents whose indexer reports no seeder count when `min_seeders` is set,
+    /// instead of keeping them.
+    ///
+    /// Env override: `CHORROSION_SEARCH__REJECT_UNKNOWN_SEEDERS`.
+    pub reject_unknown_seeders: bool,
+    /// Torrents published more than this many days ago are never grabbed.
+    ///
+    /// Env override: `CHORROSION_SEARCH__MAX_AGE_DAYS`.
+    pub max_age_days: Option<u32>,
+}
+
+/// A Newznab, Torznab, Gazelle, or custom indexer endpoint.
+///
+/// Mirrors the application crate's `IndexerConfig`, which converts from this
+/// type; `protocol` is kept as a string to avoid a dependency cycle.
+///
+/// Declared as `[[indexers]]` tables in TOML, or as an inline array via
+/// `CHORROSION_INDEXERS='[{name="...", base_url="...", protocol="newznab"}]'`.
+#[derive(Clone, Serialize, Deserialize, JsonSchema)]
+pub struct IndexerConfig {
+    pub name: String,
+    pub base_url: String,
+    pub protocol: String,
+    #[serde(default)]
+    pub api_key: Option<String>,
+    #[serde(default = "default_indexer_enabled")]
+    pub enabled: bool,
+    /// Maximum number of requests per minute sent to this indexer. `None` disables limiting.
+    #[serde(default)]
+    pub requests_per_minute: Option<u32>,
+}
+
+fn default_indexer_enabled() -> bool {
+    true
+}
+
+impl IndexerConfig {
+    pub fn api_key(&self) -> Option<&str> {
+        self.api_key.as_deref()
+    }
+}
+
+impl std::fmt::Debug for IndexerConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IndexerConfig")
+            .field("name", &self.name)
+            .field("base_url", &self.base_url)
+            .field("protocol", &self.protocol)
+            .field("api_key", &self.api_key.as_ref().map(|_| "[REDACTED]"))
+            .field("enabled", &self.enabled)
+            .field("requests_per_minute", &self.requests_per_minute)
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Default)]
+#[schemars(default)]
 pub struct AppConfig {
     pub database: DatabaseConfig,
     pub http: HttpConfig,
@@ -444,6 +808,133 @@ pub struct AppConfig {
     pub lists: ListsConfig,
     pub activity: ActivityConfig,
     pub web: WebConfig,
+    pub library: LibraryConfig,
+    pub search: SearchConfig,
+    pub indexers: Vec<IndexerConfig>,
+}
+
+impl AppConfig {
+    /// Check invariants that deserialization cannot express, reporting every
+    /// problem at once rather than failing later at connect or startup time.
+    pub fn validate(&self) -> Result<()> {
+        let mut problems = Vec::new();
+
+        if self.database.url.trim().is_empty() {
+            problems.push("database.url must not be empty".to_string());
+        }
+        if self.database.pool_max_size == 0 {
+            problems.push("database.pool_max_size must be at least 1".to_string());
+        }
+        if self.database.backup_dir.is_some() && self.database.backup_retention == 0 {
+            problems.push(
+                "database.backup_retention must be at least 1 when backup_dir is set".to_string(),
+            );
+        }
+        if self.http.port == 0 {
+            problems.push("http.port must not be 0".to_string());
+        }
+        if let Some(proxy_url) = &self.http.proxy_url {
+            match url::Url::parse(proxy_url) {
+                Ok(url) if matches!(url.scheme(), "http" | "https") && url.has_host() => {}
+                _ => problems.push("http.proxy_url must be an http:// or https:// URL".to_string()),
+            }
+        }
+        if let Some(user_agent) = &self.http.user_agent {
+            if user_agent.trim().is_empty()
+                || !user_agent.chars().all(|c| c == ' ' || c.is_ascii_graphic())
+            {
+                problems.push("http.user_agent must be non-empty printable ASCII".to_string());
+            }
+        }
+        if self.http.rate_limit_requests > 0 && self.http.rate_limit_window_seconds == 0 {
+            problems.push(
+                "http.rate_limit_window_seconds must be at least 1 when rate limiting is enabled"
+                    .to_string(),
+            );
+        }
+        if self.auth.schemes.contains(&AuthScheme::BearerJwt) {
+            let bearer = &self.auth.bearer;
+            let has_secret = bearer
+                .shared_secret
+                .as_deref()
+                .is_some_and(|secret| !secret.is_empty());
+            if !has_secret && bearer.jwks_url.is_none() {
+                problems.push(
+                    "auth.bearer needs a shared_secret or jwks_url when bearer_jwt is enabled"
+                        .to_string(),
+                );
+            }
+        }
+        for (key, scopes) in &self.auth.api_key_scopes {
+            if key.trim().is_empty() {
+                problems.push("auth.api_key_scopes must not contain an empty key".to_string());
+            } else if scopes.is_empty() {
+                // Never echo the key itself into logs or error messages.
+                problems
+                    .push("auth.api_key_scopes entries must grant at least one scope".to_string());
+            }
+        }
+        if let Some(jwks_url) = &self.auth.bearer.jwks_url {
+            match url::Url::parse(jwks_url) {
+                Ok(url) if matches!(url.scheme(), "http" | "https") && url.has_host() => {}
+                _ => problems
+                    .push("auth.bearer.jwks_url must be an http:// or https:// URL".to_string()),
+            }
+        }
+        if self.telemetry.log_level.parse::<tracing::Level>().is_err() {
+            problems.push(format!(
+                "telemetry.log_level '{}' is not one of trace, debug, info, warn, error",
+                self.telemetry.log_level
+            ));
+        }
+        if self.scheduler.max_concurrent_jobs == 0 {
+            problems.push("scheduler.max_concurrent_jobs must be at least 1".to_string());
+        }
+        for (job_type, limit) in &self.scheduler.job_type_concurrency {
+            if *limit == 0 {
+                problems.push(format!(
+                    "scheduler.job_type_concurrency.{job_type} must be at least 1"
+                ));
+            }
+        }
+        if self.metadata.musicbrainz.rate_limit_interval_ms < 1000 {
+            problems.push(
+                "metadata.musicbrainz.rate_limit_interval_ms must be at least 1000".to_string(),
+            );
+        }
+        for root in &self.library.root_paths {
+            if !root.is_absolute() {
+                problems.push(format!(
+                    "library.root_paths entry '{}' must be an absolute path",
+                    root.display()
+                ));
+            }
+        }
+        for path in &self.library.import_paths {
+            if !path.is_absolute() {
+                problems.push(format!(
+                    "library.import_paths entry '{}' must be an absolute path",
+                    path.display()
+                ));
+            }
+        }
+
+        let mut indexer_names = std::collections::HashSet::new();
+        for indexer in &self.indexers {
+            let name = indexer.name.trim().to_lowercase();
+            if !indexer_names.insert(name) {
+                problems.push(format!(
+                    "indexers contains duplicate name '{}'",
+                    indexer.name.trim()
+                ));
+            }
+        }
+
+        if !problems.is_empty() {
+            bail!("invalid configuration: {}", problems.join("; "));
+        }
+        Ok(())
+    }
 }
 
 /// Custom Figment provider that reads `CHORROSION_WEB__ALLOWED_ORIGINS` from the
@@ -495,6 +986,13 @@ impl Provider for CommaSplitAllowedOrigins {
     }
 }
 
+/// JSON Schema describing every `AppConfig` section, including defaults, for
+/// editor autocompletion of `config.toml`.
+pub fn json_schema() -> serde_json::Value {
+    serde_json::to_value(schemars::schema_for!(AppConfig))
+        .expect("AppConfig schema is always serializable")
+}
+
 /// Load configuration from defaults, optional TOML file, and environment overrides (prefix: CHORROSION_).
 pub fn load(config_path: Option<&Path>) -> Result<AppConfig> {
     let mut figment = Figment::from(Serialized::defaults(AppConfig::default()));
@@ -508,6 +1006,369 @@ pub fn load(config_path: Option<&Path>) -> Result<AppConfig> {
         .merge(CommaSplitAllowedOrigins);
 
     let config: AppConfig = figment.extract()?;
+    config.validate()?;
     info!(target: "config", "configuration loaded");
     Ok(config)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validation_error(config: &AppConfig) -> String {
+        config
+            .validate()
+            .expect_err("config should be rejected")
+            .to_string()
+    }
+
+    #[test]
+    fn default_config_is_valid() {
+        AppConfig::default()
+            .validate()
+            .expect("defaults should validate");
+    }
+
+    #[test]
+    fn lastfm_debug_redacts_api_key() {
+        let config = LastFmConfig {
+            api_key: Some("lastfm-secret".to_string()),
+            ..LastFmConfig::default()
+        };
+
+        let debug = format!("{config:?}");
+        assert!(!debug.contains("lastfm-secret"), "leaked key: {debug}");
+        assert!(debug.contains("[REDACTED]"));
+        assert_eq!(config.api_key(), Some("lastfm-secret"));
+    }
+
+    #[test]
+    fn bearer_debug_redacts_shared_secret() {
+        let config = BearerAuthConfig {
+            shared_secret: Some("hmac-signing-secret".to_string()),
+            ..BearerAuthConfig::default()
+        };
+
+        let debug = format!("{config:?}");
+        assert!(
+            !debug.contains("hmac-signing-secret"),
+            "leaked secret: {debug}"
+        );
+        assert!(debug.contains("[REDACTED]"));
+    }
+
+    #[test]
+    fn auth_debug_redacts_api_keys_and_basic_password() {
+        let mut config = AuthConfig {
+            basic_username: Some("admin".to_string()),
+            basic_password: Some("basic-password-secret".to_string()),
+            ..AuthConfig::default()
+        };
+        config
+            .api_key_scopes
+            .insert("scoped-api-key-secret".to_string(), vec![AuthScope::Admin]);
+
+        let debug = format!("{config:?}");
+        assert!(
+            !debug.contains("scoped-api-key-secret"),
+            "leaked key: {debug}"
+        );
+        assert!(
+            !debug.contains("basic-password-secret"),
+            "leaked password: {debug}"
+        );
+        assert!(debug.contains("api_key_count: 1"));
+        assert!(debug.contains("Admin"));
+    }
+
+    #[test]
+    fn lastfm_api_key_survives_serialization() {
+        let config = LastFmConfig {
+            api_key: Some("lastfm-secret".to_string()),
+            ..LastFmConfig::default()
+        };
+
+        let figment = Figment::from(Serialized::defaults(&config));
+        let round_tripped: LastFmConfig = figment.extract().expect("extract");
+        assert_eq!(round_tripped.api_key(), Some("lastfm-secret"));
+    }
+
+    const INDEXERS_TOML: &str = r#"
+[[indexers]]
+name = "Example Newznab"
+base_url = "https://newznab.example"
+protocol = "newznab"
+api_key = "newznab-key"
+requests_per_minute = 30
+
+[[indexers]]
+name = "Example Gazelle"
+base_url = "https://gazelle.example"
+protocol = "gazelle"
+enabled = false
+"#;
+
+    fn write_fixture(contents: &str) -> tempfile::NamedTempFile {
+        let mut file = tempfile::Builder::new()
+            .suffix(".toml")
+            .tempfile()
+            .expect("temp file");
+        std::io::Write::write_all(&mut file, contents.as_bytes()).expect("write fixture");
+        file
+    }
+
+    #[test]
+    fn loads_indexers_from_toml() {
+        let fixture = write_fixture(INDEXERS_TOML);
+        let config = load(Some(fixture.path())).expect("config loads");
+
+        assert_eq!(config.indexers.len(), 2);
+
+        let newznab = &config.indexers[0];
+        assert_eq!(newznab.name, "Example Newznab");
+        assert_eq!(newznab.base_url, "https://newznab.example");
+        assert_eq!(newznab.protocol, "newznab");
+        assert_eq!(newznab.api_key(), Some("newznab-key"));
+        assert!(newznab.enabled);
+        assert_eq!(newznab.requests_per_minute, Some(30));
+
+        let gazelle = &config.indexers[1];
+        assert_eq!(gazelle.name, "Example Gazelle");
+        assert_eq!(gazelle.protocol, "gazelle");
+        assert_eq!(gazelle.api_key(), None);
+        assert!(!gazelle.enabled);
+        assert_eq!(gazelle.requests_per_minute, None);
+    }
+
+    #[test]
+    fn load_rejects_duplicate_indexer_names() {
+        let fixture = write_fixture(&format!(
+            "{INDEXERS_TOML}\n[[indexers]]\nname = \"example newznab\"\nbase_url = \"https://other.example\"\nprotocol = \"torznab\"\n"
+        ));
+
+        let error = load(Some(fixture.path()))
+            .expect_err("duplicate names should be rejected")
+            .to_string();
+        assert!(
+            error.contains("duplicate name 'example newznab'"),
+            "unexpected error: {error}"
+        );
+    }
+
+    #[test]
+    fn json_schema_describes_core_sections_with_defaults() {
+        let schema = json_schema();
+        let definitions = &schema["definitions"];
+
+        for section in ["database", "http", "scheduler", "metadata"] {
+            assert!(
+                schema["properties"][section].is_object(),
+                "missing section {section}"
+            );
+        }
+
+        let database = &definitions["DatabaseConfig"]["properties"];
+        assert_eq!(database["url"]["type"], "string");
+        assert_eq!(database["url"]["default"], "sqlite://chorrosion.db");
+        assert_eq!(database["pool_max_size"]["type"], "integer");
+        assert_eq!(database["pool_max_size"]["default"], 16);
+
+        let http = &definitions["HttpConfig"]["properties"];
+        assert_eq!(http["host"]["type"], "string");
+        assert_eq!(http["port"]["type"], "integer");
+        assert_eq!(http["port"]["default"], 5150);
+
+        let scheduler = &definitions["SchedulerConfig"]["properties"];
+        assert_eq!(scheduler["max_concurrent_jobs"]["type"], "integer");
+        assert_eq!(scheduler["max_concurrent_imports"]["type"], "integer");
+
+        let metadata = &definitions["MetadataConfig"]["properties"];
+        for provider in ["lastfm", "discogs", "lyrics", "cover_art"] {
+            assert!(
+                metadata[provider].is_object(),
+                "missing metadata.{provider}"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_empty_database_url() {
+        let mut config = AppConfig::default();
+        config.database.url = "  ".to_string();
+        assert!(validation_error(&config).contains("database.url"));
+    }
+
+    #[test]
+    fn rejects_zero_pool_max_size() {
+        let mut config = AppConfig::default();
+        config.database.pool_max_size = 0;
+        assert!(validation_error(&config).contains("database.pool_max_size"));
+    }
+
+    #[test]
+    fn rejects_zero_port() {
+        let mut config = AppConfig::default();
+        config.http.port = 0;
+        assert!(validation_error(&config).contains("http.port"));
+    }
+
+    #[test]
+    fn rejects_zero_backup_retention_when_backups_enabled() {
+        let mut config = AppConfig::default();
+        config.database.backup_retention = 0;
+        assert!(
+            config.validate().is_ok(),
+            "retention is unused without a backup dir"
+        );
+
+        config.database.backup_dir = Some(PathBuf::from("backups"));
+        assert!(validation_error(&config).contains("database.backup_retention"));
+    }
+
+    #[test]
+    fn rejects_zero_rate_limit_window_when_enabled() {
+        let mut config = AppConfig::default();
+        config.http.rate_limit_window_seconds = 0;
+        assert!(
+            config.validate().is_ok(),
+            "disabled rate limiting ignores the window"
+        );
+
+        config.http.rate_limit_requests = 100;
+        assert!(validation_error(&config).contains("http.rate_limit_window_seconds"));
+    }
+
+    #[test]
+    fn accepts_http_proxy_url() {
+        let mut config = AppConfig::default();
+        config.http.proxy_url = Some("http://proxy.internal:3128".to_string());
+        config.validate().expect("proxy URL is valid");
+    }
+
+    #[test]
+    fn rejects_invalid_proxy_url() {
+        let mut config = AppConfig::default();
+        config.http.proxy_url = Some("proxy.internal:3128".to_string());
+        assert!(validation_error(&config).contains("http.proxy_url"));
+
+        config.http.proxy_url = Some("ftp://proxy.internal".to_string());
+        assert!(validation_error(&config).contains("http.proxy_url"));
+    }
+
+    #[test]
+    fn rejects_blank_or_non_ascii_user_agent() {
+        let mut config = AppConfig::default();
+        config.http.user_agent = Some("MyApp/1.0 ( ops@example.com )".to_string());
+        config.validate().expect("printable user agent is valid");
+
+        config.http.user_agent = Some("  ".to_string());
+        assert!(validation_error(&config).contains("http.user_agent"));
+
+        config.http.user_agent = Some("MyApp/1.0\r\nX-Evil: 1".to_string());
+        assert!(validation_error(&config).contains("http.user_agent"));
+    }
+
+    #[test]
+    fn rejects_bearer_scheme_without_verification_key() {
+        let mut config = AppConfig::default();
+        config.auth.schemes = vec![AuthScheme::ApiKey, AuthScheme::BearerJwt];
+        assert!(validation_error(&config).contains("auth.bearer"));
+
+        config.auth.bearer.shared_secret = Some("s3cret".to_string());
+        config.validate().expect("shared secret is enough");
+
+        config.auth.bearer.jwks_url = Some("idp.example.com/jwks".to_string());
+        assert!(validation_error(&config).contains("auth.bearer.jwks_url"));
+    }
+
+    #[test]
+    fn rejects_api_key_without_scopes() {
+        let mut config = AppConfig::default();
+        config
+            .auth
+            .api_key_scopes
+            .insert("dashboard-key".to_string(), vec![AuthScope::Read]);
+        config.validate().expect("scoped key is valid");
+
+        config
+            .auth
+            .api_key_scopes
+            .insert("orphan-key".to_string(), Vec::new());
+        let error = validation_error(&config);
+        assert!(error.contains("auth.api_key_scopes"));
+        assert!(!error.contains("orphan-key"));
+    }
+
+    #[test]
+    fn scopes_are_ordered_by_privilege() {
+        assert!(AuthScope::Admin > AuthScope::Write);
+        assert!(AuthScope::Write > AuthScope::Read);
+        assert_eq!(PermissionLevel::ReadOnly.scope(), AuthScope::Read);
+        assert_eq!(PermissionLevel::Admin.scope(), AuthScope::Admin);
+    }
+
+    #[test]
+    fn rejects_unknown_log_level() {
+        let mut config = AppConfig::default();
+        config.telemetry.log_level = "verbose".to_string();
+        assert!(validation_error(&config).contains("telemetry.log_level 'verbose'"));
+    }
+
+    #[test]
+    fn accepts_log_level_in_any_case() {
+        let mut config = AppConfig::default();
+        config.telemetry.log_level = "WARN".to_string();
+        config.validate().expect("WARN is a tracing level");
+    }
+
+    #[test]
+    fn rejects_zero_max_concurrent_jobs() {
+        let mut config = AppConfig::default();
+        config.scheduler.max_concurrent_jobs = 0;
+        assert!(validation_error(&config).contains("scheduler.max_concurrent_jobs"));
+    }
+
+    #[test]
+    fn rejects_zero_job_type_concurrency() {
+        let mut config = AppConfig::default();
+        config
+            .scheduler
+            .job_type_concurrency
+            .insert("rss_sync".to_string(), 0);
+        assert!(validation_error(&config).contains("scheduler.job_type_concurrency.rss_sync"));
+    }
+
+    #[test]
+    fn rejects_musicbrainz_rate_faster_than_one_per_second() {
+        let mut config = AppConfig::default();
+        config.metadata.musicbrainz.rate_limit_interval_ms = 500;
+        assert!(validation_error(&config).contains("metadata.musicbrainz.rate_limit_interval_ms"));
+    }
+
+    #[test]
+    fn rejects_relative_library_root() {
+        let mut config = AppConfig::default();
+        config.library.root_paths = vec![PathBuf::from("music")];
+        assert!(validation_error(&config).contains("library.root_paths"));
+    }
+
+    #[test]
+    fn rejects_relative_import_path() {
+        let mut config = AppConfig::default();
+        config.library.import_paths = vec![PathBuf::from("downloads")];
+        assert!(validation_error(&config).contains("library.import_paths"));
+    }
+
+    #[test]
+    fn reports_every_problem_together() {
+        let mut config = AppConfig::default();
+        config.database.url = String::new();
+        config.http.port = 0;
+        config.scheduler.max_concurrent_jobs = 0;
+
+        let error = validation_error(&config);
+        assert!(error.contains("database.url"));
+        assert!(error.contains("http.port"));
+        assert!(error.contains("scheduler.max_concurrent_jobs"));
+    }
+}
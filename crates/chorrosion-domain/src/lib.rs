@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 use chrono::{DateTime, Datelike, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 use uuid::Uuid;
 
@@ -320,13 +321,29 @@ pub enum ReleaseDatePrecision {
     Day,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+impl std::fmt::Display for ReleaseDatePrecision {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Year => write!(f, "year"),
+            Self::Month => write!(f, "month"),
+            Self::Day => write!(f, "day"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ReleaseDate {
     pub year: i32,
     pub month: Option<u32>,
     pub day: Option<u32>,
 }
 
+impl From<NaiveDate> for ReleaseDate {
+    fn from(date: NaiveDate) -> Self {
+        Self::new(date.year(), Some(date.month()), Some(date.day()))
+    }
+}
+
 impl ReleaseDate {
     pub fn new(year: i32, month: Option<u32>, day: Option<u32>) -> Self {
         Self { year, month, day }
@@ -540,6 +557,9 @@ pub struct Artist {
     pub disambiguation: Option<String>,
     pub genre_tags: Option<String>,
     pub style_tags: Option<String>,
+    /// When a metadata refresh job last updated this artist; written only
+    /// through `ArtistRepository::record_metadata_refresh`.
+    pub last_metadata_refresh_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -563,6 +583,7 @@ impl Artist {
             disambiguation: None,
             genre_tags: None,
             style_tags: None,
+            last_metadata_refresh_at: None,
             created_at: now,
             updated_at: now,
         }
@@ -577,7 +598,9 @@ pub struct Album {
     pub musicbrainz_release_group_id: Option<String>,
     pub musicbrainz_release_id: Option<String>,
     pub title: String,
-    pub release_date: Option<chrono::NaiveDate>,
+    /// Kept at the precision it was known with; persisted as
+    /// [`ReleaseDate::to_iso8601`] (`2024`, `2024-03` or `2024-03-15`).
+    pub release_date: Option<ReleaseDate>,
     pub album_type: Option<String>,
     pub primary_type: Option<String>,
     pub secondary_types: Option<String>,
@@ -586,6 +609,9 @@ pub struct Album {
     pub style_tags: Option<String>,
     pub status: AlbumStatus,
     pub monitored: bool,
+    /// When a metadata refresh job last updated this album; written only
+    /// through `AlbumRepository::record_metadata_refresh`.
+    pub last_metadata_refresh_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -609,6 +635,7 @@ impl Album {
             style_tags: None,
             status: AlbumStatus::Wanted,
             monitored: true,
+            last_metadata_refresh_at: None,
             created_at: now,
             updated_at: now,
         }
@@ -683,13 +710,17 @@ impl Track {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct QualityProfile {
     pub id: ProfileId,
     pub name: String,
     pub allowed_qualities: Vec<String>,
     pub upgrade_allowed: bool,
     pub cutoff_quality: Option<String>,
+    /// Applied to new artists created without a quality profile. At most one
+    /// profile is the default; change it through the repository's `set_default`.
+    #[serde(default)]
+    pub is_default: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -703,6 +734,7 @@ impl QualityProfile {
             allowed_qualities,
             upgrade_allowed: false,
             cutoff_quality: None,
+            is_default: false,
             created_at: now,
             updated_at: now,
         }
@@ -716,6 +748,10 @@ pub struct MetadataProfile {
     pub primary_album_types: Vec<String>,
     pub secondary_album_types: Vec<String>,
     pub release_statuses: Vec<String>,
+    /// Applied to new artists created without a metadata profile. At most one
+    /// profile is the default; change it through the repository's `set_default`.
+    #[serde(default)]
+    pub is_default: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -729,12 +765,49 @@ impl MetadataProfile {
             primary_album_types: vec![],
             secondary_album_types: vec![],
             release_statuses: vec![],
+            is_default: false,
             created_at: now,
             updated_at: now,
         }
     }
+
+    /// Whether an album of the given MusicBrainz types and release status belongs in the
+    /// library under this profile.
+    ///
+    /// An empty list leaves that attribute unrestricted, and an unknown `status` is
+    /// never rejected. Every secondary type must be allowed, so an album without
+    /// secondary types (a studio album) always passes that check. Names compare
+    /// case-insensitively.
+    pub fn allows_album(
+        &self,
+        primary_type: Option<&str>,
+        secondary_types: &[String],
+        status: Option<&str>,
+    ) -> bool {
+        let allowed = |list: &[String], value: &str| {
+            list.is_empty()
+                || list
+                    .iter()
+                    .any(|item| item.trim().eq_ignore_ascii_case(value.trim()))
+        };
+
+        let primary_ok = self.primary_album_types.is_empty()
+            || primary_type.is_some_and(|primary| allowed(&self.primary_album_types, primary));
+        let secondary_ok = secondary_types
+            .iter()
+            .all(|secondary| allowed(&self.secondary_album_types, secondary));
+        let status_ok = status.is_none_or(|status| allowed(&self.release_statuses, status));
+
+        primary_ok && secondary_ok && status_ok
+    }
 }
 
+/// Secondary type for profiles that only allow studio albums.
+///
+/// MusicBrainz never reports it, so a profile listing only this type rejects
+/// every album that has a secondary type while studio albums still pass.
+pub const STUDIO_SECONDARY_TYPE: &str = "Studio";
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IndexerDefinition {
     pub id: IndexerDefinitionId,
@@ -743,6 +816,13 @@ pub struct IndexerDefinition {
     pub protocol: String,
     pub api_key: Option<String>,
     pub enabled: bool,
+    /// Overrides for the indexer's category IDs, keyed by category name
+    /// (`music`, `audio/flac`, ...), e.g. `{"audio/flac": "3040"}`.
+    #[serde(default)]
+    pub category_map: BTreeMap<String, String>,
+    /// Maximum number of requests per minute sent to this indexer. `None` disables limiting.
+    #[serde(default)]
+    pub requests_per_minute: Option<u32>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -761,6 +841,8 @@ impl IndexerDefinition {
             protocol: protocol.into(),
             api_key: None,
             enabled: true,
+            category_map: BTreeMap::new(),
+            requests_per_minute: None,
             created_at: now,
             updated_at: now,
         }
@@ -1185,6 +1267,109 @@ impl Validate for MetadataProfile {
     }
 }
 
+// ============================================================================
+// Track Positions
+// ============================================================================
+
+/// A 1-based track number within one disc.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct TrackNumber(u32);
+
+impl TrackNumber {
+    /// `None` for 0, which tags use for an unknown track number.
+    pub fn new(number: u32) -> Option<Self> {
+        (number > 0).then_some(Self(number))
+    }
+
+    pub fn get(self) -> u32 {
+        self.0
+    }
+}
+
+impl std::fmt::Display for TrackNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A 1-based disc number within a release.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct DiscNumber(u32);
+
+impl DiscNumber {
+    pub const FIRST: Self = Self(1);
+
+    /// `None` for 0, which tags use for an unknown disc number.
+    pub fn new(number: u32) -> Option<Self> {
+        (number > 0).then_some(Self(number))
+    }
+
+    pub fn get(self) -> u32 {
+        self.0
+    }
+}
+
+impl Default for DiscNumber {
+    fn default() -> Self {
+        Self::FIRST
+    }
+}
+
+impl std::fmt::Display for DiscNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Where a track sits on a release. Orders by disc, then by track.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct TrackPosition {
+    pub disc: DiscNumber,
+    pub track: TrackNumber,
+}
+
+impl TrackPosition {
+    pub fn new(disc: DiscNumber, track: TrackNumber) -> Self {
+        Self { disc, track }
+    }
+
+    /// This position counted from the start of the release; see
+    /// [`absolute_track_number`].
+    pub fn absolute(&self, tracks_per_disc: &[u32]) -> u32 {
+        absolute_track_number(self.disc.get(), self.track.get(), tracks_per_disc)
+    }
+}
+
+/// Track counts per disc, indexed from disc 1, taking each disc's highest
+/// track number as its length. Discs with no tracks among `positions` count 0.
+pub fn tracks_per_disc(positions: impl IntoIterator<Item = TrackPosition>) -> Vec<u32> {
+    let mut counts = Vec::new();
+    for position in positions {
+        let index = position.disc.get() as usize - 1;
+        if counts.len() <= index {
+            counts.resize(index + 1, 0);
+        }
+        counts[index] = counts[index].max(position.track.get());
+    }
+    counts
+}
+
+/// 1-based position of `track` on `disc` counted across the whole release.
+///
+/// `tracks_per_disc[i]` is the number of tracks on disc `i + 1`, and the counts
+/// of every disc before `disc` are added to `track`. Discs missing from the
+/// slice count as empty and disc 0 is read as disc 1, so single-disc releases
+/// get `track` back unchanged.
+pub fn absolute_track_number(disc: u32, track: u32, tracks_per_disc: &[u32]) -> u32 {
+    let preceding_discs = disc.saturating_sub(1) as usize;
+    tracks_per_disc
+        .iter()
+        .take(preceding_discs)
+        .fold(track, |absolute, &count| absolute.saturating_add(count))
+}
+
 // ============================================================================
 // File Path Generation Utilities
 // ============================================================================
@@ -1192,12 +1377,18 @@ impl Validate for MetadataProfile {
 fn sanitize_component(input: &str) -> String {
     // Remove characters invalid on Windows and common problematic ones
     let banned = ['<', '>', ':', '"', '/', '\\', '|', '?', '*'];
-    input
+    let component = input
         .chars()
         .map(|c| if banned.contains(&c) { ' ' } else { c })
         .collect::<String>()
         .trim()
-        .to_string()
+        .to_string();
+    // "." and ".." would make the joined path leave its parent.
+    if component == "." || component == ".." {
+        "_".to_string()
+    } else {
+        component
+    }
 }
 
 pub fn generate_track_path(
@@ -1225,13 +1416,133 @@ pub fn generate_track_path(
         .join(file_name)
 }
 
+// ============================================================================
+// Library Path Safety
+// ============================================================================
+
+/// Why a path was refused by [`ensure_within_root`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathSafetyError {
+    /// The root itself could not be resolved, e.g. it does not exist.
+    InvalidRoot { root: PathBuf, message: String },
+    /// The candidate's existing part could not be resolved.
+    Unresolvable { path: PathBuf, message: String },
+    /// The candidate resolves to somewhere outside the root.
+    OutsideRoot { path: PathBuf, root: PathBuf },
+}
+
+impl std::fmt::Display for PathSafetyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidRoot { root, message } => {
+                write!(
+                    f,
+                    "library root {} is not usable: {message}",
+                    root.display()
+                )
+            }
+            Self::Unresolvable { path, message } => {
+                write!(f, "cannot resolve {}: {message}", path.display())
+            }
+            Self::OutsideRoot { path, root } => write!(
+                f,
+                "{} is outside the library root {}",
+                path.display(),
+                root.display()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PathSafetyError {}
+
+/// Resolve `candidate` and check it stays inside `root`, returning the resolved path.
+///
+/// Relative candidates are taken relative to `root`. The longest existing prefix
+/// is canonicalized, so `..` and symlinks pointing out of the root are caught;
+/// the part that does not exist yet is appended as-is, which lets this check
+/// files about to be created. A `..` within that missing part is rejected.
+pub fn ensure_within_root(root: &Path, candidate: &Path) -> Result<PathBuf, PathSafetyError> {
+    let root = root
+        .canonicalize()
+        .map_err(|error| PathSafetyError::InvalidRoot {
+            root: root.to_path_buf(),
+            message: error.to_string(),
+        })?;
+    let joined = root.join(candidate);
+
+    let mut existing = joined.as_path();
+    let mut pending = Vec::new();
+    while !existing.exists() {
+        match (existing.parent(), existing.file_name()) {
+            (Some(parent), Some(name)) => {
+                pending.push(name.to_os_string());
+                existing = parent;
+            }
+            // `..` or a root: stop and let canonicalize report it.
+            _ => break,
+        }
+    }
+    let mut resolved = existing
+        .canonicalize()
+        .map_err(|error| PathSafetyError::Unresolvable {
+            path: joined.clone(),
+            message: error.to_string(),
+        })?;
+    // `file_name` never yields `..`, so these are plain names.
+    for name in pending.iter().rev() {
+        resolved.push(name);
+    }
+
+    if resolved.starts_with(&root) {
+        Ok(resolved)
+    } else {
+        Err(PathSafetyError::OutsideRoot { path: joined, root })
+    }
+}
+
+/// [`ensure_within_root`] against several roots, accepting the first that contains `candidate`.
+///
+/// An empty `roots` list means no library root is configured and fails with
+/// [`PathSafetyError::InvalidRoot`]; callers decide whether that is allowed.
+pub fn ensure_within_any_root(
+    roots: &[PathBuf],
+    candidate: &Path,
+) -> Result<PathBuf, PathSafetyError> {
+    let mut last_error = PathSafetyError::InvalidRoot {
+        root: PathBuf::new(),
+        message: "no library root configured".to_string(),
+    };
+    for root in roots {
+        match ensure_within_root(root, candidate) {
+            Ok(resolved) => return Ok(resolved),
+            Err(error) => last_error = error,
+        }
+    }
+    Err(last_error)
+}
+
 // ============================================================================
 // Domain Events (lightweight scaffolding)
 // ============================================================================
 
+/// Schema version stamped on newly created events.
+pub const DOMAIN_EVENT_SCHEMA_VERSION: u16 = 1;
+
+fn default_schema_version() -> u16 {
+    DOMAIN_EVENT_SCHEMA_VERSION
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DomainEvent<TPayload> {
+    /// Unique per event so consumers can deduplicate redeliveries.
+    pub id: Uuid,
     pub name: &'static str,
+    #[serde(default = "default_schema_version")]
+    pub schema_version: u16,
+    /// Id of the request or operation that caused this event, if known.
+    #[serde(default)]
+    pub correlation_id: Option<Uuid>,
     pub occurred_at: DateTime<Utc>,
     pub payload: TPayload,
 }
@@ -1239,11 +1550,19 @@ pub struct DomainEvent<TPayload> {
 impl<TPayload> DomainEvent<TPayload> {
     pub fn new(name: &'static str, payload: TPayload) -> Self {
         Self {
+            id: Uuid::new_v4(),
             name,
+            schema_version: DOMAIN_EVENT_SCHEMA_VERSION,
+            correlation_id: None,
             occurred_at: Utc::now(),
             payload,
         }
     }
+
+    pub fn with_correlation(mut self, correlation_id: Uuid) -> Self {
+        self.correlation_id = Some(correlation_id);
+        self
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1255,6 +1574,18 @@ pub struct TrackFileImportedPayload {
 
 pub type TrackFileImported = DomainEvent<TrackFileImportedPayload>;
 
+/// A track file was re-matched and now belongs to `track_id`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrackFileRematchedPayload {
+    pub track_id: TrackId,
+    pub previous_track_id: TrackId,
+    pub track_file_id: TrackFileId,
+    pub path: String,
+    pub confidence: f32,
+}
+
+pub type TrackFileRematched = DomainEvent<TrackFileRematchedPayload>;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ArtistCreatedPayload {
     pub artist_id: ArtistId,
@@ -1273,6 +1604,14 @@ pub struct ArtistUpdatedPayload {
 
 pub type ArtistUpdated = DomainEvent<ArtistUpdatedPayload>;
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArtistDeletedPayload {
+    pub artist_id: ArtistId,
+    pub name: String,
+}
+
+pub type ArtistDeleted = DomainEvent<ArtistDeletedPayload>;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AlbumCreatedPayload {
     pub album_id: AlbumId,
@@ -1512,6 +1851,72 @@ mod tests {
         assert!(path.ends_with(expected_end));
     }
 
+    #[test]
+    fn generate_track_path_neutralizes_dot_dot_names() {
+        let base = PathBuf::from("/music");
+        let path = generate_track_path(&base, "..", "..", None, "Song", "flac");
+        assert_eq!(path, PathBuf::from("/music/_/_/Song.flac"));
+    }
+
+    #[test]
+    fn ensure_within_root_accepts_paths_inside_the_root() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::create_dir(root.path().join("Artist")).unwrap();
+
+        let existing = ensure_within_root(root.path(), &root.path().join("Artist")).unwrap();
+        assert_eq!(existing, root.path().canonicalize().unwrap().join("Artist"));
+
+        let to_create =
+            ensure_within_root(root.path(), Path::new("Artist/Album/01 - Song.flac")).unwrap();
+        assert_eq!(
+            to_create,
+            root.path()
+                .canonicalize()
+                .unwrap()
+                .join("Artist/Album/01 - Song.flac")
+        );
+    }
+
+    #[test]
+    fn ensure_within_root_rejects_parent_dir_traversal() {
+        let parent = tempfile::tempdir().unwrap();
+        let root = parent.path().join("library");
+        std::fs::create_dir(&root).unwrap();
+
+        let result = ensure_within_root(&root, Path::new("../escaped.flac"));
+        assert!(
+            matches!(result, Err(PathSafetyError::OutsideRoot { .. })),
+            "{result:?}"
+        );
+
+        let result = ensure_within_root(&root, Path::new("missing/../../escaped.flac"));
+        assert!(result.is_err(), "{result:?}");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn ensure_within_root_rejects_symlink_escape() {
+        let parent = tempfile::tempdir().unwrap();
+        let root = parent.path().join("library");
+        let outside = parent.path().join("outside");
+        std::fs::create_dir(&root).unwrap();
+        std::fs::create_dir(&outside).unwrap();
+        std::os::unix::fs::symlink(&outside, root.join("link")).unwrap();
+
+        let result = ensure_within_root(&root, Path::new("link/new.flac"));
+        assert!(
+            matches!(result, Err(PathSafetyError::OutsideRoot { .. })),
+            "{result:?}"
+        );
+    }
+
+    #[test]
+    fn ensure_within_any_root_requires_a_configured_root() {
+        let root = tempfile::tempdir().unwrap();
+        assert!(ensure_within_any_root(&[], root.path()).is_err());
+        assert!(ensure_within_any_root(&[root.path().to_path_buf()], Path::new("a.flac")).is_ok());
+    }
+
     #[test]
     fn trackfile_constructor_defaults() {
         let tf = TrackFile::new(TrackId::new(), "C:/media/file.flac", 1234);
@@ -1532,6 +1937,45 @@ mod tests {
         assert_eq!(event.payload.name, "Test Artist");
     }
 
+    #[test]
+    fn domain_events_get_unique_ids_and_current_schema_version() {
+        let first = DomainEvent::new("artist.created", ());
+        let second = DomainEvent::new("artist.created", ());
+        assert_ne!(first.id, second.id);
+        assert_eq!(first.schema_version, DOMAIN_EVENT_SCHEMA_VERSION);
+        assert!(first.correlation_id.is_none());
+    }
+
+    #[test]
+    fn domain_event_correlation_round_trips_through_serde() {
+        let correlation_id = Uuid::new_v4();
+        let event = DomainEvent::new("artist.updated", 7_u32).with_correlation(correlation_id);
+
+        // `name` borrows `'static` data, so the JSON must outlive the decoded event.
+        let json: &'static str = Box::leak(
+            serde_json::to_string(&event)
+                .expect("serialize")
+                .into_boxed_str(),
+        );
+        let decoded: DomainEvent<u32> = serde_json::from_str(json).expect("deserialize");
+
+        assert_eq!(decoded.id, event.id);
+        assert_eq!(decoded.name, "artist.updated");
+        assert_eq!(decoded.correlation_id, Some(correlation_id));
+        assert_eq!(decoded.payload, 7);
+    }
+
+    #[test]
+    fn domain_event_schema_version_defaults_when_absent() {
+        let decoded: DomainEvent<u32> = serde_json::from_str(
+            r#"{"id":"6f1c2a52-1d5e-4c39-9b8e-0d6f1b0f5a10","name":"artist.deleted","occurred_at":"2024-01-01T00:00:00Z","payload":1}"#,
+        )
+        .expect("deserialize");
+
+        assert_eq!(decoded.schema_version, DOMAIN_EVENT_SCHEMA_VERSION);
+        assert!(decoded.correlation_id.is_none());
+    }
+
     #[test]
     fn album_created_event() {
         let artist_id = ArtistId::new();
@@ -1635,4 +2079,80 @@ mod tests {
         assert_eq!(event.name, "track.updated");
         assert_eq!(event.payload.track_id, track_id);
     }
+
+    #[test]
+    fn metadata_profile_allowing_only_studio_albums_rejects_live_and_eps() {
+        let mut profile = MetadataProfile::new("Albums");
+        profile.primary_album_types = vec!["Album".to_string()];
+        profile.secondary_album_types = vec![STUDIO_SECONDARY_TYPE.to_string()];
+
+        assert!(profile.allows_album(Some("Album"), &[], None));
+        assert!(profile.allows_album(Some("album"), &[], None));
+        assert!(!profile.allows_album(Some("Album"), &["Live".to_string()], None));
+        assert!(!profile.allows_album(Some("EP"), &[], None));
+        assert!(!profile.allows_album(None, &[], None));
+    }
+
+    #[test]
+    fn metadata_profile_requires_every_secondary_type_and_known_status_to_be_allowed() {
+        let mut profile = MetadataProfile::new("Compilations");
+        profile.secondary_album_types = vec!["Compilation".to_string(), "Live".to_string()];
+        profile.release_statuses = vec!["Official".to_string()];
+
+        let live_compilation = ["Compilation".to_string(), "Live".to_string()];
+        assert!(profile.allows_album(Some("Album"), &live_compilation, Some("Official")));
+        assert!(!profile.allows_album(
+            Some("Album"),
+            &["Compilation".to_string(), "Remix".to_string()],
+            None
+        ));
+        assert!(profile.allows_album(Some("Album"), &[], Some("Official")));
+        assert!(!profile.allows_album(Some("Album"), &[], Some("Bootleg")));
+        assert!(!profile.allows_album(Some("Album"), &live_compilation, Some("Bootleg")));
+        assert!(profile.allows_album(Some("Album"), &live_compilation, None));
+    }
+
+    #[test]
+    fn empty_metadata_profile_allows_every_album() {
+        let profile = MetadataProfile::new("Everything");
+
+        assert!(profile.allows_album(Some("Album"), &[], None));
+        assert!(profile.allows_album(Some("Album"), &["Live".to_string()], Some("Bootleg")));
+        assert!(profile.allows_album(None, &[], Some("Official")));
+    }
+
+    fn position(disc: u32, track: u32) -> TrackPosition {
+        TrackPosition::new(
+            DiscNumber::new(disc).unwrap(),
+            TrackNumber::new(track).unwrap(),
+        )
+    }
+
+    #[test]
+    fn two_disc_album_gets_increasing_absolute_track_numbers() {
+        let mut positions: Vec<_> = [(2, 2), (1, 3), (2, 1), (1, 1), (1, 2)]
+            .into_iter()
+            .map(|(disc, track)| position(disc, track))
+            .collect();
+        positions.sort();
+        let counts = tracks_per_disc(positions.iter().copied());
+        assert_eq!(counts, vec![3, 2]);
+
+        let absolute: Vec<_> = positions
+            .iter()
+            .map(|position| position.absolute(&counts))
+            .collect();
+        assert_eq!(absolute, vec![1, 2, 3, 4, 5]);
+        assert_eq!(absolute_track_number(2, 1, &counts), 4);
+    }
+
+    #[test]
+    fn single_disc_track_numbers_pass_through() {
+        assert_eq!(absolute_track_number(1, 7, &[12]), 7);
+        assert_eq!(absolute_track_number(0, 7, &[12]), 7);
+        assert_eq!(absolute_track_number(1, 7, &[]), 7);
+        assert_eq!(position(1, 7).absolute(&[12]), 7);
+        assert_eq!(TrackNumber::new(0), None);
+        assert_eq!(DiscNumber::new(0), None);
+    }
 }
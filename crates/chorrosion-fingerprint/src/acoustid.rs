@@ -2,14 +2,24 @@
 
 use crate::error::Result;
 use crate::fingerprint::Fingerprint;
+use chorrosion_domain::ReleaseDate;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
-use tracing::{debug, trace};
+use tokio::time::sleep;
+use tracing::{debug, trace, warn};
 use url::Url;
 use uuid::Uuid;
 
 const ACOUSTID_API_BASE: &str = "https://api.acoustid.org/v2";
+/// Default maximum number of fingerprints packed into one batch lookup request.
+const DEFAULT_BATCH_SIZE: usize = 20;
+/// Default number of retries after an HTTP 429 response.
+const DEFAULT_MAX_RETRIES: u32 = 3;
+/// Default delay before the first retry; doubled for each subsequent retry.
+const DEFAULT_BASE_DELAY: Duration = Duration::from_millis(500);
+/// Upper bound for a single backoff or `Retry-After` wait.
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(60);
 const USER_AGENT: &str = concat!(
     "Chorrosion/",
     env!("CARGO_PKG_VERSION"),
@@ -44,6 +54,7 @@ pub struct RecordingArtist {
 
 /// Release information for a recording.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(from = "ReleaseInfoPayload")]
 pub struct ReleaseInfo {
     /// MusicBrainz release ID.
     pub id: Uuid,
@@ -52,6 +63,160 @@ pub struct ReleaseInfo {
     /// Release date (YYYY, YYYY-MM, or YYYY-MM-DD).
     #[serde(default)]
     pub date: Option<String>,
+    /// `date` parsed at whatever precision the service reported it; `None` when
+    /// the date is missing or unparseable.
+    pub release_date: Option<ReleaseDate>,
+}
+
+impl ReleaseInfo {
+    /// Build release info, parsing `date` into [`Self::release_date`].
+    pub fn new(id: Uuid, title: impl Into<String>, date: Option<String>) -> Self {
+        let release_date = date.as_deref().and_then(ReleaseDate::parse_str);
+        Self {
+            id,
+            title: title.into(),
+            date,
+            release_date,
+        }
+    }
+}
+
+/// Wire shape of [`ReleaseInfo`]; `release_date` is always derived from `date`.
+#[derive(Deserialize)]
+struct ReleaseInfoPayload {
+    id: Uuid,
+    title: String,
+    #[serde(default)]
+    date: Option<String>,
+}
+
+impl From<ReleaseInfoPayload> for ReleaseInfo {
+    fn from(payload: ReleaseInfoPayload) -> Self {
+        Self::new(payload.id, payload.title, payload.date)
+    }
+}
+
+/// A fingerprint and its known metadata to contribute to AcoustID.
+///
+/// The `user_token` identifies the contributing AcoustID user and is redacted in `Debug`.
+#[derive(Clone, PartialEq)]
+pub struct AcoustidSubmission {
+    /// Per-user AcoustID API key of the submitting user.
+    pub user_token: String,
+    /// The Chromaprint fingerprint and the duration it covers.
+    pub fingerprint: Fingerprint,
+    /// Known MusicBrainz recording ID for this fingerprint.
+    pub mbid: Option<Uuid>,
+    /// Track title.
+    pub track: Option<String>,
+    /// Track artist name.
+    pub artist: Option<String>,
+    /// Album title.
+    pub album: Option<String>,
+    /// Album artist name.
+    pub album_artist: Option<String>,
+    /// Release year.
+    pub year: Option<u32>,
+    /// Track number on the disc.
+    pub track_number: Option<u32>,
+    /// Disc number.
+    pub disc_number: Option<u32>,
+    /// Container/codec name, e.g. `FLAC` or `MP3`.
+    pub file_format: Option<String>,
+    /// Bitrate in kbps.
+    pub bitrate: Option<u32>,
+}
+
+impl AcoustidSubmission {
+    /// Create a submission with no metadata beyond the fingerprint.
+    pub fn new(user_token: impl Into<String>, fingerprint: Fingerprint) -> Self {
+        Self {
+            user_token: user_token.into(),
+            fingerprint,
+            mbid: None,
+            track: None,
+            artist: None,
+            album: None,
+            album_artist: None,
+            year: None,
+            track_number: None,
+            disc_number: None,
+            file_format: None,
+            bitrate: None,
+        }
+    }
+
+    /// Form fields for a single-submission request (`.0` suffixed keys).
+    fn form_fields(&self) -> Vec<(&'static str, String)> {
+        let mut fields = vec![
+            ("user", self.user_token.clone()),
+            ("fingerprint.0", self.fingerprint.hash.clone()),
+            ("duration.0", self.fingerprint.duration.to_string()),
+        ];
+
+        let optional = [
+            ("mbid.0", self.mbid.map(|mbid| mbid.to_string())),
+            ("track.0", self.track.clone()),
+            ("artist.0", self.artist.clone()),
+            ("album.0", self.album.clone()),
+            ("albumartist.0", self.album_artist.clone()),
+            ("year.0", self.year.map(|year| year.to_string())),
+            ("trackno.0", self.track_number.map(|n| n.to_string())),
+            ("discno.0", self.disc_number.map(|n| n.to_string())),
+            ("fileformat.0", self.file_format.clone()),
+            ("bitrate.0", self.bitrate.map(|bitrate| bitrate.to_string())),
+        ];
+        fields.extend(
+            optional
+                .into_iter()
+                .filter_map(|(key, value)| value.map(|value| (key, value))),
+        );
+
+        fields
+    }
+}
+
+impl std::fmt::Debug for AcoustidSubmission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AcoustidSubmission")
+            .field("user_token", &"[REDACTED]")
+            .field("fingerprint", &self.fingerprint)
+            .field("mbid", &self.mbid)
+            .field("track", &self.track)
+            .field("artist", &self.artist)
+            .field("album", &self.album)
+            .field("album_artist", &self.album_artist)
+            .field("year", &self.year)
+            .field("track_number", &self.track_number)
+            .field("disc_number", &self.disc_number)
+            .field("file_format", &self.file_format)
+            .field("bitrate", &self.bitrate)
+            .finish()
+    }
+}
+
+/// Processing state of an AcoustID submission.
+///
+/// Submissions are imported asynchronously: `submit` usually reports `Pending`,
+/// and `submission_status` reports `Imported` once the fingerprint is stored.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SubmissionStatus {
+    Pending,
+    Imported,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Result of submitting a fingerprint or polling a submission.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubmitResult {
+    /// Submission ID used to poll `submission_status`.
+    pub submission_id: u64,
+    /// Current processing state.
+    pub status: SubmissionStatus,
+    /// AcoustID track ID assigned once the submission is imported.
+    pub acoustid: Option<Uuid>,
 }
 
 /// AcoustID API client for fingerprint lookup.
@@ -60,6 +225,9 @@ pub struct AcoustidClient {
     client: Client,
     base_url: String,
     api_key: String,
+    batch_size: usize,
+    max_retries: u32,
+    base_delay: Duration,
 }
 
 impl std::fmt::Debug for AcoustidClient {
@@ -68,6 +236,9 @@ impl std::fmt::Debug for AcoustidClient {
             .field("client", &self.client)
             .field("base_url", &self.base_url)
             .field("api_key", &"[REDACTED]")
+            .field("batch_size", &self.batch_size)
+            .field("max_retries", &self.max_retries)
+            .field("base_delay", &self.base_delay)
             .finish()
     }
 }
@@ -104,10 +275,7 @@ impl AcoustidClient {
         trace!(target: "fingerprint", "AcoustID lookup: {}", redacted_url);
 
         let response = self
-            .client
-            .get(url.as_str())
-            .header("User-Agent", USER_AGENT)
-            .send()
+            .send_with_rate_limit_retry(|| self.client.get(url.as_str()))
             .await?;
 
         let status = response.status();
@@ -193,6 +361,189 @@ impl AcoustidClient {
         Ok(matches)
     }
 
+    /// Send the request built by `request`, retrying with exponential backoff on HTTP 429.
+    ///
+    /// `request` is called again for every attempt. A `Retry-After` header (in seconds)
+    /// takes precedence over the computed backoff. Other statuses are returned
+    /// immediately; once retries are exhausted the final 429 response is returned to
+    /// the caller.
+    async fn send_with_rate_limit_retry(
+        &self,
+        request: impl Fn() -> reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response> {
+        let mut retries = 0u32;
+
+        loop {
+            let response = request().header("User-Agent", USER_AGENT).send().await?;
+
+            if response.status() != reqwest::StatusCode::TOO_MANY_REQUESTS
+                || retries >= self.max_retries
+            {
+                return Ok(response);
+            }
+
+            let wait = retry_after_delay(&response).unwrap_or_else(|| self.backoff_for(retries));
+            retries += 1;
+            warn!(
+                target: "fingerprint",
+                retry = retries,
+                max_retries = self.max_retries,
+                wait_ms = wait.as_millis(),
+                "AcoustID rate limit hit, retrying lookup"
+            );
+            sleep(wait).await;
+        }
+    }
+
+    fn backoff_for(&self, retry: u32) -> Duration {
+        self.base_delay
+            .saturating_mul(1u32 << retry.min(16))
+            .min(MAX_RETRY_DELAY)
+    }
+
+    /// Lookup several fingerprints, packing up to `batch_size` of them into each request.
+    ///
+    /// Returns one match list per input fingerprint, in input order, each filtered
+    /// by `min_score`. Batches larger than the configured size are split into
+    /// multiple requests automatically.
+    pub async fn lookup_batch(
+        &self,
+        fingerprints: &[Fingerprint],
+        min_score: f32,
+    ) -> Result<Vec<Vec<RecordingMatch>>> {
+        if !(0.0..=1.0).contains(&min_score) {
+            return Err(crate::FingerprintError::AcoustidError(
+                "Invalid parameter: min_score must be between 0.0 and 1.0".to_string(),
+            ));
+        }
+
+        for fingerprint in fingerprints {
+            fingerprint.validate()?;
+        }
+
+        let mut all_results = Vec::with_capacity(fingerprints.len());
+        for chunk in fingerprints.chunks(self.batch_size) {
+            let chunk_results = self.lookup_chunk(chunk).await?;
+            all_results.extend(chunk_results.into_iter().map(|matches| {
+                matches
+                    .into_iter()
+                    .filter(|m| m.score >= min_score)
+                    .collect::<Vec<_>>()
+            }));
+        }
+
+        Ok(all_results)
+    }
+
+    /// Perform a single batch lookup request for `chunk` (unfiltered, in input order).
+    async fn lookup_chunk(&self, chunk: &[Fingerprint]) -> Result<Vec<Vec<RecordingMatch>>> {
+        let url = format!("{}/lookup", self.base_url);
+        let mut form = vec![
+            ("client".to_string(), self.api_key.clone()),
+            ("format".to_string(), "json".to_string()),
+            ("batch".to_string(), "1".to_string()),
+            (
+                "meta".to_string(),
+                "recordings releases artistids".to_string(),
+            ),
+        ];
+        for (index, fingerprint) in chunk.iter().enumerate() {
+            form.push((format!("fingerprint.{index}"), fingerprint.hash.clone()));
+            form.push((
+                format!("duration.{index}"),
+                fingerprint.duration.to_string(),
+            ));
+        }
+
+        debug!(
+            target: "fingerprint",
+            batch_size = chunk.len(),
+            "AcoustID batch lookup"
+        );
+
+        let response = self
+            .send_with_rate_limit_retry(|| self.client.post(&url).form(&form))
+            .await?;
+
+        let body = read_success_body(response).await?;
+        let api_response: BatchLookupResponse = serde_json::from_str(&body)?;
+
+        if !api_response.status.eq_ignore_ascii_case("ok") {
+            return Err(crate::FingerprintError::AcoustidError(
+                api_response
+                    .error
+                    .unwrap_or_else(|| "Unknown error".to_string()),
+            ));
+        }
+
+        let mut results = vec![Vec::new(); chunk.len()];
+        for entry in api_response.fingerprints {
+            let index = entry.index()?;
+            let slot = results.get_mut(index).ok_or_else(|| {
+                crate::FingerprintError::InvalidResponse(format!(
+                    "batch result index {} out of range for {} fingerprints",
+                    index,
+                    chunk.len()
+                ))
+            })?;
+            *slot = entry.results;
+        }
+
+        Ok(results)
+    }
+
+    /// Submit a fingerprint with its known metadata to AcoustID.
+    ///
+    /// AcoustID imports submissions asynchronously; poll the returned
+    /// `submission_id` with [`AcoustidClient::submission_status`].
+    ///
+    /// # Errors
+    /// Returns `AcoustidError` on HTTP failures (including invalid API key or user token)
+    /// or when AcoustID rejects the submission.
+    pub async fn submit(&self, submission: &AcoustidSubmission) -> Result<SubmitResult> {
+        submission.fingerprint.validate()?;
+
+        let url = format!("{}/submit", self.base_url);
+        let mut form = vec![
+            ("client", self.api_key.clone()),
+            ("format", "json".to_string()),
+        ];
+        form.extend(submission.form_fields());
+
+        trace!(target: "fingerprint", "AcoustID submit: {}", url);
+
+        let response = self
+            .client
+            .post(&url)
+            .header("User-Agent", USER_AGENT)
+            .form(&form)
+            .send()
+            .await?;
+
+        let body = read_success_body(response).await?;
+        first_submission(&body)
+    }
+
+    /// Fetch the current status of a previous submission.
+    pub async fn submission_status(&self, submission_id: u64) -> Result<SubmitResult> {
+        let mut url = Url::parse(&format!("{}/submission_status", self.base_url))
+            .map_err(|e| crate::FingerprintError::InvalidResponse(e.to_string()))?;
+
+        url.query_pairs_mut()
+            .append_pair("client", &self.api_key)
+            .append_pair("id", &submission_id.to_string());
+
+        let response = self
+            .client
+            .get(url.as_str())
+            .header("User-Agent", USER_AGENT)
+            .send()
+            .await?;
+
+        let body = read_success_body(response).await?;
+        first_submission(&body)
+    }
+
     /// Lookup and return the best match (highest score).
     ///
     /// # Arguments
@@ -253,11 +604,120 @@ struct AcoustidResponse {
     error: Option<String>,
 }
 
+/// AcoustID batch lookup response structure.
+#[derive(Debug, Deserialize)]
+struct BatchLookupResponse {
+    status: String,
+    #[serde(default)]
+    fingerprints: Vec<BatchLookupEntry>,
+    error: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BatchLookupEntry {
+    /// Position of the fingerprint in the request; AcoustID may encode it as a string.
+    index: serde_json::Value,
+    #[serde(default)]
+    results: Vec<RecordingMatch>,
+}
+
+impl BatchLookupEntry {
+    fn index(&self) -> Result<usize> {
+        let index = match &self.index {
+            serde_json::Value::Number(number) => number.as_u64(),
+            serde_json::Value::String(text) => text.parse::<u64>().ok(),
+            _ => None,
+        };
+
+        index.map(|index| index as usize).ok_or_else(|| {
+            crate::FingerprintError::InvalidResponse(format!(
+                "invalid batch result index: {}",
+                self.index
+            ))
+        })
+    }
+}
+
+/// AcoustID submit/submission_status response structure.
+#[derive(Debug, Deserialize)]
+struct SubmissionResponse {
+    status: String,
+    #[serde(default)]
+    submissions: Vec<RawSubmission>,
+    error: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawSubmission {
+    id: u64,
+    status: SubmissionStatus,
+    #[serde(default)]
+    result: Option<RawSubmissionResult>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawSubmissionResult {
+    id: Uuid,
+}
+
+/// Parse a `Retry-After` header given as an integer number of seconds, capped at
+/// [`MAX_RETRY_DELAY`].
+fn retry_after_delay(response: &reqwest::Response) -> Option<Duration> {
+    let value = response.headers().get(reqwest::header::RETRY_AFTER)?;
+    let secs: u64 = value.to_str().ok()?.trim().parse().ok()?;
+    Some(Duration::from_secs(secs).min(MAX_RETRY_DELAY))
+}
+
+/// Return the response body, or an `AcoustidError` carrying status and body on HTTP failure.
+async fn read_success_body(response: reqwest::Response) -> Result<String> {
+    let status = response.status();
+    debug!(target: "fingerprint", "AcoustID response status: {}", status);
+
+    if !status.is_success() {
+        let message = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        return Err(crate::FingerprintError::AcoustidError(format!(
+            "HTTP {}: {}",
+            status, message
+        )));
+    }
+
+    Ok(response.text().await?)
+}
+
+fn first_submission(body: &str) -> Result<SubmitResult> {
+    let api_response: SubmissionResponse = serde_json::from_str(body)?;
+
+    if !api_response.status.eq_ignore_ascii_case("ok") {
+        return Err(crate::FingerprintError::AcoustidError(
+            api_response
+                .error
+                .unwrap_or_else(|| "Unknown error".to_string()),
+        ));
+    }
+
+    let submission = api_response.submissions.into_iter().next().ok_or_else(|| {
+        crate::FingerprintError::InvalidResponse("response contained no submissions".to_string())
+    })?;
+
+    Ok(SubmitResult {
+        submission_id: submission.id,
+        status: submission.status,
+        acoustid: submission.result.map(|result| result.id),
+    })
+}
+
 /// Builder for AcoustID client.
 pub struct AcoustidClientBuilder {
     api_key: String,
     base_url: String,
     timeout: Duration,
+    batch_size: usize,
+    max_retries: u32,
+    base_delay: Duration,
+    proxy_url: Option<String>,
 }
 
 impl std::fmt::Debug for AcoustidClientBuilder {
@@ -266,6 +726,10 @@ impl std::fmt::Debug for AcoustidClientBuilder {
             .field("api_key", &"[REDACTED]")
             .field("base_url", &self.base_url)
             .field("timeout", &self.timeout)
+            .field("batch_size", &self.batch_size)
+            .field("max_retries", &self.max_retries)
+            .field("base_delay", &self.base_delay)
+            .field("proxy_url", &self.proxy_url)
             .finish()
     }
 }
@@ -277,6 +741,10 @@ impl AcoustidClientBuilder {
             api_key: api_key.into(),
             base_url: ACOUSTID_API_BASE.to_string(),
             timeout: Duration::from_secs(30),
+            batch_size: DEFAULT_BATCH_SIZE,
+            max_retries: DEFAULT_MAX_RETRIES,
+            base_delay: DEFAULT_BASE_DELAY,
+            proxy_url: None,
         }
     }
 
@@ -292,11 +760,36 @@ impl AcoustidClientBuilder {
         self
     }
 
+    /// Set the maximum number of fingerprints per batch lookup request (minimum 1).
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Set how many times a lookup is retried after HTTP 429 (0 disables retries).
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Set the initial backoff delay used when no `Retry-After` header is present.
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Send every request through the HTTP proxy at `url`.
+    pub fn proxy_url(mut self, url: impl Into<String>) -> Self {
+        self.proxy_url = Some(url.into());
+        self
+    }
+
     /// Build the AcoustID client.
     ///
     /// # Errors
     /// Returns an error if:
     /// - The base URL is not a valid URL format
+    /// - The proxy URL is not valid
     /// - The HTTP client cannot be created
     pub fn build(self) -> Result<AcoustidClient> {
         // Validate base URL format early
@@ -304,15 +797,21 @@ impl AcoustidClientBuilder {
             crate::FingerprintError::AcoustidError(format!("Invalid base URL: {}", e))
         })?;
 
-        let client = Client::builder()
+        let mut client = Client::builder()
             .timeout(self.timeout)
-            .user_agent(USER_AGENT)
-            .build()?;
+            .user_agent(USER_AGENT);
+        if let Some(proxy_url) = &self.proxy_url {
+            client = client.proxy(reqwest::Proxy::all(proxy_url)?);
+        }
+        let client = client.build()?;
 
         Ok(AcoustidClient {
             client,
             base_url: self.base_url,
             api_key: self.api_key,
+            batch_size: self.batch_size,
+            max_retries: self.max_retries,
+            base_delay: self.base_delay,
         })
     }
 }
@@ -320,7 +819,8 @@ impl AcoustidClientBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use wiremock::matchers::{method, path, query_param};
+    use chorrosion_domain::ReleaseDatePrecision;
+    use wiremock::matchers::{body_string_contains, method, path, query_param};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     fn sample_response() -> serde_json::Value {
@@ -368,6 +868,65 @@ mod tests {
         assert!(matches[0].score > 0.9);
     }
 
+    #[test]
+    fn release_dates_are_parsed_with_reported_precision() {
+        let recording: RecordingMatch = serde_json::from_value(serde_json::json!({
+            "id": "0dd2d1a0-88f2-41a4-b6da-0f3ba8caf50a",
+            "score": 0.9,
+            "releases": [
+                {"id": "9c40fc9f-3e6f-4e81-b5e5-76d05ce7b5f0", "title": "Year", "date": "1995"},
+                {"id": "2a1e0bd4-2c4a-4c26-9a8a-4f3cfbd0cd3a", "title": "Month", "date": "1995-03"},
+                {"id": "5f6d2a7e-9d3c-4c43-8a49-0e0c9c0a6f1b", "title": "Day", "date": "1995-03-16"},
+                {"id": "d8b8f0c2-4b0e-4b7e-8e7a-3b0f9d1e2c4a", "title": "Bad", "date": "soon"},
+                {"id": "7c3e5a1d-6f2b-4d8e-9a0c-1b2d3e4f5a6b", "title": "Missing"}
+            ]
+        }))
+        .unwrap();
+
+        let parsed: Vec<_> = recording
+            .releases
+            .iter()
+            .map(|release| release.release_date)
+            .collect();
+        assert_eq!(
+            parsed,
+            vec![
+                Some(ReleaseDate::new(1995, None, None)),
+                Some(ReleaseDate::new(1995, Some(3), None)),
+                Some(ReleaseDate::new(1995, Some(3), Some(16))),
+                None,
+                None,
+            ]
+        );
+        assert_eq!(
+            parsed[..3]
+                .iter()
+                .map(|date| date.unwrap().precision())
+                .collect::<Vec<_>>(),
+            vec![
+                ReleaseDatePrecision::Year,
+                ReleaseDatePrecision::Month,
+                ReleaseDatePrecision::Day,
+            ]
+        );
+        assert_eq!(recording.releases[0].date.as_deref(), Some("1995"));
+        assert_eq!(recording.releases[3].date.as_deref(), Some("soon"));
+    }
+
+    #[test]
+    fn release_info_round_trips_through_json() {
+        let release = ReleaseInfo::new(
+            Uuid::parse_str("9c40fc9f-3e6f-4e81-b5e5-76d05ce7b5f0").unwrap(),
+            "The Bends",
+            Some("1995-03".to_string()),
+        );
+
+        let json = serde_json::to_value(&release).unwrap();
+        assert_eq!(json["release_date"]["month"], 3);
+        let decoded: ReleaseInfo = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded, release);
+    }
+
     #[tokio::test]
     async fn test_acoustid_lookup_best() {
         let mock_server = MockServer::start().await;
@@ -930,4 +1489,381 @@ mod tests {
         assert_eq!(client.base_url, ACOUSTID_API_BASE);
         // Default timeout is set but we can't easily inspect it in the client
     }
+
+    fn sample_submission() -> AcoustidSubmission {
+        let mut submission = AcoustidSubmission::new(
+            "secret-user-token",
+            Fingerprint::new_unchecked("AQADvEWZ==", 120),
+        );
+        submission.mbid = Some(Uuid::parse_str("0dd2d1a0-88f2-41a4-b6da-0f3ba8caf50a").unwrap());
+        submission.track = Some("Fake Plastic Trees".to_string());
+        submission.artist = Some("Radiohead".to_string());
+        submission
+    }
+
+    #[tokio::test]
+    async fn test_acoustid_submit_success() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/submit"))
+            .and(body_string_contains("client=test-key"))
+            .and(body_string_contains("user=secret-user-token"))
+            .and(body_string_contains("duration.0=120"))
+            .and(body_string_contains(
+                "mbid.0=0dd2d1a0-88f2-41a4-b6da-0f3ba8caf50a",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "status": "ok",
+                "submissions": [{"id": 42, "status": "pending"}]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/submission_status"))
+            .and(query_param("id", "42"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "status": "ok",
+                "submissions": [{
+                    "id": 42,
+                    "status": "imported",
+                    "result": {"id": "9ff43b6a-4f16-427c-93c2-92307ca505e0"}
+                }]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = AcoustidClient::builder("test-key")
+            .base_url(mock_server.uri())
+            .build()
+            .unwrap();
+
+        let submitted = client.submit(&sample_submission()).await.unwrap();
+        assert_eq!(submitted.submission_id, 42);
+        assert_eq!(submitted.status, SubmissionStatus::Pending);
+        assert_eq!(submitted.acoustid, None);
+
+        let status = client.submission_status(42).await.unwrap();
+        assert_eq!(status.status, SubmissionStatus::Imported);
+        assert_eq!(
+            status.acoustid,
+            Some(Uuid::parse_str("9ff43b6a-4f16-427c-93c2-92307ca505e0").unwrap())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_acoustid_submit_auth_error() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/submit"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "status": "error",
+                "error": "invalid user API key"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = AcoustidClient::builder("test-key")
+            .base_url(mock_server.uri())
+            .build()
+            .unwrap();
+
+        let err = client
+            .submit(&sample_submission())
+            .await
+            .expect_err("submit should fail on HTTP 400");
+        match err {
+            crate::FingerprintError::AcoustidError(msg) => {
+                assert!(msg.contains("400"), "unexpected message: {}", msg);
+                assert!(
+                    msg.contains("invalid user API key"),
+                    "unexpected message: {}",
+                    msg
+                );
+            }
+            other => panic!("Expected AcoustidError, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_acoustid_submit_rejects_invalid_fingerprint() {
+        let client = AcoustidClient::new("test-key").unwrap();
+        let submission = AcoustidSubmission::new("token", Fingerprint::new_unchecked("", 120));
+
+        let result = client.submit(&submission).await;
+        assert!(matches!(
+            result,
+            Err(crate::FingerprintError::InvalidFingerprint(_))
+        ));
+    }
+
+    #[test]
+    fn test_acoustid_submission_debug_redacts_user_token() {
+        let debug_output = format!("{:?}", sample_submission());
+
+        assert!(!debug_output.contains("secret-user-token"));
+        assert!(debug_output.contains("[REDACTED]"));
+        assert!(debug_output.contains("Fake Plastic Trees"));
+    }
+
+    fn batch_match(id: &str, title: &str, score: f32) -> serde_json::Value {
+        serde_json::json!({"id": id, "title": title, "score": score})
+    }
+
+    #[tokio::test]
+    async fn test_acoustid_lookup_batch_preserves_input_order() {
+        let mock_server = MockServer::start().await;
+
+        // Results are returned out of order and with string indexes.
+        Mock::given(method("POST"))
+            .and(path("/lookup"))
+            .and(body_string_contains("fingerprint.1=BBBB"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "status": "ok",
+                "fingerprints": [
+                    {"index": "1", "results": [
+                        batch_match("22222222-2222-2222-2222-222222222222", "Second", 0.9)
+                    ]},
+                    {"index": "0", "results": [
+                        batch_match("11111111-1111-1111-1111-111111111111", "First", 0.95),
+                        batch_match("33333333-3333-3333-3333-333333333333", "Weak", 0.2)
+                    ]},
+                    {"index": 2, "results": []}
+                ]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = AcoustidClient::builder("test-key")
+            .base_url(mock_server.uri())
+            .build()
+            .unwrap();
+
+        let fingerprints = [
+            Fingerprint::new_unchecked("AAAA", 100),
+            Fingerprint::new_unchecked("BBBB", 200),
+            Fingerprint::new_unchecked("CCCC", 300),
+        ];
+        let results = client.lookup_batch(&fingerprints, 0.5).await.unwrap();
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].len(), 1);
+        assert_eq!(results[0][0].title.as_deref(), Some("First"));
+        assert_eq!(results[1][0].title.as_deref(), Some("Second"));
+        assert!(results[2].is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_acoustid_lookup_batch_chunks_oversized_batches() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/lookup"))
+            .and(body_string_contains("fingerprint.0=AAAA"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "status": "ok",
+                "fingerprints": [
+                    {"index": 0, "results": [
+                        batch_match("11111111-1111-1111-1111-111111111111", "A", 0.9)
+                    ]},
+                    {"index": 1, "results": [
+                        batch_match("22222222-2222-2222-2222-222222222222", "B", 0.9)
+                    ]}
+                ]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/lookup"))
+            .and(body_string_contains("fingerprint.0=CCCC"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "status": "ok",
+                "fingerprints": [
+                    {"index": 0, "results": [
+                        batch_match("33333333-3333-3333-3333-333333333333", "C", 0.9)
+                    ]}
+                ]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = AcoustidClient::builder("test-key")
+            .base_url(mock_server.uri())
+            .batch_size(2)
+            .build()
+            .unwrap();
+
+        let fingerprints = [
+            Fingerprint::new_unchecked("AAAA", 100),
+            Fingerprint::new_unchecked("BBBB", 200),
+            Fingerprint::new_unchecked("CCCC", 300),
+        ];
+        let results = client.lookup_batch(&fingerprints, 0.5).await.unwrap();
+
+        let titles: Vec<Option<&str>> = results
+            .iter()
+            .map(|matches| matches[0].title.as_deref())
+            .collect();
+        assert_eq!(titles, vec![Some("A"), Some("B"), Some("C")]);
+    }
+
+    #[tokio::test]
+    async fn test_acoustid_lookup_batch_rejects_out_of_range_index() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/lookup"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "status": "ok",
+                "fingerprints": [{"index": 5, "results": []}]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = AcoustidClient::builder("test-key")
+            .base_url(mock_server.uri())
+            .build()
+            .unwrap();
+
+        let result = client
+            .lookup_batch(&[Fingerprint::new_unchecked("AAAA", 100)], 0.5)
+            .await;
+        assert!(matches!(
+            result,
+            Err(crate::FingerprintError::InvalidResponse(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_acoustid_lookup_retries_after_rate_limit() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/lookup"))
+            .respond_with(ResponseTemplate::new(429).set_body_string("rate limited"))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/lookup"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(sample_response()))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = AcoustidClient::builder("test-key")
+            .base_url(mock_server.uri())
+            .max_retries(3)
+            .base_delay(Duration::from_millis(5))
+            .build()
+            .unwrap();
+
+        let fp = Fingerprint::new_unchecked("AQADvEWZ==", 120);
+        let matches = client.lookup(&fp, 0.5).await.unwrap();
+
+        assert_eq!(matches.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_acoustid_lookup_batch_retries_after_rate_limit() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/lookup"))
+            .respond_with(ResponseTemplate::new(429).set_body_string("rate limited"))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/lookup"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "status": "ok",
+                "fingerprints": [
+                    {"index": 0, "results": [batch_match("11111111-1111-1111-1111-111111111111", "First", 0.9)]}
+                ]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = AcoustidClient::builder("test-key")
+            .base_url(mock_server.uri())
+            .max_retries(3)
+            .base_delay(Duration::from_millis(5))
+            .build()
+            .unwrap();
+
+        let fingerprints = vec![Fingerprint::new_unchecked("AQADvEWZ==", 120)];
+        let results = client.lookup_batch(&fingerprints, 0.5).await.unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_acoustid_lookup_returns_error_when_retries_exhausted() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/lookup"))
+            .respond_with(
+                ResponseTemplate::new(429)
+                    .insert_header("Retry-After", "0")
+                    .set_body_string("rate limited"),
+            )
+            .expect(3)
+            .mount(&mock_server)
+            .await;
+
+        let client = AcoustidClient::builder("test-key")
+            .base_url(mock_server.uri())
+            .max_retries(2)
+            .base_delay(Duration::from_millis(5))
+            .build()
+            .unwrap();
+
+        let fp = Fingerprint::new_unchecked("AQADvEWZ==", 120);
+        let err = client
+            .lookup(&fp, 0.5)
+            .await
+            .expect_err("lookup should fail once retries are exhausted");
+
+        match err {
+            crate::FingerprintError::AcoustidError(msg) => {
+                assert!(msg.contains("429"), "unexpected message: {}", msg);
+            }
+            other => panic!("Expected AcoustidError, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_acoustid_lookup_does_not_retry_other_errors() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/lookup"))
+            .respond_with(ResponseTemplate::new(503).set_body_string("unavailable"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = AcoustidClient::builder("test-key")
+            .base_url(mock_server.uri())
+            .base_delay(Duration::from_millis(5))
+            .build()
+            .unwrap();
+
+        let fp = Fingerprint::new_unchecked("AQADvEWZ==", 120);
+        assert!(client.lookup(&fp, 0.5).await.is_err());
+    }
 }
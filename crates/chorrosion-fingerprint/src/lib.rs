@@ -15,7 +15,10 @@ pub mod generator;
 #[cfg(feature = "ffmpeg-support")]
 pub mod ffmpeg_decoder;
 
-pub use acoustid::{AcoustidClient, RecordingArtist, RecordingMatch, ReleaseInfo};
+pub use acoustid::{
+    AcoustidClient, AcoustidSubmission, RecordingArtist, RecordingMatch, ReleaseInfo,
+    SubmissionStatus, SubmitResult,
+};
 pub use error::{FingerprintError, Result};
 pub use fingerprint::Fingerprint;
 pub use generator::FingerprintGenerator;
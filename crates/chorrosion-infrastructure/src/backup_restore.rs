@@ -2,11 +2,98 @@
 
 use anyhow::{anyhow, Result};
 use chrono::Utc;
+use sqlx::SqlitePool;
 use std::fs;
 use std::path::{Path, PathBuf};
-use tracing::info;
+use tracing::{debug, info};
 use uuid::Uuid;
 
+/// File name prefix of snapshots written by [`create_database_backup`].
+const SNAPSHOT_PREFIX: &str = "chorrosion-";
+/// File name suffix of snapshots written by [`create_database_backup`].
+const SNAPSHOT_SUFFIX: &str = ".snapshot.db";
+
+/// Write a consistent snapshot of the database behind `pool` to `dest`.
+///
+/// Uses `VACUUM INTO`, so the snapshot is taken inside a read transaction while
+/// other connections keep serving the application, and comes out compacted
+/// with no WAL sidecars. `dest` must not exist yet, and the database must be
+/// file-backed.
+pub async fn backup_database(pool: &SqlitePool, dest: &Path) -> Result<()> {
+    if dest.exists() {
+        return Err(anyhow!(
+            "backup destination already exists: {}",
+            dest.display()
+        ));
+    }
+    if let Some(parent) = dest.parent() {
+        if !parent.as_os_str().is_empty() {
+            fs::create_dir_all(parent)?;
+        }
+    }
+
+    let dest_str = dest
+        .to_str()
+        .ok_or_else(|| anyhow!("backup path is not valid UTF-8: {}", dest.display()))?;
+    sqlx::query("VACUUM INTO ?")
+        .bind(dest_str)
+        .execute(pool)
+        .await?;
+    // In-memory connections open the target in memory too, leaving nothing on disk.
+    if !dest.exists() {
+        return Err(anyhow!(
+            "in-memory sqlite databases cannot be snapshotted to disk"
+        ));
+    }
+
+    info!(target: "infrastructure", backup = %dest.display(), "wrote sqlite snapshot");
+    Ok(())
+}
+
+/// Snapshot the database into `backup_dir` under a timestamped name, then
+/// delete all but the `keep` most recent snapshots there.
+pub async fn create_database_backup(
+    pool: &SqlitePool,
+    backup_dir: &Path,
+    keep: usize,
+) -> Result<PathBuf> {
+    let timestamp = Utc::now().format("%Y%m%d%H%M%S%f");
+    let unique_id = Uuid::new_v4().simple();
+    let dest = backup_dir.join(format!(
+        "{SNAPSHOT_PREFIX}{timestamp}-{unique_id}{SNAPSHOT_SUFFIX}"
+    ));
+    backup_database(pool, &dest).await?;
+    prune_database_backups(backup_dir, keep)?;
+    Ok(dest)
+}
+
+/// Delete all but the `keep` most recent snapshots in `backup_dir`.
+///
+/// Only files named like those from [`create_database_backup`] are considered;
+/// their timestamped names sort chronologically. Returns the deleted paths.
+pub fn prune_database_backups(backup_dir: &Path, keep: usize) -> Result<Vec<PathBuf>> {
+    let mut snapshots: Vec<PathBuf> = fs::read_dir(backup_dir)?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_ok_and(|kind| kind.is_file()))
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| {
+                    name.starts_with(SNAPSHOT_PREFIX) && name.ends_with(SNAPSHOT_SUFFIX)
+                })
+        })
+        .collect();
+    snapshots.sort_unstable_by(|left, right| right.file_name().cmp(&left.file_name()));
+
+    let expired = snapshots.split_off(keep.min(snapshots.len()));
+    for path in &expired {
+        fs::remove_file(path)?;
+        debug!(target: "infrastructure", backup = %path.display(), "deleted expired sqlite snapshot");
+    }
+    Ok(expired)
+}
+
 /// Create a timestamped backup copy of a SQLite database file.
 ///
 /// `database_url` must be a file-backed SQLite URL (for example:
@@ -429,6 +516,105 @@ mod tests {
         let _ = fs::remove_dir_all(&temp_root);
     }
 
+    async fn file_pool_with_artist(temp_root: &Path) -> SqlitePool {
+        use crate::repositories::Repository;
+        use crate::sqlite_adapters::SqliteArtistRepository;
+
+        let mut config = chorrosion_config::AppConfig::default();
+        config.database.url = format!("sqlite://{}", temp_root.join("chorrosion.db").display());
+        let pool = crate::init_database(&config).await.expect("file database");
+        SqliteArtistRepository::new(pool.clone())
+            .create(chorrosion_domain::Artist::new("Stereolab"))
+            .await
+            .expect("artist should be created");
+        pool
+    }
+
+    #[tokio::test]
+    async fn test_backup_database_writes_readable_snapshot() {
+        let temp_root = unique_temp_dir("snapshot");
+        let pool = file_pool_with_artist(&temp_root).await;
+        let dest = temp_root.join("snapshots").join("chorrosion.db");
+
+        backup_database(&pool, &dest)
+            .await
+            .expect("snapshot should be written");
+        assert!(
+            backup_database(&pool, &dest).await.is_err(),
+            "an existing snapshot must not be overwritten"
+        );
+
+        let snapshot = SqlitePool::connect(&format!("sqlite://{}", dest.display()))
+            .await
+            .expect("snapshot should open");
+        let (artists,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM artists")
+            .fetch_one(&snapshot)
+            .await
+            .expect("snapshot should contain the artists table");
+        assert_eq!(artists, 1);
+        snapshot.close().await;
+        pool.close().await;
+
+        let _ = fs::remove_dir_all(&temp_root);
+    }
+
+    #[tokio::test]
+    async fn test_create_database_backup_keeps_only_most_recent_snapshots() {
+        let temp_root = unique_temp_dir("snapshot-retention");
+        let pool = file_pool_with_artist(&temp_root).await;
+        let backup_dir = temp_root.join("backups");
+
+        let mut created = Vec::new();
+        for _ in 0..3 {
+            created.push(
+                create_database_backup(&pool, &backup_dir, 2)
+                    .await
+                    .expect("backup should be created"),
+            );
+        }
+
+        assert!(!created[0].exists(), "oldest snapshot should be pruned");
+        assert!(created[1].exists());
+        assert!(created[2].exists());
+        pool.close().await;
+
+        let _ = fs::remove_dir_all(&temp_root);
+    }
+
+    #[test]
+    fn test_prune_database_backups_deletes_oldest_and_ignores_other_files() {
+        let backup_dir = unique_temp_dir("snapshot-prune");
+        for timestamp in ["20260101000000", "20260102000000", "20260103000000"] {
+            fs::write(
+                backup_dir.join(format!("chorrosion-{timestamp}-a.snapshot.db")),
+                b"snapshot",
+            )
+            .expect("snapshot should be written");
+        }
+        fs::write(backup_dir.join("notes.txt"), b"keep me").expect("file should be written");
+
+        let deleted = prune_database_backups(&backup_dir, 1).expect("prune should succeed");
+
+        let mut deleted_names: Vec<_> = deleted
+            .iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        deleted_names.sort();
+        assert_eq!(
+            deleted_names,
+            [
+                "chorrosion-20260101000000-a.snapshot.db",
+                "chorrosion-20260102000000-a.snapshot.db"
+            ]
+        );
+        assert!(backup_dir
+            .join("chorrosion-20260103000000-a.snapshot.db")
+            .exists());
+        assert!(backup_dir.join("notes.txt").exists());
+
+        let _ = fs::remove_dir_all(&backup_dir);
+    }
+
     #[test]
     fn test_backup_rejects_in_memory_database_url() {
         let backup_dir = unique_temp_dir("backup-memory");
@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 pub mod backup_restore;
 pub mod cache;
+pub mod pagination;
 pub mod postgres_adapters;
 pub mod profiler;
 pub mod repositories;
@@ -9,8 +10,12 @@ pub mod sqlite_adapters;
 pub mod sqlite_to_postgres;
 pub mod transaction;
 
-pub use backup_restore::{create_sqlite_backup, restore_sqlite_backup};
+pub use backup_restore::{
+    backup_database, create_database_backup, create_sqlite_backup, prune_database_backups,
+    restore_sqlite_backup,
+};
 pub use cache::{CachedResponse, ResponseCache};
+pub use pagination::{collect_all, for_each_page};
 pub use profiler::QueryProfiler;
 pub use transaction::run_in_transaction;
 
@@ -0,0 +1,219 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Helpers for walking a repository table page by page.
+//!
+//! Repository list methods take `(limit, offset)`; these helpers drive such a
+//! method with increasing offsets until a page shorter than the page size
+//! comes back, so callers only deal with the rows themselves.
+
+use anyhow::{ensure, Result};
+use std::future::Future;
+
+/// Page size used by callers that have no reason to pick their own.
+pub const DEFAULT_PAGE_SIZE: i64 = 500;
+
+/// Call `list(limit, offset)` for successive pages and hand each non-empty page
+/// to `visit`, stopping after the first short page.
+///
+/// Returns the total number of rows visited. Rows inserted or deleted while
+/// the walk is in progress can shift later pages, so callers that mutate the
+/// listed table should collect first (see [`collect_all`]).
+pub async fn for_each_page<T, L, LFut, V, VFut>(
+    page_size: i64,
+    mut list: L,
+    mut visit: V,
+) -> Result<usize>
+where
+    L: FnMut(i64, i64) -> LFut,
+    LFut: Future<Output = Result<Vec<T>>>,
+    V: FnMut(Vec<T>) -> VFut,
+    VFut: Future<Output = Result<()>>,
+{
+    ensure!(page_size > 0, "page size must be positive, got {page_size}");
+
+    let mut offset = 0;
+    let mut visited = 0;
+    loop {
+        let page = list(page_size, offset).await?;
+        let page_len = page.len();
+        if page_len > 0 {
+            visited += page_len;
+            visit(page).await?;
+        }
+        if (page_len as i64) < page_size {
+            return Ok(visited);
+        }
+        offset += page_size;
+    }
+}
+
+/// Read every row reachable through `list(limit, offset)` into one vector.
+pub async fn collect_all<T, L, LFut>(page_size: i64, list: L) -> Result<Vec<T>>
+where
+    L: FnMut(i64, i64) -> LFut,
+    LFut: Future<Output = Result<Vec<T>>>,
+{
+    let mut rows = Vec::new();
+    for_each_page(page_size, list, |page| {
+        rows.extend(page);
+        async { Ok(()) }
+    })
+    .await?;
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::init_database;
+    use crate::repositories::Repository;
+    use crate::sqlite_adapters::SqliteArtistRepository;
+    use chorrosion_config::AppConfig;
+    use chorrosion_domain::Artist;
+    use std::cell::RefCell;
+
+    fn fake_table(rows: usize) -> Vec<usize> {
+        (0..rows).collect()
+    }
+
+    async fn list_slice(table: &[usize], limit: i64, offset: i64) -> Result<Vec<usize>> {
+        Ok(table
+            .iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .copied()
+            .collect())
+    }
+
+    #[tokio::test]
+    async fn visits_every_row_exactly_once_across_pages() {
+        let table = fake_table(23);
+        let mut seen = Vec::new();
+        let mut page_sizes = Vec::new();
+
+        let visited = for_each_page(
+            5,
+            |limit, offset| list_slice(&table, limit, offset),
+            |page| {
+                page_sizes.push(page.len());
+                seen.extend(page);
+                async { Ok(()) }
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(visited, 23);
+        assert_eq!(seen, table);
+        assert_eq!(page_sizes, vec![5, 5, 5, 5, 3]);
+    }
+
+    #[tokio::test]
+    async fn stops_after_empty_page_when_rows_fill_pages_exactly() {
+        let table = fake_table(10);
+        let calls = RefCell::new(Vec::new());
+        let mut page_sizes = Vec::new();
+
+        let visited = for_each_page(
+            5,
+            |limit, offset| {
+                calls.borrow_mut().push(offset);
+                list_slice(&table, limit, offset)
+            },
+            |page| {
+                page_sizes.push(page.len());
+                async { Ok(()) }
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(visited, 10);
+        assert_eq!(*calls.borrow(), vec![0, 5, 10]);
+        assert_eq!(page_sizes, vec![5, 5], "the empty page is not visited");
+    }
+
+    #[tokio::test]
+    async fn empty_table_lists_once_and_visits_nothing() {
+        let table = fake_table(0);
+        let calls = RefCell::new(0);
+
+        let visited = for_each_page(
+            5,
+            |limit, offset| {
+                *calls.borrow_mut() += 1;
+                list_slice(&table, limit, offset)
+            },
+            |_page: Vec<usize>| async { panic!("empty pages must not be visited") },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(visited, 0);
+        assert_eq!(*calls.borrow(), 1);
+    }
+
+    #[tokio::test]
+    async fn errors_from_list_or_visit_stop_the_walk() {
+        let table = fake_table(20);
+
+        let list_error = for_each_page(
+            5,
+            |limit, offset| {
+                let table = &table;
+                async move {
+                    ensure!(offset < 10, "list failed at offset {offset}");
+                    list_slice(table, limit, offset).await
+                }
+            },
+            |_page| async { Ok(()) },
+        )
+        .await
+        .unwrap_err();
+        assert!(list_error.to_string().contains("offset 10"));
+
+        let mut visits = 0;
+        let visit_error = for_each_page(
+            5,
+            |limit, offset| list_slice(&table, limit, offset),
+            |_page| {
+                visits += 1;
+                async { Err::<(), _>(anyhow::anyhow!("visit failed")) }
+            },
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(visit_error.to_string(), "visit failed");
+        assert_eq!(visits, 1);
+    }
+
+    #[tokio::test]
+    async fn rejects_non_positive_page_size() {
+        let table = fake_table(3);
+        let result = collect_all(0, |limit, offset| list_slice(&table, limit, offset)).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn collect_all_reads_every_artist_from_repository() {
+        let mut config = AppConfig::default();
+        config.database.url = "sqlite://:memory:".to_string();
+        config.database.pool_max_size = 1;
+        let pool = init_database(&config).await.unwrap();
+        let repo = SqliteArtistRepository::new(pool);
+        for index in 0..7 {
+            repo.create(Artist::new(format!("Artist {index}")))
+                .await
+                .unwrap();
+        }
+
+        let artists = collect_all(3, |limit, offset| repo.list(limit, offset))
+            .await
+            .unwrap();
+
+        let mut names: Vec<_> = artists.into_iter().map(|artist| artist.name).collect();
+        names.sort();
+        let expected: Vec<_> = (0..7).map(|index| format!("Artist {index}")).collect();
+        assert_eq!(names, expected);
+    }
+}
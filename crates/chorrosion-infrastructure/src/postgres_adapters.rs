@@ -12,23 +12,36 @@ use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
 use sqlx::postgres::PgRow;
 use sqlx::PgPool;
 use sqlx::Row;
+use std::collections::HashMap;
 use tracing::debug;
 use uuid::Uuid;
 
 use crate::repositories::{
-    AlbumRepository, ArtistRelationshipRepository, ArtistRepository,
+    like_prefix_pattern, next_updated_at, release_date_columns, release_date_from_columns,
+    AlbumRepository, ArtistRelationshipRepository, ArtistRepository, ConcurrencyConflict,
     DownloadClientDefinitionRepository, IndexerDefinitionRepository, MetadataProfileRepository,
-    QualityProfileRepository, Repository, TrackFileRepository, TrackRepository,
+    MonitoredCascade, QualityProfileRepository, Repository, StatusParsing, TrackFileRepository,
+    TrackRepository, TrackStats,
 };
 
 /// PostgreSQL-backed Artist repository scaffold.
 pub struct PostgresArtistRepository {
     pool: PgPool,
+    status_parsing: StatusParsing,
 }
 
 impl PostgresArtistRepository {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            status_parsing: StatusParsing::default(),
+        }
+    }
+
+    /// Choose how rows with an unrecognised status are decoded (lenient by default).
+    pub fn with_status_parsing(mut self, status_parsing: StatusParsing) -> Self {
+        self.status_parsing = status_parsing;
+        self
     }
 
     pub fn pool(&self) -> &PgPool {
@@ -38,17 +51,24 @@ impl PostgresArtistRepository {
 
 #[async_trait::async_trait]
 impl Repository<Artist> for PostgresArtistRepository {
-    async fn create(&self, entity: Artist) -> Result<Artist> {
+    async fn create(&self, mut entity: Artist) -> Result<Artist> {
         debug!(target: "repository", artist_id = %entity.id, "creating artist (postgres)");
 
+        // Missing profiles fall back to the default ones.
         let q = r#"
             INSERT INTO artists (
                 id, name, foreign_artist_id, musicbrainz_artist_id, metadata_profile_id, quality_profile_id,
                 status, path, monitored, artist_type, sort_name, country, disambiguation, genre_tags, style_tags, created_at, updated_at
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17)
+            ) VALUES (
+                $1, $2, $3, $4,
+                COALESCE($5, (SELECT id FROM metadata_profiles WHERE is_default LIMIT 1)),
+                COALESCE($6, (SELECT id FROM quality_profiles WHERE is_default LIMIT 1)),
+                $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17
+            )
+            RETURNING metadata_profile_id, quality_profile_id
         "#;
 
-        sqlx::query(q)
+        let row = sqlx::query(q)
             .bind(entity.id.to_string())
             .bind(entity.name.clone())
             .bind(entity.foreign_artist_id.clone())
@@ -66,8 +86,10 @@ impl Repository<Artist> for PostgresArtistRepository {
             .bind(entity.style_tags.clone())
             .bind(entity.created_at.naive_utc())
             .bind(entity.updated_at.naive_utc())
-            .execute(&self.pool)
+            .fetch_one(&self.pool)
             .await?;
+        entity.metadata_profile_id = parse_profile_id_opt(row.try_get("metadata_profile_id")?)?;
+        entity.quality_profile_id = parse_profile_id_opt(row.try_get("quality_profile_id")?)?;
 
         Ok(entity)
     }
@@ -80,7 +102,9 @@ impl Repository<Artist> for PostgresArtistRepository {
             .fetch_optional(&self.pool)
             .await?;
 
-        Ok(row.map(|r| row_to_artist(&r)).transpose()?)
+        Ok(row
+            .map(|r| row_to_artist(&r, self.status_parsing))
+            .transpose()?)
     }
 
     async fn list(&self, limit: i64, offset: i64) -> Result<Vec<Artist>> {
@@ -94,13 +118,14 @@ impl Repository<Artist> for PostgresArtistRepository {
 
         let mut out = Vec::with_capacity(rows.len());
         for row in rows {
-            out.push(row_to_artist(&row)?);
+            out.push(row_to_artist(&row, self.status_parsing)?);
         }
         Ok(out)
     }
 
     async fn update(&self, entity: Artist) -> Result<Artist> {
         debug!(target: "repository", artist_id = %entity.id, "updating artist (postgres)");
+        let updated_at = next_updated_at(entity.updated_at);
 
         let q = r#"
             UPDATE artists SET
@@ -119,10 +144,10 @@ impl Repository<Artist> for PostgresArtistRepository {
                 genre_tags = $13,
                 style_tags = $14,
                 updated_at = $15
-            WHERE id = $16
+            WHERE id = $16 AND date_trunc('milliseconds', updated_at) = date_trunc('milliseconds', $17::timestamp)
         "#;
 
-        sqlx::query(q)
+        let result = sqlx::query(q)
             .bind(entity.name.clone())
             .bind(entity.foreign_artist_id.clone())
             .bind(entity.musicbrainz_artist_id.clone())
@@ -137,12 +162,24 @@ impl Repository<Artist> for PostgresArtistRepository {
             .bind(entity.disambiguation.clone())
             .bind(entity.genre_tags.clone())
             .bind(entity.style_tags.clone())
-            .bind(entity.updated_at.naive_utc())
+            .bind(updated_at.naive_utc())
             .bind(entity.id.to_string())
+            .bind(entity.updated_at.naive_utc())
             .execute(&self.pool)
             .await?;
 
-        Ok(entity)
+        ensure_updated(
+            &self.pool,
+            "artists",
+            "artist",
+            &entity.id.to_string(),
+            result.rows_affected(),
+        )
+        .await?;
+        Ok(Artist {
+            updated_at,
+            ..entity
+        })
     }
 
     async fn delete(&self, id: &str) -> Result<()> {
@@ -177,7 +214,9 @@ impl ArtistRepository for PostgresArtistRepository {
             .fetch_optional(&self.pool)
             .await?;
 
-        Ok(row.map(|r| row_to_artist(&r)).transpose()?)
+        Ok(row
+            .map(|r| row_to_artist(&r, self.status_parsing))
+            .transpose()?)
     }
 
     async fn get_by_foreign_id(&self, foreign_id: &str) -> Result<Option<Artist>> {
@@ -188,7 +227,9 @@ impl ArtistRepository for PostgresArtistRepository {
             .fetch_optional(&self.pool)
             .await?;
 
-        Ok(row.map(|r| row_to_artist(&r)).transpose()?)
+        Ok(row
+            .map(|r| row_to_artist(&r, self.status_parsing))
+            .transpose()?)
     }
 
     async fn list_monitored(&self, limit: i64, offset: i64) -> Result<Vec<Artist>> {
@@ -204,7 +245,7 @@ impl ArtistRepository for PostgresArtistRepository {
 
         let mut out = Vec::with_capacity(rows.len());
         for row in rows {
-            out.push(row_to_artist(&row)?);
+            out.push(row_to_artist(&row, self.status_parsing)?);
         }
         Ok(out)
     }
@@ -228,10 +269,315 @@ impl ArtistRepository for PostgresArtistRepository {
 
         let mut out = Vec::with_capacity(rows.len());
         for row in rows {
-            out.push(row_to_artist(&row)?);
+            out.push(row_to_artist(&row, self.status_parsing)?);
         }
         Ok(out)
     }
+
+    async fn list_recently_added(&self, limit: i64, offset: i64) -> Result<Vec<Artist>> {
+        debug!(target: "repository", limit, offset, "listing recently added artists (postgres)");
+
+        let rows =
+            sqlx::query("SELECT * FROM artists ORDER BY created_at DESC, id LIMIT $1 OFFSET $2")
+                .bind(limit)
+                .bind(offset)
+                .fetch_all(&self.pool)
+                .await?;
+
+        let mut out = Vec::with_capacity(rows.len());
+        for row in rows {
+            out.push(row_to_artist(&row, self.status_parsing)?);
+        }
+        Ok(out)
+    }
+
+    async fn autocomplete(&self, prefix: &str, limit: i64) -> Result<Vec<Artist>> {
+        debug!(target: "repository", prefix, limit, "autocompleting artists (postgres)");
+        if prefix.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // Compare lowercased values so the text_pattern_ops indexes on LOWER(name)
+        // and LOWER(sort_name) serve the anchored LIKE.
+        let rows = sqlx::query(
+            "SELECT * FROM artists \
+             WHERE LOWER(name) LIKE $1 ESCAPE '\\' OR LOWER(sort_name) LIKE $1 ESCAPE '\\' \
+             ORDER BY LOWER(name), id LIMIT $2",
+        )
+        .bind(like_prefix_pattern(&prefix.to_lowercase()))
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut out = Vec::with_capacity(rows.len());
+        for row in rows {
+            out.push(row_to_artist(&row, self.status_parsing)?);
+        }
+        Ok(out)
+    }
+
+    async fn set_monitored(&self, ids: &[ArtistId], monitored: bool) -> Result<u64> {
+        debug!(target: "repository", count = ids.len(), monitored, "setting artists monitored (postgres)");
+        let ids: Vec<String> = ids.iter().map(ToString::to_string).collect();
+        let result =
+            sqlx::query("UPDATE artists SET monitored = $1, updated_at = $2 WHERE id = ANY($3)")
+                .bind(monitored)
+                .bind(Utc::now().naive_utc())
+                .bind(&ids)
+                .execute(&self.pool)
+                .await?;
+        Ok(result.rows_affected())
+    }
+
+    async fn set_monitored_cascade(
+        &self,
+        ids: &[ArtistId],
+        monitored: bool,
+    ) -> Result<MonitoredCascade> {
+        debug!(target: "repository", count = ids.len(), monitored, "setting artists monitored with albums and tracks (postgres)");
+        let ids: Vec<String> = ids.iter().map(ToString::to_string).collect();
+        let now = Utc::now().naive_utc();
+        let mut tx = self.pool.begin().await?;
+        let artists =
+            sqlx::query("UPDATE artists SET monitored = $1, updated_at = $2 WHERE id = ANY($3)")
+                .bind(monitored)
+                .bind(now)
+                .bind(&ids)
+                .execute(&mut *tx)
+                .await?
+                .rows_affected();
+        let albums = sqlx::query(
+            "UPDATE albums SET monitored = $1, updated_at = $2 WHERE artist_id = ANY($3)",
+        )
+        .bind(monitored)
+        .bind(now)
+        .bind(&ids)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+        let tracks = sqlx::query(
+            "UPDATE tracks SET monitored = $1, updated_at = $2 WHERE artist_id = ANY($3)",
+        )
+        .bind(monitored)
+        .bind(now)
+        .bind(&ids)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+        tx.commit().await?;
+        Ok(MonitoredCascade {
+            artists,
+            albums,
+            tracks,
+        })
+    }
+
+    async fn record_metadata_refresh(&self, id: ArtistId, at: DateTime<Utc>) -> Result<()> {
+        debug!(target: "repository", artist_id = %id, %at, "recording artist metadata refresh (postgres)");
+        sqlx::query("UPDATE artists SET last_metadata_refresh_at = $1 WHERE id = $2")
+            .bind(at.naive_utc())
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn list_updated_since(
+        &self,
+        since: DateTime<Utc>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Artist>> {
+        debug!(target: "repository", %since, limit, offset, "listing artists updated since (postgres)");
+
+        let rows = sqlx::query(
+            "SELECT * FROM artists WHERE updated_at >= $1 ORDER BY updated_at, id LIMIT $2 OFFSET $3",
+        )
+        .bind(since.naive_utc())
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut out = Vec::with_capacity(rows.len());
+        for row in rows {
+            out.push(row_to_artist(&row, self.status_parsing)?);
+        }
+        Ok(out)
+    }
+
+    async fn merge_artists(&self, keep_id: ArtistId, remove_id: ArtistId) -> Result<Artist> {
+        debug!(target: "repository", %keep_id, %remove_id, "merging artists (postgres)");
+        if keep_id == remove_id {
+            return Err(anyhow!("cannot merge artist {} into itself", keep_id));
+        }
+
+        let keep = keep_id.to_string();
+        let remove = remove_id.to_string();
+        let now = Utc::now().naive_utc();
+        let mut tx = self.pool.begin().await?;
+
+        let removed = sqlx::query(
+            "SELECT foreign_artist_id, musicbrainz_artist_id FROM artists WHERE id = $1",
+        )
+        .bind(&remove)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| anyhow!("artist not found: {}", remove))?;
+        let foreign_artist_id: Option<String> = removed.try_get("foreign_artist_id")?;
+        let musicbrainz_artist_id: Option<String> = removed.try_get("musicbrainz_artist_id")?;
+
+        sqlx::query(
+            "UPDATE artists SET foreign_artist_id = NULL, musicbrainz_artist_id = NULL WHERE id = $1",
+        )
+        .bind(&remove)
+        .execute(&mut *tx)
+        .await?;
+        let kept = sqlx::query(
+            "UPDATE artists SET foreign_artist_id = COALESCE(foreign_artist_id, $1), \
+             musicbrainz_artist_id = COALESCE(musicbrainz_artist_id, $2), updated_at = $3 \
+             WHERE id = $4",
+        )
+        .bind(foreign_artist_id)
+        .bind(musicbrainz_artist_id)
+        .bind(now)
+        .bind(&keep)
+        .execute(&mut *tx)
+        .await?;
+        if kept.rows_affected() == 0 {
+            return Err(anyhow!("artist not found: {}", keep));
+        }
+
+        for table in ["albums", "tracks"] {
+            sqlx::query(&format!(
+                "UPDATE {table} SET artist_id = $1, updated_at = $2 WHERE artist_id = $3"
+            ))
+            .bind(&keep)
+            .bind(now)
+            .bind(&remove)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        // Relationships between the two artists would become self-relationships,
+        // and ones the kept artist already has would break the unique index.
+        sqlx::query(
+            "DELETE FROM artist_relationships \
+             WHERE (source_artist_id = $1 AND related_artist_id = $2) \
+             OR (source_artist_id = $2 AND related_artist_id = $1)",
+        )
+        .bind(&keep)
+        .bind(&remove)
+        .execute(&mut *tx)
+        .await?;
+        for (column, other) in [
+            ("source_artist_id", "related_artist_id"),
+            ("related_artist_id", "source_artist_id"),
+        ] {
+            sqlx::query(&format!(
+                "UPDATE artist_relationships SET {column} = $1, updated_at = $2 \
+                 WHERE {column} = $3 AND NOT EXISTS (\
+                 SELECT 1 FROM artist_relationships existing \
+                 WHERE existing.{column} = $1 \
+                 AND existing.{other} = artist_relationships.{other} \
+                 AND existing.relationship_type = artist_relationships.relationship_type)"
+            ))
+            .bind(&keep)
+            .bind(now)
+            .bind(&remove)
+            .execute(&mut *tx)
+            .await?;
+        }
+        // The Postgres schema has no tag tables (tags are SQLite-only), so there
+        // are no tagged_entities rows to move here.
+
+        sqlx::query("DELETE FROM artists WHERE id = $1")
+            .bind(&remove)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+
+        self.get_by_id(&keep)
+            .await?
+            .ok_or_else(|| anyhow!("artist not found: {}", keep))
+    }
+
+    async fn upsert_by_foreign_id(&self, entity: Artist) -> Result<Artist> {
+        debug!(target: "repository", artist_id = %entity.id, foreign_artist_id = ?entity.foreign_artist_id, "upserting artist by foreign id (postgres)");
+
+        let q = r#"
+            INSERT INTO artists (
+                id, name, foreign_artist_id, musicbrainz_artist_id, metadata_profile_id, quality_profile_id,
+                status, path, monitored, artist_type, sort_name, country, disambiguation, genre_tags, style_tags, created_at, updated_at
+            ) VALUES (
+                $1, $2, $3, $4,
+                COALESCE($5, (SELECT id FROM metadata_profiles WHERE is_default LIMIT 1)),
+                COALESCE($6, (SELECT id FROM quality_profiles WHERE is_default LIMIT 1)),
+                $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17
+            )
+            ON CONFLICT (foreign_artist_id) WHERE foreign_artist_id IS NOT NULL DO UPDATE SET
+                name = EXCLUDED.name,
+                musicbrainz_artist_id = EXCLUDED.musicbrainz_artist_id,
+                status = EXCLUDED.status,
+                artist_type = EXCLUDED.artist_type,
+                sort_name = EXCLUDED.sort_name,
+                country = EXCLUDED.country,
+                disambiguation = EXCLUDED.disambiguation,
+                genre_tags = EXCLUDED.genre_tags,
+                style_tags = EXCLUDED.style_tags,
+                updated_at = EXCLUDED.updated_at
+            RETURNING *
+        "#;
+
+        let row = sqlx::query(q)
+            .bind(entity.id.to_string())
+            .bind(&entity.name)
+            .bind(&entity.foreign_artist_id)
+            .bind(&entity.musicbrainz_artist_id)
+            .bind(entity.metadata_profile_id.map(|p| p.to_string()))
+            .bind(entity.quality_profile_id.map(|p| p.to_string()))
+            .bind(entity.status.to_string())
+            .bind(&entity.path)
+            .bind(entity.monitored)
+            .bind(&entity.artist_type)
+            .bind(&entity.sort_name)
+            .bind(&entity.country)
+            .bind(&entity.disambiguation)
+            .bind(&entity.genre_tags)
+            .bind(&entity.style_tags)
+            .bind(entity.created_at.naive_utc())
+            .bind(entity.updated_at.naive_utc())
+            .fetch_one(&self.pool)
+            .await?;
+
+        row_to_artist(&row, self.status_parsing)
+    }
+}
+
+/// Resolve an optimistic `update` of `id` in `table` that touched `rows_affected` rows.
+///
+/// Zero rows means the row is either gone or its `updated_at` no longer matches the
+/// entity's, i.e. someone else wrote it after the entity was read.
+async fn ensure_updated(
+    pool: &PgPool,
+    table: &'static str,
+    label: &'static str,
+    id: &str,
+    rows_affected: u64,
+) -> Result<()> {
+    if rows_affected > 0 {
+        return Ok(());
+    }
+    let exists = sqlx::query(&format!("SELECT 1 FROM {table} WHERE id = $1"))
+        .bind(id)
+        .fetch_optional(pool)
+        .await?
+        .is_some();
+    if exists {
+        Err(ConcurrencyConflict::new(label, id).into())
+    } else {
+        Err(anyhow!("{label} not found: {id}"))
+    }
 }
 
 fn parse_profile_id_opt(value: Option<String>) -> Result<Option<chorrosion_domain::ProfileId>> {
@@ -244,15 +590,7 @@ fn parse_profile_id_opt(value: Option<String>) -> Result<Option<chorrosion_domai
     }
 }
 
-fn parse_artist_status(value: &str) -> Result<ArtistStatus> {
-    match value {
-        "continuing" => Ok(ArtistStatus::Continuing),
-        "ended" => Ok(ArtistStatus::Ended),
-        other => Err(anyhow!("unknown artist status: {}", other)),
-    }
-}
-
-fn row_to_artist(row: &PgRow) -> Result<Artist> {
+fn row_to_artist(row: &PgRow, status_parsing: StatusParsing) -> Result<Artist> {
     let id: String = row.try_get("id")?;
     let name: String = row.try_get("name")?;
     let foreign_artist_id: Option<String> = row.try_get("foreign_artist_id")?;
@@ -268,6 +606,8 @@ fn row_to_artist(row: &PgRow) -> Result<Artist> {
     let disambiguation: Option<String> = row.try_get("disambiguation")?;
     let genre_tags: Option<String> = row.try_get("genre_tags")?;
     let style_tags: Option<String> = row.try_get("style_tags")?;
+    let last_metadata_refresh_at: Option<NaiveDateTime> =
+        row.try_get("last_metadata_refresh_at")?;
     let created_at: NaiveDateTime = row.try_get("created_at")?;
     let updated_at: NaiveDateTime = row.try_get("updated_at")?;
 
@@ -278,7 +618,7 @@ fn row_to_artist(row: &PgRow) -> Result<Artist> {
         musicbrainz_artist_id,
         metadata_profile_id: parse_profile_id_opt(metadata_profile_id)?,
         quality_profile_id: parse_profile_id_opt(quality_profile_id)?,
-        status: parse_artist_status(&status)?,
+        status: status_parsing.parse_artist_status(&status)?,
         path,
         monitored,
         artist_type,
@@ -287,6 +627,8 @@ fn row_to_artist(row: &PgRow) -> Result<Artist> {
         disambiguation,
         genre_tags,
         style_tags,
+        last_metadata_refresh_at: last_metadata_refresh_at
+            .map(|at| DateTime::<Utc>::from_naive_utc_and_offset(at, Utc)),
         created_at: DateTime::<Utc>::from_naive_utc_and_offset(created_at, Utc),
         updated_at: DateTime::<Utc>::from_naive_utc_and_offset(updated_at, Utc),
     })
@@ -295,11 +637,21 @@ fn row_to_artist(row: &PgRow) -> Result<Artist> {
 /// PostgreSQL-backed Album repository scaffold.
 pub struct PostgresAlbumRepository {
     pool: PgPool,
+    status_parsing: StatusParsing,
 }
 
 impl PostgresAlbumRepository {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            status_parsing: StatusParsing::default(),
+        }
+    }
+
+    /// Choose how rows with an unrecognised status are decoded (lenient by default).
+    pub fn with_status_parsing(mut self, status_parsing: StatusParsing) -> Self {
+        self.status_parsing = status_parsing;
+        self
     }
 
     pub fn pool(&self) -> &PgPool {
@@ -425,13 +777,11 @@ impl Repository<Album> for PostgresAlbumRepository {
             INSERT INTO albums (
                 id, artist_id, foreign_album_id, musicbrainz_release_group_id, musicbrainz_release_id,
                 title, release_date, album_type, primary_type, secondary_types, first_release_date,
-                genre_tags, style_tags, status, monitored, created_at, updated_at
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17)
+                genre_tags, style_tags, status, monitored, created_at, updated_at, release_date_precision
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18)
         "#;
 
-        let release_date = entity
-            .release_date
-            .map(|d| d.format("%Y-%m-%d").to_string());
+        let (release_date, release_date_precision) = release_date_columns(entity.release_date);
 
         sqlx::query(q)
             .bind(entity.id.to_string())
@@ -451,6 +801,7 @@ impl Repository<Album> for PostgresAlbumRepository {
             .bind(entity.monitored)
             .bind(entity.created_at.naive_utc())
             .bind(entity.updated_at.naive_utc())
+            .bind(release_date_precision)
             .execute(&self.pool)
             .await?;
 
@@ -465,7 +816,9 @@ impl Repository<Album> for PostgresAlbumRepository {
             .fetch_optional(&self.pool)
             .await?;
 
-        Ok(row.map(|r| row_to_album(&r)).transpose()?)
+        Ok(row
+            .map(|r| row_to_album(&r, self.status_parsing))
+            .transpose()?)
     }
 
     async fn list(&self, limit: i64, offset: i64) -> Result<Vec<Album>> {
@@ -479,13 +832,14 @@ impl Repository<Album> for PostgresAlbumRepository {
 
         let mut out = Vec::with_capacity(rows.len());
         for row in rows {
-            out.push(row_to_album(&row)?);
+            out.push(row_to_album(&row, self.status_parsing)?);
         }
         Ok(out)
     }
 
     async fn update(&self, entity: Album) -> Result<Album> {
         debug!(target: "repository", album_id = %entity.id, "updating album (postgres)");
+        let updated_at = next_updated_at(entity.updated_at);
 
         let q = r#"
             UPDATE albums SET
@@ -503,15 +857,14 @@ impl Repository<Album> for PostgresAlbumRepository {
                 style_tags = $12,
                 status = $13,
                 monitored = $14,
-                updated_at = $15
-            WHERE id = $16
+                updated_at = $15,
+                release_date_precision = $18
+            WHERE id = $16 AND date_trunc('milliseconds', updated_at) = date_trunc('milliseconds', $17::timestamp)
         "#;
 
-        let release_date = entity
-            .release_date
-            .map(|d| d.format("%Y-%m-%d").to_string());
+        let (release_date, release_date_precision) = release_date_columns(entity.release_date);
 
-        sqlx::query(q)
+        let result = sqlx::query(q)
             .bind(entity.artist_id.to_string())
             .bind(entity.foreign_album_id.clone())
             .bind(entity.musicbrainz_release_group_id.clone())
@@ -526,12 +879,25 @@ impl Repository<Album> for PostgresAlbumRepository {
             .bind(entity.style_tags.clone())
             .bind(entity.status.to_string())
             .bind(entity.monitored)
-            .bind(entity.updated_at.naive_utc())
+            .bind(updated_at.naive_utc())
             .bind(entity.id.to_string())
+            .bind(entity.updated_at.naive_utc())
+            .bind(release_date_precision)
             .execute(&self.pool)
             .await?;
 
-        Ok(entity)
+        ensure_updated(
+            &self.pool,
+            "albums",
+            "album",
+            &entity.id.to_string(),
+            result.rows_affected(),
+        )
+        .await?;
+        Ok(Album {
+            updated_at,
+            ..entity
+        })
     }
 
     async fn delete(&self, id: &str) -> Result<()> {
@@ -571,7 +937,7 @@ impl AlbumRepository for PostgresAlbumRepository {
 
         let mut out = Vec::with_capacity(rows.len());
         for row in rows {
-            out.push(row_to_album(&row)?);
+            out.push(row_to_album(&row, self.status_parsing)?);
         }
         Ok(out)
     }
@@ -584,7 +950,9 @@ impl AlbumRepository for PostgresAlbumRepository {
             .fetch_optional(&self.pool)
             .await?;
 
-        Ok(row.map(|r| row_to_album(&r)).transpose()?)
+        Ok(row
+            .map(|r| row_to_album(&r, self.status_parsing))
+            .transpose()?)
     }
 
     async fn get_by_artist_and_title(
@@ -602,7 +970,9 @@ impl AlbumRepository for PostgresAlbumRepository {
         .fetch_optional(&self.pool)
         .await?;
 
-        Ok(row.map(|r| row_to_album(&r)).transpose()?)
+        Ok(row
+            .map(|r| row_to_album(&r, self.status_parsing))
+            .transpose()?)
     }
 
     async fn get_by_status(
@@ -623,7 +993,7 @@ impl AlbumRepository for PostgresAlbumRepository {
 
         let mut out = Vec::with_capacity(rows.len());
         for row in rows {
-            out.push(row_to_album(&row)?);
+            out.push(row_to_album(&row, self.status_parsing)?);
         }
         Ok(out)
     }
@@ -641,7 +1011,7 @@ impl AlbumRepository for PostgresAlbumRepository {
 
         let mut out = Vec::with_capacity(rows.len());
         for row in rows {
-            out.push(row_to_album(&row)?);
+            out.push(row_to_album(&row, self.status_parsing)?);
         }
         Ok(out)
     }
@@ -665,7 +1035,7 @@ impl AlbumRepository for PostgresAlbumRepository {
 
         let mut out = Vec::with_capacity(rows.len());
         for row in rows {
-            out.push(row_to_album(&row)?);
+            out.push(row_to_album(&row, self.status_parsing)?);
         }
         Ok(out)
     }
@@ -687,7 +1057,7 @@ impl AlbumRepository for PostgresAlbumRepository {
 
         let mut out = Vec::with_capacity(rows.len());
         for row in rows {
-            out.push(row_to_album(&row)?);
+            out.push(row_to_album(&row, self.status_parsing)?);
         }
         Ok(out)
     }
@@ -732,7 +1102,7 @@ impl AlbumRepository for PostgresAlbumRepository {
 
         let mut out = Vec::with_capacity(rows.len());
         for row in rows {
-            out.push(row_to_album(&row)?);
+            out.push(row_to_album(&row, self.status_parsing)?);
         }
         Ok(out)
     }
@@ -767,22 +1137,172 @@ impl AlbumRepository for PostgresAlbumRepository {
 
         let mut out = Vec::with_capacity(rows.len());
         for row in rows {
-            out.push(row_to_album(&row)?);
+            out.push(row_to_album(&row, self.status_parsing)?);
         }
         Ok(out)
     }
-}
 
-fn parse_album_status(value: &str) -> Result<AlbumStatus> {
-    match value {
-        "wanted" => Ok(AlbumStatus::Wanted),
-        "released" => Ok(AlbumStatus::Released),
-        "announced" => Ok(AlbumStatus::Announced),
-        other => Err(anyhow!("unknown album status: {}", other)),
+    async fn list_recently_added(&self, limit: i64, offset: i64) -> Result<Vec<Album>> {
+        debug!(target: "repository", limit, offset, "listing recently added albums (postgres)");
+
+        let rows =
+            sqlx::query("SELECT * FROM albums ORDER BY created_at DESC, id LIMIT $1 OFFSET $2")
+                .bind(limit)
+                .bind(offset)
+                .fetch_all(&self.pool)
+                .await?;
+
+        let mut out = Vec::with_capacity(rows.len());
+        for row in rows {
+            out.push(row_to_album(&row, self.status_parsing)?);
+        }
+        Ok(out)
+    }
+
+    async fn set_monitored(&self, ids: &[AlbumId], monitored: bool) -> Result<u64> {
+        debug!(target: "repository", count = ids.len(), monitored, "setting albums monitored (postgres)");
+        let ids: Vec<String> = ids.iter().map(ToString::to_string).collect();
+        let result =
+            sqlx::query("UPDATE albums SET monitored = $1, updated_at = $2 WHERE id = ANY($3)")
+                .bind(monitored)
+                .bind(Utc::now().naive_utc())
+                .bind(&ids)
+                .execute(&self.pool)
+                .await?;
+        Ok(result.rows_affected())
+    }
+
+    async fn record_metadata_refresh(&self, id: AlbumId, at: DateTime<Utc>) -> Result<()> {
+        debug!(target: "repository", album_id = %id, %at, "recording album metadata refresh (postgres)");
+        sqlx::query("UPDATE albums SET last_metadata_refresh_at = $1 WHERE id = $2")
+            .bind(at.naive_utc())
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn list_updated_since(
+        &self,
+        since: DateTime<Utc>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Album>> {
+        debug!(target: "repository", %since, limit, offset, "listing albums updated since (postgres)");
+
+        let rows = sqlx::query(
+            "SELECT * FROM albums WHERE updated_at >= $1 ORDER BY updated_at, id LIMIT $2 OFFSET $3",
+        )
+        .bind(since.naive_utc())
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut out = Vec::with_capacity(rows.len());
+        for row in rows {
+            out.push(row_to_album(&row, self.status_parsing)?);
+        }
+        Ok(out)
+    }
+
+    async fn upsert_by_foreign_id(&self, entity: Album) -> Result<Album> {
+        debug!(target: "repository", album_id = %entity.id, foreign_album_id = ?entity.foreign_album_id, "upserting album by foreign id (postgres)");
+
+        let q = r#"
+            INSERT INTO albums (
+                id, artist_id, foreign_album_id, musicbrainz_release_group_id, musicbrainz_release_id,
+                title, release_date, album_type, primary_type, secondary_types, first_release_date,
+                genre_tags, style_tags, status, monitored, created_at, updated_at, release_date_precision
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18)
+            ON CONFLICT (foreign_album_id) WHERE foreign_album_id IS NOT NULL DO UPDATE SET
+                musicbrainz_release_group_id = EXCLUDED.musicbrainz_release_group_id,
+                musicbrainz_release_id = EXCLUDED.musicbrainz_release_id,
+                title = EXCLUDED.title,
+                release_date = EXCLUDED.release_date,
+                release_date_precision = EXCLUDED.release_date_precision,
+                album_type = EXCLUDED.album_type,
+                primary_type = EXCLUDED.primary_type,
+                secondary_types = EXCLUDED.secondary_types,
+                first_release_date = EXCLUDED.first_release_date,
+                genre_tags = EXCLUDED.genre_tags,
+                style_tags = EXCLUDED.style_tags,
+                updated_at = EXCLUDED.updated_at
+            RETURNING *
+        "#;
+
+        let (release_date, release_date_precision) = release_date_columns(entity.release_date);
+
+        let row = sqlx::query(q)
+            .bind(entity.id.to_string())
+            .bind(entity.artist_id.to_string())
+            .bind(&entity.foreign_album_id)
+            .bind(&entity.musicbrainz_release_group_id)
+            .bind(&entity.musicbrainz_release_id)
+            .bind(&entity.title)
+            .bind(release_date)
+            .bind(&entity.album_type)
+            .bind(&entity.primary_type)
+            .bind(&entity.secondary_types)
+            .bind(&entity.first_release_date)
+            .bind(&entity.genre_tags)
+            .bind(&entity.style_tags)
+            .bind(entity.status.to_string())
+            .bind(entity.monitored)
+            .bind(entity.created_at.naive_utc())
+            .bind(entity.updated_at.naive_utc())
+            .bind(release_date_precision)
+            .fetch_one(&self.pool)
+            .await?;
+
+        row_to_album(&row, self.status_parsing)
+    }
+
+    async fn track_stats(&self, album_id: AlbumId) -> Result<TrackStats> {
+        let mut stats = self.track_stats_for(&[album_id]).await?;
+        Ok(stats.remove(&album_id).unwrap_or_default())
+    }
+
+    async fn track_stats_for(&self, album_ids: &[AlbumId]) -> Result<HashMap<AlbumId, TrackStats>> {
+        debug!(target: "repository", count = album_ids.len(), "fetching album track stats (postgres)");
+        let mut out: HashMap<AlbumId, TrackStats> = album_ids
+            .iter()
+            .map(|id| (*id, TrackStats::default()))
+            .collect();
+        if album_ids.is_empty() {
+            return Ok(out);
+        }
+
+        let ids: Vec<String> = album_ids.iter().map(ToString::to_string).collect();
+        let rows = sqlx::query(
+            "SELECT album_id, COUNT(*) AS total, \
+                    COUNT(*) FILTER (WHERE has_file) AS with_file, \
+                    COUNT(*) FILTER (WHERE monitored) AS monitored, \
+                    COUNT(*) FILTER (WHERE monitored AND NOT has_file) AS monitored_missing \
+             FROM tracks WHERE album_id = ANY($1) GROUP BY album_id",
+        )
+        .bind(&ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        for row in rows {
+            let album_id: String = row.try_get("album_id")?;
+            let album_id = AlbumId::from_uuid(Uuid::parse_str(&album_id)?);
+            out.insert(
+                album_id,
+                TrackStats::from_counts(
+                    row.try_get("total")?,
+                    row.try_get("with_file")?,
+                    row.try_get("monitored")?,
+                    row.try_get("monitored_missing")?,
+                ),
+            );
+        }
+        Ok(out)
     }
 }
 
-fn row_to_album(row: &PgRow) -> Result<Album> {
+fn row_to_album(row: &PgRow, status_parsing: StatusParsing) -> Result<Album> {
     let id: String = row.try_get("id")?;
     let artist_id: String = row.try_get("artist_id")?;
     let foreign_album_id: Option<String> = row.try_get("foreign_album_id")?;
@@ -790,7 +1310,10 @@ fn row_to_album(row: &PgRow) -> Result<Album> {
         row.try_get("musicbrainz_release_group_id")?;
     let musicbrainz_release_id: Option<String> = row.try_get("musicbrainz_release_id")?;
     let title: String = row.try_get("title")?;
-    let release_date: Option<String> = row.try_get("release_date")?;
+    let release_date = release_date_from_columns(
+        row.try_get("release_date")?,
+        row.try_get("release_date_precision")?,
+    );
     let album_type: Option<String> = row.try_get("album_type")?;
     let primary_type: Option<String> = row.try_get("primary_type")?;
     let secondary_types: Option<String> = row.try_get("secondary_types")?;
@@ -799,6 +1322,8 @@ fn row_to_album(row: &PgRow) -> Result<Album> {
     let style_tags: Option<String> = row.try_get("style_tags")?;
     let status: String = row.try_get("status")?;
     let monitored: bool = row.try_get("monitored")?;
+    let last_metadata_refresh_at: Option<NaiveDateTime> =
+        row.try_get("last_metadata_refresh_at")?;
     let created_at: NaiveDateTime = row.try_get("created_at")?;
     let updated_at: NaiveDateTime = row.try_get("updated_at")?;
 
@@ -809,15 +1334,17 @@ fn row_to_album(row: &PgRow) -> Result<Album> {
         musicbrainz_release_group_id,
         musicbrainz_release_id,
         title,
-        release_date: release_date.and_then(|d| NaiveDate::parse_from_str(&d, "%Y-%m-%d").ok()),
+        release_date,
         album_type,
         primary_type,
         secondary_types,
         first_release_date,
         genre_tags,
         style_tags,
-        status: parse_album_status(&status)?,
+        status: status_parsing.parse_album_status(&status)?,
         monitored,
+        last_metadata_refresh_at: last_metadata_refresh_at
+            .map(|at| DateTime::<Utc>::from_naive_utc_and_offset(at, Utc)),
         created_at: DateTime::<Utc>::from_naive_utc_and_offset(created_at, Utc),
         updated_at: DateTime::<Utc>::from_naive_utc_and_offset(updated_at, Utc),
     })
@@ -887,6 +1414,7 @@ impl Repository<Track> for PostgresTrackRepository {
 
     async fn update(&self, entity: Track) -> Result<Track> {
         debug!(target: "repository", track_id = %entity.id, "updating track (postgres)");
+        let updated_at = next_updated_at(entity.updated_at);
 
         let q = r#"
             UPDATE tracks SET
@@ -898,11 +1426,13 @@ impl Repository<Track> for PostgresTrackRepository {
                 duration_ms = $6,
                 has_file = $7,
                 monitored = $8,
-                updated_at = $9
-            WHERE id = $10
+                musicbrainz_recording_id = $9,
+                match_confidence = $10,
+                updated_at = $11
+            WHERE id = $12 AND date_trunc('milliseconds', updated_at) = date_trunc('milliseconds', $13::timestamp)
         "#;
 
-        sqlx::query(q)
+        let result = sqlx::query(q)
             .bind(entity.album_id.to_string())
             .bind(entity.artist_id.to_string())
             .bind(entity.foreign_track_id.clone())
@@ -911,12 +1441,26 @@ impl Repository<Track> for PostgresTrackRepository {
             .bind(entity.duration_ms.map(|n| n as i32))
             .bind(entity.has_file)
             .bind(entity.monitored)
-            .bind(entity.updated_at.naive_utc())
+            .bind(entity.musicbrainz_recording_id.clone())
+            .bind(entity.match_confidence.map(f64::from))
+            .bind(updated_at.naive_utc())
             .bind(entity.id.to_string())
+            .bind(entity.updated_at.naive_utc())
             .execute(&self.pool)
             .await?;
 
-        Ok(entity)
+        ensure_updated(
+            &self.pool,
+            "tracks",
+            "track",
+            &entity.id.to_string(),
+            result.rows_affected(),
+        )
+        .await?;
+        Ok(Track {
+            updated_at,
+            ..entity
+        })
     }
 
     async fn delete(&self, id: &str) -> Result<()> {
@@ -991,6 +1535,17 @@ impl TrackRepository for PostgresTrackRepository {
         Ok(row.map(|r| row_to_track(&r)).transpose()?)
     }
 
+    async fn get_by_musicbrainz_recording_id(&self, recording_id: &str) -> Result<Option<Track>> {
+        debug!(target: "repository", recording_id, "fetching track by musicbrainz_recording_id (postgres)");
+
+        let row = sqlx::query("SELECT * FROM tracks WHERE musicbrainz_recording_id = $1 LIMIT 1")
+            .bind(recording_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(|r| row_to_track(&r)).transpose()?)
+    }
+
     async fn list_monitored(&self, limit: i64, offset: i64) -> Result<Vec<Track>> {
         debug!(target: "repository", limit, offset, "listing monitored tracks (postgres)");
 
@@ -1026,6 +1581,84 @@ impl TrackRepository for PostgresTrackRepository {
         }
         Ok(out)
     }
+
+    async fn list_wanted(&self, limit: i64, offset: i64) -> Result<Vec<Track>> {
+        debug!(target: "repository", limit, offset, "listing wanted tracks (postgres)");
+
+        let rows = sqlx::query(
+            "SELECT tracks.* FROM tracks \
+             JOIN albums ON albums.id = tracks.album_id \
+             JOIN artists ON artists.id = tracks.artist_id \
+             WHERE tracks.has_file = false AND tracks.monitored = true \
+               AND albums.monitored = true AND artists.monitored = true \
+             ORDER BY tracks.album_id, tracks.track_number, tracks.title, tracks.id \
+             LIMIT $1 OFFSET $2",
+        )
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut out = Vec::with_capacity(rows.len());
+        for row in rows {
+            out.push(row_to_track(&row)?);
+        }
+        Ok(out)
+    }
+
+    async fn list_recently_added(&self, limit: i64, offset: i64) -> Result<Vec<Track>> {
+        debug!(target: "repository", limit, offset, "listing recently added tracks (postgres)");
+
+        let rows =
+            sqlx::query("SELECT * FROM tracks ORDER BY created_at DESC, id LIMIT $1 OFFSET $2")
+                .bind(limit)
+                .bind(offset)
+                .fetch_all(&self.pool)
+                .await?;
+
+        let mut out = Vec::with_capacity(rows.len());
+        for row in rows {
+            out.push(row_to_track(&row)?);
+        }
+        Ok(out)
+    }
+
+    async fn set_monitored(&self, ids: &[TrackId], monitored: bool) -> Result<u64> {
+        debug!(target: "repository", count = ids.len(), monitored, "setting tracks monitored (postgres)");
+        let ids: Vec<String> = ids.iter().map(ToString::to_string).collect();
+        let result =
+            sqlx::query("UPDATE tracks SET monitored = $1, updated_at = $2 WHERE id = ANY($3)")
+                .bind(monitored)
+                .bind(Utc::now().naive_utc())
+                .bind(&ids)
+                .execute(&self.pool)
+                .await?;
+        Ok(result.rows_affected())
+    }
+
+    async fn list_updated_since(
+        &self,
+        since: DateTime<Utc>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Track>> {
+        debug!(target: "repository", %since, limit, offset, "listing tracks updated since (postgres)");
+
+        let rows = sqlx::query(
+            "SELECT * FROM tracks WHERE updated_at >= $1 ORDER BY updated_at, id LIMIT $2 OFFSET $3",
+        )
+        .bind(since.naive_utc())
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut out = Vec::with_capacity(rows.len());
+        for row in rows {
+            out.push(row_to_track(&row)?);
+        }
+        Ok(out)
+    }
 }
 
 fn row_to_track(row: &PgRow) -> Result<Track> {
@@ -1074,8 +1707,9 @@ impl Repository<QualityProfile> for PostgresQualityProfileRepository {
         sqlx::query(
             r#"
             INSERT INTO quality_profiles (
-                id, name, allowed_qualities, upgrade_allowed, cutoff_quality, created_at, updated_at
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7)
+                id, name, allowed_qualities, upgrade_allowed, cutoff_quality, is_default,
+                created_at, updated_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
             "#,
         )
         .bind(entity.id.to_string())
@@ -1083,6 +1717,7 @@ impl Repository<QualityProfile> for PostgresQualityProfileRepository {
         .bind(qualities_json)
         .bind(entity.upgrade_allowed)
         .bind(entity.cutoff_quality.clone())
+        .bind(entity.is_default)
         .bind(entity.created_at.naive_utc())
         .bind(entity.updated_at.naive_utc())
         .execute(&self.pool)
@@ -1120,10 +1755,11 @@ impl Repository<QualityProfile> for PostgresQualityProfileRepository {
 
     async fn update(&self, entity: QualityProfile) -> Result<QualityProfile> {
         debug!(target: "repository", profile_id = %entity.id, "updating quality profile (postgres)");
+        let updated_at = next_updated_at(entity.updated_at);
 
         let qualities_json = serde_json::to_string(&entity.allowed_qualities)?;
 
-        sqlx::query(
+        let result = sqlx::query(
             r#"
             UPDATE quality_profiles SET
                 name = $1,
@@ -1131,30 +1767,53 @@ impl Repository<QualityProfile> for PostgresQualityProfileRepository {
                 upgrade_allowed = $3,
                 cutoff_quality = $4,
                 updated_at = $5
-            WHERE id = $6
+            WHERE id = $6 AND date_trunc('milliseconds', updated_at) = date_trunc('milliseconds', $7::timestamp)
             "#,
         )
         .bind(entity.name.clone())
         .bind(qualities_json)
         .bind(entity.upgrade_allowed)
         .bind(entity.cutoff_quality.clone())
-        .bind(entity.updated_at.naive_utc())
+        .bind(updated_at.naive_utc())
         .bind(entity.id.to_string())
+        .bind(entity.updated_at.naive_utc())
         .execute(&self.pool)
         .await?;
 
-        Ok(entity)
+        ensure_updated(
+            &self.pool,
+            "quality_profiles",
+            "quality profile",
+            &entity.id.to_string(),
+            result.rows_affected(),
+        )
+        .await?;
+        Ok(QualityProfile {
+            updated_at,
+            ..entity
+        })
     }
 
     async fn delete(&self, id: &str) -> Result<()> {
         debug!(target: "repository", %id, "deleting quality profile (postgres)");
 
-        let result = sqlx::query("DELETE FROM quality_profiles WHERE id = $1")
-            .bind(id)
-            .execute(&self.pool)
-            .await?;
+        // Artists reference profiles without a foreign key, so refuse in the same
+        // statement rather than leave their `quality_profile_id` dangling.
+        let result = sqlx::query(
+            "DELETE FROM quality_profiles WHERE id = $1 \
+             AND NOT EXISTS (SELECT 1 FROM artists WHERE quality_profile_id = $1)",
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
 
         if result.rows_affected() == 0 {
+            let assigned = self.count_assigned_artists(id).await?;
+            if assigned > 0 {
+                return Err(anyhow!(
+                    "quality profile {id} is still assigned to {assigned} artist(s)"
+                ));
+            }
             return Err(anyhow!("quality profile not found: {}", id));
         }
 
@@ -1174,6 +1833,65 @@ impl QualityProfileRepository for PostgresQualityProfileRepository {
 
         Ok(row.map(|r| row_to_quality_profile(&r)).transpose()?)
     }
+
+    async fn get_default(&self) -> Result<Option<QualityProfile>> {
+        debug!(target: "repository", "fetching default quality profile (postgres)");
+
+        let row = sqlx::query("SELECT * FROM quality_profiles WHERE is_default LIMIT 1")
+            .fetch_optional(&self.pool)
+            .await?;
+
+        row.map(|r| row_to_quality_profile(&r)).transpose()
+    }
+
+    async fn set_default(&self, id: &str) -> Result<()> {
+        debug!(target: "repository", id, "setting default quality profile (postgres)");
+        set_default_in(&self.pool, "quality_profiles", "quality profile", id).await
+    }
+
+    async fn count_assigned_artists(&self, id: &str) -> Result<i64> {
+        debug!(target: "repository", id, "counting artists assigned to quality profile (postgres)");
+
+        let count: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM artists WHERE quality_profile_id = $1")
+                .bind(id)
+                .fetch_one(&self.pool)
+                .await?;
+        Ok(count)
+    }
+}
+
+/// Make `id` the only default row of the profile `table` in one transaction.
+async fn set_default_in(
+    pool: &PgPool,
+    table: &'static str,
+    label: &'static str,
+    id: &str,
+) -> Result<()> {
+    let now = Utc::now().naive_utc();
+    let mut tx = pool.begin().await?;
+
+    sqlx::query(&format!(
+        "UPDATE {table} SET is_default = false, updated_at = $1 WHERE is_default AND id <> $2"
+    ))
+    .bind(now)
+    .bind(id)
+    .execute(&mut *tx)
+    .await?;
+    let updated = sqlx::query(&format!(
+        "UPDATE {table} SET is_default = true, updated_at = $1 WHERE id = $2"
+    ))
+    .bind(now)
+    .bind(id)
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+    if updated == 0 {
+        return Err(anyhow!("{label} not found: {id}"));
+    }
+
+    tx.commit().await?;
+    Ok(())
 }
 
 fn row_to_quality_profile(row: &PgRow) -> Result<QualityProfile> {
@@ -1182,6 +1900,7 @@ fn row_to_quality_profile(row: &PgRow) -> Result<QualityProfile> {
     let allowed_qualities_json: String = row.try_get("allowed_qualities")?;
     let upgrade_allowed: bool = row.try_get("upgrade_allowed")?;
     let cutoff_quality: Option<String> = row.try_get("cutoff_quality")?;
+    let is_default: bool = row.try_get("is_default")?;
     let created_at: NaiveDateTime = row.try_get("created_at")?;
     let updated_at: NaiveDateTime = row.try_get("updated_at")?;
 
@@ -1194,6 +1913,7 @@ fn row_to_quality_profile(row: &PgRow) -> Result<QualityProfile> {
         allowed_qualities,
         upgrade_allowed,
         cutoff_quality,
+        is_default,
         created_at: DateTime::<Utc>::from_naive_utc_and_offset(created_at, Utc),
         updated_at: DateTime::<Utc>::from_naive_utc_and_offset(updated_at, Utc),
     })
@@ -1215,8 +1935,9 @@ impl Repository<MetadataProfile> for PostgresMetadataProfileRepository {
         sqlx::query(
             r#"
             INSERT INTO metadata_profiles (
-                id, name, primary_album_types, secondary_album_types, release_statuses, created_at, updated_at
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7)
+                id, name, primary_album_types, secondary_album_types, release_statuses, is_default,
+                created_at, updated_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
             "#,
         )
         .bind(entity.id.to_string())
@@ -1224,6 +1945,7 @@ impl Repository<MetadataProfile> for PostgresMetadataProfileRepository {
         .bind(primary_json)
         .bind(secondary_json)
         .bind(statuses_json)
+        .bind(entity.is_default)
         .bind(entity.created_at.naive_utc())
         .bind(entity.updated_at.naive_utc())
         .execute(&self.pool)
@@ -1261,12 +1983,13 @@ impl Repository<MetadataProfile> for PostgresMetadataProfileRepository {
 
     async fn update(&self, entity: MetadataProfile) -> Result<MetadataProfile> {
         debug!(target: "repository", profile_id = %entity.id, "updating metadata profile (postgres)");
+        let updated_at = next_updated_at(entity.updated_at);
 
         let primary_json = serde_json::to_string(&entity.primary_album_types)?;
         let secondary_json = serde_json::to_string(&entity.secondary_album_types)?;
         let statuses_json = serde_json::to_string(&entity.release_statuses)?;
 
-        sqlx::query(
+        let result = sqlx::query(
             r#"
             UPDATE metadata_profiles SET
                 name = $1,
@@ -1274,30 +1997,53 @@ impl Repository<MetadataProfile> for PostgresMetadataProfileRepository {
                 secondary_album_types = $3,
                 release_statuses = $4,
                 updated_at = $5
-            WHERE id = $6
+            WHERE id = $6 AND date_trunc('milliseconds', updated_at) = date_trunc('milliseconds', $7::timestamp)
             "#,
         )
         .bind(entity.name.clone())
         .bind(primary_json)
         .bind(secondary_json)
         .bind(statuses_json)
-        .bind(entity.updated_at.naive_utc())
+        .bind(updated_at.naive_utc())
         .bind(entity.id.to_string())
+        .bind(entity.updated_at.naive_utc())
         .execute(&self.pool)
         .await?;
 
-        Ok(entity)
+        ensure_updated(
+            &self.pool,
+            "metadata_profiles",
+            "metadata profile",
+            &entity.id.to_string(),
+            result.rows_affected(),
+        )
+        .await?;
+        Ok(MetadataProfile {
+            updated_at,
+            ..entity
+        })
     }
 
     async fn delete(&self, id: &str) -> Result<()> {
         debug!(target: "repository", %id, "deleting metadata profile (postgres)");
 
-        let result = sqlx::query("DELETE FROM metadata_profiles WHERE id = $1")
-            .bind(id)
-            .execute(&self.pool)
-            .await?;
+        // Artists reference profiles without a foreign key, so refuse in the same
+        // statement rather than leave their `metadata_profile_id` dangling.
+        let result = sqlx::query(
+            "DELETE FROM metadata_profiles WHERE id = $1 \
+             AND NOT EXISTS (SELECT 1 FROM artists WHERE metadata_profile_id = $1)",
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
 
         if result.rows_affected() == 0 {
+            let assigned = self.count_assigned_artists(id).await?;
+            if assigned > 0 {
+                return Err(anyhow!(
+                    "metadata profile {id} is still assigned to {assigned} artist(s)"
+                ));
+            }
             return Err(anyhow!("metadata profile not found: {}", id));
         }
 
@@ -1317,6 +2063,32 @@ impl MetadataProfileRepository for PostgresMetadataProfileRepository {
 
         Ok(row.map(|r| row_to_metadata_profile(&r)).transpose()?)
     }
+
+    async fn get_default(&self) -> Result<Option<MetadataProfile>> {
+        debug!(target: "repository", "fetching default metadata profile (postgres)");
+
+        let row = sqlx::query("SELECT * FROM metadata_profiles WHERE is_default LIMIT 1")
+            .fetch_optional(&self.pool)
+            .await?;
+
+        row.map(|r| row_to_metadata_profile(&r)).transpose()
+    }
+
+    async fn set_default(&self, id: &str) -> Result<()> {
+        debug!(target: "repository", id, "setting default metadata profile (postgres)");
+        set_default_in(&self.pool, "metadata_profiles", "metadata profile", id).await
+    }
+
+    async fn count_assigned_artists(&self, id: &str) -> Result<i64> {
+        debug!(target: "repository", id, "counting artists assigned to metadata profile (postgres)");
+
+        let count: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM artists WHERE metadata_profile_id = $1")
+                .bind(id)
+                .fetch_one(&self.pool)
+                .await?;
+        Ok(count)
+    }
 }
 
 fn row_to_metadata_profile(row: &PgRow) -> Result<MetadataProfile> {
@@ -1344,6 +2116,7 @@ fn row_to_metadata_profile(row: &PgRow) -> Result<MetadataProfile> {
         primary_album_types,
         secondary_album_types,
         release_statuses,
+        is_default: row.try_get("is_default")?,
         created_at: DateTime::<Utc>::from_naive_utc_and_offset(created_at, Utc),
         updated_at: DateTime::<Utc>::from_naive_utc_and_offset(updated_at, Utc),
     })
@@ -1357,12 +2130,14 @@ fn row_to_metadata_profile(row: &PgRow) -> Result<MetadataProfile> {
 impl Repository<IndexerDefinition> for PostgresIndexerDefinitionRepository {
     async fn create(&self, entity: IndexerDefinition) -> Result<IndexerDefinition> {
         debug!(target: "repository", indexer_id = %entity.id, "creating indexer definition (postgres)");
+        let category_map_json = serde_json::to_string(&entity.category_map)?;
 
         sqlx::query(
             r#"
             INSERT INTO indexer_definitions (
-                id, name, base_url, protocol, api_key, enabled, created_at, updated_at
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                id, name, base_url, protocol, api_key, enabled, category_map,
+                requests_per_minute, created_at, updated_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
             "#,
         )
         .bind(entity.id.to_string())
@@ -1371,6 +2146,8 @@ impl Repository<IndexerDefinition> for PostgresIndexerDefinitionRepository {
         .bind(entity.protocol.clone())
         .bind(entity.api_key.clone())
         .bind(entity.enabled)
+        .bind(category_map_json)
+        .bind(entity.requests_per_minute.map(|rpm| rpm as i32))
         .bind(entity.created_at.naive_utc())
         .bind(entity.updated_at.naive_utc())
         .execute(&self.pool)
@@ -1409,8 +2186,10 @@ impl Repository<IndexerDefinition> for PostgresIndexerDefinitionRepository {
 
     async fn update(&self, entity: IndexerDefinition) -> Result<IndexerDefinition> {
         debug!(target: "repository", indexer_id = %entity.id, "updating indexer definition (postgres)");
+        let updated_at = next_updated_at(entity.updated_at);
+        let category_map_json = serde_json::to_string(&entity.category_map)?;
 
-        sqlx::query(
+        let result = sqlx::query(
             r#"
             UPDATE indexer_definitions SET
                 name = $1,
@@ -1418,8 +2197,10 @@ impl Repository<IndexerDefinition> for PostgresIndexerDefinitionRepository {
                 protocol = $3,
                 api_key = $4,
                 enabled = $5,
-                updated_at = $6
-            WHERE id = $7
+                category_map = $6,
+                requests_per_minute = $7,
+                updated_at = $8
+            WHERE id = $9 AND date_trunc('milliseconds', updated_at) = date_trunc('milliseconds', $10::timestamp)
             "#,
         )
         .bind(entity.name.clone())
@@ -1427,12 +2208,26 @@ impl Repository<IndexerDefinition> for PostgresIndexerDefinitionRepository {
         .bind(entity.protocol.clone())
         .bind(entity.api_key.clone())
         .bind(entity.enabled)
-        .bind(entity.updated_at.naive_utc())
+        .bind(category_map_json)
+        .bind(entity.requests_per_minute.map(|rpm| rpm as i32))
+        .bind(updated_at.naive_utc())
         .bind(entity.id.to_string())
+        .bind(entity.updated_at.naive_utc())
         .execute(&self.pool)
         .await?;
 
-        Ok(entity)
+        ensure_updated(
+            &self.pool,
+            "indexer_definitions",
+            "indexer definition",
+            &entity.id.to_string(),
+            result.rows_affected(),
+        )
+        .await?;
+        Ok(IndexerDefinition {
+            updated_at,
+            ..entity
+        })
     }
 
     async fn delete(&self, id: &str) -> Result<()> {
@@ -1472,6 +2267,8 @@ fn row_to_indexer_definition(row: &PgRow) -> Result<IndexerDefinition> {
     let protocol: String = row.try_get("protocol")?;
     let api_key: Option<String> = row.try_get("api_key")?;
     let enabled: bool = row.try_get("enabled")?;
+    let category_map_json: String = row.try_get("category_map")?;
+    let requests_per_minute: Option<i32> = row.try_get("requests_per_minute")?;
     let created_at: NaiveDateTime = row.try_get("created_at")?;
     let updated_at: NaiveDateTime = row.try_get("updated_at")?;
 
@@ -1482,6 +2279,8 @@ fn row_to_indexer_definition(row: &PgRow) -> Result<IndexerDefinition> {
         protocol,
         api_key,
         enabled,
+        category_map: serde_json::from_str(&category_map_json)?,
+        requests_per_minute: requests_per_minute.map(u32::try_from).transpose()?,
         created_at: DateTime::<Utc>::from_naive_utc_and_offset(created_at, Utc),
         updated_at: DateTime::<Utc>::from_naive_utc_and_offset(updated_at, Utc),
     })
@@ -1552,8 +2351,9 @@ impl Repository<DownloadClientDefinition> for PostgresDownloadClientDefinitionRe
 
     async fn update(&self, entity: DownloadClientDefinition) -> Result<DownloadClientDefinition> {
         debug!(target: "repository", client_id = %entity.id, "updating download client definition (postgres)");
+        let updated_at = next_updated_at(entity.updated_at);
 
-        sqlx::query(
+        let result = sqlx::query(
             r#"
             UPDATE download_client_definitions SET
                 name = $1,
@@ -1564,7 +2364,7 @@ impl Repository<DownloadClientDefinition> for PostgresDownloadClientDefinitionRe
                 category = $6,
                 enabled = $7,
                 updated_at = $8
-            WHERE id = $9
+            WHERE id = $9 AND date_trunc('milliseconds', updated_at) = date_trunc('milliseconds', $10::timestamp)
             "#,
         )
         .bind(entity.name.clone())
@@ -1574,12 +2374,24 @@ impl Repository<DownloadClientDefinition> for PostgresDownloadClientDefinitionRe
         .bind(entity.password_encrypted.clone())
         .bind(entity.category.clone())
         .bind(entity.enabled)
-        .bind(entity.updated_at.naive_utc())
+        .bind(updated_at.naive_utc())
         .bind(entity.id.to_string())
+        .bind(entity.updated_at.naive_utc())
         .execute(&self.pool)
         .await?;
 
-        Ok(entity)
+        ensure_updated(
+            &self.pool,
+            "download_client_definitions",
+            "download client definition",
+            &entity.id.to_string(),
+            result.rows_affected(),
+        )
+        .await?;
+        Ok(DownloadClientDefinition {
+            updated_at,
+            ..entity
+        })
     }
 
     async fn delete(&self, id: &str) -> Result<()> {
@@ -1707,18 +2519,20 @@ impl Repository<TrackFile> for PostgresTrackFileRepository {
 
     async fn update(&self, entity: TrackFile) -> Result<TrackFile> {
         debug!(target: "repository", track_file_id = %entity.id, "updating track file (postgres)");
+        let updated_at = next_updated_at(entity.updated_at);
 
         let q = r#"
             UPDATE track_files SET
-                path = $1, size_bytes = $2, duration_ms = $3, bitrate_kbps = $4,
-                channels = $5, codec = $6, quality = $7, hash = $8, fingerprint_hash = $9,
-                fingerprint_duration = $10, fingerprint_computed_at = $11, updated_at = $12
-            WHERE id = $13
+                track_id = $1, path = $2, size_bytes = $3, duration_ms = $4, bitrate_kbps = $5,
+                channels = $6, codec = $7, quality = $8, hash = $9, fingerprint_hash = $10,
+                fingerprint_duration = $11, fingerprint_computed_at = $12, updated_at = $13
+            WHERE id = $14 AND date_trunc('milliseconds', updated_at) = date_trunc('milliseconds', $15::timestamp)
         "#;
 
         let fingerprint_computed_at = entity.fingerprint_computed_at.map(|dt| dt.naive_utc());
 
-        sqlx::query(q)
+        let result = sqlx::query(q)
+            .bind(entity.track_id.to_string())
             .bind(entity.path.clone())
             .bind(entity.size_bytes as i64)
             .bind(entity.duration_ms.map(|d| d as i32))
@@ -1730,12 +2544,24 @@ impl Repository<TrackFile> for PostgresTrackFileRepository {
             .bind(entity.fingerprint_hash.clone())
             .bind(entity.fingerprint_duration.map(|d| d as i32))
             .bind(fingerprint_computed_at)
-            .bind(entity.updated_at.naive_utc())
+            .bind(updated_at.naive_utc())
             .bind(entity.id.to_string())
+            .bind(entity.updated_at.naive_utc())
             .execute(&self.pool)
             .await?;
 
-        Ok(entity)
+        ensure_updated(
+            &self.pool,
+            "track_files",
+            "track file",
+            &entity.id.to_string(),
+            result.rows_affected(),
+        )
+        .await?;
+        Ok(TrackFile {
+            updated_at,
+            ..entity
+        })
     }
 
     async fn delete(&self, id: &str) -> Result<()> {
@@ -1810,6 +2636,23 @@ impl TrackFileRepository for PostgresTrackFileRepository {
 
         rows.iter().map(row_to_track_file).collect()
     }
+
+    async fn list_orphaned(&self, limit: i64, offset: i64) -> Result<Vec<TrackFile>> {
+        debug!(target: "repository", limit, offset, "listing orphaned track files (postgres)");
+
+        let rows = sqlx::query(
+            "SELECT tf.* FROM track_files tf \
+             LEFT JOIN tracks t ON t.id = tf.track_id \
+             WHERE t.id IS NULL \
+             ORDER BY tf.created_at DESC, tf.id LIMIT $1 OFFSET $2",
+        )
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_track_file).collect()
+    }
 }
 
 fn row_to_track_file(row: &PgRow) -> Result<TrackFile> {
@@ -1910,6 +2753,7 @@ impl Repository<ArtistRelationship> for PostgresArtistRelationshipRepository {
 
     async fn update(&self, entity: ArtistRelationship) -> Result<ArtistRelationship> {
         debug!(target: "repository", relationship_id = %entity.id, "updating artist relationship (postgres)");
+        let updated_at = next_updated_at(entity.updated_at);
 
         let q = r#"
             UPDATE artist_relationships SET
@@ -1918,20 +2762,32 @@ impl Repository<ArtistRelationship> for PostgresArtistRelationshipRepository {
                 relationship_type = $3,
                 description = $4,
                 updated_at = $5
-            WHERE id = $6
+            WHERE id = $6 AND date_trunc('milliseconds', updated_at) = date_trunc('milliseconds', $7::timestamp)
         "#;
 
-        sqlx::query(q)
+        let result = sqlx::query(q)
             .bind(entity.source_artist_id.to_string())
             .bind(entity.related_artist_id.to_string())
             .bind(entity.relationship_type.clone())
             .bind(entity.description.clone())
-            .bind(entity.updated_at.naive_utc())
+            .bind(updated_at.naive_utc())
             .bind(entity.id.to_string())
+            .bind(entity.updated_at.naive_utc())
             .execute(&self.pool)
             .await?;
 
-        Ok(entity)
+        ensure_updated(
+            &self.pool,
+            "artist_relationships",
+            "artist relationship",
+            &entity.id.to_string(),
+            result.rows_affected(),
+        )
+        .await?;
+        Ok(ArtistRelationship {
+            updated_at,
+            ..entity
+        })
     }
 
     async fn delete(&self, id: &str) -> Result<()> {
@@ -1,39 +1,230 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use chorrosion_config::DatabaseConfig;
 use chorrosion_domain::{
     Album, AlbumId, AlbumStatus, Artist, ArtistId, ArtistRelationship, ArtistStatus,
     DownloadClientDefinition, DuplicateFileDetail, DuplicateGroup, EntityType, IndexerDefinition,
-    MetadataProfile, QualityProfile, SmartPlaylist, Tag, TagId, TaggedEntity, Track, TrackFile,
-    TrackId,
+    MetadataProfile, QualityProfile, ReleaseDate, ReleaseDatePrecision, SmartPlaylist, Tag, TagId,
+    TaggedEntity, Track, TrackFile, TrackId,
 };
-use chrono::NaiveDate;
+use chrono::{DateTime, NaiveDate, SubsecRound, Utc};
+use std::collections::HashMap;
+use tracing::warn;
+
+// ============================================================================
+// Row Decoding
+// ============================================================================
+
+/// How artist and album rows carrying an unrecognised `status` value are decoded.
+///
+/// Unknown values can appear after a manual database edit or when reading rows written
+/// by a newer release. In lenient mode a single such row no longer fails a whole `list`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StatusParsing {
+    /// Reject the row with an error, failing the query that loaded it.
+    Strict,
+    /// Log a warning and fall back to a safe default
+    /// ([`ArtistStatus::Continuing`] / [`AlbumStatus::Wanted`]).
+    #[default]
+    Lenient,
+}
+
+impl From<&DatabaseConfig> for StatusParsing {
+    fn from(config: &DatabaseConfig) -> Self {
+        if config.strict_status_parsing {
+            Self::Strict
+        } else {
+            Self::Lenient
+        }
+    }
+}
+
+impl StatusParsing {
+    pub fn parse_artist_status(self, value: &str) -> Result<ArtistStatus> {
+        match value {
+            "continuing" => Ok(ArtistStatus::Continuing),
+            "ended" => Ok(ArtistStatus::Ended),
+            other => match self {
+                Self::Strict => Err(anyhow!("unknown artist status: {}", other)),
+                Self::Lenient => {
+                    warn!(target: "repository", status = %other, "unknown artist status; defaulting to continuing");
+                    Ok(ArtistStatus::Continuing)
+                }
+            },
+        }
+    }
+
+    pub fn parse_album_status(self, value: &str) -> Result<AlbumStatus> {
+        match value {
+            "wanted" => Ok(AlbumStatus::Wanted),
+            "released" => Ok(AlbumStatus::Released),
+            "announced" => Ok(AlbumStatus::Announced),
+            other => match self {
+                Self::Strict => Err(anyhow!("unknown album status: {}", other)),
+                Self::Lenient => {
+                    warn!(target: "repository", status = %other, "unknown album status; defaulting to wanted");
+                    Ok(AlbumStatus::Wanted)
+                }
+            },
+        }
+    }
+}
 
 // ============================================================================
 // Repository Traits
 // ============================================================================
 
+/// Returned (inside [`anyhow::Error`]) by [`Repository::update`] when the stored row
+/// changed after the entity being written was read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConcurrencyConflict {
+    /// Human-readable entity kind, e.g. `"artist"`.
+    pub entity: &'static str,
+    pub id: String,
+}
+
+impl ConcurrencyConflict {
+    pub fn new(entity: &'static str, id: impl Into<String>) -> Self {
+        Self {
+            entity,
+            id: id.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for ConcurrencyConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} was modified since it was read; reload it and try again",
+            self.entity, self.id
+        )
+    }
+}
+
+impl std::error::Error for ConcurrencyConflict {}
+
+/// A `LIKE ... ESCAPE '\'` pattern matching values that start with `prefix`.
+pub(crate) fn like_prefix_pattern(prefix: &str) -> String {
+    let escaped = prefix
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    format!("{escaped}%")
+}
+
+/// The `updated_at` to stamp on an entity whose stored version is `previous`.
+///
+/// Versions are compared at millisecond precision, so the new timestamp is
+/// rounded to whole milliseconds and kept at least one millisecond after
+/// `previous`, so a copy read just before another update is still stale.
+pub(crate) fn next_updated_at(previous: DateTime<Utc>) -> DateTime<Utc> {
+    Utc::now()
+        .max(previous + chrono::Duration::milliseconds(1))
+        .round_subsecs(3)
+}
+
+/// `albums.release_date` and `albums.release_date_precision` values for `date`.
+///
+/// The date column always holds a full `YYYY-MM-DD` date (the first day of a
+/// year- or month-precision period) so range queries compare like with like.
+pub(crate) fn release_date_columns(date: Option<ReleaseDate>) -> (Option<String>, Option<String>) {
+    match date.and_then(|date| Some((date.to_naive_date_opt()?, date.precision()))) {
+        Some((start, precision)) => (
+            Some(start.format("%Y-%m-%d").to_string()),
+            Some(precision.to_string()),
+        ),
+        None => (None, None),
+    }
+}
+
+/// Rebuild an album's [`ReleaseDate`] from the columns [`release_date_columns`] wrote.
+pub(crate) fn release_date_from_columns(
+    date: Option<String>,
+    precision: Option<String>,
+) -> Option<ReleaseDate> {
+    let date = ReleaseDate::parse_str(&date?)?;
+    let precision = match precision.as_deref() {
+        Some("year") => ReleaseDatePrecision::Year,
+        Some("month") => ReleaseDatePrecision::Month,
+        _ => ReleaseDatePrecision::Day,
+    };
+    Some(match precision {
+        ReleaseDatePrecision::Year => ReleaseDate::new(date.year, None, None),
+        ReleaseDatePrecision::Month => ReleaseDate::new(date.year, date.month, None),
+        ReleaseDatePrecision::Day => date,
+    })
+}
+
 /// Generic repository for CRUD operations on a domain entity
 #[async_trait::async_trait]
 pub trait Repository<T>: Send + Sync {
     async fn create(&self, entity: T) -> Result<T>;
     async fn get_by_id(&self, id: &str) -> Result<Option<T>>;
     async fn list(&self, limit: i64, offset: i64) -> Result<Vec<T>>;
+    /// Write `entity` over its stored row and return it with a fresh `updated_at`.
+    ///
+    /// `entity.updated_at` is the version the caller read: if the row has been written
+    /// since, nothing is changed and the error is a [`ConcurrencyConflict`]. A missing
+    /// row is a plain not-found error.
     async fn update(&self, entity: T) -> Result<T>;
     async fn delete(&self, id: &str) -> Result<()>;
 }
 
 /// Artist repository with specialized queries
+///
+/// Creating an artist (via `create` or the insert of `upsert_by_foreign_id`)
+/// without a quality or metadata profile assigns the default profile, if any.
 #[async_trait::async_trait]
 pub trait ArtistRepository: Repository<Artist> {
     async fn get_by_name(&self, name: &str) -> Result<Option<Artist>>;
     async fn get_by_foreign_id(&self, foreign_id: &str) -> Result<Option<Artist>>;
     async fn list_monitored(&self, limit: i64, offset: i64) -> Result<Vec<Artist>>;
+    /// Set `monitored` on each of `ids`, touching only that column and `updated_at`.
+    /// Returns how many artists were updated; unknown ids are skipped.
+    async fn set_monitored(&self, ids: &[ArtistId], monitored: bool) -> Result<u64>;
+    /// Set `monitored` on each of `ids` and on every one of their albums and
+    /// tracks in one transaction, one statement per table. Unknown ids are skipped.
+    async fn set_monitored_cascade(
+        &self,
+        ids: &[ArtistId],
+        monitored: bool,
+    ) -> Result<MonitoredCascade>;
+    /// Record that a metadata refresh of `id` completed at `at`, touching only
+    /// `last_metadata_refresh_at`. Unknown ids are ignored.
+    async fn record_metadata_refresh(&self, id: ArtistId, at: DateTime<Utc>) -> Result<()>;
     async fn get_by_status(
         &self,
         status: ArtistStatus,
         limit: i64,
         offset: i64,
     ) -> Result<Vec<Artist>>;
+    /// Return artists ordered by `created_at` descending (newest first).
+    async fn list_recently_added(&self, limit: i64, offset: i64) -> Result<Vec<Artist>>;
+    /// Return artists whose `updated_at` is at or after `since` (inclusive), ordered by
+    /// `updated_at` ascending so callers can page through changes oldest-first.
+    async fn list_updated_since(
+        &self,
+        since: DateTime<Utc>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Artist>>;
+    /// Artists whose `name` or `sort_name` starts with `prefix`, case-insensitively,
+    /// ordered by name. `%`, `_` and `\` in `prefix` match literally. A blank
+    /// prefix matches nothing.
+    async fn autocomplete(&self, prefix: &str, limit: i64) -> Result<Vec<Artist>>;
+    /// Fold `remove_id` into `keep_id` atomically and return the kept artist.
+    ///
+    /// Albums and tracks of the removed artist are re-parented onto the kept artist and the
+    /// removed artist is deleted. When both artists carry a `foreign_artist_id` or
+    /// MusicBrainz ID the kept artist's value wins; otherwise the removed artist's is adopted.
+    /// Nothing is changed if any step fails.
+    async fn merge_artists(&self, keep_id: ArtistId, remove_id: ArtistId) -> Result<Artist>;
+    /// Insert `entity`, or update the existing artist with the same `foreign_artist_id`,
+    /// in a single statement. Only provider metadata is overwritten on update; `monitored`,
+    /// `path` and profile assignments are kept. Returns the persisted artist, whose `id` is
+    /// the existing row's id when one matched. A `None` foreign id always inserts.
+    async fn upsert_by_foreign_id(&self, entity: Artist) -> Result<Artist>;
 }
 
 /// Album repository with specialized queries
@@ -60,6 +251,12 @@ pub trait AlbumRepository: Repository<Album> {
         offset: i64,
     ) -> Result<Vec<Album>>;
     async fn list_monitored(&self, limit: i64, offset: i64) -> Result<Vec<Album>>;
+    /// Set `monitored` on each of `ids`, touching only that column and `updated_at`.
+    /// Returns how many albums were updated; unknown ids are skipped.
+    async fn set_monitored(&self, ids: &[AlbumId], monitored: bool) -> Result<u64>;
+    /// Record that a metadata refresh of `id` completed at `at`, touching only
+    /// `last_metadata_refresh_at`. Unknown ids are ignored.
+    async fn record_metadata_refresh(&self, id: AlbumId, at: DateTime<Utc>) -> Result<()>;
     async fn get_by_album_type(
         &self,
         album_type: &str,
@@ -76,7 +273,8 @@ pub trait AlbumRepository: Repository<Album> {
     /// or when the codec is unknown / absent from the allowed list.
     async fn list_cutoff_unmet_albums(&self, limit: i64, offset: i64) -> Result<Vec<Album>>;
     /// Return monitored albums whose ``release_date`` falls within [start, end] inclusive,
-    /// ordered by ``release_date`` ascending.
+    /// ordered by ``release_date`` ascending. Dates are compared as ISO strings, so
+    /// month- or year-only dates sort before full dates of the same period.
     async fn list_upcoming_releases(
         &self,
         start: NaiveDate,
@@ -84,6 +282,58 @@ pub trait AlbumRepository: Repository<Album> {
         limit: i64,
         offset: i64,
     ) -> Result<Vec<Album>>;
+    /// Return albums ordered by `created_at` descending (newest first).
+    async fn list_recently_added(&self, limit: i64, offset: i64) -> Result<Vec<Album>>;
+    /// Return albums whose `updated_at` is at or after `since` (inclusive), ordered by
+    /// `updated_at` ascending so callers can page through changes oldest-first.
+    async fn list_updated_since(
+        &self,
+        since: DateTime<Utc>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Album>>;
+    /// Insert `entity`, or update the existing album with the same `foreign_album_id`,
+    /// in a single statement. Only provider metadata is overwritten on update; `artist_id`,
+    /// `status` and `monitored` are kept. Returns the persisted album, whose `id` is the
+    /// existing row's id when one matched. A `None` foreign id always inserts.
+    async fn upsert_by_foreign_id(&self, entity: Album) -> Result<Album>;
+    /// Count the tracks of `album_id` in a single aggregate query. An album
+    /// without tracks yields all-zero stats.
+    async fn track_stats(&self, album_id: AlbumId) -> Result<TrackStats>;
+    /// [`Self::track_stats`] for many albums in one query. Every requested id
+    /// is present in the result, with all-zero stats when it has no tracks.
+    async fn track_stats_for(&self, album_ids: &[AlbumId]) -> Result<HashMap<AlbumId, TrackStats>>;
+}
+
+/// Rows updated by [`ArtistRepository::set_monitored_cascade`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MonitoredCascade {
+    pub artists: u64,
+    pub albums: u64,
+    pub tracks: u64,
+}
+
+/// Track counts rolled up for one album, e.g. for `3/12 tracks` progress.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TrackStats {
+    pub total: i64,
+    pub with_file: i64,
+    pub monitored: i64,
+    /// Every monitored track has a file. False when no track is monitored.
+    pub is_complete: bool,
+}
+
+impl TrackStats {
+    /// Build stats from raw counts; `monitored_missing` is the number of
+    /// monitored tracks without a file.
+    pub fn from_counts(total: i64, with_file: i64, monitored: i64, monitored_missing: i64) -> Self {
+        Self {
+            total,
+            with_file,
+            monitored,
+            is_complete: monitored > 0 && monitored_missing == 0,
+        }
+    }
 }
 
 /// Track repository with specialized queries
@@ -97,20 +347,50 @@ pub trait TrackRepository: Repository<Track> {
         offset: i64,
     ) -> Result<Vec<Track>>;
     async fn get_by_foreign_id(&self, foreign_id: &str) -> Result<Option<Track>>;
+    /// Get the track whose `musicbrainz_recording_id` is `recording_id`.
+    async fn get_by_musicbrainz_recording_id(&self, recording_id: &str) -> Result<Option<Track>>;
     async fn list_monitored(&self, limit: i64, offset: i64) -> Result<Vec<Track>>;
+    /// Set `monitored` on each of `ids`, touching only that column and `updated_at`.
+    /// Returns how many tracks were updated; unknown ids are skipped.
+    async fn set_monitored(&self, ids: &[TrackId], monitored: bool) -> Result<u64>;
     async fn list_without_files(&self, limit: i64, offset: i64) -> Result<Vec<Track>>;
+    /// Return monitored tracks without a file whose album and artist are both
+    /// monitored, grouped by album in track-number order.
+    async fn list_wanted(&self, limit: i64, offset: i64) -> Result<Vec<Track>>;
+    /// Return tracks ordered by `created_at` descending (newest first).
+    async fn list_recently_added(&self, limit: i64, offset: i64) -> Result<Vec<Track>>;
+    /// Return tracks whose `updated_at` is at or after `since` (inclusive), ordered by
+    /// `updated_at` ascending so callers can page through changes oldest-first.
+    async fn list_updated_since(
+        &self,
+        since: DateTime<Utc>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Track>>;
 }
 
 /// Quality profile repository
 #[async_trait::async_trait]
 pub trait QualityProfileRepository: Repository<QualityProfile> {
     async fn get_by_name(&self, name: &str) -> Result<Option<QualityProfile>>;
+    /// The profile applied to new artists created without one, if any.
+    async fn get_default(&self) -> Result<Option<QualityProfile>>;
+    /// Make `id` the default, unsetting the previous default in the same transaction.
+    async fn set_default(&self, id: &str) -> Result<()>;
+    /// Count the artists whose `quality_profile_id` is `id`.
+    async fn count_assigned_artists(&self, id: &str) -> Result<i64>;
 }
 
 /// Metadata profile repository
 #[async_trait::async_trait]
 pub trait MetadataProfileRepository: Repository<MetadataProfile> {
     async fn get_by_name(&self, name: &str) -> Result<Option<MetadataProfile>>;
+    /// The profile applied to new artists created without one, if any.
+    async fn get_default(&self) -> Result<Option<MetadataProfile>>;
+    /// Make `id` the default, unsetting the previous default in the same transaction.
+    async fn set_default(&self, id: &str) -> Result<()>;
+    /// Count the artists whose `metadata_profile_id` is `id`.
+    async fn count_assigned_artists(&self, id: &str) -> Result<i64>;
 }
 
 /// Indexer definition repository
@@ -144,6 +424,45 @@ pub trait TrackFileRepository: Repository<TrackFile> {
 
     /// List track files without fingerprints (need processing)
     async fn list_without_fingerprints(&self, limit: i64, offset: i64) -> Result<Vec<TrackFile>>;
+
+    /// List track files whose `track_id` no longer references an existing track.
+    async fn list_orphaned(&self, limit: i64, offset: i64) -> Result<Vec<TrackFile>>;
+
+    /// List track files whose `path` is reported missing by `exists`.
+    ///
+    /// `limit` and `offset` page over the missing files rather than the scanned rows,
+    /// so each call walks the table from the start in batches of [`Self::list`].
+    async fn list_missing_on_disk(
+        &self,
+        exists: &(dyn for<'p> Fn(&'p str) -> bool + Send + Sync),
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<TrackFile>> {
+        const SCAN_BATCH_SIZE: i64 = 500;
+
+        let mut to_skip = offset.max(0);
+        let mut missing = Vec::new();
+        let mut scan_offset = 0;
+        while (missing.len() as i64) < limit {
+            let batch = self.list(SCAN_BATCH_SIZE, scan_offset).await?;
+            let batch_len = batch.len() as i64;
+            for file in batch {
+                if exists(&file.path) {
+                    continue;
+                }
+                if to_skip > 0 {
+                    to_skip -= 1;
+                } else if (missing.len() as i64) < limit {
+                    missing.push(file);
+                }
+            }
+            if batch_len < SCAN_BATCH_SIZE {
+                break;
+            }
+            scan_offset += batch_len;
+        }
+        Ok(missing)
+    }
 }
 
 /// Artist relationship repository with specialized queries for artist connections
@@ -286,6 +605,12 @@ pub trait DuplicateRepository: Send + Sync {
         fingerprint_hash: &str,
     ) -> Result<Vec<DuplicateFileDetail>>;
 
+    /// Return the files for each of `fingerprint_hashes` in one batch, keyed by hash.
+    async fn get_files_by_fingerprints(
+        &self,
+        fingerprint_hashes: &[String],
+    ) -> Result<HashMap<String, Vec<DuplicateFileDetail>>>;
+
     /// Return all files that share the given content hash.
     async fn get_files_by_hash(&self, file_hash: &str) -> Result<Vec<DuplicateFileDetail>>;
 
@@ -4,52 +4,76 @@ use chorrosion_domain::{
     Album, AlbumId, AlbumStatus, Artist, ArtistId, ArtistRelationship, ArtistRelationshipId,
     ArtistStatus, DownloadClientDefinition, DownloadClientDefinitionId, DuplicateDetectionMethod,
     DuplicateFileDetail, DuplicateGroup, EntityType, IndexerDefinition, IndexerDefinitionId,
-    MetadataProfile, ProfileId, QualityProfile, SmartPlaylist, SmartPlaylistCriteria,
+    MetadataProfile, ProfileId, QualityProfile, ReleaseDate, SmartPlaylist, SmartPlaylistCriteria,
     SmartPlaylistId, Tag, TagId, TaggedEntity, Track, TrackFile, TrackFileId, TrackId,
 };
 use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
 use sqlx::Row;
 use sqlx::SqlitePool;
+use std::collections::HashMap;
 use tracing::debug;
 use uuid::Uuid;
 
 use crate::profiler::QueryProfiler;
 use crate::repositories::{
-    AlbumRepository, ArtistRelationshipRepository, ArtistRepository,
+    like_prefix_pattern, next_updated_at, release_date_columns, release_date_from_columns,
+    AlbumRepository, ArtistRelationshipRepository, ArtistRepository, ConcurrencyConflict,
     DownloadClientDefinitionRepository, DuplicateRepository, IndexerDefinitionRepository,
-    MetadataProfileRepository, QualityProfileRepository, Repository, SmartPlaylistRepository,
-    TagRepository, TaggedEntityRepository, TrackFileRepository, TrackRepository,
+    MetadataProfileRepository, MonitoredCascade, QualityProfileRepository, Repository,
+    SmartPlaylistRepository, StatusParsing, TagRepository, TaggedEntityRepository,
+    TrackFileRepository, TrackRepository, TrackStats,
 };
+use crate::transaction::run_in_transaction;
+
+/// Most ids bound into one `IN (...)` list, kept well under SQLite's
+/// bound-parameter limit (999 before 3.32, 32766 since) with room for other binds.
+const MAX_IN_LIST_IDS: usize = 500;
 
 /// SQLx-backed Artist repository
 #[allow(dead_code)]
 pub struct SqliteArtistRepository {
     pool: SqlitePool,
     profiler: QueryProfiler,
+    status_parsing: StatusParsing,
 }
 
 impl SqliteArtistRepository {
     pub fn new(pool: SqlitePool) -> Self {
-        let profiler = QueryProfiler::new(pool.clone(), 0);
-        Self { pool, profiler }
+        Self::new_with_threshold(pool, 0)
     }
 
     pub fn new_with_threshold(pool: SqlitePool, threshold_ms: u64) -> Self {
         let profiler = QueryProfiler::new(pool.clone(), threshold_ms);
-        Self { pool, profiler }
+        Self {
+            pool,
+            profiler,
+            status_parsing: StatusParsing::default(),
+        }
+    }
+
+    /// Choose how rows with an unrecognised status are decoded (lenient by default).
+    pub fn with_status_parsing(mut self, status_parsing: StatusParsing) -> Self {
+        self.status_parsing = status_parsing;
+        self
     }
 }
 
 #[async_trait::async_trait]
 impl Repository<Artist> for SqliteArtistRepository {
-    async fn create(&self, entity: Artist) -> Result<Artist> {
+    async fn create(&self, mut entity: Artist) -> Result<Artist> {
         debug!(target: "repository", artist_id = %entity.id, "creating artist");
-        // Insert artist row
+        // Insert artist row; missing profiles fall back to the default ones
         let q = r#"
             INSERT INTO artists (
                 id, name, foreign_artist_id, musicbrainz_artist_id, metadata_profile_id, quality_profile_id,
                 status, path, monitored, artist_type, sort_name, country, disambiguation, genre_tags, style_tags, created_at, updated_at
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            ) VALUES (
+                ?, ?, ?, ?,
+                COALESCE(?, (SELECT id FROM metadata_profiles WHERE is_default = 1)),
+                COALESCE(?, (SELECT id FROM quality_profiles WHERE is_default = 1)),
+                ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?
+            )
+            RETURNING metadata_profile_id, quality_profile_id
         "#;
 
         let id_str = entity.id.to_string();
@@ -63,7 +87,7 @@ impl Repository<Artist> for SqliteArtistRepository {
         let created_at = entity.created_at.to_rfc3339();
         let updated_at = entity.updated_at.to_rfc3339();
 
-        sqlx::query(q)
+        let row = sqlx::query(q)
             .bind(id_str) // 1: id
             .bind(entity.name.clone()) // 2: name
             .bind(foreign_id) // 3: foreign_artist_id
@@ -81,8 +105,10 @@ impl Repository<Artist> for SqliteArtistRepository {
             .bind(entity.style_tags.clone()) // 15: style_tags
             .bind(created_at) // 16: created_at
             .bind(updated_at) // 17: updated_at
-            .execute(&self.pool)
+            .fetch_one(&self.pool)
             .await?;
+        entity.metadata_profile_id = parse_uuid_opt(row.try_get("metadata_profile_id")?)?;
+        entity.quality_profile_id = parse_uuid_opt(row.try_get("quality_profile_id")?)?;
         Ok(entity)
     }
 
@@ -98,7 +124,7 @@ impl Repository<Artist> for SqliteArtistRepository {
             })
             .await?;
         if let Some(r) = row {
-            Ok(Some(row_to_artist(&r)?))
+            Ok(Some(row_to_artist(&r, self.status_parsing)?))
         } else {
             Ok(None)
         }
@@ -118,13 +144,14 @@ impl Repository<Artist> for SqliteArtistRepository {
             .await?;
         let mut out = Vec::with_capacity(rows.len());
         for r in rows {
-            out.push(row_to_artist(&r)?);
+            out.push(row_to_artist(&r, self.status_parsing)?);
         }
         Ok(out)
     }
 
     async fn update(&self, entity: Artist) -> Result<Artist> {
         debug!(target: "repository", artist_id = %entity.id, "updating artist");
+        let updated_at = next_updated_at(entity.updated_at);
         let q = r#"
             UPDATE artists SET
                 name = ?,
@@ -142,9 +169,9 @@ impl Repository<Artist> for SqliteArtistRepository {
                 genre_tags = ?,
                 style_tags = ?,
                 updated_at = ?
-            WHERE id = ?
+            WHERE id = ? AND julianday(updated_at) = julianday(?)
         "#;
-        sqlx::query(q)
+        let result = sqlx::query(q)
             .bind(entity.name.clone())
             .bind(entity.foreign_artist_id.clone())
             .bind(entity.musicbrainz_artist_id.clone())
@@ -159,11 +186,23 @@ impl Repository<Artist> for SqliteArtistRepository {
             .bind(entity.disambiguation.clone())
             .bind(entity.genre_tags.clone())
             .bind(entity.style_tags.clone())
-            .bind(entity.updated_at.to_rfc3339())
+            .bind(updated_at.to_rfc3339())
             .bind(entity.id.to_string())
+            .bind(entity.updated_at.to_rfc3339())
             .execute(&self.pool)
             .await?;
-        Ok(entity)
+        ensure_updated(
+            &self.pool,
+            "artists",
+            "artist",
+            &entity.id.to_string(),
+            result.rows_affected(),
+        )
+        .await?;
+        Ok(Artist {
+            updated_at,
+            ..entity
+        })
     }
 
     async fn delete(&self, id: &str) -> Result<()> {
@@ -192,7 +231,9 @@ impl ArtistRepository for SqliteArtistRepository {
                     .await
             })
             .await?;
-        Ok(row.map(|r| row_to_artist(&r)).transpose()?)
+        Ok(row
+            .map(|r| row_to_artist(&r, self.status_parsing))
+            .transpose()?)
     }
 
     async fn get_by_foreign_id(&self, foreign_id: &str) -> Result<Option<Artist>> {
@@ -206,7 +247,9 @@ impl ArtistRepository for SqliteArtistRepository {
                     .await
             })
             .await?;
-        Ok(row.map(|r| row_to_artist(&r)).transpose()?)
+        Ok(row
+            .map(|r| row_to_artist(&r, self.status_parsing))
+            .transpose()?)
     }
 
     async fn list_monitored(&self, limit: i64, offset: i64) -> Result<Vec<Artist>> {
@@ -225,7 +268,7 @@ impl ArtistRepository for SqliteArtistRepository {
             .await?;
         let mut out = Vec::with_capacity(rows.len());
         for r in rows {
-            out.push(row_to_artist(&r)?);
+            out.push(row_to_artist(&r, self.status_parsing)?);
         }
         Ok(out)
     }
@@ -251,10 +294,306 @@ impl ArtistRepository for SqliteArtistRepository {
             .await?;
         let mut out = Vec::with_capacity(rows.len());
         for r in rows {
-            out.push(row_to_artist(&r)?);
+            out.push(row_to_artist(&r, self.status_parsing)?);
+        }
+        Ok(out)
+    }
+
+    async fn list_recently_added(&self, limit: i64, offset: i64) -> Result<Vec<Artist>> {
+        debug!(target: "repository", limit, offset, "listing recently added artists");
+        let rows = self
+            .profiler
+            .timed("artists::list_recently_added", || async {
+                sqlx::query("SELECT * FROM artists ORDER BY created_at DESC, id LIMIT ? OFFSET ?")
+                    .bind(limit)
+                    .bind(offset)
+                    .fetch_all(&self.pool)
+                    .await
+            })
+            .await?;
+        let mut out = Vec::with_capacity(rows.len());
+        for r in rows {
+            out.push(row_to_artist(&r, self.status_parsing)?);
+        }
+        Ok(out)
+    }
+
+    async fn autocomplete(&self, prefix: &str, limit: i64) -> Result<Vec<Artist>> {
+        debug!(target: "repository", prefix, limit, "autocompleting artists");
+        if prefix.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+        let pattern = like_prefix_pattern(prefix);
+        let rows = self
+            .profiler
+            .timed("artists::autocomplete", || async {
+                sqlx::query(
+                    "SELECT * FROM artists \
+                     WHERE name LIKE ?1 ESCAPE '\\' OR sort_name LIKE ?1 ESCAPE '\\' \
+                     ORDER BY name COLLATE NOCASE, id LIMIT ?2",
+                )
+                .bind(&pattern)
+                .bind(limit)
+                .fetch_all(&self.pool)
+                .await
+            })
+            .await?;
+        let mut out = Vec::with_capacity(rows.len());
+        for r in rows {
+            out.push(row_to_artist(&r, self.status_parsing)?);
+        }
+        Ok(out)
+    }
+
+    async fn set_monitored(&self, ids: &[ArtistId], monitored: bool) -> Result<u64> {
+        debug!(target: "repository", count = ids.len(), monitored, "setting artists monitored");
+        let ids = ids.iter().map(ToString::to_string).collect();
+        set_monitored_in(&self.pool, "artists", ids, monitored).await
+    }
+
+    async fn set_monitored_cascade(
+        &self,
+        ids: &[ArtistId],
+        monitored: bool,
+    ) -> Result<MonitoredCascade> {
+        debug!(target: "repository", count = ids.len(), monitored, "setting artists monitored with albums and tracks");
+        if ids.is_empty() {
+            return Ok(MonitoredCascade::default());
+        }
+        let ids: Vec<String> = ids.iter().map(ToString::to_string).collect();
+        let now = Utc::now().to_rfc3339();
+        run_in_transaction(&self.pool, |tx| {
+            Box::pin(async move {
+                let mut cascade = MonitoredCascade::default();
+                for (table, column, count) in [
+                    ("artists", "id", &mut cascade.artists),
+                    ("albums", "artist_id", &mut cascade.albums),
+                    ("tracks", "artist_id", &mut cascade.tracks),
+                ] {
+                    for chunk in ids.chunks(MAX_IN_LIST_IDS) {
+                        let placeholders = vec!["?"; chunk.len()].join(", ");
+                        let sql = format!(
+                            "UPDATE {table} SET monitored = ?, updated_at = ? WHERE {column} IN ({placeholders})"
+                        );
+                        let mut query = sqlx::query(&sql).bind(monitored).bind(&now);
+                        for id in chunk {
+                            query = query.bind(id);
+                        }
+                        *count += query.execute(&mut **tx).await?.rows_affected();
+                    }
+                }
+                Ok(cascade)
+            })
+        })
+        .await
+    }
+
+    async fn record_metadata_refresh(&self, id: ArtistId, at: DateTime<Utc>) -> Result<()> {
+        debug!(target: "repository", artist_id = %id, %at, "recording artist metadata refresh");
+        record_metadata_refresh_in(&self.pool, "artists", id.to_string(), at).await
+    }
+
+    async fn list_updated_since(
+        &self,
+        since: DateTime<Utc>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Artist>> {
+        debug!(target: "repository", %since, limit, offset, "listing artists updated since");
+        // Timestamps are stored as UTC RFC 3339 strings, which sort chronologically.
+        let since = since.to_rfc3339();
+        let rows = self
+            .profiler
+            .timed("artists::list_updated_since", || async {
+                sqlx::query(
+                    "SELECT * FROM artists WHERE updated_at >= ? ORDER BY updated_at, id LIMIT ? OFFSET ?",
+                )
+                .bind(&since)
+                .bind(limit)
+                .bind(offset)
+                .fetch_all(&self.pool)
+                .await
+            })
+            .await?;
+        let mut out = Vec::with_capacity(rows.len());
+        for r in rows {
+            out.push(row_to_artist(&r, self.status_parsing)?);
         }
         Ok(out)
     }
+
+    async fn merge_artists(&self, keep_id: ArtistId, remove_id: ArtistId) -> Result<Artist> {
+        debug!(target: "repository", %keep_id, %remove_id, "merging artists");
+        if keep_id == remove_id {
+            return Err(anyhow!("cannot merge artist {} into itself", keep_id));
+        }
+
+        let keep = keep_id.to_string();
+        let remove = remove_id.to_string();
+        let now = Utc::now().to_rfc3339();
+        run_in_transaction(&self.pool, |tx| {
+            Box::pin(async move {
+                let removed = sqlx::query(
+                    "SELECT foreign_artist_id, musicbrainz_artist_id FROM artists WHERE id = ?",
+                )
+                .bind(&remove)
+                .fetch_optional(&mut **tx)
+                .await?
+                .ok_or_else(|| anyhow!("artist not found: {}", remove))?;
+                let foreign_artist_id: Option<String> = removed.try_get("foreign_artist_id")?;
+                let musicbrainz_artist_id: Option<String> =
+                    removed.try_get("musicbrainz_artist_id")?;
+
+                // Release the removed artist's identifiers first so the kept artist can adopt
+                // them without tripping the unique MusicBrainz ID index.
+                sqlx::query(
+                    "UPDATE artists SET foreign_artist_id = NULL, musicbrainz_artist_id = NULL WHERE id = ?",
+                )
+                .bind(&remove)
+                .execute(&mut **tx)
+                .await?;
+                let kept = sqlx::query(
+                    "UPDATE artists SET foreign_artist_id = COALESCE(foreign_artist_id, ?), \
+                     musicbrainz_artist_id = COALESCE(musicbrainz_artist_id, ?), updated_at = ? \
+                     WHERE id = ?",
+                )
+                .bind(foreign_artist_id)
+                .bind(musicbrainz_artist_id)
+                .bind(&now)
+                .bind(&keep)
+                .execute(&mut **tx)
+                .await?;
+                if kept.rows_affected() == 0 {
+                    return Err(anyhow!("artist not found: {}", keep));
+                }
+
+                for table in ["albums", "tracks"] {
+                    sqlx::query(&format!(
+                        "UPDATE {table} SET artist_id = ?, updated_at = ? WHERE artist_id = ?"
+                    ))
+                    .bind(&keep)
+                    .bind(&now)
+                    .bind(&remove)
+                    .execute(&mut **tx)
+                    .await?;
+                }
+
+                // Relationships between the two artists would become self-relationships,
+                // and ones the kept artist already has would break the unique index.
+                sqlx::query(
+                    "DELETE FROM artist_relationships \
+                     WHERE (source_artist_id = ? AND related_artist_id = ?) \
+                     OR (source_artist_id = ? AND related_artist_id = ?)",
+                )
+                .bind(&keep)
+                .bind(&remove)
+                .bind(&remove)
+                .bind(&keep)
+                .execute(&mut **tx)
+                .await?;
+                for (column, other) in [
+                    ("source_artist_id", "related_artist_id"),
+                    ("related_artist_id", "source_artist_id"),
+                ] {
+                    sqlx::query(&format!(
+                        "UPDATE artist_relationships SET {column} = ?, updated_at = ? \
+                         WHERE {column} = ? AND NOT EXISTS (\
+                         SELECT 1 FROM artist_relationships existing \
+                         WHERE existing.{column} = ? \
+                         AND existing.{other} = artist_relationships.{other} \
+                         AND existing.relationship_type = artist_relationships.relationship_type)"
+                    ))
+                    .bind(&keep)
+                    .bind(&now)
+                    .bind(&remove)
+                    .bind(&keep)
+                    .execute(&mut **tx)
+                    .await?;
+                }
+
+                sqlx::query(
+                    "INSERT OR IGNORE INTO tagged_entities (tag_id, entity_id, entity_type, created_at) \
+                     SELECT tag_id, ?, entity_type, created_at FROM tagged_entities \
+                     WHERE entity_id = ? AND entity_type = 'artist'",
+                )
+                .bind(&keep)
+                .bind(&remove)
+                .execute(&mut **tx)
+                .await?;
+                sqlx::query(
+                    "DELETE FROM tagged_entities WHERE entity_id = ? AND entity_type = 'artist'",
+                )
+                .bind(&remove)
+                .execute(&mut **tx)
+                .await?;
+
+                sqlx::query("DELETE FROM artists WHERE id = ?")
+                    .bind(&remove)
+                    .execute(&mut **tx)
+                    .await?;
+                Ok(())
+            })
+        })
+        .await?;
+
+        self.get_by_id(&keep_id.to_string())
+            .await?
+            .ok_or_else(|| anyhow!("artist not found: {}", keep_id))
+    }
+
+    async fn upsert_by_foreign_id(&self, entity: Artist) -> Result<Artist> {
+        debug!(target: "repository", artist_id = %entity.id, foreign_artist_id = ?entity.foreign_artist_id, "upserting artist by foreign id");
+        let q = r#"
+            INSERT INTO artists (
+                id, name, foreign_artist_id, musicbrainz_artist_id, metadata_profile_id, quality_profile_id,
+                status, path, monitored, artist_type, sort_name, country, disambiguation, genre_tags, style_tags, created_at, updated_at
+            ) VALUES (
+                ?, ?, ?, ?,
+                COALESCE(?, (SELECT id FROM metadata_profiles WHERE is_default = 1)),
+                COALESCE(?, (SELECT id FROM quality_profiles WHERE is_default = 1)),
+                ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?
+            )
+            ON CONFLICT(foreign_artist_id) WHERE foreign_artist_id IS NOT NULL DO UPDATE SET
+                name = excluded.name,
+                musicbrainz_artist_id = excluded.musicbrainz_artist_id,
+                status = excluded.status,
+                artist_type = excluded.artist_type,
+                sort_name = excluded.sort_name,
+                country = excluded.country,
+                disambiguation = excluded.disambiguation,
+                genre_tags = excluded.genre_tags,
+                style_tags = excluded.style_tags,
+                updated_at = excluded.updated_at
+            RETURNING *
+        "#;
+
+        let row = self
+            .profiler
+            .timed("artists::upsert_by_foreign_id", || async {
+                sqlx::query(q)
+                    .bind(entity.id.to_string())
+                    .bind(&entity.name)
+                    .bind(&entity.foreign_artist_id)
+                    .bind(&entity.musicbrainz_artist_id)
+                    .bind(entity.metadata_profile_id.map(|p| p.to_string()))
+                    .bind(entity.quality_profile_id.map(|p| p.to_string()))
+                    .bind(entity.status.to_string())
+                    .bind(&entity.path)
+                    .bind(entity.monitored)
+                    .bind(&entity.artist_type)
+                    .bind(&entity.sort_name)
+                    .bind(&entity.country)
+                    .bind(&entity.disambiguation)
+                    .bind(&entity.genre_tags)
+                    .bind(&entity.style_tags)
+                    .bind(entity.created_at.to_rfc3339())
+                    .bind(entity.updated_at.to_rfc3339())
+                    .fetch_one(&self.pool)
+                    .await
+            })
+            .await?;
+        row_to_artist(&row, self.status_parsing)
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -271,23 +610,6 @@ fn parse_uuid_opt(s: Option<String>) -> Result<Option<chorrosion_domain::Profile
     }
 }
 
-fn parse_artist_status(s: &str) -> Result<ArtistStatus> {
-    match s {
-        "continuing" => Ok(ArtistStatus::Continuing),
-        "ended" => Ok(ArtistStatus::Ended),
-        other => Err(anyhow!("unknown artist status: {}", other)),
-    }
-}
-
-fn parse_album_status(s: &str) -> Result<AlbumStatus> {
-    match s {
-        "wanted" => Ok(AlbumStatus::Wanted),
-        "released" => Ok(AlbumStatus::Released),
-        "announced" => Ok(AlbumStatus::Announced),
-        other => Err(anyhow!("unknown album status: {}", other)),
-    }
-}
-
 fn entity_type_as_str(entity_type: EntityType) -> &'static str {
     match entity_type {
         EntityType::Artist => "artist",
@@ -303,6 +625,113 @@ fn parse_entity_type(s: &str) -> Result<EntityType> {
     }
 }
 
+/// Update `monitored` and `updated_at` for each id in `table` within one transaction.
+async fn set_monitored_in(
+    pool: &SqlitePool,
+    table: &'static str,
+    ids: Vec<String>,
+    monitored: bool,
+) -> Result<u64> {
+    if ids.is_empty() {
+        return Ok(0);
+    }
+    let now = Utc::now().to_rfc3339();
+    run_in_transaction(pool, |tx| {
+        Box::pin(async move {
+            let mut updated = 0;
+            for chunk in ids.chunks(MAX_IN_LIST_IDS) {
+                let placeholders = vec!["?"; chunk.len()].join(", ");
+                let sql = format!(
+                    "UPDATE {table} SET monitored = ?, updated_at = ? WHERE id IN ({placeholders})"
+                );
+                let mut query = sqlx::query(&sql).bind(monitored).bind(&now);
+                for id in chunk {
+                    query = query.bind(id);
+                }
+                updated += query.execute(&mut **tx).await?.rows_affected();
+            }
+            Ok(updated)
+        })
+    })
+    .await
+}
+
+/// Resolve an optimistic `update` of `id` in `table` that touched `rows_affected` rows.
+///
+/// Zero rows means the row is either gone or its `updated_at` no longer matches the
+/// entity's, i.e. someone else wrote it after the entity was read.
+async fn ensure_updated(
+    pool: &SqlitePool,
+    table: &'static str,
+    label: &'static str,
+    id: &str,
+    rows_affected: u64,
+) -> Result<()> {
+    if rows_affected > 0 {
+        return Ok(());
+    }
+    let exists = sqlx::query(&format!("SELECT 1 FROM {table} WHERE id = ?"))
+        .bind(id)
+        .fetch_optional(pool)
+        .await?
+        .is_some();
+    if exists {
+        Err(ConcurrencyConflict::new(label, id).into())
+    } else {
+        Err(anyhow!("{label} not found: {id}"))
+    }
+}
+
+/// Set `last_metadata_refresh_at` for `id` in `table`, leaving `updated_at` alone.
+async fn record_metadata_refresh_in(
+    pool: &SqlitePool,
+    table: &'static str,
+    id: String,
+    at: DateTime<Utc>,
+) -> Result<()> {
+    let sql = format!("UPDATE {table} SET last_metadata_refresh_at = ? WHERE id = ?");
+    sqlx::query(&sql)
+        .bind(at.to_rfc3339())
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+/// Make `id` the only default row of the profile `table` in one transaction.
+async fn set_default_in(
+    pool: &SqlitePool,
+    table: &'static str,
+    label: &'static str,
+    id: String,
+) -> Result<()> {
+    let now = Utc::now().to_rfc3339();
+    run_in_transaction(pool, |tx| {
+        Box::pin(async move {
+            sqlx::query(&format!(
+                "UPDATE {table} SET is_default = 0, updated_at = ? WHERE is_default = 1 AND id <> ?"
+            ))
+            .bind(&now)
+            .bind(&id)
+            .execute(&mut **tx)
+            .await?;
+            let updated = sqlx::query(&format!(
+                "UPDATE {table} SET is_default = 1, updated_at = ? WHERE id = ?"
+            ))
+            .bind(&now)
+            .bind(&id)
+            .execute(&mut **tx)
+            .await?
+            .rows_affected();
+            if updated == 0 {
+                return Err(anyhow!("{label} not found: {id}"));
+            }
+            Ok(())
+        })
+    })
+    .await
+}
+
 fn parse_dt(s: String) -> Result<DateTime<Utc>> {
     // Try RFC3339 first
     if let Ok(dt) = DateTime::parse_from_rfc3339(&s) {
@@ -313,7 +742,14 @@ fn parse_dt(s: String) -> Result<DateTime<Utc>> {
     Ok(DateTime::<Utc>::from_naive_utc_and_offset(ndt, Utc))
 }
 
-fn row_to_artist(row: &sqlx::sqlite::SqliteRow) -> Result<Artist> {
+fn read_release_date(row: &sqlx::sqlite::SqliteRow) -> Result<Option<ReleaseDate>> {
+    Ok(release_date_from_columns(
+        row.try_get("release_date")?,
+        row.try_get("release_date_precision")?,
+    ))
+}
+
+fn row_to_artist(row: &sqlx::sqlite::SqliteRow, status_parsing: StatusParsing) -> Result<Artist> {
     let id_str: String = row.try_get("id")?;
     let id = ArtistId::from_uuid(Uuid::parse_str(&id_str)?);
 
@@ -331,6 +767,7 @@ fn row_to_artist(row: &sqlx::sqlite::SqliteRow) -> Result<Artist> {
     let disambiguation: Option<String> = row.try_get("disambiguation")?;
     let genre_tags: Option<String> = row.try_get("genre_tags")?;
     let style_tags: Option<String> = row.try_get("style_tags")?;
+    let last_metadata_refresh_at: Option<String> = row.try_get("last_metadata_refresh_at")?;
     let created_at_s: String = row.try_get("created_at")?;
     let updated_at_s: String = row.try_get("updated_at")?;
 
@@ -341,7 +778,7 @@ fn row_to_artist(row: &sqlx::sqlite::SqliteRow) -> Result<Artist> {
         musicbrainz_artist_id,
         metadata_profile_id: parse_uuid_opt(metadata_profile_id)?,
         quality_profile_id: parse_uuid_opt(quality_profile_id)?,
-        status: parse_artist_status(&status_str)?,
+        status: status_parsing.parse_artist_status(&status_str)?,
         path,
         monitored,
         artist_type,
@@ -350,12 +787,13 @@ fn row_to_artist(row: &sqlx::sqlite::SqliteRow) -> Result<Artist> {
         disambiguation,
         genre_tags,
         style_tags,
+        last_metadata_refresh_at: last_metadata_refresh_at.map(parse_dt).transpose()?,
         created_at: parse_dt(created_at_s)?,
         updated_at: parse_dt(updated_at_s)?,
     })
 }
 
-fn row_to_album(row: &sqlx::sqlite::SqliteRow) -> Result<Album> {
+fn row_to_album(row: &sqlx::sqlite::SqliteRow, status_parsing: StatusParsing) -> Result<Album> {
     let id_str: String = row.try_get("id")?;
     let id = AlbumId::from_uuid(Uuid::parse_str(&id_str)?);
 
@@ -367,7 +805,7 @@ fn row_to_album(row: &sqlx::sqlite::SqliteRow) -> Result<Album> {
         row.try_get("musicbrainz_release_group_id")?;
     let musicbrainz_release_id: Option<String> = row.try_get("musicbrainz_release_id")?;
     let title: String = row.try_get("title")?;
-    let release_date: Option<String> = row.try_get("release_date")?;
+    let release_date = read_release_date(row)?;
     let album_type: Option<String> = row.try_get("album_type")?;
     let primary_type: Option<String> = row.try_get("primary_type")?;
     let secondary_types: Option<String> = row.try_get("secondary_types")?;
@@ -376,6 +814,7 @@ fn row_to_album(row: &sqlx::sqlite::SqliteRow) -> Result<Album> {
     let style_tags: Option<String> = row.try_get("style_tags")?;
     let status_str: String = row.try_get("status")?;
     let monitored: bool = row.try_get("monitored")?;
+    let last_metadata_refresh_at: Option<String> = row.try_get("last_metadata_refresh_at")?;
     let created_at_s: String = row.try_get("created_at")?;
     let updated_at_s: String = row.try_get("updated_at")?;
 
@@ -386,16 +825,16 @@ fn row_to_album(row: &sqlx::sqlite::SqliteRow) -> Result<Album> {
         musicbrainz_release_group_id,
         musicbrainz_release_id,
         title,
-        release_date: release_date
-            .and_then(|d| chrono::NaiveDate::parse_from_str(&d, "%Y-%m-%d").ok()),
+        release_date,
         album_type,
         primary_type,
         secondary_types,
         first_release_date,
         genre_tags,
         style_tags,
-        status: parse_album_status(&status_str)?,
+        status: status_parsing.parse_album_status(&status_str)?,
         monitored,
+        last_metadata_refresh_at: last_metadata_refresh_at.map(parse_dt).transpose()?,
         created_at: parse_dt(created_at_s)?,
         updated_at: parse_dt(updated_at_s)?,
     })
@@ -472,17 +911,27 @@ fn row_to_artist_relationship(row: &sqlx::sqlite::SqliteRow) -> Result<ArtistRel
 pub struct SqliteAlbumRepository {
     pool: SqlitePool,
     profiler: QueryProfiler,
+    status_parsing: StatusParsing,
 }
 
 impl SqliteAlbumRepository {
     pub fn new(pool: SqlitePool) -> Self {
-        let profiler = QueryProfiler::new(pool.clone(), 0);
-        Self { pool, profiler }
+        Self::new_with_threshold(pool, 0)
     }
 
     pub fn new_with_threshold(pool: SqlitePool, threshold_ms: u64) -> Self {
         let profiler = QueryProfiler::new(pool.clone(), threshold_ms);
-        Self { pool, profiler }
+        Self {
+            pool,
+            profiler,
+            status_parsing: StatusParsing::default(),
+        }
+    }
+
+    /// Choose how rows with an unrecognised status are decoded (lenient by default).
+    pub fn with_status_parsing(mut self, status_parsing: StatusParsing) -> Self {
+        self.status_parsing = status_parsing;
+        self
     }
 }
 
@@ -494,17 +943,15 @@ impl Repository<Album> for SqliteAlbumRepository {
             INSERT INTO albums (
                 id, artist_id, foreign_album_id, musicbrainz_release_group_id, musicbrainz_release_id,
                 title, release_date, album_type, primary_type, secondary_types, first_release_date,
-                genre_tags, style_tags, status, monitored, created_at, updated_at
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                genre_tags, style_tags, status, monitored, created_at, updated_at, release_date_precision
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
         "#;
 
         let id_str = entity.id.to_string();
         let artist_id_str = entity.artist_id.to_string();
         let foreign_id = entity.foreign_album_id.clone();
         let title = entity.title.clone();
-        let release_date = entity
-            .release_date
-            .map(|d| d.format("%Y-%m-%d").to_string());
+        let (release_date, release_date_precision) = release_date_columns(entity.release_date);
         let album_type = entity.album_type.clone();
         let status = entity.status.to_string();
         let monitored = entity.monitored;
@@ -529,6 +976,7 @@ impl Repository<Album> for SqliteAlbumRepository {
             .bind(monitored)
             .bind(created_at)
             .bind(updated_at)
+            .bind(release_date_precision)
             .execute(&self.pool)
             .await?;
         Ok(entity)
@@ -546,7 +994,7 @@ impl Repository<Album> for SqliteAlbumRepository {
             })
             .await?;
         if let Some(r) = row {
-            Ok(Some(row_to_album(&r)?))
+            Ok(Some(row_to_album(&r, self.status_parsing)?))
         } else {
             Ok(None)
         }
@@ -566,13 +1014,14 @@ impl Repository<Album> for SqliteAlbumRepository {
             .await?;
         let mut out = Vec::with_capacity(rows.len());
         for r in rows {
-            out.push(row_to_album(&r)?);
+            out.push(row_to_album(&r, self.status_parsing)?);
         }
         Ok(out)
     }
 
     async fn update(&self, entity: Album) -> Result<Album> {
         debug!(target: "repository", album_id = %entity.id, "updating album");
+        let updated_at = next_updated_at(entity.updated_at);
         let q = r#"
             UPDATE albums SET
                 artist_id = ?,
@@ -581,6 +1030,7 @@ impl Repository<Album> for SqliteAlbumRepository {
                 musicbrainz_release_id = ?,
                 title = ?,
                 release_date = ?,
+                release_date_precision = ?,
                 album_type = ?,
                 primary_type = ?,
                 secondary_types = ?,
@@ -590,19 +1040,17 @@ impl Repository<Album> for SqliteAlbumRepository {
                 status = ?,
                 monitored = ?,
                 updated_at = ?
-            WHERE id = ?
+            WHERE id = ? AND julianday(updated_at) = julianday(?)
         "#;
-        sqlx::query(q)
+        let (release_date, release_date_precision) = release_date_columns(entity.release_date);
+        let result = sqlx::query(q)
             .bind(entity.artist_id.to_string())
             .bind(entity.foreign_album_id.clone())
             .bind(entity.musicbrainz_release_group_id.clone())
             .bind(entity.musicbrainz_release_id.clone())
             .bind(entity.title.clone())
-            .bind(
-                entity
-                    .release_date
-                    .map(|d| d.format("%Y-%m-%d").to_string()),
-            )
+            .bind(release_date)
+            .bind(release_date_precision)
             .bind(entity.album_type.clone())
             .bind(entity.primary_type.clone())
             .bind(entity.secondary_types.clone())
@@ -611,11 +1059,23 @@ impl Repository<Album> for SqliteAlbumRepository {
             .bind(entity.style_tags.clone())
             .bind(entity.status.to_string())
             .bind(entity.monitored)
-            .bind(entity.updated_at.to_rfc3339())
+            .bind(updated_at.to_rfc3339())
             .bind(entity.id.to_string())
+            .bind(entity.updated_at.to_rfc3339())
             .execute(&self.pool)
             .await?;
-        Ok(entity)
+        ensure_updated(
+            &self.pool,
+            "albums",
+            "album",
+            &entity.id.to_string(),
+            result.rows_affected(),
+        )
+        .await?;
+        Ok(Album {
+            updated_at,
+            ..entity
+        })
     }
 
     async fn delete(&self, id: &str) -> Result<()> {
@@ -656,7 +1116,7 @@ impl AlbumRepository for SqliteAlbumRepository {
             .await?;
         let mut out = Vec::with_capacity(rows.len());
         for r in rows {
-            out.push(row_to_album(&r)?);
+            out.push(row_to_album(&r, self.status_parsing)?);
         }
         Ok(out)
     }
@@ -672,7 +1132,9 @@ impl AlbumRepository for SqliteAlbumRepository {
                     .await
             })
             .await?;
-        Ok(row.map(|r| row_to_album(&r)).transpose()?)
+        Ok(row
+            .map(|r| row_to_album(&r, self.status_parsing))
+            .transpose()?)
     }
 
     async fn get_by_artist_and_title(
@@ -694,7 +1156,9 @@ impl AlbumRepository for SqliteAlbumRepository {
                 .await
             })
             .await?;
-        Ok(row.map(|r| row_to_album(&r)).transpose()?)
+        Ok(row
+            .map(|r| row_to_album(&r, self.status_parsing))
+            .transpose()?)
     }
 
     async fn get_by_status(
@@ -718,7 +1182,7 @@ impl AlbumRepository for SqliteAlbumRepository {
             .await?;
         let mut out = Vec::with_capacity(rows.len());
         for r in rows {
-            out.push(row_to_album(&r)?);
+            out.push(row_to_album(&r, self.status_parsing)?);
         }
         Ok(out)
     }
@@ -739,7 +1203,7 @@ impl AlbumRepository for SqliteAlbumRepository {
             .await?;
         let mut out = Vec::with_capacity(rows.len());
         for r in rows {
-            out.push(row_to_album(&r)?);
+            out.push(row_to_album(&r, self.status_parsing)?);
         }
         Ok(out)
     }
@@ -766,7 +1230,7 @@ impl AlbumRepository for SqliteAlbumRepository {
             .await?;
         let mut out = Vec::with_capacity(rows.len());
         for r in rows {
-            out.push(row_to_album(&r)?);
+            out.push(row_to_album(&r, self.status_parsing)?);
         }
         Ok(out)
     }
@@ -791,7 +1255,7 @@ impl AlbumRepository for SqliteAlbumRepository {
             .await?;
         let mut out = Vec::with_capacity(rows.len());
         for r in rows {
-            out.push(row_to_album(&r)?);
+            out.push(row_to_album(&r, self.status_parsing)?);
         }
         Ok(out)
     }
@@ -842,7 +1306,7 @@ impl AlbumRepository for SqliteAlbumRepository {
             .await?;
         let mut out = Vec::with_capacity(rows.len());
         for r in rows {
-            out.push(row_to_album(&r)?);
+            out.push(row_to_album(&r, self.status_parsing)?);
         }
         Ok(out)
     }
@@ -883,7 +1347,173 @@ impl AlbumRepository for SqliteAlbumRepository {
             .await?;
         let mut out = Vec::with_capacity(rows.len());
         for r in rows {
-            out.push(row_to_album(&r)?);
+            out.push(row_to_album(&r, self.status_parsing)?);
+        }
+        Ok(out)
+    }
+
+    async fn list_recently_added(&self, limit: i64, offset: i64) -> Result<Vec<Album>> {
+        debug!(target: "repository", limit, offset, "listing recently added albums");
+        let rows = self
+            .profiler
+            .timed("albums::list_recently_added", || async {
+                sqlx::query("SELECT * FROM albums ORDER BY created_at DESC, id LIMIT ? OFFSET ?")
+                    .bind(limit)
+                    .bind(offset)
+                    .fetch_all(&self.pool)
+                    .await
+            })
+            .await?;
+        let mut out = Vec::with_capacity(rows.len());
+        for r in rows {
+            out.push(row_to_album(&r, self.status_parsing)?);
+        }
+        Ok(out)
+    }
+
+    async fn set_monitored(&self, ids: &[AlbumId], monitored: bool) -> Result<u64> {
+        debug!(target: "repository", count = ids.len(), monitored, "setting albums monitored");
+        let ids = ids.iter().map(ToString::to_string).collect();
+        set_monitored_in(&self.pool, "albums", ids, monitored).await
+    }
+
+    async fn record_metadata_refresh(&self, id: AlbumId, at: DateTime<Utc>) -> Result<()> {
+        debug!(target: "repository", album_id = %id, %at, "recording album metadata refresh");
+        record_metadata_refresh_in(&self.pool, "albums", id.to_string(), at).await
+    }
+
+    async fn list_updated_since(
+        &self,
+        since: DateTime<Utc>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Album>> {
+        debug!(target: "repository", %since, limit, offset, "listing albums updated since");
+        // Timestamps are stored as UTC RFC 3339 strings, which sort chronologically.
+        let since = since.to_rfc3339();
+        let rows = self
+            .profiler
+            .timed("albums::list_updated_since", || async {
+                sqlx::query(
+                    "SELECT * FROM albums WHERE updated_at >= ? ORDER BY updated_at, id LIMIT ? OFFSET ?",
+                )
+                .bind(&since)
+                .bind(limit)
+                .bind(offset)
+                .fetch_all(&self.pool)
+                .await
+            })
+            .await?;
+        let mut out = Vec::with_capacity(rows.len());
+        for r in rows {
+            out.push(row_to_album(&r, self.status_parsing)?);
+        }
+        Ok(out)
+    }
+
+    async fn upsert_by_foreign_id(&self, entity: Album) -> Result<Album> {
+        debug!(target: "repository", album_id = %entity.id, foreign_album_id = ?entity.foreign_album_id, "upserting album by foreign id");
+        let q = r#"
+            INSERT INTO albums (
+                id, artist_id, foreign_album_id, musicbrainz_release_group_id, musicbrainz_release_id,
+                title, release_date, album_type, primary_type, secondary_types, first_release_date,
+                genre_tags, style_tags, status, monitored, created_at, updated_at, release_date_precision
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            ON CONFLICT(foreign_album_id) WHERE foreign_album_id IS NOT NULL DO UPDATE SET
+                musicbrainz_release_group_id = excluded.musicbrainz_release_group_id,
+                musicbrainz_release_id = excluded.musicbrainz_release_id,
+                title = excluded.title,
+                release_date = excluded.release_date,
+                release_date_precision = excluded.release_date_precision,
+                album_type = excluded.album_type,
+                primary_type = excluded.primary_type,
+                secondary_types = excluded.secondary_types,
+                first_release_date = excluded.first_release_date,
+                genre_tags = excluded.genre_tags,
+                style_tags = excluded.style_tags,
+                updated_at = excluded.updated_at
+            RETURNING *
+        "#;
+
+        let (release_date, release_date_precision) = release_date_columns(entity.release_date);
+        let row = self
+            .profiler
+            .timed("albums::upsert_by_foreign_id", || async {
+                sqlx::query(q)
+                    .bind(entity.id.to_string())
+                    .bind(entity.artist_id.to_string())
+                    .bind(&entity.foreign_album_id)
+                    .bind(&entity.musicbrainz_release_group_id)
+                    .bind(&entity.musicbrainz_release_id)
+                    .bind(&entity.title)
+                    .bind(&release_date)
+                    .bind(&entity.album_type)
+                    .bind(&entity.primary_type)
+                    .bind(&entity.secondary_types)
+                    .bind(&entity.first_release_date)
+                    .bind(&entity.genre_tags)
+                    .bind(&entity.style_tags)
+                    .bind(entity.status.to_string())
+                    .bind(entity.monitored)
+                    .bind(entity.created_at.to_rfc3339())
+                    .bind(entity.updated_at.to_rfc3339())
+                    .bind(&release_date_precision)
+                    .fetch_one(&self.pool)
+                    .await
+            })
+            .await?;
+        row_to_album(&row, self.status_parsing)
+    }
+
+    async fn track_stats(&self, album_id: AlbumId) -> Result<TrackStats> {
+        let mut stats = self.track_stats_for(&[album_id]).await?;
+        Ok(stats.remove(&album_id).unwrap_or_default())
+    }
+
+    async fn track_stats_for(&self, album_ids: &[AlbumId]) -> Result<HashMap<AlbumId, TrackStats>> {
+        debug!(target: "repository", count = album_ids.len(), "fetching album track stats");
+        let mut out: HashMap<AlbumId, TrackStats> = album_ids
+            .iter()
+            .map(|id| (*id, TrackStats::default()))
+            .collect();
+        if album_ids.is_empty() {
+            return Ok(out);
+        }
+
+        let mut rows = Vec::new();
+        for chunk in album_ids.chunks(MAX_IN_LIST_IDS) {
+            let placeholders = vec!["?"; chunk.len()].join(", ");
+            let sql = format!(
+                "SELECT album_id, COUNT(*) AS total, \
+                        COALESCE(SUM(has_file), 0) AS with_file, \
+                        COALESCE(SUM(monitored), 0) AS monitored, \
+                        COALESCE(SUM(monitored AND NOT has_file), 0) AS monitored_missing \
+                 FROM tracks WHERE album_id IN ({placeholders}) GROUP BY album_id"
+            );
+            rows.extend(
+                self.profiler
+                    .timed("albums::track_stats", || async {
+                        let mut query = sqlx::query(&sql);
+                        for id in chunk {
+                            query = query.bind(id.to_string());
+                        }
+                        query.fetch_all(&self.pool).await
+                    })
+                    .await?,
+            );
+        }
+        for row in rows {
+            let album_id: String = row.get("album_id");
+            let album_id = AlbumId::from_uuid(Uuid::parse_str(&album_id)?);
+            out.insert(
+                album_id,
+                TrackStats::from_counts(
+                    row.get("total"),
+                    row.get("with_file"),
+                    row.get("monitored"),
+                    row.get("monitored_missing"),
+                ),
+            );
         }
         Ok(out)
     }
@@ -989,6 +1619,7 @@ impl Repository<Track> for SqliteTrackRepository {
 
     async fn update(&self, entity: Track) -> Result<Track> {
         debug!(target: "repository", track_id = %entity.id, "updating track");
+        let updated_at = next_updated_at(entity.updated_at);
         let q = r#"
             UPDATE tracks SET
                 album_id = ?,
@@ -999,10 +1630,12 @@ impl Repository<Track> for SqliteTrackRepository {
                 duration_ms = ?,
                 has_file = ?,
                 monitored = ?,
+                musicbrainz_recording_id = ?,
+                match_confidence = ?,
                 updated_at = ?
-            WHERE id = ?
+            WHERE id = ? AND julianday(updated_at) = julianday(?)
         "#;
-        sqlx::query(q)
+        let result = sqlx::query(q)
             .bind(entity.album_id.to_string())
             .bind(entity.artist_id.to_string())
             .bind(entity.foreign_track_id.clone())
@@ -1011,11 +1644,25 @@ impl Repository<Track> for SqliteTrackRepository {
             .bind(entity.duration_ms.map(|n| n as i32))
             .bind(entity.has_file)
             .bind(entity.monitored)
-            .bind(entity.updated_at.to_rfc3339())
+            .bind(entity.musicbrainz_recording_id.clone())
+            .bind(entity.match_confidence.map(f64::from))
+            .bind(updated_at.to_rfc3339())
             .bind(entity.id.to_string())
+            .bind(entity.updated_at.to_rfc3339())
             .execute(&self.pool)
             .await?;
-        Ok(entity)
+        ensure_updated(
+            &self.pool,
+            "tracks",
+            "track",
+            &entity.id.to_string(),
+            result.rows_affected(),
+        )
+        .await?;
+        Ok(Track {
+            updated_at,
+            ..entity
+        })
     }
 
     async fn delete(&self, id: &str) -> Result<()> {
@@ -1102,6 +1749,20 @@ impl TrackRepository for SqliteTrackRepository {
         }
     }
 
+    async fn get_by_musicbrainz_recording_id(&self, recording_id: &str) -> Result<Option<Track>> {
+        debug!(target: "repository", recording_id, "fetching track by musicbrainz_recording_id");
+        let row = self
+            .profiler
+            .timed("tracks::get_by_musicbrainz_recording_id", || async {
+                sqlx::query("SELECT * FROM tracks WHERE musicbrainz_recording_id = ? LIMIT 1")
+                    .bind(recording_id)
+                    .fetch_optional(&self.pool)
+                    .await
+            })
+            .await?;
+        row.map(|r| row_to_track(&r)).transpose()
+    }
+
     async fn list_monitored(&self, limit: i64, offset: i64) -> Result<Vec<Track>> {
         debug!(target: "repository", limit, offset, "listing monitored tracks");
         let rows = self
@@ -1143,6 +1804,87 @@ impl TrackRepository for SqliteTrackRepository {
         }
         Ok(out)
     }
+
+    async fn list_wanted(&self, limit: i64, offset: i64) -> Result<Vec<Track>> {
+        debug!(target: "repository", limit, offset, "listing wanted tracks");
+        let rows = self
+            .profiler
+            .timed("tracks::list_wanted", || async {
+                sqlx::query(
+                    "SELECT tracks.* FROM tracks \
+                     JOIN albums ON albums.id = tracks.album_id \
+                     JOIN artists ON artists.id = tracks.artist_id \
+                     WHERE tracks.has_file = 0 AND tracks.monitored = 1 \
+                       AND albums.monitored = 1 AND artists.monitored = 1 \
+                     ORDER BY tracks.album_id, tracks.track_number, tracks.title, tracks.id \
+                     LIMIT ? OFFSET ?",
+                )
+                .bind(limit)
+                .bind(offset)
+                .fetch_all(&self.pool)
+                .await
+            })
+            .await?;
+        let mut out = Vec::with_capacity(rows.len());
+        for r in rows {
+            out.push(row_to_track(&r)?);
+        }
+        Ok(out)
+    }
+
+    async fn list_recently_added(&self, limit: i64, offset: i64) -> Result<Vec<Track>> {
+        debug!(target: "repository", limit, offset, "listing recently added tracks");
+        let rows = self
+            .profiler
+            .timed("tracks::list_recently_added", || async {
+                sqlx::query("SELECT * FROM tracks ORDER BY created_at DESC, id LIMIT ? OFFSET ?")
+                    .bind(limit)
+                    .bind(offset)
+                    .fetch_all(&self.pool)
+                    .await
+            })
+            .await?;
+        let mut out = Vec::with_capacity(rows.len());
+        for r in rows {
+            out.push(row_to_track(&r)?);
+        }
+        Ok(out)
+    }
+
+    async fn set_monitored(&self, ids: &[TrackId], monitored: bool) -> Result<u64> {
+        debug!(target: "repository", count = ids.len(), monitored, "setting tracks monitored");
+        let ids = ids.iter().map(ToString::to_string).collect();
+        set_monitored_in(&self.pool, "tracks", ids, monitored).await
+    }
+
+    async fn list_updated_since(
+        &self,
+        since: DateTime<Utc>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Track>> {
+        debug!(target: "repository", %since, limit, offset, "listing tracks updated since");
+        // Timestamps are stored as UTC RFC 3339 strings, which sort chronologically.
+        let since = since.to_rfc3339();
+        let rows = self
+            .profiler
+            .timed("tracks::list_updated_since", || async {
+                sqlx::query(
+                    "SELECT * FROM tracks WHERE updated_at >= ? ORDER BY updated_at, id LIMIT ? OFFSET ?",
+                )
+                .bind(&since)
+                .bind(limit)
+                .bind(offset)
+                .fetch_all(&self.pool)
+                .await
+            })
+            .await?;
+        let mut out = Vec::with_capacity(rows.len());
+        for r in rows {
+            out.push(row_to_track(&r)?);
+        }
+        Ok(out)
+    }
 }
 
 // ============================================================================
@@ -1167,6 +1909,7 @@ fn row_to_quality_profile(row: &sqlx::sqlite::SqliteRow) -> Result<QualityProfil
         allowed_qualities,
         upgrade_allowed,
         cutoff_quality,
+        is_default: row.get("is_default"),
         created_at: parse_dt(row.get("created_at"))?,
         updated_at: parse_dt(row.get("updated_at"))?,
     })
@@ -1197,6 +1940,7 @@ fn row_to_metadata_profile(row: &sqlx::sqlite::SqliteRow) -> Result<MetadataProf
         primary_album_types,
         secondary_album_types,
         release_statuses,
+        is_default: row.get("is_default"),
         created_at: parse_dt(row.get("created_at"))?,
         updated_at: parse_dt(row.get("updated_at"))?,
     })
@@ -1209,6 +1953,8 @@ fn row_to_indexer_definition(row: &sqlx::sqlite::SqliteRow) -> Result<IndexerDef
     let protocol: String = row.get("protocol");
     let api_key: Option<String> = row.get("api_key");
     let enabled: bool = row.get("enabled");
+    let category_map_json: String = row.get("category_map");
+    let requests_per_minute: Option<i64> = row.get("requests_per_minute");
 
     let indexer_id = IndexerDefinitionId::from_uuid(uuid::Uuid::parse_str(&id)?);
 
@@ -1219,6 +1965,8 @@ fn row_to_indexer_definition(row: &sqlx::sqlite::SqliteRow) -> Result<IndexerDef
         protocol,
         api_key,
         enabled,
+        category_map: serde_json::from_str(&category_map_json)?,
+        requests_per_minute: requests_per_minute.map(u32::try_from).transpose()?,
         created_at: parse_dt(row.get("created_at"))?,
         updated_at: parse_dt(row.get("updated_at"))?,
     })
@@ -1278,8 +2026,9 @@ impl Repository<QualityProfile> for SqliteQualityProfileRepository {
         sqlx::query(
             r#"
             INSERT INTO quality_profiles (
-                id, name, allowed_qualities, upgrade_allowed, cutoff_quality, created_at, updated_at
-            ) VALUES (?, ?, ?, ?, ?, ?, ?)
+                id, name, allowed_qualities, upgrade_allowed, cutoff_quality, is_default,
+                created_at, updated_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?)
             "#,
         )
         .bind(id_str)
@@ -1287,6 +2036,7 @@ impl Repository<QualityProfile> for SqliteQualityProfileRepository {
         .bind(qualities_json)
         .bind(entity.upgrade_allowed)
         .bind(entity.cutoff_quality.clone())
+        .bind(entity.is_default)
         .bind(created_at)
         .bind(updated_at)
         .execute(&self.pool)
@@ -1324,9 +2074,9 @@ impl Repository<QualityProfile> for SqliteQualityProfileRepository {
     async fn update(&self, entity: QualityProfile) -> Result<QualityProfile> {
         debug!(target: "repository", profile_id = %entity.id, "updating quality profile");
         let qualities_json = serde_json::to_string(&entity.allowed_qualities)?;
-        let updated_at = entity.updated_at.to_rfc3339();
+        let updated_at = next_updated_at(entity.updated_at);
 
-        sqlx::query(
+        let result = sqlx::query(
             r#"
             UPDATE quality_profiles SET
                 name = ?,
@@ -1334,27 +2084,51 @@ impl Repository<QualityProfile> for SqliteQualityProfileRepository {
                 upgrade_allowed = ?,
                 cutoff_quality = ?,
                 updated_at = ?
-            WHERE id = ?
+            WHERE id = ? AND julianday(updated_at) = julianday(?)
             "#,
         )
         .bind(entity.name.clone())
         .bind(qualities_json)
         .bind(entity.upgrade_allowed)
         .bind(entity.cutoff_quality.clone())
-        .bind(updated_at)
+        .bind(updated_at.to_rfc3339())
         .bind(entity.id.to_string())
+        .bind(entity.updated_at.to_rfc3339())
         .execute(&self.pool)
         .await?;
-        Ok(entity)
+        ensure_updated(
+            &self.pool,
+            "quality_profiles",
+            "quality profile",
+            &entity.id.to_string(),
+            result.rows_affected(),
+        )
+        .await?;
+        Ok(QualityProfile {
+            updated_at,
+            ..entity
+        })
     }
 
     async fn delete(&self, id: &str) -> Result<()> {
         debug!(target: "repository", %id, "deleting quality profile");
-        let result = sqlx::query("DELETE FROM quality_profiles WHERE id = ?")
-            .bind(id)
-            .execute(&self.pool)
-            .await?;
+        // Artists reference profiles without a foreign key, so refuse in the same
+        // statement rather than leave their `quality_profile_id` dangling.
+        let result = sqlx::query(
+            "DELETE FROM quality_profiles WHERE id = ? \
+             AND NOT EXISTS (SELECT 1 FROM artists WHERE quality_profile_id = ?)",
+        )
+        .bind(id)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
         if result.rows_affected() == 0 {
+            let assigned = self.count_assigned_artists(id).await?;
+            if assigned > 0 {
+                return Err(anyhow!(
+                    "quality profile {id} is still assigned to {assigned} artist(s)"
+                ));
+            }
             return Err(anyhow!("quality profile not found: {}", id));
         }
         Ok(())
@@ -1375,6 +2149,35 @@ impl QualityProfileRepository for SqliteQualityProfileRepository {
             Ok(None)
         }
     }
+
+    async fn get_default(&self) -> Result<Option<QualityProfile>> {
+        debug!(target: "repository", "fetching default quality profile");
+        let row = sqlx::query("SELECT * FROM quality_profiles WHERE is_default = 1 LIMIT 1")
+            .fetch_optional(&self.pool)
+            .await?;
+        row.map(|r| row_to_quality_profile(&r)).transpose()
+    }
+
+    async fn set_default(&self, id: &str) -> Result<()> {
+        debug!(target: "repository", id, "setting default quality profile");
+        set_default_in(
+            &self.pool,
+            "quality_profiles",
+            "quality profile",
+            id.to_string(),
+        )
+        .await
+    }
+
+    async fn count_assigned_artists(&self, id: &str) -> Result<i64> {
+        debug!(target: "repository", id, "counting artists assigned to quality profile");
+        let count: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM artists WHERE quality_profile_id = ?")
+                .bind(id)
+                .fetch_one(&self.pool)
+                .await?;
+        Ok(count)
+    }
 }
 
 // ============================================================================
@@ -1405,8 +2208,9 @@ impl Repository<MetadataProfile> for SqliteMetadataProfileRepository {
         sqlx::query(
             r#"
             INSERT INTO metadata_profiles (
-                id, name, primary_album_types, secondary_album_types, release_statuses, created_at, updated_at
-            ) VALUES (?, ?, ?, ?, ?, ?, ?)
+                id, name, primary_album_types, secondary_album_types, release_statuses, is_default,
+                created_at, updated_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?)
             "#,
         )
         .bind(id_str)
@@ -1414,6 +2218,7 @@ impl Repository<MetadataProfile> for SqliteMetadataProfileRepository {
         .bind(primary_json)
         .bind(secondary_json)
         .bind(statuses_json)
+        .bind(entity.is_default)
         .bind(created_at)
         .bind(updated_at)
         .execute(&self.pool)
@@ -1453,9 +2258,9 @@ impl Repository<MetadataProfile> for SqliteMetadataProfileRepository {
         let primary_json = serde_json::to_string(&entity.primary_album_types)?;
         let secondary_json = serde_json::to_string(&entity.secondary_album_types)?;
         let statuses_json = serde_json::to_string(&entity.release_statuses)?;
-        let updated_at = entity.updated_at.to_rfc3339();
+        let updated_at = next_updated_at(entity.updated_at);
 
-        sqlx::query(
+        let result = sqlx::query(
             r#"
             UPDATE metadata_profiles SET
                 name = ?,
@@ -1463,27 +2268,51 @@ impl Repository<MetadataProfile> for SqliteMetadataProfileRepository {
                 secondary_album_types = ?,
                 release_statuses = ?,
                 updated_at = ?
-            WHERE id = ?
+            WHERE id = ? AND julianday(updated_at) = julianday(?)
             "#,
         )
         .bind(entity.name.clone())
         .bind(primary_json)
         .bind(secondary_json)
         .bind(statuses_json)
-        .bind(updated_at)
+        .bind(updated_at.to_rfc3339())
         .bind(entity.id.to_string())
+        .bind(entity.updated_at.to_rfc3339())
         .execute(&self.pool)
         .await?;
-        Ok(entity)
+        ensure_updated(
+            &self.pool,
+            "metadata_profiles",
+            "metadata profile",
+            &entity.id.to_string(),
+            result.rows_affected(),
+        )
+        .await?;
+        Ok(MetadataProfile {
+            updated_at,
+            ..entity
+        })
     }
 
     async fn delete(&self, id: &str) -> Result<()> {
         debug!(target: "repository", %id, "deleting metadata profile");
-        let result = sqlx::query("DELETE FROM metadata_profiles WHERE id = ?")
-            .bind(id)
-            .execute(&self.pool)
-            .await?;
+        // Artists reference profiles without a foreign key, so refuse in the same
+        // statement rather than leave their `metadata_profile_id` dangling.
+        let result = sqlx::query(
+            "DELETE FROM metadata_profiles WHERE id = ? \
+             AND NOT EXISTS (SELECT 1 FROM artists WHERE metadata_profile_id = ?)",
+        )
+        .bind(id)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
         if result.rows_affected() == 0 {
+            let assigned = self.count_assigned_artists(id).await?;
+            if assigned > 0 {
+                return Err(anyhow!(
+                    "metadata profile {id} is still assigned to {assigned} artist(s)"
+                ));
+            }
             return Err(anyhow!("metadata profile not found: {}", id));
         }
         Ok(())
@@ -1504,6 +2333,35 @@ impl MetadataProfileRepository for SqliteMetadataProfileRepository {
             Ok(None)
         }
     }
+
+    async fn get_default(&self) -> Result<Option<MetadataProfile>> {
+        debug!(target: "repository", "fetching default metadata profile");
+        let row = sqlx::query("SELECT * FROM metadata_profiles WHERE is_default = 1 LIMIT 1")
+            .fetch_optional(&self.pool)
+            .await?;
+        row.map(|r| row_to_metadata_profile(&r)).transpose()
+    }
+
+    async fn set_default(&self, id: &str) -> Result<()> {
+        debug!(target: "repository", id, "setting default metadata profile");
+        set_default_in(
+            &self.pool,
+            "metadata_profiles",
+            "metadata profile",
+            id.to_string(),
+        )
+        .await
+    }
+
+    async fn count_assigned_artists(&self, id: &str) -> Result<i64> {
+        debug!(target: "repository", id, "counting artists assigned to metadata profile");
+        let count: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM artists WHERE metadata_profile_id = ?")
+                .bind(id)
+                .fetch_one(&self.pool)
+                .await?;
+        Ok(count)
+    }
 }
 
 // ============================================================================
@@ -1526,12 +2384,14 @@ impl Repository<IndexerDefinition> for SqliteIndexerDefinitionRepository {
         debug!(target: "repository", indexer_definition_id = %entity.id, "creating indexer definition");
         let created_at = entity.created_at.to_rfc3339();
         let updated_at = entity.updated_at.to_rfc3339();
+        let category_map_json = serde_json::to_string(&entity.category_map)?;
 
         sqlx::query(
             r#"
             INSERT INTO indexer_definitions (
-                id, name, base_url, protocol, api_key, enabled, created_at, updated_at
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+                id, name, base_url, protocol, api_key, enabled, category_map,
+                requests_per_minute, created_at, updated_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#,
         )
         .bind(entity.id.to_string())
@@ -1540,6 +2400,8 @@ impl Repository<IndexerDefinition> for SqliteIndexerDefinitionRepository {
         .bind(entity.protocol.clone())
         .bind(entity.api_key.clone())
         .bind(entity.enabled)
+        .bind(category_map_json)
+        .bind(entity.requests_per_minute.map(i64::from))
         .bind(created_at)
         .bind(updated_at)
         .execute(&self.pool)
@@ -1576,9 +2438,10 @@ impl Repository<IndexerDefinition> for SqliteIndexerDefinitionRepository {
 
     async fn update(&self, entity: IndexerDefinition) -> Result<IndexerDefinition> {
         debug!(target: "repository", indexer_definition_id = %entity.id, "updating indexer definition");
-        let updated_at = entity.updated_at.to_rfc3339();
+        let updated_at = next_updated_at(entity.updated_at);
+        let category_map_json = serde_json::to_string(&entity.category_map)?;
 
-        sqlx::query(
+        let result = sqlx::query(
             r#"
             UPDATE indexer_definitions SET
                 name = ?,
@@ -1586,8 +2449,10 @@ impl Repository<IndexerDefinition> for SqliteIndexerDefinitionRepository {
                 protocol = ?,
                 api_key = ?,
                 enabled = ?,
+                category_map = ?,
+                requests_per_minute = ?,
                 updated_at = ?
-            WHERE id = ?
+            WHERE id = ? AND julianday(updated_at) = julianday(?)
             "#,
         )
         .bind(entity.name.clone())
@@ -1595,11 +2460,25 @@ impl Repository<IndexerDefinition> for SqliteIndexerDefinitionRepository {
         .bind(entity.protocol.clone())
         .bind(entity.api_key.clone())
         .bind(entity.enabled)
-        .bind(updated_at)
+        .bind(category_map_json)
+        .bind(entity.requests_per_minute.map(i64::from))
+        .bind(updated_at.to_rfc3339())
         .bind(entity.id.to_string())
+        .bind(entity.updated_at.to_rfc3339())
         .execute(&self.pool)
         .await?;
-        Ok(entity)
+        ensure_updated(
+            &self.pool,
+            "indexer_definitions",
+            "indexer definition",
+            &entity.id.to_string(),
+            result.rows_affected(),
+        )
+        .await?;
+        Ok(IndexerDefinition {
+            updated_at,
+            ..entity
+        })
     }
 
     async fn delete(&self, id: &str) -> Result<()> {
@@ -1704,9 +2583,9 @@ impl Repository<DownloadClientDefinition> for SqliteDownloadClientDefinitionRepo
 
     async fn update(&self, entity: DownloadClientDefinition) -> Result<DownloadClientDefinition> {
         debug!(target: "repository", download_client_definition_id = %entity.id, "updating download client definition");
-        let updated_at = entity.updated_at.to_rfc3339();
+        let updated_at = next_updated_at(entity.updated_at);
 
-        sqlx::query(
+        let result = sqlx::query(
             r#"
             UPDATE download_client_definitions SET
                 name = ?,
@@ -1717,7 +2596,7 @@ impl Repository<DownloadClientDefinition> for SqliteDownloadClientDefinitionRepo
                 category = ?,
                 enabled = ?,
                 updated_at = ?
-            WHERE id = ?
+            WHERE id = ? AND julianday(updated_at) = julianday(?)
             "#,
         )
         .bind(entity.name.clone())
@@ -1727,11 +2606,23 @@ impl Repository<DownloadClientDefinition> for SqliteDownloadClientDefinitionRepo
         .bind(entity.password_encrypted.clone())
         .bind(entity.category.clone())
         .bind(entity.enabled)
-        .bind(updated_at)
+        .bind(updated_at.to_rfc3339())
         .bind(entity.id.to_string())
+        .bind(entity.updated_at.to_rfc3339())
         .execute(&self.pool)
         .await?;
-        Ok(entity)
+        ensure_updated(
+            &self.pool,
+            "download_client_definitions",
+            "download client definition",
+            &entity.id.to_string(),
+            result.rows_affected(),
+        )
+        .await?;
+        Ok(DownloadClientDefinition {
+            updated_at,
+            ..entity
+        })
     }
 
     async fn delete(&self, id: &str) -> Result<()> {
@@ -1916,13 +2807,14 @@ impl Repository<TrackFile> for SqliteTrackFileRepository {
 
     async fn update(&self, entity: TrackFile) -> Result<TrackFile> {
         debug!(target: "repository", track_file_id = %entity.id, "updating track file");
+        let updated_at = next_updated_at(entity.updated_at);
 
         let q = r#"
             UPDATE track_files SET
-                path = ?, size_bytes = ?, duration_ms = ?, bitrate_kbps = ?,
+                track_id = ?, path = ?, size_bytes = ?, duration_ms = ?, bitrate_kbps = ?,
                 channels = ?, codec = ?, quality = ?, hash = ?, fingerprint_hash = ?,
                 fingerprint_duration = ?, fingerprint_computed_at = ?, updated_at = ?
-            WHERE id = ?
+            WHERE id = ? AND julianday(updated_at) = julianday(?)
         "#;
 
         let id_str = entity.id.to_string();
@@ -1937,7 +2829,8 @@ impl Repository<TrackFile> for SqliteTrackFileRepository {
         let fingerprint_hash = entity.fingerprint_hash.as_deref();
         let fingerprint_duration = entity.fingerprint_duration.map(|d| d as i64);
         let fingerprint_computed_at = entity.fingerprint_computed_at.map(|dt| dt.to_rfc3339());
-        sqlx::query(q)
+        let result = sqlx::query(q)
+            .bind(entity.track_id.to_string())
             .bind(path_str)
             .bind(size_bytes)
             .bind(duration_ms)
@@ -1949,13 +2842,25 @@ impl Repository<TrackFile> for SqliteTrackFileRepository {
             .bind(fingerprint_hash)
             .bind(fingerprint_duration)
             .bind(fingerprint_computed_at.as_deref())
-            .bind(entity.updated_at.to_rfc3339())
+            .bind(updated_at.to_rfc3339())
             .bind(&id_str)
+            .bind(entity.updated_at.to_rfc3339())
             .execute(&self.pool)
             .await?;
 
         debug!(target: "repository", track_file_id = %entity.id, "track file updated successfully");
-        Ok(entity)
+        ensure_updated(
+            &self.pool,
+            "track_files",
+            "track file",
+            &entity.id.to_string(),
+            result.rows_affected(),
+        )
+        .await?;
+        Ok(TrackFile {
+            updated_at,
+            ..entity
+        })
     }
 
     async fn delete(&self, id: &str) -> Result<()> {
@@ -2035,6 +2940,25 @@ impl TrackFileRepository for SqliteTrackFileRepository {
             .await?;
         rows.iter().map(row_to_track_file).collect()
     }
+
+    async fn list_orphaned(&self, limit: i64, offset: i64) -> Result<Vec<TrackFile>> {
+        debug!(target: "repository", limit, offset, "listing orphaned track files");
+        let rows = self
+            .profiler
+            .timed("track_files::list_orphaned", || async {
+                let q = "SELECT tf.* FROM track_files tf \
+                         LEFT JOIN tracks t ON t.id = tf.track_id \
+                         WHERE t.id IS NULL \
+                         ORDER BY tf.created_at DESC, tf.id LIMIT ? OFFSET ?";
+                sqlx::query(q)
+                    .bind(limit)
+                    .bind(offset)
+                    .fetch_all(&self.pool)
+                    .await
+            })
+            .await?;
+        rows.iter().map(row_to_track_file).collect()
+    }
 }
 
 // ============================================================================
@@ -2124,25 +3048,37 @@ impl Repository<ArtistRelationship> for SqliteArtistRelationshipRepository {
         let q = r#"
             UPDATE artist_relationships
             SET source_artist_id = ?, related_artist_id = ?, relationship_type = ?, description = ?, updated_at = ?
-            WHERE id = ?
+            WHERE id = ? AND julianday(updated_at) = julianday(?)
         "#;
 
         let id_str = entity.id.to_string();
         let source_artist_id_str = entity.source_artist_id.to_string();
         let related_artist_id_str = entity.related_artist_id.to_string();
-        let updated_at = entity.updated_at.to_rfc3339();
+        let updated_at = next_updated_at(entity.updated_at);
 
-        sqlx::query(q)
+        let result = sqlx::query(q)
             .bind(source_artist_id_str)
             .bind(related_artist_id_str)
             .bind(entity.relationship_type.clone())
             .bind(entity.description.clone())
-            .bind(updated_at)
+            .bind(updated_at.to_rfc3339())
             .bind(id_str)
+            .bind(entity.updated_at.to_rfc3339())
             .execute(&self.pool)
             .await?;
 
-        Ok(entity)
+        ensure_updated(
+            &self.pool,
+            "artist_relationships",
+            "artist relationship",
+            &entity.id.to_string(),
+            result.rows_affected(),
+        )
+        .await?;
+        Ok(ArtistRelationship {
+            updated_at,
+            ..entity
+        })
     }
 
     async fn delete(&self, id: &str) -> Result<()> {
@@ -2342,22 +3278,34 @@ impl Repository<Tag> for SqliteTagRepository {
         debug!(target: "repository", tag_id = %entity.id, tag_name = %entity.name, "updating tag");
 
         let id_str = entity.id.to_string();
-        let updated_at = entity.updated_at.to_rfc3339();
+        let updated_at = next_updated_at(entity.updated_at);
 
         let q = r#"
             UPDATE tags
             SET name = ?, description = ?, updated_at = ?
-            WHERE id = ?"#;
+            WHERE id = ? AND julianday(updated_at) = julianday(?)"#;
 
-        sqlx::query(q)
+        let result = sqlx::query(q)
             .bind(&entity.name)
             .bind(&entity.description)
-            .bind(&updated_at)
+            .bind(updated_at.to_rfc3339())
             .bind(&id_str)
+            .bind(entity.updated_at.to_rfc3339())
             .execute(&self.pool)
             .await?;
 
-        Ok(entity)
+        ensure_updated(
+            &self.pool,
+            "tags",
+            "tag",
+            &entity.id.to_string(),
+            result.rows_affected(),
+        )
+        .await?;
+        Ok(Tag {
+            updated_at,
+            ..entity
+        })
     }
 
     async fn delete(&self, id: &str) -> Result<()> {
@@ -2735,24 +3683,36 @@ impl Repository<SmartPlaylist> for SqliteSmartPlaylistRepository {
         debug!(target: "repository", smart_playlist_id = %entity.id, smart_playlist_name = %entity.name, "updating smart playlist");
 
         let criteria_json = serde_json::to_string(&entity.criteria)?;
-        let updated_at = entity.updated_at.to_rfc3339();
+        let updated_at = next_updated_at(entity.updated_at);
 
-        sqlx::query(
+        let result = sqlx::query(
             r#"
             UPDATE smart_playlists
             SET name = ?, description = ?, criteria_json = ?, updated_at = ?
-            WHERE id = ?
+            WHERE id = ? AND julianday(updated_at) = julianday(?)
             "#,
         )
         .bind(&entity.name)
         .bind(&entity.description)
         .bind(criteria_json)
-        .bind(updated_at)
+        .bind(updated_at.to_rfc3339())
         .bind(entity.id.to_string())
+        .bind(entity.updated_at.to_rfc3339())
         .execute(&self.pool)
         .await?;
 
-        Ok(entity)
+        ensure_updated(
+            &self.pool,
+            "smart_playlists",
+            "smart playlist",
+            &entity.id.to_string(),
+            result.rows_affected(),
+        )
+        .await?;
+        Ok(SmartPlaylist {
+            updated_at,
+            ..entity
+        })
     }
 
     async fn delete(&self, id: &str) -> Result<()> {
@@ -3030,6 +3990,40 @@ impl DuplicateRepository for SqliteDuplicateRepository {
         rows.iter().map(row_to_duplicate_file_detail).collect()
     }
 
+    async fn get_files_by_fingerprints(
+        &self,
+        fingerprint_hashes: &[String],
+    ) -> Result<HashMap<String, Vec<DuplicateFileDetail>>> {
+        let mut out: HashMap<String, Vec<DuplicateFileDetail>> = HashMap::new();
+        for chunk in fingerprint_hashes.chunks(MAX_IN_LIST_IDS) {
+            let placeholders = vec!["?"; chunk.len()].join(", ");
+            let sql = format!(
+                "SELECT id, track_id, path, size_bytes, quality, bitrate_kbps, codec, \
+                        fingerprint_hash, hash, created_at \
+                 FROM track_files \
+                 WHERE fingerprint_hash IN ({placeholders}) \
+                 ORDER BY bitrate_kbps DESC, size_bytes DESC"
+            );
+            let rows = self
+                .profiler
+                .timed("duplicates::get_files_by_fingerprints", || async {
+                    let mut query = sqlx::query(&sql);
+                    for hash in chunk {
+                        query = query.bind(hash);
+                    }
+                    query.fetch_all(&self.pool).await
+                })
+                .await?;
+            for row in &rows {
+                let file = row_to_duplicate_file_detail(row)?;
+                if let Some(hash) = file.fingerprint_hash.clone() {
+                    out.entry(hash).or_default().push(file);
+                }
+            }
+        }
+        Ok(out)
+    }
+
     async fn get_files_by_hash(&self, file_hash: &str) -> Result<Vec<DuplicateFileDetail>> {
         let rows = self
             .profiler
@@ -3289,6 +4283,111 @@ mod tests {
         assert!(absent.is_none());
     }
 
+    #[tokio::test]
+    async fn artist_update_rejects_stale_copy_and_accepts_fresh_one() {
+        let pool = setup_pool().await;
+        let repo = SqliteArtistRepository::new(pool.clone());
+
+        let created = repo
+            .create(chorrosion_domain::Artist::new("Before"))
+            .await
+            .expect("create");
+        let stale = created.clone();
+
+        let mut fresh = created.clone();
+        fresh.name = "First writer".to_string();
+        let fresh = repo.update(fresh).await.expect("fresh update");
+        assert!(fresh.updated_at > created.updated_at);
+
+        let mut late = stale;
+        late.name = "Second writer".to_string();
+        let error = repo.update(late).await.expect_err("stale update");
+        let conflict = error
+            .downcast_ref::<ConcurrencyConflict>()
+            .expect("stale update is a concurrency conflict");
+        assert_eq!(conflict.id, created.id.to_string());
+
+        let fetched = repo
+            .get_by_id(&created.id.to_string())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(fetched.name, "First writer");
+
+        let mut renamed = fetched;
+        renamed.name = "After reload".to_string();
+        repo.update(renamed).await.expect("update after reload");
+
+        let missing = chorrosion_domain::Artist::new("Missing");
+        let error = repo.update(missing).await.expect_err("missing artist");
+        assert!(!error.is::<ConcurrencyConflict>());
+        assert!(error.to_string().contains("not found"));
+    }
+
+    #[tokio::test]
+    async fn artist_autocomplete_matches_name_and_sort_name_prefixes() {
+        let pool = setup_pool().await;
+        let repo = SqliteArtistRepository::new(pool.clone());
+
+        let mut beatles = chorrosion_domain::Artist::new("The Beatles");
+        beatles.sort_name = Some("Beatles, The".to_string());
+        for artist in [
+            chorrosion_domain::Artist::new("Radiohead"),
+            chorrosion_domain::Artist::new("Rage Against the Machine"),
+            chorrosion_domain::Artist::new("Portishead"),
+            beatles,
+        ] {
+            repo.create(artist).await.expect("create");
+        }
+        let names = |artists: Vec<Artist>| -> Vec<String> {
+            artists.into_iter().map(|artist| artist.name).collect()
+        };
+
+        let ra = repo.autocomplete("ra", 10).await.expect("autocomplete");
+        assert_eq!(names(ra), ["Radiohead", "Rage Against the Machine"]);
+
+        let rad = repo.autocomplete("RAD", 10).await.expect("autocomplete");
+        assert_eq!(names(rad), ["Radiohead"]);
+
+        let head = repo.autocomplete("head", 10).await.expect("autocomplete");
+        assert!(head.is_empty(), "matches are anchored at the start");
+
+        let beatles = repo
+            .autocomplete("beatles", 10)
+            .await
+            .expect("autocomplete");
+        assert_eq!(names(beatles), ["The Beatles"]);
+
+        let limited = repo.autocomplete("r", 1).await.expect("autocomplete");
+        assert_eq!(names(limited), ["Radiohead"]);
+
+        assert!(repo.autocomplete("  ", 10).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn artist_autocomplete_escapes_like_wildcards() {
+        let pool = setup_pool().await;
+        let repo = SqliteArtistRepository::new(pool.clone());
+
+        for name in ["100% Pure", "1000 Homo DJs", "A_B", "AXB", "Back\\slash"] {
+            repo.create(chorrosion_domain::Artist::new(name))
+                .await
+                .expect("create");
+        }
+        let names = |artists: Vec<Artist>| -> Vec<String> {
+            artists.into_iter().map(|artist| artist.name).collect()
+        };
+
+        let percent = repo.autocomplete("100%", 10).await.expect("autocomplete");
+        assert_eq!(names(percent), ["100% Pure"]);
+
+        let underscore = repo.autocomplete("a_", 10).await.expect("autocomplete");
+        assert_eq!(names(underscore), ["A_B"]);
+
+        let backslash = repo.autocomplete("back\\", 10).await.expect("autocomplete");
+        assert_eq!(names(backslash), ["Back\\slash"]);
+    }
+
     #[tokio::test]
     async fn artist_list_ordering_and_pagination() {
         let pool = setup_pool().await;
@@ -3591,6 +4690,85 @@ mod tests {
         assert_eq!(comps[0].title, "Compilation");
     }
 
+    #[tokio::test]
+    async fn album_release_date_round_trips_with_precision() {
+        let pool = setup_pool().await;
+        let artist_repo = SqliteArtistRepository::new(pool.clone());
+        let album_repo = SqliteAlbumRepository::new(pool.clone());
+        let artist = artist_repo
+            .create(Artist::new("Release Date Artist"))
+            .await
+            .expect("artist");
+
+        let cases = [
+            ("Year", ReleaseDate::new(1994, None, None), "1994"),
+            ("Month", ReleaseDate::new(1997, Some(6), None), "1997-06"),
+            (
+                "Day",
+                ReleaseDate::new(2008, Some(4), Some(28)),
+                "2008-04-28",
+            ),
+        ];
+        for (title, release_date, iso) in cases {
+            let mut album = Album::new(artist.id, title);
+            album.release_date = Some(release_date);
+            let created = album_repo.create(album).await.expect("create");
+
+            let fetched = album_repo
+                .get_by_id(&created.id.to_string())
+                .await
+                .unwrap()
+                .unwrap();
+            let fetched_date = fetched.release_date.expect("release date");
+            assert_eq!(fetched_date, release_date);
+            assert_eq!(fetched_date.precision(), release_date.precision());
+            assert_eq!(fetched_date.to_iso8601(), iso);
+
+            let updated = album_repo.update(fetched).await.expect("update");
+            assert_eq!(updated.release_date, Some(release_date));
+        }
+    }
+
+    #[tokio::test]
+    async fn year_only_release_date_is_stored_as_full_date_for_range_queries() {
+        let pool = setup_pool().await;
+        let artist_repo = SqliteArtistRepository::new(pool.clone());
+        let album_repo = SqliteAlbumRepository::new(pool.clone());
+        let artist = artist_repo
+            .create(Artist::new("Year Only Artist"))
+            .await
+            .expect("artist");
+
+        let mut album = Album::new(artist.id, "Year Only");
+        album.release_date = Some(ReleaseDate::new(1994, None, None));
+        let created = album_repo.create(album).await.expect("create");
+
+        let (stored, precision): (String, String) = sqlx::query_as(
+            "SELECT CAST(release_date AS TEXT), release_date_precision FROM albums WHERE id = ?",
+        )
+        .bind(created.id.to_string())
+        .fetch_one(&pool)
+        .await
+        .expect("stored columns");
+        assert_eq!(stored, "1994-01-01");
+        assert_eq!(precision, "year");
+
+        let upcoming = album_repo
+            .list_upcoming_releases(
+                NaiveDate::from_ymd_opt(1994, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(1994, 1, 31).unwrap(),
+                10,
+                0,
+            )
+            .await
+            .expect("upcoming");
+        assert_eq!(upcoming.len(), 1);
+        assert_eq!(
+            upcoming[0].release_date.expect("release date").to_iso8601(),
+            "1994"
+        );
+    }
+
     #[tokio::test]
     async fn album_update_and_delete_flow() {
         let pool = setup_pool().await;
@@ -3611,7 +4789,7 @@ mod tests {
         album.album_type = Some("live".to_string());
         album.monitored = false;
         album.status = AlbumStatus::Released;
-        album.release_date = Some(chrono::NaiveDate::from_ymd_opt(2024, 1, 15).unwrap());
+        album.release_date = Some(ReleaseDate::new(2024, Some(1), Some(15)));
         let updated = album_repo.update(album.clone()).await.expect("update");
         assert_eq!(updated.title, "After");
         assert!(!updated.monitored);
@@ -3625,7 +4803,7 @@ mod tests {
         assert_eq!(fetched.album_type.as_deref(), Some("live"));
         assert_eq!(
             fetched.release_date,
-            Some(chrono::NaiveDate::from_ymd_opt(2024, 1, 15).unwrap())
+            Some(ReleaseDate::new(2024, Some(1), Some(15)))
         );
 
         // Delete and ensure gone
@@ -3923,6 +5101,161 @@ mod tests {
         assert!(without_files_titles.contains(&"Not monitored without file"));
     }
 
+    #[tokio::test]
+    async fn track_list_wanted_requires_monitored_track_album_and_artist() {
+        let pool = setup_pool().await;
+        let artist_repo = SqliteArtistRepository::new(pool.clone());
+        let album_repo = SqliteAlbumRepository::new(pool.clone());
+        let track_repo = SqliteTrackRepository::new(pool.clone());
+
+        // Every combination of artist, album and track monitoring, plus has_file.
+        for artist_monitored in [true, false] {
+            let mut artist = chorrosion_domain::Artist::new(format!("Artist {artist_monitored}"));
+            artist.monitored = artist_monitored;
+            let artist_id = artist.id;
+            artist_repo.create(artist).await.expect("create artist");
+
+            for album_monitored in [true, false] {
+                let mut album = chorrosion_domain::Album::new(artist_id, "Album");
+                album.monitored = album_monitored;
+                let album_id = album.id;
+                album_repo.create(album).await.expect("create album");
+
+                for (track_monitored, has_file) in
+                    [(true, false), (true, true), (false, false), (false, true)]
+                {
+                    let title = format!(
+                        "artist={artist_monitored} album={album_monitored} \
+                         track={track_monitored} file={has_file}"
+                    );
+                    let mut track = chorrosion_domain::Track::new(album_id, artist_id, title);
+                    track.monitored = track_monitored;
+                    track.has_file = has_file;
+                    track_repo.create(track).await.expect("create track");
+                }
+            }
+        }
+
+        let wanted = track_repo.list_wanted(100, 0).await.expect("list wanted");
+        let titles: Vec<_> = wanted.iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(
+            titles,
+            ["artist=true album=true track=true file=false"],
+            "only fully monitored tracks without files are wanted"
+        );
+
+        assert!(track_repo.list_wanted(100, 1).await.unwrap().is_empty());
+    }
+
+    /// Creates an album with one track per `(monitored, has_file)` pair.
+    async fn album_with_tracks(pool: &SqlitePool, tracks: &[(bool, bool)]) -> AlbumId {
+        let artist = chorrosion_domain::Artist::new("Artist");
+        let artist_id = artist.id;
+        SqliteArtistRepository::new(pool.clone())
+            .create(artist)
+            .await
+            .expect("create artist");
+        let album = chorrosion_domain::Album::new(artist_id, "Album");
+        let album_id = album.id;
+        SqliteAlbumRepository::new(pool.clone())
+            .create(album)
+            .await
+            .expect("create album");
+        let track_repo = SqliteTrackRepository::new(pool.clone());
+        for (n, (monitored, has_file)) in tracks.iter().enumerate() {
+            let mut track =
+                chorrosion_domain::Track::new(album_id, artist_id, format!("Track {n}"));
+            track.monitored = *monitored;
+            track.has_file = *has_file;
+            track_repo.create(track).await.expect("create track");
+        }
+        album_id
+    }
+
+    #[tokio::test]
+    async fn album_track_stats_for_partially_complete_album() {
+        let pool = setup_pool().await;
+        let album_id = album_with_tracks(
+            &pool,
+            &[(true, true), (true, false), (true, false), (false, true)],
+        )
+        .await;
+
+        let stats = SqliteAlbumRepository::new(pool)
+            .track_stats(album_id)
+            .await
+            .expect("track stats");
+
+        assert_eq!(
+            stats,
+            TrackStats {
+                total: 4,
+                with_file: 2,
+                monitored: 3,
+                is_complete: false,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn album_track_stats_for_complete_album_ignores_unmonitored_tracks() {
+        let pool = setup_pool().await;
+        let album_id =
+            album_with_tracks(&pool, &[(true, true), (true, true), (false, false)]).await;
+
+        let stats = SqliteAlbumRepository::new(pool)
+            .track_stats(album_id)
+            .await
+            .expect("track stats");
+
+        assert_eq!(stats.total, 3);
+        assert_eq!(stats.with_file, 2);
+        assert!(stats.is_complete);
+    }
+
+    #[tokio::test]
+    async fn album_track_stats_for_returns_stats_per_album() {
+        let pool = setup_pool().await;
+        let partial = album_with_tracks(&pool, &[(true, true), (true, false)]).await;
+        let complete = album_with_tracks(&pool, &[(true, true)]).await;
+        let empty = album_with_tracks(&pool, &[]).await;
+        let repo = SqliteAlbumRepository::new(pool);
+
+        let stats = repo
+            .track_stats_for(&[partial, complete, empty])
+            .await
+            .expect("batch track stats");
+
+        assert_eq!(stats.len(), 3);
+        assert_eq!(stats[&partial], TrackStats::from_counts(2, 1, 2, 1));
+        assert_eq!(stats[&complete], TrackStats::from_counts(1, 1, 1, 0));
+        assert_eq!(stats[&empty], TrackStats::default());
+        assert!(!stats[&empty].is_complete);
+        assert!(repo.track_stats_for(&[]).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn bulk_id_lists_are_chunked_under_the_parameter_limit() {
+        let pool = setup_pool().await;
+        let album_id = album_with_tracks(&pool, &[(true, true), (true, false)]).await;
+        let mut album_ids: Vec<AlbumId> = (0..40_000).map(|_| AlbumId::new()).collect();
+        album_ids.push(album_id);
+        let album_repo = SqliteAlbumRepository::new(pool.clone());
+
+        let stats = album_repo
+            .track_stats_for(&album_ids)
+            .await
+            .expect("batch track stats");
+        assert_eq!(stats.len(), album_ids.len());
+        assert_eq!(stats[&album_id], TrackStats::from_counts(2, 1, 2, 1));
+
+        let updated = album_repo
+            .set_monitored(&album_ids, false)
+            .await
+            .expect("set monitored");
+        assert_eq!(updated, 1);
+    }
+
     #[tokio::test]
     async fn track_update_and_delete_flow() {
         let pool = setup_pool().await;
@@ -3948,6 +5281,8 @@ mod tests {
         track.duration_ms = Some(240000);
         track.has_file = true;
         track.monitored = false;
+        track.musicbrainz_recording_id = Some("recording-mbid".to_string());
+        track.match_confidence = Some(0.5);
         let updated = track_repo.update(track.clone()).await.expect("update");
         assert_eq!(updated.title, "After");
 
@@ -3961,6 +5296,17 @@ mod tests {
         assert_eq!(fetched.duration_ms, Some(240000));
         assert!(fetched.has_file);
         assert!(!fetched.monitored);
+        assert_eq!(
+            fetched.musicbrainz_recording_id.as_deref(),
+            Some("recording-mbid")
+        );
+        assert_eq!(fetched.match_confidence, Some(0.5));
+        let by_recording = track_repo
+            .get_by_musicbrainz_recording_id("recording-mbid")
+            .await
+            .unwrap()
+            .expect("track by recording id");
+        assert_eq!(by_recording.id, track_id);
 
         // Delete and ensure gone
         track_repo
@@ -4141,17 +5487,256 @@ mod tests {
             "Lossy",
             vec!["MP3".to_string(), "AAC".to_string()],
         );
-        profile_repo.create(profile.clone()).await.expect("create");
+        profile_repo.create(profile.clone()).await.expect("create");
+
+        let found = profile_repo.get_by_name("Lossy").await.unwrap().unwrap();
+        assert_eq!(found.id, profile.id);
+        assert_eq!(
+            found.allowed_qualities,
+            vec!["MP3".to_string(), "AAC".to_string()]
+        );
+
+        let absent = profile_repo.get_by_name("NonExistent").await.unwrap();
+        assert!(absent.is_none());
+    }
+
+    #[tokio::test]
+    async fn profiles_count_assigned_artists() {
+        let pool = setup_pool().await;
+        let quality_repo = SqliteQualityProfileRepository::new(pool.clone());
+        let metadata_repo = SqliteMetadataProfileRepository::new(pool.clone());
+        let artist_repo = SqliteArtistRepository::new(pool.clone());
+
+        let quality = chorrosion_domain::QualityProfile::new("Lossless", vec!["FLAC".to_string()]);
+        quality_repo.create(quality.clone()).await.expect("create");
+        let metadata = chorrosion_domain::MetadataProfile::new("Standard");
+        metadata_repo
+            .create(metadata.clone())
+            .await
+            .expect("create");
+
+        for name in ["A", "B"] {
+            let mut artist = chorrosion_domain::Artist::new(name);
+            artist.quality_profile_id = Some(quality.id);
+            artist_repo.create(artist).await.expect("create artist");
+        }
+        let mut artist = chorrosion_domain::Artist::new("C");
+        artist.metadata_profile_id = Some(metadata.id);
+        artist_repo.create(artist).await.expect("create artist");
+
+        let quality_id = quality.id.to_string();
+        let metadata_id = metadata.id.to_string();
+        assert_eq!(
+            quality_repo
+                .count_assigned_artists(&quality_id)
+                .await
+                .unwrap(),
+            2
+        );
+        assert_eq!(
+            metadata_repo
+                .count_assigned_artists(&metadata_id)
+                .await
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            quality_repo
+                .count_assigned_artists(&metadata_id)
+                .await
+                .unwrap(),
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn profile_delete_is_refused_while_assigned_to_an_artist() {
+        let pool = setup_pool().await;
+        let quality_repo = SqliteQualityProfileRepository::new(pool.clone());
+        let metadata_repo = SqliteMetadataProfileRepository::new(pool.clone());
+        let artist_repo = SqliteArtistRepository::new(pool.clone());
+
+        let unused = chorrosion_domain::QualityProfile::new("Unused", vec!["MP3".to_string()]);
+        quality_repo.create(unused.clone()).await.expect("create");
+        quality_repo
+            .delete(&unused.id.to_string())
+            .await
+            .expect("an unused profile can be deleted");
+
+        let quality = chorrosion_domain::QualityProfile::new("Lossless", vec!["FLAC".to_string()]);
+        quality_repo.create(quality.clone()).await.expect("create");
+        let metadata = chorrosion_domain::MetadataProfile::new("Standard");
+        metadata_repo
+            .create(metadata.clone())
+            .await
+            .expect("create");
+        let mut artist = chorrosion_domain::Artist::new("Artist");
+        artist.quality_profile_id = Some(quality.id);
+        artist.metadata_profile_id = Some(metadata.id);
+        let artist = artist_repo.create(artist).await.expect("create artist");
+
+        let quality_id = quality.id.to_string();
+        let metadata_id = metadata.id.to_string();
+        let error = quality_repo.delete(&quality_id).await.unwrap_err();
+        assert!(
+            error.to_string().contains("still assigned to 1 artist"),
+            "unexpected error: {error}"
+        );
+        let error = metadata_repo.delete(&metadata_id).await.unwrap_err();
+        assert!(error.to_string().contains("still assigned to 1 artist"));
+        assert!(quality_repo.get_by_id(&quality_id).await.unwrap().is_some());
+        assert!(metadata_repo
+            .get_by_id(&metadata_id)
+            .await
+            .unwrap()
+            .is_some());
+
+        // Reassigning the artist releases both profiles.
+        let replacement = chorrosion_domain::QualityProfile::new("Any", vec!["MP3".to_string()]);
+        quality_repo
+            .create(replacement.clone())
+            .await
+            .expect("create");
+        let mut artist = artist;
+        artist.quality_profile_id = Some(replacement.id);
+        artist.metadata_profile_id = None;
+        artist_repo.update(artist).await.expect("update artist");
+
+        quality_repo.delete(&quality_id).await.expect("delete");
+        metadata_repo.delete(&metadata_id).await.expect("delete");
+    }
+
+    #[tokio::test]
+    async fn artist_create_applies_default_profiles() {
+        let pool = setup_pool().await;
+        let artist_repo = SqliteArtistRepository::new(pool.clone());
+        let quality_repo = SqliteQualityProfileRepository::new(pool.clone());
+        let metadata_repo = SqliteMetadataProfileRepository::new(pool.clone());
+
+        let quality = chorrosion_domain::QualityProfile::new("Lossless", vec!["FLAC".to_string()]);
+        let other_quality =
+            chorrosion_domain::QualityProfile::new("Lossy", vec!["MP3".to_string()]);
+        let metadata = chorrosion_domain::MetadataProfile::new("Albums");
+        quality_repo.create(quality.clone()).await.expect("create");
+        quality_repo
+            .create(other_quality.clone())
+            .await
+            .expect("create");
+        metadata_repo
+            .create(metadata.clone())
+            .await
+            .expect("create");
+
+        let created = artist_repo
+            .create(chorrosion_domain::Artist::new("Without Defaults"))
+            .await
+            .expect("create artist");
+        assert_eq!(created.quality_profile_id, None);
+        assert_eq!(created.metadata_profile_id, None);
+
+        quality_repo
+            .set_default(&quality.id.to_string())
+            .await
+            .expect("set default");
+        metadata_repo
+            .set_default(&metadata.id.to_string())
+            .await
+            .expect("set default");
+
+        let created = artist_repo
+            .create(chorrosion_domain::Artist::new("Inherits Defaults"))
+            .await
+            .expect("create artist");
+        assert_eq!(created.quality_profile_id, Some(quality.id));
+        assert_eq!(created.metadata_profile_id, Some(metadata.id));
+        let stored = artist_repo
+            .get_by_id(&created.id.to_string())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.quality_profile_id, Some(quality.id));
+
+        let mut explicit = chorrosion_domain::Artist::new("Explicit Profile");
+        explicit.quality_profile_id = Some(other_quality.id);
+        let created = artist_repo.create(explicit).await.expect("create artist");
+        assert_eq!(created.quality_profile_id, Some(other_quality.id));
+        assert_eq!(created.metadata_profile_id, Some(metadata.id));
+
+        let mut upserted = chorrosion_domain::Artist::new("Upserted");
+        upserted.foreign_artist_id = Some("mb-upserted".to_string());
+        let upserted = artist_repo
+            .upsert_by_foreign_id(upserted)
+            .await
+            .expect("upsert artist");
+        assert_eq!(upserted.quality_profile_id, Some(quality.id));
+    }
+
+    #[tokio::test]
+    async fn set_default_profile_replaces_the_previous_default() {
+        let pool = setup_pool().await;
+        let quality_repo = SqliteQualityProfileRepository::new(pool.clone());
+        let metadata_repo = SqliteMetadataProfileRepository::new(pool.clone());
+
+        assert!(quality_repo.get_default().await.unwrap().is_none());
+
+        let lossless = chorrosion_domain::QualityProfile::new("Lossless", vec!["FLAC".to_string()]);
+        let lossy = chorrosion_domain::QualityProfile::new("Lossy", vec!["MP3".to_string()]);
+        quality_repo.create(lossless.clone()).await.expect("create");
+        quality_repo.create(lossy.clone()).await.expect("create");
+
+        quality_repo
+            .set_default(&lossless.id.to_string())
+            .await
+            .expect("set default");
+        let default = quality_repo.get_default().await.unwrap().expect("default");
+        assert_eq!(default.id, lossless.id);
+        assert!(default.is_default);
+
+        quality_repo
+            .set_default(&lossy.id.to_string())
+            .await
+            .expect("set default");
+        assert_eq!(
+            quality_repo.get_default().await.unwrap().map(|p| p.id),
+            Some(lossy.id)
+        );
+        let previous = quality_repo
+            .get_by_id(&lossless.id.to_string())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!previous.is_default);
 
-        let found = profile_repo.get_by_name("Lossy").await.unwrap().unwrap();
-        assert_eq!(found.id, profile.id);
+        let error = quality_repo.set_default("missing").await.unwrap_err();
+        assert!(error.to_string().contains("not found"), "{error}");
         assert_eq!(
-            found.allowed_qualities,
-            vec!["MP3".to_string(), "AAC".to_string()]
+            quality_repo.get_default().await.unwrap().map(|p| p.id),
+            Some(lossy.id),
+            "a failed set_default keeps the current default"
         );
 
-        let absent = profile_repo.get_by_name("NonExistent").await.unwrap();
-        assert!(absent.is_none());
+        let standard = chorrosion_domain::MetadataProfile::new("Standard");
+        let everything = chorrosion_domain::MetadataProfile::new("Everything");
+        metadata_repo
+            .create(standard.clone())
+            .await
+            .expect("create");
+        metadata_repo
+            .create(everything.clone())
+            .await
+            .expect("create");
+        metadata_repo
+            .set_default(&standard.id.to_string())
+            .await
+            .expect("set default");
+        metadata_repo
+            .set_default(&everything.id.to_string())
+            .await
+            .expect("set default");
+        assert_eq!(
+            metadata_repo.get_default().await.unwrap().map(|p| p.id),
+            Some(everything.id)
+        );
     }
 
     #[tokio::test]
@@ -4427,6 +6012,62 @@ mod tests {
         assert!(deleted.is_none());
     }
 
+    #[tokio::test]
+    async fn indexer_definition_round_trips_protocol_enabled_and_category_map() {
+        let pool = setup_pool().await;
+        let repo = SqliteIndexerDefinitionRepository::new(pool);
+
+        let mut indexer = chorrosion_domain::IndexerDefinition::new(
+            "Usenet Main",
+            "https://usenet.example",
+            "newznab",
+        );
+        indexer.enabled = false;
+        indexer.requests_per_minute = Some(30);
+        indexer
+            .category_map
+            .insert("audio/flac".to_string(), "3040".to_string());
+        let indexer_id = indexer.id.to_string();
+        repo.create(indexer).await.expect("create indexer");
+
+        let fetched = repo
+            .get_by_id(&indexer_id)
+            .await
+            .expect("get_by_id")
+            .expect("indexer exists");
+        assert_eq!(fetched.protocol, "newznab");
+        assert!(!fetched.enabled);
+        assert_eq!(fetched.requests_per_minute, Some(30));
+        assert_eq!(
+            fetched.category_map.get("audio/flac").map(String::as_str),
+            Some("3040")
+        );
+
+        let mut updated = fetched;
+        updated.protocol = "torznab".to_string();
+        updated.enabled = true;
+        updated.requests_per_minute = None;
+        updated.category_map.clear();
+        updated
+            .category_map
+            .insert("music".to_string(), "100".to_string());
+        repo.update(updated).await.expect("update indexer");
+
+        let refetched = repo
+            .get_by_name("Usenet Main")
+            .await
+            .expect("get_by_name")
+            .expect("indexer exists");
+        assert_eq!(refetched.protocol, "torznab");
+        assert!(refetched.enabled);
+        assert_eq!(refetched.requests_per_minute, None);
+        assert_eq!(refetched.category_map.len(), 1);
+        assert_eq!(
+            refetched.category_map.get("music").map(String::as_str),
+            Some("100")
+        );
+    }
+
     #[tokio::test]
     async fn indexer_definition_list_ordering_and_pagination() {
         let pool = setup_pool().await;
@@ -4828,6 +6469,122 @@ mod tests {
         assert!(page1_ids.is_disjoint(&page2_ids));
     }
 
+    async fn seed_track_for_files(pool: &SqlitePool) -> chorrosion_domain::Track {
+        let artist = SqliteArtistRepository::new(pool.clone())
+            .create(chorrosion_domain::Artist::new("Test Artist"))
+            .await
+            .expect("create artist");
+        let album = SqliteAlbumRepository::new(pool.clone())
+            .create(chorrosion_domain::Album::new(artist.id, "Test Album"))
+            .await
+            .expect("create album");
+        SqliteTrackRepository::new(pool.clone())
+            .create(chorrosion_domain::Track::new(
+                album.id,
+                artist.id,
+                "Test Track",
+            ))
+            .await
+            .expect("create track")
+    }
+
+    #[tokio::test]
+    async fn track_file_list_orphaned_finds_files_without_track() {
+        let pool = setup_pool().await;
+        let track_file_repo = SqliteTrackFileRepository::new(pool.clone());
+        let kept_track = seed_track_for_files(&pool).await;
+        let deleted_track = seed_track_for_files(&pool).await;
+
+        let kept = track_file_repo
+            .create(chorrosion_domain::TrackFile::new(
+                kept_track.id,
+                "/music/kept.flac",
+                1024,
+            ))
+            .await
+            .expect("create kept file");
+        let orphan = track_file_repo
+            .create(chorrosion_domain::TrackFile::new(
+                deleted_track.id,
+                "/music/orphan.flac",
+                1024,
+            ))
+            .await
+            .expect("create orphan file");
+
+        // Bypass the ON DELETE CASCADE so the track file row is left dangling.
+        sqlx::query("PRAGMA foreign_keys = OFF")
+            .execute(&pool)
+            .await
+            .expect("disable foreign keys");
+        sqlx::query("DELETE FROM tracks WHERE id = ?")
+            .bind(deleted_track.id.to_string())
+            .execute(&pool)
+            .await
+            .expect("delete track");
+
+        let orphaned = track_file_repo
+            .list_orphaned(10, 0)
+            .await
+            .expect("list_orphaned");
+        let ids: Vec<_> = orphaned.iter().map(|f| f.id).collect();
+        assert_eq!(ids, vec![orphan.id]);
+        assert!(!ids.contains(&kept.id));
+
+        let second_page = track_file_repo
+            .list_orphaned(10, 1)
+            .await
+            .expect("list_orphaned page 2");
+        assert!(second_page.is_empty());
+    }
+
+    #[tokio::test]
+    async fn track_file_list_missing_on_disk_uses_checker() {
+        let pool = setup_pool().await;
+        let track_file_repo = SqliteTrackFileRepository::new(pool.clone());
+        let track = seed_track_for_files(&pool).await;
+
+        for path in [
+            "/music/present.flac",
+            "/music/gone_1.flac",
+            "/music/gone_2.flac",
+        ] {
+            track_file_repo
+                .create(chorrosion_domain::TrackFile::new(track.id, path, 1024))
+                .await
+                .expect("create track file");
+        }
+
+        let exists = |path: &str| !path.contains("gone");
+        let mut missing: Vec<String> = track_file_repo
+            .list_missing_on_disk(&exists, 10, 0)
+            .await
+            .expect("list_missing_on_disk")
+            .into_iter()
+            .map(|f| f.path)
+            .collect();
+        missing.sort();
+        assert_eq!(missing, vec!["/music/gone_1.flac", "/music/gone_2.flac"]);
+
+        let first = track_file_repo
+            .list_missing_on_disk(&exists, 1, 0)
+            .await
+            .expect("first page");
+        let second = track_file_repo
+            .list_missing_on_disk(&exists, 1, 1)
+            .await
+            .expect("second page");
+        assert_eq!(first.len(), 1);
+        assert_eq!(second.len(), 1);
+        assert_ne!(first[0].id, second[0].id);
+
+        let none_missing = track_file_repo
+            .list_missing_on_disk(&|_: &str| true, 10, 0)
+            .await
+            .expect("nothing missing");
+        assert!(none_missing.is_empty());
+    }
+
     #[tokio::test]
     async fn artist_with_musicbrainz_metadata() {
         let pool = setup_pool().await;
@@ -5026,6 +6783,40 @@ mod tests {
         assert_eq!(fetched.style_tags.as_deref(), Some("smooth|soulful"));
     }
 
+    #[tokio::test]
+    async fn record_metadata_refresh_persists_timestamp_for_list() {
+        let pool = setup_pool().await;
+        let artist_repo = SqliteArtistRepository::new(pool.clone());
+        let album_repo = SqliteAlbumRepository::new(pool);
+
+        let artist = artist_repo
+            .create(chorrosion_domain::Artist::new("Refreshed"))
+            .await
+            .expect("create artist");
+        let album = album_repo
+            .create(chorrosion_domain::Album::new(artist.id, "Refreshed Album"))
+            .await
+            .expect("create album");
+        assert!(artist.last_metadata_refresh_at.is_none());
+
+        let at = DateTime::parse_from_rfc3339("2026-03-01T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        artist_repo
+            .record_metadata_refresh(artist.id, at)
+            .await
+            .expect("record artist refresh");
+        album_repo
+            .record_metadata_refresh(album.id, at)
+            .await
+            .expect("record album refresh");
+
+        let artists = artist_repo.list(10, 0).await.expect("list artists");
+        assert_eq!(artists[0].last_metadata_refresh_at, Some(at));
+        let albums = album_repo.list(10, 0).await.expect("list albums");
+        assert_eq!(albums[0].last_metadata_refresh_at, Some(at));
+    }
+
     #[tokio::test]
     async fn album_with_genre_and_style_tags() {
         let pool = setup_pool().await;
@@ -5324,4 +7115,505 @@ mod tests {
             "tag assignment should be removed"
         );
     }
+
+    /// Simulate a manual edit that bypasses the status triggers.
+    async fn corrupt_status(pool: &SqlitePool, table: &str, id: &str) {
+        sqlx::query(&format!("DROP TRIGGER tr_{table}_constraints_update"))
+            .execute(pool)
+            .await
+            .expect("drop trigger");
+        sqlx::query(&format!("UPDATE {table} SET status = 'bogus' WHERE id = ?"))
+            .bind(id)
+            .execute(pool)
+            .await
+            .expect("corrupt status");
+    }
+
+    #[tokio::test]
+    async fn artist_list_coerces_unknown_status_when_lenient() {
+        let pool = setup_pool().await;
+        let repo = SqliteArtistRepository::new(pool.clone());
+
+        let good = repo
+            .create(chorrosion_domain::Artist::new("Good"))
+            .await
+            .expect("create good");
+        let bad = repo
+            .create(chorrosion_domain::Artist::new("Bad"))
+            .await
+            .expect("create bad");
+        corrupt_status(&pool, "artists", &bad.id.to_string()).await;
+
+        let artists = repo.list(10, 0).await.expect("lenient list succeeds");
+        assert_eq!(artists.len(), 2);
+        assert!(artists.iter().any(|a| a.id == good.id));
+        let coerced = artists.iter().find(|a| a.id == bad.id).expect("bad row");
+        assert_eq!(coerced.status, ArtistStatus::Continuing);
+    }
+
+    #[tokio::test]
+    async fn artist_list_rejects_unknown_status_when_strict() {
+        let pool = setup_pool().await;
+        let repo =
+            SqliteArtistRepository::new(pool.clone()).with_status_parsing(StatusParsing::Strict);
+
+        let bad = repo
+            .create(chorrosion_domain::Artist::new("Bad"))
+            .await
+            .expect("create bad");
+        corrupt_status(&pool, "artists", &bad.id.to_string()).await;
+
+        let error = repo.list(10, 0).await.expect_err("strict list fails");
+        assert!(error.to_string().contains("unknown artist status: bogus"));
+    }
+
+    #[tokio::test]
+    async fn album_list_coerces_unknown_status_when_lenient() {
+        let pool = setup_pool().await;
+        let artist = SqliteArtistRepository::new(pool.clone())
+            .create(chorrosion_domain::Artist::new("Artist"))
+            .await
+            .expect("create artist");
+        let repo = SqliteAlbumRepository::new(pool.clone());
+
+        let mut released = Album::new(artist.id, "Released");
+        released.status = AlbumStatus::Released;
+        let good = repo.create(released).await.expect("create good");
+        let bad = repo
+            .create(Album::new(artist.id, "Bad"))
+            .await
+            .expect("create bad");
+        corrupt_status(&pool, "albums", &bad.id.to_string()).await;
+
+        let albums = repo.list(10, 0).await.expect("lenient list succeeds");
+        assert_eq!(albums.len(), 2);
+        let good = albums.iter().find(|a| a.id == good.id).expect("good row");
+        assert_eq!(good.status, AlbumStatus::Released);
+        let coerced = albums.iter().find(|a| a.id == bad.id).expect("bad row");
+        assert_eq!(coerced.status, AlbumStatus::Wanted);
+
+        let strict = SqliteAlbumRepository::new(pool).with_status_parsing(StatusParsing::Strict);
+        assert!(strict.list(10, 0).await.is_err());
+    }
+
+    fn at(minutes: i64) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-01T00:00:00Z")
+            .expect("valid timestamp")
+            .with_timezone(&Utc)
+            + chrono::Duration::minutes(minutes)
+    }
+
+    #[tokio::test]
+    async fn artists_recently_added_and_updated_since() {
+        let pool = setup_pool().await;
+        let repo = SqliteArtistRepository::new(pool.clone());
+
+        for (name, minutes) in [("Oldest", 0), ("Newest", 20), ("Middle", 10)] {
+            let mut artist = chorrosion_domain::Artist::new(name);
+            artist.created_at = at(minutes);
+            artist.updated_at = at(minutes);
+            repo.create(artist).await.expect("create artist");
+        }
+
+        let recent = repo.list_recently_added(10, 0).await.expect("recent");
+        let names: Vec<_> = recent.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, ["Newest", "Middle", "Oldest"]);
+
+        let page = repo.list_recently_added(1, 1).await.expect("recent page");
+        assert_eq!(page[0].name, "Middle");
+
+        // The boundary is inclusive: a row updated exactly at `since` is returned.
+        let updated = repo.list_updated_since(at(10), 10, 0).await.expect("since");
+        let names: Vec<_> = updated.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, ["Middle", "Newest"]);
+
+        let none = repo
+            .list_updated_since(at(20) + chrono::Duration::milliseconds(1), 10, 0)
+            .await
+            .expect("since after newest");
+        assert!(none.is_empty());
+    }
+
+    #[tokio::test]
+    async fn albums_and_tracks_recently_added_and_updated_since() {
+        let pool = setup_pool().await;
+        let artist = SqliteArtistRepository::new(pool.clone())
+            .create(chorrosion_domain::Artist::new("Artist"))
+            .await
+            .expect("create artist");
+        let albums = SqliteAlbumRepository::new(pool.clone());
+        let tracks = SqliteTrackRepository::new(pool.clone());
+
+        let mut album_ids = Vec::new();
+        for (title, minutes) in [("First", 0), ("Second", 5), ("Third", 10)] {
+            let mut album = Album::new(artist.id, title);
+            album.created_at = at(minutes);
+            album.updated_at = at(minutes);
+            let album = albums.create(album).await.expect("create album");
+
+            let mut track = Track::new(album.id, artist.id, title);
+            track.created_at = at(minutes);
+            track.updated_at = at(minutes);
+            tracks.create(track).await.expect("create track");
+            album_ids.push(album.id);
+        }
+
+        let recent = albums.list_recently_added(2, 0).await.expect("recent");
+        let titles: Vec<_> = recent.iter().map(|a| a.title.as_str()).collect();
+        assert_eq!(titles, ["Third", "Second"]);
+
+        let updated = albums
+            .list_updated_since(at(5), 10, 0)
+            .await
+            .expect("since");
+        let ids: Vec<_> = updated.iter().map(|a| a.id).collect();
+        assert_eq!(ids, album_ids[1..]);
+
+        let recent = tracks.list_recently_added(10, 0).await.expect("recent");
+        let titles: Vec<_> = recent.iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, ["Third", "Second", "First"]);
+
+        let updated = tracks
+            .list_updated_since(at(4), 10, 0)
+            .await
+            .expect("since");
+        let titles: Vec<_> = updated.iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, ["Second", "Third"]);
+    }
+
+    async fn seed_artist_with_album(
+        pool: &SqlitePool,
+        name: &str,
+        foreign_artist_id: Option<&str>,
+    ) -> (Artist, Album) {
+        let mut artist = chorrosion_domain::Artist::new(name);
+        artist.foreign_artist_id = foreign_artist_id.map(str::to_string);
+        let artist = SqliteArtistRepository::new(pool.clone())
+            .create(artist)
+            .await
+            .expect("create artist");
+        let album = SqliteAlbumRepository::new(pool.clone())
+            .create(Album::new(artist.id, format!("{name} Album")))
+            .await
+            .expect("create album");
+        SqliteTrackRepository::new(pool.clone())
+            .create(Track::new(album.id, artist.id, format!("{name} Track")))
+            .await
+            .expect("create track");
+        (artist, album)
+    }
+
+    #[tokio::test]
+    async fn merge_artists_reparents_albums_and_tracks_and_deletes_removed() {
+        let pool = setup_pool().await;
+        let (keep, keep_album) = seed_artist_with_album(&pool, "Keep", Some("mbid:keep")).await;
+        let (remove, remove_album) =
+            seed_artist_with_album(&pool, "Remove", Some("mbid:remove")).await;
+        let artists = SqliteArtistRepository::new(pool.clone());
+
+        let merged = artists
+            .merge_artists(keep.id, remove.id)
+            .await
+            .expect("merge succeeds");
+        assert_eq!(merged.id, keep.id);
+        assert_eq!(merged.foreign_artist_id.as_deref(), Some("mbid:keep"));
+
+        assert!(artists
+            .get_by_id(&remove.id.to_string())
+            .await
+            .expect("fetch removed")
+            .is_none());
+
+        let albums = SqliteAlbumRepository::new(pool.clone())
+            .get_by_artist(keep.id, 10, 0)
+            .await
+            .expect("albums");
+        let album_ids: std::collections::HashSet<_> = albums.iter().map(|a| a.id).collect();
+        assert_eq!(
+            album_ids,
+            std::collections::HashSet::from([keep_album.id, remove_album.id])
+        );
+
+        let tracks = SqliteTrackRepository::new(pool)
+            .get_by_artist(keep.id, 10, 0)
+            .await
+            .expect("tracks");
+        assert_eq!(tracks.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn merge_artists_repoints_relationships_and_tags() {
+        let pool = setup_pool().await;
+        let (keep, _) = seed_artist_with_album(&pool, "Keep", None).await;
+        let (remove, _) = seed_artist_with_album(&pool, "Remove", None).await;
+        let (other, _) = seed_artist_with_album(&pool, "Other", None).await;
+        let relationships = SqliteArtistRelationshipRepository::new(pool.clone());
+        for (source, related, kind) in [
+            (keep.id, other.id, "collaborator"),
+            (remove.id, other.id, "collaborator"),
+            (remove.id, other.id, "member"),
+            (other.id, remove.id, "similar"),
+            (keep.id, remove.id, "related"),
+        ] {
+            relationships
+                .create(ArtistRelationship::new(source, related, kind))
+                .await
+                .expect("create relationship");
+        }
+        let tag = SqliteTagRepository::new(pool.clone())
+            .create(chorrosion_domain::Tag::new("Favorites", None))
+            .await
+            .expect("create tag");
+        let tagged = SqliteTaggedEntityRepository::new(pool.clone());
+        tagged
+            .assign_tag(
+                tag.id,
+                &remove.id.to_string(),
+                chorrosion_domain::EntityType::Artist,
+            )
+            .await
+            .expect("assign tag");
+
+        SqliteArtistRepository::new(pool.clone())
+            .merge_artists(keep.id, remove.id)
+            .await
+            .expect("merge succeeds");
+
+        let mut outgoing: Vec<_> = relationships
+            .get_by_source_artist(keep.id, 10, 0)
+            .await
+            .expect("outgoing relationships")
+            .into_iter()
+            .map(|r| (r.related_artist_id, r.relationship_type))
+            .collect();
+        outgoing.sort_by(|a, b| a.1.cmp(&b.1));
+        assert_eq!(
+            outgoing,
+            [
+                (other.id, "collaborator".to_string()),
+                (other.id, "member".to_string())
+            ]
+        );
+        let incoming: Vec<_> = relationships
+            .get_by_related_artist(keep.id, 10, 0)
+            .await
+            .expect("incoming relationships")
+            .into_iter()
+            .map(|r| (r.source_artist_id, r.relationship_type))
+            .collect();
+        assert_eq!(incoming, [(other.id, "similar".to_string())]);
+
+        assert!(tagged
+            .has_tag(
+                tag.id,
+                &keep.id.to_string(),
+                chorrosion_domain::EntityType::Artist
+            )
+            .await
+            .expect("kept artist tags"));
+        assert!(tagged
+            .get_tags_for_entity(
+                &remove.id.to_string(),
+                chorrosion_domain::EntityType::Artist
+            )
+            .await
+            .expect("removed artist tags")
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn unique_foreign_id_migration_merges_duplicates_into_oldest_row() {
+        let pool = setup_pool().await;
+        // Recreate the pre-migration state the unique indexes now prevent.
+        sqlx::raw_sql(
+            "DROP INDEX ux_artists_foreign_artist_id; DROP INDEX ux_albums_foreign_album_id;\
+             INSERT INTO artists (id, name, foreign_artist_id, created_at, updated_at) VALUES\
+               ('artist-old', 'Portishead', 'mbid:artist', '2024-01-01T00:00:00+00:00', '2024-01-01T00:00:00+00:00'),\
+               ('artist-new', 'Portishead', 'mbid:artist', '2024-02-01T00:00:00+00:00', '2024-02-01T00:00:00+00:00');\
+             INSERT INTO albums (id, artist_id, foreign_album_id, title, created_at, updated_at) VALUES\
+               ('album-old', 'artist-old', 'mbid:album', 'Dummy', '2024-01-01T00:00:00+00:00', '2024-01-01T00:00:00+00:00'),\
+               ('album-new', 'artist-new', 'mbid:album', 'Dummy', '2024-02-01T00:00:00+00:00', '2024-02-01T00:00:00+00:00');\
+             INSERT INTO tracks (id, album_id, artist_id, title) VALUES\
+               ('track-new', 'album-new', 'artist-new', 'Sour Times');\
+             INSERT INTO tags (id, name) VALUES ('tag', 'Trip Hop');\
+             INSERT INTO tagged_entities (tag_id, entity_id, entity_type) VALUES ('tag', 'artist-new', 'artist');",
+        )
+        .execute(&pool)
+        .await
+        .expect("seed duplicates");
+
+        sqlx::raw_sql(include_str!(
+            "../../../migrations/20260425000000_unique_foreign_ids.sql"
+        ))
+        .execute(&pool)
+        .await
+        .expect("migration merges duplicates");
+
+        let artists: Vec<String> = sqlx::query_scalar("SELECT id FROM artists")
+            .fetch_all(&pool)
+            .await
+            .expect("artists");
+        assert_eq!(artists, ["artist-old"]);
+        let albums: Vec<(String, String)> = sqlx::query_as("SELECT id, artist_id FROM albums")
+            .fetch_all(&pool)
+            .await
+            .expect("albums");
+        assert_eq!(
+            albums,
+            [("album-old".to_string(), "artist-old".to_string())]
+        );
+        let track: (String, String) =
+            sqlx::query_as("SELECT album_id, artist_id FROM tracks WHERE id = 'track-new'")
+                .fetch_one(&pool)
+                .await
+                .expect("track survives the merge");
+        assert_eq!(track, ("album-old".to_string(), "artist-old".to_string()));
+        let tagged: Vec<String> = sqlx::query_scalar("SELECT entity_id FROM tagged_entities")
+            .fetch_all(&pool)
+            .await
+            .expect("tagged entities");
+        assert_eq!(tagged, ["artist-old"]);
+    }
+
+    #[tokio::test]
+    async fn merge_artists_adopts_foreign_id_when_kept_has_none() {
+        let pool = setup_pool().await;
+        let (keep, _) = seed_artist_with_album(&pool, "Keep", None).await;
+        let (remove, _) = seed_artist_with_album(&pool, "Remove", Some("mbid:remove")).await;
+
+        let merged = SqliteArtistRepository::new(pool)
+            .merge_artists(keep.id, remove.id)
+            .await
+            .expect("merge succeeds");
+        assert_eq!(merged.foreign_artist_id.as_deref(), Some("mbid:remove"));
+    }
+
+    #[tokio::test]
+    async fn merge_artists_rolls_back_when_reparent_fails() {
+        let pool = setup_pool().await;
+        let (keep, _) = seed_artist_with_album(&pool, "Keep", Some("mbid:keep")).await;
+        let (remove, remove_album) = seed_artist_with_album(&pool, "Remove", None).await;
+        sqlx::query(
+            "CREATE TRIGGER fail_track_reparent BEFORE UPDATE OF artist_id ON tracks \
+             BEGIN SELECT RAISE(ABORT, 'track re-parent failed'); END",
+        )
+        .execute(&pool)
+        .await
+        .expect("create failing trigger");
+        let artists = SqliteArtistRepository::new(pool.clone());
+
+        let error = artists
+            .merge_artists(keep.id, remove.id)
+            .await
+            .expect_err("merge fails");
+        assert!(error.to_string().contains("track re-parent failed"));
+
+        assert!(artists
+            .get_by_id(&remove.id.to_string())
+            .await
+            .expect("fetch removed")
+            .is_some());
+        let album = SqliteAlbumRepository::new(pool)
+            .get_by_id(&remove_album.id.to_string())
+            .await
+            .expect("fetch album")
+            .expect("album exists");
+        assert_eq!(album.artist_id, remove.id, "album re-parent is rolled back");
+    }
+
+    #[tokio::test]
+    async fn merge_artists_rejects_self_merge() {
+        let pool = setup_pool().await;
+        let (keep, _) = seed_artist_with_album(&pool, "Keep", None).await;
+
+        assert!(SqliteArtistRepository::new(pool)
+            .merge_artists(keep.id, keep.id)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn artist_upsert_by_foreign_id_inserts_then_updates() {
+        let pool = setup_pool().await;
+        let repo = SqliteArtistRepository::new(pool.clone());
+
+        let mut first = chorrosion_domain::Artist::new("Original Name");
+        first.foreign_artist_id = Some("mbid:upsert".to_string());
+        let inserted = repo
+            .upsert_by_foreign_id(first.clone())
+            .await
+            .expect("first upsert inserts");
+        assert_eq!(inserted.id, first.id);
+
+        let mut unmonitored = inserted.clone();
+        unmonitored.monitored = false;
+        repo.update(unmonitored).await.expect("unmonitor");
+
+        // A refresh builds a fresh entity with a new id for the same foreign id.
+        let mut refreshed = chorrosion_domain::Artist::new("Renamed");
+        refreshed.foreign_artist_id = Some("mbid:upsert".to_string());
+        let updated = repo
+            .upsert_by_foreign_id(refreshed.clone())
+            .await
+            .expect("second upsert updates");
+        assert_eq!(updated.id, first.id, "persisted id is the existing row's");
+        assert_ne!(updated.id, refreshed.id);
+        assert_eq!(updated.name, "Renamed");
+        assert!(!updated.monitored, "user-managed fields are preserved");
+
+        assert_eq!(repo.list(10, 0).await.expect("list").len(), 1);
+    }
+
+    #[tokio::test]
+    async fn artist_upsert_without_foreign_id_always_inserts() {
+        let pool = setup_pool().await;
+        let repo = SqliteArtistRepository::new(pool.clone());
+
+        let a = repo
+            .upsert_by_foreign_id(chorrosion_domain::Artist::new("Same"))
+            .await
+            .expect("insert a");
+        let b = repo
+            .upsert_by_foreign_id(chorrosion_domain::Artist::new("Same"))
+            .await
+            .expect("insert b");
+        assert_ne!(a.id, b.id);
+        assert_eq!(repo.list(10, 0).await.expect("list").len(), 2);
+    }
+
+    #[tokio::test]
+    async fn album_upsert_by_foreign_id_inserts_then_updates() {
+        let pool = setup_pool().await;
+        let artist = SqliteArtistRepository::new(pool.clone())
+            .create(chorrosion_domain::Artist::new("Artist"))
+            .await
+            .expect("create artist");
+        let repo = SqliteAlbumRepository::new(pool.clone());
+
+        let mut first = Album::new(artist.id, "Original Title");
+        first.foreign_album_id = Some("rg:upsert".to_string());
+        let inserted = repo
+            .upsert_by_foreign_id(first.clone())
+            .await
+            .expect("first upsert inserts");
+        assert_eq!(inserted.id, first.id);
+
+        let mut refreshed = Album::new(artist.id, "New Title");
+        refreshed.foreign_album_id = Some("rg:upsert".to_string());
+        refreshed.status = AlbumStatus::Released;
+        let updated = repo
+            .upsert_by_foreign_id(refreshed)
+            .await
+            .expect("second upsert updates");
+        assert_eq!(updated.id, first.id);
+        assert_eq!(updated.title, "New Title");
+        assert_eq!(updated.status, AlbumStatus::Wanted, "status is preserved");
+
+        let no_foreign_id = repo
+            .upsert_by_foreign_id(Album::new(artist.id, "New Title"))
+            .await
+            .expect("insert without foreign id");
+        assert_ne!(no_foreign_id.id, first.id);
+        assert_eq!(repo.list(10, 0).await.expect("list").len(), 2);
+    }
 }
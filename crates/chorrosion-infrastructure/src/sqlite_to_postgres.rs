@@ -2,7 +2,7 @@
 #![cfg(feature = "postgres")]
 
 use anyhow::{anyhow, Result};
-use chrono::{NaiveDate, NaiveDateTime};
+use chrono::NaiveDateTime;
 use sqlx::{FromRow, PgPool, SqlitePool};
 use std::collections::{HashSet, VecDeque};
 
@@ -319,7 +319,7 @@ pub async fn migrate_sqlite_to_postgres_with_options(
     let mut offset = 0;
     loop {
         let quality_profiles = sqlx::query_as::<_, QualityProfileRow>(
-            "SELECT id, name, allowed_qualities, upgrade_allowed, cutoff_quality, created_at, updated_at FROM quality_profiles ORDER BY id LIMIT ? OFFSET ?",
+            "SELECT id, name, allowed_qualities, upgrade_allowed, cutoff_quality, is_default, created_at, updated_at FROM quality_profiles ORDER BY id LIMIT ? OFFSET ?",
         )
         .bind(options.sqlite_batch_size)
         .bind(offset)
@@ -332,13 +332,14 @@ pub async fn migrate_sqlite_to_postgres_with_options(
 
         for row in &quality_profiles {
             sqlx::query(
-                "INSERT INTO quality_profiles (id, name, allowed_qualities, upgrade_allowed, cutoff_quality, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, $6, $7)",
+                "INSERT INTO quality_profiles (id, name, allowed_qualities, upgrade_allowed, cutoff_quality, is_default, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
             )
             .bind(&row.id)
             .bind(&row.name)
             .bind(&row.allowed_qualities)
             .bind(row.upgrade_allowed)
             .bind(&row.cutoff_quality)
+            .bind(row.is_default)
             .bind(row.created_at)
             .bind(row.updated_at)
             .execute(&mut *tx)
@@ -349,7 +350,7 @@ pub async fn migrate_sqlite_to_postgres_with_options(
     let mut offset = 0;
     loop {
         let metadata_profiles = sqlx::query_as::<_, MetadataProfileRow>(
-            "SELECT id, name, primary_album_types, secondary_album_types, release_statuses, created_at, updated_at FROM metadata_profiles ORDER BY id LIMIT ? OFFSET ?",
+            "SELECT id, name, primary_album_types, secondary_album_types, release_statuses, is_default, created_at, updated_at FROM metadata_profiles ORDER BY id LIMIT ? OFFSET ?",
         )
         .bind(options.sqlite_batch_size)
         .bind(offset)
@@ -362,13 +363,14 @@ pub async fn migrate_sqlite_to_postgres_with_options(
 
         for row in &metadata_profiles {
             sqlx::query(
-                "INSERT INTO metadata_profiles (id, name, primary_album_types, secondary_album_types, release_statuses, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, $6, $7)",
+                "INSERT INTO metadata_profiles (id, name, primary_album_types, secondary_album_types, release_statuses, is_default, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
             )
             .bind(&row.id)
             .bind(&row.name)
             .bind(&row.primary_album_types)
             .bind(&row.secondary_album_types)
             .bind(&row.release_statuses)
+            .bind(row.is_default)
             .bind(row.created_at)
             .bind(row.updated_at)
             .execute(&mut *tx)
@@ -379,7 +381,7 @@ pub async fn migrate_sqlite_to_postgres_with_options(
     let mut offset = 0;
     loop {
         let artists = sqlx::query_as::<_, ArtistRow>(
-            "SELECT id, name, foreign_artist_id, musicbrainz_artist_id, metadata_profile_id, quality_profile_id, status, path, monitored, artist_type, sort_name, country, disambiguation, genre_tags, style_tags, created_at, updated_at FROM artists ORDER BY id LIMIT ? OFFSET ?",
+            "SELECT id, name, foreign_artist_id, musicbrainz_artist_id, metadata_profile_id, quality_profile_id, status, path, monitored, artist_type, sort_name, country, disambiguation, genre_tags, style_tags, last_metadata_refresh_at, created_at, updated_at FROM artists ORDER BY id LIMIT ? OFFSET ?",
         )
         .bind(options.sqlite_batch_size)
         .bind(offset)
@@ -392,7 +394,7 @@ pub async fn migrate_sqlite_to_postgres_with_options(
 
         for row in &artists {
             sqlx::query(
-                "INSERT INTO artists (id, name, foreign_artist_id, musicbrainz_artist_id, metadata_profile_id, quality_profile_id, status, path, monitored, artist_type, sort_name, country, disambiguation, genre_tags, style_tags, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17)",
+                "INSERT INTO artists (id, name, foreign_artist_id, musicbrainz_artist_id, metadata_profile_id, quality_profile_id, status, path, monitored, artist_type, sort_name, country, disambiguation, genre_tags, style_tags, last_metadata_refresh_at, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18)",
             )
             .bind(&row.id)
             .bind(&row.name)
@@ -409,6 +411,7 @@ pub async fn migrate_sqlite_to_postgres_with_options(
             .bind(&row.disambiguation)
             .bind(&row.genre_tags)
             .bind(&row.style_tags)
+            .bind(row.last_metadata_refresh_at)
             .bind(row.created_at)
             .bind(row.updated_at)
             .execute(&mut *tx)
@@ -419,7 +422,7 @@ pub async fn migrate_sqlite_to_postgres_with_options(
     let mut offset = 0;
     loop {
         let albums = sqlx::query_as::<_, AlbumRow>(
-            "SELECT id, artist_id, foreign_album_id, title, release_date, album_type, status, monitored, musicbrainz_release_group_id, musicbrainz_release_id, primary_type, secondary_types, first_release_date, genre_tags, style_tags, created_at, updated_at FROM albums ORDER BY id LIMIT ? OFFSET ?",
+            "SELECT id, artist_id, foreign_album_id, title, CAST(release_date AS TEXT) AS release_date, release_date_precision, album_type, status, monitored, musicbrainz_release_group_id, musicbrainz_release_id, primary_type, secondary_types, first_release_date, genre_tags, style_tags, last_metadata_refresh_at, created_at, updated_at FROM albums ORDER BY id LIMIT ? OFFSET ?",
         )
         .bind(options.sqlite_batch_size)
         .bind(offset)
@@ -432,13 +435,14 @@ pub async fn migrate_sqlite_to_postgres_with_options(
 
         for row in &albums {
             sqlx::query(
-                "INSERT INTO albums (id, artist_id, foreign_album_id, title, release_date, album_type, status, monitored, musicbrainz_release_group_id, musicbrainz_release_id, primary_type, secondary_types, first_release_date, genre_tags, style_tags, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17)",
+                "INSERT INTO albums (id, artist_id, foreign_album_id, title, release_date, release_date_precision, album_type, status, monitored, musicbrainz_release_group_id, musicbrainz_release_id, primary_type, secondary_types, first_release_date, genre_tags, style_tags, last_metadata_refresh_at, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19)",
             )
             .bind(&row.id)
             .bind(&row.artist_id)
             .bind(&row.foreign_album_id)
             .bind(&row.title)
-            .bind(row.release_date)
+            .bind(&row.release_date)
+            .bind(&row.release_date_precision)
             .bind(&row.album_type)
             .bind(&row.status)
             .bind(row.monitored)
@@ -449,6 +453,7 @@ pub async fn migrate_sqlite_to_postgres_with_options(
             .bind(&row.first_release_date)
             .bind(&row.genre_tags)
             .bind(&row.style_tags)
+            .bind(row.last_metadata_refresh_at)
             .bind(row.created_at)
             .bind(row.updated_at)
             .execute(&mut *tx)
@@ -1008,6 +1013,7 @@ struct QualityProfileRow {
     allowed_qualities: String,
     upgrade_allowed: bool,
     cutoff_quality: Option<String>,
+    is_default: bool,
     created_at: NaiveDateTime,
     updated_at: NaiveDateTime,
 }
@@ -1019,6 +1025,7 @@ struct MetadataProfileRow {
     primary_album_types: Option<String>,
     secondary_album_types: Option<String>,
     release_statuses: Option<String>,
+    is_default: bool,
     created_at: NaiveDateTime,
     updated_at: NaiveDateTime,
 }
@@ -1040,6 +1047,7 @@ struct ArtistRow {
     disambiguation: Option<String>,
     genre_tags: Option<String>,
     style_tags: Option<String>,
+    last_metadata_refresh_at: Option<NaiveDateTime>,
     created_at: NaiveDateTime,
     updated_at: NaiveDateTime,
 }
@@ -1050,7 +1058,8 @@ struct AlbumRow {
     artist_id: String,
     foreign_album_id: Option<String>,
     title: String,
-    release_date: Option<NaiveDate>,
+    release_date: Option<String>,
+    release_date_precision: Option<String>,
     album_type: Option<String>,
     status: String,
     monitored: bool,
@@ -1061,6 +1070,7 @@ struct AlbumRow {
     first_release_date: Option<String>,
     genre_tags: Option<String>,
     style_tags: Option<String>,
+    last_metadata_refresh_at: Option<NaiveDateTime>,
     created_at: NaiveDateTime,
     updated_at: NaiveDateTime,
 }
@@ -120,6 +120,13 @@ impl CoverArtFallbackClient {
         }
     }
 
+    /// Sends Cover Art Archive requests through `client`, e.g. one built with the
+    /// configured outbound proxy. A Fanart.tv client keeps its own HTTP client.
+    pub fn with_http_client(mut self, client: Client) -> Self {
+        self.cover_art_archive_client.client = client;
+        self
+    }
+
     #[instrument(skip(self), fields(release_group_mbid = release_group_mbid))]
     pub async fn fetch_album_cover(
         &self,
@@ -111,6 +111,13 @@ impl FanartTvClient {
         }
     }
 
+    /// Sends requests through `client`, e.g. one built with the configured outbound
+    /// proxy. The client should carry its own request timeout.
+    pub fn with_http_client(mut self, client: Client) -> Self {
+        self.client = client;
+        self
+    }
+
     #[instrument(skip(self), fields(artist_mbid = artist_mbid))]
     pub async fn fetch_artist_artwork(
         &self,
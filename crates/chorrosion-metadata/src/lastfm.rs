@@ -111,6 +111,13 @@ impl LastFmClient {
         }
     }
 
+    /// Sends requests through `client`, e.g. one built with the configured outbound
+    /// proxy. The client should carry its own request timeout.
+    pub fn with_http_client(mut self, client: Client) -> Self {
+        self.client = client;
+        self
+    }
+
     /// Fetches metadata for an artist.
     #[instrument(skip(self), fields(artist = artist_name))]
     pub async fn fetch_artist_metadata(
@@ -97,6 +97,13 @@ impl LyricsClient {
         }
     }
 
+    /// Sends requests through `client`, e.g. one built with the configured outbound
+    /// proxy. The client should carry its own request timeout.
+    pub fn with_http_client(mut self, client: Client) -> Self {
+        self.client = client;
+        self
+    }
+
     #[instrument(skip(self), fields(artist = artist_name, title = track_title))]
     pub async fn fetch_lyrics(
         &self,
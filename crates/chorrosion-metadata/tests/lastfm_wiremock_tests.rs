@@ -139,3 +139,38 @@ async fn test_fetch_artist_metadata_maps_lastfm_api_error() {
         other => panic!("expected LastFmError::Api, got {other}"),
     }
 }
+
+#[tokio::test]
+async fn test_injected_http_client_routes_requests_through_its_proxy() {
+    let proxy = MockServer::start().await;
+
+    let body = serde_json::json!({
+        "artist": {
+            "name": "Proxied Artist"
+        }
+    });
+
+    Mock::given(method("GET"))
+        .and(path("/2.0/"))
+        .and(query_param("artist", "Proxied Artist"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .expect(1)
+        .mount(&proxy)
+        .await;
+
+    let proxied = reqwest::Client::builder()
+        .proxy(reqwest::Proxy::all(proxy.uri()).expect("valid proxy URL"))
+        .build()
+        .expect("client should build");
+    let client = LastFmClient::new(
+        "test_api_key".to_string(),
+        Some("http://lastfm.invalid/2.0/".to_string()),
+    )
+    .with_http_client(proxied);
+    let metadata = client
+        .fetch_artist_metadata("Proxied Artist")
+        .await
+        .expect("request should reach the proxy");
+
+    assert_eq!(metadata.name, "Proxied Artist");
+}
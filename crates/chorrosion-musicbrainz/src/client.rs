@@ -2,8 +2,8 @@
 
 use crate::error::{MusicBrainzError, Result};
 use crate::models::{
-    Album, AlbumSearchResult, Artist, ArtistSearchResult, CoverArtResponse, Recording, SearchQuery,
-    SearchResponse,
+    Album, AlbumSearchResult, Artist, ArtistSearchResult, CoverArtResponse, IsrcLookupResult,
+    Recording, ReleaseBrowseResult, SearchQuery, SearchResponse,
 };
 use crate::rate_limiter::RateLimiter;
 use moka::sync::Cache;
@@ -15,8 +15,8 @@ use url::Url;
 use uuid::Uuid;
 
 const MUSICBRAINZ_API_BASE: &str = "https://musicbrainz.org/ws/2";
-const COVER_ART_ARCHIVE_BASE: &str = "https://coverartarchive.org";
-const USER_AGENT: &str = concat!(
+pub(crate) const COVER_ART_ARCHIVE_BASE: &str = "https://coverartarchive.org";
+pub(crate) const USER_AGENT: &str = concat!(
     "Chorrosion/",
     env!("CARGO_PKG_VERSION"),
     " ( https://github.com/SvetaKrava/chorrosion )"
@@ -38,6 +38,31 @@ where
         .build()
 }
 
+/// Validate an ISRC and return it in canonical (uppercase, unhyphenated) form.
+///
+/// An ISRC is a two-letter country code, a three-character alphanumeric
+/// registrant code, and seven digits (year of reference plus designation code).
+fn normalize_isrc(isrc: &str) -> Result<String> {
+    let normalized: String = isrc
+        .trim()
+        .chars()
+        .filter(|c| *c != '-')
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+
+    let bytes = normalized.as_bytes();
+    let valid = bytes.len() == 12
+        && bytes[..2].iter().all(u8::is_ascii_uppercase)
+        && bytes[2..5].iter().all(u8::is_ascii_alphanumeric)
+        && bytes[5..].iter().all(u8::is_ascii_digit);
+
+    if valid {
+        Ok(normalized)
+    } else {
+        Err(MusicBrainzError::InvalidIsrc(isrc.to_string()))
+    }
+}
+
 /// MusicBrainz API client with rate limiting.
 #[derive(Debug, Clone)]
 pub struct MusicBrainzClient {
@@ -101,7 +126,7 @@ impl MusicBrainzClient {
         self.get(url.as_str()).await
     }
 
-    /// Look up an artist by MusicBrainz ID.
+    /// Look up an artist by MusicBrainz ID, including its genres.
     ///
     /// # Arguments
     /// * `mbid` - MusicBrainz artist ID.
@@ -122,7 +147,7 @@ impl MusicBrainzClient {
             debug!(target: "musicbrainz", %mbid, "artist lookup cache HIT");
             return Ok(cached);
         }
-        let url = format!("{}/artist/{}?fmt=json", self.base_url, mbid);
+        let url = format!("{}/artist/{}?inc=genres&fmt=json", self.base_url, mbid);
         let artist: Artist = self.get(&url).await?;
         self.artist_lookup_cache.insert(mbid, artist.clone());
         Ok(artist)
@@ -197,6 +222,93 @@ impl MusicBrainzClient {
         Ok(album)
     }
 
+    /// Browse all release groups credited to an artist, one page at a time.
+    ///
+    /// Unlike a search, browsing returns every release group linked to the
+    /// artist, including secondary types (live, compilation, ...) so callers
+    /// can filter them against a metadata profile.
+    ///
+    /// # Arguments
+    /// * `artist_mbid` - MusicBrainz artist ID.
+    /// * `limit` - Maximum number of results (default 25, max 100).
+    /// * `offset` - Offset for pagination (default 0).
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use chorrosion_musicbrainz::MusicBrainzClient;
+    /// # use uuid::Uuid;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = MusicBrainzClient::new()?;
+    /// let mbid = Uuid::parse_str("a74b1b7f-71a5-4011-9441-d0b5e4122711")?; // Radiohead
+    /// let page = client
+    ///     .browse_release_groups_by_artist(&mbid, Some(100), Some(0))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn browse_release_groups_by_artist(
+        &self,
+        artist_mbid: &Uuid,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<SearchResponse<AlbumSearchResult>> {
+        let mut url = Url::parse(&format!("{}/release-group", self.base_url))
+            .map_err(|e| MusicBrainzError::InvalidResponse(e.to_string()))?;
+
+        url.query_pairs_mut()
+            .append_pair("artist", &artist_mbid.to_string())
+            .append_pair("inc", "artist-credits")
+            .append_pair("fmt", "json");
+
+        if let Some(limit) = limit {
+            url.query_pairs_mut()
+                .append_pair("limit", &limit.to_string());
+        }
+
+        if let Some(offset) = offset {
+            url.query_pairs_mut()
+                .append_pair("offset", &offset.to_string());
+        }
+
+        self.get(url.as_str()).await
+    }
+
+    /// Browse all releases credited to an artist, one page at a time.
+    ///
+    /// Each release carries its status (official, bootleg, ...) and the release
+    /// group it belongs to, which release group browsing does not report.
+    ///
+    /// # Arguments
+    /// * `artist_mbid` - MusicBrainz artist ID.
+    /// * `limit` - Maximum number of results (default 25, max 100).
+    /// * `offset` - Offset for pagination (default 0).
+    pub async fn browse_releases_by_artist(
+        &self,
+        artist_mbid: &Uuid,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<SearchResponse<ReleaseBrowseResult>> {
+        let mut url = Url::parse(&format!("{}/release", self.base_url))
+            .map_err(|e| MusicBrainzError::InvalidResponse(e.to_string()))?;
+
+        url.query_pairs_mut()
+            .append_pair("artist", &artist_mbid.to_string())
+            .append_pair("inc", "release-groups")
+            .append_pair("fmt", "json");
+
+        if let Some(limit) = limit {
+            url.query_pairs_mut()
+                .append_pair("limit", &limit.to_string());
+        }
+
+        if let Some(offset) = offset {
+            url.query_pairs_mut()
+                .append_pair("offset", &offset.to_string());
+        }
+
+        self.get(url.as_str()).await
+    }
+
     /// Look up a recording (track) by MusicBrainz ID, including artist credits and releases.
     ///
     /// # Arguments
@@ -227,6 +339,38 @@ impl MusicBrainzClient {
         Ok(recording)
     }
 
+    /// Look up the recordings associated with an ISRC.
+    ///
+    /// The ISRC is validated locally (two letters, three alphanumerics, seven
+    /// digits; hyphens and case are ignored) before any request is made. An
+    /// ISRC unknown to MusicBrainz yields an empty list.
+    ///
+    /// # Arguments
+    /// * `isrc` - International Standard Recording Code, e.g. `GBAYE9700245`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use chorrosion_musicbrainz::MusicBrainzClient;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = MusicBrainzClient::new()?;
+    /// let recordings = client.lookup_recording_by_isrc("GBAYE9700245").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn lookup_recording_by_isrc(&self, isrc: &str) -> Result<Vec<Recording>> {
+        let isrc = normalize_isrc(isrc)?;
+        let url = format!("{}/isrc/{}?fmt=json&inc=artists", self.base_url, isrc);
+
+        match self.get::<IsrcLookupResult>(&url).await {
+            Ok(result) => Ok(result.recordings),
+            Err(MusicBrainzError::NotFound(_)) => {
+                debug!(target: "musicbrainz", %isrc, "no recordings for ISRC");
+                Ok(Vec::new())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
     /// Fetch cover art metadata for a release group from the Cover Art Archive.
     /// Results are cached in-memory with a 24-hour TTL.
     pub async fn fetch_cover_art(&self, release_group_mbid: Uuid) -> Result<CoverArtResponse> {
@@ -253,12 +397,7 @@ impl MusicBrainzClient {
 
         trace!(target: "musicbrainz", "GET {}", url);
 
-        let response = self
-            .client
-            .get(url)
-            .header("User-Agent", USER_AGENT)
-            .send()
-            .await?;
+        let response = self.client.get(url).send().await?;
 
         let status = response.status();
         debug!(target: "musicbrainz", "response status: {}", status);
@@ -323,6 +462,9 @@ pub struct MusicBrainzClientBuilder {
     cover_art_base_url: String,
     timeout: Duration,
     rate_limit_interval: Duration,
+    rate_limiter: Option<RateLimiter>,
+    proxy_url: Option<String>,
+    user_agent: String,
 }
 
 impl Default for MusicBrainzClientBuilder {
@@ -332,6 +474,9 @@ impl Default for MusicBrainzClientBuilder {
             cover_art_base_url: COVER_ART_ARCHIVE_BASE.to_string(),
             timeout: Duration::from_secs(30),
             rate_limit_interval: Duration::from_secs(1),
+            rate_limiter: None,
+            proxy_url: None,
+            user_agent: USER_AGENT.to_string(),
         }
     }
 }
@@ -361,14 +506,42 @@ impl MusicBrainzClientBuilder {
         self
     }
 
+    /// Pace requests through an existing rate limiter instead of creating one.
+    ///
+    /// Clients built with clones of the same limiter share a single pacing gate,
+    /// so together they never exceed its rate. Overrides [`Self::rate_limit_interval`].
+    pub fn rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Send every request through the HTTP proxy at `url`.
+    pub fn proxy_url(mut self, url: impl Into<String>) -> Self {
+        self.proxy_url = Some(url.into());
+        self
+    }
+
+    /// Send `user_agent` instead of the default Chorrosion User-Agent.
+    ///
+    /// MusicBrainz asks for an application name, version and contact URL or email.
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
     /// Build the MusicBrainz client.
     pub fn build(self) -> Result<MusicBrainzClient> {
-        let client = Client::builder()
+        let mut client = Client::builder()
             .timeout(self.timeout)
-            .user_agent(USER_AGENT)
-            .build()?;
+            .user_agent(self.user_agent);
+        if let Some(proxy_url) = &self.proxy_url {
+            client = client.proxy(reqwest::Proxy::all(proxy_url)?);
+        }
+        let client = client.build()?;
 
-        let rate_limiter = RateLimiter::new(self.rate_limit_interval);
+        let rate_limiter = self
+            .rate_limiter
+            .unwrap_or_else(|| RateLimiter::new(self.rate_limit_interval));
 
         Ok(MusicBrainzClient {
             client,
@@ -4,7 +4,7 @@
 mod tests {
     use crate::{MusicBrainzClient, SearchQuery};
     use uuid::Uuid;
-    use wiremock::matchers::{method, path, query_param};
+    use wiremock::matchers::{header, method, path, query_param};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     const RADIOHEAD_MBID: &str = "a74b1b7f-71a5-4011-9441-d0b5e4122711";
@@ -34,7 +34,12 @@ mod tests {
             "name": "Radiohead",
             "sort-name": "Radiohead",
             "type": "Group",
-            "country": "GB"
+            "country": "GB",
+            "life-span": { "begin": "1991", "end": null, "ended": false },
+            "genres": [
+                { "name": "alternative rock", "count": 12 },
+                { "name": "art rock", "count": 9 }
+            ]
         })
     }
 
@@ -108,6 +113,29 @@ mod tests {
         })
     }
 
+    fn release_group_browse_response() -> serde_json::Value {
+        serde_json::json!({
+            "release-group-count": 42,
+            "release-group-offset": 25,
+            "release-groups": [
+                {
+                    "id": OK_COMPUTER_MBID,
+                    "title": "OK Computer",
+                    "primary-type": "Album",
+                    "secondary-types": [],
+                    "first-release-date": "1997-05-21"
+                },
+                {
+                    "id": "0b6b4ba0-d36f-47bd-b4ea-6a5b91842d29",
+                    "title": "I Might Be Wrong: Live Recordings",
+                    "primary-type": "Album",
+                    "secondary-types": ["Live"],
+                    "first-release-date": "2001-11-12"
+                }
+            ]
+        })
+    }
+
     fn cover_art_response() -> serde_json::Value {
         serde_json::json!({
             "images": [{
@@ -182,6 +210,7 @@ mod tests {
         Mock::given(method("GET"))
             .and(path(format!("/artist/{}", RADIOHEAD_MBID)))
             .and(query_param("fmt", "json"))
+            .and(query_param("inc", "genres"))
             .respond_with(ResponseTemplate::new(200).set_body_json(artist_lookup_response()))
             .mount(&mock_server)
             .await;
@@ -197,6 +226,11 @@ mod tests {
         assert_eq!(artist.name, "Radiohead");
         assert_eq!(artist.id, mbid);
         assert_eq!(artist.country, Some("GB".to_string()));
+        assert_eq!(
+            artist.life_span.as_ref().and_then(|span| span.ended),
+            Some(false)
+        );
+        assert_eq!(artist.genres[0].name, "alternative rock");
     }
 
     #[tokio::test]
@@ -252,6 +286,91 @@ mod tests {
         assert_eq!(album.primary_type, Some("Album".to_string()));
     }
 
+    #[tokio::test]
+    async fn test_browse_release_groups_by_artist() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/release-group"))
+            .and(query_param("artist", RADIOHEAD_MBID))
+            .and(query_param("limit", "25"))
+            .and(query_param("offset", "25"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(release_group_browse_response()))
+            .mount(&mock_server)
+            .await;
+
+        let client = MusicBrainzClient::builder()
+            .base_url(mock_server.uri())
+            .build()
+            .unwrap();
+
+        let artist_mbid = Uuid::parse_str(RADIOHEAD_MBID).unwrap();
+        let response = client
+            .browse_release_groups_by_artist(&artist_mbid, Some(25), Some(25))
+            .await
+            .unwrap();
+
+        assert_eq!(response.count, 42);
+        assert_eq!(response.offset, 25);
+        assert_eq!(response.results.release_groups.len(), 2);
+
+        let studio = &response.results.release_groups[0];
+        assert_eq!(studio.primary_type, Some("Album".to_string()));
+        assert!(studio.secondary_types.is_empty());
+
+        let live = &response.results.release_groups[1];
+        assert_eq!(live.primary_type, Some("Album".to_string()));
+        assert_eq!(live.secondary_types, vec!["Live".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_browse_releases_by_artist() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/release"))
+            .and(query_param("artist", RADIOHEAD_MBID))
+            .and(query_param("inc", "release-groups"))
+            .and(query_param("offset", "0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "release-count": 7,
+                "release-offset": 0,
+                "releases": [{
+                    "id": "52709206-8816-3c12-9ff6-f957f2f1eecf",
+                    "title": "OK Computer",
+                    "status": "Official",
+                    "country": "GB",
+                    "date": "1997-05-21",
+                    "release-group": {
+                        "id": OK_COMPUTER_MBID,
+                        "title": "OK Computer",
+                        "primary-type": "Album"
+                    }
+                }]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = MusicBrainzClient::builder()
+            .base_url(mock_server.uri())
+            .build()
+            .unwrap();
+
+        let artist_mbid = Uuid::parse_str(RADIOHEAD_MBID).unwrap();
+        let response = client
+            .browse_releases_by_artist(&artist_mbid, Some(100), Some(0))
+            .await
+            .unwrap();
+
+        assert_eq!(response.count, 7);
+        let release = &response.results.releases[0];
+        assert_eq!(release.status.as_deref(), Some("Official"));
+        assert_eq!(
+            release.release_group.id,
+            Uuid::parse_str(OK_COMPUTER_MBID).unwrap()
+        );
+    }
+
     #[tokio::test]
     async fn test_lookup_recording() {
         let mock_server = MockServer::start().await;
@@ -285,6 +404,88 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_lookup_recording_by_isrc() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/isrc/GBAYE9700245"))
+            .and(query_param("fmt", "json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "isrc": "GBAYE9700245",
+                "recordings": [recording_lookup_response()]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = MusicBrainzClient::builder()
+            .base_url(mock_server.uri())
+            .build()
+            .unwrap();
+
+        let recordings = client
+            .lookup_recording_by_isrc("gb-aye-97-00245")
+            .await
+            .unwrap();
+
+        assert_eq!(recordings.len(), 1);
+        assert_eq!(recordings[0].title, "Paranoid Android");
+        assert_eq!(
+            recordings[0].id,
+            Uuid::parse_str(PARANOID_ANDROID_RECORDING_MBID).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_lookup_recording_by_unmatched_isrc_returns_empty() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/isrc/USRC17607839"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let client = MusicBrainzClient::builder()
+            .base_url(mock_server.uri())
+            .build()
+            .unwrap();
+
+        let recordings = client
+            .lookup_recording_by_isrc("USRC17607839")
+            .await
+            .unwrap();
+
+        assert!(recordings.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_lookup_recording_by_malformed_isrc_is_rejected_locally() {
+        let mock_server = MockServer::start().await;
+
+        let client = MusicBrainzClient::builder()
+            .base_url(mock_server.uri())
+            .build()
+            .unwrap();
+
+        for isrc in [
+            "",
+            "GBAYE970024",
+            "1BAYE9700245",
+            "GBAYE97002X5",
+            "GB_YE9700245",
+        ] {
+            let result = client.lookup_recording_by_isrc(isrc).await;
+            assert!(
+                matches!(result, Err(crate::MusicBrainzError::InvalidIsrc(_))),
+                "{isrc:?} should be rejected"
+            );
+        }
+
+        let requests = mock_server.received_requests().await.unwrap();
+        assert!(requests.is_empty());
+    }
+
     #[tokio::test]
     async fn test_fetch_cover_art_cached() {
         let mock_server = MockServer::start().await;
@@ -363,4 +564,99 @@ mod tests {
             crate::MusicBrainzError::RateLimitExceeded
         ));
     }
+
+    #[tokio::test]
+    async fn test_clients_sharing_rate_limiter_are_paced_together() {
+        use crate::RateLimiter;
+        use std::time::{Duration, Instant};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/artist"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(artist_search_response()))
+            .expect(4)
+            .mount(&mock_server)
+            .await;
+
+        let interval = Duration::from_millis(100);
+        let limiter = RateLimiter::new(interval);
+        let artists_client = MusicBrainzClient::builder()
+            .base_url(mock_server.uri())
+            .rate_limiter(limiter.clone())
+            .build()
+            .unwrap();
+        let albums_client = MusicBrainzClient::builder()
+            .base_url(mock_server.uri())
+            .rate_limiter(limiter)
+            .build()
+            .unwrap();
+
+        let search_twice = |client: &MusicBrainzClient| {
+            let client = client.clone();
+            async move {
+                for _ in 0..2 {
+                    client
+                        .search_artists(SearchQuery::new("Radiohead"))
+                        .await
+                        .unwrap();
+                }
+            }
+        };
+
+        let start = Instant::now();
+        tokio::join!(search_twice(&artists_client), search_twice(&albums_client));
+        let elapsed = start.elapsed();
+
+        // Four requests through one gate need at least three full intervals.
+        assert!(
+            elapsed >= interval * 3,
+            "expected >= {:?}, got {:?}",
+            interval * 3,
+            elapsed
+        );
+    }
+
+    #[tokio::test]
+    async fn requests_send_default_user_agent() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/artist"))
+            .and(header("user-agent", crate::client::USER_AGENT))
+            .respond_with(ResponseTemplate::new(200).set_body_json(artist_search_response()))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = MusicBrainzClient::builder()
+            .base_url(mock_server.uri())
+            .build()
+            .unwrap();
+        client
+            .search_artists(SearchQuery::new("Radiohead"))
+            .await
+            .expect("request should carry the default user-agent");
+    }
+
+    #[tokio::test]
+    async fn requests_send_configured_user_agent() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/artist"))
+            .and(header("user-agent", "MyLibrary/1.0 ( ops@example.com )"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(artist_search_response()))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = MusicBrainzClient::builder()
+            .base_url(mock_server.uri())
+            .user_agent("MyLibrary/1.0 ( ops@example.com )")
+            .build()
+            .unwrap();
+        client
+            .search_artists(SearchQuery::new("Radiohead"))
+            .await
+            .expect("request should carry the configured user-agent");
+    }
 }
@@ -0,0 +1,353 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Cover Art Archive client for album artwork.
+//!
+//! Artwork is looked up per release or release group. Missing artwork (HTTP 404)
+//! is reported as `None` rather than an error, and image downloads follow the
+//! archive's redirect to the actual image host.
+
+use crate::client::{COVER_ART_ARCHIVE_BASE, USER_AGENT};
+use crate::error::{MusicBrainzError, Result};
+use crate::models::{CoverArtImage, CoverArtResponse};
+use reqwest::{Client, StatusCode};
+use std::time::Duration;
+use tracing::{debug, trace};
+use uuid::Uuid;
+
+/// Which kind of MusicBrainz entity artwork is requested for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoverArtTarget {
+    /// A specific release (`/release/{mbid}`).
+    Release,
+    /// A release group, i.e. the album across all its releases (`/release-group/{mbid}`).
+    ReleaseGroup,
+}
+
+impl CoverArtTarget {
+    fn path_segment(&self) -> &'static str {
+        match self {
+            Self::Release => "release",
+            Self::ReleaseGroup => "release-group",
+        }
+    }
+}
+
+impl CoverArtResponse {
+    /// The front cover image, if the entity has one.
+    pub fn front(&self) -> Option<&CoverArtImage> {
+        self.images.iter().find(|image| image.front)
+    }
+
+    /// The back cover image, if the entity has one.
+    pub fn back(&self) -> Option<&CoverArtImage> {
+        self.images.iter().find(|image| image.back)
+    }
+}
+
+/// Cover Art Archive API client.
+#[derive(Debug, Clone)]
+pub struct CoverArtClient {
+    client: Client,
+    base_url: String,
+}
+
+impl CoverArtClient {
+    /// Create a new Cover Art Archive client with default settings.
+    pub fn new() -> Result<Self> {
+        Self::builder().build()
+    }
+
+    /// Create a client builder for custom configuration.
+    pub fn builder() -> CoverArtClientBuilder {
+        CoverArtClientBuilder::default()
+    }
+
+    /// List the artwork available for a release.
+    ///
+    /// Returns `Ok(None)` when the release has no artwork.
+    pub async fn release_art(&self, mbid: Uuid) -> Result<Option<CoverArtResponse>> {
+        self.fetch_art(CoverArtTarget::Release, mbid).await
+    }
+
+    /// List the artwork available for a release group.
+    ///
+    /// Returns `Ok(None)` when the release group has no artwork.
+    pub async fn release_group_art(&self, mbid: Uuid) -> Result<Option<CoverArtResponse>> {
+        self.fetch_art(CoverArtTarget::ReleaseGroup, mbid).await
+    }
+
+    /// List the artwork available for a release or release group.
+    pub async fn fetch_art(
+        &self,
+        target: CoverArtTarget,
+        mbid: Uuid,
+    ) -> Result<Option<CoverArtResponse>> {
+        let url = format!("{}/{}/{}", self.base_url, target.path_segment(), mbid);
+        let Some(response) = self.get(&url).await? else {
+            return Ok(None);
+        };
+
+        let body = response.text().await?;
+        trace!(target: "musicbrainz", "cover art response body: {}", body);
+
+        serde_json::from_str(&body).map(Some).map_err(|e| {
+            MusicBrainzError::InvalidResponse(format!("Failed to parse cover art response: {}", e))
+        })
+    }
+
+    /// Download the front cover image bytes for a release or release group.
+    ///
+    /// Follows the archive's redirect to the image host. Returns `Ok(None)` when
+    /// there is no front cover.
+    pub async fn download_front_cover(
+        &self,
+        target: CoverArtTarget,
+        mbid: Uuid,
+    ) -> Result<Option<Vec<u8>>> {
+        let url = format!("{}/{}/{}/front", self.base_url, target.path_segment(), mbid);
+        let Some(response) = self.get(&url).await? else {
+            return Ok(None);
+        };
+
+        debug!(target: "musicbrainz", final_url = %response.url(), "downloading front cover");
+        Ok(Some(response.bytes().await?.to_vec()))
+    }
+
+    /// Perform a GET request, mapping 404 to `None` and other failures to errors.
+    async fn get(&self, url: &str) -> Result<Option<reqwest::Response>> {
+        trace!(target: "musicbrainz", "GET {}", url);
+
+        let response = self.client.get(url).send().await?;
+
+        let status = response.status();
+        debug!(target: "musicbrainz", "cover art response status: {}", status);
+
+        if status == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        if !status.is_success() {
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(MusicBrainzError::ApiError {
+                status: status.as_u16(),
+                message,
+            });
+        }
+
+        Ok(Some(response))
+    }
+}
+
+/// Builder for configuring a Cover Art Archive client.
+#[derive(Debug)]
+pub struct CoverArtClientBuilder {
+    base_url: String,
+    timeout: Duration,
+    proxy_url: Option<String>,
+    user_agent: String,
+}
+
+impl Default for CoverArtClientBuilder {
+    fn default() -> Self {
+        Self {
+            base_url: COVER_ART_ARCHIVE_BASE.to_string(),
+            timeout: Duration::from_secs(30),
+            proxy_url: None,
+            user_agent: USER_AGENT.to_string(),
+        }
+    }
+}
+
+impl CoverArtClientBuilder {
+    /// Set a custom base URL (useful for testing with mock servers).
+    pub fn base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = url.into();
+        self
+    }
+
+    /// Set request timeout duration.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Send every request through the HTTP proxy at `url`.
+    pub fn proxy_url(mut self, url: impl Into<String>) -> Self {
+        self.proxy_url = Some(url.into());
+        self
+    }
+
+    /// Send `user_agent` instead of the default Chorrosion User-Agent.
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    /// Build the Cover Art Archive client.
+    pub fn build(self) -> Result<CoverArtClient> {
+        let mut client = Client::builder()
+            .timeout(self.timeout)
+            .user_agent(self.user_agent);
+        if let Some(proxy_url) = &self.proxy_url {
+            client = client.proxy(reqwest::Proxy::all(proxy_url)?);
+        }
+        let client = client.build()?;
+
+        Ok(CoverArtClient {
+            client,
+            base_url: self.base_url,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const RELEASE_MBID: &str = "b84ee12a-09ef-421b-82de-0441a926375b";
+
+    fn client_for(server: &MockServer) -> CoverArtClient {
+        CoverArtClient::builder()
+            .base_url(server.uri())
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn release_with_front_art_lists_images_and_downloads_front() {
+        let server = MockServer::start().await;
+        let image_url = format!("{}/images/front.jpg", server.uri());
+
+        Mock::given(method("GET"))
+            .and(path(format!("/release/{}", RELEASE_MBID)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "images": [
+                    {
+                        "image": image_url,
+                        "front": true,
+                        "back": false,
+                        "approved": true,
+                        "types": ["Front"],
+                        "thumbnails": {"250": format!("{}/images/front-250.jpg", server.uri())}
+                    },
+                    {
+                        "image": format!("{}/images/back.jpg", server.uri()),
+                        "front": false,
+                        "back": true,
+                        "approved": true,
+                        "types": ["Back"]
+                    }
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path(format!("/release/{}/front", RELEASE_MBID)))
+            .respond_with(ResponseTemplate::new(307).insert_header("Location", image_url.as_str()))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/images/front.jpg"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(vec![0xFF, 0xD8, 0xFF]))
+            .mount(&server)
+            .await;
+
+        let client = client_for(&server);
+        let mbid = Uuid::parse_str(RELEASE_MBID).unwrap();
+
+        let art = client
+            .release_art(mbid)
+            .await
+            .unwrap()
+            .expect("release should have art");
+        assert_eq!(art.images.len(), 2);
+        assert_eq!(
+            art.front().map(|image| image.image.as_str()),
+            Some(image_url.as_str())
+        );
+        assert!(art.back().is_some());
+
+        let bytes = client
+            .download_front_cover(CoverArtTarget::Release, mbid)
+            .await
+            .unwrap();
+        assert_eq!(bytes, Some(vec![0xFF, 0xD8, 0xFF]));
+    }
+
+    #[tokio::test]
+    async fn release_group_without_art_returns_none() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+
+        let client = client_for(&server);
+        let mbid = Uuid::parse_str(RELEASE_MBID).unwrap();
+
+        assert_eq!(client.release_group_art(mbid).await.unwrap(), None);
+        assert_eq!(
+            client
+                .download_front_cover(CoverArtTarget::ReleaseGroup, mbid)
+                .await
+                .unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn server_error_is_reported() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(500).set_body_string("boom"))
+            .mount(&server)
+            .await;
+
+        let client = client_for(&server);
+        let err = client
+            .release_art(Uuid::parse_str(RELEASE_MBID).unwrap())
+            .await
+            .expect_err("500 should be an error");
+        assert!(matches!(
+            err,
+            MusicBrainzError::ApiError { status: 500, .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn requests_go_through_the_configured_proxy() {
+        let proxy = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path(format!("/release/{}", RELEASE_MBID)))
+            .and(header("user-agent", "Tester/1.0 (ops@example.com)"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&proxy)
+            .await;
+
+        let client = CoverArtClient::builder()
+            .base_url("http://coverart.invalid")
+            .proxy_url(proxy.uri())
+            .user_agent("Tester/1.0 (ops@example.com)")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            client
+                .release_art(Uuid::parse_str(RELEASE_MBID).unwrap())
+                .await
+                .unwrap(),
+            None
+        );
+    }
+}
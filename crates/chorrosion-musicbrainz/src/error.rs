@@ -15,6 +15,9 @@ pub enum MusicBrainzError {
     #[error("Invalid response from MusicBrainz API: {0}")]
     InvalidResponse(String),
 
+    #[error("Invalid ISRC: {0}")]
+    InvalidIsrc(String),
+
     #[error("Resource not found: {0}")]
     NotFound(String),
 
@@ -4,18 +4,23 @@
 //!
 //! This crate provides a client for interacting with the MusicBrainz API,
 //! including artist and album search/lookup functionality with built-in
-//! rate limiting to comply with MusicBrainz API guidelines.
+//! rate limiting to comply with MusicBrainz API guidelines, plus a Cover Art
+//! Archive client for album artwork.
 
 pub mod client;
 #[cfg(test)]
 mod client_tests;
+pub mod cover_art;
 pub mod error;
 pub mod models;
 pub mod rate_limiter;
 
 pub use client::MusicBrainzClient;
+pub use cover_art::{CoverArtClient, CoverArtTarget};
 pub use error::{MusicBrainzError, Result};
 pub use models::{
     Album, AlbumSearchResult, Artist, ArtistSearchResult, CoverArtImage, CoverArtResponse,
-    CoverArtThumbnails, Recording, Release, ReleaseGroupRef, SearchQuery, SearchResponse,
+    CoverArtThumbnails, Genre, IsrcLookupResult, LifeSpan, Recording, Release, ReleaseBrowseResult,
+    ReleaseGroupRef, SearchQuery, SearchResponse,
 };
+pub use rate_limiter::RateLimiter;
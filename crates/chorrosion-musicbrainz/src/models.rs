@@ -21,11 +21,38 @@ pub struct Artist {
     pub artist_type: Option<String>,
     /// Country code (ISO 3166-1 alpha-2).
     pub country: Option<String>,
+    /// Active period; `ended` is set for dissolved groups and deceased persons.
+    #[serde(rename = "life-span", default)]
+    pub life_span: Option<LifeSpan>,
+    /// Genres voted on the artist (only present with `inc=genres`).
+    #[serde(default)]
+    pub genres: Vec<Genre>,
     /// Search score (only present in search results).
     #[serde(default)]
     pub score: Option<u32>,
 }
 
+/// Active period of an artist.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct LifeSpan {
+    /// Start date (YYYY, YYYY-MM, or YYYY-MM-DD).
+    #[serde(default)]
+    pub begin: Option<String>,
+    /// End date (YYYY, YYYY-MM, or YYYY-MM-DD).
+    #[serde(default)]
+    pub end: Option<String>,
+    #[serde(default)]
+    pub ended: Option<bool>,
+}
+
+/// A genre with the number of votes it received.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Genre {
+    pub name: String,
+    #[serde(default)]
+    pub count: u32,
+}
+
 /// Album (release group) information from MusicBrainz.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Album {
@@ -106,13 +133,20 @@ impl SearchQuery {
 }
 
 /// Generic search response wrapper.
+///
+/// Also used for browse responses, which report their paging as
+/// `release-group-count`/`release-group-offset` (or `release-count`/
+/// `release-offset`) and carry no timestamp.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchResponse<T> {
-    /// Creation timestamp.
+    /// Creation timestamp (empty for browse responses).
+    #[serde(default)]
     pub created: String,
     /// Total number of results.
+    #[serde(alias = "release-group-count", alias = "release-count")]
     pub count: u32,
     /// Offset used for this page.
+    #[serde(alias = "release-group-offset", alias = "release-offset")]
     pub offset: u32,
     /// Results for this page.
     #[serde(flatten)]
@@ -132,6 +166,13 @@ pub struct AlbumSearchResult {
     pub release_groups: Vec<Album>,
 }
 
+/// Release browse results.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseBrowseResult {
+    #[serde(default)]
+    pub releases: Vec<Release>,
+}
+
 /// Recording information from MusicBrainz.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Recording {
@@ -149,6 +190,16 @@ pub struct Recording {
     pub releases: Vec<Release>,
 }
 
+/// Recordings associated with an ISRC.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IsrcLookupResult {
+    /// The ISRC that was looked up.
+    pub isrc: String,
+    /// Recordings carrying this ISRC.
+    #[serde(default)]
+    pub recordings: Vec<Recording>,
+}
+
 /// Release information linked to a recording.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Release {
@@ -1,6 +1,12 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 use tracing::info;
 
+pub mod sse;
+pub mod websocket;
+
+pub use sse::SseHub;
+pub use websocket::{RealtimeMessage, WebSocketHub, DEFAULT_HUB_CAPACITY};
+
 #[async_trait::async_trait]
 pub trait RealtimeHub: Send + Sync + 'static {
     async fn broadcast(&self, channel: &str, payload: &str);
@@ -14,3 +20,30 @@ impl RealtimeHub for NoopRealtimeHub {
         info!(target: "realtime", %channel, %payload, "noop realtime broadcast");
     }
 }
+
+/// Hub that keeps every broadcast in memory so tests can assert on what was
+/// published.
+#[derive(Debug, Default)]
+pub struct RecordingHub {
+    messages: std::sync::Mutex<Vec<RealtimeMessage>>,
+}
+
+impl RecordingHub {
+    /// Every message broadcast so far, oldest first.
+    pub fn messages(&self) -> Vec<RealtimeMessage> {
+        self.messages.lock().expect("recording hub lock").clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl RealtimeHub for RecordingHub {
+    async fn broadcast(&self, channel: &str, payload: &str) {
+        self.messages
+            .lock()
+            .expect("recording hub lock")
+            .push(RealtimeMessage {
+                channel: channel.to_string(),
+                payload: payload.to_string(),
+            });
+    }
+}
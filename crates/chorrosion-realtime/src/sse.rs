@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+//! Broadcast-channel backed hub for Server-Sent Events streams.
+//!
+//! An alternative to [`crate::WebSocketHub`] for deployments behind proxies
+//! that do not pass WebSocket upgrades through. Each broadcast becomes one
+//! named SSE event whose name is the channel and whose data is the payload.
+//! Fan-out and lag handling are [`WebSocketHub`]'s; only the framing differs.
+
+use crate::{RealtimeHub, RealtimeMessage, WebSocketHub};
+use tokio::sync::broadcast;
+use tracing::debug;
+
+/// [`RealtimeHub`] that fans messages out to subscribed SSE streams.
+#[derive(Debug, Clone, Default)]
+pub struct SseHub {
+    hub: WebSocketHub,
+}
+
+impl SseHub {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            hub: WebSocketHub::new(capacity),
+        }
+    }
+
+    /// Subscribe to all messages published after this call.
+    pub fn subscribe(&self) -> broadcast::Receiver<RealtimeMessage> {
+        self.hub.subscribe()
+    }
+
+    /// Publish a message, returning how many streams it was delivered to.
+    pub fn publish(&self, channel: &str, payload: &str) -> usize {
+        let delivered_to = self.hub.publish(channel, payload);
+        debug!(target: "realtime", %channel, delivered_to, "sse hub broadcast");
+        delivered_to
+    }
+
+    /// Number of currently subscribed streams.
+    pub fn subscriber_count(&self) -> usize {
+        self.hub.subscriber_count()
+    }
+}
+
+#[async_trait::async_trait]
+impl RealtimeHub for SseHub {
+    async fn broadcast(&self, channel: &str, payload: &str) {
+        self.publish(channel, payload);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn broadcast_is_delivered_to_subscribers() {
+        let hub = SseHub::default();
+        let mut receiver = hub.subscribe();
+
+        hub.broadcast("albums", "{\"id\":1}").await;
+
+        let message = receiver.recv().await.expect("message delivered");
+        assert_eq!(message.channel, "albums");
+        assert_eq!(message.payload, "{\"id\":1}");
+    }
+
+    #[test]
+    fn publish_reports_delivery_count() {
+        let hub = SseHub::default();
+        assert_eq!(hub.publish("albums", "{}"), 0);
+
+        let _first = hub.subscribe();
+        let _second = hub.subscribe();
+        assert_eq!(hub.publish("albums", "{}"), 2);
+        assert_eq!(hub.subscriber_count(), 2);
+    }
+}
@@ -0,0 +1,131 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+//! Broadcast-channel backed hub for WebSocket clients.
+//!
+//! Every connected client holds its own [`broadcast::Receiver`], so publishing
+//! never waits on a client. A client that falls more than the channel capacity
+//! behind observes a lag error on its receiver and is expected to disconnect.
+
+use crate::RealtimeHub;
+use serde::Serialize;
+use tokio::sync::broadcast;
+use tracing::debug;
+
+/// Number of messages buffered per subscriber before it is considered lagging.
+pub const DEFAULT_HUB_CAPACITY: usize = 256;
+
+/// A message published on a named channel.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RealtimeMessage {
+    pub channel: String,
+    pub payload: String,
+}
+
+impl RealtimeMessage {
+    /// Whether a client subscribed to `channels` should receive this message.
+    /// An empty subscription list receives every channel.
+    pub fn matches<S: AsRef<str>>(&self, channels: &[S]) -> bool {
+        channels.is_empty() || channels.iter().any(|c| c.as_ref() == self.channel)
+    }
+}
+
+/// [`RealtimeHub`] that fans messages out to subscribed WebSocket clients.
+#[derive(Debug, Clone)]
+pub struct WebSocketHub {
+    sender: broadcast::Sender<RealtimeMessage>,
+}
+
+impl WebSocketHub {
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _receiver) = broadcast::channel(capacity.max(1));
+        Self { sender }
+    }
+
+    /// Subscribe to all messages published after this call.
+    pub fn subscribe(&self) -> broadcast::Receiver<RealtimeMessage> {
+        self.sender.subscribe()
+    }
+
+    /// Publish a message, returning how many subscribers it was delivered to.
+    pub fn publish(&self, channel: &str, payload: &str) -> usize {
+        let message = RealtimeMessage {
+            channel: channel.to_string(),
+            payload: payload.to_string(),
+        };
+        // Sending only fails when nobody is subscribed, which is not an error.
+        self.sender.send(message).unwrap_or(0)
+    }
+
+    /// Number of currently subscribed clients.
+    pub fn subscriber_count(&self) -> usize {
+        self.sender.receiver_count()
+    }
+}
+
+impl Default for WebSocketHub {
+    fn default() -> Self {
+        Self::new(DEFAULT_HUB_CAPACITY)
+    }
+}
+
+#[async_trait::async_trait]
+impl RealtimeHub for WebSocketHub {
+    async fn broadcast(&self, channel: &str, payload: &str) {
+        let delivered_to = self.publish(channel, payload);
+        debug!(target: "realtime", %channel, delivered_to, "websocket hub broadcast");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn broadcast_reaches_every_subscriber() {
+        let hub = WebSocketHub::default();
+        let mut first = hub.subscribe();
+        let mut second = hub.subscribe();
+        assert_eq!(hub.subscriber_count(), 2);
+
+        hub.broadcast("downloads", "{\"progress\":50}").await;
+
+        for receiver in [&mut first, &mut second] {
+            let message = receiver.recv().await.expect("message delivered");
+            assert_eq!(message.channel, "downloads");
+            assert_eq!(message.payload, "{\"progress\":50}");
+        }
+    }
+
+    #[tokio::test]
+    async fn broadcast_without_subscribers_does_not_fail() {
+        let hub = WebSocketHub::default();
+        hub.broadcast("downloads", "ignored").await;
+        assert_eq!(hub.subscriber_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn slow_subscriber_lags_instead_of_blocking() {
+        let hub = WebSocketHub::new(2);
+        let mut slow = hub.subscribe();
+
+        for i in 0..5 {
+            hub.broadcast("jobs", &i.to_string()).await;
+        }
+
+        assert!(matches!(
+            slow.recv().await,
+            Err(broadcast::error::RecvError::Lagged(3))
+        ));
+    }
+
+    #[test]
+    fn message_matches_requested_channels() {
+        let message = RealtimeMessage {
+            channel: "imports".to_string(),
+            payload: String::new(),
+        };
+
+        assert!(message.matches::<&str>(&[]));
+        assert!(message.matches(&["downloads", "imports"]));
+        assert!(!message.matches(&["downloads"]));
+    }
+}
@@ -1,13 +1,101 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use chorrosion_application::EventDispatcher;
+use chorrosion_config::DatabaseConfig;
+use chorrosion_fingerprint::AcoustidClient;
+use chorrosion_infrastructure::repositories::{
+    AlbumRepository, ArtistRepository, DownloadClientDefinitionRepository,
+    IndexerDefinitionRepository, MetadataProfileRepository, StatusParsing, TrackFileRepository,
+    TrackRepository,
+};
+use chorrosion_infrastructure::sqlite_adapters::{
+    SqliteAlbumRepository, SqliteArtistRepository, SqliteDownloadClientDefinitionRepository,
+    SqliteIndexerDefinitionRepository, SqliteMetadataProfileRepository, SqliteTrackFileRepository,
+    SqliteTrackRepository,
+};
+use chorrosion_musicbrainz::MusicBrainzClient;
 use chrono::{DateTime, Utc};
+use sqlx::SqlitePool;
 use std::fmt;
+use std::sync::Arc;
+
+/// Dependencies shared by every job run, built once by the scheduler.
+#[derive(Clone)]
+pub struct JobDependencies {
+    pub pool: SqlitePool,
+    pub artist_repository: Arc<dyn ArtistRepository>,
+    pub album_repository: Arc<dyn AlbumRepository>,
+    pub track_repository: Arc<dyn TrackRepository>,
+    pub track_file_repository: Arc<dyn TrackFileRepository>,
+    pub indexer_definition_repository: Arc<dyn IndexerDefinitionRepository>,
+    pub download_client_definition_repository: Arc<dyn DownloadClientDefinitionRepository>,
+    pub metadata_profile_repository: Arc<dyn MetadataProfileRepository>,
+    pub musicbrainz_client: Option<Arc<MusicBrainzClient>>,
+    pub acoustid_client: Option<Arc<AcoustidClient>>,
+    pub event_dispatcher: EventDispatcher,
+}
+
+impl JobDependencies {
+    /// SQLite-backed repositories over `pool`, with no clients and a no-op event dispatcher.
+    pub fn from_pool(pool: SqlitePool, database: &DatabaseConfig) -> Self {
+        let threshold_ms = database.slow_query_threshold_ms;
+        let status_parsing = StatusParsing::from(database);
+        Self {
+            artist_repository: Arc::new(
+                SqliteArtistRepository::new_with_threshold(pool.clone(), threshold_ms)
+                    .with_status_parsing(status_parsing),
+            ),
+            album_repository: Arc::new(
+                SqliteAlbumRepository::new_with_threshold(pool.clone(), threshold_ms)
+                    .with_status_parsing(status_parsing),
+            ),
+            track_repository: Arc::new(SqliteTrackRepository::new_with_threshold(
+                pool.clone(),
+                threshold_ms,
+            )),
+            track_file_repository: Arc::new(SqliteTrackFileRepository::new_with_threshold(
+                pool.clone(),
+                threshold_ms,
+            )),
+            indexer_definition_repository: Arc::new(SqliteIndexerDefinitionRepository::new(
+                pool.clone(),
+            )),
+            download_client_definition_repository: Arc::new(
+                SqliteDownloadClientDefinitionRepository::new(pool.clone()),
+            ),
+            metadata_profile_repository: Arc::new(SqliteMetadataProfileRepository::new(
+                pool.clone(),
+            )),
+            musicbrainz_client: None,
+            acoustid_client: None,
+            event_dispatcher: EventDispatcher::default(),
+            pool,
+        }
+    }
+
+    pub fn with_musicbrainz_client(mut self, client: Arc<MusicBrainzClient>) -> Self {
+        self.musicbrainz_client = Some(client);
+        self
+    }
+
+    pub fn with_acoustid_client(mut self, client: Arc<AcoustidClient>) -> Self {
+        self.acoustid_client = Some(client);
+        self
+    }
+
+    pub fn with_event_dispatcher(mut self, event_dispatcher: EventDispatcher) -> Self {
+        self.event_dispatcher = event_dispatcher;
+        self
+    }
+}
 
 /// Represents the execution context for a job
 #[derive(Clone)]
 pub struct JobContext {
     pub job_id: String,
     pub execution_time: DateTime<Utc>,
+    /// Set by the registry when the scheduler provided shared dependencies.
+    pub dependencies: Option<Arc<JobDependencies>>,
 }
 
 impl JobContext {
@@ -15,8 +103,21 @@ impl JobContext {
         Self {
             job_id: job_id.into(),
             execution_time: Utc::now(),
+            dependencies: None,
         }
     }
+
+    pub fn with_dependencies(mut self, dependencies: Arc<JobDependencies>) -> Self {
+        self.dependencies = Some(dependencies);
+        self
+    }
+
+    /// The shared dependencies, or an error for contexts created without them.
+    pub fn dependencies(&self) -> Result<&JobDependencies> {
+        self.dependencies
+            .as_deref()
+            .ok_or_else(|| anyhow!("job {} has no shared dependencies", self.job_id))
+    }
 }
 
 /// Job execution result with optional retry information
@@ -62,3 +163,78 @@ impl fmt::Debug for dyn Job {
             .finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chorrosion_domain::{Artist, DomainEvent};
+    use chorrosion_realtime::RecordingHub;
+
+    /// Counts artists through the context and announces the total.
+    struct CountArtistsJob;
+
+    #[async_trait::async_trait]
+    impl Job for CountArtistsJob {
+        fn job_type(&self) -> &'static str {
+            "count_artists"
+        }
+
+        fn name(&self) -> String {
+            "Count artists".to_string()
+        }
+
+        async fn execute(&self, ctx: JobContext) -> Result<JobResult> {
+            let dependencies = ctx.dependencies()?;
+            let artists = dependencies.artist_repository.list(100, 0).await?;
+            let event = DomainEvent::new("job.artists_counted", artists.len());
+            dependencies.event_dispatcher.dispatch(&event).await;
+            Ok(JobResult::Success)
+        }
+    }
+
+    async fn migrated_pool() -> SqlitePool {
+        let config = chorrosion_config::AppConfig {
+            database: DatabaseConfig {
+                url: "sqlite::memory:".to_string(),
+                pool_max_size: 1,
+                ..DatabaseConfig::default()
+            },
+            ..chorrosion_config::AppConfig::default()
+        };
+        chorrosion_infrastructure::init_database(&config)
+            .await
+            .expect("in-memory DB init failed")
+    }
+
+    #[tokio::test]
+    async fn job_reads_repositories_through_its_context() {
+        let pool = migrated_pool().await;
+        let hub = Arc::new(RecordingHub::default());
+        let dependencies = JobDependencies::from_pool(pool, &DatabaseConfig::default())
+            .with_event_dispatcher(EventDispatcher::new(hub.clone()));
+        dependencies
+            .artist_repository
+            .create(Artist::new("Boards of Canada"))
+            .await
+            .unwrap();
+        let ctx = JobContext::new("count-artists").with_dependencies(Arc::new(dependencies));
+
+        let result = CountArtistsJob.execute(ctx).await.unwrap();
+
+        assert!(matches!(result, JobResult::Success));
+        let messages = hub.messages();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].channel, "jobs");
+        assert!(messages[0].payload.contains("\"payload\":1"));
+    }
+
+    #[tokio::test]
+    async fn context_without_dependencies_reports_an_error() {
+        let error = CountArtistsJob
+            .execute(JobContext::new("count-artists"))
+            .await
+            .unwrap_err();
+
+        assert!(error.to_string().contains("no shared dependencies"));
+    }
+}
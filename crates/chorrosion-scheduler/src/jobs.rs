@@ -1,28 +1,41 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
-use crate::job::{Job, JobContext, JobResult};
+use crate::job::{Job, JobContext, JobDependencies, JobResult};
 use anyhow::Result;
 use chorrosion_application::{
-    parse_release_title, AddTorrentRequest, DelugeClient, DownloadClient, IndexerClient,
-    IndexerConfig, IndexerProtocol, NewznabClient, NzbgetClient, QBittorrentClient, SabnzbdClient,
-    TorznabClient, TransmissionClient,
+    active_download_album_keys, build_album_query, deduplicate_releases_with_strategy,
+    download_client_for, filter_releases, parse_release_title, parsed_release_key, rank_releases,
+    release_match_key, release_torrent_health, score_release, AddTorrentRequest,
+    AutomaticSearchReason, DelayProfile, DownloadClient, EventDispatcher, IndexerClient,
+    IndexerClients, IndexerProtocol, IndexerSearchQuery, IndexerSearchResult, OutboundHttp,
+    QueryBuildOptions, RankedRelease, ReleaseFilterOptions, ReleaseGroupSyncSummary,
+    SearchDecisionStore,
 };
 use chorrosion_config::{
     CacheConfig, DiscogsAlbumSeed, DiscogsConfig, LastFmAlbumSeed, LastFmConfig,
 };
-use chorrosion_domain::Artist as DomainArtist;
+use chorrosion_domain::{
+    Album as DomainAlbum, Artist as DomainArtist, ArtistStatus, ArtistUpdated,
+    ArtistUpdatedPayload, DomainEvent, DownloadClientDefinition, IndexerDefinition, TrackFile,
+};
+use chorrosion_fingerprint::{AcoustidClient, Fingerprint, FingerprintGenerator, RecordingMatch};
 use chorrosion_infrastructure::{
-    repositories::{AlbumRepository, ArtistRepository, Repository},
+    collect_all, create_database_backup,
+    repositories::{
+        AlbumRepository, ArtistRepository, MetadataProfileRepository, QualityProfileRepository,
+        Repository, TrackRepository,
+    },
     sqlite_adapters::{
         SqliteAlbumRepository, SqliteArtistRepository, SqliteDownloadClientDefinitionRepository,
-        SqliteIndexerDefinitionRepository,
+        SqliteIndexerDefinitionRepository, SqliteMetadataProfileRepository,
     },
 };
 use chorrosion_metadata::discogs::DiscogsClient;
 use chorrosion_metadata::lastfm::LastFmClient;
-use chorrosion_musicbrainz::MusicBrainzClient;
+use chorrosion_musicbrainz::{MusicBrainzClient, RateLimiter};
 use chrono::{DateTime, Utc};
 use sqlx::SqlitePool;
 use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
 use std::sync::{
     atomic::{AtomicU64, Ordering},
     Arc, RwLock,
@@ -145,6 +158,16 @@ impl MetadataRefreshCache {
         should_refresh
     }
 
+    /// Whether a persisted `last_metadata_refresh_at` is absent or older than the TTL.
+    ///
+    /// The in-memory maps are lost on restart; the persisted timestamp keeps a
+    /// freshly started scheduler from refreshing everything again.
+    pub fn is_persisted_refresh_stale(&self, last_refresh: Option<DateTime<Utc>>) -> bool {
+        last_refresh.is_none_or(|last_refresh| {
+            Utc::now().signed_duration_since(last_refresh).num_seconds() > self.ttl_seconds
+        })
+    }
+
     /// Clear all cached refresh times (useful for testing)
     pub fn clear(&self) {
         if let Ok(mut cache) = self.artist_refreshes.write() {
@@ -252,12 +275,15 @@ pub struct LastFmMetadataRefreshJob {
 
 impl LastFmMetadataRefreshJob {
     pub fn from_config(config: &LastFmConfig) -> Option<Self> {
-        Self::from_config_with_cache(config, &CacheConfig::default())
+        Self::from_config_with_cache(config, &CacheConfig::default(), &OutboundHttp::default())
     }
 
+    /// Build the job with `cache_config`'s capacities, sending its requests
+    /// through `outbound`'s proxy.
     pub fn from_config_with_cache(
         config: &LastFmConfig,
         cache_config: &CacheConfig,
+        outbound: &OutboundHttp,
     ) -> Option<Self> {
         let api_key = config.api_key.as_deref()?.trim();
         if api_key.is_empty() {
@@ -289,7 +315,10 @@ impl LastFmMetadataRefreshJob {
             cache_config.metadata_album_max_capacity,
             config.request_timeout_seconds,
             config.base_url.clone(),
-        );
+        )
+        .with_http_client(outbound.build_client(std::time::Duration::from_secs(
+            config.request_timeout_seconds.max(1),
+        )));
 
         Some(Self {
             client: Arc::new(client),
@@ -434,12 +463,15 @@ pub struct DiscogsMetadataRefreshJob {
 
 impl DiscogsMetadataRefreshJob {
     pub fn from_config(config: &DiscogsConfig) -> Option<Self> {
-        Self::from_config_with_cache(config, &CacheConfig::default())
+        Self::from_config_with_cache(config, &CacheConfig::default(), &OutboundHttp::default())
     }
 
+    /// Build the job with `cache_config`'s capacities, sending its requests
+    /// through `outbound`'s proxy.
     pub fn from_config_with_cache(
         config: &DiscogsConfig,
         cache_config: &CacheConfig,
+        outbound: &OutboundHttp,
     ) -> Option<Self> {
         let artists: Vec<String> = config
             .seed_artists
@@ -470,7 +502,10 @@ impl DiscogsMetadataRefreshJob {
             cache_config.metadata_album_max_capacity,
             config.request_timeout_seconds,
             config.base_url.clone(),
-        );
+        )
+        .with_http_client(outbound.build_client(std::time::Duration::from_secs(
+            config.request_timeout_seconds.max(1),
+        )));
 
         Some(Self {
             client: Arc::new(client),
@@ -603,10 +638,14 @@ pub struct RssSyncJob {
     album_repository: Arc<SqliteAlbumRepository>,
     indexer_repository: Arc<SqliteIndexerDefinitionRepository>,
     download_client_repository: Arc<SqliteDownloadClientDefinitionRepository>,
+    indexer_clients: IndexerClients,
+    outbound_http: OutboundHttp,
+    filter_options: ReleaseFilterOptions,
     scan_limit: i64,
 }
 
-const SUPPORTED_RSS_PROTOCOLS: &str = "newznab, torznab";
+const SUPPORTED_RSS_PROTOCOLS: &str = "newznab, torznab, custom";
+const SUPPORTED_SEARCH_PROTOCOLS: &str = "newznab, torznab";
 const SUPPORTED_GRAB_CLIENTS: &str = "qbittorrent, transmission, deluge, sabnzbd, nzbget";
 
 impl RssSyncJob {
@@ -619,9 +658,30 @@ impl RssSyncJob {
             album_repository,
             indexer_repository,
             download_client_repository,
+            indexer_clients: IndexerClients::default(),
+            outbound_http: OutboundHttp::default(),
+            filter_options: ReleaseFilterOptions::default(),
             scan_limit: 5000,
         }
     }
+
+    /// Build indexer clients through `indexer_clients`, typically shared with the API.
+    pub fn with_indexer_clients(mut self, indexer_clients: IndexerClients) -> Self {
+        self.indexer_clients = indexer_clients;
+        self
+    }
+
+    /// Send download client requests through `outbound`'s proxy.
+    pub fn with_outbound_http(mut self, outbound: OutboundHttp) -> Self {
+        self.outbound_http = outbound;
+        self
+    }
+
+    /// Drop feed items these preferences reject, e.g. torrents that are too old.
+    pub fn with_filter_options(mut self, filter_options: ReleaseFilterOptions) -> Self {
+        self.filter_options = filter_options;
+        self
+    }
 }
 
 #[async_trait::async_trait]
@@ -675,7 +735,9 @@ impl Job for RssSyncJob {
             active_download_client_name,
             active_download_client_category,
             mut active_download_client,
-        ) = match load_active_download_client(&self.download_client_repository).await {
+        ) = match load_active_download_client(&self.download_client_repository, &self.outbound_http)
+            .await
+        {
             Ok(client) => client,
             Err(error) => {
                 return Ok(JobResult::Failure {
@@ -713,8 +775,8 @@ impl Job for RssSyncJob {
         let mut seen_grab_urls: HashSet<String> = HashSet::new();
 
         for definition in indexers {
-            let protocol = match definition.protocol.parse::<IndexerProtocol>() {
-                Ok(protocol) => protocol,
+            let client = match self.indexer_clients.for_definition(&definition) {
+                Ok(client) => client,
                 Err(error) => {
                     config_failures += 1;
                     warn!(
@@ -729,44 +791,30 @@ impl Job for RssSyncJob {
                 }
             };
 
-            let config = IndexerConfig {
-                name: definition.name.clone(),
-                base_url: definition.base_url.clone(),
-                protocol: protocol.clone(),
-                api_key: definition.api_key.clone(),
-                enabled: definition.enabled,
-            };
+            if client.config().protocol == IndexerProtocol::Gazelle {
+                config_failures += 1;
+                warn!(
+                    target: "jobs",
+                    job_id = %ctx.job_id,
+                    indexer = %definition.name,
+                    protocol = %client.config().protocol.as_str(),
+                    supported_protocols = %SUPPORTED_RSS_PROTOCOLS,
+                    "skipping indexer: unsupported RSS sync protocol"
+                );
+                continue;
+            }
 
-            let fetch_result = match protocol {
-                IndexerProtocol::Newznab => {
-                    indexers_polled += 1;
-                    let client = NewznabClient::new(config);
-                    let rss_items = client.fetch_rss_feed().await;
-                    rss_items
-                }
-                IndexerProtocol::Torznab => {
-                    indexers_polled += 1;
-                    let client = TorznabClient::new(config);
-                    let rss_items = client.fetch_rss_feed().await;
-                    rss_items
-                }
-                other => {
-                    config_failures += 1;
-                    warn!(
-                        target: "jobs",
-                        job_id = %ctx.job_id,
-                        indexer = %definition.name,
-                        protocol = %other.as_str(),
-                        supported_protocols = %SUPPORTED_RSS_PROTOCOLS,
-                        "skipping indexer: unsupported RSS sync protocol"
-                    );
-                    continue;
-                }
-            };
+            indexers_polled += 1;
+            let fetch_result = client.fetch_rss_feed().await;
 
             match fetch_result {
                 Ok(items) => {
-                    let candidates = collect_rss_grab_candidates(&items, &wanted_titles);
+                    let candidates = collect_rss_grab_candidates(
+                        &items,
+                        &wanted_titles,
+                        &client.config().protocol,
+                        &self.filter_options,
+                    );
                     let matched = candidates.len();
                     rss_items_seen += items.len();
                     rss_items_matched += matched;
@@ -895,65 +943,20 @@ async fn collect_wanted_album_titles(
     album_repository: &SqliteAlbumRepository,
     scan_limit: i64,
 ) -> Result<HashSet<String>> {
-    let mut titles = HashSet::new();
-
-    collect_titles_by_source(
-        album_repository,
-        scan_limit,
-        &mut titles,
-        WantedAlbumTitleSource::WantedWithoutTracks,
-    )
+    let without_tracks = collect_all(scan_limit, |limit, offset| {
+        album_repository.list_wanted_without_tracks(limit, offset)
+    })
     .await?;
-    collect_titles_by_source(
-        album_repository,
-        scan_limit,
-        &mut titles,
-        WantedAlbumTitleSource::CutoffUnmet,
-    )
+    let cutoff_unmet = collect_all(scan_limit, |limit, offset| {
+        album_repository.list_cutoff_unmet_albums(limit, offset)
+    })
     .await?;
 
-    Ok(titles)
-}
-
-/// Source query used to collect candidate album titles for RSS matching.
-enum WantedAlbumTitleSource {
-    WantedWithoutTracks,
-    CutoffUnmet,
-}
-
-/// Collect wanted album titles from a paginated repository query and append them to `titles`.
-async fn collect_titles_by_source(
-    album_repository: &SqliteAlbumRepository,
-    scan_limit: i64,
-    titles: &mut HashSet<String>,
-    source: WantedAlbumTitleSource,
-) -> Result<()> {
-    let mut offset: i64 = 0;
-    loop {
-        let batch = match source {
-            WantedAlbumTitleSource::WantedWithoutTracks => {
-                album_repository
-                    .list_wanted_without_tracks(scan_limit, offset)
-                    .await?
-            }
-            WantedAlbumTitleSource::CutoffUnmet => {
-                album_repository
-                    .list_cutoff_unmet_albums(scan_limit, offset)
-                    .await?
-            }
-        };
-
-        let batch_len = batch.len();
-        for album in batch {
-            titles.insert(normalize_match_key(&album.title));
-        }
-        if batch_len < scan_limit as usize {
-            break;
-        }
-        offset += scan_limit;
-    }
-
-    Ok(())
+    Ok(without_tracks
+        .into_iter()
+        .chain(cutoff_unmet)
+        .map(|album| normalize_match_key(&album.title))
+        .collect())
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -963,14 +966,35 @@ struct RssGrabCandidate {
     download_url: String,
 }
 
+/// Feed items matching a wanted album title that `options` accepts, with their grab URL.
 fn collect_rss_grab_candidates(
     items: &[chorrosion_application::IndexerRssItem],
     wanted_titles: &HashSet<String>,
+    protocol: &IndexerProtocol,
+    options: &ReleaseFilterOptions,
 ) -> Vec<RssGrabCandidate> {
-    items
+    let parsed = items
         .iter()
-        .filter_map(|item| {
+        .map(|item| {
             let parsed = parse_release_title(&item.title);
+            match release_torrent_health(protocol, None, item.published_at.as_deref()) {
+                Some(torrent) => parsed.with_torrent_health(torrent),
+                None => parsed,
+            }
+        })
+        .collect::<Vec<_>>();
+    let accepted = filter_releases(&parsed, options)
+        .into_iter()
+        .map(|release| release.original_title)
+        .collect::<HashSet<_>>();
+
+    items
+        .iter()
+        .zip(parsed)
+        .filter_map(|(item, parsed)| {
+            if !accepted.contains(&item.title) {
+                return None;
+            }
             let album_title = parsed.album.as_deref()?;
             if !wanted_titles.contains(&normalize_match_key(album_title)) {
                 return None;
@@ -1038,73 +1062,407 @@ fn rss_sync_terminal_failure(
 
 async fn load_active_download_client(
     download_client_repository: &SqliteDownloadClientDefinitionRepository,
+    outbound: &OutboundHttp,
 ) -> Result<(String, Option<String>, Option<Box<dyn DownloadClient>>)> {
     let definitions = download_client_repository.list(5000, 0).await?;
     for definition in definitions
         .into_iter()
         .filter(|definition| definition.enabled)
     {
-        let name = definition.name.clone();
-        let category = definition.category.clone();
-        let client_type = definition.client_type.trim().to_lowercase();
-
-        let client: Option<Box<dyn DownloadClient>> = match client_type.as_str() {
-            "qbittorrent" => Some(Box::new(QBittorrentClient::new(
-                definition.base_url.clone(),
-                definition.username.clone(),
-                definition.password_encrypted.clone(),
-            ))),
-            "transmission" => Some(Box::new(TransmissionClient::new(
-                definition.base_url.clone(),
-                definition.username.clone(),
-                definition.password_encrypted.clone(),
-            ))),
-            "deluge" => Some(Box::new(DelugeClient::new(
-                definition.base_url.clone(),
-                definition.password_encrypted.clone(),
-            ))),
-            "sabnzbd" => Some(Box::new(SabnzbdClient::new(
-                definition.base_url.clone(),
-                definition.password_encrypted.clone(),
-            ))),
-            "nzbget" => Some(Box::new(NzbgetClient::new(
-                definition.base_url.clone(),
-                definition.username.clone(),
-                definition.password_encrypted.clone(),
-            ))),
-            other => {
-                warn!(
-                    target: "jobs",
-                    download_client = %name,
-                    client_type = %other,
-                    supported_client_types = %SUPPORTED_GRAB_CLIENTS,
-                    "configured download client type is not supported for automatic RSS grabs"
-                );
-                None
-            }
-        };
-
-        if client.is_some() {
-            return Ok((name, category, client));
+        if let Some(client) = download_client_from_definition(&definition, outbound) {
+            return Ok((definition.name, definition.category, Some(client)));
         }
     }
 
     Ok(("<none>".to_string(), None, None))
 }
 
+/// Build a client for a download client definition, or `None` for unsupported types.
+fn download_client_from_definition(
+    definition: &DownloadClientDefinition,
+    outbound: &OutboundHttp,
+) -> Option<Box<dyn DownloadClient>> {
+    let client = download_client_for(definition, outbound);
+    if client.is_none() {
+        warn!(
+            target: "jobs",
+            download_client = %definition.name,
+            client_type = %definition.client_type.trim().to_lowercase(),
+            supported_client_types = %SUPPORTED_GRAB_CLIENTS,
+            "configured download client type is not supported for automatic RSS grabs"
+        );
+    }
+    client
+}
+
+/// Build a search client for an indexer definition, or `None` when its protocol
+/// is unrecognized or does not support searching.
+fn search_client_from_definition(
+    indexer_clients: &IndexerClients,
+    definition: &IndexerDefinition,
+    job_id: &str,
+) -> Option<Arc<dyn IndexerClient>> {
+    let client = match indexer_clients.for_definition(definition) {
+        Ok(client) => client,
+        Err(error) => {
+            warn!(
+                target: "jobs",
+                job_id = %job_id,
+                indexer = %definition.name,
+                protocol = %definition.protocol,
+                error = %error,
+                "skipping indexer: unrecognized protocol"
+            );
+            return None;
+        }
+    };
+
+    match &client.config().protocol {
+        IndexerProtocol::Newznab | IndexerProtocol::Torznab => Some(client),
+        other => {
+            warn!(
+                target: "jobs",
+                job_id = %job_id,
+                indexer = %definition.name,
+                protocol = %other.as_str(),
+                supported_protocols = %SUPPORTED_SEARCH_PROTOCOLS,
+                "skipping indexer: unsupported search protocol"
+            );
+            None
+        }
+    }
+}
+
 /// Backlog search job - searches indexers for missing albums
+///
+/// Collects monitored wanted albums that have no tracks, whose tracks are missing
+/// files, or whose files are below the quality profile cutoff, searches every enabled
+/// indexer for `"<artist> <album>"`, ranks the results and submits the best matching
+/// release to the active download client. Unofficial releases are dropped for artists
+/// whose metadata profile allows only official ones, and releases younger than the
+/// delay profile allows are left for a later run. Albums that already have a download
+/// in progress are skipped, and failures for a single album are logged without
+/// aborting the rest of the run.
 pub struct BacklogSearchJob {
-    album_repository: Arc<SqliteAlbumRepository>,
+    album_repository: Arc<dyn AlbumRepository>,
+    track_repository: Arc<dyn TrackRepository>,
+    artist_repository: Arc<dyn ArtistRepository>,
+    quality_profile_repository: Arc<dyn QualityProfileRepository>,
+    metadata_profile_repository: Arc<dyn MetadataProfileRepository>,
+    indexer_repository: Arc<SqliteIndexerDefinitionRepository>,
+    download_client_repository: Arc<SqliteDownloadClientDefinitionRepository>,
+    indexer_clients: IndexerClients,
+    outbound_http: OutboundHttp,
+    indexers: Option<Vec<Arc<dyn IndexerClient>>>,
+    download_client: Option<(String, Arc<dyn DownloadClient>)>,
+    filter_options: ReleaseFilterOptions,
+    delay_profile: DelayProfile,
+    decision_store: SearchDecisionStore,
     scan_limit: i64,
 }
 
 impl BacklogSearchJob {
-    pub fn new(album_repository: Arc<SqliteAlbumRepository>) -> Self {
+    pub fn new(
+        album_repository: Arc<dyn AlbumRepository>,
+        track_repository: Arc<dyn TrackRepository>,
+        artist_repository: Arc<dyn ArtistRepository>,
+        quality_profile_repository: Arc<dyn QualityProfileRepository>,
+        metadata_profile_repository: Arc<dyn MetadataProfileRepository>,
+        indexer_repository: Arc<SqliteIndexerDefinitionRepository>,
+        download_client_repository: Arc<SqliteDownloadClientDefinitionRepository>,
+    ) -> Self {
         Self {
             album_repository,
+            track_repository,
+            artist_repository,
+            quality_profile_repository,
+            metadata_profile_repository,
+            indexer_repository,
+            download_client_repository,
+            indexer_clients: IndexerClients::default(),
+            outbound_http: OutboundHttp::default(),
+            indexers: None,
+            download_client: None,
+            filter_options: ReleaseFilterOptions::default(),
+            delay_profile: DelayProfile::default(),
+            decision_store: SearchDecisionStore::default(),
             scan_limit: 5000,
         }
     }
+
+    /// Build indexer clients through `indexer_clients`, typically shared with the API.
+    pub fn with_indexer_clients(mut self, indexer_clients: IndexerClients) -> Self {
+        self.indexer_clients = indexer_clients;
+        self
+    }
+
+    /// Send download client requests through `outbound`'s proxy.
+    pub fn with_outbound_http(mut self, outbound: OutboundHttp) -> Self {
+        self.outbound_http = outbound;
+        self
+    }
+
+    /// Search these indexer clients instead of the enabled indexer definitions.
+    pub fn with_indexers(mut self, indexers: Vec<Arc<dyn IndexerClient>>) -> Self {
+        self.indexers = Some(indexers);
+        self
+    }
+
+    /// Submit grabs to this client instead of the first enabled download client definition.
+    pub fn with_download_client(
+        mut self,
+        name: impl Into<String>,
+        client: Arc<dyn DownloadClient>,
+    ) -> Self {
+        self.download_client = Some((name.into(), client));
+        self
+    }
+
+    /// Preferences used to filter and rank indexer results.
+    pub fn with_filter_options(mut self, filter_options: ReleaseFilterOptions) -> Self {
+        self.filter_options = filter_options;
+        self
+    }
+
+    /// Minimum release age, per protocol, before a release may be grabbed.
+    pub fn with_delay_profile(mut self, delay_profile: DelayProfile) -> Self {
+        self.delay_profile = delay_profile;
+        self
+    }
+
+    /// Record the per-album search decision in `store`.
+    pub fn with_decision_store(mut self, store: SearchDecisionStore) -> Self {
+        self.decision_store = store;
+        self
+    }
+
+    async fn load_indexers(&self, job_id: &str) -> Result<Vec<Arc<dyn IndexerClient>>> {
+        if let Some(indexers) = &self.indexers {
+            return Ok(indexers.clone());
+        }
+
+        let definitions = self.indexer_repository.list(5000, 0).await?;
+        Ok(definitions
+            .iter()
+            .filter(|definition| definition.enabled)
+            .filter_map(|definition| {
+                search_client_from_definition(&self.indexer_clients, definition, job_id)
+            })
+            .collect())
+    }
+
+    async fn load_download_client(
+        &self,
+    ) -> Result<(String, Option<String>, Option<Arc<dyn DownloadClient>>)> {
+        if let Some((name, client)) = &self.download_client {
+            return Ok((name.clone(), None, Some(client.clone())));
+        }
+
+        let (name, category, client) =
+            load_active_download_client(&self.download_client_repository, &self.outbound_http)
+                .await?;
+        Ok((name, category, client.map(Arc::from)))
+    }
+
+    /// Collect monitored albums that are wanted and have no tracks, missing track files,
+    /// or files below the quality cutoff.
+    async fn collect_backlog_albums(&self) -> Result<Vec<DomainAlbum>> {
+        let mut seen = HashSet::new();
+        let mut albums = Vec::new();
+
+        let without_tracks = collect_all(self.scan_limit, |limit, offset| {
+            self.album_repository
+                .list_wanted_without_tracks(limit, offset)
+        })
+        .await?;
+        let cutoff_unmet = collect_all(self.scan_limit, |limit, offset| {
+            self.album_repository
+                .list_cutoff_unmet_albums(limit, offset)
+        })
+        .await?;
+        for album in without_tracks.into_iter().chain(cutoff_unmet) {
+            if album.monitored && seen.insert(album.id) {
+                albums.push(album);
+            }
+        }
+
+        let tracks_without_files = collect_all(self.scan_limit, |limit, offset| {
+            self.track_repository.list_without_files(limit, offset)
+        })
+        .await?;
+        let mut missing_album_ids = Vec::new();
+        for track in tracks_without_files {
+            if track.monitored && seen.insert(track.album_id) {
+                missing_album_ids.push(track.album_id);
+            }
+        }
+
+        for album_id in missing_album_ids {
+            if let Some(album) = self
+                .album_repository
+                .get_by_id(&album_id.to_string())
+                .await?
+            {
+                if album.monitored {
+                    albums.push(album);
+                }
+            }
+        }
+
+        Ok(albums)
+    }
+
+    /// Name of artist `artist_id` and the filter options for its albums, which rank by its
+    /// quality profile and reject unofficial releases when its metadata profile allows
+    /// only official ones.
+    ///
+    /// Returns `None` when the artist is missing or not monitored.
+    async fn load_search_artist(
+        &self,
+        artist_id: chorrosion_domain::ArtistId,
+    ) -> Result<Option<(String, ReleaseFilterOptions)>> {
+        let Some(artist) = self
+            .artist_repository
+            .get_by_id(&artist_id.to_string())
+            .await?
+            .filter(|artist| artist.monitored)
+        else {
+            return Ok(None);
+        };
+        let profile = match &artist.metadata_profile_id {
+            Some(id) => {
+                self.metadata_profile_repository
+                    .get_by_id(&id.to_string())
+                    .await?
+            }
+            None => self.metadata_profile_repository.get_default().await?,
+        };
+        let mut options = match profile {
+            Some(profile) => self
+                .filter_options
+                .clone()
+                .with_release_statuses(&profile.release_statuses),
+            None => self.filter_options.clone(),
+        };
+        options.quality_profile = match &artist.quality_profile_id {
+            Some(id) => {
+                self.quality_profile_repository
+                    .get_by_id(&id.to_string())
+                    .await?
+            }
+            None => self.quality_profile_repository.get_default().await?,
+        };
+        Ok(Some((artist.name, options)))
+    }
+
+    /// Search all indexers for `album` and return the best ranked release with a download URL
+    /// that the delay profile allows grabbing now.
+    ///
+    /// When no release matches, the error is [`AutomaticSearchReason::NoResults`]; when every
+    /// match is still too new, it is [`AutomaticSearchReason::Deferred`] with the best of them.
+    async fn search_album(
+        &self,
+        job_id: &str,
+        indexers: &[Arc<dyn IndexerClient>],
+        artist_name: &str,
+        album: &DomainAlbum,
+        options: &ReleaseFilterOptions,
+    ) -> std::result::Result<RankedRelease, AutomaticSearchReason> {
+        let query = IndexerSearchQuery {
+            query: build_album_query(artist_name, &album.title, &QueryBuildOptions::default()),
+            category: Some("music".to_string()),
+            limit: Some(100),
+            offset: Some(0),
+        };
+
+        // Keyed by title and indexer, so copies on different trackers survive until the
+        // dedupe strategy decides which to keep.
+        let mut grabbable: HashMap<(String, String), (IndexerSearchResult, IndexerProtocol)> =
+            HashMap::new();
+        for indexer in indexers {
+            match indexer.search(&query).await {
+                Ok(results) => {
+                    for mut result in results {
+                        let Some(url) = result.download_url.as_deref().map(str::trim) else {
+                            continue;
+                        };
+                        if url.is_empty() {
+                            continue;
+                        }
+                        result.download_url = Some(url.to_string());
+                        let config = indexer.config();
+                        grabbable
+                            .entry((result.title.clone(), config.name.clone()))
+                            .or_insert((result, config.protocol.clone()));
+                    }
+                }
+                Err(error) => {
+                    warn!(
+                        target: "jobs",
+                        job_id = %job_id,
+                        indexer = %indexer.config().name,
+                        album = %album.title,
+                        error = %error,
+                        "backlog search failed for indexer"
+                    );
+                }
+            }
+        }
+
+        let album_key = release_match_key(artist_name, &album.title);
+        let parsed = grabbable
+            .iter()
+            .map(|((title, indexer), (result, protocol))| {
+                let parsed = parse_release_title(title)
+                    .with_size_bytes(result.size_bytes)
+                    .with_indexer(indexer.clone());
+                let torrent = release_torrent_health(
+                    protocol,
+                    result.seeders,
+                    result.published_at.as_deref(),
+                );
+                match torrent {
+                    Some(torrent) => parsed.with_torrent_health(torrent),
+                    None => parsed,
+                }
+            })
+            .filter(|parsed| parsed_release_key(parsed).as_ref() == Some(&album_key))
+            .collect::<Vec<_>>();
+        let filtered = filter_releases(&parsed, options);
+        let deduped = deduplicate_releases_with_strategy(&filtered, options.dedupe_strategy);
+
+        let now = Utc::now();
+        let mut deferred = None;
+        for best in rank_releases(deduped, options) {
+            let key = (
+                best.original_title.clone(),
+                best.indexer.clone().unwrap_or_default(),
+            );
+            let Some((search_result, protocol)) = grabbable.remove(&key) else {
+                continue;
+            };
+            let available_at = self.delay_profile.deferred_until(
+                &protocol,
+                search_result.published_at.as_deref(),
+                now,
+            );
+            let release = RankedRelease {
+                score: score_release(&best, options),
+                parsed: best,
+                search_result,
+            };
+            match available_at {
+                None => return Ok(release),
+                Some(available_at) => {
+                    deferred.get_or_insert(AutomaticSearchReason::Deferred {
+                        release,
+                        available_at,
+                    });
+                }
+            }
+        }
+        Err(deferred.unwrap_or(AutomaticSearchReason::NoResults))
+    }
 }
 
 #[async_trait::async_trait]
@@ -1120,74 +1478,209 @@ impl Job for BacklogSearchJob {
     async fn execute(&self, ctx: JobContext) -> Result<JobResult> {
         info!(target: "jobs", job_id = %ctx.job_id, "executing backlog search job");
 
-        let mut candidate_ids = HashSet::new();
+        let albums = match self.collect_backlog_albums().await {
+            Ok(albums) => albums,
+            Err(error) => {
+                return Ok(JobResult::Failure {
+                    error: format!("failed to collect wanted albums for backlog search: {error}"),
+                    retry: true,
+                });
+            }
+        };
 
-        // Page through all wanted albums without tracks, collecting only IDs
-        let mut missing_count: usize = 0;
-        let mut offset: i64 = 0;
-        loop {
-            let batch = match self
-                .album_repository
-                .list_wanted_without_tracks(self.scan_limit, offset)
-                .await
-            {
-                Ok(albums) => albums,
+        if albums.is_empty() {
+            info!(target: "jobs", job_id = %ctx.job_id, "no wanted albums to search; backlog search completed");
+            return Ok(JobResult::Success);
+        }
+
+        let indexers = match self.load_indexers(&ctx.job_id).await {
+            Ok(indexers) => indexers,
+            Err(error) => {
+                return Ok(JobResult::Failure {
+                    error: format!("failed to list configured indexers: {error}"),
+                    retry: true,
+                });
+            }
+        };
+
+        if indexers.is_empty() {
+            info!(target: "jobs", job_id = %ctx.job_id, "no usable indexers configured; skipping backlog search");
+            return Ok(JobResult::Success);
+        }
+
+        let (download_client_name, download_client_category, download_client) =
+            match self.load_download_client().await {
+                Ok(client) => client,
                 Err(error) => {
                     return Ok(JobResult::Failure {
-                        error: format!("failed to collect wanted albums without tracks: {error}"),
+                        error: format!(
+                            "failed to load download client for backlog search grabs: {error}"
+                        ),
                         retry: true,
                     });
                 }
             };
-            let batch_len = batch.len();
-            missing_count += batch_len;
-            for album in batch {
-                candidate_ids.insert(album.id);
+
+        let Some(download_client) = download_client else {
+            warn!(
+                target: "jobs",
+                job_id = %ctx.job_id,
+                wanted_album_count = albums.len(),
+                "no enabled/usable download client configured; skipping backlog search"
+            );
+            return Ok(JobResult::Success);
+        };
+
+        let mut downloading = match download_client.list_downloads().await {
+            Ok(downloads) => active_download_album_keys(&downloads),
+            Err(error) => {
+                return Ok(JobResult::Failure {
+                    error: format!(
+                        "failed to list downloads from download client '{download_client_name}': {error}"
+                    ),
+                    retry: true,
+                });
             }
-            if batch_len < self.scan_limit as usize {
-                break;
+        };
+
+        let mut artists: HashMap<
+            chorrosion_domain::ArtistId,
+            Option<(String, ReleaseFilterOptions)>,
+        > = HashMap::new();
+        let mut searched: usize = 0;
+        let mut skipped_downloading: usize = 0;
+        let mut skipped_unmonitored_artist: usize = 0;
+        let mut no_results: usize = 0;
+        let mut deferred: usize = 0;
+        let mut grab_succeeded: usize = 0;
+        let mut grab_failed: usize = 0;
+        let mut seen_grab_urls: HashSet<String> = HashSet::new();
+
+        for album in &albums {
+            let album_id = album.id.to_string();
+            let artist = match artists.get(&album.artist_id) {
+                Some(artist) => artist.clone(),
+                None => {
+                    let artist = match self.load_search_artist(album.artist_id).await {
+                        Ok(artist) => artist,
+                        Err(error) => {
+                            warn!(
+                                target: "jobs",
+                                job_id = %ctx.job_id,
+                                album = %album.title,
+                                error = %error,
+                                "failed to load artist for backlog search; skipping album"
+                            );
+                            continue;
+                        }
+                    };
+                    artists.insert(album.artist_id, artist.clone());
+                    artist
+                }
+            };
+            let Some((artist_name, options)) = artist else {
+                skipped_unmonitored_artist += 1;
+                self.decision_store
+                    .record(&album_id, AutomaticSearchReason::Unmonitored);
+                continue;
+            };
+
+            let album_key = release_match_key(&artist_name, &album.title);
+            if downloading.contains(&album_key) {
+                skipped_downloading += 1;
+                self.decision_store
+                    .record(&album_id, AutomaticSearchReason::AlreadyDownloading);
+                debug!(target: "jobs", job_id = %ctx.job_id, artist = %artist_name, album = %album.title, "album already downloading; skipping");
+                continue;
             }
-            offset += self.scan_limit;
-        }
 
-        // Page through all cutoff-unmet albums, collecting only IDs
-        let mut cutoff_unmet_count: usize = 0;
-        let mut cutoff_offset: i64 = 0;
-        loop {
-            let batch = match self
-                .album_repository
-                .list_cutoff_unmet_albums(self.scan_limit, cutoff_offset)
+            searched += 1;
+            let release = match self
+                .search_album(&ctx.job_id, &indexers, &artist_name, album, &options)
                 .await
             {
-                Ok(albums) => albums,
-                Err(error) => {
-                    return Ok(JobResult::Failure {
-                        error: format!("failed to collect cutoff-unmet albums: {error}"),
-                        retry: true,
-                    });
+                Ok(release) => release,
+                Err(reason) => {
+                    if matches!(reason, AutomaticSearchReason::Deferred { .. }) {
+                        deferred += 1;
+                        debug!(target: "jobs", job_id = %ctx.job_id, artist = %artist_name, album = %album.title, "matching releases are newer than the delay profile allows");
+                    } else {
+                        no_results += 1;
+                        debug!(target: "jobs", job_id = %ctx.job_id, artist = %artist_name, album = %album.title, "no suitable release found");
+                    }
+                    self.decision_store.record(&album_id, reason);
+                    continue;
                 }
             };
-            let batch_len = batch.len();
-            cutoff_unmet_count += batch_len;
-            for album in batch {
-                candidate_ids.insert(album.id);
+
+            let download_url = release
+                .search_result
+                .download_url
+                .clone()
+                .unwrap_or_default();
+            if !should_attempt_grab_url(&download_url, &mut seen_grab_urls) {
+                continue;
             }
-            if batch_len < self.scan_limit as usize {
-                break;
+
+            match download_client
+                .add_torrent(AddTorrentRequest {
+                    torrent_or_magnet: download_url,
+                    category: download_client_category.clone(),
+                })
+                .await
+            {
+                Ok(()) => {
+                    grab_succeeded += 1;
+                    downloading.insert(album_key);
+                    info!(
+                        target: "jobs",
+                        job_id = %ctx.job_id,
+                        artist = %artist_name,
+                        album = %album.title,
+                        release_title = %release.search_result.title,
+                        download_client = %download_client_name,
+                        "submitted backlog search grab"
+                    );
+                    self.decision_store
+                        .record(&album_id, AutomaticSearchReason::Grabbed { release });
+                }
+                Err(error) => {
+                    grab_failed += 1;
+                    warn!(
+                        target: "jobs",
+                        job_id = %ctx.job_id,
+                        artist = %artist_name,
+                        album = %album.title,
+                        release_title = %release.search_result.title,
+                        download_client = %download_client_name,
+                        error = %error,
+                        "failed to submit backlog search grab"
+                    );
+                    self.decision_store.record(
+                        &album_id,
+                        AutomaticSearchReason::GrabFailed {
+                            release,
+                            error: error.to_string(),
+                        },
+                    );
+                }
             }
-            cutoff_offset += self.scan_limit;
         }
 
         info!(
             target: "jobs",
             job_id = %ctx.job_id,
-            missing_count,
-            cutoff_unmet_count,
-            scheduled_count = candidate_ids.len(),
-            "automated backlog search scheduling snapshot"
+            wanted_album_count = albums.len(),
+            searched,
+            skipped_downloading,
+            skipped_unmonitored_artist,
+            no_results,
+            deferred,
+            grab_succeeded,
+            grab_failed,
+            download_client = %download_client_name,
+            "backlog search completed"
         );
-
-        info!(target: "jobs", job_id = %ctx.job_id, "backlog search completed");
         Ok(JobResult::Success)
     }
 
@@ -1200,10 +1693,12 @@ impl Job for BacklogSearchJob {
 ///
 /// This job refreshes artist metadata from MusicBrainz based on the artist's MBID.
 /// It implements rate limiting and caching to avoid excessive API calls:
-/// - Tracks refresh timestamps per artist (24-hour TTL by default)
+/// - Tracks refresh timestamps per artist in memory and in `last_metadata_refresh_at` (24-hour TTL by default)
 /// - Skips refresh if already completed within TTL window
 /// - Respects MusicBrainz rate limiting via the client
 /// - Supports both single artist and bulk refresh operations
+/// - Adds the artist's missing release groups as albums, monitoring only those
+///   its metadata profile allows
 pub struct RefreshArtistJob {
     artist_id: Option<String>,
     /// Shared cache for tracking refresh timestamps
@@ -1212,8 +1707,13 @@ pub struct RefreshArtistJob {
     pool: Option<SqlitePool>,
     /// MusicBrainz client for API calls (None in unit-test mode)
     mb_client: Option<Arc<MusicBrainzClient>>,
+    /// Leave release groups rejected by the metadata profile out instead of adding them unmonitored
+    skip_filtered_release_groups: bool,
 }
 
+/// Release groups or releases fetched per MusicBrainz browse request (the API maximum).
+const MUSICBRAINZ_BROWSE_LIMIT: u32 = 100;
+
 impl RefreshArtistJob {
     /// Create a job to refresh a single artist by ID (unit-test constructor; no DB/MB access)
     pub fn single(artist_id: impl Into<String>) -> Self {
@@ -1222,6 +1722,7 @@ impl RefreshArtistJob {
             cache: MetadataRefreshCache::new(),
             pool: None,
             mb_client: None,
+            skip_filtered_release_groups: false,
         }
     }
 
@@ -1232,6 +1733,7 @@ impl RefreshArtistJob {
             cache: MetadataRefreshCache::new(),
             pool: None,
             mb_client: None,
+            skip_filtered_release_groups: false,
         }
     }
 
@@ -1242,6 +1744,7 @@ impl RefreshArtistJob {
             cache,
             pool: None,
             mb_client: None,
+            skip_filtered_release_groups: false,
         }
     }
 
@@ -1258,19 +1761,114 @@ impl RefreshArtistJob {
             cache,
             pool: Some(pool),
             mb_client: Some(mb_client),
+            skip_filtered_release_groups: false,
         }
     }
 
+    /// Skip release groups the artist's metadata profile rejects instead of adding them unmonitored.
+    pub fn with_skip_filtered_release_groups(mut self, skip: bool) -> Self {
+        self.skip_filtered_release_groups = skip;
+        self
+    }
+
     /// Get a reference to the cache for external use (e.g., scheduler reuse across invocations)
     pub fn cache(&self) -> &MetadataRefreshCache {
         &self.cache
     }
 
+    /// Add the artist's release groups missing from the library, filtered by its
+    /// metadata profile (or the default profile when it has none).
+    async fn sync_release_groups(
+        &self,
+        mb_client: &MusicBrainzClient,
+        albums: &dyn AlbumRepository,
+        profiles: &dyn MetadataProfileRepository,
+        artist: &DomainArtist,
+        mbid: Uuid,
+    ) -> Result<ReleaseGroupSyncSummary> {
+        let mut release_groups = Vec::new();
+        loop {
+            let page = mb_client
+                .browse_release_groups_by_artist(
+                    &mbid,
+                    Some(MUSICBRAINZ_BROWSE_LIMIT),
+                    Some(release_groups.len() as u32),
+                )
+                .await?;
+            let fetched = page.results.release_groups.len();
+            release_groups.extend(page.results.release_groups);
+            if fetched == 0 || release_groups.len() >= page.count as usize {
+                break;
+            }
+        }
+
+        let profile = match &artist.metadata_profile_id {
+            Some(id) => profiles.get_by_id(&id.to_string()).await?,
+            None => profiles.get_default().await?,
+        };
+
+        // Statuses are only reported per release, so browse the releases too when
+        // the profile restricts them.
+        let mut release_statuses: HashMap<Uuid, Vec<String>> = HashMap::new();
+        if profile
+            .as_ref()
+            .is_some_and(|profile| !profile.release_statuses.is_empty())
+        {
+            let mut fetched_total = 0usize;
+            loop {
+                let page = mb_client
+                    .browse_releases_by_artist(
+                        &mbid,
+                        Some(MUSICBRAINZ_BROWSE_LIMIT),
+                        Some(fetched_total as u32),
+                    )
+                    .await?;
+                let fetched = page.results.releases.len();
+                fetched_total += fetched;
+                for release in page.results.releases {
+                    if let Some(status) = release.status {
+                        release_statuses
+                            .entry(release.release_group.id)
+                            .or_default()
+                            .push(status);
+                    }
+                }
+                if fetched == 0 || fetched_total >= page.count as usize {
+                    break;
+                }
+            }
+        }
+
+        chorrosion_application::sync_release_groups(
+            albums,
+            artist,
+            profile.as_ref(),
+            &release_groups,
+            &release_statuses,
+            self.skip_filtered_release_groups,
+        )
+        .await
+    }
+
     /// Apply MusicBrainz artist data onto a mutable domain Artist.
     fn apply_mb_artist(artist: &mut DomainArtist, mb: &chorrosion_musicbrainz::models::Artist) {
+        artist.name = mb.name.clone();
         if mb.sort_name != artist.name {
             artist.sort_name = Some(mb.sort_name.clone());
         }
+        if let Some(ended) = mb.life_span.as_ref().and_then(|span| span.ended) {
+            artist.status = if ended {
+                ArtistStatus::Ended
+            } else {
+                ArtistStatus::Continuing
+            };
+        }
+        if !mb.genres.is_empty() {
+            let mut genres = mb.genres.clone();
+            genres.sort_by_key(|genre| std::cmp::Reverse(genre.count));
+            let names: Vec<String> = genres.into_iter().map(|genre| genre.name).collect();
+            artist.genre_tags = Some(names.join(","));
+        }
         if mb.artist_type.is_some() {
             artist.artist_type = mb.artist_type.clone();
         }
@@ -1280,7 +1878,18 @@ impl RefreshArtistJob {
         if mb.disambiguation.is_some() {
             artist.disambiguation = mb.disambiguation.clone();
         }
-        artist.updated_at = Utc::now();
+    }
+
+    async fn dispatch_updated(events: &EventDispatcher, artist: &DomainArtist) {
+        let event: ArtistUpdated = DomainEvent::new(
+            "artist.updated",
+            ArtistUpdatedPayload {
+                artist_id: artist.id,
+                name: artist.name.clone(),
+                monitored: artist.monitored,
+            },
+        );
+        events.dispatch(&event).await;
     }
 }
 
@@ -1300,7 +1909,36 @@ impl Job for RefreshArtistJob {
     async fn execute(&self, ctx: JobContext) -> Result<JobResult> {
         self.cache.prune_stale_entries();
 
-        let (Some(pool), Some(mb_client)) = (self.pool.as_ref(), self.mb_client.as_ref()) else {
+        // Dependencies passed to the constructor win over the shared ones in the context.
+        let shared = ctx.dependencies.as_deref();
+        let repo: Option<Arc<dyn ArtistRepository>> = match (&self.pool, shared) {
+            (Some(pool), _) => Some(Arc::new(SqliteArtistRepository::new(pool.clone()))),
+            (None, Some(shared)) => Some(shared.artist_repository.clone()),
+            (None, None) => None,
+        };
+        let library_repos: Option<(Arc<dyn AlbumRepository>, Arc<dyn MetadataProfileRepository>)> =
+            match (&self.pool, shared) {
+                (Some(pool), _) => Some((
+                    Arc::new(SqliteAlbumRepository::new(pool.clone())),
+                    Arc::new(SqliteMetadataProfileRepository::new(pool.clone())),
+                )),
+                (None, Some(shared)) => Some((
+                    shared.album_repository.clone(),
+                    shared.metadata_profile_repository.clone(),
+                )),
+                (None, None) => None,
+            };
+        let mb_client = self
+            .mb_client
+            .clone()
+            .or_else(|| shared.and_then(|shared| shared.musicbrainz_client.clone()));
+        let events = shared
+            .map(|shared| shared.event_dispatcher.clone())
+            .unwrap_or_default();
+
+        let (Some(repo), Some((album_repo, profile_repo)), Some(mb_client)) =
+            (repo, library_repos, mb_client)
+        else {
             // No dependencies injected — used in unit tests or scheduler dry-run
             if let Some(id) = &self.artist_id {
                 match Uuid::parse_str(id) {
@@ -1322,8 +1960,6 @@ impl Job for RefreshArtistJob {
             return Ok(JobResult::Success);
         };
 
-        let repo = SqliteArtistRepository::new(pool.clone());
-
         match &self.artist_id {
             Some(id) => {
                 info!(target: "jobs", job_id = %ctx.job_id, artist_id = %id, "refreshing single artist metadata");
@@ -1354,6 +1990,15 @@ impl Job for RefreshArtistJob {
                     }
                 };
 
+                if !self
+                    .cache
+                    .is_persisted_refresh_stale(artist.last_metadata_refresh_at)
+                {
+                    debug!(target: "jobs", job_id = %ctx.job_id, artist_id = %id,
+                           "artist refresh persisted within TTL, skipping");
+                    return Ok(JobResult::Success);
+                }
+
                 let mbid_str = match &artist.musicbrainz_artist_id {
                     Some(m) => m.clone(),
                     None => {
@@ -1374,9 +2019,30 @@ impl Job for RefreshArtistJob {
                 match mb_client.lookup_artist(mbid).await {
                     Ok(mb_artist) => {
                         Self::apply_mb_artist(&mut artist, &mb_artist);
-                        repo.update(artist).await?;
+                        let updated = repo.update(artist).await?;
+                        repo.record_metadata_refresh(updated.id, Utc::now()).await?;
+                        Self::dispatch_updated(&events, &updated).await;
                         self.cache.try_mark_artist_refreshed(uuid);
                         info!(target: "jobs", job_id = %ctx.job_id, artist_id = %id, %mbid, "artist metadata refreshed");
+
+                        match self
+                            .sync_release_groups(
+                                &mb_client,
+                                album_repo.as_ref(),
+                                profile_repo.as_ref(),
+                                &updated,
+                                mbid,
+                            )
+                            .await
+                        {
+                            Ok(summary) => {
+                                info!(target: "jobs", job_id = %ctx.job_id, artist_id = %id, ?summary, "artist release groups synced")
+                            }
+                            Err(e) => {
+                                warn!(target: "jobs", job_id = %ctx.job_id, artist_id = %id, %mbid,
+                                      error = %e, "failed to sync artist release groups")
+                            }
+                        }
                     }
                     Err(e) => {
                         warn!(target: "jobs", job_id = %ctx.job_id, artist_id = %id, %mbid,
@@ -1407,7 +2073,11 @@ impl Job for RefreshArtistJob {
 
                     for mut artist in artists {
                         let uuid = artist.id.0;
-                        if !self.cache.should_refresh_artist(uuid) {
+                        if !self.cache.should_refresh_artist(uuid)
+                            || !self
+                                .cache
+                                .is_persisted_refresh_stale(artist.last_metadata_refresh_at)
+                        {
                             continue;
                         }
 
@@ -1431,9 +2101,31 @@ impl Job for RefreshArtistJob {
                                               error = %e, "failed to persist artist update");
                                         failures += 1;
                                     }
-                                    _ => {
+                                    Ok(updated) => {
+                                        if let Err(e) = repo
+                                            .record_metadata_refresh(updated.id, Utc::now())
+                                            .await
+                                        {
+                                            warn!(target: "jobs", job_id = %ctx.job_id, %mbid,
+                                                  error = %e, "failed to record artist refresh time");
+                                        }
+                                        Self::dispatch_updated(&events, &updated).await;
                                         self.cache.try_mark_artist_refreshed(uuid);
                                         refreshed += 1;
+
+                                        if let Err(e) = self
+                                            .sync_release_groups(
+                                                &mb_client,
+                                                album_repo.as_ref(),
+                                                profile_repo.as_ref(),
+                                                &updated,
+                                                mbid,
+                                            )
+                                            .await
+                                        {
+                                            warn!(target: "jobs", job_id = %ctx.job_id, %mbid,
+                                                  error = %e, "failed to sync artist release groups");
+                                        }
                                     }
                                 }
                             }
@@ -1473,7 +2165,7 @@ impl Job for RefreshArtistJob {
 ///
 /// This job refreshes album metadata from MusicBrainz based on the album's MBID.
 /// It implements rate limiting and caching similar to RefreshArtistJob:
-/// - Tracks refresh timestamps per album (24-hour TTL by default)
+/// - Tracks refresh timestamps per album in memory and in `last_metadata_refresh_at` (24-hour TTL by default)
 /// - Skips refresh if already completed within TTL window
 /// - Respects MusicBrainz rate limiting via the client
 /// - Supports both single album and bulk refresh operations
@@ -1553,7 +2245,6 @@ impl RefreshAlbumJob {
         if mb.first_release_date.is_some() {
             album.first_release_date = mb.first_release_date.clone();
         }
-        album.updated_at = Utc::now();
     }
 }
 
@@ -1627,6 +2318,15 @@ impl Job for RefreshAlbumJob {
                     }
                 };
 
+                if !self
+                    .cache
+                    .is_persisted_refresh_stale(album.last_metadata_refresh_at)
+                {
+                    debug!(target: "jobs", job_id = %ctx.job_id, album_id = %id,
+                           "album refresh persisted within TTL, skipping");
+                    return Ok(JobResult::Success);
+                }
+
                 let mbid_str = match &album.musicbrainz_release_group_id {
                     Some(m) => m.clone(),
                     None => {
@@ -1647,7 +2347,8 @@ impl Job for RefreshAlbumJob {
                 match mb_client.lookup_album(mbid).await {
                     Ok(mb_album) => {
                         Self::apply_mb_album(&mut album, &mb_album);
-                        repo.update(album).await?;
+                        let updated = repo.update(album).await?;
+                        repo.record_metadata_refresh(updated.id, Utc::now()).await?;
                         self.cache.try_mark_album_refreshed(uuid);
                         info!(target: "jobs", job_id = %ctx.job_id, album_id = %id, %mbid, "album metadata refreshed");
                     }
@@ -1680,7 +2381,11 @@ impl Job for RefreshAlbumJob {
 
                     for mut album in albums {
                         let uuid = album.id.0;
-                        if !self.cache.should_refresh_album(uuid) {
+                        if !self.cache.should_refresh_album(uuid)
+                            || !self
+                                .cache
+                                .is_persisted_refresh_stale(album.last_metadata_refresh_at)
+                        {
                             continue;
                         }
 
@@ -1704,7 +2409,14 @@ impl Job for RefreshAlbumJob {
                                               error = %e, "failed to persist album update");
                                         failures += 1;
                                     }
-                                    _ => {
+                                    Ok(updated) => {
+                                        if let Err(e) = repo
+                                            .record_metadata_refresh(updated.id, Utc::now())
+                                            .await
+                                        {
+                                            warn!(target: "jobs", job_id = %ctx.job_id, %mbid,
+                                                  error = %e, "failed to record album refresh time");
+                                        }
                                         self.cache.try_mark_album_refreshed(uuid);
                                         refreshed += 1;
                                     }
@@ -1742,21 +2454,401 @@ impl Job for RefreshAlbumJob {
     }
 }
 
-/// Housekeeping job - cleanup, backups, maintenance tasks
-pub struct HousekeepingJob;
+/// Files fingerprinted at once by [`FingerprintScanJob`] unless overridden.
+const DEFAULT_FINGERPRINT_CONCURRENCY: usize = 4;
 
-impl HousekeepingJob {
+/// Page size used when scanning `track_files` for rows without a fingerprint.
+const FINGERPRINT_SCAN_PAGE_SIZE: i64 = 100;
+
+/// AcoustID allows three lookups per second per application key.
+const ACOUSTID_MIN_INTERVAL: std::time::Duration = std::time::Duration::from_millis(334);
+
+/// Produces a Chromaprint fingerprint for an audio file.
+#[async_trait::async_trait]
+pub trait FingerprintSource: Send + Sync {
+    async fn fingerprint(&self, path: &std::path::Path) -> Result<Fingerprint>;
+}
+
+#[async_trait::async_trait]
+impl FingerprintSource for FingerprintGenerator {
+    async fn fingerprint(&self, path: &std::path::Path) -> Result<Fingerprint> {
+        Ok(self.generate_from_file(path).await?)
+    }
+}
+
+/// Finds the best-scoring recording for a fingerprint.
+#[async_trait::async_trait]
+pub trait FingerprintLookup: Send + Sync {
+    /// The highest-scoring match, or `None` when the fingerprint is unknown.
+    async fn best_match(&self, fingerprint: &Fingerprint) -> Result<Option<RecordingMatch>>;
+}
+
+#[async_trait::async_trait]
+impl FingerprintLookup for AcoustidClient {
+    async fn best_match(&self, fingerprint: &Fingerprint) -> Result<Option<RecordingMatch>> {
+        let matches = self.lookup(fingerprint, 0.0).await?;
+        Ok(matches
+            .into_iter()
+            .max_by(|a, b| a.score.total_cmp(&b.score)))
+    }
+}
+
+/// How one [`FingerprintScanJob`] run went.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FingerprintScanSummary {
+    pub fingerprinted: usize,
+    pub matched: usize,
+    pub failed: usize,
+}
+
+/// Fingerprint scan job - generates fingerprints for track files that lack one
+///
+/// Pages through `list_without_fingerprints`, fingerprints up to `concurrency`
+/// files at a time and stores the result on the track file.  When an AcoustID
+/// client is available (set on the job or in the shared dependencies) each new
+/// fingerprint is also looked up, paced by the AcoustID rate limiter, and the
+/// best score is recorded as the track's `match_confidence`.
+pub struct FingerprintScanJob {
+    source: Arc<dyn FingerprintSource>,
+    lookup: Option<Arc<dyn FingerprintLookup>>,
+    rate_limiter: RateLimiter,
+    concurrency: usize,
+}
+
+impl FingerprintScanJob {
     pub fn new() -> Self {
-        Self
+        Self {
+            source: Arc::new(FingerprintGenerator::new()),
+            lookup: None,
+            rate_limiter: RateLimiter::new(ACOUSTID_MIN_INTERVAL),
+            concurrency: DEFAULT_FINGERPRINT_CONCURRENCY,
+        }
+    }
+
+    /// Generate fingerprints with `source` instead of Chromaprint.
+    pub fn with_source(mut self, source: Arc<dyn FingerprintSource>) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Look fingerprints up with `lookup` instead of the shared AcoustID client.
+    pub fn with_lookup(mut self, lookup: Arc<dyn FingerprintLookup>) -> Self {
+        self.lookup = Some(lookup);
+        self
+    }
+
+    /// Pace lookups through `rate_limiter`, e.g. one shared with other AcoustID users.
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    /// Fingerprint at most `concurrency` files at once (minimum 1).
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    async fn scan(&self, ctx: &JobContext) -> Result<FingerprintScanSummary> {
+        let dependencies = ctx.dependencies()?;
+        let lookup = self.lookup.clone().or_else(|| {
+            dependencies
+                .acoustid_client
+                .clone()
+                .map(|client| client as Arc<dyn FingerprintLookup>)
+        });
+        let semaphore = Arc::new(Semaphore::new(self.concurrency));
+        let mut summary = FingerprintScanSummary::default();
+        // Not `for_each_page`: fingerprinted files drop out of the listing, so only failures
+        // move the offset.
+        let mut offset = 0;
+
+        loop {
+            let page = dependencies
+                .track_file_repository
+                .list_without_fingerprints(FINGERPRINT_SCAN_PAGE_SIZE, offset)
+                .await?;
+            let page_len = page.len() as i64;
+            let mut set: JoinSet<Result<bool, (String, String)>> = JoinSet::new();
+
+            for track_file in page {
+                if track_file.fingerprint_hash.is_some() {
+                    continue;
+                }
+                // The semaphore is created locally and never closed.
+                let permit = Arc::clone(&semaphore)
+                    .acquire_owned()
+                    .await
+                    .expect("fingerprint semaphore closed unexpectedly");
+                let task = FingerprintTask {
+                    source: Arc::clone(&self.source),
+                    lookup: lookup.clone(),
+                    rate_limiter: self.rate_limiter.clone(),
+                    dependencies: dependencies.clone(),
+                };
+                set.spawn(async move {
+                    let _permit = permit;
+                    let path = track_file.path.clone();
+                    task.run(track_file)
+                        .await
+                        .map_err(|error| (path, error.to_string()))
+                });
+            }
+
+            let mut page_failures = 0;
+            while let Some(joined) = set.join_next().await {
+                match joined {
+                    Ok(Ok(matched)) => {
+                        summary.fingerprinted += 1;
+                        summary.matched += usize::from(matched);
+                    }
+                    Ok(Err((path, error))) => {
+                        page_failures += 1;
+                        warn!(target: "jobs", job_id = %ctx.job_id, %path, %error, "failed to fingerprint track file");
+                    }
+                    Err(join_err) => {
+                        page_failures += 1;
+                        warn!(target: "jobs", job_id = %ctx.job_id, error = %join_err, "fingerprint task panicked");
+                    }
+                }
+            }
+            summary.failed += page_failures;
+            offset += page_failures as i64;
+
+            if page_len < FINGERPRINT_SCAN_PAGE_SIZE {
+                break;
+            }
+        }
+
+        Ok(summary)
     }
 }
 
-impl Default for HousekeepingJob {
+impl Default for FingerprintScanJob {
     fn default() -> Self {
         Self::new()
     }
 }
 
+/// Everything one spawned fingerprint task needs.
+struct FingerprintTask {
+    source: Arc<dyn FingerprintSource>,
+    lookup: Option<Arc<dyn FingerprintLookup>>,
+    rate_limiter: RateLimiter,
+    dependencies: JobDependencies,
+}
+
+impl FingerprintTask {
+    /// Fingerprint `track_file` and, when a lookup is configured, score its track.
+    /// Returns whether a match confidence was recorded.
+    async fn run(self, mut track_file: TrackFile) -> Result<bool> {
+        let fingerprint = self
+            .source
+            .fingerprint(std::path::Path::new(&track_file.path))
+            .await?;
+        let now = Utc::now();
+        track_file.fingerprint_hash = Some(fingerprint.hash.clone());
+        track_file.fingerprint_duration = Some(fingerprint.duration);
+        track_file.fingerprint_computed_at = Some(now);
+        let track_id = track_file.track_id;
+        self.dependencies
+            .track_file_repository
+            .update(track_file)
+            .await?;
+
+        let Some(lookup) = self.lookup else {
+            return Ok(false);
+        };
+        let best_match = {
+            let _permit = self.rate_limiter.acquire().await;
+            lookup.best_match(&fingerprint).await
+        };
+        let best_match = match best_match {
+            Ok(Some(best_match)) => best_match,
+            Ok(None) => return Ok(false),
+            Err(error) => {
+                // The fingerprint is already stored; the next match pass can retry.
+                warn!(target: "jobs", %track_id, %error, "AcoustID lookup failed");
+                return Ok(false);
+            }
+        };
+
+        let Some(mut track) = self
+            .dependencies
+            .track_repository
+            .get_by_id(&track_id.to_string())
+            .await?
+        else {
+            return Ok(false);
+        };
+        track.match_confidence = Some(best_match.score);
+        if track.musicbrainz_recording_id.is_none() {
+            track.musicbrainz_recording_id = Some(best_match.id.to_string());
+        }
+        self.dependencies.track_repository.update(track).await?;
+        debug!(target: "jobs", %track_id, score = best_match.score, "recorded fingerprint match confidence");
+        Ok(true)
+    }
+}
+
+#[async_trait::async_trait]
+impl Job for FingerprintScanJob {
+    fn job_type(&self) -> &'static str {
+        "fingerprint_scan"
+    }
+
+    fn name(&self) -> String {
+        "Fingerprint scan".to_string()
+    }
+
+    async fn execute(&self, ctx: JobContext) -> Result<JobResult> {
+        info!(target: "jobs", job_id = %ctx.job_id, "executing fingerprint scan job");
+        let summary = self.scan(&ctx).await?;
+        if summary.failed > 0 {
+            // Undecodable files fail on every run, so they are reported but not retried.
+            warn!(target: "jobs", job_id = %ctx.job_id, failed = summary.failed, "some track files could not be fingerprinted");
+        }
+        info!(
+            target: "jobs",
+            job_id = %ctx.job_id,
+            fingerprinted = summary.fingerprinted,
+            matched = summary.matched,
+            failed = summary.failed,
+            "fingerprint scan completed"
+        );
+        Ok(JobResult::Success)
+    }
+
+    fn is_retriable(&self) -> bool {
+        false
+    }
+}
+
+/// Default number of `job_runs` rows kept by [`HousekeepingJob`].
+const DEFAULT_JOB_HISTORY_RETENTION: usize = 1000;
+
+/// Page size used when scanning `track_files` for rows whose file is gone.
+const ORPHAN_SCAN_PAGE_SIZE: i64 = 500;
+
+/// Housekeeping job - database maintenance, cache pruning and history cleanup
+///
+/// Each task runs independently: a failure is logged and the remaining tasks
+/// still run.  The job reports a non-retriable failure if any task failed.
+pub struct HousekeepingJob {
+    pool: SqlitePool,
+    refresh_caches: Vec<MetadataRefreshCache>,
+    job_history_retention: usize,
+    /// Snapshot directory and how many snapshots to keep there; `None` skips backups.
+    backups: Option<(PathBuf, usize)>,
+}
+
+impl HousekeepingJob {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            pool,
+            refresh_caches: Vec::new(),
+            job_history_retention: DEFAULT_JOB_HISTORY_RETENTION,
+            backups: None,
+        }
+    }
+
+    /// Snapshot the database into `backup_dir` on every run, keeping the `retention` most recent.
+    pub fn with_database_backups(mut self, backup_dir: PathBuf, retention: usize) -> Self {
+        self.backups = Some((backup_dir, retention));
+        self
+    }
+
+    /// Prune stale entries from `cache` on every run.  May be called repeatedly.
+    pub fn with_refresh_cache(mut self, cache: MetadataRefreshCache) -> Self {
+        self.refresh_caches.push(cache);
+        self
+    }
+
+    /// Keep only the `retention` most recent `job_runs` rows.
+    pub fn with_job_history_retention(mut self, retention: usize) -> Self {
+        self.job_history_retention = retention;
+        self
+    }
+
+    /// Run `PRAGMA optimize` followed by `VACUUM` to refresh planner statistics
+    /// and reclaim free pages.
+    async fn optimize_database(&self) -> Result<()> {
+        sqlx::query("PRAGMA optimize").execute(&self.pool).await?;
+        sqlx::query("VACUUM").execute(&self.pool).await?;
+        Ok(())
+    }
+
+    /// Prune stale entries from every registered metadata refresh cache.
+    fn prune_refresh_caches(&self) -> usize {
+        for cache in &self.refresh_caches {
+            cache.prune_stale_entries();
+        }
+        self.refresh_caches.len()
+    }
+
+    /// Delete `track_files` rows whose file no longer exists on disk.
+    ///
+    /// A row is only treated as orphaned when its parent directory still exists,
+    /// so an unmounted library root does not wipe the whole table.
+    async fn prune_orphaned_track_files(&self) -> Result<u64> {
+        let rows: Vec<(String, String)> = collect_all(ORPHAN_SCAN_PAGE_SIZE, |limit, offset| {
+            let pool = &self.pool;
+            async move {
+                Ok(
+                    sqlx::query_as("SELECT id, path FROM track_files ORDER BY id LIMIT ? OFFSET ?")
+                        .bind(limit)
+                        .bind(offset)
+                        .fetch_all(pool)
+                        .await?,
+                )
+            }
+        })
+        .await?;
+
+        let mut orphaned = Vec::new();
+        for (id, path) in rows {
+            if is_orphaned_file(std::path::Path::new(&path)).await {
+                debug!(target: "jobs", track_file_id = %id, %path, "track file missing on disk");
+                orphaned.push(id);
+            }
+        }
+
+        let mut deleted = 0;
+        for id in orphaned {
+            deleted += sqlx::query("DELETE FROM track_files WHERE id = ?")
+                .bind(id)
+                .execute(&self.pool)
+                .await?
+                .rows_affected();
+        }
+        Ok(deleted)
+    }
+
+    /// Delete all but the `job_history_retention` most recent `job_runs` rows.
+    async fn trim_job_history(&self) -> Result<u64> {
+        let retention = i64::try_from(self.job_history_retention).unwrap_or(i64::MAX);
+        let result = sqlx::query(
+            "DELETE FROM job_runs WHERE id NOT IN \
+             (SELECT id FROM job_runs ORDER BY finished_at DESC, id DESC LIMIT ?)",
+        )
+        .bind(retention)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+}
+
+/// A file is orphaned when it is definitely missing but its directory is present.
+async fn is_orphaned_file(path: &std::path::Path) -> bool {
+    let parent_exists = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => {
+            tokio::fs::try_exists(parent).await.unwrap_or(false)
+        }
+        _ => false,
+    };
+    parent_exists && matches!(tokio::fs::try_exists(path).await, Ok(false))
+}
+
 #[async_trait::async_trait]
 impl Job for HousekeepingJob {
     fn job_type(&self) -> &'static str {
@@ -1769,17 +2861,66 @@ impl Job for HousekeepingJob {
 
     async fn execute(&self, ctx: JobContext) -> Result<JobResult> {
         info!(target: "jobs", job_id = %ctx.job_id, "executing housekeeping job");
+        let mut failed_tasks = Vec::new();
+
+        if let Some((backup_dir, retention)) = &self.backups {
+            match create_database_backup(&self.pool, backup_dir, *retention).await {
+                Ok(path) => {
+                    info!(target: "jobs", job_id = %ctx.job_id, backup = %path.display(), "database backed up")
+                }
+                Err(e) => {
+                    warn!(target: "jobs", job_id = %ctx.job_id, error = %e, "database backup failed");
+                    failed_tasks.push("backup-database");
+                }
+            }
+        }
 
-        // TODO: Implement housekeeping tasks
-        // - Cleanup old job logs
-        // - Vacuum database
-        // - Remove orphaned files
-        // - Create backups if configured
+        match self.optimize_database().await {
+            Ok(()) => {
+                info!(target: "jobs", job_id = %ctx.job_id, "database optimized and vacuumed")
+            }
+            Err(e) => {
+                warn!(target: "jobs", job_id = %ctx.job_id, error = %e, "database optimize/vacuum failed");
+                failed_tasks.push("optimize-database");
+            }
+        }
 
-        tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+        let caches = self.prune_refresh_caches();
+        info!(target: "jobs", job_id = %ctx.job_id, caches, "pruned metadata refresh caches");
 
-        info!(target: "jobs", job_id = %ctx.job_id, "housekeeping completed");
-        Ok(JobResult::Success)
+        match self.prune_orphaned_track_files().await {
+            Ok(deleted) => {
+                info!(target: "jobs", job_id = %ctx.job_id, deleted, "pruned orphaned track files")
+            }
+            Err(e) => {
+                warn!(target: "jobs", job_id = %ctx.job_id, error = %e, "orphaned track file pruning failed");
+                failed_tasks.push("prune-orphaned-track-files");
+            }
+        }
+
+        match self.trim_job_history().await {
+            Ok(deleted) => info!(
+                target: "jobs",
+                job_id = %ctx.job_id,
+                deleted,
+                retention = self.job_history_retention,
+                "trimmed job run history"
+            ),
+            Err(e) => {
+                warn!(target: "jobs", job_id = %ctx.job_id, error = %e, "job history trimming failed");
+                failed_tasks.push("trim-job-history");
+            }
+        }
+
+        if failed_tasks.is_empty() {
+            info!(target: "jobs", job_id = %ctx.job_id, "housekeeping completed");
+            Ok(JobResult::Success)
+        } else {
+            Ok(JobResult::Failure {
+                error: format!("housekeeping tasks failed: {}", failed_tasks.join(", ")),
+                retry: false,
+            })
+        }
     }
 
     fn is_retriable(&self) -> bool {
@@ -1789,6 +2930,8 @@ impl Job for HousekeepingJob {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chorrosion_application::{DownloadItem, DownloadState, IndexerConfig};
+    use chorrosion_realtime::RecordingHub;
 
     #[test]
     fn test_lastfm_job_not_created_without_api_key() {
@@ -2005,6 +3148,323 @@ mod tests {
         }
     }
 
+    const PORTISHEAD_MBID: &str = "8f6bd1e4-fbe1-4f50-aa9b-94c450ec0f11";
+
+    /// A context whose shared dependencies use `pool` and a MusicBrainz client
+    /// pointed at `mock_server`.
+    fn refresh_context(
+        pool: sqlx::SqlitePool,
+        mock_server: &wiremock::MockServer,
+        hub: Arc<RecordingHub>,
+    ) -> JobContext {
+        let mb_client = MusicBrainzClient::builder()
+            .base_url(mock_server.uri())
+            .build()
+            .unwrap();
+        let dependencies = crate::job::JobDependencies::from_pool(
+            pool,
+            &chorrosion_config::DatabaseConfig::default(),
+        )
+        .with_musicbrainz_client(Arc::new(mb_client))
+        .with_event_dispatcher(EventDispatcher::new(hub));
+        JobContext::new("refresh-artist").with_dependencies(Arc::new(dependencies))
+    }
+
+    #[tokio::test]
+    async fn test_refresh_single_artist_applies_musicbrainz_metadata() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let pool = make_migrated_pool().await;
+        let repo = SqliteArtistRepository::new(pool.clone());
+        let mut artist = DomainArtist::new("portishead");
+        artist.musicbrainz_artist_id = Some(PORTISHEAD_MBID.to_string());
+        let artist = repo.create(artist).await.unwrap();
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(format!("/artist/{PORTISHEAD_MBID}")))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(
+                format!(
+                    r#"{{
+                        "id": "{PORTISHEAD_MBID}",
+                        "name": "Portishead",
+                        "sort-name": "Portishead",
+                        "disambiguation": "Bristol trip hop group",
+                        "type": "Group",
+                        "country": "GB",
+                        "life-span": {{ "begin": "1991", "end": null, "ended": true }},
+                        "genres": [
+                            {{ "name": "electronic", "count": 3 }},
+                            {{ "name": "trip hop", "count": 14 }}
+                        ]
+                    }}"#
+                ),
+                "application/json",
+            ))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let hub = Arc::new(RecordingHub::default());
+
+        let result = RefreshArtistJob::single(artist.id.to_string())
+            .execute(refresh_context(pool, &mock_server, hub.clone()))
+            .await
+            .unwrap();
+
+        assert!(matches!(result, JobResult::Success));
+        let refreshed = repo
+            .get_by_id(&artist.id.to_string())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(refreshed.name, "Portishead");
+        assert_eq!(
+            refreshed.disambiguation.as_deref(),
+            Some("Bristol trip hop group")
+        );
+        assert_eq!(refreshed.country.as_deref(), Some("GB"));
+        assert_eq!(refreshed.status, chorrosion_domain::ArtistStatus::Ended);
+        assert_eq!(refreshed.genre_tags.as_deref(), Some("trip hop,electronic"));
+        assert!(refreshed.last_metadata_refresh_at.is_some());
+
+        let messages = hub.messages();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].channel, "artists");
+        assert!(messages[0].payload.contains("artist.updated"));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_artist_events_reach_sse_subscribers_through_the_scheduler() {
+        use chorrosion_realtime::{SseHub, WebSocketHub};
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let pool = make_migrated_pool().await;
+        let repo = SqliteArtistRepository::new(pool.clone());
+        let mut artist = DomainArtist::new("portishead");
+        artist.musicbrainz_artist_id = Some(PORTISHEAD_MBID.to_string());
+        let artist = repo.create(artist).await.unwrap();
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(format!("/artist/{PORTISHEAD_MBID}")))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(
+                format!(
+                    r#"{{ "id": "{PORTISHEAD_MBID}", "name": "Portishead", "sort-name": "Portishead" }}"#
+                ),
+                "application/json",
+            ))
+            .mount(&mock_server)
+            .await;
+        let mb_client = MusicBrainzClient::builder()
+            .base_url(mock_server.uri())
+            .build()
+            .unwrap();
+
+        // Wired the way the CLI wires the API state's dispatcher into the scheduler.
+        let websocket_hub = WebSocketHub::default();
+        let sse_hub = SseHub::default();
+        let mut websocket_receiver = websocket_hub.subscribe();
+        let mut sse_receiver = sse_hub.subscribe();
+        let scheduler = crate::Scheduler::new(chorrosion_config::AppConfig::default(), pool)
+            .with_event_dispatcher(
+                EventDispatcher::new(Arc::new(websocket_hub)).with_hub(Arc::new(sse_hub)),
+            );
+        let dependencies = scheduler.job_dependencies(Some(Arc::new(mb_client)));
+        let ctx = JobContext::new("refresh-artist").with_dependencies(Arc::new(dependencies));
+
+        let result = RefreshArtistJob::single(artist.id.to_string())
+            .execute(ctx)
+            .await
+            .unwrap();
+
+        assert!(matches!(result, JobResult::Success));
+        let message = sse_receiver.try_recv().expect("sse event");
+        assert_eq!(message.channel, "artists");
+        assert!(message.payload.contains("artist.updated"));
+        assert_eq!(websocket_receiver.try_recv().expect("ws event"), message);
+    }
+
+    #[tokio::test]
+    async fn test_refresh_single_artist_adds_release_groups_filtered_by_metadata_profile() {
+        use chorrosion_domain::{MetadataProfile, STUDIO_SECONDARY_TYPE};
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let pool = make_migrated_pool().await;
+        let mut profile = MetadataProfile::new("Studio Albums");
+        profile.primary_album_types = vec!["Album".to_string()];
+        profile.secondary_album_types = vec![STUDIO_SECONDARY_TYPE.to_string()];
+        profile.release_statuses = vec!["Official".to_string()];
+        let profile = SqliteMetadataProfileRepository::new(pool.clone())
+            .create(profile)
+            .await
+            .unwrap();
+        let repo = SqliteArtistRepository::new(pool.clone());
+        let mut artist = DomainArtist::new("portishead");
+        artist.musicbrainz_artist_id = Some(PORTISHEAD_MBID.to_string());
+        artist.metadata_profile_id = Some(profile.id);
+        let artist = repo.create(artist).await.unwrap();
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(format!("/artist/{PORTISHEAD_MBID}")))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(
+                format!(r#"{{ "id": "{PORTISHEAD_MBID}", "name": "Portishead", "sort-name": "Portishead" }}"#),
+                "application/json",
+            ))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/release-group"))
+            .and(query_param("artist", PORTISHEAD_MBID))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(
+                r#"{
+                    "release-group-count": 2,
+                    "release-group-offset": 0,
+                    "release-groups": [
+                        {
+                            "id": "48140466-cff6-3222-bd55-63c27e43190d",
+                            "title": "Dummy",
+                            "primary-type": "Album",
+                            "secondary-types": [],
+                            "first-release-date": "1994-08-22"
+                        },
+                        {
+                            "id": "1b1f4b9e-9bb0-3d1a-a6a7-4a5a3a1e2f2c",
+                            "title": "Roseland NYC Live",
+                            "primary-type": "Album",
+                            "secondary-types": ["Live"],
+                            "first-release-date": "1998-11-02"
+                        }
+                    ]
+                }"#,
+                "application/json",
+            ))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/release"))
+            .and(query_param("artist", PORTISHEAD_MBID))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(
+                r#"{
+                    "release-count": 2,
+                    "release-offset": 0,
+                    "releases": [
+                        {
+                            "id": "76df3287-6cda-33eb-8e9a-044b5e15ffdd",
+                            "title": "Dummy",
+                            "status": "Official",
+                            "release-group": {
+                                "id": "48140466-cff6-3222-bd55-63c27e43190d",
+                                "title": "Dummy"
+                            }
+                        },
+                        {
+                            "id": "a2f3c8e6-1f4b-4d8e-9c3a-1e2f3a4b5c6d",
+                            "title": "Roseland NYC Live",
+                            "status": "Official",
+                            "release-group": {
+                                "id": "1b1f4b9e-9bb0-3d1a-a6a7-4a5a3a1e2f2c",
+                                "title": "Roseland NYC Live"
+                            }
+                        }
+                    ]
+                }"#,
+                "application/json",
+            ))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let hub = Arc::new(RecordingHub::default());
+
+        let result = RefreshArtistJob::single(artist.id.to_string())
+            .execute(refresh_context(pool.clone(), &mock_server, hub))
+            .await
+            .unwrap();
+
+        assert!(matches!(result, JobResult::Success));
+        let mut albums: Vec<_> = SqliteAlbumRepository::new(pool)
+            .get_by_artist(artist.id, 10, 0)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|album| (album.title, album.monitored))
+            .collect();
+        albums.sort();
+        assert_eq!(
+            albums,
+            [
+                ("Dummy".to_string(), true),
+                ("Roseland NYC Live".to_string(), false)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_refresh_single_artist_skips_when_persisted_refresh_is_recent() {
+        use wiremock::{matchers::any, Mock, MockServer, ResponseTemplate};
+
+        let pool = make_migrated_pool().await;
+        let repo = SqliteArtistRepository::new(pool.clone());
+        let mut artist = DomainArtist::new("portishead");
+        artist.musicbrainz_artist_id = Some(PORTISHEAD_MBID.to_string());
+        let artist = repo.create(artist).await.unwrap();
+        repo.record_metadata_refresh(artist.id, Utc::now() - chrono::Duration::hours(1))
+            .await
+            .unwrap();
+
+        let mock_server = MockServer::start().await;
+        Mock::given(any())
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+        let hub = Arc::new(RecordingHub::default());
+
+        // A fresh job has an empty in-memory cache, as after a restart.
+        let result = RefreshArtistJob::single(artist.id.to_string())
+            .execute(refresh_context(pool, &mock_server, hub.clone()))
+            .await
+            .unwrap();
+
+        assert!(matches!(result, JobResult::Success));
+        assert!(hub.messages().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_refresh_single_artist_without_mbid_is_skipped() {
+        use wiremock::{matchers::any, Mock, MockServer, ResponseTemplate};
+
+        let pool = make_migrated_pool().await;
+        let repo = SqliteArtistRepository::new(pool.clone());
+        let artist = repo.create(DomainArtist::new("Unlinked")).await.unwrap();
+
+        let mock_server = MockServer::start().await;
+        Mock::given(any())
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+        let hub = Arc::new(RecordingHub::default());
+
+        let result = RefreshArtistJob::single(artist.id.to_string())
+            .execute(refresh_context(pool, &mock_server, hub.clone()))
+            .await
+            .unwrap();
+
+        assert!(matches!(result, JobResult::Success));
+        let unchanged = repo
+            .get_by_id(&artist.id.to_string())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(unchanged.name, "Unlinked");
+        assert!(hub.messages().is_empty());
+    }
+
     #[tokio::test]
     async fn test_refresh_artist_job_single() {
         let artist_id = Uuid::new_v4();
@@ -2270,12 +3730,173 @@ mod tests {
             .expect("in-memory DB init failed")
     }
 
+    fn backlog_job(pool: sqlx::SqlitePool) -> BacklogSearchJob {
+        use chorrosion_infrastructure::sqlite_adapters::{
+            SqliteQualityProfileRepository, SqliteTrackRepository,
+        };
+
+        BacklogSearchJob::new(
+            Arc::new(SqliteAlbumRepository::new(pool.clone())),
+            Arc::new(SqliteTrackRepository::new(pool.clone())),
+            Arc::new(SqliteArtistRepository::new(pool.clone())),
+            Arc::new(SqliteQualityProfileRepository::new(pool.clone())),
+            Arc::new(SqliteMetadataProfileRepository::new(pool.clone())),
+            Arc::new(SqliteIndexerDefinitionRepository::new(pool.clone())),
+            Arc::new(SqliteDownloadClientDefinitionRepository::new(pool)),
+        )
+    }
+
+    struct FakeSearchIndexer {
+        config: IndexerConfig,
+        results: Vec<chorrosion_application::IndexerSearchResult>,
+    }
+
+    impl FakeSearchIndexer {
+        fn new(results: Vec<chorrosion_application::IndexerSearchResult>) -> Self {
+            Self {
+                config: IndexerConfig {
+                    name: "fake".to_string(),
+                    base_url: "http://indexer.invalid".to_string(),
+                    protocol: IndexerProtocol::Torznab,
+                    api_key: None,
+                    enabled: true,
+                    requests_per_minute: None,
+                },
+                results,
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl IndexerClient for FakeSearchIndexer {
+        fn config(&self) -> &IndexerConfig {
+            &self.config
+        }
+
+        async fn detect_capabilities(
+            &self,
+        ) -> std::result::Result<
+            chorrosion_application::IndexerCapabilities,
+            chorrosion_application::IndexerError,
+        > {
+            Err(chorrosion_application::IndexerError::Unsupported(
+                "capabilities".to_string(),
+            ))
+        }
+
+        async fn search(
+            &self,
+            query: &IndexerSearchQuery,
+        ) -> std::result::Result<
+            Vec<chorrosion_application::IndexerSearchResult>,
+            chorrosion_application::IndexerError,
+        > {
+            let query = query.query.to_lowercase();
+            Ok(self
+                .results
+                .iter()
+                .filter(|result| {
+                    parse_release_title(&result.title)
+                        .album
+                        .is_some_and(|album| query.contains(&album.to_lowercase()))
+                })
+                .cloned()
+                .collect())
+        }
+
+        async fn fetch_rss_feed(
+            &self,
+        ) -> std::result::Result<
+            Vec<chorrosion_application::IndexerRssItem>,
+            chorrosion_application::IndexerError,
+        > {
+            Ok(Vec::new())
+        }
+
+        async fn test_connection(
+            &self,
+        ) -> std::result::Result<
+            chorrosion_application::IndexerTestResult,
+            chorrosion_application::IndexerError,
+        > {
+            Err(chorrosion_application::IndexerError::Unsupported(
+                "test".to_string(),
+            ))
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingDownloadClient {
+        added: std::sync::Mutex<Vec<AddTorrentRequest>>,
+        downloads: Vec<DownloadItem>,
+    }
+
+    #[async_trait::async_trait]
+    impl DownloadClient for RecordingDownloadClient {
+        async fn test_connection(
+            &self,
+        ) -> std::result::Result<(), chorrosion_application::DownloadClientError> {
+            Ok(())
+        }
+
+        async fn add_torrent(
+            &self,
+            request: AddTorrentRequest,
+        ) -> std::result::Result<(), chorrosion_application::DownloadClientError> {
+            self.added.lock().unwrap().push(request);
+            Ok(())
+        }
+
+        async fn set_category(
+            &self,
+            _hash: &str,
+            _category: &str,
+        ) -> std::result::Result<(), chorrosion_application::DownloadClientError> {
+            Ok(())
+        }
+
+        async fn list_downloads(
+            &self,
+        ) -> std::result::Result<Vec<DownloadItem>, chorrosion_application::DownloadClientError>
+        {
+            Ok(self.downloads.clone())
+        }
+
+        async fn prioritize_download(
+            &self,
+            _hash: &str,
+        ) -> std::result::Result<(), chorrosion_application::DownloadClientError> {
+            Ok(())
+        }
+    }
+
+    fn search_result(title: &str, url: &str) -> chorrosion_application::IndexerSearchResult {
+        chorrosion_application::IndexerSearchResult {
+            title: title.to_string(),
+            guid: None,
+            download_url: Some(url.to_string()),
+            published_at: None,
+            size_bytes: None,
+            seeders: None,
+            leechers: None,
+        }
+    }
+
+    async fn seed_wanted_album(pool: &sqlx::SqlitePool, artist: &str, album: &str) -> DomainAlbum {
+        let artist = SqliteArtistRepository::new(pool.clone())
+            .create(DomainArtist::new(artist))
+            .await
+            .expect("create artist");
+        SqliteAlbumRepository::new(pool.clone())
+            .create(DomainAlbum::new(artist.id, album))
+            .await
+            .expect("create album")
+    }
+
     #[tokio::test]
     async fn test_backlog_search_job_name_and_type() {
         let pool = make_migrated_pool().await;
-        let repo =
-            Arc::new(chorrosion_infrastructure::sqlite_adapters::SqliteAlbumRepository::new(pool));
-        let job = BacklogSearchJob::new(repo);
+        let job = backlog_job(pool);
         assert_eq!(job.job_type(), "backlog_search");
         assert_eq!(job.name(), "Backlog Search");
         assert_eq!(job.max_retries(), 1);
@@ -2284,9 +3905,7 @@ mod tests {
     #[tokio::test]
     async fn test_backlog_search_job_empty_database_returns_success() {
         let pool = make_migrated_pool().await;
-        let repo =
-            Arc::new(chorrosion_infrastructure::sqlite_adapters::SqliteAlbumRepository::new(pool));
-        let job = BacklogSearchJob::new(repo);
+        let job = backlog_job(pool);
         let ctx = JobContext::new("test-backlog-empty");
 
         let result = job.execute(ctx).await;
@@ -2299,9 +3918,7 @@ mod tests {
         let pool = sqlx::SqlitePool::connect("sqlite::memory:")
             .await
             .expect("pool connect failed");
-        let repo =
-            Arc::new(chorrosion_infrastructure::sqlite_adapters::SqliteAlbumRepository::new(pool));
-        let job = BacklogSearchJob::new(repo);
+        let job = backlog_job(pool);
         let ctx = JobContext::new("test-backlog-no-tables");
 
         let result = job.execute(ctx).await.expect("execute should not Err");
@@ -2311,6 +3928,314 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_backlog_search_job_queues_best_match_and_skips_albums_without_results() {
+        let pool = make_migrated_pool().await;
+        let found = seed_wanted_album(&pool, "Radiohead", "OK Computer").await;
+        let missing = seed_wanted_album(&pool, "Nobody", "Unreleased Demos").await;
+
+        let indexer = FakeSearchIndexer::new(vec![
+            search_result("Radiohead - OK Computer MP3 128", "magnet:?xt=urn:btih:mp3"),
+            search_result("Radiohead - OK Computer FLAC", "magnet:?xt=urn:btih:flac"),
+        ]);
+        let client = Arc::new(RecordingDownloadClient::default());
+        let decisions = SearchDecisionStore::new();
+        let job = backlog_job(pool)
+            .with_indexers(vec![Arc::new(indexer)])
+            .with_download_client("recording", client.clone())
+            .with_decision_store(decisions.clone());
+
+        let result = job
+            .execute(JobContext::new("test-backlog-grab"))
+            .await
+            .expect("execute should not Err");
+        assert!(matches!(result, JobResult::Success));
+
+        let added = client.added.lock().unwrap().clone();
+        assert_eq!(added.len(), 1, "only the album with results is grabbed");
+        assert_eq!(added[0].torrent_or_magnet, "magnet:?xt=urn:btih:flac");
+
+        let grabbed = decisions.get(&found.id.to_string()).expect("decision");
+        match grabbed.reason {
+            AutomaticSearchReason::Grabbed { release } => {
+                assert_eq!(release.search_result.title, "Radiohead - OK Computer FLAC");
+            }
+            other => panic!("expected Grabbed, got {other:?}"),
+        }
+        assert_eq!(
+            decisions
+                .get(&missing.id.to_string())
+                .map(|status| status.reason),
+            Some(AutomaticSearchReason::NoResults)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_backlog_search_job_skips_unmonitored_and_downloading_albums() {
+        let pool = make_migrated_pool().await;
+        let downloading = seed_wanted_album(&pool, "Radiohead", "OK Computer").await;
+        let artist = SqliteArtistRepository::new(pool.clone())
+            .create(DomainArtist::new("Portishead"))
+            .await
+            .expect("create artist");
+        let mut unmonitored = DomainAlbum::new(artist.id, "Dummy");
+        unmonitored.monitored = false;
+        SqliteAlbumRepository::new(pool.clone())
+            .create(unmonitored)
+            .await
+            .expect("create album");
+
+        let indexer = FakeSearchIndexer::new(vec![
+            search_result("Radiohead - OK Computer FLAC", "magnet:?xt=urn:btih:okc"),
+            search_result("Portishead - Dummy FLAC", "magnet:?xt=urn:btih:dummy"),
+        ]);
+        let client = Arc::new(RecordingDownloadClient {
+            downloads: vec![DownloadItem {
+                hash: "abc".to_string(),
+                name: "Radiohead - OK Computer FLAC".to_string(),
+                progress_percent: 40,
+                category: None,
+                state: DownloadState::Downloading,
+            }],
+            ..RecordingDownloadClient::default()
+        });
+        let decisions = SearchDecisionStore::new();
+        let job = backlog_job(pool)
+            .with_indexers(vec![Arc::new(indexer)])
+            .with_download_client("recording", client.clone())
+            .with_decision_store(decisions.clone());
+
+        let result = job
+            .execute(JobContext::new("test-backlog-skip"))
+            .await
+            .expect("execute should not Err");
+        assert!(matches!(result, JobResult::Success));
+        assert!(client.added.lock().unwrap().is_empty());
+        assert_eq!(
+            decisions
+                .get(&downloading.id.to_string())
+                .map(|status| status.reason),
+            Some(AutomaticSearchReason::AlreadyDownloading)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_backlog_search_job_matches_artist_as_well_as_album_title() {
+        let pool = make_migrated_pool().await;
+        let wanted = seed_wanted_album(&pool, "Weezer", "Weezer").await;
+        let other = seed_wanted_album(&pool, "Peter Gabriel", "Peter Gabriel").await;
+
+        let indexer = FakeSearchIndexer::new(vec![
+            search_result("Imposter - Weezer FLAC", "magnet:?xt=urn:btih:imposter"),
+            search_result("Weezer - Weezer FLAC", "magnet:?xt=urn:btih:weezer"),
+        ]);
+        let client = Arc::new(RecordingDownloadClient {
+            downloads: vec![DownloadItem {
+                hash: "abc".to_string(),
+                name: "Someone Else - Peter Gabriel FLAC".to_string(),
+                progress_percent: 40,
+                category: None,
+                state: DownloadState::Downloading,
+            }],
+            ..RecordingDownloadClient::default()
+        });
+        let decisions = SearchDecisionStore::new();
+        let job = backlog_job(pool)
+            .with_indexers(vec![Arc::new(indexer)])
+            .with_download_client("recording", client.clone())
+            .with_decision_store(decisions.clone());
+
+        let result = job
+            .execute(JobContext::new("test-backlog-artist-match"))
+            .await
+            .expect("execute should not Err");
+        assert!(matches!(result, JobResult::Success));
+
+        let added = client.added.lock().unwrap().clone();
+        assert_eq!(added.len(), 1);
+        assert_eq!(added[0].torrent_or_magnet, "magnet:?xt=urn:btih:weezer");
+        assert!(matches!(
+            decisions
+                .get(&wanted.id.to_string())
+                .map(|status| status.reason),
+            Some(AutomaticSearchReason::Grabbed { .. })
+        ));
+        assert_eq!(
+            decisions
+                .get(&other.id.to_string())
+                .map(|status| status.reason),
+            Some(AutomaticSearchReason::NoResults),
+            "a download by another artist does not block the album"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_backlog_search_job_defers_releases_newer_than_delay_profile() {
+        let pool = make_migrated_pool().await;
+        let album = seed_wanted_album(&pool, "Radiohead", "OK Computer").await;
+
+        let mut fresh = search_result("Radiohead - OK Computer FLAC", "magnet:?xt=urn:btih:new");
+        fresh.published_at = Some(Utc::now().to_rfc3339());
+        let indexer = FakeSearchIndexer::new(vec![fresh]);
+        let client = Arc::new(RecordingDownloadClient::default());
+        let decisions = SearchDecisionStore::new();
+        let job = backlog_job(pool)
+            .with_indexers(vec![Arc::new(indexer)])
+            .with_download_client("recording", client.clone())
+            .with_delay_profile(DelayProfile {
+                usenet_delay_minutes: 0,
+                torrent_delay_minutes: 60,
+            })
+            .with_decision_store(decisions.clone());
+
+        let result = job
+            .execute(JobContext::new("test-backlog-delay"))
+            .await
+            .expect("execute should not Err");
+        assert!(matches!(result, JobResult::Success));
+        assert!(client.added.lock().unwrap().is_empty());
+        assert!(matches!(
+            decisions
+                .get(&album.id.to_string())
+                .map(|status| status.reason),
+            Some(AutomaticSearchReason::Deferred { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_backlog_search_job_applies_seeder_filter_options() {
+        let pool = make_migrated_pool().await;
+        let album = seed_wanted_album(&pool, "Radiohead", "OK Computer").await;
+
+        let mut dead = search_result("Radiohead - OK Computer FLAC", "magnet:?xt=urn:btih:dead");
+        dead.seeders = Some(0);
+        let indexer = FakeSearchIndexer::new(vec![dead]);
+        let client = Arc::new(RecordingDownloadClient::default());
+        let decisions = SearchDecisionStore::new();
+        let job = backlog_job(pool)
+            .with_indexers(vec![Arc::new(indexer)])
+            .with_download_client("recording", client.clone())
+            .with_filter_options(ReleaseFilterOptions {
+                min_seeders: Some(1),
+                ..ReleaseFilterOptions::default()
+            })
+            .with_decision_store(decisions.clone());
+
+        let result = job
+            .execute(JobContext::new("test-backlog-seeders"))
+            .await
+            .expect("execute should not Err");
+        assert!(matches!(result, JobResult::Success));
+        assert!(client.added.lock().unwrap().is_empty());
+        assert_eq!(
+            decisions
+                .get(&album.id.to_string())
+                .map(|status| status.reason),
+            Some(AutomaticSearchReason::NoResults)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_backlog_search_job_drops_unofficial_releases_for_official_only_profile() {
+        let pool = make_migrated_pool().await;
+        let mut profile = chorrosion_domain::MetadataProfile::new("Official Only");
+        profile.release_statuses = vec!["Official".to_string()];
+        let profile = SqliteMetadataProfileRepository::new(pool.clone())
+            .create(profile)
+            .await
+            .expect("create metadata profile");
+        let mut artist = DomainArtist::new("Oasis");
+        artist.metadata_profile_id = Some(profile.id);
+        let artist = SqliteArtistRepository::new(pool.clone())
+            .create(artist)
+            .await
+            .expect("create artist");
+        SqliteAlbumRepository::new(pool.clone())
+            .create(DomainAlbum::new(artist.id, "Live Forever"))
+            .await
+            .expect("create album");
+
+        let indexer = FakeSearchIndexer::new(vec![
+            search_result(
+                "Oasis - Live Forever (Bootleg) FLAC",
+                "magnet:?xt=urn:btih:bootleg",
+            ),
+            search_result("Oasis - Live Forever MP3", "magnet:?xt=urn:btih:official"),
+        ]);
+        let client = Arc::new(RecordingDownloadClient::default());
+        let job = backlog_job(pool)
+            .with_indexers(vec![Arc::new(indexer)])
+            .with_download_client("recording", client.clone());
+
+        let result = job
+            .execute(JobContext::new("test-backlog-official"))
+            .await
+            .expect("execute should not Err");
+        assert!(matches!(result, JobResult::Success));
+
+        let added = client.added.lock().unwrap().clone();
+        assert_eq!(added.len(), 1);
+        assert_eq!(added[0].torrent_or_magnet, "magnet:?xt=urn:btih:official");
+    }
+
+    #[tokio::test]
+    async fn test_backlog_search_job_searches_cutoff_unmet_albums() {
+        use chorrosion_infrastructure::sqlite_adapters::{
+            SqliteQualityProfileRepository, SqliteTrackFileRepository, SqliteTrackRepository,
+        };
+
+        let pool = make_migrated_pool().await;
+        let mut profile = chorrosion_domain::QualityProfile::new(
+            "HD Audio",
+            vec!["FLAC".to_string(), "MP3".to_string()],
+        );
+        profile.upgrade_allowed = true;
+        profile.cutoff_quality = Some("FLAC".to_string());
+        let profile = SqliteQualityProfileRepository::new(pool.clone())
+            .create(profile)
+            .await
+            .expect("create quality profile");
+        let mut artist = DomainArtist::new("Radiohead");
+        artist.quality_profile_id = Some(profile.id);
+        let artist = SqliteArtistRepository::new(pool.clone())
+            .create(artist)
+            .await
+            .expect("create artist");
+        let album = SqliteAlbumRepository::new(pool.clone())
+            .create(DomainAlbum::new(artist.id, "OK Computer"))
+            .await
+            .expect("create album");
+        let track = SqliteTrackRepository::new(pool.clone())
+            .create(chorrosion_domain::Track::new(album.id, artist.id, "Airbag"))
+            .await
+            .expect("create track");
+        let mut track_file = TrackFile::new(track.id, "/music/airbag.mp3", 1024);
+        track_file.codec = Some("MP3".to_string());
+        SqliteTrackFileRepository::new(pool.clone())
+            .create(track_file)
+            .await
+            .expect("create track file");
+
+        let indexer = FakeSearchIndexer::new(vec![search_result(
+            "Radiohead - OK Computer FLAC",
+            "magnet:?xt=urn:btih:flac",
+        )]);
+        let client = Arc::new(RecordingDownloadClient::default());
+        let job = backlog_job(pool)
+            .with_indexers(vec![Arc::new(indexer)])
+            .with_download_client("recording", client.clone());
+
+        let result = job
+            .execute(JobContext::new("test-backlog-cutoff"))
+            .await
+            .expect("execute should not Err");
+        assert!(matches!(result, JobResult::Success));
+
+        let added = client.added.lock().unwrap().clone();
+        assert_eq!(added.len(), 1, "the below-cutoff album is searched");
+        assert_eq!(added[0].torrent_or_magnet, "magnet:?xt=urn:btih:flac");
+    }
+
     // ── RssSyncJob tests ────────────────────────────────────────────────────
 
     #[test]
@@ -2355,12 +4280,48 @@ mod tests {
             },
         ];
 
-        let candidates = collect_rss_grab_candidates(&items, &wanted);
+        let candidates = collect_rss_grab_candidates(
+            &items,
+            &wanted,
+            &IndexerProtocol::Newznab,
+            &ReleaseFilterOptions::default(),
+        );
         assert_eq!(candidates.len(), 2);
         assert_eq!(candidates[0].album_title, "OK Computer");
         assert_eq!(candidates[1].album_title, "In Rainbows");
     }
 
+    #[test]
+    fn test_collect_rss_grab_candidates_drops_torrents_older_than_max_age() {
+        let wanted = ["OK Computer"]
+            .into_iter()
+            .map(normalize_match_key)
+            .collect::<HashSet<_>>();
+        let old = (Utc::now() - chrono::Duration::days(30)).to_rfc3339();
+        let items = vec![chorrosion_application::IndexerRssItem {
+            title: "Radiohead - OK Computer FLAC".to_string(),
+            guid: None,
+            link: None,
+            download_url: Some("magnet:?xt=urn:btih:old".to_string()),
+            published_at: Some(old),
+            description: None,
+        }];
+        let options = ReleaseFilterOptions {
+            max_age_days: Some(7),
+            ..ReleaseFilterOptions::default()
+        };
+
+        assert!(
+            collect_rss_grab_candidates(&items, &wanted, &IndexerProtocol::Torznab, &options)
+                .is_empty()
+        );
+        assert_eq!(
+            collect_rss_grab_candidates(&items, &wanted, &IndexerProtocol::Newznab, &options).len(),
+            1,
+            "Usenet items are not age-checked"
+        );
+    }
+
     #[test]
     fn test_collect_rss_grab_candidates_prefers_download_url_over_link() {
         let wanted = ["OK Computer"]
@@ -2377,7 +4338,12 @@ mod tests {
             description: None,
         }];
 
-        let candidates = collect_rss_grab_candidates(&items, &wanted);
+        let candidates = collect_rss_grab_candidates(
+            &items,
+            &wanted,
+            &IndexerProtocol::Newznab,
+            &ReleaseFilterOptions::default(),
+        );
         assert_eq!(candidates.len(), 1);
         assert_eq!(candidates[0].download_url, "magnet:?xt=urn:btih:okc");
     }
@@ -2432,9 +4398,10 @@ mod tests {
         .await
         .expect("insert disabled download client failed");
 
-        let (name, category, client) = load_active_download_client(&repository)
-            .await
-            .expect("load active download client should succeed");
+        let (name, category, client) =
+            load_active_download_client(&repository, &OutboundHttp::default())
+                .await
+                .expect("load active download client should succeed");
 
         assert_eq!(name, "<none>");
         assert_eq!(category, None);
@@ -2473,9 +4440,10 @@ mod tests {
         .await
         .expect("insert supported download client failed");
 
-        let (name, category, client) = load_active_download_client(&repository)
-            .await
-            .expect("load active download client should succeed");
+        let (name, category, client) =
+            load_active_download_client(&repository, &OutboundHttp::default())
+                .await
+                .expect("load active download client should succeed");
 
         assert_eq!(name, "B qBittorrent");
         assert_eq!(category.as_deref(), Some("music"));
@@ -2554,4 +4522,332 @@ mod tests {
             other => panic!("expected non-retriable Failure, got {other:?}"),
         }
     }
+
+    // ── HousekeepingJob tests ────────────────────────────────────────────────
+
+    async fn seed_track(pool: &sqlx::SqlitePool) -> chorrosion_domain::TrackId {
+        use chorrosion_infrastructure::sqlite_adapters::SqliteTrackRepository;
+
+        let artist = SqliteArtistRepository::new(pool.clone())
+            .create(DomainArtist::new("Housekeeping Artist"))
+            .await
+            .expect("create artist");
+        let album = SqliteAlbumRepository::new(pool.clone())
+            .create(DomainAlbum::new(artist.id, "Housekeeping Album"))
+            .await
+            .expect("create album");
+        SqliteTrackRepository::new(pool.clone())
+            .create(chorrosion_domain::Track::new(album.id, artist.id, "Track"))
+            .await
+            .expect("create track")
+            .id
+    }
+
+    async fn insert_job_run(pool: &sqlx::SqlitePool, job_id: &str, finished_minutes_ago: i64) {
+        let finished_at = Utc::now() - chrono::Duration::minutes(finished_minutes_ago);
+        sqlx::query(
+            "INSERT INTO job_runs (id, job_id, job_type, attempt, status, error, started_at, finished_at) \
+             VALUES (?, ?, 'test', 1, 'success', NULL, ?, ?)",
+        )
+        .bind(Uuid::new_v4().to_string())
+        .bind(job_id)
+        .bind(finished_at.to_rfc3339())
+        .bind(finished_at.to_rfc3339())
+        .execute(pool)
+        .await
+        .expect("insert job run");
+    }
+
+    #[tokio::test]
+    async fn test_housekeeping_prunes_track_files_missing_on_disk() {
+        use chorrosion_infrastructure::repositories::TrackFileRepository;
+        use chorrosion_infrastructure::sqlite_adapters::SqliteTrackFileRepository;
+
+        let pool = make_migrated_pool().await;
+        let track_id = seed_track(&pool).await;
+        let dir = tempfile::tempdir().expect("tempdir");
+        let kept_path = dir.path().join("kept.flac");
+        let removed_path = dir.path().join("removed.flac");
+        std::fs::write(&kept_path, b"audio").expect("write kept file");
+        std::fs::write(&removed_path, b"audio").expect("write removed file");
+
+        let files = SqliteTrackFileRepository::new(pool.clone());
+        for path in [&kept_path, &removed_path] {
+            files
+                .create(chorrosion_domain::TrackFile::new(
+                    track_id,
+                    path.to_string_lossy(),
+                    5,
+                ))
+                .await
+                .expect("create track file");
+        }
+        // A file under a directory that no longer exists (e.g. unmounted library) is kept.
+        let unmounted_path = dir.path().join("unmounted").join("gone.flac");
+        files
+            .create(chorrosion_domain::TrackFile::new(
+                track_id,
+                unmounted_path.to_string_lossy(),
+                5,
+            ))
+            .await
+            .expect("create track file");
+        std::fs::remove_file(&removed_path).expect("remove file");
+
+        let job = HousekeepingJob::new(pool.clone());
+        let result = job.execute(JobContext::new("housekeeping")).await.unwrap();
+        assert!(matches!(result, JobResult::Success), "got {result:?}");
+
+        let kept = kept_path.to_string_lossy();
+        let removed = removed_path.to_string_lossy();
+        let unmounted = unmounted_path.to_string_lossy();
+        assert!(files.get_by_path(&kept).await.unwrap().is_some());
+        assert!(files.get_by_path(&removed).await.unwrap().is_none());
+        assert!(files.get_by_path(&unmounted).await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_housekeeping_prunes_refresh_caches() {
+        let pool = make_migrated_pool().await;
+        let cache = MetadataRefreshCache::new();
+        assert_eq!(cache.last_prune_secs_value(), 0);
+
+        let job = HousekeepingJob::new(pool).with_refresh_cache(cache.clone());
+        job.execute(JobContext::new("housekeeping")).await.unwrap();
+
+        assert!(
+            cache.last_prune_secs_value() > 0,
+            "housekeeping should prune the shared refresh cache"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_housekeeping_backs_up_database_within_retention() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let config = chorrosion_config::AppConfig {
+            database: chorrosion_config::DatabaseConfig {
+                url: format!("sqlite://{}", dir.path().join("chorrosion.db").display()),
+                ..chorrosion_config::DatabaseConfig::default()
+            },
+            ..chorrosion_config::AppConfig::default()
+        };
+        let pool = chorrosion_infrastructure::init_database(&config)
+            .await
+            .expect("file DB init failed");
+        let backup_dir = dir.path().join("backups");
+        let job = HousekeepingJob::new(pool).with_database_backups(backup_dir.clone(), 1);
+
+        for _ in 0..2 {
+            let result = job.execute(JobContext::new("housekeeping")).await.unwrap();
+            assert!(matches!(result, JobResult::Success), "got {result:?}");
+        }
+
+        let snapshots = std::fs::read_dir(&backup_dir).unwrap().count();
+        assert_eq!(snapshots, 1, "only the newest snapshot is retained");
+    }
+
+    #[tokio::test]
+    async fn test_housekeeping_trims_job_history_past_retention() {
+        let pool = make_migrated_pool().await;
+        for minutes_ago in 0..5 {
+            insert_job_run(&pool, &format!("run-{minutes_ago}"), minutes_ago).await;
+        }
+
+        let job = HousekeepingJob::new(pool.clone()).with_job_history_retention(3);
+        job.execute(JobContext::new("housekeeping")).await.unwrap();
+
+        let remaining: Vec<(String,)> =
+            sqlx::query_as("SELECT job_id FROM job_runs ORDER BY finished_at DESC")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        let remaining: Vec<String> = remaining.into_iter().map(|(id,)| id).collect();
+        assert_eq!(remaining, vec!["run-0", "run-1", "run-2"]);
+    }
+
+    // ── FingerprintScanJob tests ─────────────────────────────────────────────
+
+    /// Fingerprints every path except those containing "broken", recording calls
+    /// and the highest number of fingerprints generated at once.
+    #[derive(Default)]
+    struct FakeFingerprintSource {
+        calls: std::sync::Mutex<Vec<String>>,
+        in_flight: std::sync::atomic::AtomicUsize,
+        max_in_flight: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl FingerprintSource for FakeFingerprintSource {
+        async fn fingerprint(&self, path: &std::path::Path) -> Result<Fingerprint> {
+            let path = path.display().to_string();
+            self.calls.lock().unwrap().push(path.clone());
+            let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            if path.contains("broken") {
+                anyhow::bail!("cannot decode {path}");
+            }
+            Ok(Fingerprint::new_unchecked(format!("fp:{path}"), 240))
+        }
+    }
+
+    struct FakeFingerprintLookup {
+        recording_id: Uuid,
+        score: f32,
+    }
+
+    #[async_trait::async_trait]
+    impl FingerprintLookup for FakeFingerprintLookup {
+        async fn best_match(&self, _fingerprint: &Fingerprint) -> Result<Option<RecordingMatch>> {
+            Ok(Some(RecordingMatch {
+                id: self.recording_id,
+                title: None,
+                artists: Vec::new(),
+                releases: Vec::new(),
+                score: self.score,
+            }))
+        }
+    }
+
+    /// Dependencies over a fresh database holding one track per entry in `paths`,
+    /// each with a track file at that path.
+    async fn fingerprint_fixture(
+        paths: &[&str],
+    ) -> (Arc<crate::job::JobDependencies>, Vec<TrackFile>) {
+        use chorrosion_domain::Track;
+
+        let pool = make_migrated_pool().await;
+        let dependencies = Arc::new(crate::job::JobDependencies::from_pool(
+            pool,
+            &chorrosion_config::DatabaseConfig::default(),
+        ));
+        let artist = dependencies
+            .artist_repository
+            .create(DomainArtist::new("Burial"))
+            .await
+            .unwrap();
+        let album = dependencies
+            .album_repository
+            .create(DomainAlbum::new(artist.id, "Untrue"))
+            .await
+            .unwrap();
+        let mut files = Vec::new();
+        for path in paths {
+            let track = dependencies
+                .track_repository
+                .create(Track::new(album.id, artist.id, *path))
+                .await
+                .unwrap();
+            let file = dependencies
+                .track_file_repository
+                .create(TrackFile::new(track.id, *path, 1024))
+                .await
+                .unwrap();
+            files.push(file);
+        }
+        (dependencies, files)
+    }
+
+    #[tokio::test]
+    async fn test_fingerprint_scan_persists_fingerprints_and_match_confidence() {
+        let (dependencies, files) =
+            fingerprint_fixture(&["/music/archangel.flac", "/music/etched.flac"]).await;
+        let mut already_done = files[1].clone();
+        already_done.fingerprint_hash = Some("existing".to_string());
+        already_done.fingerprint_duration = Some(300);
+        dependencies
+            .track_file_repository
+            .update(already_done)
+            .await
+            .unwrap();
+
+        let source = Arc::new(FakeFingerprintSource::default());
+        let recording_id = Uuid::new_v4();
+        let job = FingerprintScanJob::new()
+            .with_source(source.clone())
+            .with_lookup(Arc::new(FakeFingerprintLookup {
+                recording_id,
+                score: 0.87,
+            }))
+            .with_rate_limiter(RateLimiter::new(std::time::Duration::ZERO));
+        let ctx = JobContext::new("fingerprint-scan").with_dependencies(dependencies.clone());
+
+        let result = job.execute(ctx).await.unwrap();
+
+        assert!(matches!(result, JobResult::Success));
+        assert_eq!(
+            *source.calls.lock().unwrap(),
+            vec!["/music/archangel.flac".to_string()],
+            "already fingerprinted files are skipped"
+        );
+        let scanned = dependencies
+            .track_file_repository
+            .get_by_id(&files[0].id.to_string())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            scanned.fingerprint_hash.as_deref(),
+            Some("fp:/music/archangel.flac")
+        );
+        assert_eq!(scanned.fingerprint_duration, Some(240));
+        assert!(scanned.fingerprint_computed_at.is_some());
+        let untouched = dependencies
+            .track_file_repository
+            .get_by_id(&files[1].id.to_string())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(untouched.fingerprint_hash.as_deref(), Some("existing"));
+
+        let track = dependencies
+            .track_repository
+            .get_by_id(&files[0].track_id.to_string())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(track.match_confidence, Some(0.87));
+        assert_eq!(
+            track.musicbrainz_recording_id,
+            Some(recording_id.to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fingerprint_scan_respects_concurrency_and_skips_failures() {
+        let paths = [
+            "/music/1.flac",
+            "/music/2.flac",
+            "/music/broken.ogg",
+            "/music/3.flac",
+            "/music/4.flac",
+        ];
+        let (dependencies, _) = fingerprint_fixture(&paths).await;
+        let source = Arc::new(FakeFingerprintSource::default());
+        let job = FingerprintScanJob::new()
+            .with_source(source.clone())
+            .with_concurrency(2);
+        let ctx = JobContext::new("fingerprint-scan").with_dependencies(dependencies.clone());
+
+        let summary = job.scan(&ctx).await.unwrap();
+
+        assert_eq!(
+            summary,
+            FingerprintScanSummary {
+                fingerprinted: 4,
+                matched: 0,
+                failed: 1,
+            }
+        );
+        assert_eq!(source.calls.lock().unwrap().len(), paths.len());
+        assert!(source.max_in_flight.load(Ordering::SeqCst) <= 2);
+        let remaining = dependencies
+            .track_file_repository
+            .list_without_fingerprints(10, 0)
+            .await
+            .unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].path, "/music/broken.ogg");
+    }
 }
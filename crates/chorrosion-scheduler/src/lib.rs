@@ -4,21 +4,29 @@ pub mod jobs;
 pub mod registry;
 
 use anyhow::Result;
+use chorrosion_application::{
+    AppMetrics, DelayProfile, EventDispatcher, IndexerClients, OutboundHttp, ReleaseFilterOptions,
+    SearchDecisionStore,
+};
 use chorrosion_config::AppConfig;
+use chorrosion_fingerprint::AcoustidClient;
+use chorrosion_infrastructure::repositories::StatusParsing;
 use chorrosion_infrastructure::sqlite_adapters::{
-    SqliteAlbumRepository, SqliteDownloadClientDefinitionRepository,
-    SqliteIndexerDefinitionRepository,
+    SqliteAlbumRepository, SqliteArtistRepository, SqliteDownloadClientDefinitionRepository,
+    SqliteIndexerDefinitionRepository, SqliteMetadataProfileRepository,
+    SqliteQualityProfileRepository, SqliteTrackRepository,
 };
-use chorrosion_musicbrainz::MusicBrainzClient;
+use chorrosion_musicbrainz::{MusicBrainzClient, RateLimiter};
 use registry::JobRegistry;
 use sqlx::SqlitePool;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::task::JoinHandle;
 use tracing::info;
 
 use jobs::{
-    BacklogSearchJob, DiscogsMetadataRefreshJob, HousekeepingJob, LastFmMetadataRefreshJob,
-    RefreshAlbumJob, RefreshArtistJob, RssSyncJob,
+    BacklogSearchJob, DiscogsMetadataRefreshJob, FingerprintScanJob, HousekeepingJob,
+    LastFmMetadataRefreshJob, RefreshAlbumJob, RefreshArtistJob, RssSyncJob,
 };
 
 #[allow(dead_code)]
@@ -26,27 +34,71 @@ pub struct Scheduler {
     config: AppConfig,
     registry: Arc<JobRegistry>,
     pool: SqlitePool,
+    search_decision_store: SearchDecisionStore,
+    event_dispatcher: EventDispatcher,
+    acoustid_client: Option<Arc<AcoustidClient>>,
+    indexer_clients: IndexerClients,
 }
 
 impl Scheduler {
     pub fn new(config: AppConfig, pool: SqlitePool) -> Self {
-        let registry = Arc::new(JobRegistry::new(config.scheduler.max_concurrent_jobs));
+        let registry = Arc::new(build_registry(&config, pool.clone(), AppMetrics::default()));
         Self {
             config,
             registry,
             pool,
+            search_decision_store: SearchDecisionStore::default(),
+            event_dispatcher: EventDispatcher::default(),
+            acoustid_client: None,
+            indexer_clients: IndexerClients::default(),
         }
     }
 
+    /// Record job executions in `metrics`; call before [`Scheduler::register_jobs`].
+    pub fn with_metrics(mut self, metrics: AppMetrics) -> Self {
+        self.registry = Arc::new(build_registry(&self.config, self.pool.clone(), metrics));
+        self
+    }
+
+    /// Record backlog search decisions in `store`, typically shared with the API state.
+    pub fn with_search_decision_store(mut self, store: SearchDecisionStore) -> Self {
+        self.search_decision_store = store;
+        self
+    }
+
+    /// Publish job events through `event_dispatcher`, typically the API state's,
+    /// so both WebSocket and SSE clients receive them.
+    pub fn with_event_dispatcher(mut self, event_dispatcher: EventDispatcher) -> Self {
+        self.event_dispatcher = event_dispatcher;
+        self
+    }
+
+    /// Make `client` available to jobs for fingerprint lookups.
+    pub fn with_acoustid_client(mut self, client: Arc<AcoustidClient>) -> Self {
+        self.acoustid_client = Some(client);
+        self
+    }
+
+    /// Build indexer clients through `indexer_clients`, typically the API state's,
+    /// so jobs and handlers share one connection pool.
+    pub fn with_indexer_clients(mut self, indexer_clients: IndexerClients) -> Self {
+        self.indexer_clients = indexer_clients;
+        self
+    }
+
     /// Register all background jobs with their schedules
     pub async fn register_jobs(&self) {
         info!(target: "scheduler", "registering background jobs");
 
         // RSS sync every 15 minutes
-        let rss_album_repository = Arc::new(SqliteAlbumRepository::new_with_threshold(
-            self.pool.clone(),
-            self.config.database.slow_query_threshold_ms,
-        ));
+        let status_parsing = StatusParsing::from(&self.config.database);
+        let rss_album_repository = Arc::new(
+            SqliteAlbumRepository::new_with_threshold(
+                self.pool.clone(),
+                self.config.database.slow_query_threshold_ms,
+            )
+            .with_status_parsing(status_parsing),
+        );
         let rss_indexer_repository =
             Arc::new(SqliteIndexerDefinitionRepository::new(self.pool.clone()));
         let rss_download_client_repository = Arc::new(
@@ -59,29 +111,76 @@ impl Scheduler {
                     rss_album_repository,
                     rss_indexer_repository,
                     rss_download_client_repository,
-                ),
+                )
+                .with_indexer_clients(self.indexer_clients.clone())
+                .with_outbound_http(OutboundHttp::from_config(&self.config.http))
+                .with_filter_options(ReleaseFilterOptions::from(&self.config.search)),
                 Schedule::Interval(15 * 60),
             )
             .await;
 
         // Backlog search every hour, reusing the caller-provided database pool
-        let album_repository = Arc::new(SqliteAlbumRepository::new_with_threshold(
-            self.pool.clone(),
-            self.config.database.slow_query_threshold_ms,
-        ));
+        let slow_query_threshold_ms = self.config.database.slow_query_threshold_ms;
+        let backlog_job = BacklogSearchJob::new(
+            Arc::new(
+                SqliteAlbumRepository::new_with_threshold(
+                    self.pool.clone(),
+                    slow_query_threshold_ms,
+                )
+                .with_status_parsing(status_parsing),
+            ),
+            Arc::new(SqliteTrackRepository::new_with_threshold(
+                self.pool.clone(),
+                slow_query_threshold_ms,
+            )),
+            Arc::new(
+                SqliteArtistRepository::new_with_threshold(
+                    self.pool.clone(),
+                    slow_query_threshold_ms,
+                )
+                .with_status_parsing(status_parsing),
+            ),
+            Arc::new(SqliteQualityProfileRepository::new(self.pool.clone())),
+            Arc::new(SqliteMetadataProfileRepository::new(self.pool.clone())),
+            Arc::new(SqliteIndexerDefinitionRepository::new(self.pool.clone())),
+            Arc::new(SqliteDownloadClientDefinitionRepository::new(
+                self.pool.clone(),
+            )),
+        )
+        .with_indexer_clients(self.indexer_clients.clone())
+        .with_outbound_http(OutboundHttp::from_config(&self.config.http))
+        .with_filter_options(ReleaseFilterOptions::from(&self.config.search))
+        .with_delay_profile(DelayProfile::from(&self.config.search))
+        .with_decision_store(self.search_decision_store.clone());
         self.registry
-            .register(
-                "backlog-search",
-                BacklogSearchJob::new(album_repository),
-                Schedule::Interval(60 * 60),
-            )
+            .register("backlog-search", backlog_job, Schedule::Interval(60 * 60))
             .await;
 
+        // Shared with the housekeeping job so it can prune stale entries.
+        let refresh_artist_cache = jobs::MetadataRefreshCache::new();
+        let refresh_album_cache = jobs::MetadataRefreshCache::new();
+
+        // Every MusicBrainz-backed job paces its requests through this one limiter,
+        // so concurrent refreshes stay within the rate MusicBrainz allows overall.
+        let musicbrainz_rate_limiter = RateLimiter::new(Duration::from_millis(
+            self.config.metadata.musicbrainz.rate_limit_interval_ms,
+        ));
+        let musicbrainz_client = || {
+            let mut builder =
+                MusicBrainzClient::builder().rate_limiter(musicbrainz_rate_limiter.clone());
+            if let Some(proxy_url) = &self.config.http.proxy_url {
+                builder = builder.proxy_url(proxy_url);
+            }
+            if let Some(user_agent) = &self.config.http.user_agent {
+                builder = builder.user_agent(user_agent);
+            }
+            builder.build()
+        };
+
         // Refresh all artists metadata every 12 hours
-        match MusicBrainzClient::new() {
+        match musicbrainz_client() {
             Ok(c) => {
                 let mb_client_artists = Arc::new(c);
-                let refresh_artist_cache = jobs::MetadataRefreshCache::new();
                 self.registry
                     .register(
                         "refresh-artists",
@@ -89,7 +188,10 @@ impl Scheduler {
                             None,
                             self.pool.clone(),
                             mb_client_artists,
-                            refresh_artist_cache,
+                            refresh_artist_cache.clone(),
+                        )
+                        .with_skip_filtered_release_groups(
+                            self.config.metadata.skip_filtered_release_groups,
                         ),
                         Schedule::Interval(12 * 60 * 60),
                     )
@@ -101,10 +203,9 @@ impl Scheduler {
         }
 
         // Refresh all albums metadata every 12 hours, offset by 15 minutes from artists
-        match MusicBrainzClient::new() {
+        match musicbrainz_client() {
             Ok(c) => {
                 let mb_client_albums = Arc::new(c);
-                let refresh_album_cache = jobs::MetadataRefreshCache::new();
                 self.registry
                     .register(
                         "refresh-albums",
@@ -112,7 +213,7 @@ impl Scheduler {
                             None,
                             self.pool.clone(),
                             mb_client_albums,
-                            refresh_album_cache,
+                            refresh_album_cache.clone(),
                         ),
                         Schedule::Interval(12 * 60 * 60 + 15 * 60),
                     )
@@ -124,17 +225,36 @@ impl Scheduler {
         }
 
         // Housekeeping every 24 hours
+        let mut housekeeping = HousekeepingJob::new(self.pool.clone())
+            .with_refresh_cache(refresh_artist_cache)
+            .with_refresh_cache(refresh_album_cache)
+            .with_job_history_retention(self.config.scheduler.job_history_retention);
+        if let Some(backup_dir) = &self.config.database.backup_dir {
+            housekeeping = housekeeping
+                .with_database_backups(backup_dir.clone(), self.config.database.backup_retention);
+        }
         self.registry
             .register(
                 "housekeeping",
-                HousekeepingJob::new(),
+                housekeeping,
                 Schedule::Interval(24 * 60 * 60),
             )
             .await;
 
+        // Fingerprint new track files every 6 hours; AcoustID lookups run only
+        // when a client was provided through `with_acoustid_client`.
+        self.registry
+            .register(
+                "fingerprint-scan",
+                FingerprintScanJob::new(),
+                Schedule::Interval(6 * 60 * 60),
+            )
+            .await;
+
         match LastFmMetadataRefreshJob::from_config_with_cache(
             &self.config.metadata.lastfm,
             &self.config.cache,
+            &OutboundHttp::from_config(&self.config.http),
         ) {
             Some(lastfm_job) => {
                 self.registry
@@ -154,6 +274,7 @@ impl Scheduler {
         match DiscogsMetadataRefreshJob::from_config_with_cache(
             &self.config.metadata.discogs,
             &self.config.cache,
+            &OutboundHttp::from_config(&self.config.http),
         ) {
             Some(discogs_job) => {
                 self.registry
@@ -170,19 +291,59 @@ impl Scheduler {
             }
         }
 
+        let dependencies = self.job_dependencies(musicbrainz_client().ok().map(Arc::new));
+        self.registry.set_dependencies(Arc::new(dependencies)).await;
+
         info!(target: "scheduler", "all jobs registered");
     }
 
+    /// Build the dependencies shared through every job's context.
+    fn job_dependencies(
+        &self,
+        musicbrainz_client: Option<Arc<MusicBrainzClient>>,
+    ) -> JobDependencies {
+        let mut dependencies = JobDependencies::from_pool(self.pool.clone(), &self.config.database)
+            .with_event_dispatcher(self.event_dispatcher.clone());
+
+        if let Some(client) = musicbrainz_client {
+            dependencies = dependencies.with_musicbrainz_client(client);
+        }
+        if let Some(client) = &self.acoustid_client {
+            dependencies = dependencies.with_acoustid_client(client.clone());
+        }
+        dependencies
+    }
+
     /// Start the scheduler and return a handle to the background task
-    pub fn start(self) -> JoinHandle<Result<()>> {
+    pub fn start(&self) -> JoinHandle<Result<()>> {
         let registry = self.registry.clone();
         tokio::spawn(async move {
             registry.start().await;
             Ok(())
         })
     }
+
+    /// Stop scheduling new jobs and wait for running ones, up to the configured
+    /// `scheduler.shutdown_timeout_secs`. Returns `true` when every job finished.
+    pub async fn shutdown(&self) -> bool {
+        let timeout = Duration::from_secs(self.config.scheduler.shutdown_timeout_secs);
+        self.registry.shutdown(timeout).await
+    }
+}
+
+/// Registry honouring the `scheduler` concurrency and retry settings.
+fn build_registry(config: &AppConfig, pool: SqlitePool, metrics: AppMetrics) -> JobRegistry {
+    let backoff = RetryBackoff::new(Duration::from_secs(config.scheduler.max_retry_delay_secs));
+    let mut registry = JobRegistry::new(config.scheduler.max_concurrent_jobs)
+        .with_metrics(metrics)
+        .with_run_history(pool)
+        .with_retry_backoff(backoff);
+    for (job_type, limit) in &config.scheduler.job_type_concurrency {
+        registry = registry.with_job_type_limit(job_type, *limit);
+    }
+    registry
 }
 
 // Re-export key types for convenience
-pub use job::{Job, JobContext, JobResult};
-pub use registry::Schedule;
+pub use job::{Job, JobContext, JobDependencies, JobResult};
+pub use registry::{RetryBackoff, RetrySleeper, Schedule};
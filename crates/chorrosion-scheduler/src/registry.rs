@@ -1,11 +1,14 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
-use crate::job::{Job, JobContext, JobResult};
+use crate::job::{Job, JobContext, JobDependencies, JobResult};
+use chorrosion_application::AppMetrics;
+use chrono::{DateTime, Utc};
+use sqlx::SqlitePool;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
-use tokio::sync::{RwLock, Semaphore};
+use tokio::sync::{OwnedSemaphorePermit, RwLock, Semaphore};
 use tokio::time::{interval, Duration};
-use tracing::{error, info, warn};
+use tracing::{debug, error, info, warn};
 
 /// Job schedule configuration
 #[derive(Debug, Clone)]
@@ -18,16 +21,169 @@ pub enum Schedule {
     Cron(String),
 }
 
+/// Default upper bound for the wait between retry attempts.
+pub const DEFAULT_MAX_RETRY_DELAY: Duration = Duration::from_secs(3600);
+
+/// Waits out the delay between retry attempts; injectable so tests need not sleep.
+#[async_trait::async_trait]
+pub trait RetrySleeper: Send + Sync {
+    async fn sleep(&self, delay: Duration);
+}
+
+/// [`RetrySleeper`] backed by the tokio timer.
+pub struct TokioSleeper;
+
+#[async_trait::async_trait]
+impl RetrySleeper for TokioSleeper {
+    async fn sleep(&self, delay: Duration) {
+        tokio::time::sleep(delay).await;
+    }
+}
+
+/// Picks the actual wait in `[0, ceiling]` for one retry.
+pub type JitterFn = dyn Fn(Duration) -> Duration + Send + Sync;
+
+/// Exponential backoff with full jitter between job retry attempts.
+///
+/// Retry `n` (1-based) waits a uniformly random duration in
+/// `[0, min(max_delay, base * 2^(n-1))]`, where `base` is the job's
+/// [`Job::retry_delay_seconds`].
+#[derive(Clone)]
+pub struct RetryBackoff {
+    max_delay: Duration,
+    jitter: Arc<JitterFn>,
+    sleeper: Arc<dyn RetrySleeper>,
+}
+
+impl Default for RetryBackoff {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_RETRY_DELAY)
+    }
+}
+
+impl RetryBackoff {
+    pub fn new(max_delay: Duration) -> Self {
+        Self {
+            max_delay,
+            jitter: Arc::new(full_jitter),
+            sleeper: Arc::new(TokioSleeper),
+        }
+    }
+
+    /// Replace the random jitter source; results above the ceiling are clamped.
+    pub fn with_jitter(
+        mut self,
+        jitter: impl Fn(Duration) -> Duration + Send + Sync + 'static,
+    ) -> Self {
+        self.jitter = Arc::new(jitter);
+        self
+    }
+
+    /// Wait between attempts with `sleeper` instead of the tokio timer.
+    pub fn with_sleeper(mut self, sleeper: Arc<dyn RetrySleeper>) -> Self {
+        self.sleeper = sleeper;
+        self
+    }
+
+    /// Un-jittered upper bound for the wait before retry `retry` (1-based).
+    pub fn ceiling(&self, base: Duration, retry: u32) -> Duration {
+        let factor = 1u32
+            .checked_shl(retry.saturating_sub(1))
+            .unwrap_or(u32::MAX);
+        base.checked_mul(factor)
+            .unwrap_or(Duration::MAX)
+            .min(self.max_delay)
+    }
+
+    /// Jittered wait before retry `retry` (1-based).
+    pub fn delay(&self, base: Duration, retry: u32) -> Duration {
+        let ceiling = self.ceiling(base, retry);
+        (self.jitter)(ceiling).min(ceiling)
+    }
+
+    async fn wait(&self, delay: Duration) {
+        self.sleeper.sleep(delay).await;
+    }
+}
+
+/// Uniformly random duration in `[0, ceiling]` at millisecond resolution.
+fn full_jitter(ceiling: Duration) -> Duration {
+    let ceiling_ms = u64::try_from(ceiling.as_millis()).unwrap_or(u64::MAX);
+    if ceiling_ms == 0 {
+        return Duration::ZERO;
+    }
+    let (random, _) = uuid::Uuid::new_v4().as_u64_pair();
+    Duration::from_millis(random % ceiling_ms.saturating_add(1))
+}
+
 /// Registered job with its schedule
 struct RegisteredJob {
     job: Arc<dyn Job>,
     schedule: Schedule,
 }
 
+/// Permits a job holds while it runs: one under its type's limit, if it has
+/// one, and one under the global cap.
+struct JobSlot {
+    type_limit: Option<Arc<Semaphore>>,
+    global: Arc<Semaphore>,
+    permits: Option<(Option<OwnedSemaphorePermit>, OwnedSemaphorePermit)>,
+}
+
+impl JobSlot {
+    fn new(type_limit: Option<Arc<Semaphore>>, global: Arc<Semaphore>) -> Self {
+        Self {
+            type_limit,
+            global,
+            permits: None,
+        }
+    }
+
+    /// Wait for a slot under the job type's limit, then under the global cap.
+    ///
+    /// Returns `false` once shutdown has closed the semaphores.
+    async fn acquire(&mut self, job_id: &str) -> bool {
+        let type_permit = match &self.type_limit {
+            Some(limit) => {
+                if limit.available_permits() == 0 {
+                    debug!(target: "registry", %job_id, "job type at its concurrency limit, queueing");
+                }
+                match limit.clone().acquire_owned().await {
+                    Ok(permit) => Some(permit),
+                    Err(_) => return false,
+                }
+            }
+            None => None,
+        };
+        match self.global.clone().acquire_owned().await {
+            Ok(permit) => {
+                self.permits = Some((type_permit, permit));
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Give the permits back so other jobs can run while this one waits.
+    fn release(&mut self) {
+        self.permits = None;
+    }
+}
+
 /// Job registry that manages and executes scheduled jobs
 pub struct JobRegistry {
     jobs: Arc<RwLock<HashMap<String, RegisteredJob>>>,
     max_concurrent: usize,
+    /// One permit per running job; closed once shutdown stops new executions.
+    semaphore: Arc<Semaphore>,
+    metrics: AppMetrics,
+    /// When set, every job attempt is appended to the `job_runs` table.
+    history: Option<SqlitePool>,
+    /// Handed to every job through its [`JobContext`]; read once by [`JobRegistry::start`].
+    dependencies: RwLock<Option<Arc<JobDependencies>>>,
+    retry_backoff: RetryBackoff,
+    /// Per-job-type permits, checked before the global `semaphore`.
+    type_limits: HashMap<String, Arc<Semaphore>>,
 }
 
 impl JobRegistry {
@@ -35,9 +191,46 @@ impl JobRegistry {
         Self {
             jobs: Arc::new(RwLock::new(HashMap::new())),
             max_concurrent,
+            semaphore: Arc::new(Semaphore::new(max_concurrent)),
+            metrics: AppMetrics::default(),
+            history: None,
+            dependencies: RwLock::new(None),
+            retry_backoff: RetryBackoff::default(),
+            type_limits: HashMap::new(),
         }
     }
 
+    /// Record job executions in `metrics` instead of a private registry.
+    pub fn with_metrics(mut self, metrics: AppMetrics) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// Persist one `job_runs` row per job attempt in `pool`.
+    pub fn with_run_history(mut self, pool: SqlitePool) -> Self {
+        self.history = Some(pool);
+        self
+    }
+
+    /// Space retry attempts with `backoff` instead of the default policy.
+    pub fn with_retry_backoff(mut self, backoff: RetryBackoff) -> Self {
+        self.retry_backoff = backoff;
+        self
+    }
+
+    /// Run at most `limit` jobs of `job_type` at once; extra runs queue until a
+    /// slot frees up. The global `max_concurrent` cap still applies.
+    pub fn with_job_type_limit(mut self, job_type: impl Into<String>, limit: usize) -> Self {
+        self.type_limits
+            .insert(job_type.into(), Arc::new(Semaphore::new(limit)));
+        self
+    }
+
+    /// Share `dependencies` with every job started after this call.
+    pub async fn set_dependencies(&self, dependencies: Arc<JobDependencies>) {
+        *self.dependencies.write().await = Some(dependencies);
+    }
+
     /// Register a job with its schedule
     pub async fn register(
         &self,
@@ -60,8 +253,8 @@ impl JobRegistry {
     pub async fn start(self: Arc<Self>) {
         info!(target: "registry", max_concurrent = self.max_concurrent, "starting job registry");
 
-        let semaphore = Arc::new(Semaphore::new(self.max_concurrent));
         let jobs = self.jobs.read().await;
+        let dependencies = self.dependencies.read().await.clone();
 
         for (job_id, registered) in jobs.iter() {
             match &registered.schedule {
@@ -69,33 +262,66 @@ impl JobRegistry {
                     let job_id = job_id.clone();
                     let job = registered.job.clone();
                     let interval_duration = Duration::from_secs(*seconds);
-                    let semaphore = semaphore.clone();
+                    let semaphore = self.semaphore.clone();
+                    let type_limit = self.type_limit(job.job_type());
+                    let metrics = self.metrics.clone();
+                    let history = self.history.clone();
+                    let dependencies = dependencies.clone();
+                    let backoff = self.retry_backoff.clone();
 
                     tokio::spawn(async move {
                         let mut ticker = interval(interval_duration);
                         loop {
                             ticker.tick().await;
-                            let permit = semaphore.clone().acquire_owned().await;
-                            if let Ok(permit) = permit {
-                                let job = job.clone();
-                                let job_id = job_id.clone();
-                                tokio::spawn(async move {
-                                    let _permit = permit;
-                                    Self::execute_job(job_id, job).await;
-                                });
+                            let mut slot = JobSlot::new(type_limit.clone(), semaphore.clone());
+                            if !slot.acquire(&job_id).await {
+                                debug!(target: "registry", %job_id, "registry shut down, stopping schedule");
+                                break;
                             }
+                            let job = job.clone();
+                            let job_id = job_id.clone();
+                            let metrics = metrics.clone();
+                            let history = history.clone();
+                            let dependencies = dependencies.clone();
+                            let backoff = backoff.clone();
+                            tokio::spawn(async move {
+                                Self::execute_job(
+                                    job_id,
+                                    job,
+                                    slot,
+                                    metrics,
+                                    history,
+                                    dependencies,
+                                    backoff,
+                                )
+                                .await;
+                            });
                         }
                     });
                 }
                 Schedule::Once => {
                     let job_id = job_id.clone();
                     let job = registered.job.clone();
-                    let semaphore = semaphore.clone();
+                    let semaphore = self.semaphore.clone();
+                    let type_limit = self.type_limit(job.job_type());
+                    let metrics = self.metrics.clone();
+                    let history = self.history.clone();
+                    let dependencies = dependencies.clone();
+                    let backoff = self.retry_backoff.clone();
 
                     tokio::spawn(async move {
-                        let permit = semaphore.acquire_owned().await;
-                        if let Ok(_permit) = permit {
-                            Self::execute_job(job_id, job).await;
+                        let mut slot = JobSlot::new(type_limit, semaphore);
+                        if slot.acquire(&job_id).await {
+                            Self::execute_job(
+                                job_id,
+                                job,
+                                slot,
+                                metrics,
+                                history,
+                                dependencies,
+                                backoff,
+                            )
+                            .await;
                         }
                     });
                 }
@@ -108,15 +334,69 @@ impl JobRegistry {
         info!(target: "registry", "job registry started with {} jobs", jobs.len());
     }
 
-    /// Execute a single job with retry logic
-    async fn execute_job(job_id: String, job: Arc<dyn Job>) {
-        let ctx = JobContext::new(&job_id);
+    fn type_limit(&self, job_type: &str) -> Option<Arc<Semaphore>> {
+        self.type_limits.get(job_type).cloned()
+    }
+
+    /// Stop starting new jobs and wait up to `timeout` for running ones to finish.
+    ///
+    /// Jobs still running when the timeout elapses are logged and abandoned.
+    /// Returns `true` when every in-flight job finished in time.
+    pub async fn shutdown(&self, timeout: Duration) -> bool {
+        info!(target: "registry", ?timeout, "shutting down job registry");
+
+        // The semaphore is fair, so once this request is queued no new job can
+        // acquire a permit ahead of it; holding every permit means nothing runs.
+        let all_permits = u32::try_from(self.max_concurrent).unwrap_or(u32::MAX);
+        let drained = tokio::time::timeout(timeout, self.semaphore.acquire_many(all_permits)).await;
+        let in_flight = self
+            .max_concurrent
+            .saturating_sub(self.semaphore.available_permits());
+        self.semaphore.close();
+        for limit in self.type_limits.values() {
+            limit.close();
+        }
+
+        match drained {
+            Ok(_) => {
+                info!(target: "registry", "all in-flight jobs finished");
+                true
+            }
+            Err(_) => {
+                warn!(
+                    target: "registry",
+                    in_flight,
+                    ?timeout,
+                    "abandoning in-flight jobs after shutdown timeout"
+                );
+                false
+            }
+        }
+    }
+
+    /// Execute a single job, retrying with jittered exponential backoff
+    ///
+    /// `slot` is released for the wait between attempts and taken again before
+    /// the next one, so a failing job does not hold back other jobs or shutdown.
+    async fn execute_job(
+        job_id: String,
+        job: Arc<dyn Job>,
+        mut slot: JobSlot,
+        metrics: AppMetrics,
+        history: Option<SqlitePool>,
+        dependencies: Option<Arc<JobDependencies>>,
+        backoff: RetryBackoff,
+    ) {
+        let _active = metrics.job_started();
+        let mut ctx = JobContext::new(&job_id);
+        ctx.dependencies = dependencies;
         let mut attempts = 0;
         let max_attempts = if job.is_retriable() {
             job.max_retries() + 1
         } else {
             1
         };
+        let base_delay = Duration::from_secs(job.retry_delay_seconds());
 
         loop {
             attempts += 1;
@@ -130,9 +410,21 @@ impl JobRegistry {
             );
 
             let attempt_start = Instant::now();
+            let started_at = Utc::now();
             let execution_result = job.execute(ctx.clone()).await;
             match execution_result {
                 Ok(JobResult::Success) => {
+                    metrics.record_job_execution(job.job_type(), "success");
+                    Self::record_run(
+                        history.as_ref(),
+                        &job_id,
+                        job.job_type(),
+                        attempts,
+                        "success",
+                        None,
+                        started_at,
+                    )
+                    .await;
                     let elapsed_ms = attempt_start.elapsed().as_millis() as u64;
                     info!(
                         target: "registry",
@@ -146,6 +438,17 @@ impl JobRegistry {
                     break;
                 }
                 Ok(JobResult::Failure { error, retry }) => {
+                    metrics.record_job_execution(job.job_type(), "failure");
+                    Self::record_run(
+                        history.as_ref(),
+                        &job_id,
+                        job.job_type(),
+                        attempts,
+                        "failure",
+                        Some(&error),
+                        started_at,
+                    )
+                    .await;
                     let elapsed_ms = attempt_start.elapsed().as_millis() as u64;
                     error!(
                         target: "registry",
@@ -160,14 +463,16 @@ impl JobRegistry {
                     );
 
                     if retry && attempts < max_attempts {
-                        let delay = Duration::from_secs(job.retry_delay_seconds());
+                        let delay = backoff.delay(base_delay, attempts);
                         warn!(
                             target: "registry",
                             job_id = %job_id,
                             ?delay,
                             "retrying job after delay"
                         );
-                        tokio::time::sleep(delay).await;
+                        if !Self::wait_for_retry(&job_id, &mut slot, &backoff, delay).await {
+                            break;
+                        }
                     } else {
                         error!(
                             target: "registry",
@@ -178,6 +483,17 @@ impl JobRegistry {
                     }
                 }
                 Err(err) => {
+                    metrics.record_job_execution(job.job_type(), "error");
+                    Self::record_run(
+                        history.as_ref(),
+                        &job_id,
+                        job.job_type(),
+                        attempts,
+                        "error",
+                        Some(&err.to_string()),
+                        started_at,
+                    )
+                    .await;
                     let elapsed_ms = attempt_start.elapsed().as_millis() as u64;
                     error!(
                         target: "registry",
@@ -191,14 +507,16 @@ impl JobRegistry {
                     );
 
                     if job.is_retriable() && attempts < max_attempts {
-                        let delay = Duration::from_secs(job.retry_delay_seconds());
+                        let delay = backoff.delay(base_delay, attempts);
                         warn!(
                             target: "registry",
                             job_id = %job_id,
                             ?delay,
                             "retrying job after delay"
                         );
-                        tokio::time::sleep(delay).await;
+                        if !Self::wait_for_retry(&job_id, &mut slot, &backoff, delay).await {
+                            break;
+                        }
                     } else {
                         break;
                     }
@@ -206,4 +524,553 @@ impl JobRegistry {
             }
         }
     }
+
+    /// Wait out `delay` without holding `slot`, then take it again.
+    ///
+    /// Returns `false` when shutdown closed the registry during the wait.
+    async fn wait_for_retry(
+        job_id: &str,
+        slot: &mut JobSlot,
+        backoff: &RetryBackoff,
+        delay: Duration,
+    ) -> bool {
+        slot.release();
+        backoff.wait(delay).await;
+        if slot.acquire(job_id).await {
+            true
+        } else {
+            warn!(target: "registry", %job_id, "registry shut down, abandoning job retries");
+            false
+        }
+    }
+
+    /// Append a `job_runs` row for one attempt; failures are logged, never propagated.
+    async fn record_run(
+        history: Option<&SqlitePool>,
+        job_id: &str,
+        job_type: &str,
+        attempt: u32,
+        status: &str,
+        error: Option<&str>,
+        started_at: DateTime<Utc>,
+    ) {
+        let Some(pool) = history else {
+            return;
+        };
+
+        let result = sqlx::query(
+            "INSERT INTO job_runs (id, job_id, job_type, attempt, status, error, started_at, finished_at) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(uuid::Uuid::new_v4().to_string())
+        .bind(job_id)
+        .bind(job_type)
+        .bind(attempt as i64)
+        .bind(status)
+        .bind(error)
+        .bind(started_at.to_rfc3339())
+        .bind(Utc::now().to_rfc3339())
+        .execute(pool)
+        .await;
+
+        if let Err(e) = result {
+            warn!(target: "registry", %job_id, error = %e, "failed to record job run history");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, AtomicU32, AtomicUsize, Ordering};
+    use std::sync::Mutex;
+    use tokio::sync::Notify;
+
+    struct SleepingJob {
+        duration: Duration,
+        started: Arc<Notify>,
+        finished: Arc<AtomicBool>,
+    }
+
+    #[async_trait::async_trait]
+    impl Job for SleepingJob {
+        fn job_type(&self) -> &'static str {
+            "sleeping"
+        }
+
+        fn name(&self) -> String {
+            "Sleeping job".to_string()
+        }
+
+        async fn execute(&self, _ctx: JobContext) -> anyhow::Result<JobResult> {
+            self.started.notify_one();
+            tokio::time::sleep(self.duration).await;
+            self.finished.store(true, Ordering::SeqCst);
+            Ok(JobResult::Success)
+        }
+    }
+
+    struct FailingJob {
+        retriable: bool,
+        attempts: Arc<AtomicU32>,
+    }
+
+    #[async_trait::async_trait]
+    impl Job for FailingJob {
+        fn job_type(&self) -> &'static str {
+            "failing"
+        }
+
+        fn name(&self) -> String {
+            "Failing job".to_string()
+        }
+
+        async fn execute(&self, _ctx: JobContext) -> anyhow::Result<JobResult> {
+            self.attempts.fetch_add(1, Ordering::SeqCst);
+            Ok(JobResult::Failure {
+                error: "boom".to_string(),
+                retry: true,
+            })
+        }
+
+        fn is_retriable(&self) -> bool {
+            self.retriable
+        }
+
+        fn max_retries(&self) -> u32 {
+            4
+        }
+
+        fn retry_delay_seconds(&self) -> u64 {
+            10
+        }
+    }
+
+    /// Tracks how many jobs of its type run at once.
+    #[derive(Clone)]
+    struct ConcurrencyProbeJob {
+        running: Arc<AtomicUsize>,
+        peak: Arc<AtomicUsize>,
+        completed: Arc<AtomicUsize>,
+    }
+
+    impl ConcurrencyProbeJob {
+        fn new() -> Self {
+            Self {
+                running: Arc::new(AtomicUsize::new(0)),
+                peak: Arc::new(AtomicUsize::new(0)),
+                completed: Arc::new(AtomicUsize::new(0)),
+            }
+        }
+
+        async fn wait_for_completed(&self, count: usize) {
+            tokio::time::timeout(Duration::from_secs(5), async {
+                while self.completed.load(Ordering::SeqCst) < count {
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                }
+            })
+            .await
+            .expect("jobs did not complete in time");
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl Job for ConcurrencyProbeJob {
+        fn job_type(&self) -> &'static str {
+            "limited"
+        }
+
+        fn name(&self) -> String {
+            "Concurrency probe job".to_string()
+        }
+
+        async fn execute(&self, _ctx: JobContext) -> anyhow::Result<JobResult> {
+            let running = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(running, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            self.running.fetch_sub(1, Ordering::SeqCst);
+            self.completed.fetch_add(1, Ordering::SeqCst);
+            Ok(JobResult::Success)
+        }
+    }
+
+    async fn register_probes(registry: &JobRegistry, probe: &ConcurrencyProbeJob, count: usize) {
+        for i in 0..count {
+            registry
+                .register(format!("limited-{i}"), probe.clone(), Schedule::Once)
+                .await;
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingSleeper {
+        delays: Mutex<Vec<Duration>>,
+    }
+
+    #[async_trait::async_trait]
+    impl RetrySleeper for RecordingSleeper {
+        async fn sleep(&self, delay: Duration) {
+            self.delays.lock().unwrap().push(delay);
+        }
+    }
+
+    /// Runs a [`FailingJob`] to completion and returns its attempt count and
+    /// the delays slept between attempts.
+    async fn run_failing_job(retriable: bool, backoff: RetryBackoff) -> (u32, Vec<Duration>) {
+        let sleeper = Arc::new(RecordingSleeper::default());
+        let metrics = AppMetrics::new();
+        let registry = Arc::new(
+            JobRegistry::new(1)
+                .with_metrics(metrics.clone())
+                .with_retry_backoff(backoff.with_sleeper(sleeper.clone())),
+        );
+        let attempts = Arc::new(AtomicU32::new(0));
+        registry
+            .register(
+                "failing",
+                FailingJob {
+                    retriable,
+                    attempts: attempts.clone(),
+                },
+                Schedule::Once,
+            )
+            .await;
+
+        registry.clone().start().await;
+        // The job gives its permit back between attempts, so wait for it to
+        // finish rather than draining the registry.
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while attempts.load(Ordering::SeqCst) == 0 || metrics.active_jobs() > 0 {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("failing job did not finish in time");
+
+        let delays = sleeper.delays.lock().unwrap().clone();
+        (attempts.load(Ordering::SeqCst), delays)
+    }
+
+    /// Blocks every retry wait until the test opens the gate.
+    #[derive(Default)]
+    struct GatedSleeper {
+        waiting: Notify,
+        gate: Notify,
+    }
+
+    #[async_trait::async_trait]
+    impl RetrySleeper for GatedSleeper {
+        async fn sleep(&self, _delay: Duration) {
+            self.waiting.notify_one();
+            self.gate.notified().await;
+        }
+    }
+
+    /// Starts a registry running one job that sleeps for `duration`, returning
+    /// once the job has begun executing.
+    async fn start_with_running_job(duration: Duration) -> (Arc<JobRegistry>, Arc<AtomicBool>) {
+        let registry = Arc::new(JobRegistry::new(2));
+        let started = Arc::new(Notify::new());
+        let finished = Arc::new(AtomicBool::new(false));
+        registry
+            .register(
+                "sleeping",
+                SleepingJob {
+                    duration,
+                    started: started.clone(),
+                    finished: finished.clone(),
+                },
+                Schedule::Once,
+            )
+            .await;
+
+        registry.clone().start().await;
+        started.notified().await;
+        (registry, finished)
+    }
+
+    #[tokio::test]
+    async fn shutdown_waits_for_in_flight_job() {
+        let (registry, finished) = start_with_running_job(Duration::from_millis(100)).await;
+
+        let drained = registry.shutdown(Duration::from_secs(5)).await;
+
+        assert!(drained);
+        assert!(finished.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn shutdown_abandons_jobs_past_timeout() {
+        let (registry, finished) = start_with_running_job(Duration::from_secs(60)).await;
+
+        let start = Instant::now();
+        let drained = registry.shutdown(Duration::from_millis(100)).await;
+
+        assert!(!drained);
+        assert!(start.elapsed() < Duration::from_secs(5));
+        assert!(!finished.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn executing_a_job_increments_its_counter() {
+        let metrics = AppMetrics::new();
+        let registry = Arc::new(JobRegistry::new(1).with_metrics(metrics.clone()));
+        let started = Arc::new(Notify::new());
+        registry
+            .register(
+                "sleeping",
+                SleepingJob {
+                    duration: Duration::ZERO,
+                    started: started.clone(),
+                    finished: Arc::new(AtomicBool::new(false)),
+                },
+                Schedule::Once,
+            )
+            .await;
+
+        registry.clone().start().await;
+        started.notified().await;
+        // Draining guarantees the execution has been recorded.
+        assert!(registry.shutdown(Duration::from_secs(5)).await);
+
+        assert_eq!(metrics.job_executions("sleeping", "success"), 1);
+        assert_eq!(metrics.active_jobs(), 0);
+    }
+
+    #[tokio::test]
+    async fn executing_a_job_records_run_history() {
+        let config = chorrosion_config::AppConfig {
+            database: chorrosion_config::DatabaseConfig {
+                url: "sqlite::memory:".to_string(),
+                pool_max_size: 1,
+                ..chorrosion_config::DatabaseConfig::default()
+            },
+            ..chorrosion_config::AppConfig::default()
+        };
+        let pool = chorrosion_infrastructure::init_database(&config)
+            .await
+            .expect("in-memory DB init failed");
+        let registry = Arc::new(JobRegistry::new(1).with_run_history(pool.clone()));
+        let started = Arc::new(Notify::new());
+        registry
+            .register(
+                "sleeping",
+                SleepingJob {
+                    duration: Duration::ZERO,
+                    started: started.clone(),
+                    finished: Arc::new(AtomicBool::new(false)),
+                },
+                Schedule::Once,
+            )
+            .await;
+
+        registry.clone().start().await;
+        started.notified().await;
+        assert!(registry.shutdown(Duration::from_secs(5)).await);
+
+        let runs: Vec<(String, String, String, i64)> =
+            sqlx::query_as("SELECT job_id, job_type, status, attempt FROM job_runs")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(
+            runs,
+            vec![(
+                "sleeping".to_string(),
+                "sleeping".to_string(),
+                "success".to_string(),
+                1
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn shutdown_stops_interval_schedules() {
+        let registry = Arc::new(JobRegistry::new(1));
+        registry.shutdown(Duration::from_secs(1)).await;
+
+        let started = Arc::new(Notify::new());
+        registry
+            .register(
+                "sleeping",
+                SleepingJob {
+                    duration: Duration::ZERO,
+                    started: started.clone(),
+                    finished: Arc::new(AtomicBool::new(false)),
+                },
+                Schedule::Interval(1),
+            )
+            .await;
+        registry.clone().start().await;
+
+        let ran = tokio::time::timeout(Duration::from_millis(200), started.notified()).await;
+        assert!(ran.is_err(), "no job should start after shutdown");
+    }
+
+    #[test]
+    fn backoff_ceiling_doubles_up_to_the_cap() {
+        let backoff = RetryBackoff::new(Duration::from_secs(50));
+        let base = Duration::from_secs(10);
+
+        let ceilings: Vec<u64> = (1..=5)
+            .map(|retry| backoff.ceiling(base, retry).as_secs())
+            .collect();
+
+        assert_eq!(ceilings, vec![10, 20, 40, 50, 50]);
+        assert_eq!(
+            backoff.ceiling(base, u32::MAX),
+            Duration::from_secs(50),
+            "huge retry counts must not overflow"
+        );
+    }
+
+    #[test]
+    fn backoff_delay_stays_within_jitter_bounds() {
+        let backoff = RetryBackoff::default();
+        let base = Duration::from_secs(1);
+
+        for retry in 1..=6 {
+            let ceiling = backoff.ceiling(base, retry);
+            for _ in 0..50 {
+                assert!(backoff.delay(base, retry) <= ceiling);
+            }
+        }
+
+        let greedy = RetryBackoff::default().with_jitter(|ceiling| ceiling * 3);
+        assert_eq!(greedy.delay(base, 2), Duration::from_secs(2));
+    }
+
+    #[tokio::test]
+    async fn retries_wait_with_growing_jittered_delays() {
+        let backoff = RetryBackoff::new(Duration::from_secs(60)).with_jitter(|ceiling| ceiling);
+
+        let (attempts, delays) = run_failing_job(true, backoff).await;
+
+        assert_eq!(attempts, 5);
+        assert_eq!(
+            delays,
+            vec![
+                Duration::from_secs(10),
+                Duration::from_secs(20),
+                Duration::from_secs(40),
+                Duration::from_secs(60),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn random_jitter_never_exceeds_the_backoff_ceiling() {
+        let backoff = RetryBackoff::new(Duration::from_secs(60));
+
+        let (_, delays) = run_failing_job(true, backoff.clone()).await;
+
+        assert_eq!(delays.len(), 4);
+        for (retry, delay) in (1..).zip(&delays) {
+            assert!(*delay <= backoff.ceiling(Duration::from_secs(10), retry));
+        }
+    }
+
+    #[tokio::test]
+    async fn retry_wait_frees_the_slot_for_other_jobs() {
+        let global = Arc::new(Semaphore::new(1));
+        let type_limit = Arc::new(Semaphore::new(1));
+        let sleeper = Arc::new(GatedSleeper::default());
+        let attempts = Arc::new(AtomicU32::new(0));
+
+        let mut slot = JobSlot::new(Some(type_limit.clone()), global.clone());
+        assert!(slot.acquire("failing").await);
+        let failing = tokio::spawn(JobRegistry::execute_job(
+            "failing".to_string(),
+            Arc::new(FailingJob {
+                retriable: true,
+                attempts: attempts.clone(),
+            }),
+            slot,
+            AppMetrics::new(),
+            None,
+            None,
+            RetryBackoff::default().with_sleeper(sleeper.clone()),
+        ));
+        sleeper.waiting.notified().await;
+
+        // Both the type and the global permit are free while the failing job waits.
+        let started = Arc::new(Notify::new());
+        let finished = Arc::new(AtomicBool::new(false));
+        let mut other = JobSlot::new(Some(type_limit.clone()), global.clone());
+        tokio::time::timeout(Duration::from_secs(1), other.acquire("other"))
+            .await
+            .expect("slot should be free during the retry wait");
+        JobRegistry::execute_job(
+            "other".to_string(),
+            Arc::new(SleepingJob {
+                duration: Duration::ZERO,
+                started,
+                finished: finished.clone(),
+            }),
+            other,
+            AppMetrics::new(),
+            None,
+            None,
+            RetryBackoff::default(),
+        )
+        .await;
+        assert!(finished.load(Ordering::SeqCst));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+
+        // Once the wait ends the failing job takes its slot back for the next attempt.
+        sleeper.gate.notify_one();
+        sleeper.waiting.notified().await;
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+        failing.abort();
+    }
+
+    #[tokio::test]
+    async fn non_retriable_job_runs_once() {
+        let (attempts, delays) = run_failing_job(false, RetryBackoff::default()).await;
+
+        assert_eq!(attempts, 1);
+        assert!(delays.is_empty());
+    }
+
+    #[tokio::test]
+    async fn job_type_limit_queues_excess_jobs_while_other_types_progress() {
+        let registry = Arc::new(JobRegistry::new(8).with_job_type_limit("limited", 2));
+        let probe = ConcurrencyProbeJob::new();
+        register_probes(&registry, &probe, 6).await;
+        let other_started = Arc::new(Notify::new());
+        registry
+            .register(
+                "sleeping",
+                SleepingJob {
+                    duration: Duration::ZERO,
+                    started: other_started.clone(),
+                    finished: Arc::new(AtomicBool::new(false)),
+                },
+                Schedule::Once,
+            )
+            .await;
+
+        registry.clone().start().await;
+        tokio::time::timeout(Duration::from_secs(1), other_started.notified())
+            .await
+            .expect("other job types must not wait behind the limited type");
+        assert!(probe.completed.load(Ordering::SeqCst) < 6);
+
+        probe.wait_for_completed(6).await;
+        assert_eq!(probe.peak.load(Ordering::SeqCst), 2);
+        assert!(registry.shutdown(Duration::from_secs(5)).await);
+    }
+
+    #[tokio::test]
+    async fn global_cap_bounds_job_type_limit() {
+        let registry = Arc::new(JobRegistry::new(2).with_job_type_limit("limited", 5));
+        let probe = ConcurrencyProbeJob::new();
+        register_probes(&registry, &probe, 6).await;
+
+        registry.clone().start().await;
+
+        probe.wait_for_completed(6).await;
+        assert_eq!(probe.peak.load(Ordering::SeqCst), 2);
+        assert!(registry.shutdown(Duration::from_secs(5)).await);
+    }
 }
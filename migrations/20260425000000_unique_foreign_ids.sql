@@ -0,0 +1,102 @@
+-- Foreign (metadata provider) IDs identify a single artist/album when present so
+-- repositories can upsert on them with INSERT ... ON CONFLICT.
+--
+-- Earlier check-then-create races may have left duplicates behind. Each group
+-- of rows sharing a foreign ID is merged into its oldest row before the unique
+-- indexes are added: children, relationships and tags move to the kept row and
+-- the duplicates are deleted, so no library data is lost.
+
+CREATE TEMP TABLE duplicate_artists AS
+SELECT artists.id AS duplicate_id,
+       (SELECT keep.id FROM artists keep
+        WHERE keep.foreign_artist_id = artists.foreign_artist_id
+        ORDER BY keep.created_at, keep.id
+        LIMIT 1) AS keep_id
+FROM artists
+WHERE foreign_artist_id IS NOT NULL
+  AND EXISTS (
+    SELECT 1 FROM artists older
+    WHERE older.foreign_artist_id = artists.foreign_artist_id
+      AND (older.created_at, older.id) < (artists.created_at, artists.id)
+  );
+
+UPDATE albums
+SET artist_id = (SELECT keep_id FROM duplicate_artists WHERE duplicate_id = albums.artist_id)
+WHERE artist_id IN (SELECT duplicate_id FROM duplicate_artists);
+
+UPDATE tracks
+SET artist_id = (SELECT keep_id FROM duplicate_artists WHERE duplicate_id = tracks.artist_id)
+WHERE artist_id IN (SELECT duplicate_id FROM duplicate_artists);
+
+-- Relationships that would point an artist at itself, or repeat one that
+-- survives the merge, are dropped before the rest are re-pointed.
+DELETE FROM artist_relationships
+WHERE COALESCE((SELECT keep_id FROM duplicate_artists WHERE duplicate_id = source_artist_id), source_artist_id)
+    = COALESCE((SELECT keep_id FROM duplicate_artists WHERE duplicate_id = related_artist_id), related_artist_id);
+
+DELETE FROM artist_relationships
+WHERE EXISTS (
+  SELECT 1 FROM artist_relationships older
+  WHERE older.relationship_type = artist_relationships.relationship_type
+    AND COALESCE((SELECT keep_id FROM duplicate_artists WHERE duplicate_id = older.source_artist_id), older.source_artist_id)
+      = COALESCE((SELECT keep_id FROM duplicate_artists WHERE duplicate_id = artist_relationships.source_artist_id), artist_relationships.source_artist_id)
+    AND COALESCE((SELECT keep_id FROM duplicate_artists WHERE duplicate_id = older.related_artist_id), older.related_artist_id)
+      = COALESCE((SELECT keep_id FROM duplicate_artists WHERE duplicate_id = artist_relationships.related_artist_id), artist_relationships.related_artist_id)
+    AND (older.created_at, older.id) < (artist_relationships.created_at, artist_relationships.id)
+);
+
+UPDATE artist_relationships
+SET source_artist_id = (SELECT keep_id FROM duplicate_artists WHERE duplicate_id = artist_relationships.source_artist_id)
+WHERE source_artist_id IN (SELECT duplicate_id FROM duplicate_artists);
+
+UPDATE artist_relationships
+SET related_artist_id = (SELECT keep_id FROM duplicate_artists WHERE duplicate_id = artist_relationships.related_artist_id)
+WHERE related_artist_id IN (SELECT duplicate_id FROM duplicate_artists);
+
+INSERT OR IGNORE INTO tagged_entities (tag_id, entity_id, entity_type, created_at)
+SELECT tagged_entities.tag_id, duplicate_artists.keep_id, tagged_entities.entity_type, tagged_entities.created_at
+FROM tagged_entities
+JOIN duplicate_artists ON duplicate_artists.duplicate_id = tagged_entities.entity_id
+WHERE tagged_entities.entity_type = 'artist';
+
+DELETE FROM artists WHERE id IN (SELECT duplicate_id FROM duplicate_artists);
+
+DROP TABLE duplicate_artists;
+
+CREATE TEMP TABLE duplicate_albums AS
+SELECT albums.id AS duplicate_id,
+       (SELECT keep.id FROM albums keep
+        WHERE keep.foreign_album_id = albums.foreign_album_id
+        ORDER BY keep.created_at, keep.id
+        LIMIT 1) AS keep_id
+FROM albums
+WHERE foreign_album_id IS NOT NULL
+  AND EXISTS (
+    SELECT 1 FROM albums older
+    WHERE older.foreign_album_id = albums.foreign_album_id
+      AND (older.created_at, older.id) < (albums.created_at, albums.id)
+  );
+
+UPDATE tracks
+SET album_id = (SELECT keep_id FROM duplicate_albums WHERE duplicate_id = tracks.album_id)
+WHERE album_id IN (SELECT duplicate_id FROM duplicate_albums);
+
+INSERT OR IGNORE INTO tagged_entities (tag_id, entity_id, entity_type, created_at)
+SELECT tagged_entities.tag_id, duplicate_albums.keep_id, tagged_entities.entity_type, tagged_entities.created_at
+FROM tagged_entities
+JOIN duplicate_albums ON duplicate_albums.duplicate_id = tagged_entities.entity_id
+WHERE tagged_entities.entity_type = 'album';
+
+DELETE FROM albums WHERE id IN (SELECT duplicate_id FROM duplicate_albums);
+
+DROP TABLE duplicate_albums;
+
+DROP INDEX IF EXISTS idx_artists_foreign_artist_id;
+CREATE UNIQUE INDEX IF NOT EXISTS ux_artists_foreign_artist_id
+ON artists(foreign_artist_id)
+WHERE foreign_artist_id IS NOT NULL;
+
+DROP INDEX IF EXISTS idx_albums_foreign_album_id;
+CREATE UNIQUE INDEX IF NOT EXISTS ux_albums_foreign_album_id
+ON albums(foreign_album_id)
+WHERE foreign_album_id IS NOT NULL;
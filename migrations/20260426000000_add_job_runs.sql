@@ -0,0 +1,16 @@
+-- SPDX-License-Identifier: GPL-3.0-or-later
+
+-- One row per scheduled job attempt; trimmed by the housekeeping job.
+CREATE TABLE IF NOT EXISTS job_runs (
+    id TEXT PRIMARY KEY,
+    job_id TEXT NOT NULL,
+    job_type TEXT NOT NULL,
+    attempt INTEGER NOT NULL,
+    status TEXT NOT NULL CHECK(status IN ('success', 'failure', 'error')),
+    error TEXT,
+    started_at TEXT NOT NULL,
+    finished_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_job_runs_finished_at ON job_runs(finished_at DESC);
+CREATE INDEX IF NOT EXISTS idx_job_runs_job_id ON job_runs(job_id);
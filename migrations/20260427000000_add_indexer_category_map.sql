@@ -0,0 +1,4 @@
+-- SPDX-License-Identifier: GPL-3.0-or-later
+
+-- Per-indexer category ID overrides, stored as a JSON object of name -> ID.
+ALTER TABLE indexer_definitions ADD COLUMN category_map TEXT NOT NULL DEFAULT '{}';
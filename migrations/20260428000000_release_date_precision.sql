@@ -0,0 +1,26 @@
+-- SPDX-License-Identifier: GPL-3.0-or-later
+
+-- albums.release_date always holds a full "YYYY-MM-DD" date so range queries
+-- such as list_upcoming_releases compare like with like. A partial date is
+-- stored as the first day of its period, and release_date_precision records
+-- whether the value is a "year", "month" or "day" date.
+--
+-- Rows written before this migration may hold "2024" (stored as an INTEGER by
+-- the column's NUMERIC affinity) or "2024-03"; they are normalized here.
+ALTER TABLE albums ADD COLUMN release_date_precision TEXT;
+
+UPDATE albums
+SET release_date_precision = CASE length(CAST(release_date AS TEXT))
+        WHEN 4 THEN 'year'
+        WHEN 7 THEN 'month'
+        ELSE 'day'
+    END
+WHERE release_date IS NOT NULL;
+
+UPDATE albums
+SET release_date = CAST(release_date AS TEXT) || '-01-01'
+WHERE release_date_precision = 'year';
+
+UPDATE albums
+SET release_date = CAST(release_date AS TEXT) || '-01'
+WHERE release_date_precision = 'month';
@@ -0,0 +1,11 @@
+-- SPDX-License-Identifier: GPL-3.0-or-later
+
+-- Profiles applied to new artists created without one. The partial unique
+-- indexes allow at most one default of each kind.
+ALTER TABLE quality_profiles ADD COLUMN is_default BOOLEAN NOT NULL DEFAULT 0;
+ALTER TABLE metadata_profiles ADD COLUMN is_default BOOLEAN NOT NULL DEFAULT 0;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_quality_profiles_single_default
+    ON quality_profiles (is_default) WHERE is_default = 1;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_metadata_profiles_single_default
+    ON metadata_profiles (is_default) WHERE is_default = 1;
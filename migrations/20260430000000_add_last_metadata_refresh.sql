@@ -0,0 +1,6 @@
+-- SPDX-License-Identifier: GPL-3.0-or-later
+
+-- When the metadata refresh jobs last updated an artist or album from
+-- MusicBrainz; NULL until the first successful refresh.
+ALTER TABLE artists ADD COLUMN last_metadata_refresh_at TEXT;
+ALTER TABLE albums ADD COLUMN last_metadata_refresh_at TEXT;
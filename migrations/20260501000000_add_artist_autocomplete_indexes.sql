@@ -0,0 +1,5 @@
+-- SPDX-License-Identifier: GPL-3.0-or-later
+
+-- Anchored case-insensitive prefix lookups for artist autocomplete. Names are
+-- already covered by idx_artists_name_nocase.
+CREATE INDEX IF NOT EXISTS idx_artists_sort_name_nocase ON artists(sort_name COLLATE NOCASE);
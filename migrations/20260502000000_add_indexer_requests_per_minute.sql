@@ -0,0 +1,4 @@
+-- SPDX-License-Identifier: GPL-3.0-or-later
+
+-- Per-indexer request rate limit; NULL leaves the indexer unthrottled.
+ALTER TABLE indexer_definitions ADD COLUMN requests_per_minute INTEGER;
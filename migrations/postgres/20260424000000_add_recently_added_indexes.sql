@@ -0,0 +1,7 @@
+-- Support "recently added" and "updated since" listings for artists, albums and tracks.
+CREATE INDEX IF NOT EXISTS idx_artists_created_at ON artists(created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_artists_updated_at ON artists(updated_at);
+CREATE INDEX IF NOT EXISTS idx_albums_created_at ON albums(created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_albums_updated_at ON albums(updated_at);
+CREATE INDEX IF NOT EXISTS idx_tracks_created_at ON tracks(created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_tracks_updated_at ON tracks(updated_at);
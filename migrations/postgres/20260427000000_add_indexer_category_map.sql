@@ -0,0 +1,2 @@
+-- Per-indexer category ID overrides, stored as a JSON object of name -> ID.
+ALTER TABLE indexer_definitions ADD COLUMN IF NOT EXISTS category_map TEXT NOT NULL DEFAULT '{}';
@@ -0,0 +1,15 @@
+-- SPDX-License-Identifier: GPL-3.0-or-later
+
+-- albums.release_date always holds a full "YYYY-MM-DD" date so range queries
+-- such as list_upcoming_releases compare like with like. A partial date is
+-- stored as the first day of its period, and release_date_precision records
+-- whether the value is a "year", "month" or "day" date. Existing DATE values
+-- are full dates.
+ALTER TABLE albums
+    ALTER COLUMN release_date TYPE TEXT USING to_char(release_date, 'YYYY-MM-DD');
+
+ALTER TABLE albums ADD COLUMN release_date_precision TEXT;
+
+UPDATE albums
+SET release_date_precision = 'day'
+WHERE release_date IS NOT NULL;
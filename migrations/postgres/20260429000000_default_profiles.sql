@@ -0,0 +1,9 @@
+-- Profiles applied to new artists created without one. The partial unique
+-- indexes allow at most one default of each kind.
+ALTER TABLE quality_profiles ADD COLUMN IF NOT EXISTS is_default BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE metadata_profiles ADD COLUMN IF NOT EXISTS is_default BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_quality_profiles_single_default
+    ON quality_profiles (is_default) WHERE is_default;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_metadata_profiles_single_default
+    ON metadata_profiles (is_default) WHERE is_default;
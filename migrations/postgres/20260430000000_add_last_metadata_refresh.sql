@@ -0,0 +1,4 @@
+-- When the metadata refresh jobs last updated an artist or album from
+-- MusicBrainz; NULL until the first successful refresh.
+ALTER TABLE artists ADD COLUMN IF NOT EXISTS last_metadata_refresh_at TIMESTAMP;
+ALTER TABLE albums ADD COLUMN IF NOT EXISTS last_metadata_refresh_at TIMESTAMP;
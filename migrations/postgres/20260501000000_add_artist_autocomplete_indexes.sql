@@ -0,0 +1,5 @@
+-- Anchored case-insensitive prefix lookups for artist autocomplete. The
+-- existing LOWER(name) index uses the default operator class, which LIKE
+-- cannot use outside the C locale.
+CREATE INDEX IF NOT EXISTS idx_artists_name_lower_pattern ON artists (LOWER(name) text_pattern_ops);
+CREATE INDEX IF NOT EXISTS idx_artists_sort_name_lower_pattern ON artists (LOWER(sort_name) text_pattern_ops);
@@ -0,0 +1,2 @@
+-- Per-indexer request rate limit; NULL leaves the indexer unthrottled.
+ALTER TABLE indexer_definitions ADD COLUMN IF NOT EXISTS requests_per_minute INTEGER;